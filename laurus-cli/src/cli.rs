@@ -16,9 +16,18 @@ use crate::output::OutputFormat;
 #[command(name = "laurus", version, about)]
 pub struct Cli {
     /// Path to the index directory.
+    ///
+    /// `serve` hosts every index in a subdirectory of it.
     #[arg(long, env = "LAURUS_INDEX_DIR", default_value = "./laurus_index")]
     pub index_dir: PathBuf,
 
+    /// Name of the index to use, stored in `<index-dir>/<name>`.
+    ///
+    /// Selects one of the indexes hosted by `serve` for the local commands,
+    /// and is the default index of the `mcp` tools.
+    #[arg(long, env = "LAURUS_INDEX", value_parser = parse_index_name)]
+    pub index: Option<String>,
+
     /// Output format.
     #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
     pub format: OutputFormat,
@@ -27,6 +36,20 @@ pub struct Cli {
     pub command: Command,
 }
 
+/// Accepts index names made of ASCII letters, digits, `-` and `_`, the
+/// names laurus-server hosts.
+fn parse_index_name(name: &str) -> Result<String, String> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        Ok(name.to_string())
+    } else {
+        Err("only ASCII letters, digits, '-' and '_' are allowed".to_string())
+    }
+}
+
 #[derive(Subcommand)]
 pub enum Command {
     /// Create a resource.
@@ -41,6 +64,12 @@ pub enum Command {
     Delete(DeleteCommand),
     /// Commit pending changes.
     Commit,
+    /// Create or restore a snapshot of the index.
+    Snapshot(SnapshotCommand),
+    /// Export every document as JSON Lines.
+    Export(ExportCommand),
+    /// Import documents from JSON Lines.
+    Import(ImportCommand),
     /// Execute a search query.
     Search(SearchCommand),
     /// Start an interactive REPL session.
@@ -198,6 +227,75 @@ pub enum DeleteResource {
     },
 }
 
+// --- Snapshot ---
+
+/// CLI arguments for the `snapshot` subcommand.
+///
+/// Holds the snapshot operation to perform (create or restore).
+#[derive(Parser)]
+pub struct SnapshotCommand {
+    #[command(subcommand)]
+    pub resource: SnapshotResource,
+}
+
+#[derive(Subcommand)]
+pub enum SnapshotResource {
+    /// Commit and copy the index into a backup directory.
+    Create {
+        /// Backup directory. Created if it does not exist.
+        path: PathBuf,
+        /// Only copy the files that changed since the snapshot already
+        /// stored in the backup directory.
+        #[arg(long)]
+        incremental: bool,
+    },
+    /// Replace the index with the snapshot stored in a backup directory.
+    Restore {
+        /// Backup directory holding a snapshot.
+        path: PathBuf,
+    },
+}
+
+// --- Export / Import ---
+
+/// CLI arguments for the `export` subcommand.
+///
+/// Writes every live stored document with its external ID as one JSON
+/// object per line.
+#[derive(Parser)]
+pub struct ExportCommand {
+    /// Output file, or `-` for standard output.
+    pub output: PathBuf,
+
+    /// Export the indexed vectors of vector fields instead of their stored
+    /// source values, so that documents are not embedded again on import.
+    #[arg(long)]
+    pub vectors: bool,
+
+    /// Number of document IDs read per batch.
+    #[arg(long, default_value_t = laurus::DEFAULT_EXPORT_BATCH_SIZE)]
+    pub batch_size: usize,
+}
+
+/// CLI arguments for the `import` subcommand.
+///
+/// Adds the documents of a JSON Lines export to the index, creating the
+/// index first when a schema is given.
+#[derive(Parser)]
+pub struct ImportCommand {
+    /// Input file, or `-` for standard input.
+    pub input: PathBuf,
+
+    /// Create a new index from this schema TOML file before importing.
+    /// When omitted, documents are imported into the existing index.
+    #[arg(long)]
+    pub schema: Option<PathBuf>,
+
+    /// Number of documents committed together.
+    #[arg(long, default_value_t = laurus::DEFAULT_EXPORT_BATCH_SIZE)]
+    pub batch_size: usize,
+}
+
 // --- Mcp ---
 
 /// CLI arguments for the `mcp` subcommand.
@@ -239,6 +337,11 @@ pub struct ServeCommand {
     /// HTTP Gateway port. If set, starts an HTTP gateway alongside the gRPC server.
     #[arg(long = "http-port", env = "LAURUS_HTTP_PORT")]
     pub http_port: Option<u16>,
+
+    /// Run as a read-only replication follower of the leader at this gRPC
+    /// address (e.g. `http://10.0.0.1:50051`).
+    #[arg(long = "follow", env = "LAURUS_FOLLOW")]
+    pub follow: Option<String>,
 }
 
 // --- Search ---
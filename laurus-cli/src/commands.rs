@@ -6,20 +6,26 @@
 //! - [`commit`] - Commit pending changes.
 //! - [`create`] - Create a resource (index or schema file).
 //! - [`delete`] - Delete a resource (field or document).
+//! - [`export`] - Export every document as JSON Lines.
 //! - [`get`] - Retrieve a resource (index stats or document).
+//! - [`import`] - Import documents from JSON Lines.
 //! - [`mcp`] - MCP (Model Context Protocol) server on stdio.
 //! - [`put`] - Put (upsert) a resource (document).
 //! - [`repl`] - Interactive Read-Eval-Print Loop session.
 //! - [`search`] - One-shot search query execution.
 //! - [`serve`] - gRPC (and optional HTTP gateway) server.
+//! - [`snapshot`] - Create or restore a snapshot of the index.
 
 pub mod add;
 pub mod commit;
 pub mod create;
 pub mod delete;
+pub mod export;
 pub mod get;
+pub mod import;
 pub mod mcp;
 pub mod put;
 pub mod repl;
 pub mod search;
 pub mod serve;
+pub mod snapshot;
@@ -60,7 +60,8 @@ pub async fn run_index(schema_path: Option<&Path>, index_dir: &Path) -> Result<(
     Ok(())
 }
 use laurus::lexical::core::field::{
-    BooleanOption, BytesOption, DateTimeOption, FloatOption, GeoOption, IntegerOption, TextOption,
+    BooleanOption, BytesOption, CompletionOption, DateTimeOption, FloatOption, GeoOption,
+    IntegerOption, TextOption,
 };
 use laurus::vector::DistanceMetric;
 use laurus::vector::core::field::{FlatOption, HnswOption, IvfOption};
@@ -68,7 +69,17 @@ use laurus::{FieldOption, Schema};
 
 /// Field type names shown in the interactive prompt.
 const FIELD_TYPES: &[&str] = &[
-    "Text", "Integer", "Float", "Boolean", "DateTime", "Geo", "Bytes", "Hnsw", "Flat", "Ivf",
+    "Text",
+    "Integer",
+    "Float",
+    "Boolean",
+    "DateTime",
+    "Geo",
+    "Bytes",
+    "Completion",
+    "Hnsw",
+    "Flat",
+    "Ivf",
 ];
 
 /// Distance metric names shown in the interactive prompt.
@@ -174,6 +185,9 @@ pub fn build_schema_interactive() -> Result<Schema> {
         embedders: std::collections::HashMap::new(),
         fields,
         default_fields,
+        expiry_field: None,
+        acl_field: None,
+        tenant_field: None,
     })
 }
 
@@ -215,6 +229,7 @@ fn prompt_field_type_and_options() -> Result<FieldOption> {
         "DateTime" => prompt_indexed_stored_option("DateTime"),
         "Geo" => prompt_indexed_stored_option("Geo"),
         "Bytes" => prompt_bytes_option(),
+        "Completion" => prompt_completion_option(),
         "Hnsw" => prompt_hnsw_option(),
         "Flat" => prompt_flat_option(),
         "Ivf" => prompt_ivf_option(),
@@ -238,7 +253,26 @@ fn prompt_text_option() -> Result<FieldOption> {
         .interact()?;
 
     let analyzer_choices = [
-        "standard", "keyword", "english", "japanese", "simple", "noop",
+        "standard",
+        "standard_folding",
+        "keyword",
+        "english",
+        "japanese",
+        "korean",
+        "chinese",
+        "danish",
+        "dutch",
+        "french",
+        "german",
+        "italian",
+        "norwegian",
+        "portuguese",
+        "russian",
+        "spanish",
+        "swedish",
+        "phonetic",
+        "simple",
+        "noop",
     ];
     let analyzer_idx = dialoguer::Select::new()
         .with_prompt("Analyzer")
@@ -252,6 +286,7 @@ fn prompt_text_option() -> Result<FieldOption> {
         stored,
         term_vectors,
         analyzer,
+        ..Default::default()
     }))
 }
 
@@ -286,6 +321,30 @@ fn prompt_bytes_option() -> Result<FieldOption> {
     Ok(FieldOption::Bytes(BytesOption { stored }))
 }
 
+/// Prompt for CompletionOption (stored, weight field, max edits).
+fn prompt_completion_option() -> Result<FieldOption> {
+    let stored = Confirm::new()
+        .with_prompt("Stored?")
+        .default(true)
+        .interact()?;
+    let weight_field: String = Input::new()
+        .with_prompt("Weight field (Integer field, empty for none)")
+        .allow_empty(true)
+        .interact_text()?;
+    let max_edits: u32 = Input::new()
+        .with_prompt("Max edits for fuzzy suggestions (0 disables)")
+        .default(1)
+        .interact_text()?;
+
+    let mut option = CompletionOption::default()
+        .stored(stored)
+        .max_edits(max_edits);
+    if !weight_field.is_empty() {
+        option = option.weight_field(weight_field);
+    }
+    Ok(FieldOption::Completion(option))
+}
+
 /// Prompt for a distance metric selection.
 fn prompt_distance_metric() -> Result<DistanceMetric> {
     let idx = Select::new()
@@ -404,6 +463,7 @@ fn field_type_label(option: &FieldOption) -> &'static str {
         FieldOption::DateTime(_) => "DateTime",
         FieldOption::Geo(_) => "Geo",
         FieldOption::Bytes(_) => "Bytes",
+        FieldOption::Completion(_) => "Completion",
         FieldOption::Hnsw(_) => "Hnsw",
         FieldOption::Flat(_) => "Flat",
         FieldOption::Ivf(_) => "Ivf",
@@ -0,0 +1,56 @@
+//! Implementation for the `export` subcommand.
+//!
+//! Writes every live stored document of the index, together with its
+//! external ID, as JSON Lines that `import` can read back.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::cli::ExportCommand;
+use crate::context;
+
+/// Execute the `export` command.
+///
+/// Opens the index at `index_dir`, commits pending changes and writes each
+/// document to the output as one JSON object per line. Progress is reported
+/// on standard error.
+///
+/// # Arguments
+///
+/// * `cmd` - Parsed [`ExportCommand`] with the output path and options.
+/// * `index_dir` - Path to the index directory holding the index.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The index cannot be opened.
+/// - The output file cannot be created or written.
+/// - A document cannot be read.
+pub async fn run(cmd: ExportCommand, index_dir: &Path) -> Result<()> {
+    let engine = context::open_index(index_dir).await?;
+
+    let mut writer: Box<dyn Write> = if cmd.output == Path::new("-") {
+        Box::new(BufWriter::new(std::io::stdout().lock()))
+    } else {
+        let file = File::create(&cmd.output)
+            .with_context(|| format!("Failed to create {}", cmd.output.display()))?;
+        Box::new(BufWriter::new(file))
+    };
+
+    let export = engine
+        .export(cmd.vectors)
+        .await
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+    let total = export.total();
+    let exported = export
+        .write_jsonl(&mut writer, cmd.batch_size, |n| {
+            eprint!("\rExported {n}/{total} documents");
+        })
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+
+    eprintln!("\rExported {exported} documents.");
+    Ok(())
+}
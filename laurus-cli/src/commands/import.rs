@@ -0,0 +1,56 @@
+//! Implementation for the `import` subcommand.
+//!
+//! Adds the documents of a JSON Lines export to an index, optionally
+//! creating the index from a schema first.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::cli::ImportCommand;
+use crate::context;
+
+/// Execute the `import` command.
+///
+/// If a schema is given, creates a new index at `index_dir` from it. Then
+/// opens the index, adds every document of the input as a chunk under its
+/// external ID and commits. Progress is reported on standard error.
+///
+/// # Arguments
+///
+/// * `cmd` - Parsed [`ImportCommand`] with the input path and options.
+/// * `index_dir` - Path to the index directory holding the index.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The index cannot be created (when a schema is given) or opened.
+/// - The input cannot be read or a line is not a valid exported document.
+/// - A document cannot be indexed.
+pub async fn run(cmd: ImportCommand, index_dir: &Path) -> Result<()> {
+    if let Some(schema_path) = &cmd.schema {
+        context::create_index(index_dir, schema_path).await?;
+        eprintln!("Index created at {}.", index_dir.display());
+    }
+    let engine = context::open_index(index_dir).await?;
+
+    let reader: Box<dyn BufRead> = if cmd.input == Path::new("-") {
+        Box::new(std::io::stdin().lock())
+    } else {
+        let file = File::open(&cmd.input)
+            .with_context(|| format!("Failed to open {}", cmd.input.display()))?;
+        Box::new(BufReader::new(file))
+    };
+
+    let imported = engine
+        .import(reader, cmd.batch_size, |n| {
+            eprint!("\rImported {n} documents");
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+
+    eprintln!("\rImported {imported} documents.");
+    Ok(())
+}
@@ -16,11 +16,12 @@ use anyhow::Result;
 ///
 /// * `endpoint` - Optional gRPC endpoint URL of a running laurus-server
 ///   (e.g. `http://localhost:50051`).
+/// * `index` - Optional name of the index the tools use by default.
 ///
 /// # Errors
 ///
 /// Returns an error if the MCP server fails to start or encounters a fatal
 /// runtime error.
-pub async fn run(endpoint: Option<&str>) -> Result<()> {
-    laurus_mcp::server::run(endpoint).await
+pub async fn run(endpoint: Option<&str>, index: Option<&str>) -> Result<()> {
+    laurus_mcp::server::run(endpoint, index).await
 }
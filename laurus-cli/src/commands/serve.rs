@@ -14,14 +14,14 @@ use crate::cli::ServeCommand;
 ///
 /// Loads a base configuration from the TOML file specified in `cmd.config`
 /// (or uses defaults), applies any overrides from CLI flags / environment
-/// variables, sets the index index directory, and delegates to the
+/// variables, sets the root directory of the hosted indexes, and delegates to the
 /// `laurus-server` runtime.
 ///
 /// # Arguments
 ///
 /// * `cmd` - Parsed [`ServeCommand`] containing optional config path, host,
-///   port, and HTTP port.
-/// * `index_dir` - Path to the index directory holding the index.
+///   port, HTTP port, and leader address to follow.
+/// * `index_dir` - Root directory holding one subdirectory per hosted index.
 ///
 /// # Returns
 ///
@@ -49,6 +49,10 @@ pub async fn run(cmd: ServeCommand, index_dir: &Path) -> Result<()> {
     if cmd.http_port.is_some() {
         config.server.http_port = cmd.http_port;
     }
+    if let Some(leader) = cmd.follow {
+        config.replication.role = laurus_server::config::ReplicationRole::Follower;
+        config.replication.leader = Some(leader);
+    }
     config.index.data_dir = index_dir.to_path_buf();
 
     laurus_server::server::run(&config).await
@@ -0,0 +1,76 @@
+//! Implementations for the `snapshot` subcommand.
+//!
+//! Handles backing up and restoring an index:
+//!
+//! - [`run_create`] - Copy the committed index into a backup directory.
+//! - [`run_restore`] - Replace the index with a snapshot.
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::context;
+
+/// Execute the `snapshot create` command.
+///
+/// Opens the index at `index_dir`, commits pending changes and copies the
+/// committed files into `path` together with a manifest describing them.
+///
+/// # Arguments
+///
+/// * `path` - Backup directory. Created if it does not exist.
+/// * `incremental` - Only copy the files that changed since the snapshot
+///   already stored in `path`.
+/// * `index_dir` - Path to the index directory holding the index.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The index cannot be opened.
+/// - The backup directory cannot be created or written.
+pub async fn run_create(path: &Path, incremental: bool, index_dir: &Path) -> Result<()> {
+    let engine = context::open_index(index_dir).await?;
+    let backup = context::backup_storage(path)?;
+
+    let report = engine
+        .snapshot_to(backup.as_ref(), incremental)
+        .await
+        .map_err(|e| anyhow::anyhow!("{e}"))?;
+
+    println!(
+        "Snapshot at seq {} written to {} ({} files copied, {} bytes, {} unchanged, {} removed).",
+        report.manifest.seq,
+        path.display(),
+        report.copied_files,
+        report.copied_bytes,
+        report.unchanged_files,
+        report.removed_files
+    );
+    Ok(())
+}
+
+/// Execute the `snapshot restore` command.
+///
+/// Replaces the index at `index_dir` (including its schema) with the
+/// snapshot stored in `path`. No other process may have the index open.
+///
+/// # Arguments
+///
+/// * `path` - Backup directory holding a snapshot.
+/// * `index_dir` - Path to the index directory to restore into.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `path` holds no snapshot or a file does not match its checksum.
+/// - The restored index cannot be written.
+pub fn run_restore(path: &Path, index_dir: &Path) -> Result<()> {
+    let manifest = context::restore_index(index_dir, path)?;
+    println!(
+        "Index restored from {} at seq {} ({} files).",
+        path.display(),
+        manifest.seq,
+        manifest.files.len()
+    );
+    Ok(())
+}
@@ -5,10 +5,13 @@
 //! used by the various CLI subcommands to obtain an [`Engine`] instance.
 
 use std::path::Path;
+use std::sync::Arc;
 
 use anyhow::{Context, Result, bail};
 use laurus::storage::file::FileStorageConfig;
-use laurus::{Engine, Schema, StorageConfig, StorageFactory};
+use laurus::{
+    Engine, Schema, SnapshotManifest, Storage, StorageConfig, StorageFactory, restore_snapshot,
+};
 
 /// File name used to persist the schema inside the index directory.
 const SCHEMA_FILE: &str = "schema.toml";
@@ -220,3 +223,67 @@ pub fn save_schema(index_dir: &Path, schema: &Schema) -> Result<()> {
     std::fs::write(&schema_dest, &schema_toml).context("Failed to write schema file")?;
     Ok(())
 }
+
+/// Open the file storage of a backup directory, creating it if needed.
+///
+/// # Arguments
+///
+/// * `path` - Directory holding (or receiving) a snapshot.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created.
+pub fn backup_storage(path: &Path) -> Result<Arc<dyn Storage>> {
+    let storage_config = StorageConfig::File(FileStorageConfig::new(path));
+    StorageFactory::open(storage_config)
+        .with_context(|| format!("Failed to open backup directory: {}", path.display()))
+}
+
+/// Replace the index in the given index directory with a snapshot.
+///
+/// The snapshot files are first restored into a temporary `store.next/`
+/// directory and verified against their checksums, so a failed restore
+/// leaves the current index untouched. The restored storage then replaces
+/// `store/` and the snapshot schema is written as `schema.toml`.
+///
+/// # Arguments
+///
+/// * `index_dir` - Path to the index directory to restore into. Created if
+///   it does not exist.
+/// * `backup_dir` - Directory holding a snapshot.
+///
+/// # Returns
+///
+/// Returns the manifest of the restored snapshot.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - `backup_dir` holds no snapshot or a file does not match its checksum.
+/// - The restored files or the schema cannot be written.
+pub fn restore_index(index_dir: &Path, backup_dir: &Path) -> Result<SnapshotManifest> {
+    if !backup_dir.is_dir() {
+        bail!("Backup directory not found: {}", backup_dir.display());
+    }
+    std::fs::create_dir_all(index_dir).context("Failed to create index directory")?;
+
+    let staging_path = index_dir.join(format!("{STORE_DIR}.next"));
+    if staging_path.exists() {
+        std::fs::remove_dir_all(&staging_path).context("Failed to remove staging directory")?;
+    }
+    let backup = backup_storage(backup_dir)?;
+    let staging =
+        StorageFactory::create(StorageConfig::File(FileStorageConfig::new(&staging_path)))?;
+    let manifest = restore_snapshot(backup.as_ref(), staging.as_ref())
+        .with_context(|| format!("Failed to restore snapshot from {}", backup_dir.display()))?;
+    drop(staging);
+
+    let store_path = index_dir.join(STORE_DIR);
+    if store_path.exists() {
+        std::fs::remove_dir_all(&store_path).context("Failed to remove current storage")?;
+    }
+    std::fs::rename(&staging_path, &store_path).context("Failed to move restored storage")?;
+    save_schema(index_dir, &manifest.schema)?;
+
+    Ok(manifest)
+}
@@ -24,15 +24,22 @@ use anyhow::Result;
 use clap::Parser;
 
 use crate::cli::{
-    AddResource, Cli, Command, CreateResource, DeleteResource, GetResource, McpCommand, PutResource,
+    AddResource, Cli, Command, CreateResource, DeleteResource, GetResource, McpCommand,
+    PutResource, SnapshotResource,
+};
+use crate::commands::{
+    add, commit, create, delete, export, get, import, mcp, put, repl, search, serve, snapshot,
 };
-use crate::commands::{add, commit, create, delete, get, mcp, put, repl, search, serve};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
     let format = cli.format;
-    let index_dir = cli.index_dir;
+    let root_dir = cli.index_dir;
+    let index_dir = match &cli.index {
+        Some(name) => root_dir.join(name),
+        None => root_dir.clone(),
+    };
 
     match cli.command {
         Command::Create(cmd) => match cmd.resource {
@@ -60,9 +67,19 @@ async fn main() -> Result<()> {
             DeleteResource::Field { name } => delete::run_field(&name, &index_dir).await,
         },
         Command::Commit => commit::run(&index_dir).await,
+        Command::Snapshot(cmd) => match cmd.resource {
+            SnapshotResource::Create { path, incremental } => {
+                snapshot::run_create(&path, incremental, &index_dir).await
+            }
+            SnapshotResource::Restore { path } => snapshot::run_restore(&path, &index_dir),
+        },
+        Command::Export(cmd) => export::run(cmd, &index_dir).await,
+        Command::Import(cmd) => import::run(cmd, &index_dir).await,
         Command::Search(cmd) => search::run(cmd, &index_dir, format).await,
         Command::Repl => repl::run(&index_dir, format).await,
-        Command::Serve(cmd) => serve::run(cmd, &index_dir).await,
-        Command::Mcp(McpCommand { endpoint }) => mcp::run(endpoint.as_deref()).await,
+        Command::Serve(cmd) => serve::run(cmd, &root_dir).await,
+        Command::Mcp(McpCommand { endpoint }) => {
+            mcp::run(endpoint.as_deref(), cli.index.as_deref()).await
+        }
     }
 }
@@ -11,6 +11,7 @@
 //! | Tool | Description |
 //! |------|-------------|
 //! | `connect` | Connect to a running laurus-server gRPC endpoint |
+//! | `list_indexes` | List the indexes hosted by the server |
 //! | `create_index` | Create a new search index from a JSON schema |
 //! | `get_index` | Get index statistics |
 //! | `add_document` | Add or upsert a document |
@@ -21,9 +22,10 @@
 //!
 //! # Usage
 //!
-//! Typically started via `laurus mcp [--endpoint <url>]` from the `laurus-cli`
-//! crate.  The server reads from stdin and writes to stdout (MCP stdio
-//! transport).
+//! Typically started via `laurus [--index <name>] mcp [--endpoint <url>]` from
+//! the `laurus-cli` crate.  Every index tool takes an optional `index`
+//! argument that defaults to the `--index` name.  The server reads from stdin
+//! and writes to stdout (MCP stdio transport).
 
 pub mod convert;
 pub mod error;
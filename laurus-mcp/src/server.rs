@@ -20,9 +20,10 @@ use tracing::info;
 
 use laurus_server::proto::laurus::v1::{
     AddDocumentRequest, AddFieldRequest, CommitRequest, CreateIndexRequest, DeleteDocumentsRequest,
-    DeleteFieldRequest, GetDocumentsRequest, GetIndexRequest, GetSchemaRequest, PutDocumentRequest,
-    SearchRequest, document_service_client::DocumentServiceClient,
-    index_service_client::IndexServiceClient, search_service_client::SearchServiceClient,
+    DeleteFieldRequest, GetDocumentsRequest, GetIndexRequest, GetSchemaRequest, ListIndexesRequest,
+    PutDocumentRequest, SearchRequest, UpdateFieldRequest,
+    document_service_client::DocumentServiceClient, index_service_client::IndexServiceClient,
+    search_service_client::SearchServiceClient,
 };
 
 use crate::convert;
@@ -61,6 +62,18 @@ struct CreateIndexParams {
     /// }
     /// ```
     schema_json: String,
+
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
+}
+
+/// Parameters of the tools that only address an index.
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct IndexParams {
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
 }
 
 /// Parameters for the `put_document` tool.
@@ -73,6 +86,10 @@ struct PutDocumentParams {
     ///
     /// Field names and value types must match the index schema.
     document: Value,
+
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
 }
 
 /// Parameters for the `add_document` tool.
@@ -85,6 +102,10 @@ struct AddDocumentParams {
     ///
     /// Field names and value types must match the index schema.
     document: Value,
+
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
 }
 
 /// Parameters for the `get_documents` tool.
@@ -92,6 +113,10 @@ struct AddDocumentParams {
 struct GetDocumentsParams {
     /// External document identifier to look up.
     id: String,
+
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
 }
 
 /// Parameters for the `delete_documents` tool.
@@ -99,6 +124,10 @@ struct GetDocumentsParams {
 struct DeleteDocumentsParams {
     /// External document identifier to delete.
     id: String,
+
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
 }
 
 /// Parameters for the `search` tool.
@@ -144,6 +173,10 @@ struct SearchParams {
     ///
     /// Example: `{"title": 2.0, "body": 1.0}`
     field_boosts: Option<String>,
+
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
 }
 
 /// Parameters for the `add_field` tool.
@@ -163,6 +196,15 @@ struct AddFieldParams {
     /// {"Integer": {}}
     /// ```
     field_option_json: String,
+
+    /// Optional stored field whose value is copied into the new field for
+    /// every existing document by a background backfill (e.g. `"body"` when
+    /// adding an embedded `"body_vec"`).
+    backfill_source_field: Option<String>,
+
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
 }
 
 /// Parameters for the `delete_field` tool.
@@ -170,6 +212,25 @@ struct AddFieldParams {
 struct DeleteFieldParams {
     /// The name of the field to remove from the index schema.
     name: String,
+
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
+}
+
+/// Parameters for the `update_field` tool.
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+struct UpdateFieldParams {
+    /// The name of the field to change.
+    name: String,
+
+    /// New field configuration as a JSON string, in the same format as
+    /// `add_field` (e.g. `{"Hnsw": {"dimension": 384, "embedder": "bert"}}`).
+    field_option_json: String,
+
+    /// Name of the index to use. Defaults to the index given with
+    /// `--index` when the MCP server was started.
+    index: Option<String>,
 }
 
 // ── Server struct ─────────────────────────────────────────────────────────────
@@ -179,9 +240,13 @@ struct DeleteFieldParams {
 /// The gRPC channel is stored in [`Arc<RwLock<Option<Channel>>>`].  When
 /// `None`, no connection has been established yet; use the `connect` tool to
 /// connect to a running laurus-server.
+///
+/// laurus-server hosts many named indexes. Every index tool takes an optional
+/// `index` argument, which defaults to the index given at startup.
 #[derive(Clone)]
 pub struct LaurusMcpServer {
     channel: Arc<RwLock<Option<Channel>>>,
+    default_index: Option<String>,
     tool_router: ToolRouter<LaurusMcpServer>,
 }
 
@@ -189,9 +254,10 @@ pub struct LaurusMcpServer {
 
 #[tool_router]
 impl LaurusMcpServer {
-    fn new(channel: Option<Channel>) -> Self {
+    fn new(channel: Option<Channel>, default_index: Option<String>) -> Self {
         Self {
             channel: Arc::new(RwLock::new(channel)),
+            default_index,
             tool_router: Self::tool_router(),
         }
     }
@@ -201,6 +267,19 @@ impl LaurusMcpServer {
         CallToolResult::error(vec![Content::text(msg.into())])
     }
 
+    /// Resolve the index a tool call addresses, falling back to the default
+    /// index.
+    #[allow(clippy::result_large_err)]
+    fn index_name(&self, index: Option<String>) -> Result<String, CallToolResult> {
+        index
+            .or_else(|| self.default_index.clone())
+            .ok_or_else(|| {
+                Self::tool_error(
+                    "No index specified. Pass the index argument or call list_indexes to see the available indexes.",
+                )
+            })
+    }
+
     // ── Connection tool ───────────────────────────────────────────────────────
 
     /// Connect to a running laurus-server gRPC endpoint.
@@ -233,6 +312,43 @@ impl LaurusMcpServer {
 
     // ── Index tools ───────────────────────────────────────────────────────────
 
+    /// List the indexes hosted by the server.
+    #[tool(
+        description = "List the indexes hosted by the connected laurus-server. Returns a JSON array of objects with the index name and whether it is currently loaded. Pass one of the names as the index argument of the other tools."
+    )]
+    async fn list_indexes(&self) -> Result<CallToolResult, McpError> {
+        let channel = match self.channel.read().await.clone() {
+            Some(ch) => ch,
+            None => {
+                return Ok(Self::tool_error(
+                    "Not connected. Call the connect tool first.",
+                ));
+            }
+        };
+
+        match IndexServiceClient::new(channel)
+            .list_indexes(ListIndexesRequest {})
+            .await
+        {
+            Ok(resp) => {
+                let indexes: Vec<Value> = resp
+                    .into_inner()
+                    .indexes
+                    .iter()
+                    .map(|info| json!({ "name": info.name, "open": info.open }))
+                    .collect();
+                let output = json!({
+                    "default_index": self.default_index,
+                    "indexes": indexes,
+                });
+                Ok(CallToolResult::success(vec![Content::text(
+                    output.to_string(),
+                )]))
+            }
+            Err(e) => Ok(Self::tool_error(format!("Failed to list indexes: {e}"))),
+        }
+    }
+
     /// Create a new search index with the provided schema.
     ///
     /// The schema describes the fields of the documents that will be indexed.
@@ -252,6 +368,11 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         let laurus_schema: laurus::Schema = match serde_json::from_str(&params.schema_json) {
             Ok(s) => s,
             Err(e) => {
@@ -264,6 +385,7 @@ impl LaurusMcpServer {
         let proto_schema = laurus_server::convert::schema::to_proto(&laurus_schema);
         let request = CreateIndexRequest {
             schema: Some(proto_schema),
+            index,
         };
 
         match IndexServiceClient::new(channel).create_index(request).await {
@@ -278,7 +400,10 @@ impl LaurusMcpServer {
     #[tool(
         description = "Get statistics for the current search index, including document count and vector field information."
     )]
-    async fn get_stats(&self) -> Result<CallToolResult, McpError> {
+    async fn get_stats(
+        &self,
+        Parameters(params): Parameters<IndexParams>,
+    ) -> Result<CallToolResult, McpError> {
         let channel = match self.channel.read().await.clone() {
             Some(ch) => ch,
             None => {
@@ -288,8 +413,13 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         match IndexServiceClient::new(channel)
-            .get_index(GetIndexRequest {})
+            .get_index(GetIndexRequest { index })
             .await
         {
             Ok(resp) => {
@@ -297,6 +427,11 @@ impl LaurusMcpServer {
                 let output = json!({
                     "document_count": r.document_count,
                     "vector_fields": r.vector_fields.keys().collect::<Vec<_>>(),
+                    "field_update": r.field_update.map(|p| json!({
+                        "field": p.field,
+                        "total": p.total,
+                        "processed": p.processed,
+                    })),
                 });
                 Ok(CallToolResult::success(vec![Content::text(
                     output.to_string(),
@@ -310,7 +445,10 @@ impl LaurusMcpServer {
     #[tool(
         description = "Get the current index schema, including all field definitions and their configurations. Returns the schema as a JSON object."
     )]
-    async fn get_schema(&self) -> Result<CallToolResult, McpError> {
+    async fn get_schema(
+        &self,
+        Parameters(params): Parameters<IndexParams>,
+    ) -> Result<CallToolResult, McpError> {
         let channel = match self.channel.read().await.clone() {
             Some(ch) => ch,
             None => {
@@ -320,8 +458,13 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         match IndexServiceClient::new(channel)
-            .get_schema(GetSchemaRequest {})
+            .get_schema(GetSchemaRequest { index })
             .await
         {
             Ok(resp) => {
@@ -349,7 +492,7 @@ impl LaurusMcpServer {
 
     /// Dynamically add a new field to the current index.
     #[tool(
-        description = "Dynamically add a new field to an existing index. The field_option_json must be a JSON string describing the field type and options (e.g. '{\"Text\": {\"indexed\": true, \"stored\": true}}', '{\"Hnsw\": {\"dimension\": 384}}', '{\"Integer\": {}}'). Returns the updated schema."
+        description = "Dynamically add a new field to an existing index. The field_option_json must be a JSON string describing the field type and options (e.g. '{\"Text\": {\"indexed\": true, \"stored\": true}}', '{\"Hnsw\": {\"dimension\": 384}}', '{\"Integer\": {}}'). Optionally set backfill_source_field to derive the new field from an existing stored field for documents already in the index. Returns the updated schema."
     )]
     async fn add_field(
         &self,
@@ -364,6 +507,11 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         let field_option: laurus::FieldOption =
             match serde_json::from_str(&params.field_option_json) {
                 Ok(fo) => fo,
@@ -379,6 +527,8 @@ impl LaurusMcpServer {
         let request = AddFieldRequest {
             name: params.name.clone(),
             field_option: Some(proto_field_option),
+            backfill_source_field: params.backfill_source_field.unwrap_or_default(),
+            index,
         };
 
         match IndexServiceClient::new(channel).add_field(request).await {
@@ -420,8 +570,14 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         let request = DeleteFieldRequest {
             name: params.name.clone(),
+            index,
         };
 
         match IndexServiceClient::new(channel).delete_field(request).await {
@@ -446,6 +602,61 @@ impl LaurusMcpServer {
         }
     }
 
+    /// Change the definition of an existing field.
+    #[tool(
+        description = "Change the definition of an existing field, e.g. its analyzer, embedder or vector index type. The field_option_json uses the same format as add_field. The index is rebuilt from stored documents in the background and keeps serving searches with the old definition until the rebuild is done; get_stats reports its progress. Returns the schema the index will have afterwards."
+    )]
+    async fn update_field(
+        &self,
+        Parameters(params): Parameters<UpdateFieldParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let channel = match self.channel.read().await.clone() {
+            Some(ch) => ch,
+            None => {
+                return Ok(Self::tool_error(
+                    "Not connected. Call the connect tool first.",
+                ));
+            }
+        };
+
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
+        let field_option: laurus::FieldOption =
+            match serde_json::from_str(&params.field_option_json) {
+                Ok(fo) => fo,
+                Err(e) => {
+                    return Ok(Self::tool_error(format!(
+                        "Failed to parse field_option_json: {e}"
+                    )));
+                }
+            };
+
+        let proto_field_option =
+            laurus_server::convert::schema::field_option_to_proto(&field_option);
+        let request = UpdateFieldRequest {
+            name: params.name.clone(),
+            field_option: Some(proto_field_option),
+            index,
+        };
+
+        match IndexServiceClient::new(channel).update_field(request).await {
+            Ok(resp) => {
+                let r = resp.into_inner();
+                let output = json!({
+                    "message": format!("Rebuilding index to update field '{}'.", params.name),
+                    "total_documents": r.progress.map_or(0, |p| p.total),
+                });
+                Ok(CallToolResult::success(vec![Content::text(
+                    output.to_string(),
+                )]))
+            }
+            Err(e) => Ok(Self::tool_error(format!("Failed to update field: {e}"))),
+        }
+    }
+
     // ── Document tools ────────────────────────────────────────────────────────
 
     /// Put (upsert) a document into the index.
@@ -468,6 +679,11 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         let doc = match convert::json_to_document(params.document) {
             Ok(d) => d,
             Err(e) => {
@@ -479,6 +695,8 @@ impl LaurusMcpServer {
             .put_document(PutDocumentRequest {
                 id: params.id.clone(),
                 document: Some(doc),
+                ttl_seconds: None,
+                index,
             })
             .await
         {
@@ -510,6 +728,11 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         let doc = match convert::json_to_document(params.document) {
             Ok(d) => d,
             Err(e) => {
@@ -521,6 +744,8 @@ impl LaurusMcpServer {
             .add_document(AddDocumentRequest {
                 id: params.id.clone(),
                 document: Some(doc),
+                ttl_seconds: None,
+                index,
             })
             .await
         {
@@ -549,9 +774,15 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         match DocumentServiceClient::new(channel)
             .get_documents(GetDocumentsRequest {
                 id: params.id.clone(),
+                index,
             })
             .await
         {
@@ -591,9 +822,15 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         match DocumentServiceClient::new(channel)
             .delete_documents(DeleteDocumentsRequest {
                 id: params.id.clone(),
+                index,
             })
             .await
         {
@@ -609,7 +846,10 @@ impl LaurusMcpServer {
     #[tool(
         description = "Commit pending changes to disk. Must be called after put_document, add_document, or delete_documents to make changes searchable and durable."
     )]
-    async fn commit(&self) -> Result<CallToolResult, McpError> {
+    async fn commit(
+        &self,
+        Parameters(params): Parameters<IndexParams>,
+    ) -> Result<CallToolResult, McpError> {
         let channel = match self.channel.read().await.clone() {
             Some(ch) => ch,
             None => {
@@ -619,8 +859,13 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         match DocumentServiceClient::new(channel)
-            .commit(CommitRequest {})
+            .commit(CommitRequest { index })
             .await
         {
             Ok(_) => Ok(CallToolResult::success(vec![Content::text(
@@ -649,6 +894,11 @@ impl LaurusMcpServer {
             }
         };
 
+        let index = match self.index_name(params.index) {
+            Ok(index) => index,
+            Err(e) => return Ok(e),
+        };
+
         // Parse optional fusion algorithm
         let fusion = if let Some(ref fusion_json) = params.fusion {
             match convert::json_to_fusion_algorithm(fusion_json) {
@@ -675,6 +925,7 @@ impl LaurusMcpServer {
             offset: params.offset.unwrap_or(0),
             fusion,
             field_boosts,
+            index,
             ..Default::default()
         };
 
@@ -688,6 +939,7 @@ impl LaurusMcpServer {
                         json!({
                             "id": result.id,
                             "score": result.score,
+                            "index": result.index,
                             "document": result.document.as_ref().map(convert::document_to_json),
                         })
                     })
@@ -715,10 +967,12 @@ impl ServerHandler for LaurusMcpServer {
             .with_server_info(Implementation::from_build_env())
             .with_instructions(
                 "Laurus search engine MCP server (gRPC client). \
-             Tools: connect, create_index, get_stats, get_schema, add_field, delete_field, \
+             Tools: connect, list_indexes, create_index, get_stats, get_schema, add_field, update_field, \
+             delete_field, \
              put_document, add_document, get_documents, delete_documents, commit, search. \
-             Start by calling connect(endpoint) to connect to a running laurus-server, \
-             then use the other tools to manage and search the index."
+             Start by calling connect(endpoint) to connect to a running laurus-server. \
+             The server hosts many named indexes; call list_indexes to see them and pass \
+             the index argument to the other tools unless a default index was configured."
                     .to_string(),
             )
     }
@@ -737,12 +991,14 @@ impl ServerHandler for LaurusMcpServer {
 /// # Arguments
 ///
 /// * `endpoint` - Optional gRPC endpoint URL (e.g. `http://localhost:50051`).
+/// * `index` - Optional name of the index that tools use when called without
+///   an `index` argument.
 ///
 /// # Errors
 ///
 /// Returns an error if the server transport fails to start or encounters a
 /// fatal runtime error.
-pub async fn run(endpoint: Option<&str>) -> anyhow::Result<()> {
+pub async fn run(endpoint: Option<&str>, index: Option<&str>) -> anyhow::Result<()> {
     let channel = if let Some(ep) = endpoint {
         info!("Connecting to laurus-server at {ep}");
         let ch = Channel::from_shared(ep.to_string())
@@ -754,7 +1010,7 @@ pub async fn run(endpoint: Option<&str>) -> anyhow::Result<()> {
         None
     };
 
-    let server = LaurusMcpServer::new(channel);
+    let server = LaurusMcpServer::new(channel, index.map(str::to_string));
     let transport = (tokio::io::stdin(), tokio::io::stdout());
     let service = server
         .serve(transport)
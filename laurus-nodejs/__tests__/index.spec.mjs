@@ -272,6 +272,21 @@ describe("Query types", () => {
     expect(q).toBeDefined();
   });
 
+  it("suggest completions by weight", async () => {
+    const schema = new Schema();
+    schema.addCompletionField("title", true, "popularity");
+    schema.addIntegerField("popularity");
+    const index = await Index.create(null, schema);
+    await index.putDocument("doc1", { title: "Rust Programming", popularity: 50 });
+    await index.putDocument("doc2", { title: "Ruby on Rails", popularity: 80 });
+    await index.putDocument("doc3", { title: "Python Cookbook", popularity: 100 });
+    await index.commit();
+
+    const suggestions = index.suggest("title", "ru", 5);
+    expect(suggestions.map((s) => s.text)).toEqual(["Ruby on Rails", "Rust Programming"]);
+    expect(suggestions[0].weight).toBe(80);
+  });
+
   it("boolean query (mustTerm / mustNotTerm)", async () => {
     const index = await createTextIndex();
     const bq = new BooleanQuery();
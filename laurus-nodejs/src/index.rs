@@ -2,14 +2,15 @@
 
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::convert::{data_value_to_json, json_to_document};
 use crate::errors::laurus_err;
 use crate::query::{JsQuery, JsTermQuery, JsVectorQuery, JsVectorQueryInner, JsVectorTextQuery};
 use crate::schema::JsSchema;
 use crate::search::{
-    JsSearchRequest, JsSearchResult, build_dsl_request, build_lexical_request,
-    build_vector_request, to_js_search_result,
+    JsSearchRequest, JsSearchResult, JsSuggestion, build_dsl_request, build_lexical_request,
+    build_vector_request, to_js_search_result, to_js_suggestion,
 };
 use laurus::{Engine, Storage, StorageConfig, StorageFactory};
 use napi::bindgen_prelude::*;
@@ -100,13 +101,20 @@ impl JsIndex {
     ///
     /// * `id` - External document identifier (string).
     /// * `doc` - An object mapping field names to values.
+    /// * `ttl` - Optional time-to-live in seconds. Requires an expiry field
+    ///   in the schema (see `Schema.setExpiryField`).
     #[napi]
-    pub async fn put_document(&self, id: String, doc: Value) -> Result<()> {
+    pub async fn put_document(&self, id: String, doc: Value, ttl: Option<u32>) -> Result<()> {
         let document = json_to_document(&doc)?;
-        self.engine
-            .put_document(&id, document)
-            .await
-            .map_err(laurus_err)
+        match ttl {
+            Some(secs) => {
+                self.engine
+                    .put_document_with_ttl(&id, document, Duration::from_secs(secs.into()))
+                    .await
+            }
+            None => self.engine.put_document(&id, document).await,
+        }
+        .map_err(laurus_err)
     }
 
     /// Append a document version without removing existing versions.
@@ -118,13 +126,20 @@ impl JsIndex {
     ///
     /// * `id` - External document identifier.
     /// * `doc` - An object mapping field names to values.
+    /// * `ttl` - Optional time-to-live in seconds. Requires an expiry field
+    ///   in the schema (see `Schema.setExpiryField`).
     #[napi]
-    pub async fn add_document(&self, id: String, doc: Value) -> Result<()> {
+    pub async fn add_document(&self, id: String, doc: Value, ttl: Option<u32>) -> Result<()> {
         let document = json_to_document(&doc)?;
-        self.engine
-            .add_document(&id, document)
-            .await
-            .map_err(laurus_err)
+        match ttl {
+            Some(secs) => {
+                self.engine
+                    .add_document_with_ttl(&id, document, Duration::from_secs(secs.into()))
+                    .await
+            }
+            None => self.engine.add_document(&id, document).await,
+        }
+        .map_err(laurus_err)
     }
 
     /// Retrieve all document versions stored under `id`.
@@ -310,6 +325,31 @@ impl JsIndex {
         Ok(results.into_iter().map(to_js_search_result).collect())
     }
 
+    /// Suggest completions of a prefix from a completion field.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Name of a completion field.
+    /// * `prefix` - The text typed so far.
+    /// * `limit` - Maximum number of suggestions (default 10).
+    ///
+    /// # Returns
+    ///
+    /// An array of `{ text, weight }` objects, highest weight first.
+    #[napi]
+    pub fn suggest(
+        &self,
+        field: String,
+        prefix: String,
+        limit: Option<u32>,
+    ) -> Result<Vec<JsSuggestion>> {
+        let suggestions = self
+            .engine
+            .suggest(&field, &prefix, limit.unwrap_or(10) as usize)
+            .map_err(laurus_err)?;
+        Ok(suggestions.into_iter().map(to_js_suggestion).collect())
+    }
+
     // ── Stats ─────────────────────────────────────────────────────────────
 
     /// Return index statistics.
@@ -1,8 +1,9 @@
 //! Node.js wrapper for the Laurus [`Schema`] type.
 
 use laurus::{
-    BooleanOption, BytesOption, DateTimeOption, DistanceMetric, EmbedderDefinition, FieldOption,
-    FlatOption, FloatOption, GeoOption, HnswOption, IntegerOption, IvfOption, Schema, TextOption,
+    BooleanOption, BytesOption, CompletionOption, DateTimeOption, DistanceMetric,
+    EmbedderDefinition, FieldOption, FlatOption, FloatOption, GeoOption, HnswOption, IntegerOption,
+    IvfOption, Schema, TextOption,
 };
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
@@ -82,6 +83,7 @@ impl JsSchema {
                 stored: stored.unwrap_or(true),
                 term_vectors: term_vectors.unwrap_or(false),
                 analyzer,
+                ..Default::default()
             }),
         );
     }
@@ -197,6 +199,44 @@ impl JsSchema {
         );
     }
 
+    /// Add a completion field for search-as-you-type suggestions.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Field name.
+    /// * `stored` - Whether the input text is retrievable (default `true`).
+    /// * `weight_field` - Integer field holding each input's weight (default none).
+    /// * `max_edits` - Edits tolerated by fuzzy suggestions; 0 disables them (default 1).
+    /// * `prefix_length` - Leading characters that must match exactly when fuzzy (default 1).
+    /// * `min_fuzzy_length` - Prefix length before fuzzy matching is tried (default 3).
+    /// * `transpositions` - Count swapped adjacent characters as one edit (default `true`).
+    #[napi]
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_completion_field(
+        &mut self,
+        name: String,
+        stored: Option<bool>,
+        weight_field: Option<String>,
+        max_edits: Option<u32>,
+        prefix_length: Option<u32>,
+        min_fuzzy_length: Option<u32>,
+        transpositions: Option<bool>,
+    ) {
+        let defaults = CompletionOption::default();
+        self.inner.fields.insert(
+            name,
+            FieldOption::Completion(CompletionOption {
+                stored: stored.unwrap_or(defaults.stored),
+                weight_field,
+                max_edits: max_edits.unwrap_or(defaults.max_edits),
+                prefix_length: prefix_length.map_or(defaults.prefix_length, |n| n as usize),
+                min_fuzzy_length: min_fuzzy_length
+                    .map_or(defaults.min_fuzzy_length, |n| n as usize),
+                transpositions: transpositions.unwrap_or(defaults.transpositions),
+            }),
+        );
+    }
+
     /// Add an HNSW approximate nearest-neighbor vector index field.
     ///
     /// # Arguments
@@ -363,6 +403,19 @@ impl JsSchema {
         self.inner.default_fields = fields;
     }
 
+    /// Designate the DateTime field that holds document expiry timestamps.
+    ///
+    /// Required for `ttl` on `putDocument` / `addDocument`. Expired
+    /// documents are hidden from search and retrieval.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Name of a stored and indexed DateTime field.
+    #[napi]
+    pub fn set_expiry_field(&mut self, field: String) {
+        self.inner.expiry_field = Some(field);
+    }
+
     /// Return the list of field names defined in this schema.
     ///
     /// # Returns
@@ -5,7 +5,7 @@ use crate::query::{
     JsPhraseQuery, JsQuery, JsTermQuery, JsVectorQuery, JsVectorQueryInner, JsVectorTextQuery,
     extract_lexical_query, query_to_lexical_search_query, vector_query_to_search_query,
 };
-use laurus::{FusionAlgorithm, LexicalSearchQuery, SearchRequestBuilder, SearchResult};
+use laurus::{FusionAlgorithm, LexicalSearchQuery, SearchRequestBuilder, SearchResult, Suggestion};
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
@@ -116,6 +116,23 @@ pub fn to_js_search_result(r: SearchResult) -> JsSearchResult {
     }
 }
 
+/// A completion returned by `Index.suggest`.
+#[napi(object)]
+pub struct JsSuggestion {
+    /// The input text as it was indexed.
+    pub text: String,
+    /// The weight of the input.
+    pub weight: f64,
+}
+
+/// Convert a [`Suggestion`] from the engine into a [`JsSuggestion`].
+pub fn to_js_suggestion(s: Suggestion) -> JsSuggestion {
+    JsSuggestion {
+        text: s.text,
+        weight: s.weight as f64,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // SearchRequest
 // ---------------------------------------------------------------------------
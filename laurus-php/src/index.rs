@@ -2,6 +2,7 @@
 
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use ext_php_rs::prelude::*;
 use ext_php_rs::types::{ZendHashTable, Zval};
@@ -87,12 +88,20 @@ impl PhpIndex {
     ///
     /// * `id` - External document identifier (string).
     /// * `doc` - An associative array mapping field names to values.
-    pub fn put_document(&self, id: String, doc: &ZendHashTable) -> PhpResult<()> {
+    /// * `ttl` - Optional time-to-live in seconds. Requires an expiry field
+    ///   in the schema (see `Schema::setExpiryField()`).
+    pub fn put_document(&self, id: String, doc: &ZendHashTable, ttl: Option<u32>) -> PhpResult<()> {
         let document = hashtable_to_document(doc)?;
         let engine = self.engine.clone();
-        self.rt
-            .block_on(engine.put_document(&id, document))
-            .map_err(laurus_err)
+        match ttl {
+            Some(secs) => self.rt.block_on(engine.put_document_with_ttl(
+                &id,
+                document,
+                Duration::from_secs(secs.into()),
+            )),
+            None => self.rt.block_on(engine.put_document(&id, document)),
+        }
+        .map_err(laurus_err)
     }
 
     /// Append a document version without removing existing versions.
@@ -104,12 +113,20 @@ impl PhpIndex {
     ///
     /// * `id` - External document identifier.
     /// * `doc` - An associative array mapping field names to values.
-    pub fn add_document(&self, id: String, doc: &ZendHashTable) -> PhpResult<()> {
+    /// * `ttl` - Optional time-to-live in seconds. Requires an expiry field
+    ///   in the schema (see `Schema::setExpiryField()`).
+    pub fn add_document(&self, id: String, doc: &ZendHashTable, ttl: Option<u32>) -> PhpResult<()> {
         let document = hashtable_to_document(doc)?;
         let engine = self.engine.clone();
-        self.rt
-            .block_on(engine.add_document(&id, document))
-            .map_err(laurus_err)
+        match ttl {
+            Some(secs) => self.rt.block_on(engine.add_document_with_ttl(
+                &id,
+                document,
+                Duration::from_secs(secs.into()),
+            )),
+            None => self.rt.block_on(engine.add_document(&id, document)),
+        }
+        .map_err(laurus_err)
     }
 
     /// Retrieve all document versions stored under `id`.
@@ -192,6 +209,45 @@ impl PhpIndex {
         Ok(results.into_iter().map(to_php_search_result).collect())
     }
 
+    /// Suggest completions of a prefix from a completion field.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Name of a completion field.
+    /// * `prefix` - The text typed so far.
+    /// * `limit` - Maximum number of suggestions (default: 10).
+    ///
+    /// # Returns
+    ///
+    /// An array of associative arrays with keys `"text"` and `"weight"`,
+    /// highest weight first.
+    #[php(defaults(limit = 10))]
+    pub fn suggest(&self, field: String, prefix: String, limit: i64) -> PhpResult<Vec<Zval>> {
+        let suggestions = self
+            .engine
+            .suggest(&field, &prefix, limit as usize)
+            .map_err(laurus_err)?;
+
+        let mut result = Vec::with_capacity(suggestions.len());
+        for s in suggestions {
+            let mut ht = ZendHashTable::new();
+            let mut text_zv = Zval::new();
+            text_zv
+                .set_string(&s.text, false)
+                .map_err(|_| "failed to set text")?;
+            ht.insert("text", text_zv)
+                .map_err(|_| "failed to insert text")?;
+            let mut weight_zv = Zval::new();
+            weight_zv.set_long(s.weight as i64);
+            ht.insert("weight", weight_zv)
+                .map_err(|_| "failed to insert weight")?;
+            let mut zv = Zval::new();
+            zv.set_hashtable(ht);
+            result.push(zv);
+        }
+        Ok(result)
+    }
+
     // ── Schema & stats ────────────────────────────────────────────────────
 
     /// Return index statistics as an associative array.
@@ -6,8 +6,9 @@ use ext_php_rs::convert::FromZval;
 use ext_php_rs::prelude::*;
 use ext_php_rs::types::ZendHashTable;
 use laurus::{
-    BooleanOption, BytesOption, DateTimeOption, DistanceMetric, EmbedderDefinition, FieldOption,
-    FloatOption, GeoOption, HnswOption, IntegerOption, IvfOption, Schema, TextOption,
+    BooleanOption, BytesOption, CompletionOption, DateTimeOption, DistanceMetric,
+    EmbedderDefinition, FieldOption, FloatOption, GeoOption, HnswOption, IntegerOption, IvfOption,
+    Schema, TextOption,
 };
 
 /// Parse a distance metric string into [`DistanceMetric`].
@@ -83,6 +84,7 @@ impl PhpSchema {
                 stored,
                 term_vectors,
                 analyzer,
+                ..Default::default()
             }),
         );
     }
@@ -176,6 +178,48 @@ impl PhpSchema {
             .insert(name, FieldOption::Bytes(BytesOption { stored }));
     }
 
+    /// Add a completion field for search-as-you-type suggestions.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Field name.
+    /// * `stored` - Whether the input text is retrievable (default: true).
+    /// * `weight_field` - Integer field holding each input's weight (default: none).
+    /// * `max_edits` - Edits tolerated by fuzzy suggestions; 0 disables them (default: 1).
+    /// * `prefix_length` - Leading characters that must match exactly when fuzzy (default: 1).
+    /// * `min_fuzzy_length` - Prefix length before fuzzy matching is tried (default: 3).
+    /// * `transpositions` - Count swapped adjacent characters as one edit (default: true).
+    #[php(defaults(
+        stored = true,
+        max_edits = 1,
+        prefix_length = 1,
+        min_fuzzy_length = 3,
+        transpositions = true
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_completion_field(
+        &self,
+        name: String,
+        stored: bool,
+        weight_field: Option<String>,
+        max_edits: i64,
+        prefix_length: i64,
+        min_fuzzy_length: i64,
+        transpositions: bool,
+    ) {
+        self.inner.borrow_mut().fields.insert(
+            name,
+            FieldOption::Completion(CompletionOption {
+                stored,
+                weight_field,
+                max_edits: max_edits as u32,
+                prefix_length: prefix_length as usize,
+                min_fuzzy_length: min_fuzzy_length as usize,
+                transpositions,
+            }),
+        );
+    }
+
     /// Add an HNSW approximate nearest-neighbor vector index field.
     ///
     /// # Arguments
@@ -331,6 +375,18 @@ impl PhpSchema {
         self.inner.borrow_mut().default_fields = field_names;
     }
 
+    /// Designate the DateTime field that holds document expiry timestamps.
+    ///
+    /// Required for `$ttl` on `putDocument()` / `addDocument()`. Expired
+    /// documents are hidden from search and retrieval.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Name of a stored and indexed DateTime field.
+    pub fn set_expiry_field(&self, field: String) {
+        self.inner.borrow_mut().expiry_field = Some(field);
+    }
+
     /// Return the list of field names defined in this schema.
     pub fn field_names(&self) -> Vec<String> {
         self.inner.borrow().fields.keys().cloned().collect()
@@ -203,6 +203,24 @@ class LaurusTest extends TestCase
         $this->assertCount(1, $results);
     }
 
+    public function testSuggest(): void
+    {
+        $schema = new Laurus\Schema();
+        $schema->addCompletionField("title", true, "popularity");
+        $schema->addIntegerField("popularity");
+        $idx = new Laurus\Index(null, $schema);
+        $idx->putDocument("d1", ["title" => "Rust Programming", "popularity" => 50]);
+        $idx->putDocument("d2", ["title" => "Ruby on Rails", "popularity" => 80]);
+        $idx->putDocument("d3", ["title" => "Python Cookbook", "popularity" => 100]);
+        $idx->commit();
+        $suggestions = $idx->suggest("title", "ru", 5);
+        $this->assertEquals(
+            ["Ruby on Rails", "Rust Programming"],
+            array_map(fn ($s) => $s["text"], $suggestions)
+        );
+        $this->assertEquals(80, $suggestions[0]["weight"]);
+    }
+
     public function testNumericRangeQuery(): void
     {
         $schema = new Laurus\Schema();
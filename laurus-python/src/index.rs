@@ -2,6 +2,7 @@
 
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::convert::{dict_to_document, document_to_dict};
 use crate::errors::laurus_err;
@@ -102,15 +103,30 @@ impl PyIndex {
     /// Args:
     ///     id: External document identifier (string).
     ///     doc: A `dict` mapping field names to values.
+    ///     ttl: Optional time-to-live in seconds. Requires an expiry field
+    ///         in the schema (see `Schema.set_expiry_field`).
     ///
     /// Call [`commit`] to make the change visible to searches.
-    pub fn put_document(&self, py: Python, id: &str, doc: &Bound<PyDict>) -> PyResult<()> {
+    #[pyo3(signature = (id, doc, ttl=None))]
+    pub fn put_document(
+        &self,
+        py: Python,
+        id: &str,
+        doc: &Bound<PyDict>,
+        ttl: Option<u64>,
+    ) -> PyResult<()> {
         let document = dict_to_document(py, doc)?;
         let engine = self.engine.clone();
         let id = id.to_string();
-        self.rt
-            .block_on(engine.put_document(&id, document))
-            .map_err(laurus_err)
+        match ttl {
+            Some(secs) => self.rt.block_on(engine.put_document_with_ttl(
+                &id,
+                document,
+                Duration::from_secs(secs),
+            )),
+            None => self.rt.block_on(engine.put_document(&id, document)),
+        }
+        .map_err(laurus_err)
     }
 
     /// Append a document version without removing existing versions.
@@ -121,13 +137,28 @@ impl PyIndex {
     /// Args:
     ///     id: External document identifier.
     ///     doc: A `dict` mapping field names to values.
-    pub fn add_document(&self, py: Python, id: &str, doc: &Bound<PyDict>) -> PyResult<()> {
+    ///     ttl: Optional time-to-live in seconds. Requires an expiry field
+    ///         in the schema (see `Schema.set_expiry_field`).
+    #[pyo3(signature = (id, doc, ttl=None))]
+    pub fn add_document(
+        &self,
+        py: Python,
+        id: &str,
+        doc: &Bound<PyDict>,
+        ttl: Option<u64>,
+    ) -> PyResult<()> {
         let document = dict_to_document(py, doc)?;
         let engine = self.engine.clone();
         let id = id.to_string();
-        self.rt
-            .block_on(engine.add_document(&id, document))
-            .map_err(laurus_err)
+        match ttl {
+            Some(secs) => self.rt.block_on(engine.add_document_with_ttl(
+                &id,
+                document,
+                Duration::from_secs(secs),
+            )),
+            None => self.rt.block_on(engine.add_document(&id, document)),
+        }
+        .map_err(laurus_err)
     }
 
     /// Retrieve all document versions stored under `id`.
@@ -199,6 +230,38 @@ impl PyIndex {
             .collect()
     }
 
+    /// Suggest completions of a prefix from a completion field.
+    ///
+    /// Args:
+    ///     field: Name of a completion field.
+    ///     prefix: The text typed so far.
+    ///     limit: Maximum number of suggestions (default 10).
+    ///
+    /// Returns:
+    ///     A list of dicts with keys `text` and `weight`, highest weight first.
+    #[pyo3(signature = (field, prefix, *, limit=10))]
+    pub fn suggest(
+        &self,
+        py: Python,
+        field: &str,
+        prefix: &str,
+        limit: usize,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let suggestions = self
+            .engine
+            .suggest(field, prefix, limit)
+            .map_err(laurus_err)?;
+        suggestions
+            .into_iter()
+            .map(|s| {
+                let dict = PyDict::new(py);
+                dict.set_item("text", s.text)?;
+                dict.set_item("weight", s.weight)?;
+                Ok(dict.into_any().unbind())
+            })
+            .collect()
+    }
+
     // ── Schema & stats ────────────────────────────────────────────────────
 
     /// Return index statistics.
@@ -1,8 +1,9 @@
 //! Python wrapper for the Laurus [`Schema`] type.
 
 use laurus::{
-    BooleanOption, BytesOption, DateTimeOption, DistanceMetric, EmbedderDefinition, FieldOption,
-    FlatOption, FloatOption, GeoOption, HnswOption, IntegerOption, IvfOption, Schema, TextOption,
+    BooleanOption, BytesOption, CompletionOption, DateTimeOption, DistanceMetric,
+    EmbedderDefinition, FieldOption, FlatOption, FloatOption, GeoOption, HnswOption, IntegerOption,
+    IvfOption, Schema, TextOption,
 };
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -73,6 +74,7 @@ impl PySchema {
                 stored,
                 term_vectors,
                 analyzer,
+                ..Default::default()
             }),
         );
     }
@@ -130,6 +132,50 @@ impl PySchema {
             .insert(name.to_string(), FieldOption::Bytes(BytesOption { stored }));
     }
 
+    /// Add a completion field for search-as-you-type suggestions.
+    ///
+    /// Args:
+    ///     name: Field name.
+    ///     stored: Whether the input text is stored (default True).
+    ///     weight_field: Integer field holding each input's weight (default None).
+    ///     max_edits: Edits tolerated by fuzzy suggestions; 0 disables them (default 1).
+    ///     prefix_length: Leading characters that must match exactly when fuzzy (default 1).
+    ///     min_fuzzy_length: Prefix length before fuzzy matching is tried (default 3).
+    ///     transpositions: Count swapped adjacent characters as one edit (default True).
+    #[pyo3(signature = (
+        name,
+        *,
+        stored=true,
+        weight_field=None,
+        max_edits=1,
+        prefix_length=1,
+        min_fuzzy_length=3,
+        transpositions=true
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_completion_field(
+        &mut self,
+        name: &str,
+        stored: bool,
+        weight_field: Option<String>,
+        max_edits: u32,
+        prefix_length: usize,
+        min_fuzzy_length: usize,
+        transpositions: bool,
+    ) {
+        self.inner.fields.insert(
+            name.to_string(),
+            FieldOption::Completion(CompletionOption {
+                stored,
+                weight_field,
+                max_edits,
+                prefix_length,
+                min_fuzzy_length,
+                transpositions,
+            }),
+        );
+    }
+
     /// Add an HNSW approximate nearest-neighbor vector index field.
     ///
     /// Args:
@@ -305,6 +351,14 @@ impl PySchema {
         self.inner.default_fields = fields;
     }
 
+    /// Designate the DateTime field that holds document expiry timestamps.
+    ///
+    /// Required for `ttl` on `put_document` / `add_document`. Expired
+    /// documents are hidden from search and retrieval.
+    pub fn set_expiry_field(&mut self, field: &str) {
+        self.inner.expiry_field = Some(field.to_string());
+    }
+
     /// Return the list of field names defined in this schema.
     pub fn field_names(&self) -> Vec<String> {
         self.inner.fields.keys().cloned().collect()
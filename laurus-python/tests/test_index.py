@@ -212,6 +212,20 @@ def test_numeric_range_query():
     assert results[0].id == "doc2"
 
 
+def test_suggest():
+    schema = laurus.Schema()
+    schema.add_completion_field("title", weight_field="popularity")
+    schema.add_integer_field("popularity")
+    idx = laurus.Index(schema=schema)
+    idx.put_document("doc1", {"title": "Rust Programming", "popularity": 50})
+    idx.put_document("doc2", {"title": "Ruby on Rails", "popularity": 80})
+    idx.put_document("doc3", {"title": "Python Cookbook", "popularity": 100})
+    idx.commit()
+    suggestions = idx.suggest("title", "ru", limit=5)
+    assert [s["text"] for s in suggestions] == ["Ruby on Rails", "Rust Programming"]
+    assert suggestions[0]["weight"] == 80
+
+
 def test_boolean_query(index):
     q = laurus.BooleanQuery()
     q.must(laurus.TermQuery("body", "programming"))
@@ -2,6 +2,7 @@
 
 use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::convert::{document_to_hash, hash_to_document};
 use crate::errors::laurus_err;
@@ -121,6 +122,43 @@ impl RbIndex {
             .map_err(laurus_err)
     }
 
+    /// Index a document that expires after `ttl_seconds`, replacing any
+    /// existing document with the same id.
+    ///
+    /// Requires an expiry field in the schema (see `Schema#set_expiry_field`).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - External document identifier.
+    /// * `doc` - A Hash mapping field names to values.
+    /// * `ttl_seconds` - Time-to-live in seconds.
+    fn put_document_with_ttl(&self, id: String, doc: RHash, ttl_seconds: u64) -> Result<(), Error> {
+        let ruby = Ruby::get().expect("called from Ruby thread");
+        let document = hash_to_document(&ruby, doc)?;
+        let engine = self.engine.clone();
+        self.rt
+            .block_on(engine.put_document_with_ttl(&id, document, Duration::from_secs(ttl_seconds)))
+            .map_err(laurus_err)
+    }
+
+    /// Append a document version that expires after `ttl_seconds`.
+    ///
+    /// Requires an expiry field in the schema (see `Schema#set_expiry_field`).
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - External document identifier.
+    /// * `doc` - A Hash mapping field names to values.
+    /// * `ttl_seconds` - Time-to-live in seconds.
+    fn add_document_with_ttl(&self, id: String, doc: RHash, ttl_seconds: u64) -> Result<(), Error> {
+        let ruby = Ruby::get().expect("called from Ruby thread");
+        let document = hash_to_document(&ruby, doc)?;
+        let engine = self.engine.clone();
+        self.rt
+            .block_on(engine.add_document_with_ttl(&id, document, Duration::from_secs(ttl_seconds)))
+            .map_err(laurus_err)
+    }
+
     /// Retrieve all document versions stored under `id`.
     ///
     /// # Arguments
@@ -214,6 +252,40 @@ impl RbIndex {
         Ok(arr)
     }
 
+    /// Suggest completions of a prefix from a completion field.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Positional and keyword arguments:
+    ///   - `field` (String): Name of a completion field.
+    ///   - `prefix` (String): The text typed so far.
+    ///   - `limit:` (Integer, default 10): Maximum number of suggestions.
+    ///
+    /// # Returns
+    ///
+    /// An Array of Hashes with keys `"text"` and `"weight"`, highest weight first.
+    fn suggest(&self, args: &[Value]) -> Result<RArray, Error> {
+        let ruby = Ruby::get().expect("called from Ruby thread");
+        let args = scan_args::<(String, String), (), (), (), RHash, ()>(args)?;
+        let (field, prefix) = args.required;
+        let kwargs = get_kwargs::<_, (), (Option<usize>,), ()>(args.keywords, &[], &["limit"])?;
+        let (limit,) = kwargs.optional;
+
+        let suggestions = self
+            .engine
+            .suggest(&field, &prefix, limit.unwrap_or(10))
+            .map_err(laurus_err)?;
+
+        let arr = ruby.ary_new_capa(suggestions.len());
+        for s in suggestions {
+            let hash = ruby.hash_new();
+            hash.aset(ruby.str_new("text"), s.text)?;
+            hash.aset(ruby.str_new("weight"), s.weight)?;
+            arr.push(hash)?;
+        }
+        Ok(arr)
+    }
+
     // ── Schema & stats ────────────────────────────────────────────────────
 
     /// Return index statistics.
@@ -287,6 +359,14 @@ pub fn define(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     class.define_singleton_method("new", magnus::function!(RbIndex::new, -1))?;
     class.define_method("put_document", magnus::method!(RbIndex::put_document, 2))?;
     class.define_method("add_document", magnus::method!(RbIndex::add_document, 2))?;
+    class.define_method(
+        "put_document_with_ttl",
+        magnus::method!(RbIndex::put_document_with_ttl, 3),
+    )?;
+    class.define_method(
+        "add_document_with_ttl",
+        magnus::method!(RbIndex::add_document_with_ttl, 3),
+    )?;
     class.define_method("get_documents", magnus::method!(RbIndex::get_documents, 1))?;
     class.define_method(
         "delete_documents",
@@ -294,6 +374,7 @@ pub fn define(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     )?;
     class.define_method("commit", magnus::method!(RbIndex::commit, 0))?;
     class.define_method("search", magnus::method!(RbIndex::search, -1))?;
+    class.define_method("suggest", magnus::method!(RbIndex::suggest, -1))?;
     class.define_method("stats", magnus::method!(RbIndex::stats, 0))?;
     class.define_method("inspect", magnus::method!(RbIndex::inspect, 0))?;
     class.define_method("to_s", magnus::method!(RbIndex::inspect, 0))?;
@@ -3,8 +3,9 @@
 use std::cell::RefCell;
 
 use laurus::{
-    BooleanOption, BytesOption, DateTimeOption, DistanceMetric, EmbedderDefinition, FieldOption,
-    FloatOption, GeoOption, HnswOption, IntegerOption, IvfOption, Schema, TextOption,
+    BooleanOption, BytesOption, CompletionOption, DateTimeOption, DistanceMetric,
+    EmbedderDefinition, FieldOption, FloatOption, GeoOption, HnswOption, IntegerOption, IvfOption,
+    Schema, TextOption,
 };
 use magnus::prelude::*;
 use magnus::scan_args::{get_kwargs, scan_args};
@@ -85,6 +86,7 @@ impl RbSchema {
                 stored,
                 term_vectors,
                 analyzer,
+                ..Default::default()
             }),
         );
         Ok(())
@@ -246,6 +248,62 @@ impl RbSchema {
         Ok(())
     }
 
+    /// Add a completion field for search-as-you-type suggestions.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - Positional and keyword arguments:
+    ///   - `name` (String): Field name.
+    ///   - `stored:` (bool, default true): Whether the input text is retrievable.
+    ///   - `weight_field:` (String, optional): Integer field holding each input's weight.
+    ///   - `max_edits:` (u32, default 1): Edits tolerated by fuzzy suggestions; 0 disables them.
+    ///   - `prefix_length:` (usize, default 1): Leading characters that must match exactly when fuzzy.
+    ///   - `min_fuzzy_length:` (usize, default 3): Prefix length before fuzzy matching is tried.
+    ///   - `transpositions:` (bool, default true): Count swapped adjacent characters as one edit.
+    fn add_completion_field(&self, args: &[Value]) -> Result<(), Error> {
+        let args = scan_args::<(String,), (), (), (), RHash, ()>(args)?;
+        let (name,) = args.required;
+        let kwargs = get_kwargs::<
+            _,
+            (),
+            (
+                Option<bool>,
+                Option<Option<String>>,
+                Option<u32>,
+                Option<usize>,
+                Option<usize>,
+                Option<bool>,
+            ),
+            (),
+        >(
+            args.keywords,
+            &[],
+            &[
+                "stored",
+                "weight_field",
+                "max_edits",
+                "prefix_length",
+                "min_fuzzy_length",
+                "transpositions",
+            ],
+        )?;
+        let (stored, weight_field, max_edits, prefix_length, min_fuzzy_length, transpositions) =
+            kwargs.optional;
+        let defaults = CompletionOption::default();
+        self.inner.borrow_mut().fields.insert(
+            name,
+            FieldOption::Completion(CompletionOption {
+                stored: stored.unwrap_or(defaults.stored),
+                weight_field: weight_field.flatten(),
+                max_edits: max_edits.unwrap_or(defaults.max_edits),
+                prefix_length: prefix_length.unwrap_or(defaults.prefix_length),
+                min_fuzzy_length: min_fuzzy_length.unwrap_or(defaults.min_fuzzy_length),
+                transpositions: transpositions.unwrap_or(defaults.transpositions),
+            }),
+        );
+        Ok(())
+    }
+
     /// Add an HNSW approximate nearest-neighbor vector index field.
     ///
     /// # Arguments
@@ -461,6 +519,18 @@ impl RbSchema {
         Ok(())
     }
 
+    /// Designate the DateTime field that holds document expiry timestamps.
+    ///
+    /// Required for `put_document_with_ttl` / `add_document_with_ttl`.
+    /// Expired documents are hidden from search and retrieval.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Name of a stored and indexed DateTime field.
+    fn set_expiry_field(&self, field: String) {
+        self.inner.borrow_mut().expiry_field = Some(field);
+    }
+
     /// Return the list of field names defined in this schema.
     fn field_names(&self) -> Vec<String> {
         self.inner.borrow().fields.keys().cloned().collect()
@@ -512,6 +582,10 @@ pub fn define(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
         "add_bytes_field",
         magnus::method!(RbSchema::add_bytes_field, -1),
     )?;
+    class.define_method(
+        "add_completion_field",
+        magnus::method!(RbSchema::add_completion_field, -1),
+    )?;
     class.define_method(
         "add_hnsw_field",
         magnus::method!(RbSchema::add_hnsw_field, -1),
@@ -529,6 +603,10 @@ pub fn define(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
         "set_default_fields",
         magnus::method!(RbSchema::set_default_fields, 1),
     )?;
+    class.define_method(
+        "set_expiry_field",
+        magnus::method!(RbSchema::set_expiry_field, 1),
+    )?;
     class.define_method("field_names", magnus::method!(RbSchema::field_names, 0))?;
     class.define_method("inspect", magnus::method!(RbSchema::inspect, 0))?;
     class.define_method("to_s", magnus::method!(RbSchema::inspect, 0))?;
@@ -217,6 +217,20 @@ class TestIndex < Minitest::Test
     assert_equal "doc2", results[0].id
   end
 
+  def test_suggest
+    schema = Laurus::Schema.new
+    schema.add_completion_field("title", weight_field: "popularity")
+    schema.add_integer_field("popularity")
+    idx = Laurus::Index.new(schema: schema)
+    idx.put_document("doc1", { "title" => "Rust Programming", "popularity" => 50 })
+    idx.put_document("doc2", { "title" => "Ruby on Rails", "popularity" => 80 })
+    idx.put_document("doc3", { "title" => "Python Cookbook", "popularity" => 100 })
+    idx.commit
+    suggestions = idx.suggest("title", "ru", limit: 5)
+    assert_equal ["Ruby on Rails", "Rust Programming"], suggestions.map { |s| s["text"] }
+    assert_equal 80, suggestions[0]["weight"]
+  end
+
   def test_boolean_query
     idx = create_index
     q = Laurus::BooleanQuery.new
@@ -5,6 +5,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         "proto/laurus/v1/document.proto",
         "proto/laurus/v1/search.proto",
         "proto/laurus/v1/health.proto",
+        "proto/laurus/v1/replication.proto",
+        "proto/laurus/v1/admin.proto",
     ];
 
     tonic_prost_build::configure()
@@ -1,7 +1,7 @@
 //! Server configuration types deserialized from a TOML file.
 //!
 //! The top-level [`Config`] struct contains sections for the gRPC/HTTP server,
-//! index storage, and logging. All sections have sensible defaults so that
+//! index storage, replication, and logging. All sections have sensible defaults so that
 //! a minimal (or even empty) TOML file produces a working configuration.
 
 use serde::Deserialize;
@@ -13,9 +13,12 @@ pub struct Config {
     /// Network settings for the gRPC server and the optional HTTP gateway.
     #[serde(default)]
     pub server: ServerConfig,
-    /// Index storage settings (e.g. data directory path).
+    /// Index storage settings (e.g. root data directory path).
     #[serde(default)]
     pub index: IndexConfig,
+    /// Replication settings (leader or read-only follower).
+    #[serde(default)]
+    pub replication: ReplicationConfig,
 }
 
 /// Server network configuration.
@@ -45,16 +48,71 @@ impl Default for ServerConfig {
 /// Index storage settings.
 #[derive(Debug, Deserialize)]
 pub struct IndexConfig {
-    /// Filesystem path where the index data (schema and store) is persisted.
-    /// Defaults to `"./laurus_data"`.
+    /// Root directory of the hosted indexes. Each index is persisted in a
+    /// subdirectory named after it. Defaults to `"./laurus_data"`.
     #[serde(default = "default_data_dir")]
     pub data_dir: PathBuf,
+    /// Close indexes that have not been used for this many seconds. They
+    /// are reopened by the next request addressing them. `0` keeps opened
+    /// indexes loaded. Defaults to `600`.
+    #[serde(default = "default_idle_unload_secs")]
+    pub idle_unload_secs: u64,
+    /// Interval in seconds at which open indexes with an expiry field are
+    /// purged of expired documents. `0` disables purging; expired
+    /// documents stay hidden from search either way. Followers never purge,
+    /// since they replicate the leader's deletions. Defaults to `60`.
+    #[serde(default = "default_expiry_interval_secs")]
+    pub expiry_interval_secs: u64,
 }
 
 impl Default for IndexConfig {
     fn default() -> Self {
         Self {
             data_dir: default_data_dir(),
+            idle_unload_secs: default_idle_unload_secs(),
+            expiry_interval_secs: default_expiry_interval_secs(),
+        }
+    }
+}
+
+/// Role of a server in a replication setup.
+#[derive(Debug, Deserialize, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReplicationRole {
+    /// Accepts writes and serves its change feed to followers.
+    #[default]
+    Leader,
+    /// Replays the leader's changes and rejects writes.
+    Follower,
+}
+
+/// Replication settings.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ReplicationConfig {
+    /// Role of this server. Defaults to `leader`.
+    #[serde(default)]
+    pub role: ReplicationRole,
+    /// gRPC address of the leader (e.g. `"http://10.0.0.1:50051"`).
+    /// Required when `role` is `follower`.
+    #[serde(default)]
+    pub leader: Option<String>,
+    /// Interval in milliseconds at which a follower commits replicated
+    /// changes, making them visible to search. Defaults to `1000`.
+    #[serde(default = "default_commit_interval_ms")]
+    pub commit_interval_ms: u64,
+    /// Interval in seconds at which a follower polls the leader's position
+    /// to report its lag. Defaults to `5`.
+    #[serde(default = "default_status_interval_secs")]
+    pub status_interval_secs: u64,
+}
+
+impl Default for ReplicationConfig {
+    fn default() -> Self {
+        Self {
+            role: ReplicationRole::default(),
+            leader: None,
+            commit_interval_ms: default_commit_interval_ms(),
+            status_interval_secs: default_status_interval_secs(),
         }
     }
 }
@@ -71,6 +129,22 @@ fn default_data_dir() -> PathBuf {
     PathBuf::from("./laurus_data")
 }
 
+fn default_idle_unload_secs() -> u64 {
+    600
+}
+
+fn default_expiry_interval_secs() -> u64 {
+    60
+}
+
+fn default_commit_interval_ms() -> u64 {
+    1000
+}
+
+fn default_status_interval_secs() -> u64 {
+    5
+}
+
 impl Config {
     /// Load configuration from a TOML file.
     ///
@@ -1,15 +1,27 @@
 //! Index lifecycle helpers for creating, opening, and inspecting indices on disk.
 //!
-//! Each index is stored under a *data directory* that contains:
+//! The server hosts its indexes in subdirectories of the configured root
+//! directory (see [`IndexRegistry`](crate::registry::IndexRegistry)). Each
+//! index is stored under such a *data directory* that contains:
 //!
 //! * `schema.toml` – the serialized [`Schema`] definition.
 //! * `store/`      – the underlying storage directory managed by [`Engine`].
+//! * `store.next/` – the storage of an index being rebuilt by `UpdateField`,
+//!   restored by `RestoreSnapshot` or received from a replication leader,
+//!   present only while that runs.
+//! * `store.old/`, `schema.toml.old` – the previous storage and schema while
+//!   the rebuilt index is installed, put back if it fails to open.
+//! * `store.snapshot/` – on a replication leader, the copy of the index
+//!   last served to a follower, updated incrementally by the next one.
 
 use std::path::Path;
+use std::sync::Arc;
 
 use anyhow::{Context, bail};
 use laurus::storage::file::FileStorageConfig;
-use laurus::{Engine, Schema, StorageConfig, StorageFactory};
+use laurus::{
+    Engine, Schema, SnapshotManifest, Storage, StorageConfig, StorageFactory, restore_snapshot,
+};
 
 /// Filename used to persist the index schema inside the data directory.
 const SCHEMA_FILE: &str = "schema.toml";
@@ -17,6 +29,34 @@ const SCHEMA_FILE: &str = "schema.toml";
 /// Subdirectory name for the underlying storage inside the data directory.
 const STORE_DIR: &str = "store";
 
+/// Storage file in which the engine checkpoints field backfills.
+const BACKFILL_FILE: &str = "backfill.json";
+
+/// Subdirectory name for the storage of an index being rebuilt.
+const STAGING_STORE_DIR: &str = "store.next";
+
+/// Subdirectory name for the previous storage while a rebuilt index is
+/// being installed.
+const OLD_STORE_DIR: &str = "store.old";
+
+/// Filename of the previous schema while a rebuilt index is being
+/// installed.
+const OLD_SCHEMA_FILE: &str = "schema.toml.old";
+
+/// Subdirectory name for the snapshot served to replication followers.
+const SNAPSHOT_STORE_DIR: &str = "store.snapshot";
+
+/// Returns `true` if an index has been created in `data_dir`.
+pub fn index_exists(data_dir: &Path) -> bool {
+    data_dir.join(SCHEMA_FILE).is_file()
+}
+
+/// Returns `true` if fields of the index in `data_dir` have been
+/// backfilled. Opening the index resumes backfills that did not complete.
+pub fn has_backfills(data_dir: &Path) -> bool {
+    data_dir.join(STORE_DIR).join(BACKFILL_FILE).is_file()
+}
+
 /// Create a new index at the given data directory with the provided schema.
 ///
 /// The function persists the schema as `schema.toml`, initialises file-based
@@ -101,6 +141,214 @@ pub async fn open_index(data_dir: &Path) -> anyhow::Result<Engine> {
     Ok(engine)
 }
 
+/// Create empty storage for rebuilding the index at the given data directory.
+///
+/// Any leftovers of an interrupted rebuild are removed first.
+///
+/// # Arguments
+///
+/// * `data_dir` - Root directory of the index being rebuilt.
+///
+/// # Errors
+///
+/// Returns an error if the staging directory cannot be removed or created.
+pub fn create_staging_storage(data_dir: &Path) -> anyhow::Result<Arc<dyn Storage>> {
+    let staging_path = data_dir.join(STAGING_STORE_DIR);
+    if staging_path.exists() {
+        std::fs::remove_dir_all(&staging_path).with_context(|| {
+            format!(
+                "Failed to remove staging directory: {}",
+                staging_path.display()
+            )
+        })?;
+    }
+    let storage_config = StorageConfig::File(FileStorageConfig::new(&staging_path));
+    Ok(StorageFactory::create(storage_config)?)
+}
+
+/// Replace the open index at the given data directory with the rebuilt one.
+///
+/// Closes `engine`, moves the storage created by [`create_staging_storage`]
+/// to `store/`, persists `schema` and opens the rebuilt index into
+/// `engine`. The previous storage and schema are kept as `store.old/` and
+/// `schema.toml.old` until the rebuilt index has opened. If any step
+/// fails, they are put back and the previous index is reopened, so a
+/// failed install leaves the index as it was. If there was no index yet,
+/// a failed install leaves none either.
+///
+/// # Arguments
+///
+/// * `engine` - The engine open on `data_dir`, replaced in place.
+/// * `data_dir` - Root directory of the index.
+/// * `schema` - The schema of the rebuilt index.
+///
+/// # Errors
+///
+/// Returns the error that made the install fail.
+pub async fn install_staged_index(
+    engine: &mut Option<Engine>,
+    data_dir: &Path,
+    schema: &Schema,
+) -> anyhow::Result<()> {
+    // Close the current engine before its files are replaced.
+    *engine = None;
+    let existed = index_exists(data_dir);
+    match swap_staged_index(data_dir, schema).await {
+        Ok(rebuilt) => {
+            *engine = Some(rebuilt);
+            Ok(())
+        }
+        Err(e) => {
+            if let Err(restore_err) = restore_previous_index(data_dir, existed) {
+                tracing::error!(
+                    "Failed to restore the previous index at {}: {restore_err:#}",
+                    data_dir.display()
+                );
+            }
+            if existed {
+                match open_index(data_dir).await {
+                    Ok(previous) => *engine = Some(previous),
+                    Err(open_err) => tracing::error!(
+                        "Failed to reopen the previous index at {}: {open_err:#}",
+                        data_dir.display()
+                    ),
+                }
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Move the staged storage and `schema` in place and open the result,
+/// keeping the previous storage and schema as backups.
+async fn swap_staged_index(data_dir: &Path, schema: &Schema) -> anyhow::Result<Engine> {
+    let store_path = data_dir.join(STORE_DIR);
+    let old_store_path = data_dir.join(OLD_STORE_DIR);
+    let schema_path = data_dir.join(SCHEMA_FILE);
+    let old_schema_path = data_dir.join(OLD_SCHEMA_FILE);
+
+    let schema_toml =
+        toml::to_string_pretty(schema).context("Failed to serialize schema to TOML")?;
+    let staged_schema = data_dir.join(format!("{SCHEMA_FILE}.next"));
+    std::fs::write(&staged_schema, &schema_toml).context("Failed to write schema file")?;
+
+    if old_store_path.exists() {
+        std::fs::remove_dir_all(&old_store_path)
+            .context("Failed to remove previous storage directory")?;
+    }
+    if schema_path.exists() {
+        std::fs::copy(&schema_path, &old_schema_path)
+            .context("Failed to back up current schema file")?;
+    }
+    if store_path.exists() {
+        std::fs::rename(&store_path, &old_store_path)
+            .context("Failed to move current storage directory")?;
+    }
+    std::fs::rename(data_dir.join(STAGING_STORE_DIR), &store_path)
+        .context("Failed to move rebuilt storage directory")?;
+    std::fs::rename(&staged_schema, &schema_path).context("Failed to replace schema file")?;
+    let engine = open_index(data_dir).await?;
+
+    if let Err(e) = std::fs::remove_dir_all(&old_store_path) {
+        tracing::warn!("Failed to remove previous storage directory: {e}");
+    }
+    if let Err(e) = std::fs::remove_file(&old_schema_path) {
+        tracing::warn!("Failed to remove previous schema file: {e}");
+    }
+    Ok(engine)
+}
+
+/// Put back the storage and schema backed up by [`swap_staged_index`],
+/// discarding what was installed in their place. Without a previous index
+/// (`existed` is `false`), the installed storage and schema are removed.
+fn restore_previous_index(data_dir: &Path, existed: bool) -> anyhow::Result<()> {
+    let store_path = data_dir.join(STORE_DIR);
+    if !existed {
+        let schema_path = data_dir.join(SCHEMA_FILE);
+        if schema_path.exists() {
+            std::fs::remove_file(&schema_path).context("Failed to remove schema file")?;
+        }
+        if store_path.exists() {
+            std::fs::remove_dir_all(&store_path).context("Failed to remove storage directory")?;
+        }
+        return Ok(());
+    }
+    let old_store_path = data_dir.join(OLD_STORE_DIR);
+    if old_store_path.exists() {
+        if store_path.exists() {
+            std::fs::remove_dir_all(&store_path)
+                .context("Failed to remove rebuilt storage directory")?;
+        }
+        std::fs::rename(&old_store_path, &store_path)
+            .context("Failed to restore previous storage directory")?;
+    }
+    let old_schema_path = data_dir.join(OLD_SCHEMA_FILE);
+    if old_schema_path.exists() {
+        std::fs::rename(&old_schema_path, data_dir.join(SCHEMA_FILE))
+            .context("Failed to restore previous schema file")?;
+    }
+    Ok(())
+}
+
+/// Open the file storage of a backup directory, creating it if needed.
+///
+/// # Arguments
+///
+/// * `path` - Directory holding (or receiving) a snapshot.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created.
+pub fn backup_storage(path: &Path) -> anyhow::Result<Arc<dyn Storage>> {
+    let storage_config = StorageConfig::File(FileStorageConfig::new(path));
+    StorageFactory::open(storage_config)
+        .with_context(|| format!("Failed to open backup directory: {}", path.display()))
+}
+
+/// Open the storage in which snapshots served to replication followers are
+/// staged, creating it if needed.
+///
+/// The storage keeps the last snapshot, so that the next one only copies
+/// the files that changed since.
+///
+/// # Arguments
+///
+/// * `data_dir` - Root directory of the index.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created.
+pub fn snapshot_storage(data_dir: &Path) -> anyhow::Result<Arc<dyn Storage>> {
+    backup_storage(&data_dir.join(SNAPSHOT_STORE_DIR))
+}
+
+/// Restore a snapshot from a backup directory into staging storage.
+///
+/// The snapshot files are copied to the storage returned by
+/// [`create_staging_storage`] and verified against their checksums; swap
+/// them in with [`install_staged_index`] using the returned manifest's
+/// schema. The current index is not touched.
+///
+/// # Arguments
+///
+/// * `data_dir` - Root directory of the index to restore.
+/// * `backup_dir` - Directory holding a snapshot.
+///
+/// # Errors
+///
+/// Returns an error if `backup_dir` holds no snapshot, if a file does not
+/// match its checksum, or if the staging storage cannot be written.
+pub fn stage_snapshot(data_dir: &Path, backup_dir: &Path) -> anyhow::Result<SnapshotManifest> {
+    if !backup_dir.is_dir() {
+        bail!("Backup directory not found: {}", backup_dir.display());
+    }
+    let backup = backup_storage(backup_dir)?;
+    let staging = create_staging_storage(data_dir)?;
+    let manifest = restore_snapshot(backup.as_ref(), staging.as_ref())
+        .with_context(|| format!("Failed to restore snapshot from {}", backup_dir.display()))?;
+    Ok(manifest)
+}
+
 /// Persist the current schema back to the data directory.
 ///
 /// Serializes the given schema as TOML and writes it to `schema.toml`
@@ -5,7 +5,7 @@
 
 use std::collections::HashMap;
 
-use laurus::{DataValue, Document};
+use laurus::{ChangeEvent, ChangeKind, DataValue, Document};
 
 use crate::proto::laurus::v1;
 
@@ -33,6 +33,44 @@ pub fn from_proto(proto: &v1::Document) -> Document {
     Document { fields }
 }
 
+/// Convert a laurus ChangeEvent into a proto ChangeEvent.
+pub fn change_event_to_proto(event: &ChangeEvent) -> v1::ChangeEvent {
+    let (kind, document) = match &event.kind {
+        ChangeKind::Upsert { document } => (v1::ChangeKind::Upsert, Some(to_proto(document))),
+        ChangeKind::Delete => (v1::ChangeKind::Delete, None),
+    };
+    v1::ChangeEvent {
+        seq: event.seq,
+        id: event.external_id.clone(),
+        doc_id: event.doc_id,
+        kind: kind.into(),
+        document,
+    }
+}
+
+/// Convert a proto ChangeEvent into a laurus ChangeEvent.
+///
+/// The `_id` system field stripped by [`to_proto`] is restored from the
+/// event ID so that the document can be replayed as-is.
+pub fn change_event_from_proto(proto: &v1::ChangeEvent) -> ChangeEvent {
+    let kind = match proto.kind() {
+        v1::ChangeKind::Upsert => {
+            let mut document = proto.document.as_ref().map(from_proto).unwrap_or_default();
+            document
+                .fields
+                .insert("_id".to_string(), DataValue::Text(proto.id.clone()));
+            ChangeKind::Upsert { document }
+        }
+        v1::ChangeKind::Delete => ChangeKind::Delete,
+    };
+    ChangeEvent {
+        seq: proto.seq,
+        doc_id: proto.doc_id,
+        external_id: proto.id.clone(),
+        kind,
+    }
+}
+
 fn data_value_to_proto(val: &DataValue) -> v1::Value {
     use v1::value::Kind;
     let kind = match val {
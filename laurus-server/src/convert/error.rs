@@ -1,8 +1,8 @@
 //! Error-to-[`tonic::Status`] conversion utilities.
 //!
 //! Maps [`LaurusError`] variants to appropriate gRPC status codes (e.g.
-//! `INVALID_ARGUMENT`, `UNIMPLEMENTED`, `INTERNAL`) and provides a catch-all
-//! converter for [`anyhow::Error`].
+//! `INVALID_ARGUMENT`, `RESOURCE_EXHAUSTED`, `UNIMPLEMENTED`, `INTERNAL`)
+//! and provides a catch-all converter for [`anyhow::Error`].
 
 use laurus::LaurusError;
 use tonic::Status;
@@ -16,6 +16,7 @@ pub fn to_status(err: LaurusError) -> Status {
         LaurusError::SerializationError(_) | LaurusError::Json(_) => {
             Status::invalid_argument(err.to_string())
         }
+        LaurusError::ResourceExhausted(_) => Status::resource_exhausted(err.to_string()),
         LaurusError::NotImplemented(_) => Status::unimplemented(err.to_string()),
         _ => Status::internal(err.to_string()),
     }
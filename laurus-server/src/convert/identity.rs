@@ -0,0 +1,30 @@
+//! Derivation of the caller [`Identity`] from request metadata.
+//!
+//! The server does not authenticate callers itself. It trusts the
+//! [`PRINCIPALS_METADATA_KEY`] entry, which is expected to be set by an
+//! authenticating proxy in front of it (or by the HTTP gateway from the
+//! header of the same name).
+
+use laurus::Identity;
+use tonic::metadata::MetadataMap;
+
+/// Metadata key (and HTTP header) listing the caller's user and group
+/// names, separated by commas.
+pub const PRINCIPALS_METADATA_KEY: &str = "x-laurus-principals";
+
+/// Derive the identity of the caller from request metadata.
+///
+/// A request without principals gets an empty identity, which sees no
+/// document of an index with an ACL field. Indexes without an ACL field
+/// ignore the identity.
+pub fn from_metadata(metadata: &MetadataMap) -> Identity {
+    Identity::new(
+        metadata
+            .get_all(PRINCIPALS_METADATA_KEY)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .map(str::to_string),
+    )
+}
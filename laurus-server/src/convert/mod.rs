@@ -4,10 +4,16 @@
 //!
 //! * [`document`] – [`laurus::Document`] <-> `proto::Document`.
 //! * [`error`]    – [`laurus::LaurusError`] / [`anyhow::Error`] -> [`tonic::Status`].
+//! * [`identity`] – request metadata -> [`laurus::Identity`].
+//! * [`resource`] – [`laurus::ResourceSet`] <-> `proto::ResourceSet`.
 //! * [`schema`]   – [`laurus::Schema`] <-> `proto::Schema`.
 //! * [`search`]   – [`laurus::SearchRequest`] / [`laurus::SearchResult`] <-> proto types.
+//! * [`tenant`]   – request metadata -> [`laurus::Tenant`] scope.
 
 pub mod document;
 pub mod error;
+pub mod identity;
+pub mod resource;
 pub mod schema;
 pub mod search;
+pub mod tenant;
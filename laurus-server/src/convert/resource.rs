@@ -0,0 +1,43 @@
+//! Conversion between [`laurus::ResourceSet`] and the protobuf `ResourceSet`
+//! message.
+
+use laurus::{ResourceContent, ResourceSet};
+
+use crate::proto::laurus::v1::{self, resource_set::Content};
+
+/// Convert a laurus ResourceSet into a proto ResourceSet.
+pub fn to_proto(set: &ResourceSet) -> v1::ResourceSet {
+    let content = match &set.content {
+        ResourceContent::Synonyms { rules } => Content::Synonyms(v1::SynonymRules {
+            rules: rules.clone(),
+        }),
+        ResourceContent::StopWords { words } => Content::StopWords(v1::StopWords {
+            words: words.clone(),
+        }),
+        ResourceContent::Mapping { mapping } => Content::Mapping(v1::CharMapping {
+            mapping: mapping.clone(),
+        }),
+    };
+    v1::ResourceSet {
+        name: set.name.clone(),
+        version: set.version,
+        content: Some(content),
+    }
+}
+
+/// Extract the content of a proto ResourceSet.
+///
+/// Returns `None` if no content is set.
+pub fn content_from_proto(proto: &v1::ResourceSet) -> Option<ResourceContent> {
+    Some(match proto.content.as_ref()? {
+        Content::Synonyms(synonyms) => ResourceContent::Synonyms {
+            rules: synonyms.rules.clone(),
+        },
+        Content::StopWords(stop_words) => ResourceContent::StopWords {
+            words: stop_words.words.clone(),
+        },
+        Content::Mapping(mapping) => ResourceContent::Mapping {
+            mapping: mapping.mapping.clone(),
+        },
+    })
+}
@@ -1,15 +1,15 @@
 //! Conversion between [`laurus::Schema`] and the protobuf `Schema` message.
 //!
 //! Handles mapping of all field option variants (text, integer, float, boolean,
-//! datetime, geo, bytes, HNSW, flat, IVF), distance metrics, and quantization
+//! datetime, geo, bytes, completion, HNSW, flat, IVF), distance metrics, and quantization
 //! configuration.
 
 use std::collections::HashMap;
 
 use laurus::{
-    AnalyzerDefinition, BooleanOption, BytesOption, CharFilterConfig, DateTimeOption,
-    DistanceMetric, EmbedderDefinition, FieldOption, FlatOption, FloatOption, GeoOption,
-    HnswOption, IntegerOption, IvfOption, QuantizationMethod, Schema, TextOption,
+    AnalyzerDefinition, BooleanOption, BytesOption, CharFilterConfig, CompletionOption,
+    DateTimeOption, DistanceMetric, EmbedderDefinition, FieldOption, FlatOption, FloatOption,
+    GeoOption, HnswOption, IntegerOption, IvfOption, QuantizationMethod, Schema, TextOption,
     TokenFilterConfig, TokenizerConfig,
 };
 
@@ -37,6 +37,9 @@ pub fn to_proto(schema: &Schema) -> v1::Schema {
         default_fields: schema.default_fields.clone(),
         analyzers,
         embedders,
+        expiry_field: schema.expiry_field.clone().unwrap_or_default(),
+        acl_field: schema.acl_field.clone().unwrap_or_default(),
+        tenant_field: schema.tenant_field.clone().unwrap_or_default(),
     }
 }
 
@@ -61,6 +64,21 @@ pub fn from_proto(proto: &v1::Schema) -> Result<Schema, String> {
         embedders,
         fields,
         default_fields: proto.default_fields.clone(),
+        expiry_field: if proto.expiry_field.is_empty() {
+            None
+        } else {
+            Some(proto.expiry_field.clone())
+        },
+        acl_field: if proto.acl_field.is_empty() {
+            None
+        } else {
+            Some(proto.acl_field.clone())
+        },
+        tenant_field: if proto.tenant_field.is_empty() {
+            None
+        } else {
+            Some(proto.tenant_field.clone())
+        },
     })
 }
 
@@ -77,6 +95,8 @@ pub fn field_option_to_proto(fo: &FieldOption) -> v1::FieldOption {
             stored: o.stored,
             term_vectors: o.term_vectors,
             analyzer: o.analyzer.clone().unwrap_or_default(),
+            search_analyzer: o.search_analyzer.clone().unwrap_or_default(),
+            search_quote_analyzer: o.search_quote_analyzer.clone().unwrap_or_default(),
         })),
         FieldOption::Integer(o) => Some(Opt::Integer(v1::IntegerOption {
             indexed: o.indexed,
@@ -99,6 +119,14 @@ pub fn field_option_to_proto(fo: &FieldOption) -> v1::FieldOption {
             stored: o.stored,
         })),
         FieldOption::Bytes(o) => Some(Opt::Bytes(v1::BytesOption { stored: o.stored })),
+        FieldOption::Completion(o) => Some(Opt::Completion(v1::CompletionOption {
+            stored: o.stored,
+            weight_field: o.weight_field.clone().unwrap_or_default(),
+            max_edits: o.max_edits,
+            prefix_length: o.prefix_length as u32,
+            min_fuzzy_length: o.min_fuzzy_length as u32,
+            transpositions: o.transpositions,
+        })),
         FieldOption::Hnsw(o) => Some(Opt::Hnsw(v1::HnswOption {
             dimension: o.dimension as u32,
             distance: distance_to_proto(&o.distance) as i32,
@@ -147,6 +175,16 @@ pub fn field_option_from_proto(fo: &v1::FieldOption) -> Option<FieldOption> {
             } else {
                 Some(o.analyzer.clone())
             },
+            search_analyzer: if o.search_analyzer.is_empty() {
+                None
+            } else {
+                Some(o.search_analyzer.clone())
+            },
+            search_quote_analyzer: if o.search_quote_analyzer.is_empty() {
+                None
+            } else {
+                Some(o.search_quote_analyzer.clone())
+            },
         })),
         Some(Opt::Integer(o)) => Some(FieldOption::Integer(IntegerOption {
             indexed: o.indexed,
@@ -169,6 +207,18 @@ pub fn field_option_from_proto(fo: &v1::FieldOption) -> Option<FieldOption> {
             stored: o.stored,
         })),
         Some(Opt::Bytes(o)) => Some(FieldOption::Bytes(BytesOption { stored: o.stored })),
+        Some(Opt::Completion(o)) => Some(FieldOption::Completion(CompletionOption {
+            stored: o.stored,
+            weight_field: if o.weight_field.is_empty() {
+                None
+            } else {
+                Some(o.weight_field.clone())
+            },
+            max_edits: o.max_edits,
+            prefix_length: o.prefix_length as usize,
+            min_fuzzy_length: o.min_fuzzy_length as usize,
+            transpositions: o.transpositions,
+        })),
         Some(Opt::Hnsw(o)) => Some(FieldOption::Hnsw(HnswOption {
             dimension: o.dimension as usize,
             distance: distance_from_proto(o.distance),
@@ -319,6 +369,12 @@ fn tokenizer_to_proto(config: &TokenizerConfig) -> v1::ComponentConfig {
             p.insert("max_gram".into(), max_gram.to_string());
             ("ngram", p)
         }
+        TokenizerConfig::EdgeNgram { min_gram, max_gram } => {
+            let mut p = HashMap::new();
+            p.insert("min_gram".into(), min_gram.to_string());
+            p.insert("max_gram".into(), max_gram.to_string());
+            ("edge_ngram", p)
+        }
         TokenizerConfig::Lindera {
             mode,
             dict,
@@ -352,21 +408,14 @@ fn tokenizer_from_proto(proto: &v1::ComponentConfig) -> Result<TokenizerConfig,
                 .unwrap_or_else(|| r"\w+".into()),
             gaps: proto.params.get("gaps").is_some_and(|v| v == "true"),
         }),
-        "ngram" => {
-            let min_gram = proto
-                .params
-                .get("min_gram")
-                .ok_or("ngram: missing min_gram")?
-                .parse::<usize>()
-                .map_err(|e| format!("ngram: invalid min_gram: {e}"))?;
-            let max_gram = proto
-                .params
-                .get("max_gram")
-                .ok_or("ngram: missing max_gram")?
-                .parse::<usize>()
-                .map_err(|e| format!("ngram: invalid max_gram: {e}"))?;
-            Ok(TokenizerConfig::Ngram { min_gram, max_gram })
-        }
+        "ngram" => Ok(TokenizerConfig::Ngram {
+            min_gram: usize_param(proto, "min_gram")?,
+            max_gram: usize_param(proto, "max_gram")?,
+        }),
+        "edge_ngram" => Ok(TokenizerConfig::EdgeNgram {
+            min_gram: usize_param(proto, "min_gram")?,
+            max_gram: usize_param(proto, "max_gram")?,
+        }),
         "lindera" => Ok(TokenizerConfig::Lindera {
             mode: proto
                 .params
@@ -385,6 +434,17 @@ fn tokenizer_from_proto(proto: &v1::ComponentConfig) -> Result<TokenizerConfig,
     }
 }
 
+/// Parse the required `usize` parameter `key` of a component.
+fn usize_param(proto: &v1::ComponentConfig, key: &str) -> Result<usize, String> {
+    let component = &proto.r#type;
+    proto
+        .params
+        .get(key)
+        .ok_or_else(|| format!("{component}: missing {key}"))?
+        .parse::<usize>()
+        .map_err(|e| format!("{component}: invalid {key}: {e}"))
+}
+
 fn char_filter_to_proto(config: &CharFilterConfig) -> v1::ComponentConfig {
     let (type_name, params) = match config {
         CharFilterConfig::UnicodeNormalization { form } => {
@@ -401,9 +461,13 @@ fn char_filter_to_proto(config: &CharFilterConfig) -> v1::ComponentConfig {
             p.insert("replacement".into(), replacement.clone());
             ("pattern_replace", p)
         }
-        CharFilterConfig::Mapping { mapping } => {
-            // Encode mapping as key=value pairs in params.
-            let p: HashMap<String, String> = mapping.clone();
+        CharFilterConfig::Mapping { mapping, resource } => {
+            // Encode mapping as key=value pairs in params, or a resource set
+            // reference as the single `resource` param.
+            let p: HashMap<String, String> = match resource {
+                Some(resource) => HashMap::from([("resource".into(), resource.clone())]),
+                None => mapping.clone(),
+            };
             ("mapping", p)
         }
         CharFilterConfig::JapaneseIterationMark { kanji, kana } => {
@@ -436,9 +500,16 @@ fn char_filter_from_proto(proto: &v1::ComponentConfig) -> Result<CharFilterConfi
                 .ok_or("pattern_replace: missing pattern")?,
             replacement: proto.params.get("replacement").cloned().unwrap_or_default(),
         }),
-        "mapping" => Ok(CharFilterConfig::Mapping {
-            mapping: proto.params.clone(),
-        }),
+        "mapping" => match proto.params.get("resource") {
+            Some(resource) if proto.params.len() == 1 => Ok(CharFilterConfig::Mapping {
+                mapping: HashMap::new(),
+                resource: Some(resource.clone()),
+            }),
+            _ => Ok(CharFilterConfig::Mapping {
+                mapping: proto.params.clone(),
+                resource: None,
+            }),
+        },
         "japanese_iteration_mark" => Ok(CharFilterConfig::JapaneseIterationMark {
             kanji: proto.params.get("kanji").is_none_or(|v| v != "false"),
             kana: proto.params.get("kana").is_none_or(|v| v != "false"),
@@ -450,11 +521,29 @@ fn char_filter_from_proto(proto: &v1::ComponentConfig) -> Result<CharFilterConfi
 fn token_filter_to_proto(config: &TokenFilterConfig) -> v1::ComponentConfig {
     let (type_name, params) = match config {
         TokenFilterConfig::Lowercase => ("lowercase", HashMap::new()),
-        TokenFilterConfig::Stop { words } => {
+        TokenFilterConfig::CaseFold => ("case_fold", HashMap::new()),
+        TokenFilterConfig::AsciiFolding { preserve_original } => {
+            let mut p = HashMap::new();
+            if *preserve_original {
+                p.insert("preserve_original".into(), "true".into());
+            }
+            ("ascii_folding", p)
+        }
+        TokenFilterConfig::Stop {
+            words,
+            resource,
+            language,
+        } => {
             let mut p = HashMap::new();
             if let Some(word_list) = words {
                 p.insert("words".into(), word_list.join(","));
             }
+            if let Some(resource) = resource {
+                p.insert("resource".into(), resource.clone());
+            }
+            if let Some(language) = language {
+                p.insert("language".into(), language.clone());
+            }
             ("stop", p)
         }
         TokenFilterConfig::Stem { stem_type } => {
@@ -477,6 +566,111 @@ fn token_filter_to_proto(config: &TokenFilterConfig) -> v1::ComponentConfig {
         TokenFilterConfig::Strip => ("strip", HashMap::new()),
         TokenFilterConfig::RemoveEmpty => ("remove_empty", HashMap::new()),
         TokenFilterConfig::FlattenGraph => ("flatten_graph", HashMap::new()),
+        TokenFilterConfig::EdgeNgram {
+            min_gram,
+            max_gram,
+            preserve_original,
+        } => {
+            let mut p = HashMap::new();
+            p.insert("min_gram".into(), min_gram.to_string());
+            p.insert("max_gram".into(), max_gram.to_string());
+            if *preserve_original {
+                p.insert("preserve_original".into(), "true".into());
+            }
+            ("edge_ngram", p)
+        }
+        TokenFilterConfig::Shingle {
+            min_shingle_size,
+            max_shingle_size,
+            token_separator,
+            output_unigrams,
+        } => {
+            let mut p = HashMap::new();
+            p.insert("min_shingle_size".into(), min_shingle_size.to_string());
+            p.insert("max_shingle_size".into(), max_shingle_size.to_string());
+            p.insert("token_separator".into(), token_separator.clone());
+            p.insert("output_unigrams".into(), output_unigrams.to_string());
+            ("shingle", p)
+        }
+        TokenFilterConfig::Phonetic { encoder, inject } => {
+            let mut p = HashMap::new();
+            p.insert("encoder".into(), encoder.clone());
+            p.insert("inject".into(), inject.to_string());
+            ("phonetic", p)
+        }
+        TokenFilterConfig::WordDelimiterGraph {
+            generate_word_parts,
+            generate_number_parts,
+            split_on_case_change,
+            split_on_numerics,
+            catenate_words,
+            catenate_numbers,
+            catenate_all,
+            preserve_original,
+        } => {
+            let mut p = HashMap::new();
+            p.insert(
+                "generate_word_parts".into(),
+                generate_word_parts.to_string(),
+            );
+            p.insert(
+                "generate_number_parts".into(),
+                generate_number_parts.to_string(),
+            );
+            p.insert(
+                "split_on_case_change".into(),
+                split_on_case_change.to_string(),
+            );
+            p.insert("split_on_numerics".into(), split_on_numerics.to_string());
+            p.insert("catenate_words".into(), catenate_words.to_string());
+            p.insert("catenate_numbers".into(), catenate_numbers.to_string());
+            p.insert("catenate_all".into(), catenate_all.to_string());
+            p.insert("preserve_original".into(), preserve_original.to_string());
+            ("word_delimiter_graph", p)
+        }
+        TokenFilterConfig::DictionaryDecompounder {
+            words,
+            min_word_size,
+            min_subword_size,
+            max_subword_size,
+            only_longest_match,
+        } => {
+            let mut p = HashMap::new();
+            p.insert("words".into(), words.join(","));
+            p.insert("min_word_size".into(), min_word_size.to_string());
+            p.insert("min_subword_size".into(), min_subword_size.to_string());
+            p.insert("max_subword_size".into(), max_subword_size.to_string());
+            p.insert("only_longest_match".into(), only_longest_match.to_string());
+            ("dictionary_decompounder", p)
+        }
+        TokenFilterConfig::SynonymGraph {
+            synonyms,
+            path,
+            format,
+            keep_original,
+            boost,
+            resource,
+        } => {
+            let mut p = HashMap::new();
+            // Rules may contain commas, so they are separated by newlines.
+            if !synonyms.is_empty() {
+                p.insert("synonyms".into(), synonyms.join("\n"));
+            }
+            if let Some(path) = path {
+                p.insert("path".into(), path.clone());
+            }
+            if let Some(format) = format {
+                p.insert("format".into(), format.clone());
+            }
+            p.insert("keep_original".into(), keep_original.to_string());
+            if let Some(boost) = boost {
+                p.insert("boost".into(), boost.to_string());
+            }
+            if let Some(resource) = resource {
+                p.insert("resource".into(), resource.clone());
+            }
+            ("synonym_graph", p)
+        }
     };
     v1::ComponentConfig {
         r#type: type_name.into(),
@@ -487,12 +681,21 @@ fn token_filter_to_proto(config: &TokenFilterConfig) -> v1::ComponentConfig {
 fn token_filter_from_proto(proto: &v1::ComponentConfig) -> Result<TokenFilterConfig, String> {
     match proto.r#type.as_str() {
         "lowercase" => Ok(TokenFilterConfig::Lowercase),
+        "case_fold" => Ok(TokenFilterConfig::CaseFold),
+        "ascii_folding" => Ok(TokenFilterConfig::AsciiFolding {
+            preserve_original: proto
+                .params
+                .get("preserve_original")
+                .is_some_and(|v| v == "true"),
+        }),
         "stop" => Ok(TokenFilterConfig::Stop {
             words: proto.params.get("words").map(|w| {
                 w.split(',')
                     .map(|s| s.trim().to_string())
                     .collect::<Vec<_>>()
             }),
+            resource: proto.params.get("resource").cloned(),
+            language: proto.params.get("language").cloned(),
         }),
         "stem" => Ok(TokenFilterConfig::Stem {
             stem_type: proto.params.get("stem_type").cloned(),
@@ -518,6 +721,109 @@ fn token_filter_from_proto(proto: &v1::ComponentConfig) -> Result<TokenFilterCon
         "strip" => Ok(TokenFilterConfig::Strip),
         "remove_empty" => Ok(TokenFilterConfig::RemoveEmpty),
         "flatten_graph" => Ok(TokenFilterConfig::FlattenGraph),
+        "edge_ngram" => Ok(TokenFilterConfig::EdgeNgram {
+            min_gram: usize_param(proto, "min_gram")?,
+            max_gram: usize_param(proto, "max_gram")?,
+            preserve_original: proto
+                .params
+                .get("preserve_original")
+                .is_some_and(|v| v == "true"),
+        }),
+        "shingle" => {
+            let size = |key: &str| match proto.params.get(key) {
+                Some(_) => usize_param(proto, key),
+                None => Ok(2),
+            };
+            Ok(TokenFilterConfig::Shingle {
+                min_shingle_size: size("min_shingle_size")?,
+                max_shingle_size: size("max_shingle_size")?,
+                token_separator: proto
+                    .params
+                    .get("token_separator")
+                    .cloned()
+                    .unwrap_or_else(|| " ".into()),
+                output_unigrams: proto
+                    .params
+                    .get("output_unigrams")
+                    .is_none_or(|v| v != "false"),
+            })
+        }
+        "phonetic" => Ok(TokenFilterConfig::Phonetic {
+            encoder: proto
+                .params
+                .get("encoder")
+                .cloned()
+                .unwrap_or_else(|| "double_metaphone".into()),
+            inject: proto.params.get("inject").is_none_or(|v| v != "false"),
+        }),
+        "word_delimiter_graph" => {
+            let flag = |key: &str, default: bool| match proto.params.get(key) {
+                Some(value) => value == "true",
+                None => default,
+            };
+            Ok(TokenFilterConfig::WordDelimiterGraph {
+                generate_word_parts: flag("generate_word_parts", true),
+                generate_number_parts: flag("generate_number_parts", true),
+                split_on_case_change: flag("split_on_case_change", true),
+                split_on_numerics: flag("split_on_numerics", true),
+                catenate_words: flag("catenate_words", false),
+                catenate_numbers: flag("catenate_numbers", false),
+                catenate_all: flag("catenate_all", false),
+                preserve_original: flag("preserve_original", false),
+            })
+        }
+        "dictionary_decompounder" => {
+            let size = |key: &str, default: usize| match proto.params.get(key) {
+                Some(_) => usize_param(proto, key),
+                None => Ok(default),
+            };
+            Ok(TokenFilterConfig::DictionaryDecompounder {
+                words: proto
+                    .params
+                    .get("words")
+                    .ok_or("dictionary_decompounder: missing words")?
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect(),
+                min_word_size: size("min_word_size", 5)?,
+                min_subword_size: size("min_subword_size", 2)?,
+                max_subword_size: size("max_subword_size", 15)?,
+                only_longest_match: proto
+                    .params
+                    .get("only_longest_match")
+                    .is_some_and(|v| v == "true"),
+            })
+        }
+        "synonym_graph" => {
+            let boost = proto
+                .params
+                .get("boost")
+                .map(|b| b.parse::<f32>())
+                .transpose()
+                .map_err(|e| format!("synonym_graph: invalid boost: {e}"))?;
+            Ok(TokenFilterConfig::SynonymGraph {
+                synonyms: proto
+                    .params
+                    .get("synonyms")
+                    .map(|rules| {
+                        rules
+                            .lines()
+                            .map(|rule| rule.trim().to_string())
+                            .filter(|rule| !rule.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                path: proto.params.get("path").cloned(),
+                format: proto.params.get("format").cloned(),
+                keep_original: proto
+                    .params
+                    .get("keep_original")
+                    .is_none_or(|v| v != "false"),
+                boost,
+                resource: proto.params.get("resource").cloned(),
+            })
+        }
         other => Err(format!("Unknown token filter type: {other}")),
     }
 }
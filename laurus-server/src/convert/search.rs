@@ -3,12 +3,15 @@
 //! [`from_proto`] builds a [`laurus::SearchRequest`] from the incoming proto
 //! message, mapping the `query` field to [`SearchQuery::Dsl`] so the engine
 //! can parse unified query DSL (including vector clauses) internally.
-//! [`result_to_proto`] converts engine results back to proto.
+//! [`federated_options_from_proto`] reads the fusion and page of a
+//! federated search, and [`result_to_proto`] and [`suggestion_to_proto`]
+//! convert results back to proto.
 
 use laurus::vector::Vector;
 use laurus::{
-    FusionAlgorithm, LexicalSearchQuery, QueryVector, SearchRequestBuilder, SearchResult,
-    SortField, SortOrder, VectorScoreMode, VectorSearchQuery,
+    FederatedSearchOptions, FederatedSearchResult, FusionAlgorithm, LexicalSearchQuery,
+    QueryVector, SearchRequestBuilder, SortField, SortOrder, Suggestion, VectorScoreMode,
+    VectorSearchQuery,
 };
 
 use crate::convert::document;
@@ -108,27 +111,51 @@ pub fn from_proto(proto: &v1::SearchRequest) -> Result<laurus::SearchRequest, to
     builder = builder.offset(proto.offset as usize);
 
     // Fusion
-    if let Some(fusion) = &proto.fusion
-        && let Some(alg) = &fusion.algorithm
-    {
-        let fusion_alg = match alg {
-            v1::fusion_algorithm::Algorithm::Rrf(rrf) => FusionAlgorithm::RRF { k: rrf.k },
-            v1::fusion_algorithm::Algorithm::WeightedSum(ws) => FusionAlgorithm::WeightedSum {
-                lexical_weight: ws.lexical_weight,
-                vector_weight: ws.vector_weight,
-            },
-        };
+    if let Some(fusion_alg) = proto.fusion.as_ref().and_then(fusion_from_proto) {
         builder = builder.fusion_algorithm(fusion_alg);
     }
 
     Ok(builder.build())
 }
 
-/// Convert a laurus SearchResult into a proto SearchResult.
-pub fn result_to_proto(result: &SearchResult) -> v1::SearchResult {
+/// Convert a proto FusionAlgorithm, `None` if no algorithm is set.
+fn fusion_from_proto(fusion: &v1::FusionAlgorithm) -> Option<FusionAlgorithm> {
+    Some(match fusion.algorithm.as_ref()? {
+        v1::fusion_algorithm::Algorithm::Rrf(rrf) => FusionAlgorithm::RRF { k: rrf.k },
+        v1::fusion_algorithm::Algorithm::WeightedSum(ws) => FusionAlgorithm::WeightedSum {
+            lexical_weight: ws.lexical_weight,
+            vector_weight: ws.vector_weight,
+        },
+    })
+}
+
+/// Build the fusion and page of a proto FederatedSearchRequest.
+pub fn federated_options_from_proto(proto: &v1::FederatedSearchRequest) -> FederatedSearchOptions {
+    let mut options = FederatedSearchOptions::default();
+    if let Some(fusion) = proto.fusion.as_ref().and_then(fusion_from_proto) {
+        options.fusion = fusion;
+    }
+    if proto.limit > 0 {
+        options.limit = proto.limit as usize;
+    }
+    options.offset = proto.offset as usize;
+    options
+}
+
+/// Convert a laurus FederatedSearchResult into a proto SearchResult.
+pub fn result_to_proto(result: &FederatedSearchResult) -> v1::SearchResult {
     v1::SearchResult {
         id: result.id.clone(),
         score: result.score,
         document: result.document.as_ref().map(document::to_proto),
+        index: result.index.clone(),
+    }
+}
+
+/// Convert a laurus Suggestion into a proto Suggestion.
+pub fn suggestion_to_proto(suggestion: Suggestion) -> v1::Suggestion {
+    v1::Suggestion {
+        text: suggestion.text,
+        weight: suggestion.weight,
     }
 }
@@ -0,0 +1,46 @@
+//! Derivation of the caller's tenant from request metadata.
+//!
+//! As with [`identity`](super::identity), the server trusts the
+//! [`TENANT_METADATA_KEY`] entry to be set by an authenticating proxy in
+//! front of it (or by the HTTP gateway from the header of the same name).
+
+use laurus::{Engine, Tenant};
+use tonic::Status;
+use tonic::metadata::MetadataMap;
+
+use super::error;
+
+/// Metadata key (and HTTP header) naming the tenant a request is made for.
+pub const TENANT_METADATA_KEY: &str = "x-laurus-tenant";
+
+/// Read the tenant key from request metadata.
+pub fn from_metadata(metadata: &MetadataMap) -> Option<String> {
+    metadata
+        .get(TENANT_METADATA_KEY)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim().to_string())
+}
+
+/// Scope a request on `engine` to `tenant`.
+///
+/// Returns `None` for indexes without a tenant field, which are accessed
+/// directly.
+///
+/// # Errors
+///
+/// Returns `INVALID_ARGUMENT` if the index has a tenant field and no valid
+/// tenant was given, or if a tenant was given for an index without one.
+#[allow(clippy::result_large_err)]
+pub fn scope<'a>(engine: &'a Engine, tenant: Option<&str>) -> Result<Option<Tenant<'a>>, Status> {
+    let partitioned = engine.schema().tenant_field.is_some();
+    match tenant {
+        Some(key) if partitioned => engine.tenant(key).map(Some).map_err(error::to_status),
+        Some(_) => Err(Status::invalid_argument(format!(
+            "{TENANT_METADATA_KEY} was given, but the index is not partitioned by tenant"
+        ))),
+        None if partitioned => Err(Status::invalid_argument(format!(
+            "The index is partitioned by tenant; {TENANT_METADATA_KEY} is required"
+        ))),
+        None => Ok(None),
+    }
+}
@@ -3,20 +3,29 @@
 //! Provides HTTP/JSON endpoints that act as a proxy to the gRPC server.
 //! `User Request (HTTP/JSON) → gRPC Gateway (axum) → gRPC Server (tonic) → Engine`
 
+mod admin;
 mod convert;
 mod document;
 mod error;
 mod health;
 mod index;
+mod replication;
 mod search;
 
 use axum::Router;
+use axum::http::HeaderMap;
 use axum::routing::{delete, get, post, put};
+use tonic::metadata::{Ascii, MetadataValue};
 use tonic::transport::Channel;
 
+use crate::convert::identity::PRINCIPALS_METADATA_KEY;
+use crate::convert::tenant::TENANT_METADATA_KEY;
+
+use crate::proto::laurus::v1::admin_service_client::AdminServiceClient;
 use crate::proto::laurus::v1::document_service_client::DocumentServiceClient;
 use crate::proto::laurus::v1::health_service_client::HealthServiceClient;
 use crate::proto::laurus::v1::index_service_client::IndexServiceClient;
+use crate::proto::laurus::v1::replication_service_client::ReplicationServiceClient;
 use crate::proto::laurus::v1::search_service_client::SearchServiceClient;
 
 /// Shared state for the Gateway. Holds each gRPC client.
@@ -26,6 +35,8 @@ pub struct GatewayState {
     index_client: IndexServiceClient<Channel>,
     document_client: DocumentServiceClient<Channel>,
     search_client: SearchServiceClient<Channel>,
+    replication_client: ReplicationServiceClient<Channel>,
+    admin_client: AdminServiceClient<Channel>,
 }
 
 impl GatewayState {
@@ -35,7 +46,9 @@ impl GatewayState {
             health_client: HealthServiceClient::new(channel.clone()),
             index_client: IndexServiceClient::new(channel.clone()),
             document_client: DocumentServiceClient::new(channel.clone()),
-            search_client: SearchServiceClient::new(channel),
+            search_client: SearchServiceClient::new(channel.clone()),
+            replication_client: ReplicationServiceClient::new(channel.clone()),
+            admin_client: AdminServiceClient::new(channel),
         }
     }
 }
@@ -44,19 +57,93 @@ impl GatewayState {
 pub fn create_router(state: GatewayState) -> Router {
     Router::new()
         .route("/v1/health", get(health::check))
-        .route("/v1/index", post(index::create).get(index::get_index))
-        .route("/v1/schema", get(index::get_schema))
-        .route("/v1/schema/fields", post(index::add_field))
-        .route("/v1/schema/fields/{name}", delete(index::delete_field))
+        .route("/v1/indexes", get(index::list))
+        .route("/v1/search/federated", post(search::federated_search))
+        .route(
+            "/v1/aliases",
+            get(index::list_aliases).post(index::update_aliases),
+        )
+        .route(
+            "/v1/aliases/{alias}",
+            put(index::put_alias).delete(index::delete_alias),
+        )
+        .route(
+            "/v1/indexes/{index}",
+            post(index::create)
+                .get(index::get_index)
+                .delete(index::delete),
+        )
+        .route("/v1/indexes/{index}/open", post(index::open))
+        .route("/v1/indexes/{index}/close", post(index::close))
+        .route("/v1/indexes/{index}/schema", get(index::get_schema))
+        .route("/v1/indexes/{index}/schema/fields", post(index::add_field))
+        .route(
+            "/v1/indexes/{index}/schema/fields/{name}",
+            put(index::update_field).delete(index::delete_field),
+        )
+        .route(
+            "/v1/indexes/{index}/resources",
+            get(index::list_resource_sets),
+        )
+        .route(
+            "/v1/indexes/{index}/resources/reload",
+            post(index::reload_resource_sets),
+        )
+        .route(
+            "/v1/indexes/{index}/resources/{name}",
+            put(index::put_resource_set)
+                .get(index::get_resource_set)
+                .delete(index::delete_resource_set),
+        )
         .route(
-            "/v1/documents/{id}",
+            "/v1/indexes/{index}/documents/{id}",
             put(document::put_document)
                 .post(document::add_document)
                 .get(document::get_documents)
                 .delete(document::delete_documents),
         )
-        .route("/v1/commit", post(document::commit))
-        .route("/v1/search", post(search::search))
-        .route("/v1/search/stream", post(search::search_stream))
+        .route("/v1/indexes/{index}/commit", post(document::commit))
+        .route(
+            "/v1/indexes/{index}/transaction",
+            post(document::execute_transaction),
+        )
+        .route("/v1/indexes/{index}/changes", get(document::watch_changes))
+        .route("/v1/indexes/{index}/search", post(search::search))
+        .route(
+            "/v1/indexes/{index}/search/stream",
+            post(search::search_stream),
+        )
+        .route("/v1/indexes/{index}/suggest", post(search::suggest))
+        .route(
+            "/v1/indexes/{index}/replication/status",
+            get(replication::status),
+        )
+        .route(
+            "/v1/indexes/{index}/snapshots",
+            post(admin::create_snapshot),
+        )
+        .route(
+            "/v1/indexes/{index}/snapshots/restore",
+            post(admin::restore_snapshot),
+        )
         .with_state(state)
 }
+
+/// Wrap `message` in a gRPC request that forwards the caller's principals
+/// and tenant from the `x-laurus-principals` and `x-laurus-tenant` HTTP
+/// headers.
+fn with_caller<T>(headers: &HeaderMap, message: T) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    for key in [PRINCIPALS_METADATA_KEY, TENANT_METADATA_KEY] {
+        for value in headers.get_all(key) {
+            if let Some(value) = value
+                .to_str()
+                .ok()
+                .and_then(|value| value.parse::<MetadataValue<Ascii>>().ok())
+            {
+                request.metadata_mut().append(key, value);
+            }
+        }
+    }
+    request
+}
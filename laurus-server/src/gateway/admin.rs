@@ -0,0 +1,69 @@
+//! Snapshot endpoints.
+
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use serde_json::{Value, json};
+
+use super::GatewayState;
+use super::error::{BadRequest, GatewayError};
+use crate::proto::laurus::v1;
+
+/// `POST /v1/indexes/:index/snapshots` — Writes a snapshot of the index to a backup directory.
+pub async fn create_snapshot(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, Response> {
+    let path = path_from_body(&body)?;
+    let incremental = body
+        .get("incremental")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let response = state
+        .admin_client
+        .create_snapshot(v1::CreateSnapshotRequest {
+            path,
+            incremental,
+            index,
+        })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    let report = response.into_inner();
+    Ok(Json(json!({
+        "seq": report.seq,
+        "file_count": report.file_count,
+        "copied_files": report.copied_files,
+        "copied_bytes": report.copied_bytes,
+        "unchanged_files": report.unchanged_files,
+        "removed_files": report.removed_files,
+    })))
+}
+
+/// `POST /v1/indexes/:index/snapshots/restore` — Replaces the index with a snapshot,
+/// creating the index if it does not exist.
+pub async fn restore_snapshot(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, Response> {
+    let path = path_from_body(&body)?;
+
+    let response = state
+        .admin_client
+        .restore_snapshot(v1::RestoreSnapshotRequest { path, index })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    Ok(Json(json!({ "seq": response.into_inner().seq })))
+}
+
+#[allow(clippy::result_large_err)]
+fn path_from_body(body: &Value) -> Result<String, Response> {
+    body.get("path")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| BadRequest("missing \"path\" key".to_string()).into_response())
+}
@@ -161,6 +161,69 @@ pub fn proto_document_to_json(doc: &v1::Document) -> Value {
     json!({ "fields": fields })
 }
 
+/// Converts a proto `ChangeEvent` to JSON.
+///
+/// Output format: `{"seq": 1, "id": "...", "doc_id": 1, "kind": "upsert", "document": {...}}`
+/// (`document` is omitted for deletes).
+pub fn proto_change_event_to_json(event: &v1::ChangeEvent) -> Value {
+    let kind = match v1::ChangeKind::try_from(event.kind) {
+        Ok(v1::ChangeKind::Delete) => "delete",
+        _ => "upsert",
+    };
+    let mut json = json!({
+        "seq": event.seq,
+        "id": event.id,
+        "doc_id": event.doc_id,
+        "kind": kind,
+    });
+    if let Some(doc) = &event.document {
+        json["document"] = proto_document_to_json(doc);
+    }
+    json
+}
+
+/// Converts a JSON transaction operation to a proto `TransactionOperation`.
+///
+/// Input format: `{"put": {"id": "...", "document": {...}}}`,
+/// `{"add": {"id": "...", "document": {...}}}` or `{"delete": {"id": "..."}}`.
+pub fn json_to_proto_transaction_operation(
+    json: &Value,
+) -> Result<v1::TransactionOperation, String> {
+    use v1::transaction_operation::Operation;
+
+    let id_of = |op: &Value| {
+        op.get("id")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| "missing \"id\" key in transaction operation".to_string())
+    };
+    let write_of = |op: &Value| -> Result<v1::TransactionDocument, String> {
+        let document = json_to_proto_document(
+            op.get("document")
+                .ok_or_else(|| "missing \"document\" key in transaction operation".to_string())?,
+        )?;
+        Ok(v1::TransactionDocument {
+            id: id_of(op)?,
+            document: Some(document),
+        })
+    };
+
+    let operation = if let Some(op) = json.get("put") {
+        Operation::Put(write_of(op)?)
+    } else if let Some(op) = json.get("add") {
+        Operation::Add(write_of(op)?)
+    } else if let Some(op) = json.get("delete") {
+        Operation::DeleteId(id_of(op)?)
+    } else {
+        return Err(
+            "transaction operation must be one of \"put\", \"add\" or \"delete\"".to_string(),
+        );
+    };
+    Ok(v1::TransactionOperation {
+        operation: Some(operation),
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Schema conversion
 // ---------------------------------------------------------------------------
@@ -221,11 +284,32 @@ pub fn json_to_proto_schema(json: &Value) -> Result<v1::Schema, String> {
         .transpose()?
         .unwrap_or_default();
 
+    let expiry_field = json
+        .get("expiry_field")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let acl_field = json
+        .get("acl_field")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
+    let tenant_field = json
+        .get("tenant_field")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
     Ok(v1::Schema {
         fields,
         default_fields,
         analyzers,
         embedders,
+        expiry_field,
+        acl_field,
+        tenant_field,
     })
 }
 
@@ -256,6 +340,15 @@ pub fn proto_schema_to_json(schema: &v1::Schema) -> Value {
             .collect();
         result["embedders"] = Value::Object(embedders);
     }
+    if !schema.expiry_field.is_empty() {
+        result["expiry_field"] = json!(schema.expiry_field);
+    }
+    if !schema.acl_field.is_empty() {
+        result["acl_field"] = json!(schema.acl_field);
+    }
+    if !schema.tenant_field.is_empty() {
+        result["tenant_field"] = json!(schema.tenant_field);
+    }
     result
 }
 
@@ -296,6 +389,16 @@ pub fn json_to_proto_field_option(json: &Value) -> Result<v1::FieldOption, Strin
                 .and_then(|v| v.as_str())
                 .unwrap_or("")
                 .to_string(),
+            search_analyzer: v
+                .get("search_analyzer")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
+            search_quote_analyzer: v
+                .get("search_quote_analyzer")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string(),
         })
     } else if let Some(v) = obj.get("integer") {
         Opt::Integer(v1::IntegerOption {
@@ -326,6 +429,8 @@ pub fn json_to_proto_field_option(json: &Value) -> Result<v1::FieldOption, Strin
         Opt::Bytes(v1::BytesOption {
             stored: v.get("stored").and_then(|v| v.as_bool()).unwrap_or(false),
         })
+    } else if let Some(v) = obj.get("completion") {
+        Opt::Completion(json_to_completion_option(v))
     } else if let Some(v) = obj.get("hnsw") {
         Opt::Hnsw(json_to_hnsw_option(v)?)
     } else if let Some(v) = obj.get("flat") {
@@ -353,6 +458,12 @@ fn proto_field_option_to_json(opt: &v1::FieldOption) -> Value {
             if !v.analyzer.is_empty() {
                 text_obj["analyzer"] = json!(v.analyzer);
             }
+            if !v.search_analyzer.is_empty() {
+                text_obj["search_analyzer"] = json!(v.search_analyzer);
+            }
+            if !v.search_quote_analyzer.is_empty() {
+                text_obj["search_quote_analyzer"] = json!(v.search_quote_analyzer);
+            }
             json!({ "text": text_obj })
         }
         Some(Opt::Integer(v)) => json!({
@@ -373,6 +484,19 @@ fn proto_field_option_to_json(opt: &v1::FieldOption) -> Value {
         Some(Opt::Bytes(v)) => json!({
             "bytes": { "stored": v.stored }
         }),
+        Some(Opt::Completion(v)) => {
+            let mut completion_obj = json!({
+                "stored": v.stored,
+                "max_edits": v.max_edits,
+                "prefix_length": v.prefix_length,
+                "min_fuzzy_length": v.min_fuzzy_length,
+                "transpositions": v.transpositions,
+            });
+            if !v.weight_field.is_empty() {
+                completion_obj["weight_field"] = json!(v.weight_field);
+            }
+            json!({ "completion": completion_obj })
+        }
         Some(Opt::Hnsw(v)) => json!({ "hnsw": hnsw_option_to_json(v) }),
         Some(Opt::Flat(v)) => json!({ "flat": flat_option_to_json(v) }),
         Some(Opt::Ivf(v)) => json!({ "ivf": ivf_option_to_json(v) }),
@@ -380,6 +504,29 @@ fn proto_field_option_to_json(opt: &v1::FieldOption) -> Value {
     }
 }
 
+/// Converts a JSON completion option, defaulting omitted keys the way
+/// `laurus::CompletionOption::default()` does.
+fn json_to_completion_option(v: &Value) -> v1::CompletionOption {
+    v1::CompletionOption {
+        stored: v.get("stored").and_then(|v| v.as_bool()).unwrap_or(true),
+        weight_field: v
+            .get("weight_field")
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        max_edits: v.get("max_edits").and_then(|v| v.as_u64()).unwrap_or(1) as u32,
+        prefix_length: v.get("prefix_length").and_then(|v| v.as_u64()).unwrap_or(1) as u32,
+        min_fuzzy_length: v
+            .get("min_fuzzy_length")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(3) as u32,
+        transpositions: v
+            .get("transpositions")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Vector field option conversion
 // ---------------------------------------------------------------------------
@@ -600,6 +747,44 @@ pub fn json_to_proto_search_request(json: &Value) -> Result<v1::SearchRequest, S
         lexical_params,
         vector_params,
         field_boosts,
+        index: String::new(),
+    })
+}
+
+/// Converts a JSON federated search body to a proto `FederatedSearchRequest`.
+///
+/// Each entry of `queries` is a search body with an additional `index` and
+/// optional `weight`.
+pub fn json_to_proto_federated_search_request(
+    json: &Value,
+) -> Result<v1::FederatedSearchRequest, String> {
+    let queries = json
+        .get("queries")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| "missing 'queries' array".to_string())?
+        .iter()
+        .map(|query| {
+            let index = query
+                .get("index")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| "every query needs an 'index'".to_string())?;
+            let mut request = json_to_proto_search_request(query)?;
+            request.index = index.to_string();
+            Ok(v1::FederatedQuery {
+                request: Some(request),
+                weight: query
+                    .get("weight")
+                    .and_then(|v| v.as_f64())
+                    .map(|w| w as f32),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(v1::FederatedSearchRequest {
+        queries,
+        fusion: json.get("fusion").and_then(json_to_fusion_algorithm),
+        limit: json.get("limit").and_then(|v| v.as_u64()).unwrap_or(10) as u32,
+        offset: json.get("offset").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
     })
 }
 
@@ -700,11 +885,42 @@ fn json_to_vector_params(json: &Value) -> Option<v1::VectorParams> {
     })
 }
 
+/// Converts a JSON suggest body to a proto `SuggestRequest`.
+pub fn json_to_proto_suggest_request(json: &Value) -> Result<v1::SuggestRequest, String> {
+    let field = json
+        .get("field")
+        .and_then(|v| v.as_str())
+        .ok_or("suggest request missing 'field'")?
+        .to_string();
+    let prefix = json
+        .get("prefix")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    let limit = json.get("limit").and_then(|v| v.as_u64()).unwrap_or(10) as u32;
+
+    Ok(v1::SuggestRequest {
+        index: String::new(),
+        field,
+        prefix,
+        limit,
+    })
+}
+
+/// Converts a proto `Suggestion` to a JSON value.
+pub fn proto_suggestion_to_json(suggestion: &v1::Suggestion) -> Value {
+    json!({
+        "text": suggestion.text,
+        "weight": suggestion.weight,
+    })
+}
+
 /// Converts a proto `SearchResult` to a JSON value.
 pub fn proto_search_result_to_json(result: &v1::SearchResult) -> Value {
     let mut obj = json!({
         "id": result.id,
         "score": result.score,
+        "index": result.index,
     });
     if let Some(doc) = &result.document {
         obj["document"] = proto_document_to_json(doc);
@@ -838,6 +1054,88 @@ fn proto_component_to_json(comp: &v1::ComponentConfig) -> Value {
     obj
 }
 
+// ---------------------------------------------------------------------------
+// Resource set conversion
+// ---------------------------------------------------------------------------
+
+/// Converts a JSON resource set body (`{"type": "synonyms", "rules": [...]}`,
+/// `{"type": "stop_words", "words": [...]}` or
+/// `{"type": "mapping", "mapping": {...}}`) to a proto `ResourceSet` named
+/// `name`.
+pub fn json_to_proto_resource_set(name: &str, json: &Value) -> Result<v1::ResourceSet, String> {
+    use v1::resource_set::Content;
+
+    let obj = json
+        .as_object()
+        .ok_or_else(|| "resource set must be an object".to_string())?;
+    let strings = |key: &str| -> Result<Vec<String>, String> {
+        obj.get(key)
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| format!("resource set missing '{key}' array"))?
+            .iter()
+            .map(|v| {
+                v.as_str()
+                    .map(|s| s.to_string())
+                    .ok_or_else(|| format!("'{key}' must contain strings"))
+            })
+            .collect()
+    };
+
+    let content = match obj.get("type").and_then(|v| v.as_str()) {
+        Some("synonyms") => Content::Synonyms(v1::SynonymRules {
+            rules: strings("rules")?,
+        }),
+        Some("stop_words") => Content::StopWords(v1::StopWords {
+            words: strings("words")?,
+        }),
+        Some("mapping") => {
+            let mapping = obj
+                .get("mapping")
+                .and_then(|v| v.as_object())
+                .ok_or("resource set missing 'mapping' object")?
+                .iter()
+                .map(|(k, v)| {
+                    v.as_str()
+                        .map(|s| (k.clone(), s.to_string()))
+                        .ok_or_else(|| "'mapping' values must be strings".to_string())
+                })
+                .collect::<Result<HashMap<_, _>, _>>()?;
+            Content::Mapping(v1::CharMapping { mapping })
+        }
+        Some(other) => return Err(format!("Unknown resource set type: {other}")),
+        None => return Err("resource set missing 'type'".to_string()),
+    };
+
+    Ok(v1::ResourceSet {
+        name: name.to_string(),
+        version: 0,
+        content: Some(content),
+    })
+}
+
+/// Converts a proto `ResourceSet` to a JSON value.
+pub fn proto_resource_set_to_json(set: &v1::ResourceSet) -> Value {
+    use v1::resource_set::Content;
+
+    let mut obj = json!({"name": set.name, "version": set.version});
+    match &set.content {
+        Some(Content::Synonyms(synonyms)) => {
+            obj["type"] = json!("synonyms");
+            obj["rules"] = json!(synonyms.rules);
+        }
+        Some(Content::StopWords(stop_words)) => {
+            obj["type"] = json!("stop_words");
+            obj["words"] = json!(stop_words.words);
+        }
+        Some(Content::Mapping(mapping)) => {
+            obj["type"] = json!("mapping");
+            obj["mapping"] = json!(mapping.mapping);
+        }
+        None => {}
+    }
+    obj
+}
+
 // ---------------------------------------------------------------------------
 // Embedder definition conversion
 // ---------------------------------------------------------------------------
@@ -978,4 +1276,83 @@ mod tests {
         assert_eq!(req.offset, 0);
         assert_eq!(*req.field_boosts.get("title").unwrap(), 2.0);
     }
+
+    #[test]
+    fn test_completion_field_option_json() {
+        let json = json!({ "completion": { "weight_field": "popularity", "max_edits": 0 } });
+        let opt = json_to_proto_field_option(&json).unwrap();
+        let Some(v1::field_option::Option::Completion(completion)) = &opt.option else {
+            panic!("expected completion option");
+        };
+        assert!(completion.stored);
+        assert_eq!(completion.weight_field, "popularity");
+        assert_eq!(completion.max_edits, 0);
+        assert_eq!(completion.prefix_length, 1);
+        assert_eq!(completion.min_fuzzy_length, 3);
+        assert!(completion.transpositions);
+
+        let back = proto_field_option_to_json(&opt);
+        assert_eq!(back["completion"]["weight_field"], "popularity");
+        assert_eq!(back["completion"]["max_edits"], 0);
+    }
+
+    #[test]
+    fn test_json_to_proto_suggest_request() {
+        let req =
+            json_to_proto_suggest_request(&json!({ "field": "title", "prefix": "ru" })).unwrap();
+        assert_eq!(req.field, "title");
+        assert_eq!(req.prefix, "ru");
+        assert_eq!(req.limit, 10);
+
+        assert!(json_to_proto_suggest_request(&json!({ "prefix": "ru" })).is_err());
+    }
+
+    #[test]
+    fn test_json_to_proto_federated_search_request() {
+        let json = json!({
+            "queries": [
+                { "index": "docs", "query": "body:rust" },
+                { "index": "tickets", "query": "summary:rust", "weight": 2.0 }
+            ],
+            "fusion": { "rrf": { "k": 30.0 } },
+            "limit": 5
+        });
+        let req = json_to_proto_federated_search_request(&json).unwrap();
+        assert_eq!(req.queries.len(), 2);
+        let docs = req.queries[0].request.as_ref().unwrap();
+        assert_eq!(docs.index, "docs");
+        assert_eq!(docs.query, "body:rust");
+        assert_eq!(req.queries[0].weight, None);
+        assert_eq!(req.queries[1].weight, Some(2.0));
+        assert!(req.fusion.is_some());
+        assert_eq!(req.limit, 5);
+        assert_eq!(req.offset, 0);
+
+        let missing_index = json!({ "queries": [{ "query": "body:rust" }] });
+        assert!(json_to_proto_federated_search_request(&missing_index).is_err());
+    }
+
+    #[test]
+    fn test_resource_set_json_roundtrip() {
+        let json = json!({ "type": "synonyms", "rules": ["ml, machine learning"] });
+        let set = json_to_proto_resource_set("synonyms", &json).unwrap();
+        assert_eq!(set.name, "synonyms");
+        assert_eq!(
+            proto_resource_set_to_json(&set),
+            json!({
+                "name": "synonyms",
+                "version": 0,
+                "type": "synonyms",
+                "rules": ["ml, machine learning"]
+            })
+        );
+
+        let mapping = json!({ "type": "mapping", "mapping": { "ﬁ": "fi" } });
+        assert!(json_to_proto_resource_set("chars", &mapping).is_ok());
+
+        let missing_words = json!({ "type": "stop_words" });
+        assert!(json_to_proto_resource_set("stop", &missing_words).is_err());
+        let unknown = json!({ "type": "stems", "rules": [] });
+        assert!(json_to_proto_resource_set("stems", &unknown).is_err());
+    }
 }
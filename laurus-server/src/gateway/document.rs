@@ -1,19 +1,28 @@
 //! Document CRUD endpoints.
 
+use std::convert::Infallible;
+
 use axum::Json;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
 use serde_json::{Value, json};
+use tokio_stream::StreamExt;
 
-use super::GatewayState;
 use super::convert;
 use super::error::{BadRequest, GatewayError};
+use super::{GatewayState, with_caller};
 use crate::proto::laurus::v1;
 
-/// `PUT /v1/documents/:id` — Inserts or replaces a document.
+/// `PUT /v1/indexes/:index/documents/:id` — Inserts or replaces a document.
+///
+/// An optional `ttl_seconds` key sets the document's time-to-live.
 pub async fn put_document(
     State(mut state): State<GatewayState>,
-    Path(id): Path<String>,
+    Path((index, id)): Path<(String, String)>,
+    headers: HeaderMap,
     Json(body): Json<Value>,
 ) -> Result<Json<Value>, Response> {
     let document = convert::json_to_proto_document(
@@ -24,20 +33,28 @@ pub async fn put_document(
 
     state
         .document_client
-        .put_document(v1::PutDocumentRequest {
-            id,
-            document: Some(document),
-        })
+        .put_document(with_caller(
+            &headers,
+            v1::PutDocumentRequest {
+                id,
+                document: Some(document),
+                ttl_seconds: body.get("ttl_seconds").and_then(|v| v.as_u64()),
+                index,
+            },
+        ))
         .await
         .map_err(|s| GatewayError(s).into_response())?;
 
     Ok(Json(json!({})))
 }
 
-/// `POST /v1/documents/:id` — Adds a document as a chunk.
+/// `POST /v1/indexes/:index/documents/:id` — Adds a document as a chunk.
+///
+/// An optional `ttl_seconds` key sets the chunk's time-to-live.
 pub async fn add_document(
     State(mut state): State<GatewayState>,
-    Path(id): Path<String>,
+    Path((index, id)): Path<(String, String)>,
+    headers: HeaderMap,
     Json(body): Json<Value>,
 ) -> Result<Json<Value>, Response> {
     let document = convert::json_to_proto_document(
@@ -48,24 +65,30 @@ pub async fn add_document(
 
     state
         .document_client
-        .add_document(v1::AddDocumentRequest {
-            id,
-            document: Some(document),
-        })
+        .add_document(with_caller(
+            &headers,
+            v1::AddDocumentRequest {
+                id,
+                document: Some(document),
+                ttl_seconds: body.get("ttl_seconds").and_then(|v| v.as_u64()),
+                index,
+            },
+        ))
         .await
         .map_err(|s| GatewayError(s).into_response())?;
 
     Ok(Json(json!({})))
 }
 
-/// `GET /v1/documents/:id` — Retrieves documents with the specified ID.
+/// `GET /v1/indexes/:index/documents/:id` — Retrieves documents with the specified ID.
 pub async fn get_documents(
     State(mut state): State<GatewayState>,
-    Path(id): Path<String>,
+    Path((index, id)): Path<(String, String)>,
+    headers: HeaderMap,
 ) -> Result<Json<Value>, Response> {
     let response = state
         .document_client
-        .get_documents(v1::GetDocumentsRequest { id })
+        .get_documents(with_caller(&headers, v1::GetDocumentsRequest { id, index }))
         .await
         .map_err(|s| GatewayError(s).into_response())?;
 
@@ -79,27 +102,123 @@ pub async fn get_documents(
     Ok(Json(json!({ "documents": documents })))
 }
 
-/// `DELETE /v1/documents/:id` — Deletes documents with the specified ID.
+/// `DELETE /v1/indexes/:index/documents/:id` — Deletes documents with the specified ID.
 pub async fn delete_documents(
     State(mut state): State<GatewayState>,
-    Path(id): Path<String>,
+    Path((index, id)): Path<(String, String)>,
+    headers: HeaderMap,
 ) -> Result<Json<Value>, Response> {
     state
         .document_client
-        .delete_documents(v1::DeleteDocumentsRequest { id })
+        .delete_documents(with_caller(
+            &headers,
+            v1::DeleteDocumentsRequest { id, index },
+        ))
         .await
         .map_err(|s| GatewayError(s).into_response())?;
 
     Ok(Json(json!({})))
 }
 
-/// `POST /v1/commit` — Persists pending changes.
-pub async fn commit(State(mut state): State<GatewayState>) -> Result<Json<Value>, Response> {
+/// `POST /v1/indexes/:index/commit` — Persists pending changes.
+pub async fn commit(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+) -> Result<Json<Value>, Response> {
     state
         .document_client
-        .commit(v1::CommitRequest {})
+        .commit(v1::CommitRequest { index })
         .await
         .map_err(|s| GatewayError(s).into_response())?;
 
     Ok(Json(json!({})))
 }
+
+/// `POST /v1/indexes/:index/transaction` — Applies a set of operations atomically.
+///
+/// The body holds an `operations` array; each element is one of
+/// `{"put": {"id", "document"}}`, `{"add": {"id", "document"}}` or
+/// `{"delete": {"id"}}`.
+pub async fn execute_transaction(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, Response> {
+    let operations = body
+        .get("operations")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| BadRequest("missing \"operations\" array".to_string()).into_response())?
+        .iter()
+        .map(convert::json_to_proto_transaction_operation)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| BadRequest(e).into_response())?;
+
+    state
+        .document_client
+        .execute_transaction(v1::ExecuteTransactionRequest { operations, index })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    Ok(Json(json!({})))
+}
+
+/// Query parameters of `GET /v1/indexes/:index/changes`.
+#[derive(Debug, Deserialize)]
+pub struct WatchChangesParams {
+    /// Only mutations with a greater sequence number are streamed.
+    #[serde(default)]
+    since_seq: u64,
+}
+
+/// `GET /v1/indexes/:index/changes?since_seq=N` — Streams index mutations via SSE.
+///
+/// Each event carries the mutation's sequence number as its SSE `id`, so a
+/// client can resume from the last event it processed. Only mutations of
+/// documents visible to the caller are streamed.
+pub async fn watch_changes(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+    Query(params): Query<WatchChangesParams>,
+    headers: HeaderMap,
+) -> Response {
+    let response = match state
+        .document_client
+        .watch_changes(with_caller(
+            &headers,
+            v1::WatchChangesRequest {
+                since_seq: params.since_seq,
+                index,
+            },
+        ))
+        .await
+    {
+        Ok(r) => r,
+        Err(s) => return GatewayError(s).into_response(),
+    };
+
+    let sse_stream = response
+        .into_inner()
+        .map(|result| -> Result<Event, Infallible> {
+            match result {
+                Ok(change) => {
+                    let json = convert::proto_change_event_to_json(&change);
+                    let data = serde_json::to_string(&json).unwrap_or_default();
+                    Ok(Event::default().id(change.seq.to_string()).data(data))
+                }
+                Err(status) => {
+                    let error_json = json!({
+                        "error": {
+                            "code": status.code() as i32,
+                            "message": status.message(),
+                        }
+                    });
+                    let data = serde_json::to_string(&error_json).unwrap_or_default();
+                    Ok(Event::default().event("error").data(data))
+                }
+            }
+        });
+
+    Sse::new(sse_stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
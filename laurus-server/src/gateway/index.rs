@@ -1,7 +1,7 @@
 //! Index management endpoints.
 
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, State};
 use axum::response::{IntoResponse, Response};
 use serde_json::{Value, json};
 
@@ -10,9 +10,28 @@ use super::convert;
 use super::error::{BadRequest, GatewayError};
 use crate::proto::laurus::v1;
 
-/// `POST /v1/index` — Creates a new index.
+/// `GET /v1/indexes` — Lists the hosted indexes.
+pub async fn list(State(mut state): State<GatewayState>) -> Result<Json<Value>, Response> {
+    let response = state
+        .index_client
+        .list_indexes(v1::ListIndexesRequest {})
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    let indexes: Vec<Value> = response
+        .into_inner()
+        .indexes
+        .iter()
+        .map(|info| json!({ "name": info.name, "open": info.open }))
+        .collect();
+
+    Ok(Json(json!({ "indexes": indexes })))
+}
+
+/// `POST /v1/indexes/:index` — Creates a new index.
 pub async fn create(
     State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
     Json(body): Json<Value>,
 ) -> Result<Json<Value>, Response> {
     let schema_json = body
@@ -26,6 +45,7 @@ pub async fn create(
         .index_client
         .create_index(v1::CreateIndexRequest {
             schema: Some(schema),
+            index,
         })
         .await
         .map_err(|s| GatewayError(s).into_response())?;
@@ -33,11 +53,179 @@ pub async fn create(
     Ok(Json(json!({})))
 }
 
-/// `GET /v1/index` — Returns index statistics.
-pub async fn get_index(State(mut state): State<GatewayState>) -> Result<Json<Value>, Response> {
+/// `DELETE /v1/indexes/:index` — Deletes an index and all of its files.
+pub async fn delete(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+) -> Result<Json<Value>, Response> {
+    state
+        .index_client
+        .delete_index(v1::DeleteIndexRequest { index })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    Ok(Json(json!({})))
+}
+
+/// `POST /v1/indexes/:index/open` — Loads an index into memory.
+pub async fn open(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+) -> Result<Json<Value>, Response> {
+    state
+        .index_client
+        .open_index(v1::OpenIndexRequest { index })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    Ok(Json(json!({})))
+}
+
+/// `POST /v1/indexes/:index/close` — Commits and unloads an index from memory.
+pub async fn close(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+) -> Result<Json<Value>, Response> {
+    let response = state
+        .index_client
+        .close_index(v1::CloseIndexRequest { index })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    Ok(Json(json!({ "closed": response.into_inner().closed })))
+}
+
+/// `GET /v1/aliases` — Lists the aliases and the indexes they refer to.
+pub async fn list_aliases(State(mut state): State<GatewayState>) -> Result<Json<Value>, Response> {
+    let response = state
+        .index_client
+        .list_aliases(v1::ListAliasesRequest {})
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    let aliases: Vec<Value> = response
+        .into_inner()
+        .aliases
+        .iter()
+        .map(|alias| json!({ "name": alias.name, "indexes": alias.indexes }))
+        .collect();
+
+    Ok(Json(json!({ "aliases": aliases })))
+}
+
+/// `POST /v1/aliases` — Applies alias actions atomically.
+///
+/// The body holds `"actions"`, each either `{"put": {"name": ..., "indexes": [...]}}`
+/// or `{"remove": "<alias>"}`.
+pub async fn update_aliases(
+    State(mut state): State<GatewayState>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, Response> {
+    let actions = body
+        .get("actions")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| BadRequest("missing \"actions\" array".to_string()).into_response())?
+        .iter()
+        .map(json_to_alias_action)
+        .collect::<Result<Vec<_>, String>>()
+        .map_err(|e| BadRequest(e).into_response())?;
+
+    send_alias_actions(&mut state, actions).await
+}
+
+/// `PUT /v1/aliases/:alias` — Points an alias at the `"indexes"` of the body.
+pub async fn put_alias(
+    State(mut state): State<GatewayState>,
+    Path(alias): Path<String>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, Response> {
+    let indexes =
+        json_to_index_names(body.get("indexes")).map_err(|e| BadRequest(e).into_response())?;
+    let action = v1::alias_action::Action::Put(v1::Alias {
+        name: alias,
+        indexes,
+    });
+
+    send_alias_actions(
+        &mut state,
+        vec![v1::AliasAction {
+            action: Some(action),
+        }],
+    )
+    .await
+}
+
+/// `DELETE /v1/aliases/:alias` — Removes an alias.
+pub async fn delete_alias(
+    State(mut state): State<GatewayState>,
+    Path(alias): Path<String>,
+) -> Result<Json<Value>, Response> {
+    let action = v1::alias_action::Action::Remove(alias);
+
+    send_alias_actions(
+        &mut state,
+        vec![v1::AliasAction {
+            action: Some(action),
+        }],
+    )
+    .await
+}
+
+async fn send_alias_actions(
+    state: &mut GatewayState,
+    actions: Vec<v1::AliasAction>,
+) -> Result<Json<Value>, Response> {
+    state
+        .index_client
+        .update_aliases(v1::UpdateAliasesRequest { actions })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    Ok(Json(json!({})))
+}
+
+fn json_to_alias_action(value: &Value) -> Result<v1::AliasAction, String> {
+    let action = if let Some(put) = value.get("put") {
+        let name = put
+            .get("name")
+            .and_then(|v| v.as_str())
+            .ok_or("\"put\" requires a \"name\" string")?;
+        v1::alias_action::Action::Put(v1::Alias {
+            name: name.to_string(),
+            indexes: json_to_index_names(put.get("indexes"))?,
+        })
+    } else if let Some(remove) = value.get("remove") {
+        let name = remove.as_str().ok_or("\"remove\" must be an alias name")?;
+        v1::alias_action::Action::Remove(name.to_string())
+    } else {
+        return Err("each action must be \"put\" or \"remove\"".to_string());
+    };
+    Ok(v1::AliasAction {
+        action: Some(action),
+    })
+}
+
+fn json_to_index_names(value: Option<&Value>) -> Result<Vec<String>, String> {
+    value
+        .and_then(|v| v.as_array())
+        .ok_or("missing \"indexes\" array")?
+        .iter()
+        .map(|v| {
+            v.as_str()
+                .map(str::to_string)
+                .ok_or_else(|| "\"indexes\" must contain strings".to_string())
+        })
+        .collect()
+}
+
+/// `GET /v1/indexes/:index` — Returns index statistics.
+pub async fn get_index(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+) -> Result<Json<Value>, Response> {
     let response = state
         .index_client
-        .get_index(v1::GetIndexRequest {})
+        .get_index(v1::GetIndexRequest { index })
         .await
         .map_err(|s| GatewayError(s).into_response())?;
 
@@ -56,17 +244,29 @@ pub async fn get_index(State(mut state): State<GatewayState>) -> Result<Json<Val
         })
         .collect();
 
+    let backfills: Vec<Value> = inner.backfills.iter().map(backfill_to_json).collect();
+    let field_update = inner
+        .field_update
+        .as_ref()
+        .map(backfill_to_json)
+        .unwrap_or(Value::Null);
+
     Ok(Json(json!({
         "document_count": inner.document_count,
         "vector_fields": vector_fields,
+        "backfills": backfills,
+        "field_update": field_update,
     })))
 }
 
-/// `GET /v1/schema` — Returns the current schema.
-pub async fn get_schema(State(mut state): State<GatewayState>) -> Result<Json<Value>, Response> {
+/// `GET /v1/indexes/:index/schema` — Returns the schema of the index.
+pub async fn get_schema(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+) -> Result<Json<Value>, Response> {
     let response = state
         .index_client
-        .get_schema(v1::GetSchemaRequest {})
+        .get_schema(v1::GetSchemaRequest { index })
         .await
         .map_err(|s| GatewayError(s).into_response())?;
 
@@ -80,9 +280,10 @@ pub async fn get_schema(State(mut state): State<GatewayState>) -> Result<Json<Va
     Ok(Json(json!({ "schema": schema_json })))
 }
 
-/// `POST /v1/schema/fields` — Adds a new field to the index schema.
+/// `POST /v1/indexes/:index/schema/fields` — Adds a new field to the index schema.
 pub async fn add_field(
     State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
     Json(body): Json<Value>,
 ) -> Result<Json<Value>, Response> {
     let name = body
@@ -98,11 +299,19 @@ pub async fn add_field(
     let field_option = convert::json_to_proto_field_option(field_option_json)
         .map_err(|e| BadRequest(e).into_response())?;
 
+    let backfill_source_field = body
+        .get("backfill_source_field")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+
     let response = state
         .index_client
         .add_field(v1::AddFieldRequest {
             name,
             field_option: Some(field_option),
+            backfill_source_field,
+            index,
         })
         .await
         .map_err(|s| GatewayError(s).into_response())?;
@@ -113,18 +322,25 @@ pub async fn add_field(
         .as_ref()
         .map(convert::proto_schema_to_json)
         .unwrap_or(Value::Null);
+    let backfill_json = inner
+        .backfill
+        .as_ref()
+        .map(backfill_to_json)
+        .unwrap_or(Value::Null);
 
-    Ok(Json(json!({ "schema": schema_json })))
+    Ok(Json(
+        json!({ "schema": schema_json, "backfill": backfill_json }),
+    ))
 }
 
-/// `DELETE /v1/schema/fields/:name` — Removes a field from the index schema.
+/// `DELETE /v1/indexes/:index/schema/fields/:name` — Removes a field from the index schema.
 pub async fn delete_field(
     State(mut state): State<GatewayState>,
-    axum::extract::Path(name): axum::extract::Path<String>,
+    Path((index, name)): Path<(String, String)>,
 ) -> Result<Json<Value>, Response> {
     let response = state
         .index_client
-        .delete_field(v1::DeleteFieldRequest { name })
+        .delete_field(v1::DeleteFieldRequest { name, index })
         .await
         .map_err(|s| GatewayError(s).into_response())?;
 
@@ -137,3 +353,158 @@ pub async fn delete_field(
 
     Ok(Json(json!({ "schema": schema_json })))
 }
+
+/// `PUT /v1/indexes/:index/schema/fields/:name` — Changes a field definition by rebuilding
+/// the index.
+pub async fn update_field(
+    State(mut state): State<GatewayState>,
+    Path((index, name)): Path<(String, String)>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, Response> {
+    let field_option_json = body
+        .get("field_option")
+        .ok_or_else(|| BadRequest("missing \"field_option\" key".to_string()).into_response())?;
+
+    let field_option = convert::json_to_proto_field_option(field_option_json)
+        .map_err(|e| BadRequest(e).into_response())?;
+
+    let response = state
+        .index_client
+        .update_field(v1::UpdateFieldRequest {
+            name,
+            field_option: Some(field_option),
+            index,
+        })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    let inner = response.into_inner();
+    let schema_json = inner
+        .schema
+        .as_ref()
+        .map(convert::proto_schema_to_json)
+        .unwrap_or(Value::Null);
+    let progress_json = inner
+        .progress
+        .as_ref()
+        .map(backfill_to_json)
+        .unwrap_or(Value::Null);
+
+    Ok(Json(
+        json!({ "schema": schema_json, "progress": progress_json }),
+    ))
+}
+
+/// `GET /v1/indexes/:index/resources` — Lists the resource sets of the index.
+pub async fn list_resource_sets(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+) -> Result<Json<Value>, Response> {
+    let response = state
+        .index_client
+        .list_resource_sets(v1::ListResourceSetsRequest { index })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    let sets: Vec<Value> = response
+        .into_inner()
+        .resource_sets
+        .iter()
+        .map(convert::proto_resource_set_to_json)
+        .collect();
+    Ok(Json(json!({ "resource_sets": sets })))
+}
+
+/// `PUT /v1/indexes/:index/resources/:name` — Creates or replaces a resource set and
+/// reloads the analyzers using it.
+pub async fn put_resource_set(
+    State(mut state): State<GatewayState>,
+    Path((index, name)): Path<(String, String)>,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, Response> {
+    let resource_set = convert::json_to_proto_resource_set(&name, &body)
+        .map_err(|e| BadRequest(e).into_response())?;
+
+    let response = state
+        .index_client
+        .put_resource_set(v1::PutResourceSetRequest {
+            index,
+            resource_set: Some(resource_set),
+        })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    Ok(Json(resource_set_to_json(
+        response.into_inner().resource_set.as_ref(),
+    )))
+}
+
+/// `GET /v1/indexes/:index/resources/:name` — Returns a resource set.
+pub async fn get_resource_set(
+    State(mut state): State<GatewayState>,
+    Path((index, name)): Path<(String, String)>,
+) -> Result<Json<Value>, Response> {
+    let response = state
+        .index_client
+        .get_resource_set(v1::GetResourceSetRequest { index, name })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    Ok(Json(resource_set_to_json(
+        response.into_inner().resource_set.as_ref(),
+    )))
+}
+
+/// `DELETE /v1/indexes/:index/resources/:name` — Deletes a resource set that no
+/// analyzer refers to.
+pub async fn delete_resource_set(
+    State(mut state): State<GatewayState>,
+    Path((index, name)): Path<(String, String)>,
+) -> Result<Json<Value>, Response> {
+    let response = state
+        .index_client
+        .delete_resource_set(v1::DeleteResourceSetRequest { index, name })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    Ok(Json(resource_set_to_json(
+        response.into_inner().resource_set.as_ref(),
+    )))
+}
+
+/// `POST /v1/indexes/:index/resources/reload` — Re-reads the resource sets from the
+/// index files and reloads the analyzers using them.
+pub async fn reload_resource_sets(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+) -> Result<Json<Value>, Response> {
+    let response = state
+        .index_client
+        .reload_resource_sets(v1::ReloadResourceSetsRequest { index })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    let sets: Vec<Value> = response
+        .into_inner()
+        .resource_sets
+        .iter()
+        .map(convert::proto_resource_set_to_json)
+        .collect();
+    Ok(Json(json!({ "resource_sets": sets })))
+}
+
+fn resource_set_to_json(set: Option<&v1::ResourceSet>) -> Value {
+    json!({
+        "resource_set": set.map(convert::proto_resource_set_to_json).unwrap_or(Value::Null),
+    })
+}
+
+fn backfill_to_json(progress: &v1::BackfillProgress) -> Value {
+    json!({
+        "field": progress.field,
+        "source_field": progress.source_field,
+        "total": progress.total,
+        "processed": progress.processed,
+        "completed": progress.completed,
+    })
+}
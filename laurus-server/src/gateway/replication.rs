@@ -0,0 +1,36 @@
+//! Replication status endpoint.
+
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use serde_json::{Value, json};
+
+use super::GatewayState;
+use super::error::GatewayError;
+use crate::proto::laurus::v1;
+
+/// `GET /v1/indexes/:index/replication/status` — Returns the replication role, and the
+/// position and lag of the index.
+pub async fn status(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+) -> Result<Json<Value>, Response> {
+    let response = state
+        .replication_client
+        .get_replication_status(v1::GetReplicationStatusRequest { index })
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    let status = response.into_inner();
+    let role = v1::ReplicationRole::try_from(status.role)
+        .unwrap_or(v1::ReplicationRole::Leader)
+        .as_str_name();
+
+    Ok(Json(json!({
+        "role": role,
+        "last_seq": status.last_seq,
+        "leader_seq": status.leader_seq,
+        "lag": status.lag,
+        "leader_address": status.leader_address,
+    })))
+}
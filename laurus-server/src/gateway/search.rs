@@ -1,29 +1,33 @@
-//! Search endpoints (unary + SSE streaming).
+//! Search endpoints (unary + SSE streaming) and completion suggestions.
 
 use std::convert::Infallible;
 
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::response::sse::{Event, Sse};
 use axum::response::{IntoResponse, Response};
 use serde_json::{Value, json};
 use tokio_stream::StreamExt;
 
-use super::GatewayState;
 use super::convert;
 use super::error::{BadRequest, GatewayError};
+use super::{GatewayState, with_caller};
 
-/// `POST /v1/search` — Executes a search and returns all results at once.
+/// `POST /v1/indexes/:index/search` — Executes a search and returns all results at once.
 pub async fn search(
     State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+    headers: HeaderMap,
     Json(body): Json<Value>,
 ) -> Result<Json<Value>, Response> {
-    let request =
+    let mut request =
         convert::json_to_proto_search_request(&body).map_err(|e| BadRequest(e).into_response())?;
+    request.index = index;
 
     let response = state
         .search_client
-        .search(request)
+        .search(with_caller(&headers, request))
         .await
         .map_err(|s| GatewayError(s).into_response())?;
 
@@ -40,17 +44,82 @@ pub async fn search(
     })))
 }
 
-/// `POST /v1/search/stream` — Executes a search and returns results incrementally via SSE.
+/// `POST /v1/search/federated` — Runs one search per index and returns the
+/// fused results.
+pub async fn federated_search(
+    State(mut state): State<GatewayState>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, Response> {
+    let request = convert::json_to_proto_federated_search_request(&body)
+        .map_err(|e| BadRequest(e).into_response())?;
+
+    let response = state
+        .search_client
+        .federated_search(with_caller(&headers, request))
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    let inner = response.into_inner();
+    let results: Vec<Value> = inner
+        .results
+        .iter()
+        .map(convert::proto_search_result_to_json)
+        .collect();
+
+    Ok(Json(json!({
+        "total_hits": inner.total_hits,
+        "results": results,
+    })))
+}
+
+/// `POST /v1/indexes/:index/suggest` — Returns completions of a prefix from a
+/// completion field.
+pub async fn suggest(
+    State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+    headers: HeaderMap,
+    Json(body): Json<Value>,
+) -> Result<Json<Value>, Response> {
+    let mut request =
+        convert::json_to_proto_suggest_request(&body).map_err(|e| BadRequest(e).into_response())?;
+    request.index = index;
+
+    let response = state
+        .search_client
+        .suggest(with_caller(&headers, request))
+        .await
+        .map_err(|s| GatewayError(s).into_response())?;
+
+    let suggestions: Vec<Value> = response
+        .into_inner()
+        .suggestions
+        .iter()
+        .map(convert::proto_suggestion_to_json)
+        .collect();
+
+    Ok(Json(json!({ "suggestions": suggestions })))
+}
+
+/// `POST /v1/indexes/:index/search/stream` — Executes a search and returns results
+/// incrementally via SSE.
 pub async fn search_stream(
     State(mut state): State<GatewayState>,
+    Path(index): Path<String>,
+    headers: HeaderMap,
     Json(body): Json<Value>,
 ) -> Response {
-    let request = match convert::json_to_proto_search_request(&body) {
+    let mut request = match convert::json_to_proto_search_request(&body) {
         Ok(r) => r,
         Err(e) => return BadRequest(e).into_response(),
     };
+    request.index = index;
 
-    let response = match state.search_client.search_stream(request).await {
+    let response = match state
+        .search_client
+        .search_stream(with_caller(&headers, request))
+        .await
+    {
         Ok(r) => r,
         Err(s) => return GatewayError(s).into_response(),
     };
@@ -1,20 +1,32 @@
 //! `laurus-server` provides a gRPC server and an optional HTTP gateway (gRPC-Gateway)
 //! for the laurus search engine.
 //!
-//! The crate exposes four gRPC services:
+//! The crate exposes six gRPC services:
 //!
 //! * **HealthService** – health-check endpoint.
-//! * **IndexService** – index creation and schema management.
+//! * **IndexService** – index lifecycle, aliases and schema management.
 //! * **DocumentService** – document CRUD and commit operations.
 //! * **SearchService** – lexical, vector, and hybrid search.
+//! * **ReplicationService** – replication status and index snapshots.
+//! * **AdminService** – index snapshots and restore.
+//!
+//! The server hosts many named indexes under the configured data directory.
+//! Each request names the index it addresses; indexes are loaded on first
+//! use and unloaded again when idle. Aliases give indexes additional names
+//! that can be moved atomically, e.g. to a rebuilt index.
 //!
 //! When `http_port` is configured, an HTTP/JSON gateway is started alongside the gRPC server
 //! so that clients can use either protocol.
+//!
+//! A server configured as a replication follower tails the change feed of
+//! a leader and rejects all writes; see [`config::ReplicationConfig`].
 
 pub mod config;
 mod context;
 pub mod convert;
 pub mod gateway;
+mod registry;
+mod replication;
 pub mod server;
 mod service;
 
@@ -0,0 +1,712 @@
+//! Registry of the named indexes hosted by the server.
+//!
+//! Every index lives in its own subdirectory of the configured root
+//! directory (`<data_dir>/<name>/`, laid out as described in
+//! [`context`](crate::context)). Indexes are opened lazily by the first
+//! request that addresses them and, unless the server is a replication
+//! follower, closed again after they have not been used for
+//! `idle_unload_secs`. Closing commits pending changes first, so an
+//! unloaded index only costs disk space. While an index is open on a
+//! leader, an [`ExpiryTask`] purges its expired documents every
+//! `expiry_interval_secs`.
+//!
+//! Aliases are additional names that point at one or more indexes. They are
+//! stored in `<data_dir>/aliases.json` and changed atomically, so an alias can
+//! be moved from an old index to a rebuilt one without clients noticing. An
+//! alias with a single index can be used wherever an index name is accepted;
+//! an alias with several indexes can only be searched.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::sync::{Mutex, RwLock, RwLockWriteGuard};
+use tonic::Status;
+
+use laurus::{BackfillProgress, ByQueryResponse, Engine, ExpiryTask, Schema};
+
+use crate::context;
+use crate::convert::error;
+use crate::service::index::spawn_backfill;
+
+/// Maximum length of an index name.
+const MAX_NAME_LEN: usize = 128;
+
+/// File in the root directory holding the alias table.
+const ALIASES_FILE: &str = "aliases.json";
+
+/// A named index and its engine, if it is open.
+#[derive(Debug)]
+pub struct IndexSlot {
+    /// Name of the index.
+    pub name: String,
+    /// Directory holding the schema and storage of the index.
+    pub data_dir: PathBuf,
+    /// The open engine. `None` while the index is closed or does not exist.
+    pub engine: Arc<RwLock<Option<Engine>>>,
+    /// Copy progress of the running `UpdateField` rebuild, if any.
+    pub field_update: Arc<Mutex<Option<BackfillProgress>>>,
+    /// Task purging expired documents while the index is open.
+    expiry: Mutex<Option<ExpiryTask>>,
+    /// Held while a snapshot is staged and served to a replication
+    /// follower, since snapshots share one staging directory.
+    pub snapshot: Arc<Mutex<()>>,
+    /// Unix time in seconds of the last request addressing the index.
+    last_used: AtomicU64,
+}
+
+impl IndexSlot {
+    fn new(name: &str, data_dir: PathBuf) -> Self {
+        Self {
+            name: name.to_string(),
+            data_dir,
+            engine: Arc::new(RwLock::new(None)),
+            field_update: Arc::new(Mutex::new(None)),
+            expiry: Mutex::new(None),
+            snapshot: Arc::new(Mutex::new(())),
+            last_used: AtomicU64::new(now_secs()),
+        }
+    }
+
+    /// Returns `true` if the index exists on disk.
+    pub fn exists(&self) -> bool {
+        context::index_exists(&self.data_dir)
+    }
+
+    /// Start purging expired documents every `interval`, unless already
+    /// running.
+    ///
+    /// The task purges whichever engine the slot holds, so it keeps working
+    /// when a field update or a restore replaces the engine.
+    async fn start_expiry(&self, interval: Duration) {
+        let mut expiry = self.expiry.lock().await;
+        if expiry.is_some() {
+            return;
+        }
+        let engine = self.engine.clone();
+        *expiry = Some(ExpiryTask::spawn_with(interval, move |options| {
+            let engine = engine.clone();
+            async move {
+                match engine.read().await.as_ref() {
+                    Some(engine) => engine.purge_expired(options).await,
+                    None => Ok(ByQueryResponse::default()),
+                }
+            }
+        }));
+    }
+
+    /// Close the engine held by `guard` and stop purging expired documents.
+    async fn unload(&self, mut guard: RwLockWriteGuard<'_, Option<Engine>>) {
+        *guard = None;
+        let expiry = self.expiry.lock().await.take();
+        // The task reads the engine, so it can only finish once the lock
+        // is released.
+        drop(guard);
+        if let Some(task) = expiry {
+            task.stop().await;
+        }
+    }
+
+    fn touch(&self) {
+        self.last_used.store(now_secs(), Ordering::Relaxed);
+    }
+
+    fn idle_for(&self) -> Duration {
+        Duration::from_secs(now_secs().saturating_sub(self.last_used.load(Ordering::Relaxed)))
+    }
+}
+
+/// Name and state of a hosted index.
+#[derive(Debug, Clone)]
+pub struct IndexInfo {
+    /// Name of the index.
+    pub name: String,
+    /// `true` if the index is currently loaded.
+    pub open: bool,
+}
+
+/// A change to the alias table.
+#[derive(Debug, Clone)]
+pub enum AliasAction {
+    /// Point an alias at the given indexes, creating the alias if needed.
+    Put {
+        /// Name of the alias.
+        alias: String,
+        /// Indexes the alias refers to.
+        indexes: Vec<String>,
+    },
+    /// Remove an alias.
+    Remove {
+        /// Name of the alias.
+        alias: String,
+    },
+}
+
+/// The set of indexes hosted under a root directory.
+#[derive(Debug)]
+pub struct IndexRegistry {
+    root: PathBuf,
+    /// Rejects mutations and skips resuming backfills when `true`.
+    read_only: bool,
+    /// Indexes unused for this long are closed. `None` keeps them open.
+    idle_timeout: Option<Duration>,
+    /// Interval at which open indexes are purged of expired documents.
+    /// `None` disables purging.
+    expiry_interval: Option<Duration>,
+    slots: Mutex<HashMap<String, Arc<IndexSlot>>>,
+    /// Alias name to the names of the indexes it refers to.
+    aliases: std::sync::Mutex<BTreeMap<String, Vec<String>>>,
+}
+
+impl IndexRegistry {
+    /// Create a registry for the indexes under `root`.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - Directory holding one subdirectory per index.
+    /// * `read_only` - Whether the server is a replication follower.
+    ///   Followers never unload indexes, since they are replicated
+    ///   continuously.
+    /// * `idle_unload_secs` - Close indexes unused for this many seconds.
+    ///   `0` keeps every opened index loaded.
+    /// * `expiry_interval_secs` - Purge expired documents of open indexes
+    ///   every this many seconds. `0` disables purging, and followers never
+    ///   purge, since they replicate the leader's deletions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the alias table exists but cannot be read.
+    pub fn new(
+        root: &Path,
+        read_only: bool,
+        idle_unload_secs: u64,
+        expiry_interval_secs: u64,
+    ) -> anyhow::Result<Self> {
+        let idle_timeout =
+            (!read_only && idle_unload_secs > 0).then(|| Duration::from_secs(idle_unload_secs));
+        let expiry_interval = (!read_only && expiry_interval_secs > 0)
+            .then(|| Duration::from_secs(expiry_interval_secs));
+        let aliases_path = root.join(ALIASES_FILE);
+        let aliases = if aliases_path.is_file() {
+            let content = std::fs::read_to_string(&aliases_path)?;
+            serde_json::from_str(&content)
+                .map_err(|e| anyhow::anyhow!("Failed to parse {}: {e}", aliases_path.display()))?
+        } else {
+            BTreeMap::new()
+        };
+        Ok(Self {
+            root: root.to_path_buf(),
+            read_only,
+            idle_timeout,
+            expiry_interval,
+            slots: Mutex::new(HashMap::new()),
+            aliases: std::sync::Mutex::new(aliases),
+        })
+    }
+
+    /// Root directory of the hosted indexes.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the slot of the named index without opening it.
+    ///
+    /// A slot is only added for an index that exists on disk, so unknown
+    /// names are not cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns `INVALID_ARGUMENT` if `name` is not a valid index name, or
+    /// `NOT_FOUND` if the index does not exist.
+    pub async fn slot(&self, name: &str) -> Result<Arc<IndexSlot>, Status> {
+        validate_name(name)?;
+        let mut slots = self.slots.lock().await;
+        if let Some(slot) = slots.get(name) {
+            return Ok(slot.clone());
+        }
+        let data_dir = self.root.join(name);
+        if !context::index_exists(&data_dir) {
+            return Err(Status::not_found(format!("Index '{name}' does not exist")));
+        }
+        let slot = Arc::new(IndexSlot::new(name, data_dir));
+        slots.insert(name.to_string(), slot.clone());
+        Ok(slot)
+    }
+
+    /// Returns the slot of the named index, adding one if the index does not
+    /// exist yet.
+    ///
+    /// Only for operations that create the index: creating it, restoring a
+    /// snapshot into it or copying it from a replication leader.
+    ///
+    /// # Errors
+    ///
+    /// Returns `INVALID_ARGUMENT` if `name` is not a valid index name.
+    pub async fn new_slot(&self, name: &str) -> Result<Arc<IndexSlot>, Status> {
+        validate_name(name)?;
+        let mut slots = self.slots.lock().await;
+        let slot = slots
+            .entry(name.to_string())
+            .or_insert_with(|| Arc::new(IndexSlot::new(name, self.root.join(name))))
+            .clone();
+        Ok(slot)
+    }
+
+    /// Returns the slot of the named index, opening the index if needed.
+    ///
+    /// `name` may also be an alias that refers to a single index. Backfills
+    /// interrupted while the index was closed are resumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NOT_FOUND` if the index does not exist, `INVALID_ARGUMENT`
+    /// if `name` is an alias of several indexes, or an error if the index
+    /// cannot be opened.
+    pub async fn open(&self, name: &str) -> Result<Arc<IndexSlot>, Status> {
+        let name = &self.resolve_single(name)?;
+        let slot = self.slot(name).await?;
+        slot.touch();
+        if slot.engine.read().await.is_some() {
+            return Ok(slot);
+        }
+
+        let mut guard = slot.engine.write().await;
+        if guard.is_none() {
+            if !slot.exists() {
+                return Err(Status::not_found(format!("Index '{name}' does not exist")));
+            }
+            let engine = context::open_index(&slot.data_dir)
+                .await
+                .map_err(error::anyhow_to_status)?;
+            let backfills: Vec<String> = engine
+                .backfills()
+                .into_iter()
+                .filter(|progress| !progress.completed)
+                .map(|progress| progress.field)
+                .collect();
+            *guard = Some(engine);
+            if let Some(interval) = self.expiry_interval {
+                slot.start_expiry(interval).await;
+            }
+            tracing::info!("Opened index '{name}'");
+
+            if !self.read_only {
+                for field in backfills {
+                    spawn_backfill(slot.engine.clone(), field);
+                }
+            }
+        }
+        drop(guard);
+        Ok(slot)
+    }
+
+    /// Open every index that `name` refers to: the indexes of the alias
+    /// `name`, or the index `name` itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first index that cannot be opened.
+    pub async fn open_all(&self, name: &str) -> Result<Vec<Arc<IndexSlot>>, Status> {
+        let mut slots = Vec::new();
+        for index in self.resolve(name) {
+            slots.push(self.open(&index).await?);
+        }
+        Ok(slots)
+    }
+
+    /// Create a new index and open it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ALREADY_EXISTS` if the index exists, or an error if it
+    /// cannot be created.
+    pub async fn create(&self, name: &str, schema: &Schema) -> Result<Arc<IndexSlot>, Status> {
+        if self.is_alias(name) {
+            return Err(Status::already_exists(format!(
+                "'{name}' is already used as an alias"
+            )));
+        }
+        let slot = self.new_slot(name).await?;
+        slot.touch();
+        let mut guard = slot.engine.write().await;
+        if guard.is_some() || slot.exists() {
+            return Err(Status::already_exists(format!(
+                "Index '{name}' already exists"
+            )));
+        }
+        let engine = context::create_index(&slot.data_dir, schema)
+            .await
+            .map_err(error::anyhow_to_status)?;
+        *guard = Some(engine);
+        if let Some(interval) = self.expiry_interval {
+            slot.start_expiry(interval).await;
+        }
+        drop(guard);
+        Ok(slot)
+    }
+
+    /// Close the named index, committing pending changes first.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the index was open.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NOT_FOUND` if the index does not exist,
+    /// `FAILED_PRECONDITION` while a field update rebuilds the index, or an
+    /// error if the commit fails.
+    pub async fn close(&self, name: &str) -> Result<bool, Status> {
+        let slot = self.slot(name).await?;
+        if let Some(progress) = slot.field_update.lock().await.as_ref() {
+            return Err(Status::failed_precondition(format!(
+                "Field '{}' of index '{name}' is being updated",
+                progress.field
+            )));
+        }
+        let guard = slot.engine.write().await;
+        let Some(engine) = guard.as_ref() else {
+            return Ok(false);
+        };
+        engine.commit().await.map_err(error::to_status)?;
+        slot.unload(guard).await;
+        tracing::info!("Closed index '{name}'");
+        Ok(true)
+    }
+
+    /// Close the named index and delete its files.
+    ///
+    /// The index is removed from every alias, and aliases left without an
+    /// index are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NOT_FOUND` if the index does not exist, `INVALID_ARGUMENT`
+    /// if `name` is an alias, `FAILED_PRECONDITION` while a field update
+    /// rebuilds the index, or an error if its directory cannot be removed.
+    pub async fn delete(&self, name: &str) -> Result<(), Status> {
+        if self.is_alias(name) {
+            return Err(Status::invalid_argument(format!(
+                "'{name}' is an alias; remove it with UpdateAliases instead"
+            )));
+        }
+        let slot = self.slot(name).await?;
+        if slot.field_update.lock().await.is_some() {
+            return Err(Status::failed_precondition(format!(
+                "A field of index '{name}' is being updated"
+            )));
+        }
+        let mut guard = slot.engine.write().await;
+        if guard.is_none() && !slot.exists() {
+            return Err(Status::not_found(format!("Index '{name}' does not exist")));
+        }
+        *guard = None;
+        std::fs::remove_dir_all(&slot.data_dir)
+            .map_err(|e| Status::internal(format!("Failed to delete index '{name}': {e}")))?;
+        slot.unload(guard).await;
+        self.slots.lock().await.remove(name);
+        tracing::info!("Deleted index '{name}'");
+
+        let mut aliases = self.aliases.lock().unwrap_or_else(|e| e.into_inner());
+        if aliases
+            .values()
+            .any(|indexes| indexes.iter().any(|i| i == name))
+        {
+            let mut updated = aliases.clone();
+            for indexes in updated.values_mut() {
+                indexes.retain(|index| index != name);
+            }
+            updated.retain(|_, indexes| !indexes.is_empty());
+            self.write_aliases(&updated)?;
+            *aliases = updated;
+        }
+        Ok(())
+    }
+
+    /// Returns the indexes `name` refers to: the indexes of the alias
+    /// `name`, or `name` itself if it is not an alias.
+    pub fn resolve(&self, name: &str) -> Vec<String> {
+        let aliases = self.aliases.lock().unwrap_or_else(|e| e.into_inner());
+        match aliases.get(name) {
+            Some(indexes) => indexes.clone(),
+            None => vec![name.to_string()],
+        }
+    }
+
+    /// Returns the single index `name` refers to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `INVALID_ARGUMENT` if `name` is an alias of several indexes.
+    #[allow(clippy::result_large_err)]
+    pub fn resolve_single(&self, name: &str) -> Result<String, Status> {
+        let mut indexes = self.resolve(name);
+        if indexes.len() != 1 {
+            return Err(Status::invalid_argument(format!(
+                "Alias '{name}' refers to {} indexes; only searches accept it",
+                indexes.len()
+            )));
+        }
+        Ok(indexes.remove(0))
+    }
+
+    /// Returns `true` if `name` is an alias.
+    pub fn is_alias(&self, name: &str) -> bool {
+        let aliases = self.aliases.lock().unwrap_or_else(|e| e.into_inner());
+        aliases.contains_key(name)
+    }
+
+    /// Returns every alias and the indexes it refers to, ordered by name.
+    pub fn aliases(&self) -> BTreeMap<String, Vec<String>> {
+        self.aliases
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Apply `actions` to the alias table atomically.
+    ///
+    /// Either all actions take effect or none does, so moving an alias
+    /// from one index to another never leaves it dangling or pointing at
+    /// both.
+    ///
+    /// # Errors
+    ///
+    /// Returns `INVALID_ARGUMENT` if an alias name is invalid or is the name
+    /// of an index, or if an alias would refer to no index or to another
+    /// alias; `NOT_FOUND` if an index or a removed alias does not exist; or
+    /// an error if the alias table cannot be written.
+    #[allow(clippy::result_large_err)]
+    pub fn update_aliases(&self, actions: &[AliasAction]) -> Result<(), Status> {
+        let mut aliases = self.aliases.lock().unwrap_or_else(|e| e.into_inner());
+        let mut updated = aliases.clone();
+        for action in actions {
+            match action {
+                AliasAction::Put { alias, indexes } => {
+                    validate_name(alias)?;
+                    if context::index_exists(&self.root.join(alias)) {
+                        return Err(Status::invalid_argument(format!(
+                            "'{alias}' is the name of an index"
+                        )));
+                    }
+                    if indexes.is_empty() {
+                        return Err(Status::invalid_argument(format!(
+                            "alias '{alias}' must refer to at least one index"
+                        )));
+                    }
+                    let mut targets: Vec<String> = Vec::with_capacity(indexes.len());
+                    for index in indexes {
+                        validate_name(index)?;
+                        if updated.contains_key(index) || index == alias {
+                            return Err(Status::invalid_argument(format!(
+                                "alias '{alias}' cannot refer to the alias '{index}'"
+                            )));
+                        }
+                        if !context::index_exists(&self.root.join(index)) {
+                            return Err(Status::not_found(format!(
+                                "Index '{index}' does not exist"
+                            )));
+                        }
+                        if !targets.contains(index) {
+                            targets.push(index.clone());
+                        }
+                    }
+                    updated.insert(alias.clone(), targets);
+                }
+                AliasAction::Remove { alias } => {
+                    if updated.remove(alias).is_none() {
+                        return Err(Status::not_found(format!("Alias '{alias}' does not exist")));
+                    }
+                }
+            }
+        }
+        // An alias put earlier in the batch must not be the target of
+        // another alias.
+        if let Some((alias, index)) = updated.iter().find_map(|(alias, indexes)| {
+            indexes
+                .iter()
+                .find(|index| updated.contains_key(*index))
+                .map(|index| (alias, index))
+        }) {
+            return Err(Status::invalid_argument(format!(
+                "alias '{alias}' cannot refer to the alias '{index}'"
+            )));
+        }
+
+        self.write_aliases(&updated)?;
+        *aliases = updated;
+        tracing::info!("Updated aliases: {actions:?}");
+        Ok(())
+    }
+
+    /// Replace the alias table with `aliases` without validating it.
+    ///
+    /// Used by replication followers to mirror the aliases of the leader,
+    /// whose indexes may not have been copied yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the alias table cannot be written.
+    #[allow(clippy::result_large_err)]
+    pub fn replace_aliases(&self, aliases: BTreeMap<String, Vec<String>>) -> Result<(), Status> {
+        let mut current = self.aliases.lock().unwrap_or_else(|e| e.into_inner());
+        if *current != aliases {
+            self.write_aliases(&aliases)?;
+            *current = aliases;
+        }
+        Ok(())
+    }
+
+    /// Persist the alias table by writing a temporary file and renaming it
+    /// over the previous one.
+    #[allow(clippy::result_large_err)]
+    fn write_aliases(&self, aliases: &BTreeMap<String, Vec<String>>) -> Result<(), Status> {
+        let write = || -> std::io::Result<()> {
+            std::fs::create_dir_all(&self.root)?;
+            let path = self.root.join(ALIASES_FILE);
+            let tmp = path.with_extension("json.tmp");
+            std::fs::write(&tmp, serde_json::to_vec_pretty(aliases)?)?;
+            std::fs::rename(&tmp, &path)
+        };
+        write().map_err(|e| Status::internal(format!("Failed to write aliases: {e}")))
+    }
+
+    /// List every index on disk, ordered by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the root directory cannot be read.
+    pub async fn list(&self) -> Result<Vec<IndexInfo>, Status> {
+        let mut names = Vec::new();
+        if self.root.is_dir() {
+            let entries = std::fs::read_dir(&self.root).map_err(|e| {
+                Status::internal(format!("Failed to read {}: {e}", self.root.display()))
+            })?;
+            for entry in entries.flatten() {
+                let Ok(name) = entry.file_name().into_string() else {
+                    continue;
+                };
+                if validate_name(&name).is_ok() && context::index_exists(&entry.path()) {
+                    names.push(name);
+                }
+            }
+        }
+        names.sort();
+
+        let slots: HashMap<String, Arc<IndexSlot>> = self.slots.lock().await.clone();
+        let mut indexes = Vec::with_capacity(names.len());
+        for name in names {
+            let open = match slots.get(&name) {
+                Some(slot) => slot.engine.read().await.is_some(),
+                None => false,
+            };
+            indexes.push(IndexInfo { name, open });
+        }
+        Ok(indexes)
+    }
+
+    /// Close every index that has been idle for longer than the idle
+    /// timeout.
+    ///
+    /// Indexes that are in use, backfilling a field or being rebuilt by a
+    /// field update are kept open.
+    pub async fn unload_idle(&self) {
+        let Some(timeout) = self.idle_timeout else {
+            return;
+        };
+        let slots: Vec<Arc<IndexSlot>> = self.slots.lock().await.values().cloned().collect();
+        for slot in slots {
+            if slot.idle_for() < timeout || slot.field_update.lock().await.is_some() {
+                continue;
+            }
+            // Skip indexes that are serving a request right now.
+            let Ok(guard) = slot.engine.try_write() else {
+                continue;
+            };
+            let Some(engine) = guard.as_ref() else {
+                continue;
+            };
+            if engine
+                .backfills()
+                .iter()
+                .any(|progress| !progress.completed)
+            {
+                continue;
+            }
+            if let Err(e) = engine.commit().await {
+                tracing::error!("Failed to commit idle index '{}': {e}", slot.name);
+                continue;
+            }
+            slot.unload(guard).await;
+            tracing::info!("Unloaded idle index '{}'", slot.name);
+        }
+    }
+
+    /// Periodically unload idle indexes in the background.
+    ///
+    /// Does nothing if idle unloading is disabled.
+    pub fn spawn_idle_unloader(self: &Arc<Self>) {
+        let Some(timeout) = self.idle_timeout else {
+            return;
+        };
+        let registry = Arc::clone(self);
+        let period = (timeout / 4).clamp(Duration::from_secs(1), Duration::from_secs(60));
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(period);
+            loop {
+                ticker.tick().await;
+                registry.unload_idle().await;
+            }
+        });
+    }
+
+    /// Commit pending changes of every open index.
+    pub async fn commit_all(&self) {
+        let slots: Vec<Arc<IndexSlot>> = self.slots.lock().await.values().cloned().collect();
+        for slot in slots {
+            if let Some(engine) = slot.engine.read().await.as_ref() {
+                match engine.commit().await {
+                    Ok(()) => tracing::info!("Committed index '{}'", slot.name),
+                    Err(e) => tracing::error!("Failed to commit index '{}': {e}", slot.name),
+                }
+            }
+        }
+    }
+}
+
+/// Check that `name` can be used as an index directory name.
+///
+/// Names consist of ASCII letters, digits, `-` and `_`, and are at most
+/// 128 characters long.
+///
+/// # Errors
+///
+/// Returns `INVALID_ARGUMENT` describing why the name is rejected.
+#[allow(clippy::result_large_err)]
+pub fn validate_name(name: &str) -> Result<(), Status> {
+    if name.is_empty() {
+        return Err(Status::invalid_argument("index name is required"));
+    }
+    if name.len() > MAX_NAME_LEN {
+        return Err(Status::invalid_argument(format!(
+            "index name must be at most {MAX_NAME_LEN} characters"
+        )));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(Status::invalid_argument(format!(
+            "invalid index name '{name}': only ASCII letters, digits, '-' and '_' are allowed"
+        )));
+    }
+    Ok(())
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
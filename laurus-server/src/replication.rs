@@ -0,0 +1,364 @@
+//! Follower side of WAL-shipping replication.
+//!
+//! A follower keeps a read-only copy of every index hosted by the leader.
+//! It polls `IndexService.ListIndexes` on the leader, starts replicating
+//! indexes as they appear and deletes its copy of indexes deleted on the
+//! leader. The leader's aliases are mirrored from `IndexService.ListAliases`
+//! on every poll. Each index is replicated independently over a shared
+//! connection:
+//!
+//! 1. If it has no index yet, or its position is no longer retained in the
+//!    leader's change feed, it installs a snapshot fetched through
+//!    `ReplicationService.FetchSnapshot`.
+//! 2. It then tails `ReplicationService.StreamChanges` from its last applied
+//!    sequence number and replays each event with
+//!    [`Engine::apply_change`], which also writes it to the local WAL.
+//! 3. Replicated changes are committed every `commit_interval_ms` so that
+//!    they become visible to search.
+//!
+//! Connection failures are retried indefinitely. The leader's position is
+//! polled periodically to report replication lag.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::Context;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tonic::Code;
+use tonic::transport::{Channel, Endpoint};
+
+use laurus::{Engine, SnapshotManifestFile, SnapshotReceiver};
+
+use crate::config::{ReplicationConfig, ReplicationRole};
+use crate::context;
+use crate::convert::{document as doc_convert, schema as schema_convert};
+use crate::proto::laurus::v1::{
+    FetchSnapshotRequest, GetReplicationStatusRequest, ListAliasesRequest, ListIndexesRequest,
+    WatchChangesRequest, index_service_client::IndexServiceClient,
+    replication_service_client::ReplicationServiceClient,
+};
+use crate::registry::{IndexRegistry, IndexSlot};
+
+/// Delay before reconnecting after the leader stream fails.
+const RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Replication role and leader position shared with the gRPC services.
+#[derive(Debug)]
+pub struct ReplicationState {
+    role: ReplicationRole,
+    leader: Option<String>,
+    /// Last sequence number known to exist on the leader, per index.
+    leader_seqs: Mutex<HashMap<String, u64>>,
+}
+
+impl ReplicationState {
+    /// Create the state for the given configuration.
+    pub fn new(config: &ReplicationConfig) -> Self {
+        Self {
+            role: config.role,
+            leader: config.leader.clone(),
+            leader_seqs: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Role of this server.
+    pub fn role(&self) -> ReplicationRole {
+        self.role
+    }
+
+    /// Returns `true` if this server is a read-only follower.
+    pub fn is_follower(&self) -> bool {
+        self.role == ReplicationRole::Follower
+    }
+
+    /// Address of the leader, if this server is a follower.
+    pub fn leader(&self) -> Option<&str> {
+        self.leader.as_deref()
+    }
+
+    /// Last sequence number known to exist on the leader for `index`.
+    pub fn leader_seq(&self, index: &str) -> u64 {
+        let seqs = self.leader_seqs.lock().unwrap_or_else(|e| e.into_inner());
+        seqs.get(index).copied().unwrap_or(0)
+    }
+
+    fn observe_leader_seq(&self, index: &str, seq: u64) {
+        let mut seqs = self.leader_seqs.lock().unwrap_or_else(|e| e.into_inner());
+        let known = seqs.entry(index.to_string()).or_default();
+        *known = (*known).max(seq);
+    }
+
+    fn forget(&self, index: &str) {
+        let mut seqs = self.leader_seqs.lock().unwrap_or_else(|e| e.into_inner());
+        seqs.remove(index);
+    }
+}
+
+/// Replicates every index of the leader into `registry` until the process
+/// exits.
+///
+/// # Arguments
+///
+/// * `registry` - The indexes shared with the gRPC services. Engines are
+///   replaced whenever a snapshot is installed.
+/// * `config` - Replication settings. `leader` must be set.
+/// * `state` - Shared replication state updated with the leader position.
+pub async fn run_follower(
+    registry: Arc<IndexRegistry>,
+    config: ReplicationConfig,
+    state: Arc<ReplicationState>,
+) {
+    let Some(leader) = config.leader.clone() else {
+        tracing::error!("Replication role is follower but no leader address is configured");
+        return;
+    };
+    let channel = match Endpoint::from_shared(leader.clone()) {
+        Ok(endpoint) => endpoint.connect_lazy(),
+        Err(e) => {
+            tracing::error!("Invalid leader address {leader}: {e}");
+            return;
+        }
+    };
+    tracing::info!("Following leader at {leader}");
+
+    let mut indexes = IndexServiceClient::new(channel.clone());
+    let mut followers: HashMap<String, JoinHandle<()>> = HashMap::new();
+    let mut ticker = tokio::time::interval(Duration::from_secs(config.status_interval_secs.max(1)));
+    loop {
+        ticker.tick().await;
+        let names: Vec<String> = match indexes.list_indexes(ListIndexesRequest {}).await {
+            Ok(response) => response
+                .into_inner()
+                .indexes
+                .into_iter()
+                .map(|info| info.name)
+                .collect(),
+            Err(status) => {
+                tracing::warn!("Failed to list the indexes of {leader}: {status}");
+                continue;
+            }
+        };
+
+        match indexes.list_aliases(ListAliasesRequest {}).await {
+            Ok(response) => {
+                let aliases = response
+                    .into_inner()
+                    .aliases
+                    .into_iter()
+                    .map(|alias| (alias.name, alias.indexes))
+                    .collect();
+                if let Err(status) = registry.replace_aliases(aliases) {
+                    tracing::warn!("Failed to update the aliases: {}", status.message());
+                }
+            }
+            Err(status) => tracing::warn!("Failed to list the aliases of {leader}: {status}"),
+        }
+
+        // Stop replicating indexes deleted on the leader.
+        let removed: Vec<String> = followers
+            .keys()
+            .filter(|name| !names.contains(name))
+            .cloned()
+            .collect();
+        for name in removed {
+            if let Some(handle) = followers.remove(&name) {
+                handle.abort();
+                let _ = handle.await;
+            }
+            state.forget(&name);
+            match registry.delete(&name).await {
+                Ok(()) => tracing::info!("Index '{name}' was deleted on the leader"),
+                Err(status) => tracing::warn!("Failed to delete index '{name}': {status}"),
+            }
+        }
+
+        for name in names {
+            if followers.contains_key(&name) {
+                continue;
+            }
+            let slot = match registry.new_slot(&name).await {
+                Ok(slot) => slot,
+                Err(status) => {
+                    tracing::warn!("Skipping index '{name}' of the leader: {status}");
+                    continue;
+                }
+            };
+            let handle = tokio::spawn(follow_index(
+                registry.clone(),
+                slot,
+                channel.clone(),
+                config.clone(),
+                state.clone(),
+            ));
+            followers.insert(name, handle);
+        }
+    }
+}
+
+/// Replicates one index of the leader, reconnecting after failures.
+async fn follow_index(
+    registry: Arc<IndexRegistry>,
+    slot: Arc<IndexSlot>,
+    channel: Channel,
+    config: ReplicationConfig,
+    state: Arc<ReplicationState>,
+) {
+    loop {
+        match follow(&registry, &slot, channel.clone(), &config, &state).await {
+            Ok(()) => tracing::warn!(
+                "Leader closed the change stream of index '{}', reconnecting",
+                slot.name
+            ),
+            Err(e) => tracing::warn!("Replication of index '{}' failed: {e:#}", slot.name),
+        }
+        tokio::time::sleep(RETRY_DELAY).await;
+    }
+}
+
+/// Applies the leader's changes to one index until the stream ends.
+async fn follow(
+    registry: &IndexRegistry,
+    slot: &IndexSlot,
+    channel: Channel,
+    config: &ReplicationConfig,
+    state: &ReplicationState,
+) -> anyhow::Result<()> {
+    let index = slot.name.clone();
+    let engine = slot.engine.as_ref();
+    let mut replication = ReplicationServiceClient::new(channel);
+
+    let since_seq = if slot.exists() {
+        registry.open(&index).await?;
+        engine.read().await.as_ref().map(Engine::last_seq)
+    } else {
+        None
+    };
+    let watch = |since_seq| WatchChangesRequest {
+        since_seq,
+        index: index.clone(),
+    };
+    let mut stream = match since_seq {
+        Some(since_seq) => match replication.stream_changes(watch(since_seq)).await {
+            Ok(response) => response.into_inner(),
+            // The leader no longer retains changes after our position.
+            Err(status) if status.code() == Code::InvalidArgument => {
+                tracing::info!(
+                    "Seq {since_seq} of index '{index}' is no longer retained by the leader"
+                );
+                let since_seq = install_snapshot(&mut replication, slot).await?;
+                replication
+                    .stream_changes(watch(since_seq))
+                    .await?
+                    .into_inner()
+            }
+            Err(status) => return Err(status.into()),
+        },
+        None => {
+            let since_seq = install_snapshot(&mut replication, slot).await?;
+            replication
+                .stream_changes(watch(since_seq))
+                .await?
+                .into_inner()
+        }
+    };
+
+    let mut commit_ticker =
+        tokio::time::interval(Duration::from_millis(config.commit_interval_ms.max(1)));
+    let mut status_ticker =
+        tokio::time::interval(Duration::from_secs(config.status_interval_secs.max(1)));
+    let mut dirty = false;
+
+    loop {
+        tokio::select! {
+            message = stream.message() => {
+                let Some(event) = message? else {
+                    commit(engine, dirty).await?;
+                    return Ok(());
+                };
+                state.observe_leader_seq(&index, event.seq);
+                let event = doc_convert::change_event_from_proto(&event);
+                let guard = engine.read().await;
+                let engine = guard.as_ref().context("Replicated index was closed")?;
+                dirty |= engine.apply_change(event).await?;
+            }
+            _ = commit_ticker.tick() => {
+                commit(engine, dirty).await?;
+                dirty = false;
+            }
+            _ = status_ticker.tick() => {
+                let request = GetReplicationStatusRequest { index: index.clone() };
+                match replication.get_replication_status(request).await {
+                    Ok(response) => state.observe_leader_seq(&index, response.into_inner().last_seq),
+                    Err(status) => tracing::debug!("Failed to poll leader status: {status}"),
+                }
+            }
+        }
+    }
+}
+
+/// Commits replicated changes so that they become visible to search.
+async fn commit(engine: &RwLock<Option<Engine>>, dirty: bool) -> anyhow::Result<()> {
+    if !dirty {
+        return Ok(());
+    }
+    if let Some(engine) = engine.read().await.as_ref() {
+        engine.commit().await?;
+    }
+    Ok(())
+}
+
+/// Fetches a snapshot of an index from the leader and replaces the local
+/// copy with it.
+///
+/// Returns the sequence number the snapshot is positioned at.
+async fn install_snapshot(
+    replication: &mut ReplicationServiceClient<Channel>,
+    slot: &IndexSlot,
+) -> anyhow::Result<u64> {
+    tracing::info!("Fetching snapshot of index '{}' from leader", slot.name);
+    let mut stream = replication
+        .fetch_snapshot(FetchSnapshotRequest {
+            index: slot.name.clone(),
+        })
+        .await?
+        .into_inner();
+
+    // The files are written to staging storage as they arrive, so the
+    // current index keeps serving until the snapshot is complete.
+    let staging = context::create_staging_storage(&slot.data_dir)?;
+    let mut receiver = SnapshotReceiver::new(staging.as_ref())?;
+    let mut seq = 0;
+    let mut schema = None;
+    while let Some(chunk) = stream.message().await? {
+        seq = chunk.seq;
+        if let Some(proto_schema) = &chunk.schema {
+            schema = Some(schema_convert::from_proto(proto_schema).map_err(anyhow::Error::msg)?);
+        }
+        // Schema-only chunk of a snapshot without files.
+        if chunk.file_name.is_empty() {
+            continue;
+        }
+        let file = SnapshotManifestFile {
+            name: chunk.file_name,
+            size: chunk.file_size,
+            checksum: chunk.checksum,
+        };
+        receiver.write_chunk(&file, chunk.offset, &chunk.data)?;
+    }
+    let files = receiver.finish()?;
+    drop(staging);
+    let schema = schema.context("Snapshot stream did not include a schema")?;
+
+    let mut guard = slot.engine.write().await;
+    context::install_staged_index(&mut guard, &slot.data_dir, &schema).await?;
+
+    tracing::info!(
+        "Installed snapshot of index '{}' at seq {seq} ({} files, {} bytes)",
+        slot.name,
+        files.len(),
+        files.iter().map(|file| file.size).sum::<u64>()
+    );
+    Ok(seq)
+}
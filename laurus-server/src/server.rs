@@ -1,14 +1,14 @@
 //! Server bootstrap logic.
 //!
-//! The [`run`] function initialises logging, opens or creates the search index,
-//! starts the gRPC server (and optionally the HTTP gateway), and waits for a
-//! shutdown signal (`Ctrl+C`).
+//! The [`run`] function initialises logging, sets up the registry of hosted
+//! indexes, starts the gRPC server (and optionally the HTTP gateway), starts
+//! replication when configured as a follower, and waits for a shutdown
+//! signal (`Ctrl+C`).
 
 use std::net::SocketAddr;
 use std::sync::Arc;
 
 use tokio::net::TcpListener;
-use tokio::sync::RwLock;
 use tonic::transport::{Endpoint, Server};
 use tracing_subscriber::EnvFilter;
 
@@ -16,11 +16,16 @@ use crate::config::Config;
 use crate::context;
 use crate::gateway;
 use crate::proto::laurus::v1::{
-    document_service_server::DocumentServiceServer, health_service_server::HealthServiceServer,
-    index_service_server::IndexServiceServer, search_service_server::SearchServiceServer,
+    admin_service_server::AdminServiceServer, document_service_server::DocumentServiceServer,
+    health_service_server::HealthServiceServer, index_service_server::IndexServiceServer,
+    replication_service_server::ReplicationServiceServer,
+    search_service_server::SearchServiceServer,
 };
+use crate::registry::IndexRegistry;
+use crate::replication::{self, ReplicationState};
 use crate::service::{
-    document::DocumentService, health::HealthService, index::IndexService, search::SearchService,
+    admin::AdminService, document::DocumentService, health::HealthService, index::IndexService,
+    replication::ReplicationService, search::SearchService,
 };
 
 /// Starts the server based on the given configuration.
@@ -29,7 +34,7 @@ use crate::service::{
 /// started concurrently. Otherwise, only the gRPC server is started.
 ///
 /// The function blocks until a shutdown signal (`Ctrl+C`) is received.
-/// On shutdown it commits any pending index changes before exiting.
+/// On shutdown it commits pending changes of every open index before exiting.
 ///
 /// # Arguments
 ///
@@ -54,32 +59,61 @@ pub async fn run(config: &Config) -> anyhow::Result<()> {
 
     tracing::info!("Laurus server starting");
     tracing::info!("Data directory: {}", config.index.data_dir.display());
+    if context::index_exists(&config.index.data_dir) {
+        tracing::warn!(
+            "{} holds a single index in the layout of an older version. Move its \
+             contents into a subdirectory (e.g. {}) to serve it as a named index.",
+            config.index.data_dir.display(),
+            config.index.data_dir.join("default").display()
+        );
+    }
 
-    // Open an existing index. If none exists, start without an index.
-    let engine = match context::open_index(&config.index.data_dir).await {
-        Ok(engine) => {
-            tracing::info!("Opened existing index");
-            Some(engine)
-        }
-        Err(_) => {
-            tracing::info!("No existing index found. Use CreateIndex RPC to create one.");
-            None
+    let replication_state = Arc::new(ReplicationState::new(&config.replication));
+    let read_only = replication_state.is_follower();
+    let registry = Arc::new(IndexRegistry::new(
+        &config.index.data_dir,
+        read_only,
+        config.index.idle_unload_secs,
+        config.index.expiry_interval_secs,
+    )?);
+
+    if read_only {
+        tracing::info!("Indexes will be copied from the leader.");
+        tokio::spawn(replication::run_follower(
+            registry.clone(),
+            config.replication.clone(),
+            replication_state.clone(),
+        ));
+    } else {
+        // Reopen indexes with backfills interrupted by the previous shutdown
+        // so that the backfills resume.
+        for info in registry.list().await? {
+            if let Err(status) = resume_backfills(&registry, &info.name).await {
+                tracing::error!("Failed to open index '{}': {}", info.name, status.message());
+            }
         }
-    };
-
-    let engine = Arc::new(RwLock::new(engine));
-    let data_dir = config.index.data_dir.clone();
+        registry.spawn_idle_unloader();
+    }
 
     let health_service = HealthService;
     let document_service = DocumentService {
-        engine: engine.clone(),
+        registry: registry.clone(),
+        read_only,
     };
     let index_service = IndexService {
-        engine: engine.clone(),
-        data_dir,
+        registry: registry.clone(),
+        read_only,
     };
     let search_service = SearchService {
-        engine: engine.clone(),
+        registry: registry.clone(),
+    };
+    let replication_service = ReplicationService {
+        registry: registry.clone(),
+        state: replication_state,
+    };
+    let admin_service = AdminService {
+        registry: registry.clone(),
+        read_only,
     };
 
     let grpc_addr: SocketAddr = format!("{}:{}", config.server.host, config.server.port).parse()?;
@@ -89,7 +123,9 @@ pub async fn run(config: &Config) -> anyhow::Result<()> {
         .add_service(HealthServiceServer::new(health_service))
         .add_service(DocumentServiceServer::new(document_service))
         .add_service(IndexServiceServer::new(index_service))
-        .add_service(SearchServiceServer::new(search_service));
+        .add_service(SearchServiceServer::new(search_service))
+        .add_service(ReplicationServiceServer::new(replication_service))
+        .add_service(AdminServiceServer::new(admin_service));
 
     if let Some(http_port) = config.server.http_port {
         // Also start the gRPC Gateway (HTTP server) concurrently.
@@ -114,34 +150,35 @@ pub async fn run(config: &Config) -> anyhow::Result<()> {
                     tracing::error!("HTTP gateway error: {e}");
                 }
             }
-            _ = shutdown_signal(engine.clone()) => {}
+            _ = shutdown_signal(registry.clone()) => {}
         }
     } else {
         // Start only the gRPC server (legacy behavior).
         grpc_server
-            .serve_with_shutdown(grpc_addr, shutdown_signal(engine.clone()))
+            .serve_with_shutdown(grpc_addr, shutdown_signal(registry.clone()))
             .await?;
     }
 
     Ok(())
 }
 
-/// Waits for a shutdown signal (Ctrl+C) and commits pending changes before exiting.
-async fn shutdown_signal(engine: Arc<RwLock<Option<laurus::Engine>>>) {
+/// Opens `name` if it has backfilled fields, which resumes backfills that
+/// did not complete. The index is unloaded again once it is idle.
+async fn resume_backfills(registry: &IndexRegistry, name: &str) -> Result<(), tonic::Status> {
+    if context::has_backfills(&registry.root().join(name)) {
+        registry.open(name).await?;
+    }
+    Ok(())
+}
+
+/// Waits for a shutdown signal (Ctrl+C) and commits pending changes of
+/// every open index before exiting.
+async fn shutdown_signal(registry: Arc<IndexRegistry>) {
     tokio::signal::ctrl_c()
         .await
         .expect("failed to install Ctrl+C handler");
 
     tracing::info!("Shutdown signal received, committing pending changes...");
-
-    let guard = engine.read().await;
-    if let Some(engine) = guard.as_ref() {
-        if let Err(e) = engine.commit().await {
-            tracing::error!("Failed to commit on shutdown: {e}");
-        } else {
-            tracing::info!("Committed successfully");
-        }
-    }
-
+    registry.commit_all().await;
     tracing::info!("Server shutting down");
 }
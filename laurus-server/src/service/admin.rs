@@ -0,0 +1,116 @@
+//! Administrative gRPC service.
+//!
+//! Creates point-in-time snapshots of a hosted index in a backup directory on
+//! the server and restores an index from one.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use crate::context;
+use crate::convert::error;
+use crate::proto::laurus::v1::{
+    CreateSnapshotRequest, CreateSnapshotResponse, RestoreSnapshotRequest, RestoreSnapshotResponse,
+    admin_service_server::AdminService as AdminServiceTrait,
+};
+use crate::registry::IndexRegistry;
+
+/// gRPC AdminService implementation.
+#[derive(Clone)]
+pub struct AdminService {
+    /// The hosted indexes. A restore holds the `field_update` lock of the
+    /// index so that it does not run concurrently with a field update.
+    pub registry: Arc<IndexRegistry>,
+    /// Rejects restoring a snapshot when `true` (replication followers).
+    pub read_only: bool,
+}
+
+#[tonic::async_trait]
+impl AdminServiceTrait for AdminService {
+    /// Commits and copies the committed index into a backup directory.
+    async fn create_snapshot(
+        &self,
+        request: Request<CreateSnapshotRequest>,
+    ) -> Result<Response<CreateSnapshotResponse>, Status> {
+        let req = request.into_inner();
+        if req.path.is_empty() {
+            return Err(Status::invalid_argument("path is required"));
+        }
+        let backup =
+            context::backup_storage(Path::new(&req.path)).map_err(error::anyhow_to_status)?;
+
+        let slot = self.registry.open(&req.index).await?;
+        let guard = slot.engine.read().await;
+        let engine = guard
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+        let report = engine
+            .snapshot_to(backup.as_ref(), req.incremental)
+            .await
+            .map_err(error::to_status)?;
+
+        tracing::info!(
+            "Snapshot of index '{}' at seq {} written to {} ({} files copied, {} unchanged)",
+            slot.name,
+            report.manifest.seq,
+            req.path,
+            report.copied_files,
+            report.unchanged_files
+        );
+        Ok(Response::new(CreateSnapshotResponse {
+            seq: report.manifest.seq,
+            file_count: report.manifest.files.len() as u64,
+            copied_files: report.copied_files as u64,
+            copied_bytes: report.copied_bytes,
+            unchanged_files: report.unchanged_files as u64,
+            removed_files: report.removed_files as u64,
+        }))
+    }
+
+    /// Replaces an index with a snapshot from a backup directory, creating
+    /// the index if it does not exist.
+    async fn restore_snapshot(
+        &self,
+        request: Request<RestoreSnapshotRequest>,
+    ) -> Result<Response<RestoreSnapshotResponse>, Status> {
+        if self.read_only {
+            return Err(Status::failed_precondition(
+                "This server is a read-only replication follower",
+            ));
+        }
+        let req = request.into_inner();
+        if req.path.is_empty() {
+            return Err(Status::invalid_argument("path is required"));
+        }
+
+        let index = self.registry.resolve_single(&req.index)?;
+        let slot = self.registry.new_slot(&index).await?;
+        let running = slot.field_update.lock().await;
+        if let Some(progress) = running.as_ref() {
+            return Err(Status::failed_precondition(format!(
+                "Field '{}' is being updated",
+                progress.field
+            )));
+        }
+
+        // The snapshot is copied and verified while the current index keeps
+        // serving requests.
+        let manifest = context::stage_snapshot(&slot.data_dir, Path::new(&req.path))
+            .map_err(error::anyhow_to_status)?;
+
+        let mut guard = slot.engine.write().await;
+        context::install_staged_index(&mut guard, &slot.data_dir, &manifest.schema)
+            .await
+            .map_err(error::anyhow_to_status)?;
+        drop(running);
+
+        tracing::info!(
+            "Index '{}' restored from {} at seq {}",
+            slot.name,
+            req.path,
+            manifest.seq
+        );
+        Ok(Response::new(RestoreSnapshotResponse { seq: manifest.seq }))
+    }
+}
@@ -1,31 +1,51 @@
 //! Document CRUD and commit gRPC service.
 //!
 //! Provides RPCs for inserting, updating, retrieving, and deleting documents,
-//! as well as explicitly committing pending changes to durable storage.
+//! as well as explicitly committing pending changes to durable storage,
+//! applying atomic transactions and streaming index mutations.
+//!
+//! On indexes partitioned by tenant, document RPCs are scoped to the tenant
+//! named in the request metadata (see [`tenant`](crate::convert::tenant)).
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use tokio::sync::RwLock;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
 
 use laurus::Engine;
 
-use crate::convert::{document as doc_convert, error};
+use crate::convert::{document as doc_convert, error, identity, tenant};
 use crate::proto::laurus::v1::{
-    AddDocumentRequest, AddDocumentResponse, CommitRequest, CommitResponse, DeleteDocumentsRequest,
-    DeleteDocumentsResponse, GetDocumentsRequest, GetDocumentsResponse, PutDocumentRequest,
-    PutDocumentResponse, document_service_server::DocumentService as DocumentServiceTrait,
+    AddDocumentRequest, AddDocumentResponse, ChangeEvent, CommitRequest, CommitResponse,
+    DeleteDocumentsRequest, DeleteDocumentsResponse, ExecuteTransactionRequest,
+    ExecuteTransactionResponse, GetDocumentsRequest, GetDocumentsResponse, PutDocumentRequest,
+    PutDocumentResponse, TransactionDocument, WatchChangesRequest,
+    document_service_server::DocumentService as DocumentServiceTrait,
+    transaction_operation::Operation,
 };
+use crate::registry::IndexRegistry;
 
 /// gRPC DocumentService implementation.
 #[derive(Clone)]
 pub struct DocumentService {
-    /// Shared, mutable reference to the current search engine instance.
-    /// `None` when no index has been created yet.
-    pub engine: Arc<RwLock<Option<Engine>>>,
+    /// The hosted indexes.
+    pub registry: Arc<IndexRegistry>,
+    /// Rejects every mutating RPC when `true` (replication followers).
+    pub read_only: bool,
 }
 
 impl DocumentService {
+    #[allow(clippy::result_large_err)]
+    fn check_writable(&self) -> Result<(), Status> {
+        if self.read_only {
+            return Err(Status::failed_precondition(
+                "This server is a read-only replication follower",
+            ));
+        }
+        Ok(())
+    }
+
     #[allow(clippy::result_large_err)]
     fn get_engine_ref(guard: &Option<Engine>) -> Result<&Engine, Status> {
         guard
@@ -36,11 +56,13 @@ impl DocumentService {
 
 #[tonic::async_trait]
 impl DocumentServiceTrait for DocumentService {
-    /// Inserts or replaces a document with the given ID.
+    /// Inserts or replaces a document with the given ID, optionally with a TTL.
     async fn put_document(
         &self,
         request: Request<PutDocumentRequest>,
     ) -> Result<Response<PutDocumentResponse>, Status> {
+        self.check_writable()?;
+        let tenant = tenant::from_metadata(request.metadata());
         let req = request.into_inner();
         let doc = req
             .document
@@ -48,21 +70,29 @@ impl DocumentServiceTrait for DocumentService {
             .ok_or_else(|| Status::invalid_argument("document is required"))?;
         let doc = doc_convert::from_proto(doc);
 
-        let guard = self.engine.read().await;
+        let slot = self.registry.open(&req.index).await?;
+
+        let guard = slot.engine.read().await;
         let engine = Self::get_engine_ref(&guard)?;
-        engine
-            .put_document(&req.id, doc)
-            .await
-            .map_err(error::to_status)?;
+        let ttl = req.ttl_seconds.map(Duration::from_secs);
+        match (tenant::scope(engine, tenant.as_deref())?, ttl) {
+            (Some(tenant), Some(ttl)) => tenant.put_document_with_ttl(&req.id, doc, ttl).await,
+            (Some(tenant), None) => tenant.put_document(&req.id, doc).await,
+            (None, Some(ttl)) => engine.put_document_with_ttl(&req.id, doc, ttl).await,
+            (None, None) => engine.put_document(&req.id, doc).await,
+        }
+        .map_err(error::to_status)?;
 
         Ok(Response::new(PutDocumentResponse {}))
     }
 
-    /// Adds a new document. Fails if a document with the same ID already exists.
+    /// Adds a new document chunk, optionally with a TTL.
     async fn add_document(
         &self,
         request: Request<AddDocumentRequest>,
     ) -> Result<Response<AddDocumentResponse>, Status> {
+        self.check_writable()?;
+        let tenant = tenant::from_metadata(request.metadata());
         let req = request.into_inner();
         let doc = req
             .document
@@ -70,29 +100,41 @@ impl DocumentServiceTrait for DocumentService {
             .ok_or_else(|| Status::invalid_argument("document is required"))?;
         let doc = doc_convert::from_proto(doc);
 
-        let guard = self.engine.read().await;
+        let slot = self.registry.open(&req.index).await?;
+
+        let guard = slot.engine.read().await;
         let engine = Self::get_engine_ref(&guard)?;
-        engine
-            .add_document(&req.id, doc)
-            .await
-            .map_err(error::to_status)?;
+        let ttl = req.ttl_seconds.map(Duration::from_secs);
+        match (tenant::scope(engine, tenant.as_deref())?, ttl) {
+            (Some(tenant), Some(ttl)) => tenant.add_document_with_ttl(&req.id, doc, ttl).await,
+            (Some(tenant), None) => tenant.add_document(&req.id, doc).await,
+            (None, Some(ttl)) => engine.add_document_with_ttl(&req.id, doc, ttl).await,
+            (None, None) => engine.add_document(&req.id, doc).await,
+        }
+        .map_err(error::to_status)?;
 
         Ok(Response::new(AddDocumentResponse {}))
     }
 
-    /// Retrieves documents matching the given ID.
+    /// Retrieves the documents matching the given ID that are visible to
+    /// the caller.
     async fn get_documents(
         &self,
         request: Request<GetDocumentsRequest>,
     ) -> Result<Response<GetDocumentsResponse>, Status> {
+        let identity = identity::from_metadata(request.metadata());
+        let tenant = tenant::from_metadata(request.metadata());
         let req = request.into_inner();
 
-        let guard = self.engine.read().await;
+        let slot = self.registry.open(&req.index).await?;
+
+        let guard = slot.engine.read().await;
         let engine = Self::get_engine_ref(&guard)?;
-        let docs = engine
-            .get_documents(&req.id)
-            .await
-            .map_err(error::to_status)?;
+        let docs = match tenant::scope(engine, tenant.as_deref())? {
+            Some(tenant) => tenant.get_documents_as(&req.id, &identity).await,
+            None => engine.get_documents_as(&req.id, &identity).await,
+        }
+        .map_err(error::to_status)?;
 
         let documents = docs.iter().map(doc_convert::to_proto).collect();
         Ok(Response::new(GetDocumentsResponse { documents }))
@@ -103,14 +145,19 @@ impl DocumentServiceTrait for DocumentService {
         &self,
         request: Request<DeleteDocumentsRequest>,
     ) -> Result<Response<DeleteDocumentsResponse>, Status> {
+        self.check_writable()?;
+        let tenant = tenant::from_metadata(request.metadata());
         let req = request.into_inner();
 
-        let guard = self.engine.read().await;
+        let slot = self.registry.open(&req.index).await?;
+
+        let guard = slot.engine.read().await;
         let engine = Self::get_engine_ref(&guard)?;
-        engine
-            .delete_documents(&req.id)
-            .await
-            .map_err(error::to_status)?;
+        match tenant::scope(engine, tenant.as_deref())? {
+            Some(tenant) => tenant.delete_documents(&req.id).await,
+            None => engine.delete_documents(&req.id).await,
+        }
+        .map_err(error::to_status)?;
 
         Ok(Response::new(DeleteDocumentsResponse {}))
     }
@@ -118,12 +165,102 @@ impl DocumentServiceTrait for DocumentService {
     /// Flushes all pending changes to durable storage.
     async fn commit(
         &self,
-        _request: Request<CommitRequest>,
+        request: Request<CommitRequest>,
     ) -> Result<Response<CommitResponse>, Status> {
-        let guard = self.engine.read().await;
+        self.check_writable()?;
+        let req = request.into_inner();
+        let slot = self.registry.open(&req.index).await?;
+        let guard = slot.engine.read().await;
         let engine = Self::get_engine_ref(&guard)?;
         engine.commit().await.map_err(error::to_status)?;
 
         Ok(Response::new(CommitResponse {}))
     }
+
+    /// Applies a set of operations atomically through an engine transaction.
+    async fn execute_transaction(
+        &self,
+        request: Request<ExecuteTransactionRequest>,
+    ) -> Result<Response<ExecuteTransactionResponse>, Status> {
+        self.check_writable()?;
+        let req = request.into_inner();
+
+        let slot = self.registry.open(&req.index).await?;
+
+        let guard = slot.engine.read().await;
+        let engine = Self::get_engine_ref(&guard)?;
+        if engine.schema().tenant_field.is_some() {
+            return Err(Status::failed_precondition(
+                "Transactions are not supported on indexes partitioned by tenant",
+            ));
+        }
+        let mut txn = engine.begin();
+        for op in req.operations {
+            match op.operation {
+                Some(Operation::Put(TransactionDocument { id, document })) => {
+                    let doc = document
+                        .as_ref()
+                        .ok_or_else(|| Status::invalid_argument("document is required"))?;
+                    txn.put_document(&id, doc_convert::from_proto(doc));
+                }
+                Some(Operation::Add(TransactionDocument { id, document })) => {
+                    let doc = document
+                        .as_ref()
+                        .ok_or_else(|| Status::invalid_argument("document is required"))?;
+                    txn.add_document(&id, doc_convert::from_proto(doc));
+                }
+                Some(Operation::DeleteId(id)) => {
+                    txn.delete_documents(&id);
+                }
+                None => return Err(Status::invalid_argument("operation is required")),
+            }
+        }
+        txn.commit().await.map_err(error::to_status)?;
+
+        Ok(Response::new(ExecuteTransactionResponse {}))
+    }
+
+    type WatchChangesStream = ReceiverStream<Result<ChangeEvent, Status>>;
+
+    /// Streams the index mutations newer than the requested sequence number
+    /// that are visible to the caller, scoped to the caller's tenant.
+    ///
+    /// Replication followers receive the unfiltered feed from
+    /// `ReplicationService.StreamChanges` instead.
+    async fn watch_changes(
+        &self,
+        request: Request<WatchChangesRequest>,
+    ) -> Result<Response<Self::WatchChangesStream>, Status> {
+        let identity = identity::from_metadata(request.metadata());
+        let tenant = tenant::from_metadata(request.metadata());
+        let req = request.into_inner();
+
+        let mut subscription = {
+            let slot = self.registry.open(&req.index).await?;
+            let guard = slot.engine.read().await;
+            let engine = Self::get_engine_ref(&guard)?;
+            match tenant::scope(engine, tenant.as_deref())? {
+                Some(tenant) => tenant.changes_as(req.since_seq, &identity),
+                None => engine.changes_as(req.since_seq, &identity),
+            }
+            .map_err(error::to_status)?
+        };
+
+        let (tx, rx) = tokio::sync::mpsc::channel(64);
+        tokio::spawn(async move {
+            loop {
+                let item = match subscription.next().await {
+                    Ok(Some(event)) => Ok(doc_convert::change_event_to_proto(&event)),
+                    Ok(None) => break,
+                    Err(e) => Err(error::to_status(e)),
+                };
+                let failed = item.is_err();
+                if tx.send(item).await.is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
 }
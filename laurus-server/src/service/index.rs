@@ -1,41 +1,67 @@
 //! Index management gRPC service.
 //!
-//! Handles index creation, metadata retrieval, and schema inspection through
-//! the `IndexService` gRPC trait.
+//! Handles the lifecycle of the hosted indexes (create, list, open, close,
+//! delete), their aliases, metadata retrieval, and schema inspection through the
+//! `IndexService` gRPC trait. Fields added with a backfill source are
+//! backfilled for existing documents by a background task, and field
+//! updates rebuild the index in the background before swapping it in.
+//! Resource sets used by schema analyzers are managed here as well; changing
+//! one reloads the analyzers using it without reopening the index.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use tokio::sync::RwLock;
+use anyhow::Context;
+use tokio::sync::{Mutex, RwLock};
 use tonic::{Request, Response, Status};
 
-use laurus::Engine;
+use laurus::{BackfillProgress, DEFAULT_BACKFILL_BATCH_SIZE, Engine, FieldUpdate};
 
 use crate::context;
-use crate::convert::{error, schema as schema_convert};
+use crate::convert::{error, resource as resource_convert, schema as schema_convert};
 use crate::proto::laurus::v1::{
-    AddFieldRequest, AddFieldResponse, CreateIndexRequest, CreateIndexResponse, DeleteFieldRequest,
-    DeleteFieldResponse, GetIndexRequest, GetIndexResponse, GetSchemaRequest, GetSchemaResponse,
-    VectorFieldStats, index_service_server::IndexService as IndexServiceTrait,
+    self, AddFieldRequest, AddFieldResponse, Alias, CloseIndexRequest, CloseIndexResponse,
+    CreateIndexRequest, CreateIndexResponse, DeleteFieldRequest, DeleteFieldResponse,
+    DeleteIndexRequest, DeleteIndexResponse, DeleteResourceSetRequest, DeleteResourceSetResponse,
+    GetIndexRequest, GetIndexResponse, GetResourceSetRequest, GetResourceSetResponse,
+    GetSchemaRequest, GetSchemaResponse, IndexInfo, ListAliasesRequest, ListAliasesResponse,
+    ListIndexesRequest, ListIndexesResponse, ListResourceSetsRequest, ListResourceSetsResponse,
+    OpenIndexRequest, OpenIndexResponse, PutResourceSetRequest, PutResourceSetResponse,
+    ReloadResourceSetsRequest, ReloadResourceSetsResponse, UpdateAliasesRequest,
+    UpdateAliasesResponse, UpdateFieldRequest, UpdateFieldResponse, VectorFieldStats,
+    alias_action::Action, index_service_server::IndexService as IndexServiceTrait,
 };
+use crate::registry::{AliasAction, IndexRegistry};
 
 /// gRPC IndexService implementation.
 #[derive(Clone)]
 pub struct IndexService {
-    /// Shared, mutable reference to the current search engine instance.
-    /// `None` when no index has been created yet.
-    pub engine: Arc<RwLock<Option<Engine>>>,
-    /// Filesystem path where the index data is persisted.
-    pub data_dir: PathBuf,
+    /// The hosted indexes.
+    pub registry: Arc<IndexRegistry>,
+    /// Rejects every mutating RPC when `true` (replication followers).
+    pub read_only: bool,
+}
+
+impl IndexService {
+    #[allow(clippy::result_large_err)]
+    fn check_writable(&self) -> Result<(), Status> {
+        if self.read_only {
+            return Err(Status::failed_precondition(
+                "This server is a read-only replication follower",
+            ));
+        }
+        Ok(())
+    }
 }
 
 #[tonic::async_trait]
 impl IndexServiceTrait for IndexService {
-    /// Creates a new index with the given schema. Fails if an index already exists.
+    /// Creates a new index with the given schema. Fails if the index already exists.
     async fn create_index(
         &self,
         request: Request<CreateIndexRequest>,
     ) -> Result<Response<CreateIndexResponse>, Status> {
+        self.check_writable()?;
         let req = request.into_inner();
         let proto_schema = req
             .schema
@@ -43,26 +69,108 @@ impl IndexServiceTrait for IndexService {
             .ok_or_else(|| Status::invalid_argument("schema is required"))?;
         let schema = schema_convert::from_proto(proto_schema).map_err(Status::invalid_argument)?;
 
-        let mut guard = self.engine.write().await;
-        if guard.is_some() {
-            return Err(Status::already_exists("Index already exists"));
-        }
-
-        let engine = context::create_index(&self.data_dir, &schema)
-            .await
-            .map_err(error::anyhow_to_status)?;
-        *guard = Some(engine);
+        let slot = self.registry.create(&req.index, &schema).await?;
 
-        tracing::info!("Index created at {}", self.data_dir.display());
+        tracing::info!(
+            "Index '{}' created at {}",
+            slot.name,
+            slot.data_dir.display()
+        );
         Ok(Response::new(CreateIndexResponse {}))
     }
 
+    /// Lists the hosted indexes and whether they are loaded.
+    async fn list_indexes(
+        &self,
+        _request: Request<ListIndexesRequest>,
+    ) -> Result<Response<ListIndexesResponse>, Status> {
+        let indexes = self
+            .registry
+            .list()
+            .await?
+            .into_iter()
+            .map(|info| IndexInfo {
+                name: info.name,
+                open: info.open,
+            })
+            .collect();
+        Ok(Response::new(ListIndexesResponse { indexes }))
+    }
+
+    /// Deletes an index and all of its files.
+    async fn delete_index(
+        &self,
+        request: Request<DeleteIndexRequest>,
+    ) -> Result<Response<DeleteIndexResponse>, Status> {
+        self.check_writable()?;
+        self.registry.delete(&request.into_inner().index).await?;
+        Ok(Response::new(DeleteIndexResponse {}))
+    }
+
+    /// Loads an index into memory.
+    async fn open_index(
+        &self,
+        request: Request<OpenIndexRequest>,
+    ) -> Result<Response<OpenIndexResponse>, Status> {
+        self.registry.open(&request.into_inner().index).await?;
+        Ok(Response::new(OpenIndexResponse {}))
+    }
+
+    /// Commits pending changes and unloads an index from memory.
+    async fn close_index(
+        &self,
+        request: Request<CloseIndexRequest>,
+    ) -> Result<Response<CloseIndexResponse>, Status> {
+        // Followers keep every index loaded while replicating it.
+        self.check_writable()?;
+        let closed = self.registry.close(&request.into_inner().index).await?;
+        Ok(Response::new(CloseIndexResponse { closed }))
+    }
+
+    /// Lists the aliases and the indexes they refer to.
+    async fn list_aliases(
+        &self,
+        _request: Request<ListAliasesRequest>,
+    ) -> Result<Response<ListAliasesResponse>, Status> {
+        let aliases = self
+            .registry
+            .aliases()
+            .into_iter()
+            .map(|(name, indexes)| Alias { name, indexes })
+            .collect();
+        Ok(Response::new(ListAliasesResponse { aliases }))
+    }
+
+    /// Creates, moves or removes aliases in a single atomic update.
+    async fn update_aliases(
+        &self,
+        request: Request<UpdateAliasesRequest>,
+    ) -> Result<Response<UpdateAliasesResponse>, Status> {
+        self.check_writable()?;
+        let actions = request
+            .into_inner()
+            .actions
+            .into_iter()
+            .map(|action| match action.action {
+                Some(Action::Put(alias)) => Ok(AliasAction::Put {
+                    alias: alias.name,
+                    indexes: alias.indexes,
+                }),
+                Some(Action::Remove(alias)) => Ok(AliasAction::Remove { alias }),
+                None => Err(Status::invalid_argument("alias action has no action set")),
+            })
+            .collect::<Result<Vec<_>, Status>>()?;
+        self.registry.update_aliases(&actions)?;
+        Ok(Response::new(UpdateAliasesResponse {}))
+    }
+
     /// Returns index-level statistics such as document count and per-field vector stats.
     async fn get_index(
         &self,
-        _request: Request<GetIndexRequest>,
+        request: Request<GetIndexRequest>,
     ) -> Result<Response<GetIndexResponse>, Status> {
-        let guard = self.engine.read().await;
+        let slot = self.registry.open(&request.into_inner().index).await?;
+        let guard = slot.engine.read().await;
         let engine = guard
             .as_ref()
             .ok_or_else(|| Status::failed_precondition("No index is open"))?;
@@ -86,26 +194,44 @@ impl IndexServiceTrait for IndexService {
         Ok(Response::new(GetIndexResponse {
             document_count: stats.document_count,
             vector_fields,
+            backfills: stats.backfills.iter().map(backfill_to_proto).collect(),
+            field_update: slot
+                .field_update
+                .lock()
+                .await
+                .as_ref()
+                .map(backfill_to_proto),
         }))
     }
 
-    /// Returns the schema definition of the current index.
+    /// Returns the schema definition of an index.
     async fn get_schema(
         &self,
-        _request: Request<GetSchemaRequest>,
+        request: Request<GetSchemaRequest>,
     ) -> Result<Response<GetSchemaResponse>, Status> {
-        let schema = context::read_schema(&self.data_dir).map_err(error::anyhow_to_status)?;
+        let slot = self
+            .registry
+            .slot(&self.registry.resolve_single(&request.into_inner().index)?)
+            .await?;
+        if !slot.exists() {
+            return Err(Status::not_found(format!(
+                "Index '{}' does not exist",
+                slot.name
+            )));
+        }
+        let schema = context::read_schema(&slot.data_dir).map_err(error::anyhow_to_status)?;
         let proto_schema = schema_convert::to_proto(&schema);
         Ok(Response::new(GetSchemaResponse {
             schema: Some(proto_schema),
         }))
     }
 
-    /// Dynamically adds a new field to the current index and persists the updated schema.
+    /// Dynamically adds a new field to an index and persists the updated schema.
     async fn add_field(
         &self,
         request: Request<AddFieldRequest>,
     ) -> Result<Response<AddFieldResponse>, Status> {
+        self.check_writable()?;
         let req = request.into_inner();
         let name = req.name;
         if name.is_empty() {
@@ -118,49 +244,358 @@ impl IndexServiceTrait for IndexService {
         let field_option = schema_convert::field_option_from_proto(proto_field_option)
             .ok_or_else(|| Status::invalid_argument("field_option has no option set"))?;
 
-        let guard = self.engine.read().await;
+        let slot = self.registry.open(&req.index).await?;
+        let guard = slot.engine.read().await;
         let engine = guard
             .as_ref()
             .ok_or_else(|| Status::failed_precondition("No index is open"))?;
 
-        let updated_schema = engine
-            .add_field(&name, field_option)
-            .await
-            .map_err(error::to_status)?;
+        let (updated_schema, backfill) = if req.backfill_source_field.is_empty() {
+            let schema = engine
+                .add_field(&name, field_option)
+                .await
+                .map_err(error::to_status)?;
+            (schema, None)
+        } else {
+            let (schema, progress) = engine
+                .add_field_with_backfill(&name, field_option, &req.backfill_source_field)
+                .await
+                .map_err(error::to_status)?;
+            (schema, Some(progress))
+        };
 
-        context::save_schema(&self.data_dir, &updated_schema).map_err(error::anyhow_to_status)?;
+        context::save_schema(&slot.data_dir, &updated_schema).map_err(error::anyhow_to_status)?;
 
-        tracing::info!("Field '{}' added to index", name);
+        tracing::info!("Field '{}' added to index '{}'", name, slot.name);
+        if backfill.is_some() {
+            spawn_backfill(slot.engine.clone(), name);
+        }
         let proto_schema = schema_convert::to_proto(&updated_schema);
         Ok(Response::new(AddFieldResponse {
             schema: Some(proto_schema),
+            backfill: backfill.as_ref().map(backfill_to_proto),
         }))
     }
 
-    /// Removes a field from the current index schema and persists the updated schema.
+    /// Removes a field from an index schema and persists the updated schema.
     async fn delete_field(
         &self,
         request: Request<DeleteFieldRequest>,
     ) -> Result<Response<DeleteFieldResponse>, Status> {
+        self.check_writable()?;
         let req = request.into_inner();
         let name = req.name;
         if name.is_empty() {
             return Err(Status::invalid_argument("field name is required"));
         }
 
-        let guard = self.engine.read().await;
+        let slot = self.registry.open(&req.index).await?;
+        let guard = slot.engine.read().await;
         let engine = guard
             .as_ref()
             .ok_or_else(|| Status::failed_precondition("No index is open"))?;
 
         let updated_schema = engine.delete_field(&name).await.map_err(error::to_status)?;
 
-        context::save_schema(&self.data_dir, &updated_schema).map_err(error::anyhow_to_status)?;
+        context::save_schema(&slot.data_dir, &updated_schema).map_err(error::anyhow_to_status)?;
 
-        tracing::info!("Field '{}' deleted from index", name);
+        tracing::info!("Field '{}' deleted from index '{}'", name, slot.name);
         let proto_schema = schema_convert::to_proto(&updated_schema);
         Ok(Response::new(DeleteFieldResponse {
             schema: Some(proto_schema),
         }))
     }
+
+    /// Starts rebuilding the index with a changed field definition.
+    async fn update_field(
+        &self,
+        request: Request<UpdateFieldRequest>,
+    ) -> Result<Response<UpdateFieldResponse>, Status> {
+        self.check_writable()?;
+        let req = request.into_inner();
+        let name = req.name;
+        if name.is_empty() {
+            return Err(Status::invalid_argument("field name is required"));
+        }
+        let proto_field_option = req
+            .field_option
+            .as_ref()
+            .ok_or_else(|| Status::invalid_argument("field_option is required"))?;
+        let field_option = schema_convert::field_option_from_proto(proto_field_option)
+            .ok_or_else(|| Status::invalid_argument("field_option has no option set"))?;
+
+        let slot = self.registry.open(&req.index).await?;
+        // Held until the rebuild is registered so that only one runs at a time.
+        let mut running = slot.field_update.lock().await;
+        if let Some(progress) = running.as_ref() {
+            return Err(Status::failed_precondition(format!(
+                "Field '{}' is already being updated",
+                progress.field
+            )));
+        }
+
+        let storage =
+            context::create_staging_storage(&slot.data_dir).map_err(error::anyhow_to_status)?;
+        let update = {
+            let guard = slot.engine.read().await;
+            let engine = guard
+                .as_ref()
+                .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+            engine
+                .update_field(&name, field_option, storage)
+                .await
+                .map_err(error::to_status)?
+        };
+
+        let proto_schema = schema_convert::to_proto(&update.schema());
+        let progress = update.progress().clone();
+        *running = Some(progress.clone());
+        drop(running);
+
+        tracing::info!(
+            "Rebuilding index '{}' to update field '{}'",
+            slot.name,
+            name
+        );
+        spawn_field_update(
+            slot.engine.clone(),
+            slot.data_dir.clone(),
+            slot.field_update.clone(),
+            update,
+        );
+        Ok(Response::new(UpdateFieldResponse {
+            schema: Some(proto_schema),
+            progress: Some(backfill_to_proto(&progress)),
+        }))
+    }
+
+    /// Stores a resource set and reloads the analyzers that use it.
+    async fn put_resource_set(
+        &self,
+        request: Request<PutResourceSetRequest>,
+    ) -> Result<Response<PutResourceSetResponse>, Status> {
+        self.check_writable()?;
+        let req = request.into_inner();
+        let proto_set = req
+            .resource_set
+            .as_ref()
+            .ok_or_else(|| Status::invalid_argument("resource_set is required"))?;
+        let content = resource_convert::content_from_proto(proto_set)
+            .ok_or_else(|| Status::invalid_argument("resource_set has no content set"))?;
+
+        let slot = self.registry.open(&req.index).await?;
+        let guard = slot.engine.read().await;
+        let engine = guard
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+
+        let set = engine
+            .put_resource_set(&proto_set.name, content)
+            .map_err(error::to_status)?;
+
+        tracing::info!(
+            "Resource set '{}' of index '{}' updated to version {}",
+            set.name,
+            slot.name,
+            set.version
+        );
+        Ok(Response::new(PutResourceSetResponse {
+            resource_set: Some(resource_convert::to_proto(&set)),
+        }))
+    }
+
+    /// Returns a resource set of an index.
+    async fn get_resource_set(
+        &self,
+        request: Request<GetResourceSetRequest>,
+    ) -> Result<Response<GetResourceSetResponse>, Status> {
+        let req = request.into_inner();
+        let slot = self.registry.open(&req.index).await?;
+        let guard = slot.engine.read().await;
+        let engine = guard
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+
+        let set = engine.resource_set(&req.name).ok_or_else(|| {
+            Status::not_found(format!("Resource set '{}' does not exist", req.name))
+        })?;
+        Ok(Response::new(GetResourceSetResponse {
+            resource_set: Some(resource_convert::to_proto(&set)),
+        }))
+    }
+
+    /// Lists the resource sets of an index.
+    async fn list_resource_sets(
+        &self,
+        request: Request<ListResourceSetsRequest>,
+    ) -> Result<Response<ListResourceSetsResponse>, Status> {
+        let slot = self.registry.open(&request.into_inner().index).await?;
+        let guard = slot.engine.read().await;
+        let engine = guard
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+
+        Ok(Response::new(ListResourceSetsResponse {
+            resource_sets: engine
+                .resource_sets()
+                .iter()
+                .map(resource_convert::to_proto)
+                .collect(),
+        }))
+    }
+
+    /// Deletes a resource set that no analyzer refers to.
+    async fn delete_resource_set(
+        &self,
+        request: Request<DeleteResourceSetRequest>,
+    ) -> Result<Response<DeleteResourceSetResponse>, Status> {
+        self.check_writable()?;
+        let req = request.into_inner();
+        let slot = self.registry.open(&req.index).await?;
+        let guard = slot.engine.read().await;
+        let engine = guard
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+
+        let set = engine
+            .delete_resource_set(&req.name)
+            .map_err(error::to_status)?;
+
+        tracing::info!(
+            "Resource set '{}' deleted from index '{}'",
+            set.name,
+            slot.name
+        );
+        Ok(Response::new(DeleteResourceSetResponse {
+            resource_set: Some(resource_convert::to_proto(&set)),
+        }))
+    }
+
+    /// Re-reads the resource sets of an index from its files and reloads the
+    /// analyzers using them.
+    ///
+    /// This does not modify the index, so it is also allowed on read-only
+    /// followers.
+    async fn reload_resource_sets(
+        &self,
+        request: Request<ReloadResourceSetsRequest>,
+    ) -> Result<Response<ReloadResourceSetsResponse>, Status> {
+        let slot = self.registry.open(&request.into_inner().index).await?;
+        let guard = slot.engine.read().await;
+        let engine = guard
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+
+        let sets = engine.reload_resource_sets().map_err(error::to_status)?;
+
+        tracing::info!("Resource sets of index '{}' reloaded", slot.name);
+        Ok(Response::new(ReloadResourceSetsResponse {
+            resource_sets: sets.iter().map(resource_convert::to_proto).collect(),
+        }))
+    }
+}
+
+/// Run the backfill of `field` in the background until it completes.
+///
+/// The engine lock is only held for one batch at a time, so other requests
+/// are served while the backfill runs. Backfilled documents are committed
+/// once the backfill completes. A failed batch stops the task; the backfill
+/// resumes from its checkpoint the next time the server starts.
+pub fn spawn_backfill(engine: Arc<RwLock<Option<Engine>>>, field: String) {
+    tokio::spawn(async move {
+        tracing::info!("Backfilling field '{field}'");
+        loop {
+            let guard = engine.read().await;
+            let Some(engine) = guard.as_ref() else {
+                return;
+            };
+            let progress = match engine
+                .backfill_batch(&field, DEFAULT_BACKFILL_BATCH_SIZE)
+                .await
+            {
+                Ok(progress) => progress,
+                Err(e) => {
+                    tracing::error!("Backfill of field '{field}' failed: {e}");
+                    return;
+                }
+            };
+            if progress.completed {
+                if let Err(e) = engine.commit().await {
+                    tracing::error!("Failed to commit backfill of field '{field}': {e}");
+                }
+                tracing::info!(
+                    "Backfilled field '{field}' for {} documents",
+                    progress.processed
+                );
+                return;
+            }
+        }
+    });
+}
+
+/// Run a field update in the background and swap the rebuilt index in.
+///
+/// Documents are copied one batch per engine read lock, and mutations made
+/// in the meantime are replayed after each batch. Once everything is
+/// copied, the engine write lock is taken so that no more writes arrive,
+/// the remaining mutations are replayed, the rebuilt storage replaces the
+/// current one and the updated schema is persisted. A failed rebuild leaves
+/// the current index untouched.
+pub fn spawn_field_update(
+    engine: Arc<RwLock<Option<Engine>>>,
+    data_dir: PathBuf,
+    status: Arc<Mutex<Option<BackfillProgress>>>,
+    update: FieldUpdate,
+) {
+    tokio::spawn(async move {
+        let field = update.field().to_string();
+        let result = run_field_update(&engine, &data_dir, &status, update).await;
+        *status.lock().await = None;
+        match result {
+            Ok(()) => tracing::info!("Field '{field}' updated"),
+            Err(e) => tracing::error!("Update of field '{field}' failed: {e:#}"),
+        }
+    });
+}
+
+async fn run_field_update(
+    engine: &RwLock<Option<Engine>>,
+    data_dir: &Path,
+    status: &Mutex<Option<BackfillProgress>>,
+    mut update: FieldUpdate,
+) -> anyhow::Result<()> {
+    loop {
+        let guard = engine.read().await;
+        let source = guard.as_ref().context("Index was closed")?;
+        let progress = update
+            .copy_batch(source, DEFAULT_BACKFILL_BATCH_SIZE)
+            .await?
+            .clone();
+        // Replaying after every batch keeps the pending mutations within
+        // the retained change window.
+        update.catch_up(source).await?;
+        let completed = progress.completed;
+        *status.lock().await = Some(progress);
+        if completed {
+            break;
+        }
+    }
+
+    let mut guard = engine.write().await;
+    let source = guard.as_ref().context("Index was closed")?;
+    let updated = update.finish(source).await?;
+    let schema = updated.schema();
+    drop(updated);
+
+    context::install_staged_index(&mut guard, data_dir, &schema).await
+}
+
+fn backfill_to_proto(progress: &BackfillProgress) -> v1::BackfillProgress {
+    v1::BackfillProgress {
+        field: progress.field.clone(),
+        source_field: progress.source_field.clone(),
+        total: progress.total,
+        processed: progress.processed,
+        completed: progress.completed,
+    }
 }
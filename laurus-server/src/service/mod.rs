@@ -3,12 +3,16 @@
 //! This module re-exports the concrete service types that implement the
 //! generated tonic server traits:
 //!
+//! * [`admin::AdminService`]       – snapshot creation and restore.
 //! * [`document::DocumentService`] – document CRUD and commit operations.
 //! * [`health::HealthService`]     – health-check endpoint.
 //! * [`index::IndexService`]       – index creation and schema management.
+//! * [`replication::ReplicationService`] – replication status and snapshots.
 //! * [`search::SearchService`]     – lexical, vector, and hybrid search.
 
+pub mod admin;
 pub mod document;
 pub mod health;
 pub mod index;
+pub mod replication;
 pub mod search;
@@ -0,0 +1,213 @@
+//! Replication gRPC service.
+//!
+//! Reports the replication role and position of each index on this server
+//! and serves snapshots of a committed index to followers that bootstrap or fell out
+//! of the leader's retained change window, as well as the unfiltered change
+//! feed that followers tail.
+
+use std::io::Read;
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+use laurus::{Engine, Snapshot};
+
+use crate::config::ReplicationRole;
+use crate::context;
+use crate::convert::{document as doc_convert, error, schema as schema_convert};
+use crate::proto::laurus::v1::{
+    self, ChangeEvent, FetchSnapshotRequest, GetReplicationStatusRequest,
+    GetReplicationStatusResponse, SnapshotChunk, WatchChangesRequest,
+    replication_service_server::ReplicationService as ReplicationServiceTrait,
+};
+use crate::registry::IndexRegistry;
+use crate::replication::ReplicationState;
+
+/// Maximum number of file bytes sent in a single snapshot chunk.
+const SNAPSHOT_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// gRPC ReplicationService implementation.
+#[derive(Clone)]
+pub struct ReplicationService {
+    /// The hosted indexes.
+    pub registry: Arc<IndexRegistry>,
+    /// Replication role and leader position of this server.
+    pub state: Arc<ReplicationState>,
+}
+
+#[tonic::async_trait]
+impl ReplicationServiceTrait for ReplicationService {
+    /// Returns the role of this server and the applied position and lag of
+    /// an index.
+    async fn get_replication_status(
+        &self,
+        request: Request<GetReplicationStatusRequest>,
+    ) -> Result<Response<GetReplicationStatusResponse>, Status> {
+        let req = request.into_inner();
+        let (role, last_seq, leader_seq) = match self.state.role() {
+            ReplicationRole::Leader => {
+                let slot = self.registry.open(&req.index).await?;
+                let last_seq = slot
+                    .engine
+                    .read()
+                    .await
+                    .as_ref()
+                    .map_or(0, Engine::last_seq);
+                (v1::ReplicationRole::Leader, last_seq, last_seq)
+            }
+            // A follower may not have copied the index yet.
+            ReplicationRole::Follower => {
+                let index = self.registry.resolve_single(&req.index)?;
+                let last_seq = match self.registry.slot(&index).await {
+                    Ok(slot) => slot
+                        .engine
+                        .read()
+                        .await
+                        .as_ref()
+                        .map_or(0, Engine::last_seq),
+                    Err(status) if status.code() == tonic::Code::NotFound => 0,
+                    Err(status) => return Err(status),
+                };
+                (
+                    v1::ReplicationRole::Follower,
+                    last_seq,
+                    self.state.leader_seq(&index),
+                )
+            }
+        };
+
+        Ok(Response::new(GetReplicationStatusResponse {
+            role: role.into(),
+            last_seq,
+            leader_seq,
+            lag: leader_seq.saturating_sub(last_seq),
+            leader_address: self.state.leader().unwrap_or_default().to_string(),
+        }))
+    }
+
+    type FetchSnapshotStream = ReceiverStream<Result<SnapshotChunk, Status>>;
+
+    /// Commits the index and streams its files, schema and sequence number.
+    async fn fetch_snapshot(
+        &self,
+        request: Request<FetchSnapshotRequest>,
+    ) -> Result<Response<Self::FetchSnapshotStream>, Status> {
+        let req = request.into_inner();
+        let slot = self.registry.open(&req.index).await?;
+        let serving = slot.snapshot.clone().lock_owned().await;
+        let staging = context::snapshot_storage(&slot.data_dir).map_err(error::anyhow_to_status)?;
+        let snapshot = {
+            let guard = slot.engine.read().await;
+            let engine = guard
+                .as_ref()
+                .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+            engine.snapshot(staging).await.map_err(error::to_status)?
+        };
+        tracing::info!(
+            "Serving snapshot of index '{}' at seq {} ({} bytes)",
+            slot.name,
+            snapshot.seq(),
+            snapshot.size()
+        );
+
+        let (tx, rx) = mpsc::channel(4);
+        tokio::spawn(async move {
+            // The staged files must not change until they are all sent.
+            let _serving = serving;
+            if let Err(status) = send_snapshot(&snapshot, &tx).await {
+                let _ = tx.send(Err(status)).await;
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+
+    type StreamChangesStream = ReceiverStream<Result<ChangeEvent, Status>>;
+
+    /// Streams every index mutation newer than the requested sequence
+    /// number, with the full documents.
+    async fn stream_changes(
+        &self,
+        request: Request<WatchChangesRequest>,
+    ) -> Result<Response<Self::StreamChangesStream>, Status> {
+        let req = request.into_inner();
+
+        let mut subscription = {
+            let slot = self.registry.open(&req.index).await?;
+            let guard = slot.engine.read().await;
+            let engine = guard
+                .as_ref()
+                .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+            engine.changes(req.since_seq).map_err(error::to_status)?
+        };
+
+        let (tx, rx) = mpsc::channel(64);
+        tokio::spawn(async move {
+            loop {
+                let item = match subscription.next().await {
+                    Ok(Some(event)) => Ok(doc_convert::change_event_to_proto(&event)),
+                    Ok(None) => break,
+                    Err(e) => Err(error::to_status(e)),
+                };
+                let failed = item.is_err();
+                if tx.send(item).await.is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+}
+
+/// Sends the files of `snapshot`, each in chunks of at most
+/// [`SNAPSHOT_CHUNK_SIZE`] bytes, followed by the schema if no file carried
+/// it. Stops early if the receiver goes away.
+async fn send_snapshot(
+    snapshot: &Snapshot,
+    tx: &mpsc::Sender<Result<SnapshotChunk, Status>>,
+) -> Result<(), Status> {
+    let mut schema = Some(schema_convert::to_proto(snapshot.schema()));
+    let mut buffer = vec![0; SNAPSHOT_CHUNK_SIZE];
+    for file in snapshot.files() {
+        let mut input = snapshot.open_file(&file.name).map_err(error::to_status)?;
+        let mut offset = 0;
+        loop {
+            let read = input.read(&mut buffer).map_err(|e| {
+                Status::internal(format!("Failed to read snapshot file '{}': {e}", file.name))
+            })?;
+            // Empty files still get one chunk so that they are created.
+            if read > 0 || offset == 0 {
+                let chunk = SnapshotChunk {
+                    seq: snapshot.seq(),
+                    schema: schema.take(),
+                    file_name: file.name.clone(),
+                    offset,
+                    data: buffer[..read].to_vec(),
+                    file_size: file.size,
+                    checksum: file.checksum,
+                };
+                if tx.send(Ok(chunk)).await.is_err() {
+                    return Ok(());
+                }
+            }
+            if read == 0 {
+                break;
+            }
+            offset += read as u64;
+        }
+    }
+    // A snapshot of an empty index has no files; still send the schema.
+    if let Some(schema) = schema {
+        let _ = tx
+            .send(Ok(SnapshotChunk {
+                seq: snapshot.seq(),
+                schema: Some(schema),
+                ..Default::default()
+            }))
+            .await;
+    }
+    Ok(())
+}
@@ -1,30 +1,179 @@
 //! Search gRPC service.
 //!
 //! Provides unary and server-streaming RPCs for executing lexical, vector,
-//! and hybrid search queries against the index. The unified query DSL
+//! and hybrid search queries against a hosted index. The unified query DSL
 //! (including vector clauses like `field:"text"`) is handled by the engine
 //! internally — no query-syntax branching is needed in the service layer.
+//!
+//! Searching an alias of several indexes runs the query against each of
+//! them and merges the ranked lists with Reciprocal Rank Fusion, since the
+//! scores of different indexes are not comparable. `FederatedSearch` does
+//! the same with a separate query and weight per index.
+//!
+//! Every search is made on behalf of the identity derived from the request
+//! metadata (see [`identity`](crate::convert::identity)), so indexes with an
+//! ACL field only return documents visible to the caller. Searches of an
+//! index partitioned by tenant are scoped to the tenant named in the
+//! metadata (see [`tenant`](crate::convert::tenant)); such an index can only
+//! be searched on its own, not through an alias or a federated search.
+//!
+//! `Suggest` returns completions from a completion field of a single index,
+//! restricted like a search to the caller's identity and tenant.
 
 use std::sync::Arc;
 
-use tokio::sync::RwLock;
+use laurus::{
+    FederatedQuery, FederatedSearchOptions, FederatedSearchResult, FusionAlgorithm, Identity,
+};
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
 
-use laurus::Engine;
-
-use crate::convert::{error, search as search_convert};
+use crate::convert::{error, identity, search as search_convert, tenant};
 use crate::proto::laurus::v1::{
-    SearchRequest, SearchResponse, SearchResult,
-    search_service_server::SearchService as SearchServiceTrait,
+    FederatedSearchRequest, SearchRequest, SearchResponse, SearchResult, SuggestRequest,
+    SuggestResponse, search_service_server::SearchService as SearchServiceTrait,
 };
+use crate::registry::{IndexRegistry, IndexSlot};
+
+/// Smoothing constant `k` of the Reciprocal Rank Fusion of alias results.
+const ALIAS_RRF_K: f64 = 60.0;
+
+/// Number of suggestions returned when a `Suggest` request has no limit.
+const DEFAULT_SUGGEST_LIMIT: usize = 10;
 
 /// gRPC SearchService implementation.
 #[derive(Clone)]
 pub struct SearchService {
-    /// Shared, mutable reference to the current search engine instance.
-    /// `None` when no index has been created yet.
-    pub engine: Arc<RwLock<Option<Engine>>>,
+    /// The hosted indexes.
+    pub registry: Arc<IndexRegistry>,
+}
+
+impl SearchService {
+    /// Run `req` on behalf of `identity` and `tenant` against the index or
+    /// alias it names and return the results tagged with the index each one
+    /// was found in.
+    async fn execute(
+        &self,
+        req: &SearchRequest,
+        identity: &Identity,
+        tenant: Option<&str>,
+    ) -> Result<Vec<FederatedSearchResult>, Status> {
+        let slots = self.registry.open_all(&req.index).await?;
+
+        if let [slot] = slots.as_slice() {
+            let mut search_request = search_convert::from_proto(req)?;
+            search_request.identity = Some(identity.clone());
+            let guard = slot.engine.read().await;
+            let engine = guard
+                .as_ref()
+                .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+            let results = match tenant::scope(engine, tenant)? {
+                Some(tenant) => tenant.search(search_request).await,
+                None => engine.search(search_request).await,
+            }
+            .map_err(error::to_status)?;
+            return Ok(results
+                .into_iter()
+                .map(|result| FederatedSearchResult {
+                    index: slot.name.clone(),
+                    id: result.id,
+                    score: result.score,
+                    document: result.document,
+                })
+                .collect());
+        }
+
+        let mut requests = Vec::with_capacity(slots.len());
+        for _ in &slots {
+            let mut request = search_convert::from_proto(req)?;
+            request.identity = Some(identity.clone());
+            requests.push(request);
+        }
+        let options = FederatedSearchOptions {
+            fusion: FusionAlgorithm::RRF { k: ALIAS_RRF_K },
+            limit: requests.first().map_or(0, |request| request.limit),
+            offset: req.offset as usize,
+        };
+        let weighted = slots.into_iter().map(|slot| (slot, 1.0)).collect();
+        federate(weighted, requests, options, tenant).await
+    }
+
+    /// Run the per-index searches of a federated request on behalf of
+    /// `identity` and `tenant`.
+    async fn execute_federated(
+        &self,
+        req: &FederatedSearchRequest,
+        identity: &Identity,
+        tenant: Option<&str>,
+    ) -> Result<Vec<FederatedSearchResult>, Status> {
+        let mut slots = Vec::with_capacity(req.queries.len());
+        let mut requests = Vec::with_capacity(req.queries.len());
+        for query in &req.queries {
+            let request = query
+                .request
+                .as_ref()
+                .ok_or_else(|| Status::invalid_argument("Federated query has no request"))?;
+            let name = self.registry.resolve_single(&request.index)?;
+            slots.push((
+                self.registry.open(&name).await?,
+                query.weight.unwrap_or(1.0),
+            ));
+            let mut request = search_convert::from_proto(request)?;
+            request.identity = Some(identity.clone());
+            requests.push(request);
+        }
+        let options = search_convert::federated_options_from_proto(req);
+        federate(slots, requests, options, tenant).await
+    }
+}
+
+/// Search each slot with its request and weight, and fuse the results.
+///
+/// Indexes partitioned by tenant are rejected, and so is a `tenant` for
+/// indexes without a tenant field.
+async fn federate(
+    slots: Vec<(Arc<IndexSlot>, f32)>,
+    requests: Vec<laurus::SearchRequest>,
+    options: FederatedSearchOptions,
+    tenant: Option<&str>,
+) -> Result<Vec<FederatedSearchResult>, Status> {
+    // Take one read guard per distinct index: a second read of the same
+    // lock could wait behind a queued writer while the first is held.
+    let mut distinct: Vec<&Arc<IndexSlot>> = Vec::new();
+    let mut guard_of = Vec::with_capacity(slots.len());
+    for (slot, _) in &slots {
+        let position = match distinct.iter().position(|other| Arc::ptr_eq(other, slot)) {
+            Some(position) => position,
+            None => {
+                distinct.push(slot);
+                distinct.len() - 1
+            }
+        };
+        guard_of.push(position);
+    }
+    let mut guards = Vec::with_capacity(distinct.len());
+    for slot in &distinct {
+        guards.push(slot.engine.read().await);
+    }
+
+    let mut queries = Vec::with_capacity(slots.len());
+    for (((slot, weight), request), position) in slots.iter().zip(requests).zip(guard_of) {
+        let engine = guards[position]
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+        if engine.schema().tenant_field.is_some() {
+            return Err(Status::failed_precondition(format!(
+                "Index '{}' is partitioned by tenant and can only be searched on its own",
+                slot.name
+            )));
+        }
+        tenant::scope(engine, tenant)?;
+        queries.push(FederatedQuery::new(slot.name.clone(), engine, request).weight(*weight));
+    }
+
+    laurus::federated_search(queries, options)
+        .await
+        .map_err(error::to_status)
 }
 
 #[tonic::async_trait]
@@ -34,18 +183,11 @@ impl SearchServiceTrait for SearchService {
         &self,
         request: Request<SearchRequest>,
     ) -> Result<Response<SearchResponse>, Status> {
+        let identity = identity::from_metadata(request.metadata());
+        let tenant = tenant::from_metadata(request.metadata());
         let req = request.into_inner();
-        let search_request = search_convert::from_proto(&req)?;
-
-        let guard = self.engine.read().await;
-        let engine = guard
-            .as_ref()
-            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+        let results = self.execute(&req, &identity, tenant.as_deref()).await?;
 
-        let results = engine
-            .search(search_request)
-            .await
-            .map_err(error::to_status)?;
         let total_hits = results.len() as u64;
         let results: Vec<SearchResult> = results
             .iter()
@@ -65,18 +207,10 @@ impl SearchServiceTrait for SearchService {
         &self,
         request: Request<SearchRequest>,
     ) -> Result<Response<Self::SearchStreamStream>, Status> {
+        let identity = identity::from_metadata(request.metadata());
+        let tenant = tenant::from_metadata(request.metadata());
         let req = request.into_inner();
-        let search_request = search_convert::from_proto(&req)?;
-
-        let guard = self.engine.read().await;
-        let engine = guard
-            .as_ref()
-            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
-
-        let results = engine
-            .search(search_request)
-            .await
-            .map_err(error::to_status)?;
+        let results = self.execute(&req, &identity, tenant.as_deref()).await?;
 
         let (tx, rx) = tokio::sync::mpsc::channel(64);
         tokio::spawn(async move {
@@ -90,4 +224,60 @@ impl SearchServiceTrait for SearchService {
 
         Ok(Response::new(ReceiverStream::new(rx)))
     }
+
+    /// Runs one search per index and returns the fused results.
+    async fn federated_search(
+        &self,
+        request: Request<FederatedSearchRequest>,
+    ) -> Result<Response<SearchResponse>, Status> {
+        let identity = identity::from_metadata(request.metadata());
+        let tenant = tenant::from_metadata(request.metadata());
+        let req = request.into_inner();
+        let results = self
+            .execute_federated(&req, &identity, tenant.as_deref())
+            .await?;
+
+        let total_hits = results.len() as u64;
+        let results: Vec<SearchResult> = results
+            .iter()
+            .map(search_convert::result_to_proto)
+            .collect();
+
+        Ok(Response::new(SearchResponse {
+            results,
+            total_hits,
+        }))
+    }
+
+    /// Returns completions of a prefix from a completion field.
+    async fn suggest(
+        &self,
+        request: Request<SuggestRequest>,
+    ) -> Result<Response<SuggestResponse>, Status> {
+        let identity = identity::from_metadata(request.metadata());
+        let tenant = tenant::from_metadata(request.metadata());
+        let req = request.into_inner();
+        let slot = self.registry.open(&req.index).await?;
+        let guard = slot.engine.read().await;
+        let engine = guard
+            .as_ref()
+            .ok_or_else(|| Status::failed_precondition("No index is open"))?;
+
+        let limit = if req.limit > 0 {
+            req.limit as usize
+        } else {
+            DEFAULT_SUGGEST_LIMIT
+        };
+        let suggestions = match tenant::scope(engine, tenant.as_deref())? {
+            Some(tenant) => tenant.suggest_as(&req.field, &req.prefix, limit, &identity),
+            None => engine.suggest_as(&req.field, &req.prefix, limit, &identity),
+        }
+        .map_err(error::to_status)?;
+        Ok(Response::new(SuggestResponse {
+            suggestions: suggestions
+                .into_iter()
+                .map(search_convert::suggestion_to_proto)
+                .collect(),
+        }))
+    }
 }
@@ -1,6 +1,7 @@
 //! WASM-facing [`Index`] class — the primary entry point for the laurus-wasm binding.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::convert::{data_value_to_json, json_to_document};
 use crate::errors::laurus_err;
@@ -222,15 +223,27 @@ impl WasmIndex {
     ///
     /// * `id` - External document identifier (string).
     /// * `doc` - A JS object mapping field names to values.
+    /// * `ttl` - Optional time-to-live in seconds. Requires an expiry field
+    ///   in the schema (see `Schema.setExpiryField`).
     #[wasm_bindgen(js_name = "putDocument")]
-    pub async fn put_document(&self, id: String, doc: JsValue) -> Result<(), JsValue> {
+    pub async fn put_document(
+        &self,
+        id: String,
+        doc: JsValue,
+        ttl: Option<u32>,
+    ) -> Result<(), JsValue> {
         let value: serde_json::Value = serde_wasm_bindgen::from_value(doc)
             .map_err(|e| JsValue::from_str(&format!("Invalid document: {e}")))?;
         let document = json_to_document(&value)?;
-        self.engine
-            .put_document(&id, document)
-            .await
-            .map_err(laurus_err)
+        match ttl {
+            Some(secs) => {
+                self.engine
+                    .put_document_with_ttl(&id, document, Duration::from_secs(secs.into()))
+                    .await
+            }
+            None => self.engine.put_document(&id, document).await,
+        }
+        .map_err(laurus_err)
     }
 
     /// Append a document version without removing existing versions.
@@ -239,15 +252,27 @@ impl WasmIndex {
     ///
     /// * `id` - External document identifier.
     /// * `doc` - A JS object mapping field names to values.
+    /// * `ttl` - Optional time-to-live in seconds. Requires an expiry field
+    ///   in the schema (see `Schema.setExpiryField`).
     #[wasm_bindgen(js_name = "addDocument")]
-    pub async fn add_document(&self, id: String, doc: JsValue) -> Result<(), JsValue> {
+    pub async fn add_document(
+        &self,
+        id: String,
+        doc: JsValue,
+        ttl: Option<u32>,
+    ) -> Result<(), JsValue> {
         let value: serde_json::Value = serde_wasm_bindgen::from_value(doc)
             .map_err(|e| JsValue::from_str(&format!("Invalid document: {e}")))?;
         let document = json_to_document(&value)?;
-        self.engine
-            .add_document(&id, document)
-            .await
-            .map_err(laurus_err)
+        match ttl {
+            Some(secs) => {
+                self.engine
+                    .add_document_with_ttl(&id, document, Duration::from_secs(secs.into()))
+                    .await
+            }
+            None => self.engine.add_document(&id, document).await,
+        }
+        .map_err(laurus_err)
     }
 
     /// Retrieve all document versions stored under `id`.
@@ -403,6 +428,33 @@ impl WasmIndex {
         search_results_to_js(results)
     }
 
+    /// Suggest completions of a prefix from a completion field.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - Name of a completion field.
+    /// * `prefix` - The text typed so far.
+    /// * `limit` - Maximum number of suggestions (default 10).
+    ///
+    /// # Returns
+    ///
+    /// A JS array of `{ text, weight }` objects, highest weight first.
+    #[wasm_bindgen]
+    pub fn suggest(
+        &self,
+        field: String,
+        prefix: String,
+        limit: Option<u32>,
+    ) -> Result<JsValue, JsValue> {
+        let suggestions = self
+            .engine
+            .suggest(&field, &prefix, limit.unwrap_or(10) as usize)
+            .map_err(laurus_err)?;
+        let json_str = serde_json::to_string(&suggestions)
+            .map_err(|e| JsValue::from_str(&format!("Serialization error: {e}")))?;
+        js_sys::JSON::parse(&json_str)
+    }
+
     // ── Stats ─────────────────────────────────────────────────────────────
 
     /// Return index statistics.
@@ -3,8 +3,9 @@
 use std::collections::HashMap;
 
 use laurus::{
-    BooleanOption, BytesOption, DateTimeOption, DistanceMetric, EmbedderDefinition, FieldOption,
-    FlatOption, FloatOption, GeoOption, HnswOption, IntegerOption, IvfOption, Schema, TextOption,
+    BooleanOption, BytesOption, CompletionOption, DateTimeOption, DistanceMetric,
+    EmbedderDefinition, FieldOption, FlatOption, FloatOption, GeoOption, HnswOption, IntegerOption,
+    IvfOption, Schema, TextOption,
 };
 use wasm_bindgen::prelude::*;
 
@@ -86,6 +87,7 @@ impl WasmSchema {
                 stored: stored.unwrap_or(true),
                 term_vectors: term_vectors.unwrap_or(false),
                 analyzer,
+                ..Default::default()
             }),
         );
     }
@@ -166,6 +168,44 @@ impl WasmSchema {
         );
     }
 
+    /// Add a completion field for search-as-you-type suggestions.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Field name.
+    /// * `stored` - Whether the input text is retrievable (default `true`).
+    /// * `weight_field` - Integer field holding each input's weight (default none).
+    /// * `max_edits` - Edits tolerated by fuzzy suggestions; 0 disables them (default 1).
+    /// * `prefix_length` - Leading characters that must match exactly when fuzzy (default 1).
+    /// * `min_fuzzy_length` - Prefix length before fuzzy matching is tried (default 3).
+    /// * `transpositions` - Count swapped adjacent characters as one edit (default `true`).
+    #[wasm_bindgen(js_name = "addCompletionField")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_completion_field(
+        &mut self,
+        name: String,
+        stored: Option<bool>,
+        weight_field: Option<String>,
+        max_edits: Option<u32>,
+        prefix_length: Option<u32>,
+        min_fuzzy_length: Option<u32>,
+        transpositions: Option<bool>,
+    ) {
+        let defaults = CompletionOption::default();
+        self.inner.fields.insert(
+            name,
+            FieldOption::Completion(CompletionOption {
+                stored: stored.unwrap_or(defaults.stored),
+                weight_field,
+                max_edits: max_edits.unwrap_or(defaults.max_edits),
+                prefix_length: prefix_length.map_or(defaults.prefix_length, |n| n as usize),
+                min_fuzzy_length: min_fuzzy_length
+                    .map_or(defaults.min_fuzzy_length, |n| n as usize),
+                transpositions: transpositions.unwrap_or(defaults.transpositions),
+            }),
+        );
+    }
+
     /// Add an HNSW approximate nearest-neighbor vector index field.
     ///
     /// # Arguments
@@ -312,6 +352,15 @@ impl WasmSchema {
         self.inner.default_fields = fields;
     }
 
+    /// Designate the DateTime field that holds document expiry timestamps.
+    ///
+    /// Required for `ttl` on `putDocument` / `addDocument`. Expired
+    /// documents are hidden from search and retrieval.
+    #[wasm_bindgen(js_name = "setExpiryField")]
+    pub fn set_expiry_field(&mut self, field: String) {
+        self.inner.expiry_field = Some(field);
+    }
+
     /// Return the list of field names defined in this schema.
     #[wasm_bindgen(js_name = "fieldNames")]
     pub fn field_names(&self) -> Vec<String> {
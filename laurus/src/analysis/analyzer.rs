@@ -10,6 +10,7 @@
 //! - [`simple::SimpleAnalyzer`] - Simple lowercase + letter tokenization
 //! - [`keyword::KeywordAnalyzer`] - Treats entire input as single token (for IDs, tags)
 //! - [`noop::NoOpAnalyzer`] - No-op analyzer for testing
+//! - [`phonetic::PhoneticAnalyzer`] - Adds phonetic codes for matching names by sound
 //! - [`pipeline::PipelineAnalyzer`] - Customizable analyzer with filter chain
 //! - [`per_field::PerFieldAnalyzer`] - Different analyzers per field
 //! - [`language`] - Language-specific analyzers (English, Japanese, etc.)
@@ -39,6 +40,7 @@ pub mod keyword;
 pub mod language;
 pub mod noop;
 pub mod per_field;
+pub mod phonetic;
 pub mod pipeline;
 pub mod registry;
 pub mod simple;
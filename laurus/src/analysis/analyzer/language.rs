@@ -7,6 +7,10 @@
 //!
 //! - [`english`] - English text analysis with regex tokenization and English stop words
 //! - [`japanese`] - Japanese text analysis with Lindera morphological analyzer
+//! - [`korean`] - Korean text analysis with Lindera morphological analyzer and part-of-speech filtering
+//! - [`chinese`] - Chinese text analysis with Lindera word segmentation
+//! - [`snowball`] - Danish, Dutch, French, German, Italian, Norwegian, Portuguese,
+//!   Russian, Spanish and Swedish text analysis with Snowball stemming
 //!
 //! # Examples
 //!
@@ -21,5 +25,8 @@
 //! assert_eq!(tokens.len(), 2);
 //! ```
 
+pub mod chinese;
 pub mod english;
 pub mod japanese;
+pub mod korean;
+pub mod snowball;
@@ -0,0 +1,169 @@
+//! Chinese language analyzer implementation.
+//!
+//! This module provides a specialized analyzer for Chinese text that uses
+//! Lindera for dictionary-based word segmentation and includes Chinese stop
+//! words.
+//!
+//! # Pipeline
+//!
+//! 1. UnicodeNormalizationCharFilter (NFKC normalization)
+//! 2. LinderaTokenizer (CC-CEDICT dictionary)
+//! 3. LowercaseFilter
+//! 4. StopFilter (Chinese stop words — 89 common function words and punctuation marks)
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use laurus::analysis::analyzer::analyzer::Analyzer;
+//! use laurus::analysis::analyzer::language::chinese::ChineseAnalyzer;
+//!
+//! let analyzer = ChineseAnalyzer::new().unwrap();
+//! let tokens: Vec<_> = analyzer.analyze("汉语的分词").unwrap().collect();
+//!
+//! // Properly segmented Chinese tokens
+//! assert!(tokens.len() > 0);
+//! ```
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::analysis::analyzer::analyzer::Analyzer;
+use crate::analysis::analyzer::pipeline::PipelineAnalyzer;
+use crate::analysis::char_filter::unicode_normalize::NormalizationForm;
+use crate::analysis::char_filter::unicode_normalize::UnicodeNormalizationCharFilter;
+use crate::analysis::token::TokenStream;
+use crate::analysis::token_filter::lowercase::LowercaseFilter;
+use crate::analysis::token_filter::stop::{DEFAULT_CHINESE_STOP_WORDS_SET, StopFilter};
+use crate::analysis::tokenizer::lindera::LinderaTokenizer;
+use crate::error::Result;
+
+/// Analyzer optimized for Chinese language text.
+///
+/// This analyzer uses Lindera to segment Chinese text (which doesn't use
+/// spaces between words) into dictionary words. NFKC normalization folds
+/// full-width Latin letters, digits and punctuation to their ASCII forms.
+/// CC-CEDICT entries carry no grammatical tags, so function words are
+/// removed with a stop word list rather than by part of speech.
+///
+/// # Components
+///
+/// - **Char filters**: UnicodeNormalizationCharFilter (NFKC)
+/// - **Tokenizer**: LinderaTokenizer with CC-CEDICT dictionary
+/// - **Token filters**: LowercaseFilter + StopFilter (Chinese stop words)
+///
+/// # Examples
+///
+/// ```
+/// use laurus::analysis::analyzer::analyzer::Analyzer;
+/// use laurus::analysis::analyzer::language::chinese::ChineseAnalyzer;
+///
+/// let analyzer = ChineseAnalyzer::new().unwrap();
+/// let tokens: Vec<_> = analyzer.analyze("汉语的分词").unwrap().collect();
+///
+/// // The particle "的" is removed
+/// assert!(tokens.iter().all(|t| t.text != "的"));
+/// ```
+pub struct ChineseAnalyzer {
+    inner: PipelineAnalyzer,
+}
+impl ChineseAnalyzer {
+    /// Create a new Chinese analyzer with default settings.
+    ///
+    /// # Returns
+    ///
+    /// A new `ChineseAnalyzer` instance configured with:
+    /// - UnicodeNormalizationCharFilter (NFKC)
+    /// - LinderaTokenizer (CC-CEDICT dictionary)
+    /// - LowercaseFilter
+    /// - StopFilter with Chinese stop words
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LinderaTokenizer cannot be initialized
+    /// (e.g., dictionary loading fails).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use laurus::analysis::analyzer::analyzer::Analyzer;
+    /// use laurus::analysis::analyzer::language::chinese::ChineseAnalyzer;
+    ///
+    /// let analyzer = ChineseAnalyzer::new().unwrap();
+    /// assert_eq!(analyzer.name(), "chinese");
+    /// ```
+    pub fn new() -> Result<Self> {
+        let tokenizer = Arc::new(LinderaTokenizer::new(
+            "normal",
+            "embedded://cc-cedict",
+            None,
+        )?);
+        let analyzer = PipelineAnalyzer::new(tokenizer)
+            .add_char_filter(Arc::new(UnicodeNormalizationCharFilter::new(
+                NormalizationForm::NFKC,
+            )))
+            .add_filter(Arc::new(LowercaseFilter::new()))
+            .add_filter(Arc::new(StopFilter::with_stop_words(
+                DEFAULT_CHINESE_STOP_WORDS_SET.clone(),
+            )))
+            .with_name("chinese".to_string());
+
+        Ok(Self { inner: analyzer })
+    }
+}
+
+impl Default for ChineseAnalyzer {
+    fn default() -> Self {
+        Self::new().expect("Chinese analyzer should be creatable with default settings")
+    }
+}
+
+impl Analyzer for ChineseAnalyzer {
+    fn analyze(&self, text: &str) -> Result<TokenStream> {
+        self.inner.analyze(text)
+    }
+
+    fn name(&self) -> &'static str {
+        "chinese"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl Debug for ChineseAnalyzer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChineseAnalyzer")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::token::Token;
+
+    #[test]
+    fn test_chinese_analyzer() {
+        let analyzer = ChineseAnalyzer::new().unwrap();
+
+        let text = "能够进行汉语的形态素解析。";
+
+        let tokens: Vec<Token> = analyzer.analyze(text).unwrap().collect();
+
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert!(texts.contains(&"汉语"));
+        assert!(texts.contains(&"解析"));
+        // Stop words and punctuation are removed.
+        assert!(!texts.contains(&"的"));
+        assert!(!texts.contains(&"。"));
+    }
+
+    #[test]
+    fn test_chinese_analyzer_name() {
+        let analyzer = ChineseAnalyzer::new().unwrap();
+
+        assert_eq!(analyzer.name(), "chinese");
+    }
+}
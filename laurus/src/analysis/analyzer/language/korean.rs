@@ -0,0 +1,173 @@
+//! Korean language analyzer implementation.
+//!
+//! This module provides a specialized analyzer for Korean text that uses
+//! Lindera for morphological analysis and removes grammatical morphemes by
+//! their part of speech.
+//!
+//! # Pipeline
+//!
+//! 1. UnicodeNormalizationCharFilter (NFKC normalization)
+//! 2. LinderaTokenizer (KO-DIC dictionary, with part-of-speech tags)
+//! 3. PartOfSpeechStopFilter (particles, verbal endings, affixes and punctuation)
+//! 4. LowercaseFilter
+//! 5. StopFilter (Korean stop words — 45 common dependent nouns/pronouns/conjunctions)
+//!
+//! # Examples
+//!
+//! ```ignore
+//! use laurus::analysis::analyzer::analyzer::Analyzer;
+//! use laurus::analysis::analyzer::language::korean::KoreanAnalyzer;
+//!
+//! let analyzer = KoreanAnalyzer::new().unwrap();
+//! let tokens: Vec<_> = analyzer.analyze("한국어의 형태소 분석").unwrap().collect();
+//!
+//! // Properly segmented Korean tokens
+//! assert!(tokens.len() > 0);
+//! ```
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::analysis::analyzer::analyzer::Analyzer;
+use crate::analysis::analyzer::pipeline::PipelineAnalyzer;
+use crate::analysis::char_filter::unicode_normalize::NormalizationForm;
+use crate::analysis::char_filter::unicode_normalize::UnicodeNormalizationCharFilter;
+use crate::analysis::token::TokenStream;
+use crate::analysis::token_filter::lowercase::LowercaseFilter;
+use crate::analysis::token_filter::part_of_speech::PartOfSpeechStopFilter;
+use crate::analysis::token_filter::stop::{DEFAULT_KOREAN_STOP_WORDS_SET, StopFilter};
+use crate::analysis::tokenizer::lindera::LinderaTokenizer;
+use crate::error::Result;
+
+/// Analyzer optimized for Korean language text.
+///
+/// This analyzer uses Lindera for morphological analysis to split Korean
+/// eojeol (space-separated words) into morphemes, then drops the particles
+/// and verbal endings attached to content words based on their KO-DIC
+/// part-of-speech tags, so that "한국어의" and "한국어를" both index
+/// "한국어".
+///
+/// # Components
+///
+/// - **Char filters**: UnicodeNormalizationCharFilter (NFKC)
+/// - **Tokenizer**: LinderaTokenizer with KO-DIC dictionary
+/// - **Token filters**: PartOfSpeechStopFilter (default Korean stop tags) + LowercaseFilter + StopFilter (Korean stop words)
+///
+/// # Examples
+///
+/// ```
+/// use laurus::analysis::analyzer::analyzer::Analyzer;
+/// use laurus::analysis::analyzer::language::korean::KoreanAnalyzer;
+///
+/// let analyzer = KoreanAnalyzer::new().unwrap();
+/// let tokens: Vec<_> = analyzer.analyze("한국어의 형태소 분석").unwrap().collect();
+///
+/// // The genitive particle "의" is removed
+/// assert!(tokens.iter().all(|t| t.text != "의"));
+/// ```
+pub struct KoreanAnalyzer {
+    inner: PipelineAnalyzer,
+}
+impl KoreanAnalyzer {
+    /// Create a new Korean analyzer with default settings.
+    ///
+    /// # Returns
+    ///
+    /// A new `KoreanAnalyzer` instance configured with:
+    /// - UnicodeNormalizationCharFilter (NFKC)
+    /// - LinderaTokenizer (KO-DIC dictionary)
+    /// - PartOfSpeechStopFilter with the default Korean stop tags
+    /// - LowercaseFilter
+    /// - StopFilter with Korean stop words
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LinderaTokenizer cannot be initialized
+    /// (e.g., dictionary loading fails).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use laurus::analysis::analyzer::analyzer::Analyzer;
+    /// use laurus::analysis::analyzer::language::korean::KoreanAnalyzer;
+    ///
+    /// let analyzer = KoreanAnalyzer::new().unwrap();
+    /// assert_eq!(analyzer.name(), "korean");
+    /// ```
+    pub fn new() -> Result<Self> {
+        let tokenizer = Arc::new(
+            LinderaTokenizer::new("normal", "embedded://ko-dic", None)?.with_part_of_speech(),
+        );
+        let analyzer = PipelineAnalyzer::new(tokenizer)
+            .add_char_filter(Arc::new(UnicodeNormalizationCharFilter::new(
+                NormalizationForm::NFKC,
+            )))
+            .add_filter(Arc::new(PartOfSpeechStopFilter::korean()))
+            .add_filter(Arc::new(LowercaseFilter::new()))
+            .add_filter(Arc::new(StopFilter::with_stop_words(
+                DEFAULT_KOREAN_STOP_WORDS_SET.clone(),
+            )))
+            .with_name("korean".to_string());
+
+        Ok(Self { inner: analyzer })
+    }
+}
+
+impl Default for KoreanAnalyzer {
+    fn default() -> Self {
+        Self::new().expect("Korean analyzer should be creatable with default settings")
+    }
+}
+
+impl Analyzer for KoreanAnalyzer {
+    fn analyze(&self, text: &str) -> Result<TokenStream> {
+        self.inner.analyze(text)
+    }
+
+    fn name(&self) -> &'static str {
+        "korean"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl Debug for KoreanAnalyzer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KoreanAnalyzer")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::token::Token;
+
+    #[test]
+    fn test_korean_analyzer() {
+        let analyzer = KoreanAnalyzer::new().unwrap();
+
+        let text = "한국어의형태해석을실시할수있습니다.";
+
+        let tokens: Vec<Token> = analyzer.analyze(text).unwrap().collect();
+
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert!(texts.contains(&"한국어"));
+        assert!(texts.contains(&"해석"));
+        // Particles, verbal endings and punctuation are removed.
+        assert!(!texts.contains(&"의"));
+        assert!(!texts.contains(&"을"));
+        assert!(!texts.contains(&"습니다"));
+        assert!(!texts.contains(&"."));
+    }
+
+    #[test]
+    fn test_korean_analyzer_name() {
+        let analyzer = KoreanAnalyzer::new().unwrap();
+
+        assert_eq!(analyzer.name(), "korean");
+    }
+}
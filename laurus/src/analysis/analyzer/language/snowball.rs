@@ -0,0 +1,140 @@
+//! Snowball analyzer implementation for European languages.
+//!
+//! This analyzer provides text analysis for the languages supported by
+//! [`SnowballStemmer`], combining regex-based tokenization with the
+//! language's stop words and stemmer.
+//!
+//! # Pipeline
+//!
+//! 1. RegexTokenizer (`\w+` pattern — matches sequences of word characters)
+//! 2. LowercaseFilter
+//! 3. StopFilter (Snowball stop words for the language)
+//! 4. StemFilter (Snowball stemmer for the language)
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::analyzer::analyzer::Analyzer;
+//! use laurus::analysis::analyzer::language::snowball::SnowballAnalyzer;
+//! use laurus::analysis::token_filter::stem::snowball::SnowballLanguage;
+//!
+//! let analyzer = SnowballAnalyzer::new(SnowballLanguage::French).unwrap();
+//! let tokens: Vec<_> = analyzer.analyze("Les chanteuses continuellement").unwrap().collect();
+//!
+//! // "les" is filtered out and the remaining words are stemmed
+//! assert_eq!(tokens.len(), 2);
+//! assert_eq!(tokens[0].text, "chanteux");
+//! assert_eq!(tokens[1].text, "continuel");
+//! ```
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::analysis::analyzer::analyzer::Analyzer;
+use crate::analysis::analyzer::pipeline::PipelineAnalyzer;
+use crate::analysis::token::TokenStream;
+use crate::analysis::token_filter::lowercase::LowercaseFilter;
+use crate::analysis::token_filter::stem::StemFilter;
+use crate::analysis::token_filter::stem::snowball::SnowballLanguage;
+use crate::analysis::token_filter::stop::StopFilter;
+use crate::analysis::tokenizer::regex::RegexTokenizer;
+use crate::error::Result;
+
+/// Analyzer for one of the [`SnowballLanguage`]s.
+///
+/// The analyzer is named after its language, e.g. `"french"`, which is
+/// also the name it is registered under.
+///
+/// # Components
+///
+/// - **Tokenizer**: RegexTokenizer (`\w+` pattern — matches word characters)
+/// - **Filters**: Lowercase + Snowball stop words + Snowball stemmer
+pub struct SnowballAnalyzer {
+    language: SnowballLanguage,
+    inner: PipelineAnalyzer,
+}
+
+impl SnowballAnalyzer {
+    /// Creates a new `SnowballAnalyzer` for `language`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying [`RegexTokenizer`] cannot be created.
+    pub fn new(language: SnowballLanguage) -> Result<Self> {
+        let tokenizer = Arc::new(RegexTokenizer::new()?);
+        let analyzer = PipelineAnalyzer::new(tokenizer)
+            .add_filter(Arc::new(LowercaseFilter::new()))
+            .add_filter(Arc::new(StopFilter::for_language(language.name())?))
+            .add_filter(Arc::new(StemFilter::snowball(language)))
+            .with_name(language.name().to_string());
+
+        Ok(Self {
+            language,
+            inner: analyzer,
+        })
+    }
+
+    /// The language this analyzer handles.
+    pub fn language(&self) -> SnowballLanguage {
+        self.language
+    }
+}
+
+impl Analyzer for SnowballAnalyzer {
+    fn analyze(&self, text: &str) -> Result<TokenStream> {
+        self.inner.analyze(text)
+    }
+
+    fn name(&self) -> &'static str {
+        self.language.name()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl Debug for SnowballAnalyzer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SnowballAnalyzer")
+            .field("language", &self.language)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::token::Token;
+
+    fn texts(language: SnowballLanguage, text: &str) -> Vec<String> {
+        let analyzer = SnowballAnalyzer::new(language).unwrap();
+        let tokens: Vec<Token> = analyzer.analyze(text).unwrap().collect();
+        tokens.into_iter().map(|t| t.text).collect()
+    }
+
+    #[test]
+    fn test_german_analyzer() {
+        assert_eq!(
+            texts(SnowballLanguage::German, "Die Häuser und die Straße"),
+            vec!["haus", "strass"]
+        );
+    }
+
+    #[test]
+    fn test_russian_analyzer() {
+        assert_eq!(
+            texts(SnowballLanguage::Russian, "Книгами и вечерний"),
+            vec!["книг", "вечерн"]
+        );
+    }
+
+    #[test]
+    fn test_analyzer_name() {
+        for language in SnowballLanguage::ALL {
+            let analyzer = SnowballAnalyzer::new(language).unwrap();
+            assert_eq!(analyzer.name(), language.name());
+        }
+    }
+}
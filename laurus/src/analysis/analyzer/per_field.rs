@@ -19,6 +19,14 @@ use crate::error::Result;
 /// even after the analyzer has been wrapped in an `Arc`. This enables dynamic
 /// field addition at runtime.
 ///
+/// A field can also have a separate analyzer for query text, registered with
+/// [`add_search_analyzer`](Self::add_search_analyzer), and one for quoted
+/// phrases, registered with
+/// [`add_search_quote_analyzer`](Self::add_search_quote_analyzer). Query
+/// parsers look them up with [`get_search_analyzer`](Self::get_search_analyzer)
+/// and [`get_search_quote_analyzer`](Self::get_search_quote_analyzer), which
+/// fall back to the index-time analyzer of the field.
+///
 /// # Memory Efficiency
 ///
 /// When using the same analyzer for multiple fields, reuse a single instance
@@ -50,6 +58,12 @@ pub struct PerFieldAnalyzer {
     /// Map of field names to their specific analyzers.
     /// Wrapped in `RwLock` to allow adding analyzers at runtime via `&self`.
     field_analyzers: RwLock<AHashMap<String, Arc<dyn Analyzer>>>,
+
+    /// Map of field names to the analyzers used for their query text.
+    search_analyzers: RwLock<AHashMap<String, Arc<dyn Analyzer>>>,
+
+    /// Map of field names to the analyzers used for their quoted phrases.
+    search_quote_analyzers: RwLock<AHashMap<String, Arc<dyn Analyzer>>>,
 }
 
 impl Clone for PerFieldAnalyzer {
@@ -57,6 +71,8 @@ impl Clone for PerFieldAnalyzer {
         Self {
             default_analyzer: self.default_analyzer.clone(),
             field_analyzers: RwLock::new(self.field_analyzers.read().clone()),
+            search_analyzers: RwLock::new(self.search_analyzers.read().clone()),
+            search_quote_analyzers: RwLock::new(self.search_quote_analyzers.read().clone()),
         }
     }
 }
@@ -71,6 +87,8 @@ impl PerFieldAnalyzer {
         Self {
             default_analyzer,
             field_analyzers: RwLock::new(AHashMap::new()),
+            search_analyzers: RwLock::new(AHashMap::new()),
+            search_quote_analyzers: RwLock::new(AHashMap::new()),
         }
     }
 
@@ -87,16 +105,40 @@ impl PerFieldAnalyzer {
         self.field_analyzers.write().insert(field.into(), analyzer);
     }
 
-    /// Remove the field-specific analyzer for the given field.
+    /// Add an analyzer for the query text of a field.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The field name
+    /// * `analyzer` - The analyzer to use for query terms on this field
+    pub fn add_search_analyzer(&self, field: impl Into<String>, analyzer: Arc<dyn Analyzer>) {
+        self.search_analyzers.write().insert(field.into(), analyzer);
+    }
+
+    /// Add an analyzer for quoted phrases in the query text of a field.
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The field name
+    /// * `analyzer` - The analyzer to use for phrase queries on this field
+    pub fn add_search_quote_analyzer(&self, field: impl Into<String>, analyzer: Arc<dyn Analyzer>) {
+        self.search_quote_analyzers
+            .write()
+            .insert(field.into(), analyzer);
+    }
+
+    /// Remove the field-specific analyzers for the given field.
     ///
     /// After removal, the field will fall back to the default analyzer.
     /// This method is a no-op if the field has no specific analyzer configured.
     ///
     /// # Arguments
     ///
-    /// * `field` - The field name whose analyzer should be removed
+    /// * `field` - The field name whose analyzers should be removed
     pub fn remove_analyzer(&self, field: &str) {
         self.field_analyzers.write().remove(field);
+        self.search_analyzers.write().remove(field);
+        self.search_quote_analyzers.write().remove(field);
     }
 
     /// Get the analyzer for a specific field.
@@ -115,6 +157,32 @@ impl PerFieldAnalyzer {
             .unwrap_or_else(|| self.default_analyzer.clone())
     }
 
+    /// Get the analyzer for the query text of a field.
+    ///
+    /// Returns the field's search analyzer if configured, otherwise the
+    /// analyzer returned by [`get_analyzer`](Self::get_analyzer).
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The field name
+    pub fn get_search_analyzer(&self, field: &str) -> Arc<dyn Analyzer> {
+        let search = self.search_analyzers.read().get(field).cloned();
+        search.unwrap_or_else(|| self.get_analyzer(field))
+    }
+
+    /// Get the analyzer for quoted phrases in the query text of a field.
+    ///
+    /// Returns the field's search quote analyzer if configured, otherwise the
+    /// analyzer returned by [`get_search_analyzer`](Self::get_search_analyzer).
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The field name
+    pub fn get_search_quote_analyzer(&self, field: &str) -> Arc<dyn Analyzer> {
+        let quote = self.search_quote_analyzers.read().get(field).cloned();
+        quote.unwrap_or_else(|| self.get_search_analyzer(field))
+    }
+
     /// Get the default analyzer.
     pub fn default_analyzer(&self) -> &Arc<dyn Analyzer> {
         &self.default_analyzer
@@ -207,4 +275,35 @@ mod tests {
         assert_eq!(tokens[0].text, "hello");
         assert_eq!(tokens[1].text, "world");
     }
+
+    #[test]
+    fn test_search_analyzers_fall_back() {
+        let analyzer = PerFieldAnalyzer::new(Arc::new(StandardAnalyzer::new().unwrap()));
+        analyzer.add_analyzer("title", Arc::new(KeywordAnalyzer::new()));
+        assert_eq!(analyzer.get_search_analyzer("title").name(), "keyword");
+        assert_eq!(
+            analyzer.get_search_quote_analyzer("title").name(),
+            "keyword"
+        );
+
+        analyzer.add_search_analyzer("title", Arc::new(StandardAnalyzer::new().unwrap()));
+        assert_eq!(analyzer.get_analyzer("title").name(), "keyword");
+        assert_eq!(analyzer.get_search_analyzer("title").name(), "standard");
+        assert_eq!(
+            analyzer.get_search_quote_analyzer("title").name(),
+            "standard"
+        );
+
+        analyzer.add_search_quote_analyzer("title", Arc::new(KeywordAnalyzer::new()));
+        assert_eq!(
+            analyzer.get_search_quote_analyzer("title").name(),
+            "keyword"
+        );
+
+        analyzer.remove_analyzer("title");
+        assert_eq!(
+            analyzer.get_search_quote_analyzer("title").name(),
+            "standard"
+        );
+    }
 }
@@ -0,0 +1,125 @@
+//! Phonetic analyzer for matching names by how they sound.
+//!
+//! This analyzer keeps each lowercased word and adds its Double Metaphone
+//! codes at the same position, so that exact spellings still match (and
+//! score higher) while "Stephen" also finds "Steven" and "Smith" finds
+//! "Schmidt". It does not remove stop words, which makes it suitable for
+//! name fields.
+//!
+//! # Pipeline
+//!
+//! 1. RegexTokenizer (`\w+` pattern — matches sequences of word characters)
+//! 2. LowercaseFilter
+//! 3. PhoneticFilter (Double Metaphone, codes injected next to the word)
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::analyzer::analyzer::Analyzer;
+//! use laurus::analysis::analyzer::phonetic::PhoneticAnalyzer;
+//!
+//! let analyzer = PhoneticAnalyzer::new().unwrap();
+//! let tokens: Vec<_> = analyzer
+//!     .analyze("Steven")
+//!     .unwrap()
+//!     .map(|t| t.text)
+//!     .collect();
+//!
+//! assert_eq!(tokens, vec!["steven", "STFN"]);
+//! ```
+use std::sync::Arc;
+
+use crate::analysis::analyzer::analyzer::Analyzer;
+use crate::analysis::analyzer::pipeline::PipelineAnalyzer;
+use crate::analysis::token::TokenStream;
+use crate::analysis::token_filter::lowercase::LowercaseFilter;
+use crate::analysis::token_filter::phonetic::{PhoneticAlgorithm, PhoneticFilter};
+use crate::analysis::tokenizer::regex::RegexTokenizer;
+use crate::error::Result;
+
+/// An analyzer that adds Double Metaphone codes to lowercased words.
+pub struct PhoneticAnalyzer {
+    inner: PipelineAnalyzer,
+}
+
+impl PhoneticAnalyzer {
+    /// Create a new phonetic analyzer using Double Metaphone.
+    pub fn new() -> Result<Self> {
+        Self::with_algorithm(PhoneticAlgorithm::DoubleMetaphone)
+    }
+
+    /// Create a new phonetic analyzer using `algorithm`.
+    pub fn with_algorithm(algorithm: PhoneticAlgorithm) -> Result<Self> {
+        let tokenizer = Arc::new(RegexTokenizer::new()?);
+        let analyzer = PipelineAnalyzer::new(tokenizer)
+            .add_filter(Arc::new(LowercaseFilter::new()))
+            .add_filter(Arc::new(PhoneticFilter::new(algorithm)))
+            .with_name("phonetic".to_string());
+
+        Ok(PhoneticAnalyzer { inner: analyzer })
+    }
+
+    /// Get the inner pipeline analyzer.
+    pub fn inner(&self) -> &PipelineAnalyzer {
+        &self.inner
+    }
+}
+
+impl Default for PhoneticAnalyzer {
+    fn default() -> Self {
+        Self::new().expect("Phonetic analyzer should be creatable with default settings")
+    }
+}
+
+impl Analyzer for PhoneticAnalyzer {
+    fn analyze(&self, text: &str) -> Result<TokenStream> {
+        self.inner.analyze(text)
+    }
+
+    fn name(&self) -> &'static str {
+        "phonetic"
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl std::fmt::Debug for PhoneticAnalyzer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhoneticAnalyzer")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::token::Token;
+
+    #[test]
+    fn test_phonetic_analyzer() {
+        let analyzer = PhoneticAnalyzer::new().unwrap();
+
+        let tokens: Vec<Token> = analyzer.analyze("Stephen Smith").unwrap().collect();
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+
+        assert_eq!(texts, vec!["stephen", "STFN", "smith", "SM0", "XMT"]);
+        assert_eq!(tokens[1].position_increment, 0);
+        assert_eq!(tokens[2].position_increment, 1);
+    }
+
+    #[test]
+    fn test_phonetic_analyzer_with_algorithm() {
+        let analyzer = PhoneticAnalyzer::with_algorithm(PhoneticAlgorithm::Soundex).unwrap();
+
+        let texts: Vec<String> = analyzer
+            .analyze("Schmidt")
+            .unwrap()
+            .map(|t| t.text)
+            .collect();
+
+        assert_eq!(texts, vec!["schmidt", "S530"]);
+    }
+}
@@ -9,20 +9,28 @@
 //! | Name | Description |
 //! |------|-------------|
 //! | `standard` | Regex tokenizer + lowercase + English stop words |
+//! | `standard_folding` | Regex tokenizer + case folding + ASCII folding + English stop words |
 //! | `keyword` | Treats the entire input as a single token |
 //! | `english` | English-optimized (equivalent to `standard`) |
 //! | `japanese` | Lindera/IPADIC tokenizer + Japanese stop words |
+//! | `korean` | Lindera/KO-DIC tokenizer + part-of-speech filter + Korean stop words |
+//! | `chinese` | Lindera/CC-CEDICT tokenizer + Chinese stop words |
+//! | `phonetic` | Regex tokenizer + lowercase + Double Metaphone codes |
 //! | `simple` | Regex tokenizer only, no filters |
 //! | `noop` | Produces no tokens (for stored-only fields) |
 
-use std::collections::HashSet;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::analysis::analyzer::analyzer::Analyzer;
 use crate::analysis::analyzer::keyword::KeywordAnalyzer;
+use crate::analysis::analyzer::language::chinese::ChineseAnalyzer;
 use crate::analysis::analyzer::language::english::EnglishAnalyzer;
 use crate::analysis::analyzer::language::japanese::JapaneseAnalyzer;
+use crate::analysis::analyzer::language::korean::KoreanAnalyzer;
+use crate::analysis::analyzer::language::snowball::SnowballAnalyzer;
 use crate::analysis::analyzer::noop::NoOpAnalyzer;
+use crate::analysis::analyzer::phonetic::PhoneticAnalyzer;
 use crate::analysis::analyzer::pipeline::PipelineAnalyzer;
 use crate::analysis::analyzer::simple::SimpleAnalyzer;
 use crate::analysis::analyzer::standard::StandardAnalyzer;
@@ -33,22 +41,34 @@ use crate::analysis::char_filter::pattern_replace::PatternReplaceCharFilter;
 use crate::analysis::char_filter::unicode_normalize::{
     NormalizationForm, UnicodeNormalizationCharFilter,
 };
+use crate::analysis::synonym::dictionary::{SynonymDictionary, SynonymFormat};
 use crate::analysis::token_filter::Filter;
+use crate::analysis::token_filter::ascii_folding::AsciiFoldingFilter;
 use crate::analysis::token_filter::boost::BoostFilter;
+use crate::analysis::token_filter::case_fold::CaseFoldFilter;
+use crate::analysis::token_filter::dictionary_decompounder::DictionaryDecompounderFilter;
+use crate::analysis::token_filter::edge_ngram::EdgeNgramFilter;
 use crate::analysis::token_filter::flatten_graph::FlattenGraphFilter;
 use crate::analysis::token_filter::limit::LimitFilter;
 use crate::analysis::token_filter::lowercase::LowercaseFilter;
+use crate::analysis::token_filter::phonetic::{PhoneticAlgorithm, PhoneticFilter};
 use crate::analysis::token_filter::remove_empty::RemoveEmptyFilter;
+use crate::analysis::token_filter::shingle::ShingleFilter;
+use crate::analysis::token_filter::stem::snowball::SnowballLanguage;
 use crate::analysis::token_filter::stem::{StemFilter, identity::IdentityStemmer};
 use crate::analysis::token_filter::stop::StopFilter;
 use crate::analysis::token_filter::strip::StripFilter;
+use crate::analysis::token_filter::synonym_graph::SynonymGraphFilter;
+use crate::analysis::token_filter::word_delimiter_graph::WordDelimiterGraphFilter;
 use crate::analysis::tokenizer::Tokenizer;
+use crate::analysis::tokenizer::edge_ngram::EdgeNgramTokenizer;
 use crate::analysis::tokenizer::lindera::LinderaTokenizer;
 use crate::analysis::tokenizer::ngram::NgramTokenizer;
 use crate::analysis::tokenizer::regex::RegexTokenizer;
 use crate::analysis::tokenizer::unicode_word::UnicodeWordTokenizer;
 use crate::analysis::tokenizer::whitespace::WhitespaceTokenizer;
 use crate::analysis::tokenizer::whole::WholeTokenizer;
+use crate::engine::resource::{ResourceContent, ResourceSet};
 use crate::engine::schema::analyzer::{
     AnalyzerDefinition, CharFilterConfig, TokenFilterConfig, TokenizerConfig,
 };
@@ -61,7 +81,9 @@ use crate::error::{LaurusError, Result};
 ///
 /// # Arguments
 ///
-/// * `name` - The analyzer name (e.g. `"standard"`, `"japanese"`).
+/// * `name` - The analyzer name (e.g. `"standard"`, `"japanese"`). The
+///   name of a [`SnowballLanguage`] (e.g. `"french"`) creates a
+///   [`SnowballAnalyzer`] for that language.
 ///
 /// # Returns
 ///
@@ -73,16 +95,23 @@ use crate::error::{LaurusError, Result};
 pub fn create_analyzer_by_name(name: &str) -> Result<Arc<dyn Analyzer>> {
     match name {
         "standard" => Ok(Arc::new(StandardAnalyzer::new()?)),
+        "standard_folding" => Ok(Arc::new(StandardAnalyzer::with_folding()?)),
         "keyword" => Ok(Arc::new(KeywordAnalyzer::new())),
         "english" => Ok(Arc::new(EnglishAnalyzer::new()?)),
         "japanese" => Ok(Arc::new(JapaneseAnalyzer::new()?)),
+        "korean" => Ok(Arc::new(KoreanAnalyzer::new()?)),
+        "chinese" => Ok(Arc::new(ChineseAnalyzer::new()?)),
+        "phonetic" => Ok(Arc::new(PhoneticAnalyzer::new()?)),
         "simple" => Ok(Arc::new(SimpleAnalyzer::new(Arc::new(
             RegexTokenizer::new()?,
         )))),
         "noop" => Ok(Arc::new(NoOpAnalyzer::new())),
-        unknown => Err(LaurusError::invalid_argument(format!(
-            "Unknown analyzer: {unknown}"
-        ))),
+        other => match other.parse::<SnowballLanguage>() {
+            Ok(language) => Ok(Arc::new(SnowballAnalyzer::new(language)?)),
+            Err(_) => Err(LaurusError::invalid_argument(format!(
+                "Unknown analyzer: {other}"
+            ))),
+        },
     }
 }
 
@@ -108,6 +137,32 @@ pub fn create_analyzer_by_name(name: &str) -> Result<Arc<dyn Analyzer>> {
 pub fn create_analyzer_from_definition(
     name: &str,
     definition: &AnalyzerDefinition,
+) -> Result<Arc<dyn Analyzer>> {
+    create_analyzer_with_resources(name, definition, &BTreeMap::new())
+}
+
+/// Create an analyzer from a custom definition whose components may refer
+/// to [resource sets](crate::engine::resource).
+///
+/// # Arguments
+///
+/// * `name` - The name to assign to the resulting analyzer.
+/// * `definition` - The analyzer pipeline definition.
+/// * `resources` - The resource sets available to the definition, by name.
+///
+/// # Returns
+///
+/// An `Arc<dyn Analyzer>` wrapping the constructed pipeline.
+///
+/// # Errors
+///
+/// Returns an error if any component configuration is invalid, or if a
+/// component refers to a resource set that does not exist, has the wrong
+/// kind of content, or is combined with inline data.
+pub fn create_analyzer_with_resources(
+    name: &str,
+    definition: &AnalyzerDefinition,
+    resources: &BTreeMap<String, ResourceSet>,
 ) -> Result<Arc<dyn Analyzer>> {
     // 1. Build tokenizer.
     let tokenizer: Arc<dyn Tokenizer> = match &definition.tokenizer {
@@ -123,6 +178,9 @@ pub fn create_analyzer_from_definition(
         TokenizerConfig::Ngram { min_gram, max_gram } => {
             Arc::new(NgramTokenizer::new(*min_gram, *max_gram)?)
         }
+        TokenizerConfig::EdgeNgram { min_gram, max_gram } => {
+            Arc::new(EdgeNgramTokenizer::new(*min_gram, *max_gram)?)
+        }
         TokenizerConfig::Lindera {
             mode,
             dict,
@@ -155,9 +213,20 @@ pub fn create_analyzer_from_definition(
                 pattern,
                 replacement,
             } => Arc::new(PatternReplaceCharFilter::new(pattern, replacement)?),
-            CharFilterConfig::Mapping { mapping } => {
-                Arc::new(MappingCharFilter::new(mapping.clone())?)
-            }
+            CharFilterConfig::Mapping { mapping, resource } => match resource {
+                Some(_) if !mapping.is_empty() => {
+                    return Err(LaurusError::invalid_argument(
+                        "mapping takes either an inline mapping or a resource, not both",
+                    ));
+                }
+                Some(resource) => match &lookup_resource(resources, resource)?.content {
+                    ResourceContent::Mapping { mapping } => {
+                        Arc::new(MappingCharFilter::new(mapping.clone())?)
+                    }
+                    other => return Err(resource_kind_error(resource, other, "mapping")),
+                },
+                None => Arc::new(MappingCharFilter::new(mapping.clone())?),
+            },
             CharFilterConfig::JapaneseIterationMark { kanji, kana } => {
                 Arc::new(JapaneseIterationMarkCharFilter::new(*kanji, *kana))
             }
@@ -169,13 +238,36 @@ pub fn create_analyzer_from_definition(
     for tf_config in &definition.token_filters {
         let tf: Arc<dyn Filter> = match tf_config {
             TokenFilterConfig::Lowercase => Arc::new(LowercaseFilter::new()),
-            TokenFilterConfig::Stop { words } => {
-                if let Some(word_list) = words {
-                    let set: HashSet<String> = word_list.iter().cloned().collect();
-                    Arc::new(StopFilter::with_stop_words(set))
-                } else {
-                    Arc::new(StopFilter::new())
-                }
+            TokenFilterConfig::CaseFold => Arc::new(CaseFoldFilter::new()),
+            TokenFilterConfig::AsciiFolding { preserve_original } => {
+                Arc::new(AsciiFoldingFilter::new().with_preserve_original(*preserve_original))
+            }
+            TokenFilterConfig::Stop {
+                words,
+                resource,
+                language,
+            } => {
+                let filter = match (words, resource, language) {
+                    (None, None, None) => StopFilter::new(),
+                    (Some(words), None, None) => StopFilter::from_words(words.iter().cloned()),
+                    (None, Some(resource), None) => {
+                        match &lookup_resource(resources, resource)?.content {
+                            ResourceContent::StopWords { words } => {
+                                StopFilter::from_words(words.iter().cloned())
+                            }
+                            other => {
+                                return Err(resource_kind_error(resource, other, "stop_words"));
+                            }
+                        }
+                    }
+                    (None, None, Some(language)) => StopFilter::for_language(language)?,
+                    _ => {
+                        return Err(LaurusError::invalid_argument(
+                            "stop takes only one of inline words, a resource or a language",
+                        ));
+                    }
+                };
+                Arc::new(filter)
             }
             TokenFilterConfig::Stem { stem_type } => {
                 let stemmer_name = stem_type.as_deref().unwrap_or("porter");
@@ -185,11 +277,14 @@ pub fn create_analyzer_from_definition(
                     "identity" => {
                         Arc::new(StemFilter::with_stemmer(Box::new(IdentityStemmer::new())))
                     }
-                    _ => {
-                        return Err(LaurusError::invalid_argument(format!(
-                            "Unknown stemmer type: {stemmer_name}"
-                        )));
-                    }
+                    _ => match stemmer_name.parse::<SnowballLanguage>() {
+                        Ok(language) => Arc::new(StemFilter::snowball(language)),
+                        Err(_) => {
+                            return Err(LaurusError::invalid_argument(format!(
+                                "Unknown stemmer type: {stemmer_name}"
+                            )));
+                        }
+                    },
                 }
             }
             TokenFilterConfig::Boost { boost } => Arc::new(BoostFilter::new(*boost)),
@@ -197,6 +292,111 @@ pub fn create_analyzer_from_definition(
             TokenFilterConfig::Strip => Arc::new(StripFilter::new()),
             TokenFilterConfig::RemoveEmpty => Arc::new(RemoveEmptyFilter::new()),
             TokenFilterConfig::FlattenGraph => Arc::new(FlattenGraphFilter::new()),
+            TokenFilterConfig::EdgeNgram {
+                min_gram,
+                max_gram,
+                preserve_original,
+            } => Arc::new(
+                EdgeNgramFilter::new(*min_gram, *max_gram)?
+                    .with_preserve_original(*preserve_original),
+            ),
+            TokenFilterConfig::Shingle {
+                min_shingle_size,
+                max_shingle_size,
+                token_separator,
+                output_unigrams,
+            } => Arc::new(
+                ShingleFilter::new(*min_shingle_size, *max_shingle_size)?
+                    .with_token_separator(token_separator.as_str())
+                    .with_output_unigrams(*output_unigrams),
+            ),
+            TokenFilterConfig::Phonetic { encoder, inject } => Arc::new(
+                PhoneticFilter::new(encoder.parse::<PhoneticAlgorithm>()?).with_inject(*inject),
+            ),
+            TokenFilterConfig::WordDelimiterGraph {
+                generate_word_parts,
+                generate_number_parts,
+                split_on_case_change,
+                split_on_numerics,
+                catenate_words,
+                catenate_numbers,
+                catenate_all,
+                preserve_original,
+            } => Arc::new(
+                WordDelimiterGraphFilter::new()
+                    .with_generate_word_parts(*generate_word_parts)
+                    .with_generate_number_parts(*generate_number_parts)
+                    .with_split_on_case_change(*split_on_case_change)
+                    .with_split_on_numerics(*split_on_numerics)
+                    .with_catenate_words(*catenate_words)
+                    .with_catenate_numbers(*catenate_numbers)
+                    .with_catenate_all(*catenate_all)
+                    .with_preserve_original(*preserve_original),
+            ),
+            TokenFilterConfig::DictionaryDecompounder {
+                words,
+                min_word_size,
+                min_subword_size,
+                max_subword_size,
+                only_longest_match,
+            } => {
+                if words.is_empty() {
+                    return Err(LaurusError::invalid_argument(
+                        "dictionary_decompounder requires at least one word",
+                    ));
+                }
+                Arc::new(
+                    DictionaryDecompounderFilter::new(words)
+                        .with_min_word_size(*min_word_size)
+                        .with_min_subword_size(*min_subword_size)
+                        .with_max_subword_size(*max_subword_size)
+                        .with_only_longest_match(*only_longest_match),
+                )
+            }
+            TokenFilterConfig::SynonymGraph {
+                synonyms,
+                path,
+                format,
+                keep_original,
+                boost,
+                resource,
+            } => {
+                let sources = usize::from(!synonyms.is_empty())
+                    + usize::from(path.is_some())
+                    + usize::from(resource.is_some());
+                if sources > 1 {
+                    return Err(LaurusError::invalid_argument(
+                        "synonym_graph takes only one of inline synonyms, a path or a resource",
+                    ));
+                }
+                let dictionary = match (path, resource) {
+                    (_, Some(resource)) => match &lookup_resource(resources, resource)?.content {
+                        ResourceContent::Synonyms { rules } => {
+                            SynonymDictionary::from_solr_rules(&rules.join("\n"))?
+                        }
+                        other => return Err(resource_kind_error(resource, other, "synonyms")),
+                    },
+                    (Some(path), None) => {
+                        let format = match format.as_deref().unwrap_or("solr") {
+                            "solr" => SynonymFormat::Solr,
+                            "wordnet" => SynonymFormat::WordNet,
+                            "json" => SynonymFormat::Json,
+                            other => {
+                                return Err(LaurusError::invalid_argument(format!(
+                                    "Unknown synonym format: {other}"
+                                )));
+                            }
+                        };
+                        SynonymDictionary::load_from_file_with_format(path, format)?
+                    }
+                    (None, None) => SynonymDictionary::from_solr_rules(&synonyms.join("\n"))?,
+                };
+                let filter = SynonymGraphFilter::new(dictionary, *keep_original);
+                match boost {
+                    Some(boost) => Arc::new(filter.with_boost(*boost)),
+                    None => Arc::new(filter),
+                }
+            }
         };
         pipeline = pipeline.add_filter(tf);
     }
@@ -204,6 +404,25 @@ pub fn create_analyzer_from_definition(
     Ok(Arc::new(pipeline))
 }
 
+/// Look up the resource set `name` referenced by an analyzer component.
+fn lookup_resource<'a>(
+    resources: &'a BTreeMap<String, ResourceSet>,
+    name: &str,
+) -> Result<&'a ResourceSet> {
+    resources
+        .get(name)
+        .ok_or_else(|| LaurusError::invalid_argument(format!("Unknown resource set: {name}")))
+}
+
+/// The error for a resource set whose content does not suit the component
+/// referring to it.
+fn resource_kind_error(name: &str, content: &ResourceContent, expected: &str) -> LaurusError {
+    LaurusError::invalid_argument(format!(
+        "Resource set '{name}' holds {} but {expected} is required",
+        content.kind()
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +433,18 @@ mod tests {
         assert_eq!(analyzer.name(), "standard");
     }
 
+    #[test]
+    fn test_create_standard_folding() {
+        let analyzer = create_analyzer_by_name("standard_folding").unwrap();
+        assert_eq!(analyzer.name(), "standard_folding");
+        let tokens: Vec<_> = analyzer
+            .analyze("The Café on Straße")
+            .unwrap()
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(tokens, vec!["cafe", "strasse"]);
+    }
+
     #[test]
     fn test_create_keyword() {
         let analyzer = create_analyzer_by_name("keyword").unwrap();
@@ -232,6 +463,32 @@ mod tests {
         assert_eq!(analyzer.name(), "japanese");
     }
 
+    #[test]
+    fn test_create_korean() {
+        let analyzer = create_analyzer_by_name("korean").unwrap();
+        assert_eq!(analyzer.name(), "korean");
+    }
+
+    #[test]
+    fn test_create_chinese() {
+        let analyzer = create_analyzer_by_name("chinese").unwrap();
+        assert_eq!(analyzer.name(), "chinese");
+    }
+
+    #[test]
+    fn test_create_snowball_languages() {
+        for name in ["french", "german", "spanish", "russian"] {
+            let analyzer = create_analyzer_by_name(name).unwrap();
+            assert_eq!(analyzer.name(), name);
+        }
+    }
+
+    #[test]
+    fn test_create_phonetic() {
+        let analyzer = create_analyzer_by_name("phonetic").unwrap();
+        assert_eq!(analyzer.name(), "phonetic");
+    }
+
     #[test]
     fn test_create_simple() {
         let analyzer = create_analyzer_by_name("simple").unwrap();
@@ -277,6 +534,8 @@ mod tests {
                 TokenFilterConfig::Lowercase,
                 TokenFilterConfig::Stop {
                     words: Some(vec!["the".into(), "a".into()]),
+                    resource: None,
+                    language: None,
                 },
             ],
         };
@@ -286,6 +545,54 @@ mod tests {
         assert_eq!(tokens.len(), 3);
     }
 
+    #[test]
+    fn test_create_from_definition_with_language() {
+        let def = AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![
+                TokenFilterConfig::Lowercase,
+                TokenFilterConfig::Stop {
+                    words: None,
+                    resource: None,
+                    language: Some("spanish".into()),
+                },
+                TokenFilterConfig::Stem {
+                    stem_type: Some("spanish".into()),
+                },
+            ],
+        };
+        let analyzer = create_analyzer_from_definition("spanish_custom", &def).unwrap();
+        let tokens: Vec<_> = analyzer.analyze("Los chicos corriendo").unwrap().collect();
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, vec!["chic", "corr"]);
+    }
+
+    #[test]
+    fn test_stop_rejects_language_with_words() {
+        let def = AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![TokenFilterConfig::Stop {
+                words: Some(vec!["the".into()]),
+                resource: None,
+                language: Some("french".into()),
+            }],
+        };
+        assert!(create_analyzer_from_definition("bad", &def).is_err());
+
+        let def = AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![TokenFilterConfig::Stop {
+                words: None,
+                resource: None,
+                language: Some("klingon".into()),
+            }],
+        };
+        assert!(create_analyzer_from_definition("bad", &def).is_err());
+    }
+
     #[test]
     fn test_create_from_definition_with_char_filter() {
         let def = AnalyzerDefinition {
@@ -314,4 +621,328 @@ mod tests {
         assert_eq!(tokens.len(), 1);
         assert_eq!(tokens[0].text, "Hello World");
     }
+
+    #[test]
+    fn test_create_from_definition_with_edge_ngram_and_shingle() {
+        let def = AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::EdgeNgram {
+                min_gram: 2,
+                max_gram: 3,
+            },
+            token_filters: vec![TokenFilterConfig::Lowercase],
+        };
+        let analyzer = create_analyzer_from_definition("prefix", &def).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("New York")
+            .unwrap()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                ("ne".to_string(), 1),
+                ("new".to_string(), 0),
+                ("yo".to_string(), 1),
+                ("yor".to_string(), 0),
+            ]
+        );
+
+        let def = AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![
+                TokenFilterConfig::Shingle {
+                    min_shingle_size: 2,
+                    max_shingle_size: 2,
+                    token_separator: " ".into(),
+                    output_unigrams: false,
+                },
+                TokenFilterConfig::EdgeNgram {
+                    min_gram: 4,
+                    max_gram: 5,
+                    preserve_original: true,
+                },
+            ],
+        };
+        let analyzer = create_analyzer_from_definition("phrase_prefix", &def).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("new york city")
+            .unwrap()
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(
+            tokens,
+            vec!["new ", "new y", "new york", "york", "york ", "york city"]
+        );
+
+        let def = AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![TokenFilterConfig::Shingle {
+                min_shingle_size: 1,
+                max_shingle_size: 2,
+                token_separator: " ".into(),
+                output_unigrams: true,
+            }],
+        };
+        assert!(create_analyzer_from_definition("bad", &def).is_err());
+    }
+
+    #[test]
+    fn test_create_from_definition_with_folding() {
+        let def = AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![
+                TokenFilterConfig::CaseFold,
+                TokenFilterConfig::AsciiFolding {
+                    preserve_original: true,
+                },
+            ],
+        };
+        let analyzer = create_analyzer_from_definition("folded", &def).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("CRÈME Brûlée ΣΟΦΟΣ")
+            .unwrap()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                ("creme".to_string(), 1),
+                ("crème".to_string(), 0),
+                ("brulee".to_string(), 1),
+                ("brûlée".to_string(), 0),
+                ("σοφοσ".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_from_definition_with_word_delimiter_graph() {
+        let def = AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![
+                TokenFilterConfig::WordDelimiterGraph {
+                    generate_word_parts: true,
+                    generate_number_parts: true,
+                    split_on_case_change: true,
+                    split_on_numerics: true,
+                    catenate_words: false,
+                    catenate_numbers: false,
+                    catenate_all: true,
+                    preserve_original: false,
+                },
+                TokenFilterConfig::Lowercase,
+            ],
+        };
+        let analyzer = create_analyzer_from_definition("catalog", &def).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("iPhone15Pro case")
+            .unwrap()
+            .map(|t| (t.text, t.position_increment, t.position_length))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                ("iphone15pro".to_string(), 1, 4),
+                ("i".to_string(), 0, 1),
+                ("phone".to_string(), 1, 1),
+                ("15".to_string(), 1, 1),
+                ("pro".to_string(), 1, 1),
+                ("case".to_string(), 1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_from_definition_with_dictionary_decompounder() {
+        let decompounder = |words: Vec<String>| AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![
+                TokenFilterConfig::Lowercase,
+                TokenFilterConfig::DictionaryDecompounder {
+                    words,
+                    min_word_size: 5,
+                    min_subword_size: 2,
+                    max_subword_size: 15,
+                    only_longest_match: false,
+                },
+            ],
+        };
+
+        let def = decompounder(vec!["dampf".into(), "schiff".into()]);
+        let analyzer = create_analyzer_from_definition("german", &def).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("Dampfschiff")
+            .unwrap()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                ("dampfschiff".to_string(), 1),
+                ("dampf".to_string(), 0),
+                ("schiff".to_string(), 0),
+            ]
+        );
+
+        assert!(create_analyzer_from_definition("bad", &decompounder(vec![])).is_err());
+    }
+
+    #[test]
+    fn test_create_from_definition_with_phonetic() {
+        let phonetic = |encoder: &str, inject: bool| AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![
+                TokenFilterConfig::Lowercase,
+                TokenFilterConfig::Phonetic {
+                    encoder: encoder.into(),
+                    inject,
+                },
+            ],
+        };
+
+        let analyzer =
+            create_analyzer_from_definition("names", &phonetic("soundex", false)).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("Stephen Steven")
+            .unwrap()
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(tokens, vec!["S315", "S315"]);
+
+        let analyzer =
+            create_analyzer_from_definition("names", &phonetic("double_metaphone", true)).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("Schmidt")
+            .unwrap()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                ("schmidt".to_string(), 1),
+                ("XMT".to_string(), 0),
+                ("SMT".to_string(), 0),
+            ]
+        );
+
+        let analyzer =
+            create_analyzer_from_definition("names", &phonetic("beider_morse", false)).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("Schmidt Smith")
+            .unwrap()
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(tokens, vec!["smit", "skmit", "smit"]);
+
+        assert!(create_analyzer_from_definition("bad", &phonetic("caverphone", true)).is_err());
+    }
+
+    #[test]
+    fn test_create_from_definition_with_synonym_graph() {
+        let synonym_graph = |synonyms: Vec<String>, path: Option<String>| AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![
+                TokenFilterConfig::Lowercase,
+                TokenFilterConfig::SynonymGraph {
+                    synonyms,
+                    path,
+                    format: None,
+                    keep_original: true,
+                    boost: None,
+                    resource: None,
+                },
+            ],
+        };
+
+        let def = synonym_graph(vec!["quick, fast".into(), "nyc => new york".into()], None);
+        let analyzer = create_analyzer_from_definition("syn", &def).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("Quick trip to NYC")
+            .unwrap()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                ("quick".to_string(), 1),
+                ("fast".to_string(), 0),
+                ("trip".to_string(), 1),
+                ("to".to_string(), 1),
+                ("new".to_string(), 1),
+                ("york".to_string(), 1),
+            ]
+        );
+
+        let both = synonym_graph(vec!["a, b".into()], Some("synonyms.txt".into()));
+        assert!(create_analyzer_from_definition("syn", &both).is_err());
+    }
+
+    #[test]
+    fn test_create_with_resources() {
+        let def = AnalyzerDefinition {
+            char_filters: vec![],
+            tokenizer: TokenizerConfig::Whitespace,
+            token_filters: vec![
+                TokenFilterConfig::Stop {
+                    words: None,
+                    resource: Some("stop".into()),
+                    language: None,
+                },
+                TokenFilterConfig::SynonymGraph {
+                    synonyms: vec![],
+                    path: None,
+                    format: None,
+                    keep_original: true,
+                    boost: None,
+                    resource: Some("synonyms".into()),
+                },
+            ],
+        };
+        assert_eq!(def.resource_names(), vec!["stop", "synonyms"]);
+
+        let mut resources = BTreeMap::new();
+        for (name, content) in [
+            (
+                "stop",
+                ResourceContent::StopWords {
+                    words: vec!["to".into()],
+                },
+            ),
+            (
+                "synonyms",
+                ResourceContent::Synonyms {
+                    rules: vec!["quick, fast".into()],
+                },
+            ),
+        ] {
+            resources.insert(
+                name.to_string(),
+                ResourceSet {
+                    name: name.to_string(),
+                    version: 1,
+                    content,
+                },
+            );
+        }
+        let analyzer = create_analyzer_with_resources("res", &def, &resources).unwrap();
+        let tokens: Vec<_> = analyzer
+            .analyze("quick to")
+            .unwrap()
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(tokens, vec!["quick", "fast"]);
+
+        // Missing sets and sets of the wrong kind are rejected.
+        assert!(create_analyzer_from_definition("res", &def).is_err());
+        resources.get_mut("stop").unwrap().content = ResourceContent::Synonyms { rules: vec![] };
+        assert!(create_analyzer_with_resources("res", &def, &resources).is_err());
+    }
 }
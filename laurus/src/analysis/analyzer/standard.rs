@@ -11,6 +11,10 @@
 //! 2. LowercaseFilter
 //! 3. StopFilter (33 common English stop words)
 //!
+//! [`StandardAnalyzer::with_folding`] replaces lowercasing with Unicode
+//! case folding followed by ASCII folding, so that "Café" and "cafe" or
+//! "Straße" and "strasse" produce the same terms.
+//!
 //! # Examples
 //!
 //! ```ignore
@@ -31,6 +35,8 @@ use std::sync::Arc;
 use crate::analysis::analyzer::analyzer::Analyzer;
 use crate::analysis::analyzer::pipeline::PipelineAnalyzer;
 use crate::analysis::token::TokenStream;
+use crate::analysis::token_filter::ascii_folding::AsciiFoldingFilter;
+use crate::analysis::token_filter::case_fold::CaseFoldFilter;
 use crate::analysis::token_filter::lowercase::LowercaseFilter;
 use crate::analysis::token_filter::stop::StopFilter;
 use crate::analysis::tokenizer::regex::RegexTokenizer;
@@ -65,6 +71,21 @@ impl StandardAnalyzer {
         Ok(StandardAnalyzer { inner: analyzer })
     }
 
+    /// Create a new standard analyzer that folds case and diacritics.
+    ///
+    /// Tokens are case folded and folded to ASCII before stop word
+    /// filtering. The analyzer is registered as `"standard_folding"`.
+    pub fn with_folding() -> Result<Self> {
+        let tokenizer = Arc::new(RegexTokenizer::new()?);
+        let analyzer = PipelineAnalyzer::new(tokenizer)
+            .add_filter(Arc::new(CaseFoldFilter::new()))
+            .add_filter(Arc::new(AsciiFoldingFilter::new()))
+            .add_filter(Arc::new(StopFilter::new()))
+            .with_name("standard_folding".to_string());
+
+        Ok(StandardAnalyzer { inner: analyzer })
+    }
+
     /// Get the inner pipeline analyzer.
     pub fn inner(&self) -> &PipelineAnalyzer {
         &self.inner
@@ -82,8 +103,8 @@ impl Analyzer for StandardAnalyzer {
         self.inner.analyze(text)
     }
 
-    fn name(&self) -> &'static str {
-        "standard"
+    fn name(&self) -> &str {
+        self.inner.name()
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -132,4 +153,18 @@ mod tests {
         assert_eq!(tokens[1].text, "the");
         assert_eq!(tokens[2].text, "world");
     }
+
+    #[test]
+    fn test_standard_analyzer_with_folding() {
+        let analyzer = StandardAnalyzer::with_folding().unwrap();
+        assert_eq!(analyzer.name(), "standard_folding");
+
+        let texts: Vec<String> = analyzer
+            .analyze("The naïve CAFÉ in Straße ＡＢＣ")
+            .unwrap()
+            .map(|t| t.text)
+            .collect();
+
+        assert_eq!(texts, vec!["naive", "cafe", "strasse", "abc"]);
+    }
 }
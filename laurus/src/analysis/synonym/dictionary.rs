@@ -1,13 +1,38 @@
 //! Synonym dictionary for mapping terms to their synonyms.
 //!
 //! Uses FST (Finite State Transducer) for memory-efficient storage and fast lookup.
-
+//!
+//! Dictionaries can be built from JSON synonym groups, from rules in the Solr
+//! synonym format, or from the WordNet prolog database (`wn_s.pl`):
+//!
+//! ```text
+//! # Equivalent terms: each one expands to all the others.
+//! ml, machine learning, machine-learning
+//! # One-way mapping: "ny" is replaced by "new york".
+//! ny => new york
+//! # Keep the original by listing it on the right-hand side.
+//! tv => tv, television
+//! ```
+
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use fst::{Map, MapBuilder, Streamer};
 
 use crate::error::{LaurusError, Result};
 
+/// Supported synonym file formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SynonymFormat {
+    /// JSON array of synonym groups, e.g. `[["ml", "machine learning"]]`.
+    #[default]
+    Json,
+    /// Solr synonym rules, one per line (`a, b, c` or `a, b => c`).
+    Solr,
+    /// WordNet prolog synonym database (`s(...)` facts, as in `wn_s.pl`).
+    WordNet,
+}
+
 /// Synonym dictionary for token expansion.
 ///
 /// Maps terms to their synonyms using FST (Finite State Transducer) for memory efficiency.
@@ -19,6 +44,9 @@ pub struct SynonymDictionary {
     fst_map: Arc<Map<Arc<[u8]>>>,
     /// Actual synonym lists indexed by FST values
     synonym_lists: Arc<Vec<Vec<String>>>,
+    /// Whether the term at the same index is replaced by its synonyms
+    /// (a one-way `=>` rule) rather than kept alongside them
+    replacing: Arc<Vec<bool>>,
     /// Maximum number of tokens to look ahead for multi-word synonym matching
     max_phrase_length: usize,
 }
@@ -37,18 +65,7 @@ impl SynonymDictionary {
     pub fn new(path: Option<&str>) -> Result<Self> {
         match path {
             Some(file_path) => Self::load_from_file(file_path),
-            None => {
-                // Create empty FST
-                let builder = MapBuilder::memory();
-                let fst_bytes = builder.into_inner().unwrap();
-                let fst_map = Map::new(Arc::from(fst_bytes)).unwrap();
-
-                Ok(Self {
-                    fst_map: Arc::new(fst_map),
-                    synonym_lists: Arc::new(Vec::new()),
-                    max_phrase_length: 1,
-                })
-            }
+            None => Self::from_mappings(Vec::new()),
         }
     }
 
@@ -65,6 +82,11 @@ impl SynonymDictionary {
     /// ]
     /// ```
     pub fn load_from_file(path: &str) -> Result<Self> {
+        Self::load_from_file_with_format(path, SynonymFormat::Json)
+    }
+
+    /// Load synonym dictionary from a file in the given format.
+    pub fn load_from_file_with_format(path: &str, format: SynonymFormat) -> Result<Self> {
         let content = std::fs::read_to_string(path).map_err(|e| {
             LaurusError::storage(format!(
                 "Failed to read synonym dictionary file '{}': {}",
@@ -72,72 +94,152 @@ impl SynonymDictionary {
             ))
         })?;
 
-        let synonym_groups: Vec<Vec<String>> = serde_json::from_str(&content).map_err(|e| {
-            LaurusError::parse(format!(
-                "Failed to parse synonym dictionary JSON from '{}': {}",
-                path, e
-            ))
-        })?;
-
-        Self::from_synonym_groups(synonym_groups)
+        match format {
+            SynonymFormat::Json => {
+                let synonym_groups: Vec<Vec<String>> =
+                    serde_json::from_str(&content).map_err(|e| {
+                        LaurusError::parse(format!(
+                            "Failed to parse synonym dictionary JSON from '{}': {}",
+                            path, e
+                        ))
+                    })?;
+                Self::from_synonym_groups(synonym_groups)
+            }
+            SynonymFormat::Solr => Self::from_solr_rules(&content),
+            SynonymFormat::WordNet => Self::from_wordnet(&content),
+        }
     }
 
     /// Build a synonym dictionary from synonym groups.
     pub fn from_synonym_groups(synonym_groups: Vec<Vec<String>>) -> Result<Self> {
-        use std::collections::HashMap;
-
-        // First, build all synonym mappings
-        let mut term_to_synonyms: HashMap<String, Vec<String>> = HashMap::new();
-        let mut max_phrase_length = 1;
-
+        let mut mappings = Vec::new();
         for group in synonym_groups {
-            if group.is_empty() {
+            mappings.extend(group_mappings(group));
+        }
+        Self::from_mappings(mappings)
+    }
+
+    /// Build a synonym dictionary from rules in the Solr synonym format.
+    ///
+    /// Each non-empty line that does not start with `#` is a rule:
+    ///
+    /// - `a, b, c` declares equivalent terms; each expands to the others.
+    /// - `a, b => c, d` maps `a` and `b` to `c` and `d` only. The matched
+    ///   term is replaced unless it also appears on the right-hand side.
+    ///
+    /// Commas and `=>` can be escaped with a backslash, and runs of
+    /// whitespace inside a term are collapsed to a single space.
+    ///
+    /// # Errors
+    ///
+    /// Returns a parse error for rules with more than one `=>` or with an
+    /// empty side.
+    pub fn from_solr_rules(rules: &str) -> Result<Self> {
+        let mut mappings = Vec::new();
+        for (line_no, line) in rules.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
                 continue;
             }
-
-            // Calculate max phrase length for this group
-            let max_words = group
-                .iter()
-                .map(|t| {
-                    let word_count = t.split_whitespace().count();
-                    if word_count == 1 {
-                        let has_ascii = t.chars().any(|c| c.is_ascii_alphanumeric());
-                        let char_count = t.chars().count();
-                        if !has_ascii && char_count > 3 {
-                            char_count.div_ceil(2)
-                        } else {
-                            1
-                        }
-                    } else {
-                        word_count
+            let sides = split_unescaped(line, "=>");
+            let invalid = |reason: &str| {
+                LaurusError::parse(format!(
+                    "Invalid synonym rule on line {}: {reason}: {line}",
+                    line_no + 1
+                ))
+            };
+            match sides.as_slice() {
+                [group] => mappings.extend(group_mappings(parse_solr_terms(group))),
+                [lhs, rhs] => {
+                    let sources = parse_solr_terms(lhs);
+                    let targets = parse_solr_terms(rhs);
+                    if sources.is_empty() || targets.is_empty() {
+                        return Err(invalid("both sides of '=>' must name terms"));
                     }
-                })
-                .max()
-                .unwrap_or(1);
-            max_phrase_length = max_phrase_length.max(max_words);
-
-            // Create bidirectional mappings
-            for (i, term) in group.iter().enumerate() {
-                let mut synonyms = Vec::new();
-                for (j, other_term) in group.iter().enumerate() {
-                    if i != j {
-                        synonyms.push(other_term.clone());
+                    for source in sources {
+                        let replacing = !targets.contains(&source);
+                        let synonyms: Vec<String> = targets
+                            .iter()
+                            .filter(|target| **target != source)
+                            .cloned()
+                            .collect();
+                        mappings.push((source, synonyms, replacing));
                     }
                 }
-                term_to_synonyms.insert(term.clone(), synonyms);
+                _ => return Err(invalid("more than one '=>'")),
+            }
+        }
+        Self::from_mappings(mappings)
+    }
+
+    /// Build a synonym dictionary from the WordNet prolog synonym database.
+    ///
+    /// Every `s(synset_id, w_num, 'word', ss_type, sense_number, tag_count).`
+    /// fact adds `word` to its synset, and each synset with more than one word
+    /// becomes a group of equivalent terms. Lines that are not `s(...)` facts are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a parse error for malformed `s(...)` facts.
+    pub fn from_wordnet(content: &str) -> Result<Self> {
+        let mut synsets: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (line_no, line) in content.lines().enumerate() {
+            let line = line.trim();
+            let Some(args) = line.strip_prefix("s(") else {
+                continue;
+            };
+            let (synset_id, word) = parse_wordnet_fact(args).ok_or_else(|| {
+                LaurusError::parse(format!(
+                    "Invalid WordNet fact on line {}: {line}",
+                    line_no + 1
+                ))
+            })?;
+            let words = synsets.entry(synset_id).or_default();
+            if !words.contains(&word) {
+                words.push(word);
+            }
+        }
+        Self::from_synonym_groups(
+            synsets
+                .into_values()
+                .filter(|words| words.len() > 1)
+                .collect(),
+        )
+    }
+
+    /// Build the FST from `(term, synonyms, replacing)` mappings.
+    ///
+    /// Mappings for the same term are merged; the term is only replaced if
+    /// every rule mentioning it replaces it.
+    fn from_mappings(mappings: Vec<(String, Vec<String>, bool)>) -> Result<Self> {
+        let mut merged: BTreeMap<String, (Vec<String>, bool)> = BTreeMap::new();
+        for (term, synonyms, replacing) in mappings {
+            let entry = merged
+                .entry(term)
+                .or_insert_with(|| (Vec::new(), replacing));
+            for synonym in synonyms {
+                if !entry.0.contains(&synonym) {
+                    entry.0.push(synonym);
+                }
             }
+            entry.1 &= replacing;
         }
 
-        // Build FST from sorted keys
-        let mut synonym_lists = Vec::new();
-        let mut sorted_terms: Vec<_> = term_to_synonyms.keys().cloned().collect();
-        sorted_terms.sort();
+        let max_phrase_length = merged
+            .keys()
+            .map(|term| phrase_length(term))
+            .max()
+            .unwrap_or(1)
+            .max(1);
 
+        // BTreeMap iteration yields the keys in the sorted order the FST needs.
+        let mut synonym_lists = Vec::with_capacity(merged.len());
+        let mut replacing_flags = Vec::with_capacity(merged.len());
         let mut builder = MapBuilder::memory();
-        for term in sorted_terms {
-            let synonyms = term_to_synonyms.remove(&term).unwrap();
+        for (term, (synonyms, replacing)) in merged {
             let index = synonym_lists.len() as u64;
             synonym_lists.push(synonyms);
+            replacing_flags.push(replacing);
             builder
                 .insert(term.as_bytes(), index)
                 .map_err(|e| LaurusError::parse(format!("FST build error: {}", e)))?;
@@ -152,6 +254,7 @@ impl SynonymDictionary {
         Ok(Self {
             fst_map: Arc::new(fst_map),
             synonym_lists: Arc::new(synonym_lists),
+            replacing: Arc::new(replacing_flags),
             max_phrase_length,
         })
     }
@@ -162,6 +265,15 @@ impl SynonymDictionary {
         self.synonym_lists.get(index)
     }
 
+    /// Whether `term` is replaced by its synonyms rather than kept alongside
+    /// them, as declared by a one-way `=>` rule.
+    pub fn replaces(&self, term: &str) -> bool {
+        self.fst_map
+            .get(term.as_bytes())
+            .and_then(|index| self.replacing.get(index as usize).copied())
+            .unwrap_or(false)
+    }
+
     /// Add a synonym group where all terms are synonyms of each other.
     ///
     /// Note: This method rebuilds the entire FST, so it's inefficient for adding
@@ -175,38 +287,27 @@ impl SynonymDictionary {
     ///
     /// # Panics
     ///
-    /// Panics if rebuilding the internal FST fails (e.g., due to duplicate
-    /// or out-of-order keys produced by the merged synonym groups).
+    /// Panics if rebuilding the internal FST fails.
     pub fn add_synonym_group(&mut self, terms: Vec<String>) {
         // Extract existing mappings from FST
-        let mut all_groups = Vec::new();
-        let mut processed_terms = std::collections::HashSet::new();
-
-        // Collect existing synonym groups using FST streamer
+        let mut mappings = Vec::new();
         let mut stream = self.fst_map.stream();
         while let Some((key, value)) = stream.next() {
-            let term = String::from_utf8_lossy(key).to_string();
-            if processed_terms.contains(&term) {
-                continue;
-            }
-
             let index = value as usize;
-            if let Some(synonyms) = self.synonym_lists.get(index) {
-                let mut group = vec![term.clone()];
-                group.extend(synonyms.clone());
-                processed_terms.insert(term);
-                for syn in synonyms {
-                    processed_terms.insert(syn.clone());
-                }
-                all_groups.push(group);
-            }
+            mappings.push((
+                String::from_utf8_lossy(key).to_string(),
+                self.synonym_lists[index].clone(),
+                self.replacing[index],
+            ));
         }
 
-        // Add new group
-        all_groups.push(terms);
+        // A new group overrides the existing mappings of its terms.
+        let group = group_mappings(terms);
+        mappings.retain(|(term, _, _)| !group.iter().any(|(other, _, _)| other == term));
+        mappings.extend(group);
 
         // Rebuild FST
-        *self = Self::from_synonym_groups(all_groups).unwrap();
+        *self = Self::from_mappings(mappings).unwrap();
     }
 
     /// Get the maximum phrase length in the dictionary.
@@ -215,6 +316,97 @@ impl SynonymDictionary {
     }
 }
 
+/// Bidirectional mappings for a group of equivalent terms.
+fn group_mappings(group: Vec<String>) -> Vec<(String, Vec<String>, bool)> {
+    group
+        .iter()
+        .map(|term| {
+            let synonyms = group
+                .iter()
+                .filter(|other| *other != term)
+                .cloned()
+                .collect();
+            (term.clone(), synonyms, false)
+        })
+        .collect()
+}
+
+/// Number of tokens a dictionary term is expected to span.
+fn phrase_length(term: &str) -> usize {
+    let word_count = term.split_whitespace().count();
+    if word_count == 1 {
+        // Unsegmented CJK terms may be split into several tokens.
+        let has_ascii = term.chars().any(|c| c.is_ascii_alphanumeric());
+        let char_count = term.chars().count();
+        if !has_ascii && char_count > 3 {
+            char_count.div_ceil(2)
+        } else {
+            1
+        }
+    } else {
+        word_count
+    }
+}
+
+/// Split `text` on `separator`, ignoring occurrences escaped by a backslash.
+fn split_unescaped<'a>(text: &'a str, separator: &str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if i >= start && text[i..].starts_with(separator) {
+            parts.push(&text[start..i]);
+            start = i + separator.len();
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Parse one side of a Solr rule into unescaped, whitespace-normalized terms.
+fn parse_solr_terms(side: &str) -> Vec<String> {
+    split_unescaped(side, ",")
+        .into_iter()
+        .map(|term| {
+            let mut unescaped = String::with_capacity(term.len());
+            let mut chars = term.chars();
+            while let Some(c) = chars.next() {
+                if c == '\\' {
+                    unescaped.extend(chars.next());
+                } else {
+                    unescaped.push(c);
+                }
+            }
+            unescaped.split_whitespace().collect::<Vec<_>>().join(" ")
+        })
+        .filter(|term| !term.is_empty())
+        .collect()
+}
+
+/// Parse the arguments of a WordNet `s(...)` fact into its synset ID and word.
+fn parse_wordnet_fact(args: &str) -> Option<(String, String)> {
+    let (synset_id, rest) = args.split_once(',')?;
+    let (_, rest) = rest.split_once(',')?;
+    let mut chars = rest.strip_prefix('\'')?.chars().peekable();
+    let mut word = String::new();
+    loop {
+        match chars.next()? {
+            // Quotes inside the word are doubled.
+            '\'' if chars.peek() == Some(&'\'') => {
+                chars.next();
+                word.push('\'');
+            }
+            '\'' => break,
+            c => word.push(c),
+        }
+    }
+    Some((synset_id.trim().to_string(), word))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,4 +470,53 @@ mod tests {
         let learning_synonyms = learning_synonyms.unwrap();
         assert!(learning_synonyms.contains(&"勉強".to_string()));
     }
+
+    #[test]
+    fn test_synonym_dictionary_solr_rules() {
+        let dict = SynonymDictionary::from_solr_rules(
+            "# comment\n\
+             ml, machine  learning\n\
+             ny => new york\n\
+             tv => tv, television\n\
+             a\\,b => comma\n",
+        )
+        .unwrap();
+
+        assert_eq!(dict.get_synonyms("ml").unwrap(), &vec!["machine learning"]);
+        assert_eq!(dict.get_synonyms("machine learning").unwrap(), &vec!["ml"]);
+        assert!(!dict.replaces("ml"));
+
+        assert_eq!(dict.get_synonyms("ny").unwrap(), &vec!["new york"]);
+        assert!(dict.replaces("ny"));
+        assert!(dict.get_synonyms("new york").is_none());
+
+        assert_eq!(dict.get_synonyms("tv").unwrap(), &vec!["television"]);
+        assert!(!dict.replaces("tv"));
+        assert!(dict.get_synonyms("a,b").is_some());
+        assert_eq!(dict.max_phrase_length(), 2);
+
+        assert!(SynonymDictionary::from_solr_rules("a => b => c").is_err());
+        assert!(SynonymDictionary::from_solr_rules("a =>").is_err());
+    }
+
+    #[test]
+    fn test_synonym_dictionary_wordnet() {
+        let dict = SynonymDictionary::from_wordnet(
+            "s(100001740,1,'entity',n,1,11).\n\
+             s(102084071,1,'dog',n,1,42).\n\
+             s(102084071,2,'domestic dog',n,1,0).\n\
+             s(102084071,3,'canis familiaris',n,1,0).\n\
+             s(104661580,1,'o''clock',r,1,0).\n\
+             s(104661580,2,'oclock',r,1,0).\n",
+        )
+        .unwrap();
+
+        let synonyms = dict.get_synonyms("dog").unwrap();
+        assert!(synonyms.contains(&"domestic dog".to_string()));
+        assert!(synonyms.contains(&"canis familiaris".to_string()));
+        assert!(dict.get_synonyms("entity").is_none());
+        assert_eq!(dict.get_synonyms("oclock").unwrap(), &vec!["o'clock"]);
+
+        assert!(SynonymDictionary::from_wordnet("s(1,1,dog).").is_err());
+    }
 }
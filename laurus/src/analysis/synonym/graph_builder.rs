@@ -75,8 +75,23 @@ impl SynonymGraphBuilder {
 
     /// Build graph tokens from matched synonyms.
     ///
-    /// This creates tokens with proper position_increment and position_length attributes
-    /// to represent the synonym graph structure.
+    /// The original tokens (if kept) and each synonym form alternative paths
+    /// from a shared start node to a shared end node. The original path comes
+    /// first so that its tokens stay on consecutive positions; the internal
+    /// nodes of multi-word synonyms are numbered after it. Tokens are returned
+    /// ordered by start node, with `position_increment` relative to the
+    /// previous token (the first token has an increment of 1) and
+    /// `position_length` set to the distance to the token's end node.
+    ///
+    /// ```text
+    /// "ml" with synonym "machine learning":
+    ///   ml       (pos_inc=1, pos_len=2)   node 0 -> 2
+    ///   machine  (pos_inc=0, pos_len=1)   node 0 -> 1
+    ///   learning (pos_inc=1, pos_len=1)   node 1 -> 2
+    /// ```
+    ///
+    /// Originals matched by a one-way (`=>`) rule are replaced by their
+    /// synonyms even when `keep_original` is set.
     pub fn build_graph_tokens(
         &self,
         original_tokens: &[Token],
@@ -84,27 +99,21 @@ impl SynonymGraphBuilder {
         match_length: usize,
         synonyms: &[String],
     ) -> Vec<Token> {
-        let mut result = Vec::new();
-        let match_start_offset = original_tokens[match_start].start_offset;
-        let match_end_offset = original_tokens[match_start + match_length - 1].end_offset;
-
-        // Add original tokens if keep_original is true
-        if self.keep_original {
-            for (i, original) in original_tokens[match_start..match_start + match_length]
-                .iter()
-                .enumerate()
-            {
-                let mut token = original.clone();
-                token.position_increment = if i == 0 { 1 } else { 0 };
-                token.position_length = 1;
-                result.push(token);
-            }
+        let originals = &original_tokens[match_start..match_start + match_length];
+        let match_start_offset = originals[0].start_offset;
+        let match_end_offset = originals[match_length - 1].end_offset;
+        let start_position = originals[0].position;
+
+        // Each path is a list of tokens; only the originals carry their own
+        // offsets and types.
+        let mut paths: Vec<Vec<Token>> = Vec::new();
+        if self.keeps_original(originals) {
+            paths.push(originals.to_vec());
         }
 
-        // Add synonym tokens
         for synonym in synonyms {
             // Tokenize the synonym using the tokenizer if available
-            let syn_tokens = if let Some(tokenizer) = &self.tokenizer {
+            let syn_words = if let Some(tokenizer) = &self.tokenizer {
                 // Use tokenizer to split synonym
                 match tokenizer.tokenize(synonym) {
                     Ok(tokens) => tokens.map(|t| t.text).collect::<Vec<_>>(),
@@ -118,51 +127,90 @@ impl SynonymGraphBuilder {
                 synonym.split_whitespace().map(|s| s.to_string()).collect()
             };
 
-            if syn_tokens.len() == 1 {
-                // Single-word synonym
-                let mut token = Token::new(&syn_tokens[0], original_tokens[match_start].position);
-                token.position_increment = if self.keep_original { 0 } else { 1 };
-                token.position_length = match_length; // Spans the original phrase length
-                token.start_offset = match_start_offset;
-                token.end_offset = match_end_offset;
-                token = token.with_token_type(TokenType::Synonym);
-
-                // Apply boost if configured
-                if let Some(boost) = self.synonym_boost {
-                    // Single-word synonyms spanning multiple positions get slightly higher boost
-                    let base_boost = if match_length > 1 { 0.9 } else { 0.8 };
-                    token = token.with_boost(base_boost * boost);
-                }
-
-                result.push(token);
-            } else {
-                // Multi-word synonym
-                for (i, syn_word) in syn_tokens.iter().enumerate() {
-                    let mut token = Token::new(syn_word, original_tokens[match_start].position + i);
-                    token.position_increment = if i == 0 {
-                        if self.keep_original { 0 } else { 1 }
-                    } else {
-                        1
-                    };
-                    // First token spans the entire synonym phrase length
-                    token.position_length = if i == 0 { syn_tokens.len() } else { 1 };
+            let path_length = syn_words.len();
+            let path = syn_words
+                .iter()
+                .enumerate()
+                .map(|(i, syn_word)| {
+                    let mut token = Token::new(syn_word, start_position + i);
                     token.start_offset = match_start_offset;
                     token.end_offset = match_end_offset;
                     token = token.with_token_type(TokenType::Synonym);
 
                     // Apply boost if configured
                     if let Some(boost) = self.synonym_boost {
-                        // Multi-word synonyms: first token gets slightly higher boost
-                        let base_boost = if i == 0 { 0.9 } else { 0.8 };
+                        // Single-word synonyms spanning multiple positions and
+                        // the first token of multi-word synonyms get slightly
+                        // higher boost
+                        let base_boost = if path_length == 1 {
+                            if match_length > 1 { 0.9 } else { 0.8 }
+                        } else if i == 0 {
+                            0.9
+                        } else {
+                            0.8
+                        };
                         token = token.with_boost(base_boost * boost);
                     }
+                    token
+                })
+                .collect::<Vec<_>>();
+            if !path.is_empty() {
+                paths.push(path);
+            }
+        }
 
-                    result.push(token);
-                }
+        if paths.is_empty() {
+            return originals.to_vec();
+        }
+
+        // Number the nodes: 0 is the shared start node, internal nodes are
+        // allocated path by path, and the shared end node comes last.
+        let mut next_node = 1;
+        let path_nodes: Vec<Vec<usize>> = paths
+            .iter()
+            .map(|path| {
+                let mut nodes = vec![0];
+                nodes.extend(next_node..next_node + path.len() - 1);
+                next_node += path.len() - 1;
+                nodes
+            })
+            .collect();
+        let end_node = next_node;
+
+        let mut positioned: Vec<(usize, Token)> = Vec::new();
+        for (path, nodes) in paths.into_iter().zip(path_nodes) {
+            for (i, mut token) in path.into_iter().enumerate() {
+                let to = nodes.get(i + 1).copied().unwrap_or(end_node);
+                token.position_length = to - nodes[i];
+                positioned.push((nodes[i], token));
             }
         }
+        // Stable, so tokens leaving the same node keep their path order.
+        positioned.sort_by_key(|(node, _)| *node);
+
+        let mut previous = None;
+        positioned
+            .into_iter()
+            .map(|(node, mut token)| {
+                token.position_increment = match previous {
+                    None => 1,
+                    Some(prev) => node - prev,
+                };
+                previous = Some(node);
+                token
+            })
+            .collect()
+    }
 
-        result
+    /// Whether the matched original tokens are kept alongside their synonyms.
+    fn keeps_original(&self, originals: &[Token]) -> bool {
+        if !self.keep_original {
+            return false;
+        }
+        let texts: Vec<&str> = originals.iter().map(|t| t.text.as_str()).collect();
+        let replaced = self.dictionary.replaces(&texts.join(" "))
+            || (texts.len() > 1 && self.dictionary.replaces(&texts.join("")));
+        !replaced
     }
 
     /// Try to match a synonym starting at the given position in the token buffer.
@@ -282,7 +330,12 @@ mod tests {
         assert!(machine_token.is_some());
         let machine = machine_token.unwrap();
         assert_eq!(machine.position_increment, 0);
-        assert_eq!(machine.position_length, 2); // Spans 2 positions
+        assert_eq!(machine.position_length, 1); // First edge of the side path
+
+        // The original spans the whole side path
+        let ml = result.iter().find(|t| t.text == "ml").unwrap();
+        assert_eq!(ml.position_increment, 1);
+        assert_eq!(ml.position_length, 2);
 
         // Find "learning" token (second of multi-word synonym)
         let learning_token = result.iter().find(|t| t.text == "learning");
@@ -7,7 +7,10 @@
 //! # Available Filters
 //!
 //! - [`lowercase::LowercaseFilter`] - Converts tokens to lowercase
+//! - [`case_fold::CaseFoldFilter`] - Applies Unicode case folding
+//! - [`ascii_folding::AsciiFoldingFilter`] - Folds accented and full-width characters to ASCII
 //! - [`stop::StopFilter`] - Removes stop words
+//! - [`part_of_speech::PartOfSpeechStopFilter`] - Removes tokens by part-of-speech tag
 //! - [`stem::StemFilter`] - Reduces words to their stem form
 //! - [`synonym_graph::SynonymGraphFilter`] - Expands synonyms
 //! - [`limit::LimitFilter`] - Limits number of tokens
@@ -15,6 +18,11 @@
 //! - [`strip::StripFilter`] - Removes specific characters
 //! - [`remove_empty::RemoveEmptyFilter`] - Removes empty tokens
 //! - [`flatten_graph::FlattenGraphFilter`] - Flattens token graphs
+//! - [`edge_ngram::EdgeNgramFilter`] - Replaces tokens with their prefixes
+//! - [`shingle::ShingleFilter`] - Combines adjacent tokens into word n-grams
+//! - [`phonetic::PhoneticFilter`] - Adds or substitutes phonetic codes
+//! - [`word_delimiter_graph::WordDelimiterGraphFilter`] - Splits tokens into subwords as a token graph
+//! - [`dictionary_decompounder::DictionaryDecompounderFilter`] - Adds dictionary words found in compounds
 //!
 //! # Examples
 //!
@@ -126,12 +134,20 @@ pub trait Filter: Send + Sync {
 }
 
 // Individual filter modules
+pub mod ascii_folding;
 pub mod boost;
+pub mod case_fold;
+pub mod dictionary_decompounder;
+pub mod edge_ngram;
 pub mod flatten_graph;
 pub mod limit;
 pub mod lowercase;
+pub mod part_of_speech;
+pub mod phonetic;
 pub mod remove_empty;
+pub mod shingle;
 pub mod stem;
 pub mod stop;
 pub mod strip;
 pub mod synonym_graph;
+pub mod word_delimiter_graph;
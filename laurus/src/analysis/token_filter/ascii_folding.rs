@@ -0,0 +1,274 @@
+//! ASCII folding filter implementation.
+//!
+//! This module provides a filter that converts letters, digits, and
+//! punctuation outside the Basic Latin block to their ASCII equivalents,
+//! e.g. "café" to "cafe", "Øresund" to "Oresund", and full-width "ＡＢＣ"
+//! to "ABC". Characters without an ASCII equivalent, such as Cyrillic or
+//! Japanese letters, are left unchanged.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::Filter;
+//! use laurus::analysis::token_filter::ascii_folding::AsciiFoldingFilter;
+//! use laurus::analysis::token::Token;
+//!
+//! let filter = AsciiFoldingFilter::new();
+//! let tokens = vec![Token::new("café", 0), Token::new("Straße", 1)];
+//!
+//! let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+//!     .unwrap()
+//!     .map(|t| t.text)
+//!     .collect();
+//!
+//! assert_eq!(result, vec!["cafe", "Strasse"]);
+//! ```
+
+use std::borrow::Cow;
+
+use unicode_normalization::char::{decompose_compatible, is_combining_mark};
+
+use crate::analysis::token::TokenStream;
+use crate::analysis::token_filter::Filter;
+use crate::error::Result;
+
+/// ASCII replacements for characters that have no compatibility
+/// decomposition to ASCII.
+fn replacement(c: char) -> Option<&'static str> {
+    let replacement = match c {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'æ' => "ae",
+        'Æ' => "AE",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'ø' => "o",
+        'Ø' => "O",
+        'đ' | 'ð' => "d",
+        'Đ' | 'Ð' => "D",
+        'þ' => "th",
+        'Þ' => "TH",
+        'ł' | 'ŀ' => "l",
+        'Ł' | 'Ŀ' => "L",
+        'ı' => "i",
+        'ȷ' => "j",
+        'ħ' => "h",
+        'Ħ' => "H",
+        'ŋ' => "n",
+        'Ŋ' => "N",
+        'ŧ' => "t",
+        'Ŧ' => "T",
+        'ƒ' => "f",
+        'ĸ' => "q",
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{2033}' | '«' | '»' => "\"",
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => "-",
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+/// Append the ASCII form of `c` to `output`, or return `false` if it has
+/// none.
+fn fold_char(c: char, output: &mut String) -> bool {
+    if let Some(replacement) = replacement(c) {
+        output.push_str(replacement);
+        return true;
+    }
+
+    // Compatibility decomposition splits accented letters into a base
+    // letter and combining marks, and maps full-width forms, ligatures,
+    // and the like to their plain forms.
+    let mut folded = String::new();
+    let mut foldable = true;
+    decompose_compatible(c, |d| {
+        if d.is_ascii() {
+            folded.push(d);
+        } else if let Some(replacement) = replacement(d) {
+            folded.push_str(replacement);
+        } else if !is_combining_mark(d) {
+            foldable = false;
+        }
+    });
+    if foldable && !folded.is_empty() {
+        output.push_str(&folded);
+        true
+    } else {
+        false
+    }
+}
+
+/// Fold `text` to ASCII where possible.
+///
+/// Returns the input unchanged (and unallocated) if it is already ASCII.
+pub fn fold_to_ascii(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() || !fold_char(c, &mut output) {
+            output.push(c);
+        }
+    }
+    Cow::Owned(output)
+}
+
+/// A filter that folds tokens to ASCII.
+///
+/// With `preserve_original`, tokens that change are emitted in both
+/// forms: the folded token first, then the original at the same position
+/// (position increment 0), so that queries with and without accents
+/// match while exact spellings score higher.
+#[derive(Clone, Debug, Default)]
+pub struct AsciiFoldingFilter {
+    /// Whether the original token is kept next to the folded one
+    preserve_original: bool,
+}
+
+impl AsciiFoldingFilter {
+    /// Create a new ASCII folding filter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the original token is kept next to the folded one.
+    pub fn with_preserve_original(mut self, preserve_original: bool) -> Self {
+        self.preserve_original = preserve_original;
+        self
+    }
+
+    /// Check whether the original token is kept next to the folded one.
+    pub fn preserve_original(&self) -> bool {
+        self.preserve_original
+    }
+}
+
+impl Filter for AsciiFoldingFilter {
+    fn filter(&self, tokens: TokenStream) -> Result<TokenStream> {
+        let mut output = Vec::new();
+
+        for token in tokens {
+            if token.is_stopped() {
+                output.push(token);
+                continue;
+            }
+
+            let folded = match fold_to_ascii(&token.text) {
+                Cow::Borrowed(_) => None,
+                Cow::Owned(folded) if folded == token.text => None,
+                Cow::Owned(folded) => Some(folded),
+            };
+            match folded {
+                None => output.push(token),
+                Some(folded) => {
+                    output.push(token.with_text(folded));
+                    if self.preserve_original {
+                        output.push(token.with_position_increment(0));
+                    }
+                }
+            }
+        }
+
+        Ok(Box::new(output.into_iter()))
+    }
+
+    fn name(&self) -> &'static str {
+        "ascii_folding"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::token::Token;
+
+    fn run(filter: &AsciiFoldingFilter, tokens: Vec<Token>) -> Vec<(String, usize)> {
+        filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .map(|t| (t.text, t.position_increment))
+            .collect()
+    }
+
+    #[test]
+    fn test_fold_to_ascii() {
+        assert_eq!(fold_to_ascii("café"), "cafe");
+        assert_eq!(fold_to_ascii("Ångström"), "Angstrom");
+        assert_eq!(fold_to_ascii("Øresund"), "Oresund");
+        assert_eq!(fold_to_ascii("Łódź"), "Lodz");
+        assert_eq!(fold_to_ascii("Encyclopædia"), "Encyclopaedia");
+        assert_eq!(fold_to_ascii("Straße"), "Strasse");
+        assert_eq!(fold_to_ascii("ﬁnancial"), "financial");
+        assert_eq!(fold_to_ascii("ＡＢＣ１２３"), "ABC123");
+        assert_eq!(fold_to_ascii("l\u{2019}été"), "l'ete");
+    }
+
+    #[test]
+    fn test_fold_to_ascii_keeps_other_scripts() {
+        assert!(matches!(fold_to_ascii("plain"), Cow::Borrowed("plain")));
+        assert_eq!(fold_to_ascii("Москва"), "Москва");
+        assert_eq!(fold_to_ascii("й"), "й");
+        assert_eq!(fold_to_ascii("がっこう"), "がっこう");
+        assert_eq!(fold_to_ascii("Zürich東京"), "Zurich東京");
+    }
+
+    #[test]
+    fn test_ascii_folding_filter() {
+        let filter = AsciiFoldingFilter::new();
+        let tokens = vec![
+            Token::new("naïve", 0),
+            Token::new("résumé", 1),
+            Token::new("plain", 2),
+        ];
+
+        assert_eq!(
+            run(&filter, tokens),
+            vec![
+                ("naive".to_string(), 1),
+                ("resume".to_string(), 1),
+                ("plain".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ascii_folding_filter_preserve_original() {
+        let filter = AsciiFoldingFilter::new().with_preserve_original(true);
+        assert!(filter.preserve_original());
+        let tokens = vec![
+            Token::new("crème", 0),
+            Token::new("brûlée", 1),
+            Token::new("tart", 2),
+        ];
+
+        assert_eq!(
+            run(&filter, tokens),
+            vec![
+                ("creme".to_string(), 1),
+                ("crème".to_string(), 0),
+                ("brulee".to_string(), 1),
+                ("brûlée".to_string(), 0),
+                ("tart".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ascii_folding_filter_skips_stopped_tokens() {
+        let filter = AsciiFoldingFilter::new();
+        let result: Vec<Token> = filter
+            .filter(Box::new(vec![Token::new("été", 0).stop()].into_iter()))
+            .unwrap()
+            .collect();
+
+        assert_eq!(result[0].text, "été");
+        assert!(result[0].is_stopped());
+    }
+
+    #[test]
+    fn test_filter_name() {
+        assert_eq!(AsciiFoldingFilter::new().name(), "ascii_folding");
+    }
+}
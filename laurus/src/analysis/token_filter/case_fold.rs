@@ -0,0 +1,114 @@
+//! Unicode case folding filter implementation.
+//!
+//! This module provides a filter that applies the full default case
+//! folding of the Unicode Standard (`CaseFolding.txt`, statuses C and F).
+//! Case folding is meant for caseless matching rather than display: unlike
+//! lowercasing, it also maps characters whose case variants differ in more
+//! than their case, so that "Straße" and "STRASSE" both become "strasse",
+//! final sigma "ς" becomes "σ", and the micro sign "µ" becomes "μ".
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::Filter;
+//! use laurus::analysis::token_filter::case_fold::CaseFoldFilter;
+//! use laurus::analysis::token::Token;
+//!
+//! let filter = CaseFoldFilter::new();
+//! let tokens = vec![Token::new("Straße", 0), Token::new("ΣΊΣΥΦΟΣ", 1)];
+//!
+//! let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+//!     .unwrap()
+//!     .map(|t| t.text)
+//!     .collect();
+//!
+//! assert_eq!(result, vec!["strasse", "σίσυφοσ"]);
+//! ```
+
+use unicase::UniCase;
+
+use crate::analysis::token::TokenStream;
+use crate::analysis::token_filter::Filter;
+use crate::error::Result;
+
+/// Apply full Unicode default case folding to `text`.
+pub fn case_fold(text: &str) -> String {
+    UniCase::new(text).to_folded_case()
+}
+
+/// A filter that applies Unicode default case folding to tokens.
+///
+/// Use it in place of [`LowercaseFilter`](super::lowercase::LowercaseFilter)
+/// when text in scripts other than Latin should match regardless of case.
+/// Stopped tokens are passed through.
+#[derive(Clone, Debug, Default)]
+pub struct CaseFoldFilter;
+
+impl CaseFoldFilter {
+    /// Create a new case folding filter.
+    pub fn new() -> Self {
+        CaseFoldFilter
+    }
+}
+
+impl Filter for CaseFoldFilter {
+    fn filter(&self, tokens: TokenStream) -> Result<TokenStream> {
+        let filtered_tokens = tokens
+            .map(|mut token| {
+                if !token.is_stopped() {
+                    token.text = case_fold(&token.text);
+                }
+                token
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Box::new(filtered_tokens.into_iter()))
+    }
+
+    fn name(&self) -> &'static str {
+        "case_fold"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::token::Token;
+
+    #[test]
+    fn test_case_fold() {
+        assert_eq!(case_fold("Hello WORLD"), "hello world");
+        assert_eq!(case_fold("Straße"), "strasse");
+        assert_eq!(case_fold("STRASSE"), "strasse");
+        assert_eq!(case_fold("ẞ"), "ss");
+        assert_eq!(case_fold("ς"), "σ");
+        assert_eq!(case_fold("µ"), "μ");
+        assert_eq!(case_fold("ﬁ"), "fi");
+        assert_eq!(case_fold("\u{212A}elvin"), "kelvin");
+        assert_eq!(case_fold("ＡＢＣ"), "ａｂｃ");
+        assert_eq!(case_fold("東京"), "東京");
+    }
+
+    #[test]
+    fn test_case_fold_filter() {
+        let filter = CaseFoldFilter::new();
+        let tokens = vec![
+            Token::with_offsets("Weißbier", 0, 0, 9),
+            Token::new("The", 1).stop(),
+        ];
+        let result: Vec<Token> = filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .collect();
+
+        assert_eq!(result[0].text, "weissbier");
+        assert_eq!((result[0].start_offset, result[0].end_offset), (0, 9));
+        assert_eq!(result[1].text, "The");
+        assert!(result[1].is_stopped());
+    }
+
+    #[test]
+    fn test_filter_name() {
+        assert_eq!(CaseFoldFilter::new().name(), "case_fold");
+    }
+}
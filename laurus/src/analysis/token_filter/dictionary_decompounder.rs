@@ -0,0 +1,287 @@
+//! Dictionary decompounder filter implementation.
+//!
+//! This module provides a filter that finds dictionary words inside
+//! compound words, as formed in German, Dutch, or the Scandinavian
+//! languages, and adds them next to the compound. With "dampf" and
+//! "schiff" in the dictionary, "Dampfschiff" also yields "Dampf" and
+//! "schiff", so that a search for "Schiff" finds it.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::Filter;
+//! use laurus::analysis::token_filter::dictionary_decompounder::DictionaryDecompounderFilter;
+//! use laurus::analysis::token::Token;
+//!
+//! let filter = DictionaryDecompounderFilter::new(["donau", "dampf", "schiff"]);
+//! let tokens = vec![Token::new("Donaudampfschiff", 0)];
+//!
+//! let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+//!     .unwrap()
+//!     .map(|t| t.text)
+//!     .collect();
+//!
+//! assert_eq!(result, vec!["Donaudampfschiff", "Donau", "dampf", "schiff"]);
+//! ```
+
+use std::collections::HashSet;
+
+use crate::analysis::token::{Token, TokenStream};
+use crate::analysis::token_filter::Filter;
+use crate::error::Result;
+
+/// A filter that adds the dictionary words found inside tokens.
+///
+/// Every token of at least `min_word_size` characters is kept and
+/// followed by the dictionary words of `min_subword_size` to
+/// `max_subword_size` characters it contains, stacked at its position
+/// (position increment 0) with its offsets. Subwords keep the case of
+/// the token, while the dictionary is matched case-insensitively. With
+/// `only_longest_match`, only the longest word starting at each character
+/// is added, so "dampfschiff" does not also yield "dampf" when both are
+/// in the dictionary.
+#[derive(Clone, Debug)]
+pub struct DictionaryDecompounderFilter {
+    /// Lowercased dictionary words
+    dictionary: HashSet<String>,
+    /// Minimum length of tokens that are decompounded, in characters
+    min_word_size: usize,
+    /// Minimum length of subwords, in characters
+    min_subword_size: usize,
+    /// Maximum length of subwords, in characters
+    max_subword_size: usize,
+    /// Whether only the longest subword at each character is added
+    only_longest_match: bool,
+}
+
+impl DictionaryDecompounderFilter {
+    /// Default minimum length of tokens that are decompounded.
+    pub const DEFAULT_MIN_WORD_SIZE: usize = 5;
+    /// Default minimum length of subwords.
+    pub const DEFAULT_MIN_SUBWORD_SIZE: usize = 2;
+    /// Default maximum length of subwords.
+    pub const DEFAULT_MAX_SUBWORD_SIZE: usize = 15;
+
+    /// Create a new decompounder with the given dictionary words.
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            dictionary: words
+                .into_iter()
+                .map(|word| word.as_ref().to_lowercase())
+                .collect(),
+            min_word_size: Self::DEFAULT_MIN_WORD_SIZE,
+            min_subword_size: Self::DEFAULT_MIN_SUBWORD_SIZE,
+            max_subword_size: Self::DEFAULT_MAX_SUBWORD_SIZE,
+            only_longest_match: false,
+        }
+    }
+
+    /// Set the minimum length of tokens that are decompounded.
+    pub fn with_min_word_size(mut self, min_word_size: usize) -> Self {
+        self.min_word_size = min_word_size;
+        self
+    }
+
+    /// Set the minimum length of subwords.
+    pub fn with_min_subword_size(mut self, min_subword_size: usize) -> Self {
+        self.min_subword_size = min_subword_size.max(1);
+        self
+    }
+
+    /// Set the maximum length of subwords.
+    pub fn with_max_subword_size(mut self, max_subword_size: usize) -> Self {
+        self.max_subword_size = max_subword_size;
+        self
+    }
+
+    /// Set whether only the longest subword at each character is added.
+    pub fn with_only_longest_match(mut self, only_longest_match: bool) -> Self {
+        self.only_longest_match = only_longest_match;
+        self
+    }
+
+    /// Get the number of dictionary words.
+    pub fn dictionary_len(&self) -> usize {
+        self.dictionary.len()
+    }
+
+    /// Get the minimum length of tokens that are decompounded.
+    pub fn min_word_size(&self) -> usize {
+        self.min_word_size
+    }
+
+    /// Get the minimum length of subwords.
+    pub fn min_subword_size(&self) -> usize {
+        self.min_subword_size
+    }
+
+    /// Get the maximum length of subwords.
+    pub fn max_subword_size(&self) -> usize {
+        self.max_subword_size
+    }
+
+    /// Check whether only the longest subword at each character is added.
+    pub fn only_longest_match(&self) -> bool {
+        self.only_longest_match
+    }
+
+    /// The dictionary words found in `text`, in order of their start.
+    fn subwords(&self, text: &str) -> Vec<String> {
+        let chars: Vec<char> = text.chars().collect();
+        if chars.len() < self.min_word_size {
+            return Vec::new();
+        }
+
+        let lowercase: Vec<String> = chars.iter().map(|c| c.to_lowercase().collect()).collect();
+        let mut subwords = Vec::new();
+        for start in 0..chars.len() {
+            let max_end = chars.len().min(start + self.max_subword_size);
+            let mut matches = (start + self.min_subword_size..=max_end)
+                .filter(|&end| self.dictionary.contains(&lowercase[start..end].concat()))
+                .map(|end| chars[start..end].iter().collect::<String>())
+                .collect::<Vec<_>>();
+            if self.only_longest_match {
+                matches = matches.pop().into_iter().collect();
+            }
+            subwords.extend(matches);
+        }
+        subwords
+    }
+}
+
+impl Filter for DictionaryDecompounderFilter {
+    fn filter(&self, tokens: TokenStream) -> Result<TokenStream> {
+        let mut output = Vec::new();
+
+        for token in tokens {
+            if token.is_stopped() {
+                output.push(token);
+                continue;
+            }
+
+            let subwords: Vec<Token> = self
+                .subwords(&token.text)
+                .into_iter()
+                .map(|subword| token.with_text(subword).with_position_increment(0))
+                .collect();
+            output.push(token);
+            output.extend(subwords);
+        }
+
+        Ok(Box::new(output.into_iter()))
+    }
+
+    fn name(&self) -> &'static str {
+        "dictionary_decompounder"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(filter: &DictionaryDecompounderFilter, tokens: Vec<Token>) -> Vec<(String, usize)> {
+        filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .map(|t| (t.text, t.position_increment))
+            .collect()
+    }
+
+    fn dictionary() -> DictionaryDecompounderFilter {
+        DictionaryDecompounderFilter::new(["Rind", "Fleisch", "Etikett", "Etikettierung"])
+    }
+
+    #[test]
+    fn test_decompound() {
+        let filter = dictionary();
+        let tokens = vec![
+            Token::new("Rindfleischetikettierung", 0),
+            Token::new("und", 1),
+        ];
+
+        assert_eq!(
+            run(&filter, tokens),
+            vec![
+                ("Rindfleischetikettierung".to_string(), 1),
+                ("Rind".to_string(), 0),
+                ("fleisch".to_string(), 0),
+                ("etikett".to_string(), 0),
+                ("etikettierung".to_string(), 0),
+                ("und".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_only_longest_match() {
+        let filter = dictionary().with_only_longest_match(true);
+        let texts: Vec<String> = run(&filter, vec![Token::new("Rindfleischetikettierung", 0)])
+            .into_iter()
+            .map(|t| t.0)
+            .collect();
+
+        assert_eq!(
+            texts,
+            vec![
+                "Rindfleischetikettierung",
+                "Rind",
+                "fleisch",
+                "etikettierung"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sizes() {
+        let filter = dictionary().with_min_word_size(30);
+        assert_eq!(run(&filter, vec![Token::new("Rindfleisch", 0)]).len(), 1);
+
+        let filter = dictionary().with_max_subword_size(4);
+        let texts: Vec<String> = run(&filter, vec![Token::new("Rindfleisch", 0)])
+            .into_iter()
+            .map(|t| t.0)
+            .collect();
+        assert_eq!(texts, vec!["Rindfleisch", "Rind"]);
+
+        let filter = DictionaryDecompounderFilter::new(["ab", "abc"]).with_min_subword_size(3);
+        let texts: Vec<String> = run(&filter, vec![Token::new("xxabcxx", 0)])
+            .into_iter()
+            .map(|t| t.0)
+            .collect();
+        assert_eq!(texts, vec!["xxabcxx", "abc"]);
+    }
+
+    #[test]
+    fn test_subwords_keep_offsets() {
+        let filter = dictionary();
+        let tokens = vec![Token::with_offsets("Rindfleisch", 0, 4, 15)];
+        let result: Vec<Token> = filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .collect();
+
+        assert_eq!(result.len(), 3);
+        assert!(
+            result
+                .iter()
+                .all(|t| (t.start_offset, t.end_offset) == (4, 15))
+        );
+    }
+
+    #[test]
+    fn test_stopped_tokens_pass_through() {
+        let filter = dictionary();
+        let result = run(&filter, vec![Token::new("Rindfleisch", 0).stop()]);
+        assert_eq!(result, vec![("Rindfleisch".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_filter_name() {
+        assert_eq!(dictionary().name(), "dictionary_decompounder");
+    }
+}
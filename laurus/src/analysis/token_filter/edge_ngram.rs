@@ -0,0 +1,253 @@
+//! Edge n-gram filter implementation.
+//!
+//! This module provides a filter that replaces each token with its leading
+//! character n-grams (prefixes). Placed after a word tokenizer, it indexes
+//! the prefixes needed for search-as-you-type, while a search analyzer
+//! without the filter keeps query terms whole.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::Filter;
+//! use laurus::analysis::token_filter::edge_ngram::EdgeNgramFilter;
+//! use laurus::analysis::token::Token;
+//!
+//! let filter = EdgeNgramFilter::new(1, 3).unwrap();
+//! let tokens = vec![Token::new("quick", 0), Token::new("fox", 1)];
+//!
+//! let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+//!     .unwrap()
+//!     .map(|t| t.text)
+//!     .collect();
+//!
+//! assert_eq!(result, vec!["q", "qu", "qui", "f", "fo", "fox"]);
+//! ```
+
+use crate::analysis::token::{Token, TokenStream};
+use crate::analysis::token_filter::Filter;
+use crate::error::{LaurusError, Result};
+
+/// A filter that replaces tokens with their edge n-grams.
+///
+/// Each token is replaced by its prefixes of `min_gram` to `max_gram`
+/// characters. The prefixes are stacked at the token's position: the first
+/// one takes over the token's position increment and the rest have an
+/// increment of 0, so phrase queries still see one position per token.
+/// Every prefix keeps the offsets of its token, so highlighting marks the
+/// whole word.
+///
+/// Tokens shorter than `min_gram` are dropped unless `preserve_original`
+/// is set; their position increment is carried over to the next token.
+/// With `preserve_original`, tokens shorter than `min_gram` or longer
+/// than `max_gram` are also emitted unchanged after their prefixes.
+///
+/// # Examples
+///
+/// ```
+/// use laurus::analysis::token_filter::Filter;
+/// use laurus::analysis::token_filter::edge_ngram::EdgeNgramFilter;
+/// use laurus::analysis::token::Token;
+///
+/// let filter = EdgeNgramFilter::new(2, 3).unwrap().with_preserve_original(true);
+/// let tokens = vec![Token::new("search", 0)];
+///
+/// let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+///     .unwrap()
+///     .collect();
+///
+/// assert_eq!(result.len(), 3);
+/// assert_eq!(result[0].text, "se");
+/// assert_eq!(result[1].text, "sea");
+/// assert_eq!(result[2].text, "search");
+/// assert_eq!(result[2].position_increment, 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct EdgeNgramFilter {
+    /// Minimum n-gram size
+    min_gram: usize,
+    /// Maximum n-gram size
+    max_gram: usize,
+    /// Whether tokens outside the n-gram size range are kept
+    preserve_original: bool,
+}
+
+impl EdgeNgramFilter {
+    /// Create a new edge n-gram filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_gram` - Minimum n-gram size (must be >= 1)
+    /// * `max_gram` - Maximum n-gram size (must be >= min_gram)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `min_gram` is 0
+    /// - `max_gram` is less than `min_gram`
+    pub fn new(min_gram: usize, max_gram: usize) -> Result<Self> {
+        if min_gram == 0 {
+            return Err(LaurusError::analysis(
+                "min_gram must be at least 1".to_string(),
+            ));
+        }
+        if max_gram < min_gram {
+            return Err(LaurusError::analysis(format!(
+                "max_gram ({}) must be >= min_gram ({})",
+                max_gram, min_gram
+            )));
+        }
+        Ok(Self {
+            min_gram,
+            max_gram,
+            preserve_original: false,
+        })
+    }
+
+    /// Set whether tokens shorter than `min_gram` or longer than
+    /// `max_gram` are also emitted unchanged.
+    pub fn with_preserve_original(mut self, preserve_original: bool) -> Self {
+        self.preserve_original = preserve_original;
+        self
+    }
+
+    /// Get the minimum n-gram size.
+    pub fn min_gram(&self) -> usize {
+        self.min_gram
+    }
+
+    /// Get the maximum n-gram size.
+    pub fn max_gram(&self) -> usize {
+        self.max_gram
+    }
+
+    /// Check whether tokens outside the n-gram size range are kept.
+    pub fn preserve_original(&self) -> bool {
+        self.preserve_original
+    }
+}
+
+impl Filter for EdgeNgramFilter {
+    fn filter(&self, tokens: TokenStream) -> Result<TokenStream> {
+        let mut output = Vec::new();
+        // Increment carried over from dropped tokens.
+        let mut pending_increment = 0;
+
+        for token in tokens {
+            pending_increment += token.position_increment;
+            let length = token.text.chars().count();
+
+            let prefix_ends = token
+                .text
+                .char_indices()
+                .map(|(i, c)| i + c.len_utf8())
+                .skip(self.min_gram - 1)
+                .take(self.max_gram - self.min_gram + 1);
+            let mut grams: Vec<Token> = prefix_ends
+                .map(|end| token.with_text(&token.text[..end]))
+                .collect();
+            if self.preserve_original && (length < self.min_gram || length > self.max_gram) {
+                grams.push(token);
+            }
+
+            for mut gram in grams {
+                gram.position_increment = std::mem::take(&mut pending_increment);
+                output.push(gram);
+            }
+        }
+
+        Ok(Box::new(output.into_iter()))
+    }
+
+    fn name(&self) -> &'static str {
+        "edge_ngram"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(filter: &EdgeNgramFilter, tokens: Vec<Token>) -> Vec<Token> {
+        filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .collect()
+    }
+
+    #[test]
+    fn test_edge_ngram_filter_creation() {
+        assert!(EdgeNgramFilter::new(1, 1).is_ok());
+        assert!(EdgeNgramFilter::new(0, 2).is_err());
+        assert!(EdgeNgramFilter::new(4, 3).is_err());
+    }
+
+    #[test]
+    fn test_edge_ngram_filter_positions_and_offsets() {
+        let filter = EdgeNgramFilter::new(1, 2).unwrap();
+        let tokens = vec![
+            Token::with_offsets("new", 0, 0, 3),
+            Token::with_offsets("york", 1, 4, 8),
+        ];
+        let result: Vec<(String, usize, usize, usize)> = run(&filter, tokens)
+            .into_iter()
+            .map(|t| (t.text, t.position_increment, t.start_offset, t.end_offset))
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![
+                ("n".to_string(), 1, 0, 3),
+                ("ne".to_string(), 0, 0, 3),
+                ("y".to_string(), 1, 4, 8),
+                ("yo".to_string(), 0, 4, 8),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edge_ngram_filter_short_tokens() {
+        let filter = EdgeNgramFilter::new(3, 5).unwrap();
+        let tokens = vec![
+            Token::new("an", 0),
+            Token::new("apple", 1),
+            Token::new("pie", 2),
+        ];
+        let result: Vec<(String, usize)> = run(&filter, tokens)
+            .into_iter()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+
+        // "an" is dropped and its increment carried over to "app"
+        assert_eq!(
+            result,
+            vec![
+                ("app".to_string(), 2),
+                ("appl".to_string(), 0),
+                ("apple".to_string(), 0),
+                ("pie".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edge_ngram_filter_preserve_original() {
+        let filter = EdgeNgramFilter::new(3, 3)
+            .unwrap()
+            .with_preserve_original(true);
+        let tokens = vec![Token::new("an", 0), Token::new("apple", 1)];
+        let result: Vec<String> = run(&filter, tokens).into_iter().map(|t| t.text).collect();
+
+        assert_eq!(result, vec!["an", "app", "apple"]);
+    }
+
+    #[test]
+    fn test_edge_ngram_filter_unicode() {
+        let filter = EdgeNgramFilter::new(1, 2).unwrap();
+        let result: Vec<String> = run(&filter, vec![Token::new("東京都", 0)])
+            .into_iter()
+            .map(|t| t.text)
+            .collect();
+
+        assert_eq!(result, vec!["東", "東京"]);
+    }
+}
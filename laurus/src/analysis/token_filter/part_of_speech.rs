@@ -0,0 +1,176 @@
+//! Part-of-speech stop filter implementation.
+//!
+//! This module provides a filter that removes tokens by their grammatical
+//! role rather than their text. It reads the part-of-speech tag recorded by
+//! a [`LinderaTokenizer`](crate::analysis::tokenizer::lindera::LinderaTokenizer)
+//! created [`with_part_of_speech`](crate::analysis::tokenizer::lindera::LinderaTokenizer::with_part_of_speech),
+//! which makes it the natural way to drop particles and endings in
+//! agglutinative languages such as Korean.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token::{Token, TokenMetadata};
+//! use laurus::analysis::token_filter::Filter;
+//! use laurus::analysis::token_filter::part_of_speech::PartOfSpeechStopFilter;
+//! use laurus::analysis::tokenizer::lindera::PART_OF_SPEECH_ATTRIBUTE;
+//!
+//! fn tagged(text: &str, tag: &str) -> Token {
+//!     let mut metadata = TokenMetadata::new();
+//!     metadata.set_attribute(PART_OF_SPEECH_ATTRIBUTE, tag);
+//!     Token::new(text, 0).with_metadata(metadata)
+//! }
+//!
+//! let filter = PartOfSpeechStopFilter::korean();
+//! let tokens = vec![tagged("한국어", "NNG"), tagged("의", "JKG")];
+//!
+//! let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+//!     .unwrap()
+//!     .collect();
+//!
+//! // The genitive particle is removed
+//! assert_eq!(result.len(), 1);
+//! assert_eq!(result[0].text, "한국어");
+//! ```
+
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock};
+
+use crate::analysis::token::{Token, TokenStream};
+use crate::analysis::token_filter::Filter;
+use crate::analysis::tokenizer::lindera::PART_OF_SPEECH_ATTRIBUTE;
+use crate::error::Result;
+
+/// Default KO-DIC part-of-speech tags removed from Korean text.
+///
+/// Verbal endings, particles, interjections, determiners, conjunctive
+/// adverbs, affixes and punctuation.
+const DEFAULT_KOREAN_STOP_TAGS: &[&str] = &[
+    // Verbal endings
+    "EP", "EF", "EC", "ETN", "ETM", // Interjection
+    "IC",  // Particles
+    "JKS", "JKC", "JKG", "JKO", "JKB", "JKV", "JKQ", "JX", "JC",
+    // Determiner and conjunctive adverb
+    "MM", "MAJ", // Affixes
+    "XPN", "XSN", "XSV", "XSA", // Punctuation and symbols
+    "SF", "SE", "SSO", "SSC", "SC", "SY",
+];
+
+/// Default Korean stop tags as a HashSet.
+pub static DEFAULT_KOREAN_STOP_TAGS_SET: LazyLock<HashSet<String>> = LazyLock::new(|| {
+    DEFAULT_KOREAN_STOP_TAGS
+        .iter()
+        .map(|&s| s.to_string())
+        .collect()
+});
+
+/// A filter that removes tokens whose part-of-speech tag is a stop tag.
+///
+/// Compound tags such as `"XSV+ETM"` (an inflected form spanning several
+/// morphemes) are matched by their leading tag. Tokens without a
+/// part-of-speech tag are always kept.
+#[derive(Clone, Debug)]
+pub struct PartOfSpeechStopFilter {
+    /// The set of tags to remove
+    stop_tags: Arc<HashSet<String>>,
+}
+
+impl PartOfSpeechStopFilter {
+    /// Create a new filter removing the given tags.
+    pub fn with_stop_tags(stop_tags: HashSet<String>) -> Self {
+        PartOfSpeechStopFilter {
+            stop_tags: Arc::new(stop_tags),
+        }
+    }
+
+    /// Create a new filter from a list of tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use laurus::analysis::token_filter::part_of_speech::PartOfSpeechStopFilter;
+    ///
+    /// let filter = PartOfSpeechStopFilter::from_tags(vec!["JKS", "JKO"]);
+    /// assert!(filter.is_stop_tag("JKS"));
+    /// assert!(!filter.is_stop_tag("NNG"));
+    /// ```
+    pub fn from_tags<I, S>(tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::with_stop_tags(tags.into_iter().map(|s| s.into()).collect())
+    }
+
+    /// Create a new filter with the default KO-DIC stop tags.
+    pub fn korean() -> Self {
+        Self::with_stop_tags(DEFAULT_KOREAN_STOP_TAGS_SET.clone())
+    }
+
+    /// Check if a part-of-speech tag is removed by this filter.
+    ///
+    /// Only the leading tag of a compound tag is considered.
+    pub fn is_stop_tag(&self, tag: &str) -> bool {
+        let leading = tag.split('+').next().unwrap_or(tag);
+        self.stop_tags.contains(leading)
+    }
+
+    fn is_stopped(&self, token: &Token) -> bool {
+        token
+            .metadata()
+            .and_then(|metadata| metadata.get_attribute(PART_OF_SPEECH_ATTRIBUTE))
+            .is_some_and(|tag| self.is_stop_tag(tag))
+    }
+}
+
+impl Filter for PartOfSpeechStopFilter {
+    fn filter(&self, tokens: TokenStream) -> Result<TokenStream> {
+        let filtered_tokens: Vec<Token> = tokens.filter(|token| !self.is_stopped(token)).collect();
+
+        Ok(Box::new(filtered_tokens.into_iter()))
+    }
+
+    fn name(&self) -> &'static str {
+        "part_of_speech_stop"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::token::TokenMetadata;
+
+    fn tagged(text: &str, tag: &str) -> Token {
+        let mut metadata = TokenMetadata::new();
+        metadata.set_attribute(PART_OF_SPEECH_ATTRIBUTE, tag);
+        Token::new(text, 0).with_metadata(metadata)
+    }
+
+    #[test]
+    fn test_part_of_speech_stop_filter() {
+        let filter = PartOfSpeechStopFilter::korean();
+        let tokens = vec![
+            tagged("형태", "NNG"),
+            tagged("을", "JKO"),
+            tagged("할", "XSV+ETM"),
+            tagged("있", "VV"),
+            Token::new("untagged", 0),
+        ];
+
+        let result: Vec<Token> = filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .collect();
+
+        let texts: Vec<&str> = result.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, vec!["형태", "있", "untagged"]);
+    }
+
+    #[test]
+    fn test_filter_name() {
+        assert_eq!(
+            PartOfSpeechStopFilter::korean().name(),
+            "part_of_speech_stop"
+        );
+    }
+}
@@ -0,0 +1,327 @@
+//! Phonetic token filter and phonetic encoder implementations.
+//!
+//! Phonetic encoders map words to codes that represent how they sound, so
+//! that differently spelled names such as "Stephen" and "Steven" or
+//! "Schmidt" and "Smith" share a code. Indexing and querying the codes
+//! matches such names even when their edit distance is large.
+//!
+//! # Available Encoders
+//!
+//! - [`double_metaphone::DoubleMetaphoneEncoder`] - Double Metaphone (default)
+//! - [`soundex::SoundexEncoder`] - American Soundex
+//! - [`daitch_mokotoff::DaitchMokotoffEncoder`] - Daitch–Mokotoff Soundex for
+//!   Slavic, Germanic, and Yiddish surnames
+//! - [`beider_morse::BeiderMorseEncoder`] - Beider–Morse Phonetic Matching
+//!   for generic names
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::Filter;
+//! use laurus::analysis::token_filter::phonetic::{PhoneticAlgorithm, PhoneticFilter};
+//! use laurus::analysis::token::Token;
+//!
+//! let filter = PhoneticFilter::new(PhoneticAlgorithm::DoubleMetaphone);
+//! let tokens = vec![Token::new("stephen", 0)];
+//!
+//! let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+//!     .unwrap()
+//!     .map(|t| t.text)
+//!     .collect();
+//!
+//! assert_eq!(result, vec!["stephen", "STFN"]);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::analysis::token::{Token, TokenStream, TokenType};
+use crate::analysis::token_filter::Filter;
+use crate::error::{LaurusError, Result};
+
+/// Trait for phonetic encoding algorithms.
+pub trait PhoneticEncoder: Send + Sync {
+    /// Encode a word into its phonetic codes.
+    ///
+    /// Algorithms that allow several pronunciations return more than one
+    /// code. Words the algorithm cannot encode produce no codes.
+    fn encode(&self, word: &str) -> Vec<String>;
+
+    /// Get the name of this encoder.
+    fn name(&self) -> &'static str;
+}
+
+// Encoder implementations
+pub mod beider_morse;
+pub mod daitch_mokotoff;
+pub mod double_metaphone;
+pub mod soundex;
+
+use beider_morse::BeiderMorseEncoder;
+use daitch_mokotoff::DaitchMokotoffEncoder;
+use double_metaphone::DoubleMetaphoneEncoder;
+use soundex::SoundexEncoder;
+
+/// A phonetic algorithm supported by [`PhoneticFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhoneticAlgorithm {
+    /// Double Metaphone.
+    DoubleMetaphone,
+    /// American Soundex.
+    Soundex,
+    /// Daitch–Mokotoff Soundex.
+    DaitchMokotoff,
+    /// Beider–Morse Phonetic Matching.
+    BeiderMorse,
+}
+
+impl PhoneticAlgorithm {
+    /// Every supported algorithm.
+    pub const ALL: [PhoneticAlgorithm; 4] = [
+        PhoneticAlgorithm::DoubleMetaphone,
+        PhoneticAlgorithm::Soundex,
+        PhoneticAlgorithm::DaitchMokotoff,
+        PhoneticAlgorithm::BeiderMorse,
+    ];
+
+    /// The snake_case name of the algorithm, e.g. `"double_metaphone"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            PhoneticAlgorithm::DoubleMetaphone => "double_metaphone",
+            PhoneticAlgorithm::Soundex => "soundex",
+            PhoneticAlgorithm::DaitchMokotoff => "daitch_mokotoff",
+            PhoneticAlgorithm::BeiderMorse => "beider_morse",
+        }
+    }
+
+    /// Create the encoder for this algorithm.
+    pub fn encoder(&self) -> Box<dyn PhoneticEncoder> {
+        match self {
+            PhoneticAlgorithm::DoubleMetaphone => Box::new(DoubleMetaphoneEncoder::new()),
+            PhoneticAlgorithm::Soundex => Box::new(SoundexEncoder::new()),
+            PhoneticAlgorithm::DaitchMokotoff => Box::new(DaitchMokotoffEncoder::new()),
+            PhoneticAlgorithm::BeiderMorse => Box::new(BeiderMorseEncoder::new()),
+        }
+    }
+}
+
+impl fmt::Display for PhoneticAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for PhoneticAlgorithm {
+    type Err = LaurusError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        PhoneticAlgorithm::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.name() == s)
+            .ok_or_else(|| LaurusError::invalid_argument(format!("Unknown phonetic encoder: {s}")))
+    }
+}
+
+/// A filter that adds or substitutes the phonetic codes of tokens.
+///
+/// With `inject` enabled (the default), the original token is kept and its
+/// codes are added as synonym tokens at the same position (position
+/// increment 0), so that both exact and phonetic matches are found and
+/// exact matches score higher. With `inject` disabled, each token is
+/// replaced by its codes.
+///
+/// Codes equal to the token text are not repeated, and tokens the encoder
+/// cannot encode (e.g. numbers) are kept unchanged. Stopped tokens are
+/// passed through.
+pub struct PhoneticFilter {
+    /// The encoder to use.
+    encoder: Box<dyn PhoneticEncoder>,
+    /// Whether the original token is kept next to its codes.
+    inject: bool,
+}
+
+impl fmt::Debug for PhoneticFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PhoneticFilter")
+            .field("encoder", &self.encoder.name())
+            .field("inject", &self.inject)
+            .finish()
+    }
+}
+
+impl PhoneticFilter {
+    /// Create a new phonetic filter for `algorithm` that injects codes.
+    pub fn new(algorithm: PhoneticAlgorithm) -> Self {
+        Self::with_encoder(algorithm.encoder())
+    }
+
+    /// Create a phonetic filter with a custom encoder.
+    pub fn with_encoder(encoder: Box<dyn PhoneticEncoder>) -> Self {
+        PhoneticFilter {
+            encoder,
+            inject: true,
+        }
+    }
+
+    /// Set whether the original token is kept next to its codes.
+    pub fn with_inject(mut self, inject: bool) -> Self {
+        self.inject = inject;
+        self
+    }
+
+    /// Get the name of the encoder.
+    pub fn encoder_name(&self) -> &'static str {
+        self.encoder.name()
+    }
+
+    /// Check whether the original token is kept next to its codes.
+    pub fn inject(&self) -> bool {
+        self.inject
+    }
+}
+
+impl Filter for PhoneticFilter {
+    fn filter(&self, tokens: TokenStream) -> Result<TokenStream> {
+        let mut output = Vec::new();
+
+        for token in tokens {
+            if token.is_stopped() {
+                output.push(token);
+                continue;
+            }
+
+            let mut codes = self.encoder.encode(&token.text);
+            codes.retain(|code| code != &token.text);
+            if codes.is_empty() {
+                output.push(token);
+                continue;
+            }
+
+            let mut increment = token.position_increment;
+            let codes: Vec<Token> = codes
+                .into_iter()
+                .map(|code| {
+                    let mut code = token.with_text(code);
+                    if self.inject {
+                        code = code.with_token_type(TokenType::Synonym);
+                    }
+                    code
+                })
+                .collect();
+            if self.inject {
+                output.push(token);
+                increment = 0;
+            }
+            for code in codes {
+                output.push(code.with_position_increment(std::mem::take(&mut increment)));
+            }
+        }
+
+        Ok(Box::new(output.into_iter()))
+    }
+
+    fn name(&self) -> &'static str {
+        "phonetic"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(filter: &PhoneticFilter, tokens: Vec<Token>) -> Vec<(String, usize)> {
+        filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .map(|t| (t.text, t.position_increment))
+            .collect()
+    }
+
+    #[test]
+    fn test_phonetic_filter_inject() {
+        let filter = PhoneticFilter::new(PhoneticAlgorithm::DoubleMetaphone);
+        let tokens = vec![Token::new("john", 0), Token::new("smith", 1)];
+
+        assert_eq!(
+            run(&filter, tokens),
+            vec![
+                ("john".to_string(), 1),
+                ("JN".to_string(), 0),
+                ("AN".to_string(), 0),
+                ("smith".to_string(), 1),
+                ("SM0".to_string(), 0),
+                ("XMT".to_string(), 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_phonetic_filter_replace() {
+        let filter = PhoneticFilter::new(PhoneticAlgorithm::Soundex).with_inject(false);
+        let tokens = vec![
+            Token::new("stephen", 0),
+            Token::new("42", 1),
+            Token::new("steven", 2).with_position_increment(2),
+        ];
+
+        assert_eq!(
+            run(&filter, tokens),
+            vec![
+                ("S315".to_string(), 1),
+                ("42".to_string(), 1),
+                ("S315".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_phonetic_filter_replace_with_several_codes() {
+        let filter = PhoneticFilter::new(PhoneticAlgorithm::DaitchMokotoff).with_inject(false);
+        let tokens = vec![Token::with_offsets("peters", 0, 0, 6)];
+        let result: Vec<Token> = filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .collect();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].text, "739400");
+        assert_eq!(result[0].position_increment, 1);
+        assert_eq!(result[1].text, "734000");
+        assert_eq!(result[1].position_increment, 0);
+        assert_eq!((result[1].start_offset, result[1].end_offset), (0, 6));
+    }
+
+    #[test]
+    fn test_phonetic_filter_skips_stopped_tokens() {
+        let filter = PhoneticFilter::new(PhoneticAlgorithm::DoubleMetaphone);
+        let tokens = vec![Token::new("the", 0).stop()];
+        let result: Vec<Token> = filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .collect();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].text, "the");
+        assert!(result[0].is_stopped());
+    }
+
+    #[test]
+    fn test_phonetic_algorithm_names() {
+        for algorithm in PhoneticAlgorithm::ALL {
+            assert_eq!(
+                algorithm.name().parse::<PhoneticAlgorithm>().unwrap(),
+                algorithm
+            );
+            assert_eq!(algorithm.encoder().name(), algorithm.name());
+        }
+        assert!("caverphone".parse::<PhoneticAlgorithm>().is_err());
+    }
+
+    #[test]
+    fn test_filter_name() {
+        let filter = PhoneticFilter::new(PhoneticAlgorithm::Soundex);
+        assert_eq!(filter.name(), "phonetic");
+        assert_eq!(filter.encoder_name(), "soundex");
+        assert!(filter.inject());
+    }
+}
@@ -0,0 +1,354 @@
+//! Beider–Morse phonetic encoder.
+//!
+//! Beider–Morse Phonetic Matching (BMPM) transcribes a name into the
+//! phonemes it may be pronounced with, rather than into a fixed-length
+//! code. Letter groups are transcribed depending on the letters around
+//! them (e.g. "c" before "e", "i" or "y"), and groups with several
+//! possible pronunciations (e.g. "ch" as in "Charles", "Bach" or
+//! "Christ") produce one transcription per pronunciation. The
+//! transcriptions are then approximated, merging sounds that are often
+//! confused, such as vowels in unstressed syllables, "v" and "f", or
+//! voiced and voiceless consonants at the end of a word.
+//!
+//! This encoder implements the generic name type in approximate mode with
+//! a single language-independent rule set. Unlike the full algorithm, it
+//! does not guess the language of a name to pick language-specific rules,
+//! and it has no Ashkenazi or Sephardic rule sets. Only ASCII letters are
+//! considered, and at most [`MAX_CODES`] transcriptions are produced per
+//! name.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::phonetic::PhoneticEncoder;
+//! use laurus::analysis::token_filter::phonetic::beider_morse::BeiderMorseEncoder;
+//!
+//! let encoder = BeiderMorseEncoder::new();
+//!
+//! assert_eq!(encoder.encode("Schmidt"), vec!["smit", "skmit"]);
+//! assert_eq!(encoder.encode("Smith"), vec!["smit"]);
+//! ```
+
+use std::cmp::Reverse;
+
+use crate::analysis::token_filter::phonetic::PhoneticEncoder;
+
+/// The maximum number of transcriptions produced for a name.
+pub const MAX_CODES: usize = 20;
+
+/// Name prefixes that are also encoded without the rest of the name
+/// following them, e.g. "van" in "van Dyke".
+const NAME_PREFIXES: &[&str] = &[
+    "al", "da", "dal", "de", "del", "dela", "della", "der", "des", "di", "do", "dos", "du", "el",
+    "la", "le", "van", "von",
+];
+
+/// A condition on the letter before or after a letter group.
+#[derive(Clone, Copy)]
+enum Context {
+    /// Any letter, or none.
+    Any,
+    /// The start or the end of the word.
+    Edge,
+    /// A vowel.
+    Vowel,
+    /// A letter that is not a vowel.
+    Consonant,
+    /// One of the given letters.
+    Letters(&'static str),
+}
+
+const ANY: Context = Context::Any;
+const EDGE: Context = Context::Edge;
+const VOWEL: Context = Context::Vowel;
+const CONSONANT: Context = Context::Consonant;
+const FRONT_VOWEL: Context = Context::Letters("eiy");
+
+impl Context {
+    /// Whether `letter` satisfies the context. `None` stands for the start
+    /// or the end of the word.
+    fn matches(self, letter: Option<u8>) -> bool {
+        match (self, letter) {
+            (Context::Any, _) => true,
+            (Context::Edge, letter) => letter.is_none(),
+            (Context::Vowel, Some(letter)) => is_vowel(letter),
+            (Context::Consonant, Some(letter)) => !is_vowel(letter),
+            (Context::Letters(letters), Some(letter)) => letters.as_bytes().contains(&letter),
+            (_, None) => false,
+        }
+    }
+}
+
+/// A transcription rule: a letter group, the contexts it must appear in,
+/// and its phonemes. Alternative phonemes are separated by `|`.
+///
+/// Phonemes are written with lowercase letters, plus `S` for the "sh" in
+/// "shop", `Z` for the "s" in "vision", `x` for the "ch" in "Bach", and `j`
+/// for the "y" in "yes".
+struct Rule {
+    pattern: &'static str,
+    left: Context,
+    right: Context,
+    phonemes: &'static str,
+}
+
+const fn rule(
+    pattern: &'static str,
+    left: Context,
+    right: Context,
+    phonemes: &'static str,
+) -> Rule {
+    Rule {
+        pattern,
+        left,
+        right,
+        phonemes,
+    }
+}
+
+/// The transcription table. The longest matching pattern is used, and
+/// among patterns of the same length the first whose contexts match.
+const RULES: &[Rule] = &[
+    // Vowels
+    rule("a", ANY, ANY, "a"),
+    rule("ai", ANY, ANY, "aj|e"),
+    rule("ay", ANY, ANY, "aj|e"),
+    rule("au", ANY, ANY, "au|o"),
+    rule("e", CONSONANT, EDGE, "e|"),
+    rule("e", ANY, ANY, "e"),
+    rule("ea", ANY, ANY, "i|ea"),
+    rule("ee", ANY, ANY, "i"),
+    rule("ei", ANY, ANY, "aj|i"),
+    rule("ey", ANY, ANY, "aj|i"),
+    rule("eu", ANY, ANY, "oj|u"),
+    rule("i", ANY, ANY, "i"),
+    rule("ie", ANY, ANY, "i"),
+    rule("o", ANY, ANY, "o"),
+    rule("oe", ANY, ANY, "e|o"),
+    rule("oo", ANY, ANY, "u"),
+    rule("ou", ANY, ANY, "u"),
+    rule("u", ANY, ANY, "u"),
+    rule("ue", ANY, ANY, "u|i"),
+    rule("y", EDGE, VOWEL, "j"),
+    rule("y", ANY, VOWEL, "j|i"),
+    rule("y", ANY, ANY, "i"),
+    // Consonants
+    rule("b", ANY, ANY, "b"),
+    rule("c", ANY, FRONT_VOWEL, "s|ts|tS"),
+    rule("c", ANY, ANY, "k"),
+    rule("cc", ANY, FRONT_VOWEL, "tS|ks"),
+    rule("ch", ANY, ANY, "tS|x|k|S"),
+    rule("chs", ANY, ANY, "ks"),
+    rule("ck", ANY, ANY, "k"),
+    rule("cs", ANY, ANY, "tS|ks"),
+    rule("cz", ANY, ANY, "tS"),
+    rule("d", ANY, ANY, "d"),
+    rule("dj", ANY, ANY, "dZ"),
+    rule("dt", ANY, ANY, "t"),
+    rule("dz", ANY, ANY, "dz|dZ"),
+    rule("dzh", ANY, ANY, "dZ"),
+    rule("f", ANY, ANY, "f"),
+    rule("g", ANY, FRONT_VOWEL, "g|dZ|Z"),
+    rule("g", ANY, ANY, "g"),
+    rule("gh", ANY, ANY, "g"),
+    rule("gn", ANY, ANY, "gn|nj"),
+    rule("h", ANY, ANY, "h"),
+    rule("j", ANY, ANY, "j|dZ|Z"),
+    rule("k", ANY, ANY, "k"),
+    rule("kh", ANY, ANY, "x"),
+    rule("l", ANY, ANY, "l"),
+    rule("m", ANY, ANY, "m"),
+    rule("n", ANY, ANY, "n"),
+    rule("p", ANY, ANY, "p"),
+    rule("ph", ANY, ANY, "f"),
+    rule("q", ANY, ANY, "k"),
+    rule("qu", ANY, ANY, "kv|k"),
+    rule("r", ANY, ANY, "r"),
+    rule("rz", ANY, ANY, "rz|Z"),
+    rule("s", ANY, ANY, "s"),
+    rule("sch", ANY, ANY, "S|sk"),
+    rule("sh", ANY, ANY, "S"),
+    rule("sz", ANY, ANY, "S|s"),
+    rule("t", ANY, ANY, "t"),
+    rule("tch", ANY, ANY, "tS"),
+    rule("th", ANY, ANY, "t"),
+    rule("ts", ANY, ANY, "ts"),
+    rule("tsch", ANY, ANY, "tS"),
+    rule("tz", ANY, ANY, "ts"),
+    rule("v", ANY, ANY, "v"),
+    rule("w", ANY, ANY, "v"),
+    rule("wh", ANY, ANY, "v"),
+    rule("x", ANY, ANY, "ks"),
+    rule("z", ANY, ANY, "z|ts"),
+    rule("zh", ANY, ANY, "Z"),
+];
+
+fn is_vowel(c: u8) -> bool {
+    matches!(c, b'a' | b'e' | b'i' | b'o' | b'u' | b'y')
+}
+
+/// The longest rule matching `word` at `index` in its context.
+fn longest_match(word: &[u8], index: usize) -> Option<&'static Rule> {
+    RULES
+        .iter()
+        .filter(|rule| {
+            let end = index + rule.pattern.len();
+            word[index..].starts_with(rule.pattern.as_bytes())
+                && rule
+                    .left
+                    .matches(index.checked_sub(1).map(|before| word[before]))
+                && rule.right.matches(word.get(end).copied())
+        })
+        .min_by_key(|rule| Reverse(rule.pattern.len()))
+}
+
+/// Transcribe `word` into its possible phonemes.
+fn transcribe(word: &[u8]) -> Vec<String> {
+    let mut branches = vec![String::new()];
+    let mut index = 0;
+    while index < word.len() {
+        let Some(rule) = longest_match(word, index) else {
+            index += 1;
+            continue;
+        };
+
+        let mut next = Vec::with_capacity(branches.len());
+        for branch in &branches {
+            for phonemes in rule.phonemes.split('|') {
+                let candidate = format!("{branch}{phonemes}");
+                if next.len() < MAX_CODES && !next.contains(&candidate) {
+                    next.push(candidate);
+                }
+            }
+        }
+        branches = next;
+        index += rule.pattern.len();
+    }
+    branches
+}
+
+/// Apply the approximate final rules to a transcription: "h" is dropped
+/// after the first phoneme, "sh" before a consonant becomes "s", "e"
+/// becomes "i", "o" becomes "u", "v" becomes "f", a final voiced consonant
+/// becomes voiceless, and repeated phonemes are collapsed.
+fn approximate(phonemes: &[u8]) -> String {
+    let mut approximated = String::with_capacity(phonemes.len());
+    for (index, &phoneme) in phonemes.iter().enumerate() {
+        let is_last = index + 1 == phonemes.len();
+        let next = phonemes.get(index + 1).copied();
+        let phoneme = match phoneme {
+            b'h' if index > 0 => continue,
+            b'S' if next.is_some_and(|next| !is_vowel(next) && next != b'j') => b's',
+            b'e' => b'i',
+            b'o' => b'u',
+            b'v' => b'f',
+            b'b' if is_last => b'p',
+            b'd' if is_last => b't',
+            b'g' if is_last => b'k',
+            b'z' if is_last => b's',
+            b'Z' if is_last => b'S',
+            phoneme => phoneme,
+        };
+        if !approximated.ends_with(phoneme as char) {
+            approximated.push(phoneme as char);
+        }
+    }
+    approximated
+}
+
+/// Beider–Morse phonetic encoder for generic names in approximate mode.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BeiderMorseEncoder;
+
+impl BeiderMorseEncoder {
+    /// Create a new Beider–Morse encoder.
+    pub fn new() -> Self {
+        BeiderMorseEncoder
+    }
+}
+
+impl PhoneticEncoder for BeiderMorseEncoder {
+    fn encode(&self, word: &str) -> Vec<String> {
+        let lowercase = word.to_ascii_lowercase();
+        let words: Vec<&str> = lowercase
+            .split(|c: char| !c.is_ascii_alphabetic())
+            .filter(|word| !word.is_empty())
+            .collect();
+        if words.is_empty() {
+            return Vec::new();
+        }
+
+        // A name such as "van Dyke" or "d'Angelo" is encoded both as a
+        // whole and without its prefix.
+        let mut names = vec![words.concat()];
+        if words.len() > 1 && (words[0].len() == 1 || NAME_PREFIXES.contains(&words[0])) {
+            names.push(words[1..].concat());
+        }
+
+        let mut codes: Vec<String> = Vec::new();
+        for name in names {
+            for transcription in transcribe(name.as_bytes()) {
+                let code = approximate(transcription.as_bytes());
+                if codes.len() < MAX_CODES && !code.is_empty() && !codes.contains(&code) {
+                    codes.push(code);
+                }
+            }
+        }
+        codes
+    }
+
+    fn name(&self) -> &'static str {
+        "beider_morse"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(word: &str) -> String {
+        BeiderMorseEncoder::new().encode(word).join(",")
+    }
+
+    #[test]
+    fn test_beider_morse_codes() {
+        assert_eq!(encode("Schmidt"), "smit,skmit");
+        assert_eq!(encode("Smith"), "smit");
+        assert_eq!(encode("Stephen"), "stifin");
+        assert_eq!(encode("Steven"), "stifin");
+    }
+
+    #[test]
+    fn test_beider_morse_context_rules() {
+        // "c" before a front vowel has several pronunciations.
+        assert_eq!(encode("Price"), "prisi,pris,pritsi,prits,pritSi,pritS");
+        assert_eq!(encode("Carl"), "karl");
+        // A final "e" after a consonant may be silent.
+        assert_eq!(encode("Jane"), "jani,jan,dZani,dZan,Zani,Zan");
+    }
+
+    #[test]
+    fn test_beider_morse_approximation() {
+        assert_eq!(encode("Vogel"), encode("Fogel"));
+        assert_eq!(encode("Lloyd"), "luit");
+        assert_eq!(encode("Moskowitz"), "muskufits");
+    }
+
+    #[test]
+    fn test_beider_morse_name_prefixes() {
+        assert_eq!(encode("van Dyke"), "fandiki,fandik,diki,dik");
+        assert_eq!(
+            encode("d'Angelo"),
+            encode("dangelo") + "," + &encode("angelo")
+        );
+    }
+
+    #[test]
+    fn test_beider_morse_limits_codes() {
+        assert_eq!(
+            BeiderMorseEncoder::new().encode("Chachachacha").len(),
+            MAX_CODES
+        );
+        assert!(BeiderMorseEncoder::new().encode("42").is_empty());
+    }
+}
@@ -0,0 +1,321 @@
+//! Daitch–Mokotoff Soundex encoder.
+//!
+//! Daitch–Mokotoff Soundex refines American Soundex for Slavic, Germanic,
+//! and Yiddish surnames. Letter groups are coded as a whole (e.g. "sch",
+//! "tsch", "rz"), the code of a group depends on whether it starts the
+//! word, is followed by a vowel, or neither, and some groups have two
+//! possible codes, so a word can produce several six-digit codes. This
+//! makes it a good fit for matching names transliterated from different
+//! languages, such as "Schmidt" and "Smith".
+//!
+//! Only ASCII letters are considered.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::phonetic::PhoneticEncoder;
+//! use laurus::analysis::token_filter::phonetic::daitch_mokotoff::DaitchMokotoffEncoder;
+//!
+//! let encoder = DaitchMokotoffEncoder::new();
+//!
+//! assert_eq!(encoder.encode("Schmidt"), vec!["463000"]);
+//! assert_eq!(encoder.encode("Smith"), vec!["463000"]);
+//! assert_eq!(encoder.encode("Peters"), vec!["739400", "734000"]);
+//! ```
+
+use crate::analysis::token_filter::phonetic::PhoneticEncoder;
+
+/// Length of a Daitch–Mokotoff code.
+const CODE_LENGTH: usize = 6;
+
+/// A coding rule: a letter group and its codes at the start of a word,
+/// before a vowel, and in any other position. Alternative codes are
+/// separated by `|`.
+struct Rule {
+    pattern: &'static str,
+    at_start: &'static str,
+    before_vowel: &'static str,
+    otherwise: &'static str,
+}
+
+const fn rule(
+    pattern: &'static str,
+    at_start: &'static str,
+    before_vowel: &'static str,
+    otherwise: &'static str,
+) -> Rule {
+    Rule {
+        pattern,
+        at_start,
+        before_vowel,
+        otherwise,
+    }
+}
+
+/// The coding table. Longer patterns are tried before shorter ones.
+const RULES: &[Rule] = &[
+    // Vowels
+    rule("a", "0", "", ""),
+    rule("ai", "0", "1", ""),
+    rule("aj", "0", "1", ""),
+    rule("ay", "0", "1", ""),
+    rule("au", "0", "7", ""),
+    rule("e", "0", "", ""),
+    rule("ei", "0", "1", ""),
+    rule("ej", "0", "1", ""),
+    rule("ey", "0", "1", ""),
+    rule("eu", "1", "1", ""),
+    rule("i", "0", "", ""),
+    rule("ia", "1", "", ""),
+    rule("ie", "1", "", ""),
+    rule("io", "1", "", ""),
+    rule("iu", "1", "", ""),
+    rule("o", "0", "", ""),
+    rule("oi", "0", "1", ""),
+    rule("oj", "0", "1", ""),
+    rule("oy", "0", "1", ""),
+    rule("u", "0", "", ""),
+    rule("ue", "0", "", ""),
+    rule("ui", "0", "1", ""),
+    rule("uj", "0", "1", ""),
+    rule("uy", "0", "1", ""),
+    rule("y", "1", "", ""),
+    // Consonants
+    rule("b", "7", "7", "7"),
+    rule("c", "5|4", "5|4", "5|4"),
+    rule("ch", "5|4", "5|4", "5|4"),
+    rule("chs", "5", "54", "54"),
+    rule("ck", "5|45", "5|45", "5|45"),
+    rule("cs", "4", "4", "4"),
+    rule("csz", "4", "4", "4"),
+    rule("cz", "4", "4", "4"),
+    rule("czs", "4", "4", "4"),
+    rule("d", "3", "3", "3"),
+    rule("drs", "4", "4", "4"),
+    rule("drz", "4", "4", "4"),
+    rule("ds", "4", "4", "4"),
+    rule("dsh", "4", "4", "4"),
+    rule("dsz", "4", "4", "4"),
+    rule("dt", "3", "3", "3"),
+    rule("dz", "4", "4", "4"),
+    rule("dzh", "4", "4", "4"),
+    rule("dzs", "4", "4", "4"),
+    rule("f", "7", "7", "7"),
+    rule("fb", "7", "7", "7"),
+    rule("g", "5", "5", "5"),
+    rule("h", "5", "5", ""),
+    rule("j", "1|4", "|4", "|4"),
+    rule("k", "5", "5", "5"),
+    rule("kh", "5", "5", "5"),
+    rule("ks", "5", "54", "54"),
+    rule("l", "8", "8", "8"),
+    rule("m", "6", "6", "6"),
+    rule("mn", "", "66", "66"),
+    rule("n", "6", "6", "6"),
+    rule("nm", "", "66", "66"),
+    rule("p", "7", "7", "7"),
+    rule("pf", "7", "7", "7"),
+    rule("ph", "7", "7", "7"),
+    rule("q", "5", "5", "5"),
+    rule("r", "9", "9", "9"),
+    rule("rs", "94|4", "94|4", "94|4"),
+    rule("rz", "94|4", "94|4", "94|4"),
+    rule("s", "4", "4", "4"),
+    rule("sch", "4", "4", "4"),
+    rule("schd", "2", "43", "43"),
+    rule("scht", "2", "43", "43"),
+    rule("schtch", "2", "4", "4"),
+    rule("schtsch", "2", "4", "4"),
+    rule("schtsh", "2", "4", "4"),
+    rule("sd", "2", "43", "43"),
+    rule("sh", "4", "4", "4"),
+    rule("shch", "2", "4", "4"),
+    rule("shd", "2", "43", "43"),
+    rule("sht", "2", "43", "43"),
+    rule("shtch", "2", "4", "4"),
+    rule("shtsh", "2", "4", "4"),
+    rule("st", "2", "43", "43"),
+    rule("stch", "2", "4", "4"),
+    rule("strs", "2", "4", "4"),
+    rule("strz", "2", "4", "4"),
+    rule("stsch", "2", "4", "4"),
+    rule("stsh", "2", "4", "4"),
+    rule("sz", "4", "4", "4"),
+    rule("szcs", "2", "4", "4"),
+    rule("szcz", "2", "4", "4"),
+    rule("szd", "2", "43", "43"),
+    rule("szt", "2", "43", "43"),
+    rule("t", "3", "3", "3"),
+    rule("tc", "4", "4", "4"),
+    rule("tch", "4", "4", "4"),
+    rule("th", "3", "3", "3"),
+    rule("trs", "4", "4", "4"),
+    rule("trz", "4", "4", "4"),
+    rule("ts", "4", "4", "4"),
+    rule("tsch", "4", "4", "4"),
+    rule("tsh", "4", "4", "4"),
+    rule("tsz", "4", "4", "4"),
+    rule("ttch", "4", "4", "4"),
+    rule("tth", "3", "3", "3"),
+    rule("tts", "4", "4", "4"),
+    rule("ttsch", "4", "4", "4"),
+    rule("ttsz", "4", "4", "4"),
+    rule("ttz", "4", "4", "4"),
+    rule("tz", "4", "4", "4"),
+    rule("tzs", "4", "4", "4"),
+    rule("v", "7", "7", "7"),
+    rule("w", "7", "7", "7"),
+    rule("x", "5", "54", "54"),
+    rule("z", "4", "4", "4"),
+    rule("zd", "2", "43", "43"),
+    rule("zdz", "2", "4", "4"),
+    rule("zdzh", "2", "4", "4"),
+    rule("zh", "4", "4", "4"),
+    rule("zhd", "2", "43", "43"),
+    rule("zhdzh", "2", "4", "4"),
+    rule("zs", "4", "4", "4"),
+    rule("zsch", "4", "4", "4"),
+    rule("zsh", "4", "4", "4"),
+];
+
+fn is_vowel(c: u8) -> bool {
+    matches!(c, b'a' | b'e' | b'i' | b'o' | b'u')
+}
+
+/// The longest rule matching `word` at `index`.
+fn longest_match(word: &[u8], index: usize) -> Option<&'static Rule> {
+    RULES
+        .iter()
+        .filter(|rule| word[index..].starts_with(rule.pattern.as_bytes()))
+        .max_by_key(|rule| rule.pattern.len())
+}
+
+/// One possible code being built.
+#[derive(Clone)]
+struct Branch {
+    code: String,
+    last: Option<&'static str>,
+}
+
+impl Branch {
+    /// Append `digits` unless the previous group ended with the same
+    /// digits, so that adjacent letters with the same code are coded once.
+    fn push(&mut self, digits: &'static str) {
+        if !self.last.is_some_and(|last| last.ends_with(digits)) {
+            self.code.push_str(digits);
+            self.code.truncate(CODE_LENGTH);
+        }
+        self.last = Some(digits);
+    }
+}
+
+/// Daitch–Mokotoff Soundex encoder.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DaitchMokotoffEncoder;
+
+impl DaitchMokotoffEncoder {
+    /// Create a new Daitch–Mokotoff encoder.
+    pub fn new() -> Self {
+        DaitchMokotoffEncoder
+    }
+}
+
+impl PhoneticEncoder for DaitchMokotoffEncoder {
+    fn encode(&self, word: &str) -> Vec<String> {
+        let word: Vec<u8> = word
+            .bytes()
+            .filter(u8::is_ascii_alphabetic)
+            .map(|b| b.to_ascii_lowercase())
+            .collect();
+        if word.is_empty() {
+            return Vec::new();
+        }
+
+        let mut branches = vec![Branch {
+            code: String::new(),
+            last: None,
+        }];
+        let mut index = 0;
+        while index < word.len() {
+            let Some(rule) = longest_match(&word, index) else {
+                index += 1;
+                continue;
+            };
+            let end = index + rule.pattern.len();
+            let codes = if index == 0 {
+                rule.at_start
+            } else if end < word.len() && is_vowel(word[end]) {
+                rule.before_vowel
+            } else {
+                rule.otherwise
+            };
+
+            let alternatives: Vec<&'static str> = codes.split('|').collect();
+            branches = branches
+                .into_iter()
+                .flat_map(|branch| {
+                    alternatives.iter().map(move |digits| {
+                        let mut branch = branch.clone();
+                        branch.push(digits);
+                        branch
+                    })
+                })
+                .collect();
+            index = end;
+        }
+
+        let mut codes: Vec<String> = Vec::with_capacity(branches.len());
+        for mut branch in branches {
+            while branch.code.len() < CODE_LENGTH {
+                branch.code.push('0');
+            }
+            if !codes.contains(&branch.code) {
+                codes.push(branch.code);
+            }
+        }
+        codes
+    }
+
+    fn name(&self) -> &'static str {
+        "daitch_mokotoff"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(word: &str) -> String {
+        DaitchMokotoffEncoder::new().encode(word).join(",")
+    }
+
+    #[test]
+    fn test_daitch_mokotoff_codes() {
+        assert_eq!(encode("Schmidt"), "463000");
+        assert_eq!(encode("Smith"), "463000");
+        assert_eq!(encode("Stephen"), "276000");
+        assert_eq!(encode("Steven"), "276000");
+        assert_eq!(encode("Moskowitz"), "645740");
+        assert_eq!(encode("Auerbach"), "097500,097400");
+    }
+
+    #[test]
+    fn test_daitch_mokotoff_branches() {
+        assert_eq!(encode("Peters"), "739400,734000");
+        assert_eq!(encode("Jackson"), "154600,145460,454600,445460");
+    }
+
+    #[test]
+    fn test_daitch_mokotoff_adjacent_codes() {
+        // Repeated codes are coded once unless a vowel separates them.
+        assert_eq!(encode("Lloyd"), "830000");
+        assert_eq!(encode("Lilly"), "880000");
+        // "mn" is coded as two digits.
+        assert_eq!(encode("Amnon"), "066600");
+    }
+
+    #[test]
+    fn test_daitch_mokotoff_without_letters() {
+        assert!(DaitchMokotoffEncoder::new().encode("42").is_empty());
+    }
+}
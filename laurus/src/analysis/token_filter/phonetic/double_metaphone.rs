@@ -0,0 +1,805 @@
+//! Double Metaphone encoder.
+//!
+//! Double Metaphone, by Lawrence Philips, encodes a word into a primary
+//! and an alternate key of up to four characters. The rules cover the
+//! spelling conventions of English as well as names of Germanic, Slavic,
+//! Romance, and other origins, which is where the alternate key differs
+//! from the primary one (e.g. "Schmidt" is coded `XMT` and `SMT`). `0`
+//! stands for the "th" sound and `X` for "sh"/"ch".
+//!
+//! This is a port of the reference rules as implemented by Apache Commons
+//! Codec.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::phonetic::PhoneticEncoder;
+//! use laurus::analysis::token_filter::phonetic::double_metaphone::DoubleMetaphoneEncoder;
+//!
+//! let encoder = DoubleMetaphoneEncoder::new();
+//!
+//! assert_eq!(encoder.encode("Stephen"), vec!["STFN"]);
+//! assert_eq!(encoder.encode("Smith"), vec!["SM0", "XMT"]);
+//! assert_eq!(encoder.encode("Schmidt"), vec!["XMT", "SMT"]);
+//! ```
+
+use crate::analysis::token_filter::phonetic::PhoneticEncoder;
+
+/// Default maximum length of a key.
+const DEFAULT_MAX_LENGTH: usize = 4;
+
+/// Double Metaphone encoder.
+#[derive(Debug, Clone, Copy)]
+pub struct DoubleMetaphoneEncoder {
+    max_length: usize,
+}
+
+impl DoubleMetaphoneEncoder {
+    /// Create a new Double Metaphone encoder producing keys of up to four
+    /// characters.
+    pub fn new() -> Self {
+        DoubleMetaphoneEncoder {
+            max_length: DEFAULT_MAX_LENGTH,
+        }
+    }
+
+    /// Set the maximum length of a key.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length.max(1);
+        self
+    }
+
+    /// Get the maximum length of a key.
+    pub fn max_length(&self) -> usize {
+        self.max_length
+    }
+
+    /// Compute the primary and alternate keys of `word`.
+    pub fn keys(&self, word: &str) -> (String, String) {
+        let chars: Vec<char> = word.trim().to_uppercase().chars().collect();
+        let word = Word { chars: &chars };
+        let mut keys = Keys {
+            primary: String::new(),
+            alternate: String::new(),
+            max_length: self.max_length,
+        };
+        word.encode(&mut keys);
+        (keys.primary, keys.alternate)
+    }
+}
+
+impl Default for DoubleMetaphoneEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticEncoder for DoubleMetaphoneEncoder {
+    fn encode(&self, word: &str) -> Vec<String> {
+        let (primary, alternate) = self.keys(word);
+        let mut codes = Vec::with_capacity(2);
+        if !primary.is_empty() {
+            codes.push(primary);
+        }
+        if !alternate.is_empty() && !codes.contains(&alternate) {
+            codes.push(alternate);
+        }
+        codes
+    }
+
+    fn name(&self) -> &'static str {
+        "double_metaphone"
+    }
+}
+
+/// The primary and alternate keys being built.
+struct Keys {
+    primary: String,
+    alternate: String,
+    max_length: usize,
+}
+
+impl Keys {
+    fn push_to(key: &mut String, value: &str, max_length: usize) {
+        for c in value.chars() {
+            if key.chars().count() >= max_length {
+                break;
+            }
+            key.push(c);
+        }
+    }
+
+    /// Append `value` to both keys.
+    fn append(&mut self, value: &str) {
+        self.append_both(value, value);
+    }
+
+    /// Append `primary` to the primary key and `alternate` to the alternate
+    /// key.
+    fn append_both(&mut self, primary: &str, alternate: &str) {
+        self.append_primary(primary);
+        self.append_alternate(alternate);
+    }
+
+    fn append_primary(&mut self, value: &str) {
+        Self::push_to(&mut self.primary, value, self.max_length);
+    }
+
+    fn append_alternate(&mut self, value: &str) {
+        Self::push_to(&mut self.alternate, value, self.max_length);
+    }
+
+    fn is_complete(&self) -> bool {
+        self.primary.chars().count() >= self.max_length
+            && self.alternate.chars().count() >= self.max_length
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'A' | 'E' | 'I' | 'O' | 'U' | 'Y')
+}
+
+/// An uppercased word with the lookup helpers used by the rules.
+///
+/// Indexes are signed so that rules can look behind the start of the word;
+/// lookups outside the word never match.
+struct Word<'a> {
+    chars: &'a [char],
+}
+
+impl Word<'_> {
+    fn len(&self) -> isize {
+        self.chars.len() as isize
+    }
+
+    fn last(&self) -> isize {
+        self.len() - 1
+    }
+
+    /// The character at `index`, or `'\0'` outside the word.
+    fn at(&self, index: isize) -> char {
+        if index < 0 || index >= self.len() {
+            '\0'
+        } else {
+            self.chars[index as usize]
+        }
+    }
+
+    /// Check whether the `length` characters starting at `start` equal one
+    /// of `options`.
+    fn contains(&self, start: isize, length: isize, options: &[&str]) -> bool {
+        if start < 0 || start + length > self.len() {
+            return false;
+        }
+        let slice = &self.chars[start as usize..(start + length) as usize];
+        options
+            .iter()
+            .any(|option| option.chars().eq(slice.iter().copied()))
+    }
+
+    fn is_slavo_germanic(&self) -> bool {
+        let text: String = self.chars.iter().collect();
+        text.contains('W') || text.contains('K') || text.contains("CZ") || text.contains("WITZ")
+    }
+
+    fn is_silent_start(&self) -> bool {
+        self.contains(0, 2, &["GN", "KN", "PN", "WR", "PS"])
+    }
+
+    /// Skip one or two characters depending on whether the next one is `c`.
+    fn skip_double(&self, index: isize, c: char) -> isize {
+        if self.at(index + 1) == c {
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    fn encode(&self, keys: &mut Keys) {
+        let slavo_germanic = self.is_slavo_germanic();
+        let mut index = if self.is_silent_start() { 1 } else { 0 };
+
+        while !keys.is_complete() && index < self.len() {
+            index = match self.at(index) {
+                'A' | 'E' | 'I' | 'O' | 'U' | 'Y' => {
+                    if index == 0 {
+                        keys.append("A");
+                    }
+                    index + 1
+                }
+                'B' => {
+                    keys.append("P");
+                    self.skip_double(index, 'B')
+                }
+                'Ç' => {
+                    keys.append("S");
+                    index + 1
+                }
+                'C' => self.handle_c(keys, index),
+                'D' => self.handle_d(keys, index),
+                'F' => {
+                    keys.append("F");
+                    self.skip_double(index, 'F')
+                }
+                'G' => self.handle_g(keys, index, slavo_germanic),
+                'H' => self.handle_h(keys, index),
+                'J' => self.handle_j(keys, index, slavo_germanic),
+                'K' => {
+                    keys.append("K");
+                    self.skip_double(index, 'K')
+                }
+                'L' => self.handle_l(keys, index),
+                'M' => {
+                    keys.append("M");
+                    if self.condition_m0(index) {
+                        index + 2
+                    } else {
+                        index + 1
+                    }
+                }
+                'N' => {
+                    keys.append("N");
+                    self.skip_double(index, 'N')
+                }
+                'Ñ' => {
+                    keys.append("N");
+                    index + 1
+                }
+                'P' => self.handle_p(keys, index),
+                'Q' => {
+                    keys.append("K");
+                    self.skip_double(index, 'Q')
+                }
+                'R' => self.handle_r(keys, index, slavo_germanic),
+                'S' => self.handle_s(keys, index, slavo_germanic),
+                'T' => self.handle_t(keys, index),
+                'V' => {
+                    keys.append("F");
+                    self.skip_double(index, 'V')
+                }
+                'W' => self.handle_w(keys, index),
+                'X' => self.handle_x(keys, index),
+                'Z' => self.handle_z(keys, index, slavo_germanic),
+                _ => index + 1,
+            };
+        }
+    }
+
+    fn handle_c(&self, keys: &mut Keys, index: isize) -> isize {
+        if self.condition_c0(index) {
+            // Germanic "ach", e.g. "Bacher", "Macher"
+            keys.append("K");
+            index + 2
+        } else if index == 0 && self.contains(index, 6, &["CAESAR"]) {
+            keys.append("S");
+            index + 2
+        } else if self.contains(index, 2, &["CH"]) {
+            self.handle_ch(keys, index)
+        } else if self.contains(index, 2, &["CZ"]) && !self.contains(index - 2, 4, &["WICZ"]) {
+            // "Czerny"
+            keys.append_both("S", "X");
+            index + 2
+        } else if self.contains(index + 1, 3, &["CIA"]) {
+            // "focaccia"
+            keys.append("X");
+            index + 3
+        } else if self.contains(index, 2, &["CC"]) && !(index == 1 && self.at(0) == 'M') {
+            // Double "cc" but not "McClelland"
+            self.handle_cc(keys, index)
+        } else if self.contains(index, 2, &["CK", "CG", "CQ"]) {
+            keys.append("K");
+            index + 2
+        } else if self.contains(index, 2, &["CI", "CE", "CY"]) {
+            // Italian vs. English
+            if self.contains(index, 3, &["CIO", "CIE", "CIA"]) {
+                keys.append_both("S", "X");
+            } else {
+                keys.append("S");
+            }
+            index + 2
+        } else {
+            keys.append("K");
+            if self.contains(index + 1, 2, &[" C", " Q", " G"]) {
+                // "Mac Caffrey", "Mac Gregor"
+                index + 3
+            } else if self.contains(index + 1, 1, &["C", "K", "Q"])
+                && !self.contains(index + 1, 2, &["CE", "CI"])
+            {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn handle_cc(&self, keys: &mut Keys, index: isize) -> isize {
+        if self.contains(index + 2, 1, &["I", "E", "H"]) && !self.contains(index + 2, 2, &["HU"]) {
+            // "bellocchio" but not "bacchus"
+            if (index == 1 && self.at(index - 1) == 'A')
+                || self.contains(index - 1, 5, &["UCCEE", "UCCES"])
+            {
+                // "accident", "accede", "succeed"
+                keys.append("KS");
+            } else {
+                // "bacci", "bertucci", other Italian
+                keys.append("X");
+            }
+            index + 3
+        } else {
+            // Pierce's rule
+            keys.append("K");
+            index + 2
+        }
+    }
+
+    fn handle_ch(&self, keys: &mut Keys, index: isize) -> isize {
+        if index > 0 && self.contains(index, 4, &["CHAE"]) {
+            // "Michael"
+            keys.append_both("K", "X");
+        } else if self.condition_ch0(index) || self.condition_ch1(index) {
+            // Greek roots ("chemistry", "chorus") and Germanic "ch" for "kh"
+            keys.append("K");
+        } else if index > 0 {
+            if self.contains(0, 2, &["MC"]) {
+                keys.append("K");
+            } else {
+                keys.append_both("X", "K");
+            }
+        } else {
+            keys.append("X");
+        }
+        index + 2
+    }
+
+    fn handle_d(&self, keys: &mut Keys, index: isize) -> isize {
+        if self.contains(index, 2, &["DG"]) {
+            if self.contains(index + 2, 1, &["I", "E", "Y"]) {
+                // "edge"
+                keys.append("J");
+                index + 3
+            } else {
+                // "Edgar"
+                keys.append("TK");
+                index + 2
+            }
+        } else if self.contains(index, 2, &["DT", "DD"]) {
+            keys.append("T");
+            index + 2
+        } else {
+            keys.append("T");
+            index + 1
+        }
+    }
+
+    fn handle_g(&self, keys: &mut Keys, index: isize, slavo_germanic: bool) -> isize {
+        if self.at(index + 1) == 'H' {
+            self.handle_gh(keys, index)
+        } else if self.at(index + 1) == 'N' {
+            if index == 1 && is_vowel(self.at(0)) && !slavo_germanic {
+                keys.append_both("KN", "N");
+            } else if !self.contains(index + 2, 2, &["EY"])
+                && self.at(index + 1) != 'Y'
+                && !slavo_germanic
+            {
+                keys.append_both("N", "KN");
+            } else {
+                keys.append("KN");
+            }
+            index + 2
+        } else if self.contains(index + 1, 2, &["LI"]) && !slavo_germanic {
+            keys.append_both("KL", "L");
+            index + 2
+        } else if index == 0
+            && (self.at(index + 1) == 'Y'
+                || self.contains(
+                    index + 1,
+                    2,
+                    &[
+                        "ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER",
+                    ],
+                ))
+        {
+            // -ges-, -gep-, -gel-, -gie- at the beginning
+            keys.append_both("K", "J");
+            index + 2
+        } else if (self.contains(index + 1, 2, &["ER"]) || self.at(index + 1) == 'Y')
+            && !self.contains(0, 6, &["DANGER", "RANGER", "MANGER"])
+            && !self.contains(index - 1, 1, &["E", "I"])
+            && !self.contains(index - 1, 3, &["RGY", "OGY"])
+        {
+            // -ger-, -gy-
+            keys.append_both("K", "J");
+            index + 2
+        } else if self.contains(index + 1, 1, &["E", "I", "Y"])
+            || self.contains(index - 1, 4, &["AGGI", "OGGI"])
+        {
+            if self.contains(0, 4, &["VAN ", "VON "])
+                || self.contains(0, 3, &["SCH"])
+                || self.contains(index + 1, 2, &["ET"])
+            {
+                // Obviously Germanic
+                keys.append("K");
+            } else if self.contains(index + 1, 3, &["IER"]) {
+                keys.append("J");
+            } else {
+                keys.append_both("J", "K");
+            }
+            index + 2
+        } else if self.at(index + 1) == 'G' {
+            keys.append("K");
+            index + 2
+        } else {
+            keys.append("K");
+            index + 1
+        }
+    }
+
+    fn handle_gh(&self, keys: &mut Keys, index: isize) -> isize {
+        if index > 0 && !is_vowel(self.at(index - 1)) {
+            keys.append("K");
+        } else if index == 0 {
+            if self.at(index + 2) == 'I' {
+                keys.append("J");
+            } else {
+                keys.append("K");
+            }
+        } else if (index > 1 && self.contains(index - 2, 1, &["B", "H", "D"]))
+            || (index > 2 && self.contains(index - 3, 1, &["B", "H", "D"]))
+            || (index > 3 && self.contains(index - 4, 1, &["B", "H"]))
+        {
+            // Parker's rule, e.g. "hugh"
+        } else if index > 2
+            && self.at(index - 1) == 'U'
+            && self.contains(index - 3, 1, &["C", "G", "L", "R", "T"])
+        {
+            // "laugh", "McLaughlin", "cough", "rough", "tough"
+            keys.append("F");
+        } else if index > 0 && self.at(index - 1) != 'I' {
+            keys.append("K");
+        }
+        index + 2
+    }
+
+    fn handle_h(&self, keys: &mut Keys, index: isize) -> isize {
+        // Only kept when first or between two vowels
+        if (index == 0 || is_vowel(self.at(index - 1))) && is_vowel(self.at(index + 1)) {
+            keys.append("H");
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    fn handle_j(&self, keys: &mut Keys, index: isize, slavo_germanic: bool) -> isize {
+        if self.contains(index, 4, &["JOSE"]) || self.contains(0, 4, &["SAN "]) {
+            // Obviously Spanish, "Jose", "San Jacinto"
+            if (index == 0 && self.at(index + 4) == ' ')
+                || self.len() == 4
+                || self.contains(0, 4, &["SAN "])
+            {
+                keys.append("H");
+            } else {
+                keys.append_both("J", "H");
+            }
+            return index + 1;
+        }
+
+        if index == 0 {
+            keys.append_both("J", "A");
+        } else if is_vowel(self.at(index - 1))
+            && !slavo_germanic
+            && matches!(self.at(index + 1), 'A' | 'O')
+        {
+            keys.append_both("J", "H");
+        } else if index == self.last() {
+            keys.append_primary("J");
+        } else if !self.contains(index + 1, 1, &["L", "T", "K", "S", "N", "M", "B", "Z"])
+            && !self.contains(index - 1, 1, &["S", "K", "L"])
+        {
+            keys.append("J");
+        }
+        self.skip_double(index, 'J')
+    }
+
+    fn handle_l(&self, keys: &mut Keys, index: isize) -> isize {
+        if self.at(index + 1) == 'L' {
+            if self.condition_l0(index) {
+                // Spanish "-illo", "-illa", "-alle"
+                keys.append_primary("L");
+            } else {
+                keys.append("L");
+            }
+            index + 2
+        } else {
+            keys.append("L");
+            index + 1
+        }
+    }
+
+    fn handle_p(&self, keys: &mut Keys, index: isize) -> isize {
+        if self.at(index + 1) == 'H' {
+            keys.append("F");
+            index + 2
+        } else {
+            keys.append("P");
+            if self.contains(index + 1, 1, &["P", "B"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn handle_r(&self, keys: &mut Keys, index: isize, slavo_germanic: bool) -> isize {
+        if index == self.last()
+            && !slavo_germanic
+            && self.contains(index - 2, 2, &["IE"])
+            && !self.contains(index - 4, 2, &["ME", "MA"])
+        {
+            // French, e.g. "Rogier"
+            keys.append_alternate("R");
+        } else {
+            keys.append("R");
+        }
+        self.skip_double(index, 'R')
+    }
+
+    fn handle_s(&self, keys: &mut Keys, index: isize, slavo_germanic: bool) -> isize {
+        if self.contains(index - 1, 3, &["ISL", "YSL"]) {
+            // "island", "isle", "carlisle", "carlysle"
+            index + 1
+        } else if index == 0 && self.contains(index, 5, &["SUGAR"]) {
+            keys.append_both("X", "S");
+            index + 1
+        } else if self.contains(index, 2, &["SH"]) {
+            if self.contains(index + 1, 4, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
+                // Germanic
+                keys.append("S");
+            } else {
+                keys.append("X");
+            }
+            index + 2
+        } else if self.contains(index, 3, &["SIO", "SIA"]) || self.contains(index, 4, &["SIAN"]) {
+            // Italian and Armenian
+            if slavo_germanic {
+                keys.append("S");
+            } else {
+                keys.append_both("S", "X");
+            }
+            index + 3
+        } else if (index == 0 && self.contains(index + 1, 1, &["M", "N", "L", "W"]))
+            || self.contains(index + 1, 1, &["Z"])
+        {
+            // German and anglicisations, e.g. "Smith" matches "Schmidt" and
+            // "Snider" matches "Schneider"; also Slavic "-sz-"
+            keys.append_both("S", "X");
+            self.skip_double(index, 'Z')
+        } else if self.contains(index, 2, &["SC"]) {
+            self.handle_sc(keys, index)
+        } else {
+            if index == self.last() && self.contains(index - 2, 2, &["AI", "OI"]) {
+                // French, e.g. "Resnais", "Artois"
+                keys.append_alternate("S");
+            } else {
+                keys.append("S");
+            }
+            if self.contains(index + 1, 1, &["S", "Z"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn handle_sc(&self, keys: &mut Keys, index: isize) -> isize {
+        if self.at(index + 2) == 'H' {
+            // Schlesinger's rule
+            if self.contains(index + 3, 2, &["OO", "ER", "EN", "UY", "ED", "EM"]) {
+                // Dutch origin, e.g. "school", "schooner"
+                if self.contains(index + 3, 2, &["ER", "EN"]) {
+                    // "Schermerhorn", "Schenker"
+                    keys.append_both("X", "SK");
+                } else {
+                    keys.append("SK");
+                }
+            } else if index == 0 && !is_vowel(self.at(3)) && self.at(3) != 'W' {
+                keys.append_both("X", "S");
+            } else {
+                keys.append("X");
+            }
+        } else if self.contains(index + 2, 1, &["I", "E", "Y"]) {
+            keys.append("S");
+        } else {
+            keys.append("SK");
+        }
+        index + 3
+    }
+
+    fn handle_t(&self, keys: &mut Keys, index: isize) -> isize {
+        if self.contains(index, 4, &["TION"]) || self.contains(index, 3, &["TIA", "TCH"]) {
+            keys.append("X");
+            index + 3
+        } else if self.contains(index, 2, &["TH"]) || self.contains(index, 3, &["TTH"]) {
+            if self.contains(index + 2, 2, &["OM", "AM"])
+                || self.contains(0, 4, &["VAN ", "VON "])
+                || self.contains(0, 3, &["SCH"])
+            {
+                // "Thomas", "Thames", or Germanic
+                keys.append("T");
+            } else {
+                keys.append_both("0", "T");
+            }
+            index + 2
+        } else {
+            keys.append("T");
+            if self.contains(index + 1, 1, &["T", "D"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn handle_w(&self, keys: &mut Keys, index: isize) -> isize {
+        if self.contains(index, 2, &["WR"]) {
+            keys.append("R");
+            index + 2
+        } else if index == 0 && (is_vowel(self.at(index + 1)) || self.contains(index, 2, &["WH"])) {
+            if is_vowel(self.at(index + 1)) {
+                // "Wasserman" matches "Vasserman"
+                keys.append_both("A", "F");
+            } else {
+                // "Uomo" matches "Womo"
+                keys.append("A");
+            }
+            index + 1
+        } else if (index == self.last() && is_vowel(self.at(index - 1)))
+            || self.contains(index - 1, 5, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])
+            || self.contains(0, 3, &["SCH"])
+        {
+            // Polish, e.g. "Filipowski"
+            keys.append_alternate("F");
+            index + 1
+        } else if self.contains(index, 4, &["WICZ", "WITZ"]) {
+            // Polish, e.g. "Filipowicz"
+            keys.append_both("TS", "FX");
+            index + 4
+        } else {
+            index + 1
+        }
+    }
+
+    fn handle_x(&self, keys: &mut Keys, index: isize) -> isize {
+        if index == 0 {
+            keys.append("S");
+            return index + 1;
+        }
+        if !(index == self.last()
+            && (self.contains(index - 3, 3, &["IAU", "EAU"])
+                || self.contains(index - 2, 2, &["AU", "OU"])))
+        {
+            // Not French, e.g. "breaux"
+            keys.append("KS");
+        }
+        if self.contains(index + 1, 1, &["C", "X"]) {
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    fn handle_z(&self, keys: &mut Keys, index: isize, slavo_germanic: bool) -> isize {
+        if self.at(index + 1) == 'H' {
+            // Chinese pinyin, e.g. "Zhao"
+            keys.append("J");
+            return index + 2;
+        }
+        if self.contains(index + 1, 2, &["ZO", "ZI", "ZA"])
+            || (slavo_germanic && index > 0 && self.at(index - 1) != 'T')
+        {
+            keys.append_both("S", "TS");
+        } else {
+            keys.append("S");
+        }
+        self.skip_double(index, 'Z')
+    }
+
+    fn condition_c0(&self, index: isize) -> bool {
+        if self.contains(index, 4, &["CHIA"]) {
+            true
+        } else if index <= 1
+            || is_vowel(self.at(index - 2))
+            || !self.contains(index - 1, 3, &["ACH"])
+        {
+            false
+        } else {
+            let c = self.at(index + 2);
+            (c != 'I' && c != 'E') || self.contains(index - 2, 6, &["BACHER", "MACHER"])
+        }
+    }
+
+    fn condition_ch0(&self, index: isize) -> bool {
+        index == 0
+            && (self.contains(index + 1, 5, &["HARAC", "HARIS"])
+                || self.contains(index + 1, 3, &["HOR", "HYM", "HIA", "HEM"]))
+            && !self.contains(0, 5, &["CHORE"])
+    }
+
+    fn condition_ch1(&self, index: isize) -> bool {
+        self.contains(0, 4, &["VAN ", "VON "])
+            || self.contains(0, 3, &["SCH"])
+            || self.contains(index - 2, 6, &["ORCHES", "ARCHIT", "ORCHID"])
+            || self.contains(index + 2, 1, &["T", "S"])
+            || ((self.contains(index - 1, 1, &["A", "O", "U", "E"]) || index == 0)
+                && (self.contains(
+                    index + 2,
+                    1,
+                    &["L", "R", "N", "M", "B", "H", "F", "V", "W", " "],
+                ) || index + 1 == self.last()))
+    }
+
+    fn condition_l0(&self, index: isize) -> bool {
+        (index == self.len() - 3 && self.contains(index - 1, 4, &["ILLO", "ILLA", "ALLE"]))
+            || ((self.contains(self.len() - 2, 2, &["AS", "OS"])
+                || self.contains(self.len() - 1, 1, &["A", "O"]))
+                && self.contains(index - 1, 4, &["ALLE"]))
+    }
+
+    fn condition_m0(&self, index: isize) -> bool {
+        self.at(index + 1) == 'M'
+            || (self.contains(index - 1, 3, &["UMB"])
+                && (index + 1 == self.last() || self.contains(index + 2, 2, &["ER"])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(word: &str) -> (String, String) {
+        DoubleMetaphoneEncoder::new().keys(word)
+    }
+
+    fn assert_keys(word: &str, primary: &str, alternate: &str) {
+        assert_eq!(
+            keys(word),
+            (primary.to_string(), alternate.to_string()),
+            "keys of {word}"
+        );
+    }
+
+    #[test]
+    fn test_double_metaphone_keys() {
+        assert_keys("Stephen", "STFN", "STFN");
+        assert_keys("Steven", "STFN", "STFN");
+        assert_keys("Smith", "SM0", "XMT");
+        assert_keys("Schmidt", "XMT", "SMT");
+        assert_keys("Thomas", "TMS", "TMS");
+        assert_keys("Knight", "NT", "NT");
+        assert_keys("Philip", "FLP", "FLP");
+        assert_keys("Jose", "HS", "HS");
+        assert_keys("Xavier", "SF", "SFR");
+        assert_keys("Czerny", "SRN", "XRN");
+        assert_keys("Wasserman", "ASRM", "FSRM");
+        assert_keys("Michael", "MKL", "MXL");
+        assert_keys("Laugh", "LF", "LF");
+    }
+
+    #[test]
+    fn test_double_metaphone_max_length() {
+        let encoder = DoubleMetaphoneEncoder::new().with_max_length(6);
+        assert_eq!(encoder.max_length(), 6);
+        assert_eq!(encoder.keys("Thomas").0, "TMS");
+        assert_eq!(encoder.keys("Alexandra").0, "ALKSNT");
+        assert_eq!(keys("Alexandra").0, "ALKS");
+    }
+
+    #[test]
+    fn test_double_metaphone_encode() {
+        let encoder = DoubleMetaphoneEncoder::new();
+        assert_eq!(encoder.encode("stephen"), vec!["STFN"]);
+        assert_eq!(encoder.encode("smith"), vec!["SM0", "XMT"]);
+        assert!(encoder.encode("").is_empty());
+        assert!(encoder.encode("123").is_empty());
+    }
+}
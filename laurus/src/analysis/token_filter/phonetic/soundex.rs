@@ -0,0 +1,138 @@
+//! American Soundex encoder.
+//!
+//! Soundex keeps the first letter of a word and encodes the following
+//! consonants as digits, so that names which sound alike in English map to
+//! the same four-character code:
+//!
+//! | Digit | Letters |
+//! |-------|---------|
+//! | 1 | B, F, P, V |
+//! | 2 | C, G, J, K, Q, S, X, Z |
+//! | 3 | D, T |
+//! | 4 | L |
+//! | 5 | M, N |
+//! | 6 | R |
+//!
+//! Adjacent letters with the same digit are coded once, also when they are
+//! separated by H or W; vowels separate them. Only ASCII letters are
+//! considered.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::phonetic::PhoneticEncoder;
+//! use laurus::analysis::token_filter::phonetic::soundex::SoundexEncoder;
+//!
+//! let encoder = SoundexEncoder::new();
+//!
+//! assert_eq!(encoder.encode("Robert"), vec!["R163"]);
+//! assert_eq!(encoder.encode("Rupert"), vec!["R163"]);
+//! ```
+
+use crate::analysis::token_filter::phonetic::PhoneticEncoder;
+
+/// Length of a Soundex code.
+const CODE_LENGTH: usize = 4;
+
+/// American Soundex encoder.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SoundexEncoder;
+
+impl SoundexEncoder {
+    /// Create a new Soundex encoder.
+    pub fn new() -> Self {
+        SoundexEncoder
+    }
+}
+
+/// The Soundex digit of an uppercase letter; `None` for vowels and `Some('0')`
+/// for H and W, which do not separate equal digits.
+fn digit(c: char) -> Option<char> {
+    match c {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        'H' | 'W' => Some('0'),
+        _ => None,
+    }
+}
+
+impl PhoneticEncoder for SoundexEncoder {
+    fn encode(&self, word: &str) -> Vec<String> {
+        let mut letters = word
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .map(|c| c.to_ascii_uppercase());
+        let Some(first) = letters.next() else {
+            return Vec::new();
+        };
+
+        let mut code = String::with_capacity(CODE_LENGTH);
+        code.push(first);
+        let mut last = digit(first);
+        for c in letters {
+            if code.len() == CODE_LENGTH {
+                break;
+            }
+            match digit(c) {
+                Some('0') => {}
+                Some(d) => {
+                    if last != Some(d) {
+                        code.push(d);
+                    }
+                    last = Some(d);
+                }
+                None => last = None,
+            }
+        }
+        while code.len() < CODE_LENGTH {
+            code.push('0');
+        }
+
+        vec![code]
+    }
+
+    fn name(&self) -> &'static str {
+        "soundex"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(word: &str) -> String {
+        SoundexEncoder::new().encode(word).join(",")
+    }
+
+    #[test]
+    fn test_soundex_codes() {
+        assert_eq!(encode("Robert"), "R163");
+        assert_eq!(encode("Rupert"), "R163");
+        assert_eq!(encode("Rubin"), "R150");
+        assert_eq!(encode("Tymczak"), "T522");
+        assert_eq!(encode("Pfister"), "P236");
+        assert_eq!(encode("Lee"), "L000");
+    }
+
+    #[test]
+    fn test_soundex_h_and_w_do_not_separate() {
+        assert_eq!(encode("Ashcraft"), "A261");
+        assert_eq!(encode("Ashcroft"), "A261");
+    }
+
+    #[test]
+    fn test_soundex_similar_names() {
+        assert_eq!(encode("Stephen"), encode("Steven"));
+        assert_eq!(encode("stephen"), "S315");
+    }
+
+    #[test]
+    fn test_soundex_without_letters() {
+        assert!(SoundexEncoder::new().encode("1234").is_empty());
+        assert!(SoundexEncoder::new().encode("").is_empty());
+    }
+}
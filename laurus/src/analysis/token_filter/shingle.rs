@@ -0,0 +1,335 @@
+//! Shingle filter implementation.
+//!
+//! This module provides a filter that combines adjacent tokens into
+//! word n-grams ("shingles"), such as `"quick brown"` from `"quick"` and
+//! `"brown"`. Indexing shingles makes phrase and phrase-prefix matching
+//! cheaper, because a two-word phrase becomes a single term lookup.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::Filter;
+//! use laurus::analysis::token_filter::shingle::ShingleFilter;
+//! use laurus::analysis::token::Token;
+//!
+//! let filter = ShingleFilter::new(2, 2).unwrap();
+//! let tokens = vec![
+//!     Token::new("quick", 0),
+//!     Token::new("brown", 1),
+//!     Token::new("fox", 2),
+//! ];
+//!
+//! let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+//!     .unwrap()
+//!     .map(|t| t.text)
+//!     .collect();
+//!
+//! assert_eq!(result, vec!["quick", "quick brown", "brown", "brown fox", "fox"]);
+//! ```
+
+use crate::analysis::token::{Token, TokenStream};
+use crate::analysis::token_filter::Filter;
+use crate::error::{LaurusError, Result};
+
+/// A filter that emits shingles (word n-grams) of adjacent tokens.
+///
+/// For each position, the filter emits the token itself (if
+/// `output_unigrams` is set) followed by the shingles of `min_shingle_size`
+/// to `max_shingle_size` tokens starting there, joined by
+/// `token_separator`.
+///
+/// Shingles are stacked at the position of their first token, span its
+/// offsets through those of their last token, and have a position length
+/// equal to the number of tokens they cover. The output is therefore a
+/// token graph: query parsing treats a shingle as an alternative to the
+/// tokens it covers, and indexing flattens it like a multi-word synonym.
+///
+/// Shingles are only built from tokens at consecutive positions. They do
+/// not span gaps left by removed tokens, and stacked tokens (such as
+/// synonyms) are passed through without being combined.
+///
+/// # Examples
+///
+/// ```
+/// use laurus::analysis::token_filter::Filter;
+/// use laurus::analysis::token_filter::shingle::ShingleFilter;
+/// use laurus::analysis::token::Token;
+///
+/// let filter = ShingleFilter::new(2, 3)
+///     .unwrap()
+///     .with_token_separator("_")
+///     .with_output_unigrams(false);
+/// let tokens = vec![
+///     Token::new("a", 0),
+///     Token::new("b", 1),
+///     Token::new("c", 2),
+/// ];
+///
+/// let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+///     .unwrap()
+///     .collect();
+///
+/// let texts: Vec<_> = result.iter().map(|t| t.text.as_str()).collect();
+/// assert_eq!(texts, vec!["a_b", "a_b_c", "b_c"]);
+/// assert_eq!(result[1].position_length, 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShingleFilter {
+    /// Minimum number of tokens per shingle
+    min_shingle_size: usize,
+    /// Maximum number of tokens per shingle
+    max_shingle_size: usize,
+    /// String placed between the tokens of a shingle
+    token_separator: String,
+    /// Whether the original tokens are emitted as well
+    output_unigrams: bool,
+}
+
+impl ShingleFilter {
+    /// Create a new shingle filter with a space separator that also emits
+    /// the original tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_shingle_size` - Minimum tokens per shingle (must be >= 2)
+    /// * `max_shingle_size` - Maximum tokens per shingle (must be >= min_shingle_size)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `min_shingle_size` is less than 2
+    /// - `max_shingle_size` is less than `min_shingle_size`
+    pub fn new(min_shingle_size: usize, max_shingle_size: usize) -> Result<Self> {
+        if min_shingle_size < 2 {
+            return Err(LaurusError::analysis(
+                "min_shingle_size must be at least 2".to_string(),
+            ));
+        }
+        if max_shingle_size < min_shingle_size {
+            return Err(LaurusError::analysis(format!(
+                "max_shingle_size ({}) must be >= min_shingle_size ({})",
+                max_shingle_size, min_shingle_size
+            )));
+        }
+        Ok(Self {
+            min_shingle_size,
+            max_shingle_size,
+            token_separator: " ".to_string(),
+            output_unigrams: true,
+        })
+    }
+
+    /// Set the string placed between the tokens of a shingle.
+    pub fn with_token_separator<S: Into<String>>(mut self, token_separator: S) -> Self {
+        self.token_separator = token_separator.into();
+        self
+    }
+
+    /// Set whether the original tokens are emitted along with the shingles.
+    pub fn with_output_unigrams(mut self, output_unigrams: bool) -> Self {
+        self.output_unigrams = output_unigrams;
+        self
+    }
+
+    /// Get the minimum number of tokens per shingle.
+    pub fn min_shingle_size(&self) -> usize {
+        self.min_shingle_size
+    }
+
+    /// Get the maximum number of tokens per shingle.
+    pub fn max_shingle_size(&self) -> usize {
+        self.max_shingle_size
+    }
+
+    /// Get the string placed between the tokens of a shingle.
+    pub fn token_separator(&self) -> &str {
+        &self.token_separator
+    }
+
+    /// Check whether the original tokens are emitted.
+    pub fn output_unigrams(&self) -> bool {
+        self.output_unigrams
+    }
+
+    /// The shingles starting at `input[start]`, shortest first.
+    fn shingles_at(&self, input: &[Token], start: usize) -> Vec<Token> {
+        let first = &input[start];
+        let mut shingles = Vec::new();
+        let mut text = first.text.clone();
+        let mut size = 1;
+
+        // Stacked tokens are skipped; a gap ends the shingle.
+        let following = input[start + 1..]
+            .iter()
+            .filter(|token| token.position_increment > 0);
+        for next in following {
+            if next.position_increment > 1 || size == self.max_shingle_size {
+                break;
+            }
+            text.push_str(&self.token_separator);
+            text.push_str(&next.text);
+            size += 1;
+            if size >= self.min_shingle_size {
+                let mut shingle = first.with_text(text.clone()).with_position_length(size);
+                shingle.end_offset = next.end_offset;
+                shingles.push(shingle);
+            }
+        }
+        shingles
+    }
+}
+
+impl Filter for ShingleFilter {
+    fn filter(&self, tokens: TokenStream) -> Result<TokenStream> {
+        let input: Vec<Token> = tokens.collect();
+        let mut output = Vec::new();
+        // Increment carried over from positions that emitted nothing.
+        let mut pending_increment = 0;
+
+        for (i, token) in input.iter().enumerate() {
+            pending_increment += token.position_increment;
+
+            let mut emitted = Vec::new();
+            if self.output_unigrams {
+                emitted.push(token.clone());
+            }
+            if token.position_increment > 0 {
+                emitted.extend(self.shingles_at(&input, i));
+            }
+
+            for mut emitted_token in emitted {
+                emitted_token.position_increment = std::mem::take(&mut pending_increment);
+                output.push(emitted_token);
+            }
+        }
+
+        Ok(Box::new(output.into_iter()))
+    }
+
+    fn name(&self) -> &'static str {
+        "shingle"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(filter: &ShingleFilter, tokens: Vec<Token>) -> Vec<Token> {
+        filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .collect()
+    }
+
+    fn words(words: &[&str]) -> Vec<Token> {
+        let mut offset = 0;
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let token = Token::with_offsets(*word, i, offset, offset + word.len());
+                offset += word.len() + 1;
+                token
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_shingle_filter_creation() {
+        assert!(ShingleFilter::new(2, 3).is_ok());
+        assert!(ShingleFilter::new(1, 3).is_err());
+        assert!(ShingleFilter::new(3, 2).is_err());
+    }
+
+    #[test]
+    fn test_shingle_filter_graph() {
+        let filter = ShingleFilter::new(2, 3).unwrap();
+        let result: Vec<(String, usize, usize, usize, usize)> =
+            run(&filter, words(&["please", "divide", "this"]))
+                .into_iter()
+                .map(|t| {
+                    (
+                        t.text,
+                        t.position_increment,
+                        t.position_length,
+                        t.start_offset,
+                        t.end_offset,
+                    )
+                })
+                .collect();
+
+        assert_eq!(
+            result,
+            vec![
+                ("please".to_string(), 1, 1, 0, 6),
+                ("please divide".to_string(), 0, 2, 0, 13),
+                ("please divide this".to_string(), 0, 3, 0, 18),
+                ("divide".to_string(), 1, 1, 7, 13),
+                ("divide this".to_string(), 0, 2, 7, 18),
+                ("this".to_string(), 1, 1, 14, 18),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shingle_filter_without_unigrams() {
+        let filter = ShingleFilter::new(2, 2)
+            .unwrap()
+            .with_output_unigrams(false);
+        let result: Vec<(String, usize)> = run(&filter, words(&["a", "b", "c"]))
+            .into_iter()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+
+        assert_eq!(result, vec![("a b".to_string(), 1), ("b c".to_string(), 1)]);
+    }
+
+    #[test]
+    fn test_shingle_filter_does_not_span_gaps() {
+        let filter = ShingleFilter::new(2, 2).unwrap();
+        let tokens = vec![
+            Token::new("quick", 0),
+            Token::new("fox", 2).with_position_increment(2),
+            Token::new("jumps", 3),
+        ];
+        let result: Vec<(String, usize)> = run(&filter, tokens)
+            .into_iter()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![
+                ("quick".to_string(), 1),
+                ("fox".to_string(), 2),
+                ("fox jumps".to_string(), 0),
+                ("jumps".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shingle_filter_skips_stacked_tokens() {
+        let filter = ShingleFilter::new(2, 2).unwrap();
+        let tokens = vec![
+            Token::new("big", 0),
+            Token::new("large", 0).with_position_increment(0),
+            Token::new("dog", 1),
+        ];
+        let result: Vec<(String, usize)> = run(&filter, tokens)
+            .into_iter()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![
+                ("big".to_string(), 1),
+                ("big dog".to_string(), 0),
+                ("large".to_string(), 0),
+                ("dog".to_string(), 1),
+            ]
+        );
+    }
+}
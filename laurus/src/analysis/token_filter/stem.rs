@@ -7,6 +7,7 @@
 //!
 //! - [`porter::PorterStemmer`] - Porter stemming algorithm (default)
 //! - [`simple::SimpleStemmer`] - Simple suffix removal
+//! - [`snowball::SnowballStemmer`] - Snowball stemmers for European languages
 //! - [`identity::IdentityStemmer`] - No stemming (pass-through)
 //!
 //! # Examples
@@ -47,9 +48,11 @@ pub trait Stemmer: Send + Sync {
 pub mod identity;
 pub mod porter;
 pub mod simple;
+pub mod snowball;
 
 use porter::PorterStemmer;
 use simple::SimpleStemmer;
+use snowball::{SnowballLanguage, SnowballStemmer};
 
 /// Filter that applies stemming to tokens.
 pub struct StemFilter {
@@ -84,6 +87,13 @@ impl StemFilter {
             stemmer: Box::new(SimpleStemmer::new()),
         }
     }
+
+    /// Create a stem filter with the Snowball stemmer for `language`.
+    pub fn snowball(language: SnowballLanguage) -> Self {
+        StemFilter {
+            stemmer: Box::new(SnowballStemmer::new(language)),
+        }
+    }
 }
 
 impl Default for StemFilter {
@@ -0,0 +1,437 @@
+//! Snowball stemmers for European languages.
+//!
+//! This module implements the stemming algorithms published by the
+//! [Snowball project](https://snowballstem.org/algorithms/) for languages
+//! other than English. Each algorithm removes inflectional and derivational
+//! suffixes in a fixed sequence of steps, where most suffixes may only be
+//! removed from specific regions at the end of the word:
+//!
+//! - **R1**: the region after the first non-vowel following a vowel.
+//! - **R2**: the region after the first non-vowel following a vowel in R1.
+//! - **RV**: a language-specific region used by the Romance languages and
+//!   Russian, roughly the part of the word after its first syllable.
+//!
+//! Words are lowercased before stemming. The stemmers are implemented in
+//! this crate and have no native dependencies.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::stem::Stemmer;
+//! use laurus::analysis::token_filter::stem::snowball::{SnowballLanguage, SnowballStemmer};
+//!
+//! let stemmer = SnowballStemmer::new(SnowballLanguage::French);
+//!
+//! assert_eq!(stemmer.stem("continuellement"), "continuel");
+//! assert_eq!(stemmer.stem("majestueusement"), "majestu");
+//! ```
+
+mod danish;
+mod dutch;
+mod french;
+mod german;
+mod italian;
+mod norwegian;
+mod portuguese;
+mod russian;
+mod spanish;
+mod swedish;
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::analysis::token_filter::stem::Stemmer;
+use crate::error::LaurusError;
+
+/// A language supported by [`SnowballStemmer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnowballLanguage {
+    /// Danish.
+    Danish,
+    /// Dutch.
+    Dutch,
+    /// French.
+    French,
+    /// German.
+    German,
+    /// Italian.
+    Italian,
+    /// Norwegian (Bokmål).
+    Norwegian,
+    /// Portuguese.
+    Portuguese,
+    /// Russian.
+    Russian,
+    /// Spanish.
+    Spanish,
+    /// Swedish.
+    Swedish,
+}
+
+impl SnowballLanguage {
+    /// Every supported language.
+    pub const ALL: [SnowballLanguage; 10] = [
+        SnowballLanguage::Danish,
+        SnowballLanguage::Dutch,
+        SnowballLanguage::French,
+        SnowballLanguage::German,
+        SnowballLanguage::Italian,
+        SnowballLanguage::Norwegian,
+        SnowballLanguage::Portuguese,
+        SnowballLanguage::Russian,
+        SnowballLanguage::Spanish,
+        SnowballLanguage::Swedish,
+    ];
+
+    /// The lowercase English name of the language, e.g. `"french"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SnowballLanguage::Danish => "danish",
+            SnowballLanguage::Dutch => "dutch",
+            SnowballLanguage::French => "french",
+            SnowballLanguage::German => "german",
+            SnowballLanguage::Italian => "italian",
+            SnowballLanguage::Norwegian => "norwegian",
+            SnowballLanguage::Portuguese => "portuguese",
+            SnowballLanguage::Russian => "russian",
+            SnowballLanguage::Spanish => "spanish",
+            SnowballLanguage::Swedish => "swedish",
+        }
+    }
+}
+
+impl fmt::Display for SnowballLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for SnowballLanguage {
+    type Err = LaurusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SnowballLanguage::ALL
+            .into_iter()
+            .find(|language| language.name() == s)
+            .ok_or_else(|| LaurusError::invalid_argument(format!("Unknown language: {s}")))
+    }
+}
+
+/// Snowball stemmer for one of the [`SnowballLanguage`]s.
+#[derive(Debug, Clone, Copy)]
+pub struct SnowballStemmer {
+    language: SnowballLanguage,
+}
+
+impl SnowballStemmer {
+    /// Create a new Snowball stemmer for `language`.
+    pub fn new(language: SnowballLanguage) -> Self {
+        SnowballStemmer { language }
+    }
+
+    /// The language this stemmer handles.
+    pub fn language(&self) -> SnowballLanguage {
+        self.language
+    }
+}
+
+impl Stemmer for SnowballStemmer {
+    fn stem(&self, word: &str) -> String {
+        let mut chars: Vec<char> = word.to_lowercase().chars().collect();
+        match self.language {
+            SnowballLanguage::Danish => danish::stem(&mut chars),
+            SnowballLanguage::Dutch => dutch::stem(&mut chars),
+            SnowballLanguage::French => french::stem(&mut chars),
+            SnowballLanguage::German => german::stem(&mut chars),
+            SnowballLanguage::Italian => italian::stem(&mut chars),
+            SnowballLanguage::Norwegian => norwegian::stem(&mut chars),
+            SnowballLanguage::Portuguese => portuguese::stem(&mut chars),
+            SnowballLanguage::Russian => russian::stem(&mut chars),
+            SnowballLanguage::Spanish => spanish::stem(&mut chars),
+            SnowballLanguage::Swedish => swedish::stem(&mut chars),
+        }
+        chars.into_iter().collect()
+    }
+
+    fn name(&self) -> &'static str {
+        "snowball"
+    }
+}
+
+/// Whether `w` ends with `suffix`.
+fn ends_with(w: &[char], suffix: &str) -> bool {
+    let n = suffix.chars().count();
+    n <= w.len() && w[w.len() - n..].iter().copied().eq(suffix.chars())
+}
+
+/// The position where `suffix` starts, given that `w` ends with it.
+fn start_of(w: &[char], suffix: &str) -> usize {
+    w.len() - suffix.chars().count()
+}
+
+/// The longest of `suffixes` that ends `w` and starts at or after `limit`.
+fn longest_suffix<'a>(w: &[char], limit: usize, suffixes: &[&'a str]) -> Option<&'a str> {
+    suffixes
+        .iter()
+        .copied()
+        .filter(|suffix| ends_with(w, suffix) && start_of(w, suffix) >= limit)
+        .max_by_key(|suffix| suffix.chars().count())
+}
+
+/// Replace `suffix`, which `w` ends with, by `replacement`.
+fn replace_suffix(w: &mut Vec<char>, suffix: &str, replacement: &str) {
+    w.truncate(start_of(w, suffix));
+    w.extend(replacement.chars());
+}
+
+/// Delete the longest of `suffixes` ending `w` if it starts at or after
+/// `region`. Shorter suffixes are not tried when the longest one is
+/// outside the region.
+fn delete_in(w: &mut Vec<char>, suffixes: &[&str], region: usize) -> bool {
+    match longest_suffix(w, 0, suffixes) {
+        Some(suffix) if start_of(w, suffix) >= region => {
+            w.truncate(start_of(w, suffix));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Delete each of `suffixes` in turn from the end of `w`, stopping at the
+/// first one that is missing or starts before `region`.
+fn delete_chain_in(w: &mut Vec<char>, suffixes: &[&str], region: usize) {
+    for suffix in suffixes {
+        if !delete_in(w, &[suffix], region) {
+            break;
+        }
+    }
+}
+
+/// The character before position `pos`, if it is at or after `limit`.
+fn char_before(w: &[char], pos: usize, limit: usize) -> Option<char> {
+    pos.checked_sub(1).filter(|&i| i >= limit).map(|i| w[i])
+}
+
+/// The start of the region after the first non-vowel that follows a vowel,
+/// looking at the characters from `from` on.
+fn region_after(w: &[char], from: usize, is_vowel: fn(char) -> bool) -> usize {
+    (from + 1..w.len())
+        .find(|&i| is_vowel(w[i - 1]) && !is_vowel(w[i]))
+        .map_or(w.len(), |i| i + 1)
+}
+
+/// The standard R1 and R2 regions.
+fn standard_regions(w: &[char], is_vowel: fn(char) -> bool) -> (usize, usize) {
+    let r1 = region_after(w, 0, is_vowel);
+    (r1, region_after(w, r1, is_vowel))
+}
+
+/// R1 and R2 for the Germanic languages, where R1 is adjusted so that at
+/// least three letters precede it.
+fn germanic_regions(w: &[char], is_vowel: fn(char) -> bool) -> (usize, usize) {
+    let (r1, r2) = standard_regions(w, is_vowel);
+    (r1.max(3).min(w.len()), r2)
+}
+
+/// The RV region of Spanish, Portuguese and Italian.
+///
+/// If the second letter is a consonant, RV is the region after the next
+/// vowel; if the first two letters are vowels, the region after the next
+/// consonant; otherwise the region after the third letter.
+fn romance_rv(w: &[char], is_vowel: fn(char) -> bool) -> usize {
+    let next_after = |want_vowel: bool| {
+        (2..w.len())
+            .find(|&i| is_vowel(w[i]) == want_vowel)
+            .map_or(w.len(), |i| i + 1)
+    };
+    if w.len() < 2 {
+        w.len()
+    } else if !is_vowel(w[1]) {
+        next_after(true)
+    } else if is_vowel(w[0]) {
+        next_after(false)
+    } else {
+        3.min(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stem(language: SnowballLanguage, word: &str) -> String {
+        SnowballStemmer::new(language).stem(word)
+    }
+
+    #[test]
+    fn test_regions() {
+        fn is_vowel(c: char) -> bool {
+            "aeiouy".contains(c)
+        }
+        let w: Vec<char> = "beautiful".chars().collect();
+        assert_eq!(standard_regions(&w, is_vowel), (5, 7));
+        let w: Vec<char> = "animadversion".chars().collect();
+        assert_eq!(standard_regions(&w, is_vowel), (2, 4));
+        let w: Vec<char> = "sprinkled".chars().collect();
+        assert_eq!(standard_regions(&w, is_vowel), (5, 9));
+    }
+
+    #[test]
+    fn test_language_names() {
+        for language in SnowballLanguage::ALL {
+            assert_eq!(
+                language.name().parse::<SnowballLanguage>().unwrap(),
+                language
+            );
+        }
+        assert!("klingon".parse::<SnowballLanguage>().is_err());
+    }
+
+    #[test]
+    fn test_danish() {
+        let cases = [
+            ("indtagelse", "indtag"),
+            ("indtager", "indtag"),
+            ("klokkerne", "klok"),
+            ("løbende", "løb"),
+            ("udsigtsløst", "udsigtsløs"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::Danish, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_dutch() {
+        let cases = [
+            ("lichamelijk", "licham"),
+            ("lichamelijke", "licham"),
+            ("opheffen", "opheff"),
+            ("ophouden", "ophoud"),
+            ("boeken", "boek"),
+            ("vrijheden", "vrijheid"),
+            ("maan", "man"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::Dutch, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_french() {
+        let cases = [
+            ("continuellement", "continuel"),
+            ("majestueusement", "majestu"),
+            ("nationalité", "national"),
+            ("chanteuses", "chanteux"),
+            ("finissaient", "fin"),
+            ("mangeait", "mang"),
+            ("généralement", "général"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::French, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_german() {
+        let cases = [
+            ("aufeinanderfolgenden", "aufeinanderfolg"),
+            ("häuser", "haus"),
+            ("kategorischen", "kategor"),
+            ("straße", "strass"),
+            ("zeitlich", "zeitlich"),
+            ("freundlichkeit", "freundlich"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::German, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_italian() {
+        let cases = [
+            ("abbandonata", "abbandon"),
+            ("abbandonate", "abbandon"),
+            ("abitudini", "abitudin"),
+            ("nazionale", "nazional"),
+            ("parlandogli", "parl"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::Italian, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_norwegian() {
+        let cases = [
+            ("havnedistriktene", "havnedistrikt"),
+            ("bilene", "bil"),
+            ("kjærlighet", "kjær"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::Norwegian, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_portuguese() {
+        let cases = [
+            ("quilométricas", "quilométr"),
+            ("nacionalidade", "nacional"),
+            ("informação", "inform"),
+            ("falavam", "fal"),
+            ("rapidamente", "rapid"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::Portuguese, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_russian() {
+        let cases = [
+            ("вечерний", "вечерн"),
+            ("книгами", "книг"),
+            ("читавшись", "чита"),
+            ("красивейший", "красив"),
+            ("ёлка", "елк"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::Russian, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_spanish() {
+        let cases = [
+            ("chicos", "chic"),
+            ("corriendo", "corr"),
+            ("nacionalidad", "nacional"),
+            ("rápidamente", "rapid"),
+            ("comiéndolo", "com"),
+            ("cantaban", "cant"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::Spanish, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_swedish() {
+        let cases = [
+            ("klokheten", "klok"),
+            ("flickorna", "flick"),
+            ("hopplöst", "hopplös"),
+            ("jaktkarlarne", "jaktkarl"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(SnowballLanguage::Swedish, word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_uppercase_input() {
+        assert_eq!(stem(SnowballLanguage::German, "Häuser"), "haus");
+    }
+}
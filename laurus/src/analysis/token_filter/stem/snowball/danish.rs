@@ -0,0 +1,61 @@
+//! Snowball stemmer for Danish.
+
+use super::{char_before, ends_with, germanic_regions, longest_suffix, replace_suffix, start_of};
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'æ' | 'å' | 'ø')
+}
+
+/// Letters that may precede a removable `s`.
+fn is_s_ending(c: char) -> bool {
+    "abcdfghjklmnoprtvyzå".contains(c)
+}
+
+const MAIN_SUFFIXES: &[&str] = &[
+    "hed", "ethed", "ered", "e", "erede", "ende", "erende", "ene", "erne", "ere", "en", "heden",
+    "eren", "er", "heder", "erer", "heds", "es", "endes", "erendes", "enes", "ernes", "eres",
+    "ens", "hedens", "erens", "ers", "ets", "erets", "et", "eret", "s",
+];
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    let (r1, _) = germanic_regions(w, is_vowel);
+
+    // Step 1
+    match longest_suffix(w, r1, MAIN_SUFFIXES) {
+        Some("s") if char_before(w, w.len() - 1, 0).is_some_and(is_s_ending) => {
+            w.pop();
+        }
+        Some("s") => {}
+        Some(suffix) => w.truncate(start_of(w, suffix)),
+        None => {}
+    }
+
+    // Step 2
+    consonant_pair(w, r1);
+
+    // Step 3
+    if ends_with(w, "igst") {
+        w.truncate(w.len() - 2);
+    }
+    match longest_suffix(w, r1, &["ig", "lig", "elig", "els", "løst"]) {
+        Some("løst") => replace_suffix(w, "løst", "løs"),
+        Some(suffix) => {
+            w.truncate(start_of(w, suffix));
+            consonant_pair(w, r1);
+        }
+        None => {}
+    }
+
+    // Step 4: undouble a final consonant in R1
+    let n = w.len();
+    if n >= 2 && n > r1 && !is_vowel(w[n - 1]) && w[n - 2] == w[n - 1] {
+        w.pop();
+    }
+}
+
+/// Remove the last letter of a final `gd`, `dt`, `gt` or `kt` in R1.
+fn consonant_pair(w: &mut Vec<char>, r1: usize) {
+    if longest_suffix(w, r1, &["gd", "dt", "gt", "kt"]).is_some() {
+        w.pop();
+    }
+}
@@ -0,0 +1,151 @@
+//! Snowball stemmer for Dutch.
+
+use super::{char_before, ends_with, germanic_regions, longest_suffix, replace_suffix, start_of};
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'è')
+}
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    prelude(w);
+    let (r1, r2) = germanic_regions(w, is_vowel);
+
+    // Step 1
+    match longest_suffix(w, 0, &["heden", "en", "ene", "s", "se"]) {
+        Some("heden") if start_of(w, "heden") >= r1 => replace_suffix(w, "heden", "heid"),
+        Some(suffix @ ("en" | "ene")) => {
+            en_ending(w, suffix, r1);
+        }
+        Some(suffix @ ("s" | "se"))
+            if start_of(w, suffix) >= r1
+                && char_before(w, start_of(w, suffix), 0)
+                    .is_some_and(|c| !is_vowel(c) && c != 'j') =>
+        {
+            w.truncate(start_of(w, suffix));
+        }
+        _ => {}
+    }
+
+    // Step 2
+    let e_found = e_ending(w, r1);
+
+    // Step 3a
+    if ends_with(w, "heid")
+        && start_of(w, "heid") >= r2
+        && char_before(w, start_of(w, "heid"), 0) != Some('c')
+    {
+        w.truncate(start_of(w, "heid"));
+        if ends_with(w, "en") {
+            en_ending(w, "en", r1);
+        }
+    }
+
+    // Step 3b
+    match longest_suffix(w, 0, &["end", "ing", "ig", "lijk", "baar", "bar"]) {
+        Some(suffix @ ("end" | "ing")) if start_of(w, suffix) >= r2 => {
+            w.truncate(start_of(w, suffix));
+            if ends_with(w, "ig")
+                && start_of(w, "ig") >= r2
+                && char_before(w, start_of(w, "ig"), 0) != Some('e')
+            {
+                w.truncate(start_of(w, "ig"));
+            } else {
+                undouble(w);
+            }
+        }
+        Some("ig")
+            if start_of(w, "ig") >= r2 && char_before(w, start_of(w, "ig"), 0) != Some('e') =>
+        {
+            w.truncate(start_of(w, "ig"));
+        }
+        Some("lijk") if start_of(w, "lijk") >= r2 => {
+            w.truncate(start_of(w, "lijk"));
+            e_ending(w, r1);
+        }
+        Some("baar") if start_of(w, "baar") >= r2 => w.truncate(start_of(w, "baar")),
+        Some("bar") if start_of(w, "bar") >= r2 && e_found => w.truncate(start_of(w, "bar")),
+        _ => {}
+    }
+
+    // Step 4: undouble a vowel in a final consonant-vowel-consonant group
+    let n = w.len();
+    if n >= 4
+        && !is_vowel(w[n - 1])
+        && w[n - 1] != 'I'
+        && matches!(w[n - 2], 'a' | 'e' | 'o' | 'u')
+        && w[n - 3] == w[n - 2]
+        && !is_vowel(w[n - 4])
+    {
+        w.remove(n - 2);
+    }
+
+    for c in w.iter_mut() {
+        *c = match *c {
+            'I' => 'i',
+            'Y' => 'y',
+            other => other,
+        };
+    }
+}
+
+/// Remove accents, and mark an initial `y`, `y` after a vowel and `i`
+/// between vowels as consonants.
+fn prelude(w: &mut [char]) {
+    for c in w.iter_mut() {
+        *c = match *c {
+            'ä' | 'á' => 'a',
+            'ë' | 'é' => 'e',
+            'ï' | 'í' => 'i',
+            'ö' | 'ó' => 'o',
+            'ü' | 'ú' => 'u',
+            other => other,
+        };
+    }
+    if w.first() == Some(&'y') {
+        w[0] = 'Y';
+    }
+    for i in 1..w.len() {
+        if !is_vowel(w[i - 1]) {
+            continue;
+        }
+        w[i] = match w[i] {
+            'i' if i + 1 < w.len() && is_vowel(w[i + 1]) => 'I',
+            'y' => 'Y',
+            other => other,
+        };
+    }
+}
+
+/// Remove `suffix` (`en` or `ene`) if it is in R1, preceded by a non-vowel
+/// and not preceded by `gem`.
+fn en_ending(w: &mut Vec<char>, suffix: &str, r1: usize) {
+    let start = start_of(w, suffix);
+    if start >= r1
+        && char_before(w, start, 0).is_some_and(|c| !is_vowel(c))
+        && !ends_with(&w[..start], "gem")
+    {
+        w.truncate(start);
+        undouble(w);
+    }
+}
+
+/// Remove a final `e` in R1 preceded by a non-vowel. Returns whether it was
+/// removed.
+fn e_ending(w: &mut Vec<char>, r1: usize) -> bool {
+    if !ends_with(w, "e") || w.len() - 1 < r1 {
+        return false;
+    }
+    if char_before(w, w.len() - 1, 0).is_none_or(is_vowel) {
+        return false;
+    }
+    w.pop();
+    undouble(w);
+    true
+}
+
+/// Remove the last letter of a final `kk`, `dd` or `tt`.
+fn undouble(w: &mut Vec<char>) {
+    if longest_suffix(w, 0, &["kk", "dd", "tt"]).is_some() {
+        w.pop();
+    }
+}
@@ -0,0 +1,311 @@
+//! Snowball stemmer for French.
+
+use super::{
+    char_before, delete_chain_in, delete_in, ends_with, longest_suffix, replace_suffix,
+    standard_regions, start_of,
+};
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'e'
+            | 'i'
+            | 'o'
+            | 'u'
+            | 'y'
+            | 'â'
+            | 'à'
+            | 'ë'
+            | 'é'
+            | 'ê'
+            | 'è'
+            | 'ï'
+            | 'î'
+            | 'ô'
+            | 'û'
+            | 'ù'
+    )
+}
+
+const STANDARD_SUFFIXES: &[&str] = &[
+    "ance",
+    "iqUe",
+    "isme",
+    "able",
+    "iste",
+    "eux",
+    "ances",
+    "iqUes",
+    "ismes",
+    "ables",
+    "istes",
+    "atrice",
+    "ateur",
+    "ation",
+    "atrices",
+    "ateurs",
+    "ations",
+    "logie",
+    "logies",
+    "usion",
+    "ution",
+    "usions",
+    "utions",
+    "ence",
+    "ences",
+    "ement",
+    "ements",
+    "ité",
+    "ités",
+    "if",
+    "ive",
+    "ifs",
+    "ives",
+    "eaux",
+    "aux",
+    "euse",
+    "euses",
+    "issement",
+    "issements",
+    "amment",
+    "emment",
+    "ment",
+    "ments",
+];
+
+const I_VERB_SUFFIXES: &[&str] = &[
+    "îmes", "ît", "îtes", "i", "ie", "ies", "ir", "ira", "irai", "iraIent", "irais", "irait",
+    "iras", "irent", "irez", "iriez", "irions", "irons", "iront", "is", "issaIent", "issais",
+    "issait", "issant", "issante", "issantes", "issants", "isse", "issent", "isses", "issez",
+    "issiez", "issions", "issons", "it",
+];
+
+const OTHER_VERB_SUFFIXES: &[&str] = &[
+    "ions", "é", "ée", "ées", "és", "èrent", "er", "era", "erai", "eraIent", "erais", "erait",
+    "eras", "erez", "eriez", "erions", "erons", "eront", "ez", "iez", "âmes", "ât", "âtes", "a",
+    "ai", "aIent", "ais", "ait", "ant", "ante", "antes", "ants", "as", "asse", "assent", "asses",
+    "assiez", "assions",
+];
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    prelude(w);
+    let rv = rv(w);
+    let (r1, r2) = standard_regions(w, is_vowel);
+
+    let changed = match standard_suffix(w, rv, r1, r2) {
+        Step1::Done => true,
+        Step1::Continue => i_verb_suffix(w, rv) || other_verb_suffix(w, rv, r2),
+    };
+    if changed {
+        if ends_with(w, "Y") {
+            replace_suffix(w, "Y", "i");
+        } else if ends_with(w, "ç") {
+            replace_suffix(w, "ç", "c");
+        }
+    } else {
+        residual_suffix(w, rv, r2);
+    }
+
+    if longest_suffix(w, 0, &["enn", "onn", "ett", "ell", "eill"]).is_some() {
+        w.pop();
+    }
+    un_accent(w);
+
+    for c in w.iter_mut() {
+        *c = c.to_ascii_lowercase();
+    }
+}
+
+/// Mark `u` and `i` between vowels, `y` next to a vowel, and `u` after `q`,
+/// as consonants by upper-casing them.
+fn prelude(w: &mut [char]) {
+    for i in 0..w.len() {
+        let prev_vowel = i > 0 && is_vowel(w[i - 1]);
+        let next_vowel = i + 1 < w.len() && is_vowel(w[i + 1]);
+        w[i] = match w[i] {
+            'u' | 'i' if prev_vowel && next_vowel => w[i].to_ascii_uppercase(),
+            'y' if prev_vowel || next_vowel => 'Y',
+            'u' if i > 0 && w[i - 1] == 'q' => 'U',
+            other => other,
+        };
+    }
+}
+
+/// The RV region: after the third letter if the word begins with two
+/// vowels or with `par`, `col` or `tap`, otherwise after the first vowel
+/// not at the beginning of the word.
+fn rv(w: &[char]) -> usize {
+    if w.len() >= 3
+        && ((is_vowel(w[0]) && is_vowel(w[1]))
+            || ["par", "col", "tap"]
+                .iter()
+                .any(|prefix| w[..3].iter().copied().eq(prefix.chars())))
+    {
+        return 3;
+    }
+    (1..w.len())
+        .find(|&i| is_vowel(w[i]))
+        .map_or(w.len(), |i| i + 1)
+}
+
+/// The outcome of step 1.
+enum Step1 {
+    /// A suffix was removed; the verb steps are skipped.
+    Done,
+    /// No suffix was removed, or the removal requires the verb steps to
+    /// run as well.
+    Continue,
+}
+
+/// Step 1: remove a standard suffix.
+fn standard_suffix(w: &mut Vec<char>, rv: usize, r1: usize, r2: usize) -> Step1 {
+    let Some(suffix) = longest_suffix(w, 0, STANDARD_SUFFIXES) else {
+        return Step1::Continue;
+    };
+    let start = start_of(w, suffix);
+    match suffix {
+        "ance" | "iqUe" | "isme" | "able" | "iste" | "eux" | "ances" | "iqUes" | "ismes"
+        | "ables" | "istes"
+            if start >= r2 =>
+        {
+            w.truncate(start);
+        }
+        "atrice" | "ateur" | "ation" | "atrices" | "ateurs" | "ations" if start >= r2 => {
+            w.truncate(start);
+            if ends_with(w, "ic") && !delete_in(w, &["ic"], r2) {
+                replace_suffix(w, "ic", "iqU");
+            }
+        }
+        "logie" | "logies" if start >= r2 => replace_suffix(w, suffix, "log"),
+        "usion" | "ution" | "usions" | "utions" if start >= r2 => replace_suffix(w, suffix, "u"),
+        "ence" | "ences" if start >= r2 => replace_suffix(w, suffix, "ent"),
+        "ement" | "ements" if start >= rv => {
+            w.truncate(start);
+            match longest_suffix(w, 0, &["iv", "eus", "abl", "iqU", "ièr", "Ièr"]) {
+                Some("iv") => delete_chain_in(w, &["iv", "at"], r2),
+                Some("eus") if !delete_in(w, &["eus"], r2) && start_of(w, "eus") >= r1 => {
+                    replace_suffix(w, "eus", "eux");
+                }
+                Some(preceding @ ("abl" | "iqU")) => {
+                    delete_in(w, &[preceding], r2);
+                }
+                Some(preceding @ ("ièr" | "Ièr")) if start_of(w, preceding) >= rv => {
+                    replace_suffix(w, preceding, "i");
+                }
+                _ => {}
+            }
+        }
+        "ité" | "ités" if start >= r2 => {
+            w.truncate(start);
+            match longest_suffix(w, 0, &["abil", "ic", "iv"]) {
+                Some("abil") if !delete_in(w, &["abil"], r2) => {
+                    replace_suffix(w, "abil", "abl");
+                }
+                Some("ic") if !delete_in(w, &["ic"], r2) => {
+                    replace_suffix(w, "ic", "iqU");
+                }
+                Some("iv") => {
+                    delete_in(w, &["iv"], r2);
+                }
+                _ => {}
+            }
+        }
+        "if" | "ive" | "ifs" | "ives" if start >= r2 => {
+            w.truncate(start);
+            if delete_in(w, &["at"], r2) && ends_with(w, "ic") && !delete_in(w, &["ic"], r2) {
+                replace_suffix(w, "ic", "iqU");
+            }
+        }
+        "eaux" => replace_suffix(w, suffix, "eau"),
+        "aux" if start >= r1 => replace_suffix(w, suffix, "al"),
+        "euse" | "euses" if start >= r2 => w.truncate(start),
+        "euse" | "euses" if start >= r1 => replace_suffix(w, suffix, "eux"),
+        "issement" | "issements"
+            if start >= r1 && char_before(w, start, 0).is_some_and(|c| !is_vowel(c)) =>
+        {
+            w.truncate(start);
+        }
+        "amment" if start >= rv => {
+            replace_suffix(w, suffix, "ant");
+            return Step1::Continue;
+        }
+        "emment" if start >= rv => {
+            replace_suffix(w, suffix, "ent");
+            return Step1::Continue;
+        }
+        "ment" | "ments" if char_before(w, start, rv).is_some_and(is_vowel) => {
+            w.truncate(start);
+            return Step1::Continue;
+        }
+        _ => return Step1::Continue,
+    }
+    Step1::Done
+}
+
+/// Step 2a: remove a verb suffix beginning with `i`, preceded by a
+/// non-vowel.
+fn i_verb_suffix(w: &mut Vec<char>, rv: usize) -> bool {
+    match longest_suffix(w, rv, I_VERB_SUFFIXES) {
+        Some(suffix) if char_before(w, start_of(w, suffix), rv).is_some_and(|c| !is_vowel(c)) => {
+            w.truncate(start_of(w, suffix));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Step 2b: remove any other verb suffix.
+fn other_verb_suffix(w: &mut Vec<char>, rv: usize, r2: usize) -> bool {
+    let Some(suffix) = longest_suffix(w, rv, OTHER_VERB_SUFFIXES) else {
+        return false;
+    };
+    let start = start_of(w, suffix);
+    match suffix {
+        "ions" if start < r2 => return false,
+        "âmes" | "ât" | "âtes" | "a" | "ai" | "aIent" | "ais" | "ait" | "ant" | "ante"
+        | "antes" | "ants" | "as" | "asse" | "assent" | "asses" | "assiez" | "assions" => {
+            w.truncate(start);
+            delete_in(w, &["e"], rv);
+        }
+        _ => w.truncate(start),
+    }
+    true
+}
+
+/// Step 4: remove a residual suffix.
+fn residual_suffix(w: &mut Vec<char>, rv: usize, r2: usize) {
+    if ends_with(w, "s") && char_before(w, w.len() - 1, 0).is_some_and(|c| !"aiouès".contains(c)) {
+        w.pop();
+    }
+    let Some(suffix) = longest_suffix(w, rv, &["ion", "ier", "ière", "Ier", "Ière", "e", "ë"])
+    else {
+        return;
+    };
+    let start = start_of(w, suffix);
+    match suffix {
+        "ion" => {
+            if start >= r2 && matches!(char_before(w, start, rv), Some('s' | 't')) {
+                w.truncate(start);
+            }
+        }
+        "ier" | "ière" | "Ier" | "Ière" => replace_suffix(w, suffix, "i"),
+        "e" => w.truncate(start),
+        _ => {
+            if start >= 2 && w[start - 2..start] == ['g', 'u'] && start - 2 >= rv {
+                w.truncate(start);
+            }
+        }
+    }
+}
+
+/// Step 6: replace `é` or `è` before a final run of non-vowels by `e`.
+fn un_accent(w: &mut [char]) {
+    let consonants = w.iter().rev().take_while(|&&c| !is_vowel(c)).count();
+    if consonants == 0 || consonants == w.len() {
+        return;
+    }
+    let i = w.len() - consonants - 1;
+    if matches!(w[i], 'é' | 'è') {
+        w[i] = 'e';
+    }
+}
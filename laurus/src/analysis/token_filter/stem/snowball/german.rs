@@ -0,0 +1,126 @@
+//! Snowball stemmer for German.
+
+use super::{char_before, ends_with, germanic_regions, longest_suffix, start_of};
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'ä' | 'ö' | 'ü')
+}
+
+/// Letters that may precede a removable `s`.
+fn is_s_ending(c: char) -> bool {
+    "bdfghklmnrt".contains(c)
+}
+
+/// Letters that may precede a removable `st`.
+fn is_st_ending(c: char) -> bool {
+    "bdfghklmnt".contains(c)
+}
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    prelude(w);
+    let (r1, r2) = germanic_regions(w, is_vowel);
+
+    // Step 1
+    match longest_suffix(w, 0, &["em", "ern", "er", "e", "en", "es", "s"]) {
+        Some(suffix @ ("e" | "en" | "es")) if start_of(w, suffix) >= r1 => {
+            w.truncate(start_of(w, suffix));
+            if ends_with(w, "niss") {
+                w.pop();
+            }
+        }
+        Some("s") if w.len() > r1 && char_before(w, w.len() - 1, 0).is_some_and(is_s_ending) => {
+            w.pop();
+        }
+        Some(suffix @ ("em" | "ern" | "er")) if start_of(w, suffix) >= r1 => {
+            w.truncate(start_of(w, suffix));
+        }
+        _ => {}
+    }
+
+    // Step 2
+    match longest_suffix(w, 0, &["en", "er", "est", "st"]) {
+        Some("st") => {
+            let start = start_of(w, "st");
+            if start >= r1 && start >= 4 && is_st_ending(w[start - 1]) {
+                w.truncate(start);
+            }
+        }
+        Some(suffix) if start_of(w, suffix) >= r1 => w.truncate(start_of(w, suffix)),
+        _ => {}
+    }
+
+    derivational_suffix(w, r1, r2);
+    postlude(w);
+}
+
+/// Step 3: remove a derivational suffix.
+fn derivational_suffix(w: &mut Vec<char>, r1: usize, r2: usize) {
+    let suffixes = ["end", "ung", "ig", "ik", "isch", "lich", "heit", "keit"];
+    let Some(suffix) = longest_suffix(w, 0, &suffixes) else {
+        return;
+    };
+    let start = start_of(w, suffix);
+    if start < r2 {
+        return;
+    }
+    match suffix {
+        "end" | "ung" => {
+            w.truncate(start);
+            if ends_with(w, "ig")
+                && w.len() - 2 >= r2
+                && char_before(w, w.len() - 2, 0) != Some('e')
+            {
+                w.truncate(w.len() - 2);
+            }
+        }
+        "ig" | "ik" | "isch" => {
+            if char_before(w, start, 0) != Some('e') {
+                w.truncate(start);
+            }
+        }
+        "lich" | "heit" => {
+            w.truncate(start);
+            if let Some(preceding) = longest_suffix(w, r1, &["er", "en"]) {
+                w.truncate(start_of(w, preceding));
+            }
+        }
+        _ => {
+            w.truncate(start);
+            if let Some(preceding) = longest_suffix(w, 0, &["lich", "ig"])
+                && start_of(w, preceding) >= r2
+            {
+                w.truncate(start_of(w, preceding));
+            }
+        }
+    }
+}
+
+/// Replace `ß` by `ss` and mark `u` and `y` between vowels as consonants.
+fn prelude(w: &mut Vec<char>) {
+    *w = w
+        .iter()
+        .flat_map(|&c| if c == 'ß' { vec!['s', 's'] } else { vec![c] })
+        .collect();
+    for i in 1..w.len().saturating_sub(1) {
+        if is_vowel(w[i - 1]) && is_vowel(w[i + 1]) {
+            w[i] = match w[i] {
+                'u' => 'U',
+                'y' => 'Y',
+                other => other,
+            };
+        }
+    }
+}
+
+/// Undo the prelude markers and remove umlauts.
+fn postlude(w: &mut [char]) {
+    for c in w.iter_mut() {
+        *c = match *c {
+            'U' | 'ü' => 'u',
+            'Y' => 'y',
+            'ä' => 'a',
+            'ö' => 'o',
+            other => other,
+        };
+    }
+}
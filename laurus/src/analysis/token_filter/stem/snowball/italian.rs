@@ -0,0 +1,143 @@
+//! Snowball stemmer for Italian.
+
+use super::{
+    char_before, delete_chain_in, delete_in, longest_suffix, replace_suffix, romance_rv,
+    standard_regions, start_of,
+};
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'à' | 'è' | 'ì' | 'ò' | 'ù')
+}
+
+const PRONOUNS: &[&str] = &[
+    "ci", "gli", "la", "le", "li", "lo", "mi", "ne", "si", "ti", "vi", "sene", "gliela", "gliele",
+    "glieli", "glielo", "gliene", "mela", "mele", "meli", "melo", "mene", "tela", "tele", "teli",
+    "telo", "tene", "cela", "cele", "celi", "celo", "cene", "vela", "vele", "veli", "velo", "vene",
+];
+
+const STANDARD_SUFFIXES: &[&str] = &[
+    "anza", "anze", "ico", "ici", "ica", "ice", "iche", "ichi", "ismo", "ismi", "abile", "abili",
+    "ibile", "ibili", "ista", "iste", "isti", "istà", "istè", "istì", "oso", "osi", "osa", "ose",
+    "mente", "atrice", "atrici", "ante", "anti", "azione", "azioni", "atore", "atori", "logia",
+    "logie", "uzione", "uzioni", "usione", "usioni", "enza", "enze", "amento", "amenti", "imento",
+    "imenti", "amente", "ità", "ivo", "ivi", "iva", "ive",
+];
+
+const VERB_SUFFIXES: &[&str] = &[
+    "ammo", "ando", "ano", "are", "arono", "asse", "assero", "assi", "assimo", "ata", "ate", "ati",
+    "ato", "ava", "avamo", "avano", "avate", "avi", "avo", "emmo", "enda", "ende", "endi", "endo",
+    "erà", "erai", "eranno", "ere", "erebbe", "erebbero", "erei", "eremmo", "eremo", "ereste",
+    "eresti", "erete", "erò", "erono", "essero", "ete", "eva", "evamo", "evano", "evate", "evi",
+    "evo", "iamo", "immo", "irà", "irai", "iranno", "ire", "irebbe", "irebbero", "irei", "iremmo",
+    "iremo", "ireste", "iresti", "irete", "irò", "irono", "isca", "iscano", "isce", "isci", "isco",
+    "iscono", "issero", "ita", "ite", "iti", "ito", "iva", "ivamo", "ivano", "ivate", "ivi", "ivo",
+    "ar", "ir",
+];
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    prelude(w);
+    let rv = romance_rv(w, is_vowel);
+    let (r1, r2) = standard_regions(w, is_vowel);
+
+    attached_pronoun(w, rv);
+    if !standard_suffix(w, r1, r2) {
+        delete_in(w, VERB_SUFFIXES, rv);
+    }
+    vowel_suffix(w, rv);
+
+    for c in w.iter_mut() {
+        *c = match *c {
+            'I' => 'i',
+            'U' => 'u',
+            other => other,
+        };
+    }
+}
+
+/// Replace acute accents by grave ones and mark `u` after `q`, and `u` or
+/// `i` between vowels, as consonants.
+fn prelude(w: &mut [char]) {
+    for i in 0..w.len() {
+        w[i] = match w[i] {
+            'á' => 'à',
+            'é' => 'è',
+            'í' => 'ì',
+            'ó' => 'ò',
+            'ú' => 'ù',
+            'u' if i > 0 && w[i - 1] == 'q' => 'U',
+            other => other,
+        };
+    }
+    for i in 1..w.len().saturating_sub(1) {
+        if is_vowel(w[i - 1]) && is_vowel(w[i + 1]) {
+            w[i] = match w[i] {
+                'u' => 'U',
+                'i' => 'I',
+                other => other,
+            };
+        }
+    }
+}
+
+/// Step 0: remove a pronoun attached to a gerund or infinitive.
+fn attached_pronoun(w: &mut Vec<char>, rv: usize) {
+    let Some(pronoun) = longest_suffix(w, 0, PRONOUNS) else {
+        return;
+    };
+    let end = start_of(w, pronoun);
+    match longest_suffix(&w[..end], rv, &["ando", "endo", "ar", "er", "ir"]) {
+        Some("ando" | "endo") => w.truncate(end),
+        Some(_) => replace_suffix(w, pronoun, "e"),
+        None => {}
+    }
+}
+
+/// Step 1: remove a standard suffix.
+fn standard_suffix(w: &mut Vec<char>, r1: usize, r2: usize) -> bool {
+    let Some(suffix) = longest_suffix(w, 0, STANDARD_SUFFIXES) else {
+        return false;
+    };
+    let start = start_of(w, suffix);
+    let region = if suffix == "amente" { r1 } else { r2 };
+    if start < region {
+        return false;
+    }
+    match suffix {
+        "logia" | "logie" => replace_suffix(w, suffix, "log"),
+        "uzione" | "uzioni" | "usione" | "usioni" => replace_suffix(w, suffix, "u"),
+        "enza" | "enze" => replace_suffix(w, suffix, "ente"),
+        _ => w.truncate(start),
+    }
+    match suffix {
+        "atrice" | "atrici" | "ante" | "anti" | "azione" | "azioni" | "atore" | "atori" => {
+            delete_in(w, &["ic"], r2);
+        }
+        "amente" => match longest_suffix(w, 0, &["iv", "os", "ic", "abil"]) {
+            Some("iv") => delete_chain_in(w, &["iv", "at"], r2),
+            Some(preceding) => {
+                delete_in(w, &[preceding], r2);
+            }
+            None => {}
+        },
+        "ità" => {
+            delete_in(w, &["abil", "ic", "iv"], r2);
+        }
+        "ivo" | "ivi" | "iva" | "ive" => delete_chain_in(w, &["at", "ic"], r2),
+        _ => {}
+    }
+    true
+}
+
+/// Step 3: remove a final vowel, a preceding `i`, and the `h` of a final
+/// `ch` or `gh`.
+fn vowel_suffix(w: &mut Vec<char>, rv: usize) {
+    if delete_in(w, &["a", "e", "i", "o", "à", "è", "ì", "ò"], rv) {
+        delete_in(w, &["i"], rv);
+    }
+    if let Some(&'h') = w.last() {
+        let h = w.len() - 1;
+        if matches!(char_before(w, h, rv), Some('c' | 'g')) {
+            w.pop();
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Snowball stemmer for Norwegian (Bokmål).
+
+use super::{char_before, germanic_regions, longest_suffix, replace_suffix, start_of};
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'æ' | 'å' | 'ø')
+}
+
+/// Letters that may precede a removable `s`.
+fn is_s_ending(c: char) -> bool {
+    "bcdfghjlmnoprtvyz".contains(c)
+}
+
+const MAIN_SUFFIXES: &[&str] = &[
+    "a", "e", "ede", "ande", "ende", "ane", "ene", "hetene", "en", "heten", "ar", "er", "heter",
+    "as", "es", "edes", "endes", "enes", "hetenes", "ens", "hetens", "ers", "ets", "et", "het",
+    "ast", "s", "erte", "ert",
+];
+
+const OTHER_SUFFIXES: &[&str] = &[
+    "leg", "eleg", "ig", "eig", "lig", "elig", "els", "lov", "elov", "slov", "hetslov",
+];
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    let (r1, _) = germanic_regions(w, is_vowel);
+
+    // Step 1
+    match longest_suffix(w, r1, MAIN_SUFFIXES) {
+        Some("s") => {
+            let s = w.len() - 1;
+            let removable = match char_before(w, s, 0) {
+                Some('k') => char_before(w, s - 1, 0).is_some_and(|c| !is_vowel(c)),
+                Some(c) => is_s_ending(c),
+                None => false,
+            };
+            if removable {
+                w.pop();
+            }
+        }
+        Some(suffix @ ("erte" | "ert")) => replace_suffix(w, suffix, "er"),
+        Some(suffix) => w.truncate(start_of(w, suffix)),
+        None => {}
+    }
+
+    // Step 2
+    if longest_suffix(w, r1, &["dt", "vt"]).is_some() {
+        w.pop();
+    }
+
+    // Step 3
+    if let Some(suffix) = longest_suffix(w, r1, OTHER_SUFFIXES) {
+        w.truncate(start_of(w, suffix));
+    }
+}
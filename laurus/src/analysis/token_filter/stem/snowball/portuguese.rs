@@ -0,0 +1,140 @@
+//! Snowball stemmer for Portuguese.
+//!
+//! The nasalised vowels `ã` and `õ` are rewritten as `a~` and `o~` while
+//! stemming, so that they are treated as a vowel followed by a consonant.
+
+use super::{
+    char_before, delete_chain_in, delete_in, ends_with, longest_suffix, replace_suffix, romance_rv,
+    standard_regions, start_of,
+};
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú' | 'â' | 'ê' | 'ô'
+    )
+}
+
+const STANDARD_SUFFIXES: &[&str] = &[
+    "eza", "ezas", "ico", "ica", "icos", "icas", "ismo", "ismos", "ável", "ível", "ista", "istas",
+    "oso", "osa", "osos", "osas", "amento", "amentos", "imento", "imentos", "adora", "ador",
+    "aça~o", "adoras", "adores", "aço~es", "ante", "antes", "ância", "logia", "logias", "uça~o",
+    "uço~es", "ência", "ências", "amente", "mente", "idade", "idades", "iva", "ivo", "ivas",
+    "ivos", "ira", "iras",
+];
+
+const VERB_SUFFIXES: &[&str] = &[
+    "ada", "ida", "ia", "aria", "eria", "iria", "ará", "ara", "erá", "era", "irá", "ava", "asse",
+    "esse", "isse", "aste", "este", "iste", "ei", "arei", "erei", "irei", "am", "iam", "ariam",
+    "eriam", "iriam", "aram", "eram", "iram", "avam", "em", "arem", "erem", "irem", "assem",
+    "essem", "issem", "ado", "ido", "ando", "endo", "indo", "ara~o", "era~o", "ira~o", "ar", "er",
+    "ir", "as", "adas", "idas", "ias", "arias", "erias", "irias", "arás", "aras", "erás", "eras",
+    "irás", "avas", "es", "ardes", "erdes", "irdes", "ares", "eres", "ires", "asses", "esses",
+    "isses", "astes", "estes", "istes", "is", "ais", "eis", "íeis", "aríeis", "eríeis", "iríeis",
+    "áreis", "areis", "éreis", "ereis", "íreis", "ireis", "ásseis", "ésseis", "ísseis", "áveis",
+    "ados", "idos", "ámos", "amos", "íamos", "aríamos", "eríamos", "iríamos", "áramos", "éramos",
+    "íramos", "ávamos", "emos", "aremos", "eremos", "iremos", "ássemos", "êssemos", "íssemos",
+    "imos", "armos", "ermos", "irmos", "eu", "iu", "ou", "ira", "iras",
+];
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    *w = w
+        .iter()
+        .flat_map(|&c| match c {
+            'ã' => vec!['a', '~'],
+            'õ' => vec!['o', '~'],
+            other => vec![other],
+        })
+        .collect();
+
+    let rv = romance_rv(w, is_vowel);
+    let (r1, r2) = standard_regions(w, is_vowel);
+
+    if standard_suffix(w, r1, r2, rv) || verb_suffix(w, rv) {
+        if ends_with(w, "ci") && w.len() - 2 >= rv {
+            w.pop();
+        }
+    } else {
+        residual_suffix(w, rv);
+    }
+    residual_form(w, rv);
+
+    let mut stemmed = Vec::with_capacity(w.len());
+    for &c in w.iter() {
+        match (stemmed.last().copied(), c) {
+            (Some('a'), '~') => *stemmed.last_mut().unwrap() = 'ã',
+            (Some('o'), '~') => *stemmed.last_mut().unwrap() = 'õ',
+            _ => stemmed.push(c),
+        }
+    }
+    *w = stemmed;
+}
+
+/// Step 1: remove a standard suffix.
+fn standard_suffix(w: &mut Vec<char>, r1: usize, r2: usize, rv: usize) -> bool {
+    let Some(suffix) = longest_suffix(w, 0, STANDARD_SUFFIXES) else {
+        return false;
+    };
+    let start = start_of(w, suffix);
+    match suffix {
+        "amente" if start >= r1 => {
+            w.truncate(start);
+            match longest_suffix(w, 0, &["iv", "os", "ic", "ad"]) {
+                Some("iv") => delete_chain_in(w, &["iv", "at"], r2),
+                Some(preceding) => {
+                    delete_in(w, &[preceding], r2);
+                }
+                None => {}
+            }
+        }
+        "ira" | "iras" if start >= rv && char_before(w, start, 0) == Some('e') => {
+            replace_suffix(w, suffix, "ir");
+        }
+        "amente" | "ira" | "iras" => return false,
+        _ if start < r2 => return false,
+        "logia" | "logias" => replace_suffix(w, suffix, "log"),
+        "uça~o" | "uço~es" => replace_suffix(w, suffix, "u"),
+        "ência" | "ências" => replace_suffix(w, suffix, "ente"),
+        "mente" => {
+            w.truncate(start);
+            delete_in(w, &["ante", "avel", "ível"], r2);
+        }
+        "idade" | "idades" => {
+            w.truncate(start);
+            delete_in(w, &["abil", "ic", "iv"], r2);
+        }
+        "iva" | "ivo" | "ivas" | "ivos" => {
+            w.truncate(start);
+            delete_in(w, &["at"], r2);
+        }
+        _ => w.truncate(start),
+    }
+    true
+}
+
+/// Step 2: remove a verb suffix.
+fn verb_suffix(w: &mut Vec<char>, rv: usize) -> bool {
+    match longest_suffix(w, rv, VERB_SUFFIXES) {
+        Some(suffix) => {
+            w.truncate(start_of(w, suffix));
+            true
+        }
+        None => false,
+    }
+}
+
+/// Step 4: remove a residual suffix.
+fn residual_suffix(w: &mut Vec<char>, rv: usize) {
+    delete_in(w, &["os", "a", "i", "o", "á", "í", "ó"], rv);
+}
+
+/// Step 5: remove a final `e` and normalise a final `ç`.
+fn residual_form(w: &mut Vec<char>, rv: usize) {
+    if delete_in(w, &["e", "é", "ê"], rv) {
+        if (ends_with(w, "gu") || ends_with(w, "ci")) && w.len() > rv {
+            w.pop();
+        }
+    } else if ends_with(w, "ç") {
+        replace_suffix(w, "ç", "c");
+    }
+}
@@ -0,0 +1,102 @@
+//! Snowball stemmer for Russian.
+//!
+//! All suffixes are removed from the RV region, which starts after the
+//! first vowel of the word.
+
+use super::{char_before, ends_with, longest_suffix, standard_regions, start_of};
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'а' | 'е' | 'и' | 'о' | 'у' | 'ы' | 'э' | 'ю' | 'я')
+}
+
+const PERFECTIVE_GERUND_SUFFIXES: &[&str] = &["ив", "ивши", "ившись", "ыв", "ывши", "ывшись"];
+
+const PERFECTIVE_GERUND_SUFFIXES_AFTER_A: &[&str] = &["в", "вши", "вшись"];
+
+const ADJECTIVE_SUFFIXES: &[&str] = &[
+    "ее", "ие", "ые", "ое", "ими", "ыми", "ей", "ий", "ый", "ой", "ем", "им", "ым", "ом", "его",
+    "ого", "ему", "ому", "их", "ых", "ую", "юю", "ая", "яя", "ою", "ею",
+];
+
+const PARTICIPLE_SUFFIXES: &[&str] = &["ивш", "ывш", "ующ"];
+
+const PARTICIPLE_SUFFIXES_AFTER_A: &[&str] = &["ем", "нн", "вш", "ющ", "щ"];
+
+const VERB_SUFFIXES: &[&str] = &[
+    "ила", "ыла", "ена", "ейте", "уйте", "ите", "или", "ыли", "ей", "уй", "ил", "ыл", "им", "ым",
+    "ен", "ило", "ыло", "ено", "ят", "ует", "уют", "ит", "ыт", "ены", "ить", "ыть", "ишь", "ую",
+    "ю",
+];
+
+const VERB_SUFFIXES_AFTER_A: &[&str] = &[
+    "ла", "на", "ете", "йте", "ли", "й", "л", "ем", "н", "ло", "но", "ет", "ют", "ны", "ть", "ешь",
+    "нно",
+];
+
+const NOUN_SUFFIXES: &[&str] = &[
+    "а", "ев", "ов", "ие", "ье", "е", "иями", "ями", "ами", "еи", "ии", "и", "ией", "ей", "ой",
+    "ий", "й", "иям", "ям", "ием", "ем", "ам", "ом", "о", "у", "ах", "иях", "ях", "ы", "ь", "ию",
+    "ью", "ю", "ия", "ья", "я",
+];
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    for c in w.iter_mut() {
+        if *c == 'ё' {
+            *c = 'е';
+        }
+    }
+    let rv = w
+        .iter()
+        .position(|&c| is_vowel(c))
+        .map_or(w.len(), |i| i + 1);
+    let (_, r2) = standard_regions(w, is_vowel);
+
+    // Step 1
+    if !remove(
+        w,
+        rv,
+        PERFECTIVE_GERUND_SUFFIXES,
+        PERFECTIVE_GERUND_SUFFIXES_AFTER_A,
+    ) {
+        remove(w, rv, &["ся", "сь"], &[]);
+        if remove(w, rv, ADJECTIVE_SUFFIXES, &[]) {
+            remove(w, rv, PARTICIPLE_SUFFIXES, PARTICIPLE_SUFFIXES_AFTER_A);
+        } else if !remove(w, rv, VERB_SUFFIXES, VERB_SUFFIXES_AFTER_A) {
+            remove(w, rv, NOUN_SUFFIXES, &[]);
+        }
+    }
+
+    // Step 2
+    remove(w, rv, &["и"], &[]);
+
+    // Step 3
+    if let Some(suffix) = longest_suffix(w, rv, &["ост", "ость"])
+        && start_of(w, suffix) >= r2
+    {
+        w.truncate(start_of(w, suffix));
+    }
+
+    // Step 4
+    let superlative = remove(w, rv, &["ейш", "ейше"], &[]);
+    if (ends_with(w, "нн") && w.len() - 2 >= rv)
+        || (!superlative && ends_with(w, "ь") && w.len() > rv)
+    {
+        w.pop();
+    }
+}
+
+/// Remove the longest suffix found in RV among `suffixes` and
+/// `after_a`, where the suffixes in `after_a` must be preceded by `а` or
+/// `я`.
+fn remove(w: &mut Vec<char>, rv: usize, suffixes: &[&str], after_a: &[&str]) -> bool {
+    let all: Vec<&str> = suffixes.iter().chain(after_a).copied().collect();
+    let Some(suffix) = longest_suffix(w, rv, &all) else {
+        return false;
+    };
+    let start = start_of(w, suffix);
+    if after_a.contains(&suffix) && !matches!(char_before(w, start, rv), Some('а' | 'я')) {
+        return false;
+    }
+    w.truncate(start);
+    true
+}
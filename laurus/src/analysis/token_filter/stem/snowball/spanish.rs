@@ -0,0 +1,173 @@
+//! Snowball stemmer for Spanish.
+
+use super::{
+    char_before, delete_chain_in, delete_in, ends_with, longest_suffix, replace_suffix, romance_rv,
+    standard_regions, start_of,
+};
+
+fn is_vowel(c: char) -> bool {
+    matches!(
+        c,
+        'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'í' | 'ó' | 'ú' | 'ü'
+    )
+}
+
+const PRONOUNS: &[&str] = &[
+    "me", "se", "sela", "selo", "selas", "selos", "la", "le", "lo", "las", "les", "los", "nos",
+];
+
+const PRONOUN_VERB_ENDINGS: &[&str] = &[
+    "iéndo", "ándo", "ár", "ér", "ír", "ando", "iendo", "ar", "er", "ir", "yendo",
+];
+
+const STANDARD_SUFFIXES: &[&str] = &[
+    "anza", "anzas", "ico", "ica", "icos", "icas", "ismo", "ismos", "able", "ables", "ible",
+    "ibles", "ista", "istas", "oso", "osa", "osos", "osas", "amiento", "amientos", "imiento",
+    "imientos", "adora", "ador", "ación", "adoras", "adores", "aciones", "ante", "antes", "ancia",
+    "ancias", "logía", "logías", "ución", "uciones", "encia", "encias", "amente", "mente", "idad",
+    "idades", "iva", "ivo", "ivas", "ivos",
+];
+
+const Y_VERB_SUFFIXES: &[&str] = &[
+    "ya", "ye", "yan", "yen", "yeron", "yendo", "yo", "yó", "yas", "yes", "yais", "yamos",
+];
+
+const VERB_SUFFIXES: &[&str] = &[
+    "en", "es", "éis", "emos", "arían", "arías", "arán", "arás", "aríais", "aría", "aréis",
+    "aríamos", "aremos", "ará", "aré", "erían", "erías", "erán", "erás", "eríais", "ería", "eréis",
+    "eríamos", "eremos", "erá", "eré", "irían", "irías", "irán", "irás", "iríais", "iría", "iréis",
+    "iríamos", "iremos", "irá", "iré", "aba", "ada", "ida", "ía", "ara", "iera", "ad", "ed", "id",
+    "ase", "iese", "aste", "iste", "an", "aban", "ían", "aran", "ieran", "asen", "iesen", "aron",
+    "ieron", "ado", "ido", "ando", "iendo", "ió", "ar", "er", "ir", "as", "abas", "adas", "idas",
+    "ías", "aras", "ieras", "ases", "ieses", "ís", "áis", "abais", "íais", "arais", "ierais",
+    "aseis", "ieseis", "asteis", "isteis", "ados", "idos", "amos", "ábamos", "íamos", "imos",
+    "áramos", "iéramos", "iésemos", "ásemos",
+];
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    let rv = romance_rv(w, is_vowel);
+    let (r1, r2) = standard_regions(w, is_vowel);
+
+    attached_pronoun(w, rv);
+    if !standard_suffix(w, r1, r2) && !y_verb_suffix(w, rv) {
+        verb_suffix(w, rv);
+    }
+    residual_suffix(w, rv);
+
+    for c in w.iter_mut() {
+        *c = match *c {
+            'á' => 'a',
+            'é' => 'e',
+            'í' => 'i',
+            'ó' => 'o',
+            'ú' => 'u',
+            other => other,
+        };
+    }
+}
+
+/// Step 0: remove a pronoun attached to a gerund or infinitive.
+fn attached_pronoun(w: &mut Vec<char>, rv: usize) {
+    let Some(pronoun) = longest_suffix(w, 0, PRONOUNS) else {
+        return;
+    };
+    let end = start_of(w, pronoun);
+    let Some(ending) = longest_suffix(&w[..end], 0, PRONOUN_VERB_ENDINGS) else {
+        return;
+    };
+    let ending_start = end - ending.chars().count();
+    if ending_start < rv {
+        return;
+    }
+    if ending == "yendo" && char_before(w, ending_start, 0) != Some('u') {
+        return;
+    }
+    w.truncate(end);
+    let unaccented = match ending {
+        "iéndo" => "iendo",
+        "ándo" => "ando",
+        "ár" => "ar",
+        "ér" => "er",
+        "ír" => "ir",
+        _ => return,
+    };
+    replace_suffix(w, ending, unaccented);
+}
+
+/// Step 1: remove a standard suffix.
+fn standard_suffix(w: &mut Vec<char>, r1: usize, r2: usize) -> bool {
+    let Some(suffix) = longest_suffix(w, 0, STANDARD_SUFFIXES) else {
+        return false;
+    };
+    let start = start_of(w, suffix);
+    let region = if suffix == "amente" { r1 } else { r2 };
+    if start < region {
+        return false;
+    }
+    match suffix {
+        "logía" | "logías" => replace_suffix(w, suffix, "log"),
+        "ución" | "uciones" => replace_suffix(w, suffix, "u"),
+        "encia" | "encias" => replace_suffix(w, suffix, "ente"),
+        _ => w.truncate(start),
+    }
+    match suffix {
+        "adora" | "ador" | "ación" | "adoras" | "adores" | "aciones" | "ante" | "antes"
+        | "ancia" | "ancias" => {
+            delete_in(w, &["ic"], r2);
+        }
+        "amente" => match longest_suffix(w, 0, &["iv", "os", "ic", "ad"]) {
+            Some("iv") => delete_chain_in(w, &["iv", "at"], r2),
+            Some(preceding) => {
+                delete_in(w, &[preceding], r2);
+            }
+            None => {}
+        },
+        "mente" => {
+            delete_in(w, &["ante", "able", "ible"], r2);
+        }
+        "idad" | "idades" => {
+            delete_in(w, &["abil", "ic", "iv"], r2);
+        }
+        "iva" | "ivo" | "ivas" | "ivos" => {
+            delete_in(w, &["at"], r2);
+        }
+        _ => {}
+    }
+    true
+}
+
+/// Step 2a: remove a verb suffix beginning with `y` and preceded by `u`.
+fn y_verb_suffix(w: &mut Vec<char>, rv: usize) -> bool {
+    match longest_suffix(w, rv, Y_VERB_SUFFIXES) {
+        Some(suffix) if char_before(w, start_of(w, suffix), 0) == Some('u') => {
+            w.truncate(start_of(w, suffix));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Step 2b: remove any other verb suffix.
+fn verb_suffix(w: &mut Vec<char>, rv: usize) {
+    let Some(suffix) = longest_suffix(w, rv, VERB_SUFFIXES) else {
+        return;
+    };
+    w.truncate(start_of(w, suffix));
+    if matches!(suffix, "en" | "es" | "éis" | "emos") && ends_with(w, "gu") {
+        w.pop();
+    }
+}
+
+/// Step 3: remove a residual suffix.
+fn residual_suffix(w: &mut Vec<char>, rv: usize) {
+    match longest_suffix(w, 0, &["os", "a", "o", "á", "í", "ó", "e", "é"]) {
+        Some(suffix @ ("e" | "é")) if start_of(w, suffix) >= rv => {
+            w.pop();
+            if ends_with(w, "gu") && w.len() > rv {
+                w.pop();
+            }
+        }
+        Some(suffix) if start_of(w, suffix) >= rv => w.truncate(start_of(w, suffix)),
+        _ => {}
+    }
+}
@@ -0,0 +1,45 @@
+//! Snowball stemmer for Swedish.
+
+use super::{char_before, germanic_regions, longest_suffix, replace_suffix, start_of};
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y' | 'ä' | 'å' | 'ö')
+}
+
+/// Letters that may precede a removable `s`.
+fn is_s_ending(c: char) -> bool {
+    "bcdfghjklmnoprtvy".contains(c)
+}
+
+const MAIN_SUFFIXES: &[&str] = &[
+    "a", "arna", "erna", "heterna", "orna", "ad", "e", "ade", "ande", "arne", "are", "aste", "en",
+    "anden", "aren", "heten", "ern", "ar", "er", "heter", "or", "as", "arnas", "ernas", "ornas",
+    "es", "ades", "andes", "ens", "arens", "hetens", "erns", "at", "andet", "het", "ast", "s",
+];
+
+pub(super) fn stem(w: &mut Vec<char>) {
+    let (r1, _) = germanic_regions(w, is_vowel);
+
+    // Step 1
+    match longest_suffix(w, r1, MAIN_SUFFIXES) {
+        Some("s") if char_before(w, w.len() - 1, 0).is_some_and(is_s_ending) => {
+            w.pop();
+        }
+        Some("s") => {}
+        Some(suffix) => w.truncate(start_of(w, suffix)),
+        None => {}
+    }
+
+    // Step 2
+    if longest_suffix(w, r1, &["dd", "gd", "nn", "dt", "gt", "kt", "tt"]).is_some() {
+        w.pop();
+    }
+
+    // Step 3
+    match longest_suffix(w, r1, &["lig", "ig", "els", "löst", "fullt"]) {
+        Some("löst") => replace_suffix(w, "löst", "lös"),
+        Some("fullt") => replace_suffix(w, "fullt", "full"),
+        Some(suffix) => w.truncate(start_of(w, suffix)),
+        None => {}
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! This module provides a filter that removes common words (stop words) that
 //! typically don't contribute to search relevance. Includes default stop word
-//! lists for English and Japanese, with support for custom word lists.
+//! lists for English, Japanese, Korean and Chinese, lists for European
+//! languages in [`european`], and support for custom word lists.
 //!
 //! # Examples
 //!
@@ -33,7 +34,16 @@ use std::sync::{Arc, LazyLock};
 
 use crate::analysis::token::{Token, TokenStream};
 use crate::analysis::token_filter::Filter;
-use crate::error::Result;
+use crate::error::{LaurusError, Result};
+
+pub mod european;
+
+use european::{
+    DEFAULT_DANISH_STOP_WORDS_SET, DEFAULT_DUTCH_STOP_WORDS_SET, DEFAULT_FRENCH_STOP_WORDS_SET,
+    DEFAULT_GERMAN_STOP_WORDS_SET, DEFAULT_ITALIAN_STOP_WORDS_SET,
+    DEFAULT_NORWEGIAN_STOP_WORDS_SET, DEFAULT_PORTUGUESE_STOP_WORDS_SET,
+    DEFAULT_RUSSIAN_STOP_WORDS_SET, DEFAULT_SPANISH_STOP_WORDS_SET, DEFAULT_SWEDISH_STOP_WORDS_SET,
+};
 
 /// Default English stop words list.
 ///
@@ -280,6 +290,73 @@ const DEFAULT_JAPANESE_STOP_WORDS: &[&str] = &[
     "ここ",
 ];
 
+/// Default Korean stop words list.
+///
+/// Common dependent nouns, pronouns, conjunctions and light verb stems.
+/// Particles and endings are removed by part-of-speech instead, see
+/// [`PartOfSpeechStopFilter`](crate::analysis::token_filter::part_of_speech::PartOfSpeechStopFilter).
+const DEFAULT_KOREAN_STOP_WORDS: &[&str] = &[
+    "것",
+    "수",
+    "등",
+    "들",
+    "및",
+    "때",
+    "중",
+    "거",
+    "데",
+    "바",
+    "뿐",
+    "줄",
+    "이",
+    "그",
+    "저",
+    "이것",
+    "그것",
+    "저것",
+    "여기",
+    "거기",
+    "저기",
+    "우리",
+    "저희",
+    "나",
+    "너",
+    "또",
+    "또한",
+    "그리고",
+    "그러나",
+    "하지만",
+    "그래서",
+    "그런데",
+    "더",
+    "좀",
+    "잘",
+    "안",
+    "못",
+    "위해",
+    "대한",
+    "통해",
+    "있",
+    "없",
+    "하",
+    "되",
+    "않",
+];
+
+/// Default Chinese stop words list.
+///
+/// Common function words, pronouns and conjunctions, plus punctuation
+/// (including the ASCII forms NFKC normalization maps full-width
+/// punctuation to).
+const DEFAULT_CHINESE_STOP_WORDS: &[&str] = &[
+    "的", "了", "是", "在", "和", "与", "及", "或", "也", "就", "都", "而", "着", "被", "把", "让",
+    "给", "对", "从", "向", "于", "以", "之", "其", "这", "那", "这个", "那个", "这些", "那些",
+    "一个", "我", "你", "他", "她", "它", "我们", "你们", "他们", "她们", "它们", "自己", "什么",
+    "哪", "谁", "吗", "呢", "吧", "啊", "呀", "哦", "又", "还", "很", "更", "最", "只", "但",
+    "但是", "而且", "因为", "所以", "如果", "虽然", "然后", "不", "没", "没有", "等", "。", "、",
+    "“", "”", "‘", "’", "《", "》", "【", "】", "…", "—", ",", ".", "!", "?", ";", ":", "(", ")",
+];
+
 /// Default English stop words as a HashSet.
 pub static DEFAULT_ENGLISH_STOP_WORDS_SET: LazyLock<HashSet<String>> = LazyLock::new(|| {
     DEFAULT_ENGLISH_STOP_WORDS
@@ -296,6 +373,63 @@ pub static DEFAULT_JAPANESE_STOP_WORDS_SET: LazyLock<HashSet<String>> = LazyLock
         .collect()
 });
 
+/// Default Korean stop words as a HashSet.
+pub static DEFAULT_KOREAN_STOP_WORDS_SET: LazyLock<HashSet<String>> = LazyLock::new(|| {
+    DEFAULT_KOREAN_STOP_WORDS
+        .iter()
+        .map(|&s| s.to_string())
+        .collect()
+});
+
+/// Default Chinese stop words as a HashSet.
+pub static DEFAULT_CHINESE_STOP_WORDS_SET: LazyLock<HashSet<String>> = LazyLock::new(|| {
+    DEFAULT_CHINESE_STOP_WORDS
+        .iter()
+        .map(|&s| s.to_string())
+        .collect()
+});
+
+/// Languages with a default stop word list, by lowercase English name.
+pub const STOP_WORD_LANGUAGES: &[&str] = &[
+    "chinese",
+    "danish",
+    "dutch",
+    "english",
+    "french",
+    "german",
+    "italian",
+    "japanese",
+    "korean",
+    "norwegian",
+    "portuguese",
+    "russian",
+    "spanish",
+    "swedish",
+];
+
+/// Get the default stop words for a language by its lowercase English
+/// name, e.g. `"french"`. Returns `None` for languages without a list.
+pub fn default_stop_words(language: &str) -> Option<&'static HashSet<String>> {
+    let set: &'static LazyLock<HashSet<String>> = match language {
+        "chinese" => &DEFAULT_CHINESE_STOP_WORDS_SET,
+        "danish" => &DEFAULT_DANISH_STOP_WORDS_SET,
+        "dutch" => &DEFAULT_DUTCH_STOP_WORDS_SET,
+        "english" => &DEFAULT_ENGLISH_STOP_WORDS_SET,
+        "french" => &DEFAULT_FRENCH_STOP_WORDS_SET,
+        "german" => &DEFAULT_GERMAN_STOP_WORDS_SET,
+        "italian" => &DEFAULT_ITALIAN_STOP_WORDS_SET,
+        "japanese" => &DEFAULT_JAPANESE_STOP_WORDS_SET,
+        "korean" => &DEFAULT_KOREAN_STOP_WORDS_SET,
+        "norwegian" => &DEFAULT_NORWEGIAN_STOP_WORDS_SET,
+        "portuguese" => &DEFAULT_PORTUGUESE_STOP_WORDS_SET,
+        "russian" => &DEFAULT_RUSSIAN_STOP_WORDS_SET,
+        "spanish" => &DEFAULT_SPANISH_STOP_WORDS_SET,
+        "swedish" => &DEFAULT_SWEDISH_STOP_WORDS_SET,
+        _ => return None,
+    };
+    Some(set)
+}
+
 /// A filter that removes stop words from the token stream.
 ///
 /// Stop words are common words (like "the", "is", "at") that are often
@@ -307,6 +441,10 @@ pub static DEFAULT_JAPANESE_STOP_WORDS_SET: LazyLock<HashSet<String>> = LazyLock
 ///
 /// - English: 33 common words (articles, prepositions, conjunctions)
 /// - Japanese: 127 common particles and auxiliary verbs
+/// - Korean: 45 common dependent nouns, pronouns and conjunctions
+/// - Chinese: 89 common function words and punctuation marks
+/// - Danish, Dutch, French, German, Italian, Norwegian, Portuguese, Russian,
+///   Spanish and Swedish: the Snowball stop word lists, see [`european`]
 ///
 /// # Examples
 ///
@@ -410,6 +548,29 @@ impl StopFilter {
         }
     }
 
+    /// Create a new stop filter with the default stop words for a language.
+    ///
+    /// See [`STOP_WORD_LANGUAGES`] for the supported names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use laurus::analysis::token_filter::stop::StopFilter;
+    ///
+    /// let filter = StopFilter::for_language("german").unwrap();
+    /// assert!(filter.is_stop_word("und"));
+    /// assert!(StopFilter::for_language("klingon").is_err());
+    /// ```
+    pub fn for_language(language: &str) -> Result<Self> {
+        default_stop_words(language)
+            .map(|words| Self::with_stop_words(words.clone()))
+            .ok_or_else(|| {
+                LaurusError::invalid_argument(format!(
+                    "No default stop words for language: {language}"
+                ))
+            })
+    }
+
     /// Create a new stop filter from a list of stop words.
     ///
     /// # Arguments
@@ -554,6 +715,25 @@ mod tests {
         assert!(!result[2].is_stopped());
     }
 
+    #[test]
+    fn test_stop_filter_for_language() {
+        for language in STOP_WORD_LANGUAGES {
+            let filter = StopFilter::for_language(language).unwrap();
+            assert!(!filter.is_empty(), "{language}");
+        }
+        assert!(
+            StopFilter::for_language("french")
+                .unwrap()
+                .is_stop_word("avec")
+        );
+        assert!(
+            StopFilter::for_language("russian")
+                .unwrap()
+                .is_stop_word("между")
+        );
+        assert!(StopFilter::for_language("klingon").is_err());
+    }
+
     #[test]
     fn test_filter_name() {
         assert_eq!(StopFilter::new().name(), "stop");
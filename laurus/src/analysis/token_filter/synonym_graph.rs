@@ -114,24 +114,56 @@ impl SynonymGraphFilter {
 impl Filter for SynonymGraphFilter {
     fn filter(&self, tokens: TokenStream) -> Result<TokenStream> {
         let input_tokens: Vec<Token> = tokens.collect();
-        let mut output_tokens = Vec::new();
+        // Output tokens paired with the graph node they leave from.
+        let mut output_tokens: Vec<(usize, Token)> = Vec::new();
+        // The node the next input position maps to.
+        let mut next_node = 0;
         let mut i = 0;
 
         while i < input_tokens.len() {
+            let increment = input_tokens[i].position_increment;
+            let start_node = match output_tokens.last() {
+                Some((node, _)) if increment == 0 => *node,
+                _ => next_node + increment.saturating_sub(1),
+            };
+
             // Try to match a synonym at the current position
             if let Some((_, match_length, synonyms)) = self.try_match_synonym(&input_tokens, i) {
                 // Build and add graph tokens
                 let graph_tokens =
                     self.build_graph_tokens(&input_tokens, i, match_length, &synonyms);
-                output_tokens.extend(graph_tokens);
+                let mut node = start_node;
+                next_node = start_node + 1;
+                for (j, token) in graph_tokens.into_iter().enumerate() {
+                    if j > 0 {
+                        node += token.position_increment;
+                    }
+                    next_node = next_node.max(node + token.position_length);
+                    output_tokens.push((node, token));
+                }
                 i += match_length;
             } else {
                 // No match, add the original token
-                output_tokens.push(input_tokens[i].clone());
+                output_tokens.push((start_node, input_tokens[i].clone()));
+                next_node = next_node.max(start_node + 1);
                 i += 1;
             }
         }
 
+        // Re-derive the increments from the node each token leaves from.
+        let mut previous: Option<usize> = None;
+        let output_tokens: Vec<Token> = output_tokens
+            .into_iter()
+            .map(|(node, mut token)| {
+                token.position_increment = match previous {
+                    None => node + 1,
+                    Some(prev) => node - prev,
+                };
+                previous = Some(node);
+                token
+            })
+            .collect();
+
         Ok(Box::new(output_tokens.into_iter()))
     }
 
@@ -740,8 +772,8 @@ mod tests {
         assert_eq!(machine.position, 0);
         assert_eq!(machine.position_increment, 0);
         assert_eq!(
-            machine.position_length, 2,
-            "First token of multi-word synonym should have pos_len=2"
+            machine.position_length, 1,
+            "First token of multi-word synonym should lead to its second token"
         );
 
         // Verify "learning" token (second token of multi-word synonym)
@@ -759,8 +791,8 @@ mod tests {
         assert_eq!(artificial.position, 2);
         assert_eq!(artificial.position_increment, 0);
         assert_eq!(
-            artificial.position_length, 2,
-            "First token of multi-word synonym should have pos_len=2"
+            artificial.position_length, 1,
+            "First token of multi-word synonym should lead to its second token"
         );
 
         // Verify "intelligence" token (second token of multi-word synonym)
@@ -851,8 +883,8 @@ mod tests {
         assert_eq!(kikai.position, 0);
         assert_eq!(kikai.position_increment, 0);
         assert_eq!(
-            kikai.position_length, 2,
-            "First token of Japanese multi-word synonym should have pos_len=2"
+            kikai.position_length, 1,
+            "First token of Japanese multi-word synonym should lead to its second token"
         );
 
         // Verify "学習" token (second token of Japanese multi-word synonym)
@@ -870,8 +902,8 @@ mod tests {
         assert_eq!(jinko.position, 2);
         assert_eq!(jinko.position_increment, 0);
         assert_eq!(
-            jinko.position_length, 2,
-            "First token of Japanese multi-word synonym should have pos_len=2"
+            jinko.position_length, 1,
+            "First token of Japanese multi-word synonym should lead to its second token"
         );
 
         // Verify "知能" token (second token of Japanese multi-word synonym)
@@ -920,4 +952,41 @@ mod tests {
         // Second token: 0.8 * 0.8 = 0.64
         assert!((learning_token.unwrap().boost - 0.64).abs() < 0.001);
     }
+
+    #[test]
+    fn test_synonym_graph_filter_positions_after_match() {
+        let dict =
+            SynonymDictionary::from_solr_rules("ml, machine learning\nny => new york").unwrap();
+        let filter = SynonymGraphFilter::new(dict, true);
+
+        let tokens = vec![
+            Token::new("ml", 0),
+            Token::new("in", 1),
+            Token::new("ny", 2),
+        ];
+
+        let result: Vec<(String, usize, usize)> = filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .map(|t| (t.text, t.position_increment, t.position_length))
+            .collect();
+
+        // "ml" spans the two-token side path, so "in" follows its end node,
+        // and the one-way rule replaces "ny".
+        let expected = [
+            ("ml", 1, 2),
+            ("machine", 0, 1),
+            ("learning", 1, 1),
+            ("in", 1, 1),
+            ("new", 1, 1),
+            ("york", 1, 1),
+        ];
+        assert_eq!(
+            result,
+            expected
+                .iter()
+                .map(|(text, inc, len)| (text.to_string(), *inc, *len))
+                .collect::<Vec<_>>()
+        );
+    }
 }
@@ -0,0 +1,670 @@
+//! Word delimiter graph filter implementation.
+//!
+//! This module provides a filter that splits tokens such as
+//! "PowerShot-SD500", "WiFi", or "iPhone15Pro" into their parts: at
+//! delimiters (any character that is neither a letter nor a digit), at
+//! case changes, and at transitions between letters and digits. It can
+//! also emit catenated parts ("WiFi" from "Wi-Fi") and the original token,
+//! so that queries match whichever form users type.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::token_filter::Filter;
+//! use laurus::analysis::token_filter::word_delimiter_graph::WordDelimiterGraphFilter;
+//! use laurus::analysis::token::Token;
+//!
+//! let filter = WordDelimiterGraphFilter::new();
+//! let tokens = vec![Token::new("PowerShot-SD500", 0), Token::new("iPhone15Pro", 1)];
+//!
+//! let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+//!     .unwrap()
+//!     .map(|t| t.text)
+//!     .collect();
+//!
+//! assert_eq!(
+//!     result,
+//!     vec!["Power", "Shot", "SD", "500", "i", "Phone", "15", "Pro"]
+//! );
+//! ```
+
+use std::cmp::Reverse;
+
+use crate::analysis::token::TokenStream;
+use crate::analysis::token_filter::Filter;
+use crate::error::Result;
+
+/// Character classes that decide where a token is split.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CharClass {
+    Lower,
+    Upper,
+    /// A letter without case, e.g. from CJK scripts
+    Letter,
+    Digit,
+    Delimiter,
+}
+
+impl CharClass {
+    fn of(c: char) -> Self {
+        if c.is_lowercase() {
+            CharClass::Lower
+        } else if c.is_uppercase() {
+            CharClass::Upper
+        } else if c.is_alphabetic() {
+            CharClass::Letter
+        } else if c.is_numeric() {
+            CharClass::Digit
+        } else {
+            CharClass::Delimiter
+        }
+    }
+}
+
+/// A part of a token, as a byte range of its text.
+#[derive(Clone, Copy, Debug)]
+struct Part {
+    start: usize,
+    end: usize,
+    numeric: bool,
+}
+
+/// A token to be emitted, spanning parts `from..to`.
+#[derive(Debug)]
+struct Piece {
+    from: usize,
+    to: usize,
+    text: String,
+    /// Orders pieces with the same span: the original first, then
+    /// catenations, then single parts.
+    rank: u8,
+}
+
+/// A filter that splits tokens into subwords and emits a token graph.
+///
+/// By default the filter replaces each token by its word and number
+/// parts. Catenations of consecutive word parts (`catenate_words`),
+/// number parts (`catenate_numbers`), or all parts (`catenate_all`), and
+/// the original token (`preserve_original`) are stacked at the position
+/// of their first part, with a position length equal to the number of
+/// positions they cover. As with shingles and multi-word synonyms, query
+/// parsing treats them as alternatives to the parts they cover, and
+/// indexing flattens the graph with
+/// [`FlattenGraphFilter`](super::flatten_graph::FlattenGraphFilter).
+///
+/// Case changes split "PowerShot" into "Power" and "Shot", and an
+/// uppercase run followed by a capitalized word is split before the last
+/// capital ("SDCard" into "SD" and "Card"). The filter needs the original
+/// case, so it should run before lowercasing. Tokens that need no
+/// splitting and stopped tokens are passed through unchanged, and tokens
+/// made only of delimiters are removed unless `preserve_original` is set.
+///
+/// Tokens stacked on a split token (position increment 0) are placed on
+/// its last part, so the filter should run before filters that produce
+/// graphs, such as synonym expansion.
+///
+/// # Examples
+///
+/// ```
+/// use laurus::analysis::token_filter::Filter;
+/// use laurus::analysis::token_filter::word_delimiter_graph::WordDelimiterGraphFilter;
+/// use laurus::analysis::token::Token;
+///
+/// let filter = WordDelimiterGraphFilter::new()
+///     .with_catenate_words(true)
+///     .with_preserve_original(true);
+/// let tokens = vec![Token::new("Wi-Fi", 0)];
+///
+/// let result: Vec<_> = filter.filter(Box::new(tokens.into_iter()))
+///     .unwrap()
+///     .collect();
+///
+/// let texts: Vec<_> = result.iter().map(|t| t.text.as_str()).collect();
+/// assert_eq!(texts, vec!["Wi-Fi", "WiFi", "Wi", "Fi"]);
+/// assert_eq!(result[0].position_length, 2);
+/// assert_eq!(result[3].position_increment, 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct WordDelimiterGraphFilter {
+    /// Whether word parts are emitted
+    generate_word_parts: bool,
+    /// Whether number parts are emitted
+    generate_number_parts: bool,
+    /// Whether tokens are split at case changes
+    split_on_case_change: bool,
+    /// Whether tokens are split between letters and digits
+    split_on_numerics: bool,
+    /// Whether runs of word parts are catenated
+    catenate_words: bool,
+    /// Whether runs of number parts are catenated
+    catenate_numbers: bool,
+    /// Whether all parts are catenated
+    catenate_all: bool,
+    /// Whether the original token is kept
+    preserve_original: bool,
+}
+
+impl Default for WordDelimiterGraphFilter {
+    fn default() -> Self {
+        Self {
+            generate_word_parts: true,
+            generate_number_parts: true,
+            split_on_case_change: true,
+            split_on_numerics: true,
+            catenate_words: false,
+            catenate_numbers: false,
+            catenate_all: false,
+            preserve_original: false,
+        }
+    }
+}
+
+impl WordDelimiterGraphFilter {
+    /// Create a new word delimiter graph filter that emits word and number
+    /// parts, splitting at delimiters, case changes, and letter/digit
+    /// transitions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether word parts are emitted.
+    pub fn with_generate_word_parts(mut self, generate_word_parts: bool) -> Self {
+        self.generate_word_parts = generate_word_parts;
+        self
+    }
+
+    /// Set whether number parts are emitted.
+    pub fn with_generate_number_parts(mut self, generate_number_parts: bool) -> Self {
+        self.generate_number_parts = generate_number_parts;
+        self
+    }
+
+    /// Set whether tokens are split at case changes, e.g. "WiFi" into
+    /// "Wi" and "Fi".
+    pub fn with_split_on_case_change(mut self, split_on_case_change: bool) -> Self {
+        self.split_on_case_change = split_on_case_change;
+        self
+    }
+
+    /// Set whether tokens are split between letters and digits, e.g.
+    /// "SD500" into "SD" and "500".
+    pub fn with_split_on_numerics(mut self, split_on_numerics: bool) -> Self {
+        self.split_on_numerics = split_on_numerics;
+        self
+    }
+
+    /// Set whether runs of consecutive word parts are catenated, e.g.
+    /// "PowerShot" from "Power-Shot".
+    pub fn with_catenate_words(mut self, catenate_words: bool) -> Self {
+        self.catenate_words = catenate_words;
+        self
+    }
+
+    /// Set whether runs of consecutive number parts are catenated, e.g.
+    /// "5001234" from "500-1234".
+    pub fn with_catenate_numbers(mut self, catenate_numbers: bool) -> Self {
+        self.catenate_numbers = catenate_numbers;
+        self
+    }
+
+    /// Set whether all parts are catenated, e.g. "SD500" from "SD-500".
+    pub fn with_catenate_all(mut self, catenate_all: bool) -> Self {
+        self.catenate_all = catenate_all;
+        self
+    }
+
+    /// Set whether the original token is kept next to its parts.
+    pub fn with_preserve_original(mut self, preserve_original: bool) -> Self {
+        self.preserve_original = preserve_original;
+        self
+    }
+
+    /// Check whether word parts are emitted.
+    pub fn generate_word_parts(&self) -> bool {
+        self.generate_word_parts
+    }
+
+    /// Check whether number parts are emitted.
+    pub fn generate_number_parts(&self) -> bool {
+        self.generate_number_parts
+    }
+
+    /// Check whether tokens are split at case changes.
+    pub fn split_on_case_change(&self) -> bool {
+        self.split_on_case_change
+    }
+
+    /// Check whether tokens are split between letters and digits.
+    pub fn split_on_numerics(&self) -> bool {
+        self.split_on_numerics
+    }
+
+    /// Check whether runs of word parts are catenated.
+    pub fn catenate_words(&self) -> bool {
+        self.catenate_words
+    }
+
+    /// Check whether runs of number parts are catenated.
+    pub fn catenate_numbers(&self) -> bool {
+        self.catenate_numbers
+    }
+
+    /// Check whether all parts are catenated.
+    pub fn catenate_all(&self) -> bool {
+        self.catenate_all
+    }
+
+    /// Check whether the original token is kept.
+    pub fn preserve_original(&self) -> bool {
+        self.preserve_original
+    }
+
+    /// Whether a new part starts at `current`, which follows `previous`
+    /// and precedes `next` (all non-delimiters except `next`).
+    fn is_break(&self, previous: CharClass, current: CharClass, next: CharClass) -> bool {
+        let digit_change = (previous == CharClass::Digit) != (current == CharClass::Digit);
+        if digit_change {
+            return self.split_on_numerics;
+        }
+        self.split_on_case_change
+            && current == CharClass::Upper
+            && (previous == CharClass::Lower
+                || (previous == CharClass::Upper && next == CharClass::Lower))
+    }
+
+    /// Split `text` into parts.
+    fn split(&self, text: &str) -> Vec<Part> {
+        let chars: Vec<(usize, CharClass)> = text
+            .char_indices()
+            .map(|(i, c)| (i, CharClass::of(c)))
+            .collect();
+        let mut parts = Vec::new();
+        let mut start: Option<usize> = None;
+
+        for (n, &(offset, class)) in chars.iter().enumerate() {
+            if class == CharClass::Delimiter {
+                if let Some(start) = start.take() {
+                    parts.push(Self::part(text, start, offset));
+                }
+                continue;
+            }
+            match start {
+                None => start = Some(offset),
+                Some(part_start) => {
+                    let previous = chars[n - 1].1;
+                    let next = chars.get(n + 1).map_or(CharClass::Delimiter, |c| c.1);
+                    if self.is_break(previous, class, next) {
+                        parts.push(Self::part(text, part_start, offset));
+                        start = Some(offset);
+                    }
+                }
+            }
+        }
+        if let Some(start) = start {
+            parts.push(Self::part(text, start, text.len()));
+        }
+        parts
+    }
+
+    fn part(text: &str, start: usize, end: usize) -> Part {
+        let numeric = text[start..end].chars().all(char::is_numeric);
+        Part {
+            start,
+            end,
+            numeric,
+        }
+    }
+
+    fn generates(&self, numeric: bool) -> bool {
+        if numeric {
+            self.generate_number_parts
+        } else {
+            self.generate_word_parts
+        }
+    }
+
+    fn catenates(&self, numeric: bool) -> bool {
+        if numeric {
+            self.catenate_numbers
+        } else {
+            self.catenate_words
+        }
+    }
+
+    /// The tokens to emit for a token whose text splits into `parts`.
+    fn pieces(&self, text: &str, parts: &[Part]) -> Vec<Piece> {
+        let catenate = |from: usize, to: usize| -> String {
+            parts[from..to]
+                .iter()
+                .map(|part| &text[part.start..part.end])
+                .collect()
+        };
+        let mut pieces = Vec::new();
+
+        if self.preserve_original {
+            pieces.push(Piece {
+                from: 0,
+                to: parts.len(),
+                text: text.to_string(),
+                rank: 0,
+            });
+        }
+        if self.catenate_all && parts.len() > 1 {
+            pieces.push(Piece {
+                from: 0,
+                to: parts.len(),
+                text: catenate(0, parts.len()),
+                rank: 1,
+            });
+        }
+
+        // Runs of consecutive parts of the same kind. A run of a single
+        // part is catenated only when the part itself is not emitted.
+        let mut run_start = 0;
+        for i in 1..=parts.len() {
+            if i < parts.len() && parts[i].numeric == parts[run_start].numeric {
+                continue;
+            }
+            let numeric = parts[run_start].numeric;
+            if self.catenates(numeric) && (i - run_start > 1 || !self.generates(numeric)) {
+                pieces.push(Piece {
+                    from: run_start,
+                    to: i,
+                    text: catenate(run_start, i),
+                    rank: 2,
+                });
+            }
+            run_start = i;
+        }
+
+        for (i, part) in parts.iter().enumerate() {
+            if self.generates(part.numeric) {
+                pieces.push(Piece {
+                    from: i,
+                    to: i + 1,
+                    text: text[part.start..part.end].to_string(),
+                    rank: 3,
+                });
+            }
+        }
+
+        pieces.sort_by_key(|piece| (piece.from, Reverse(piece.to), piece.rank));
+        pieces.dedup_by(|a, b| a.from == b.from && a.to == b.to && a.text == b.text);
+        pieces
+    }
+}
+
+impl Filter for WordDelimiterGraphFilter {
+    fn filter(&self, tokens: TokenStream) -> Result<TokenStream> {
+        let mut output = Vec::new();
+        // Increment carried over from tokens that emitted nothing.
+        let mut pending_increment = 0;
+
+        for token in tokens {
+            pending_increment += token.position_increment;
+            if token.is_stopped() {
+                output.push(token.with_position_increment(std::mem::take(&mut pending_increment)));
+                continue;
+            }
+
+            let parts = self.split(&token.text);
+            let unchanged = match parts.as_slice() {
+                [part] => part.start == 0 && part.end == token.text.len(),
+                [] => self.preserve_original,
+                _ => false,
+            };
+            if unchanged {
+                output.push(token.with_position_increment(std::mem::take(&mut pending_increment)));
+                continue;
+            }
+
+            let pieces = self.pieces(&token.text, &parts);
+            if pieces.is_empty() {
+                continue;
+            }
+
+            // Positions are only given to parts where an emitted token
+            // starts, so that skipped parts leave no holes in the graph.
+            // A token ending at a skipped part extends to the next one.
+            let mut nodes: Vec<usize> = pieces.iter().map(|piece| piece.from).collect();
+            nodes.push(parts.len());
+            nodes.dedup();
+            let node = |part: usize| nodes.partition_point(|&n| n < part);
+
+            // Offsets are only split when they match the text.
+            let exact_offsets =
+                token.end_offset.checked_sub(token.start_offset) == Some(token.text.len());
+
+            let mut previous_node = 0;
+            for piece in pieces {
+                let from = node(piece.from);
+                let mut split = token
+                    .with_text(piece.text)
+                    .with_position_length(node(piece.to) - from);
+                split.position_increment =
+                    std::mem::take(&mut pending_increment) + from - previous_node;
+                if exact_offsets {
+                    split.start_offset = token.start_offset + parts[piece.from].start;
+                    split.end_offset = token.start_offset + parts[piece.to - 1].end;
+                }
+                previous_node = from;
+                output.push(split);
+            }
+        }
+
+        Ok(Box::new(output.into_iter()))
+    }
+
+    fn name(&self) -> &'static str {
+        "word_delimiter_graph"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::token::Token;
+    use crate::analysis::token_filter::flatten_graph::FlattenGraphFilter;
+
+    /// (text, position increment, position length, start offset, end offset)
+    type Graph = Vec<(String, usize, usize, usize, usize)>;
+
+    fn run(filter: &WordDelimiterGraphFilter, tokens: Vec<Token>) -> Graph {
+        filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .map(|t| {
+                (
+                    t.text,
+                    t.position_increment,
+                    t.position_length,
+                    t.start_offset,
+                    t.end_offset,
+                )
+            })
+            .collect()
+    }
+
+    fn texts(filter: &WordDelimiterGraphFilter, text: &str) -> Vec<String> {
+        run(filter, vec![Token::new(text, 0)])
+            .into_iter()
+            .map(|t| t.0)
+            .collect()
+    }
+
+    fn words(words: &[&str]) -> Vec<Token> {
+        let mut offset = 0;
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let token = Token::with_offsets(*word, i, offset, offset + word.len());
+                offset += word.len() + 1;
+                token
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split() {
+        let filter = WordDelimiterGraphFilter::new();
+        assert_eq!(
+            texts(&filter, "PowerShot-SD500"),
+            vec!["Power", "Shot", "SD", "500"]
+        );
+        assert_eq!(texts(&filter, "WiFi"), vec!["Wi", "Fi"]);
+        assert_eq!(
+            texts(&filter, "iPhone15Pro"),
+            vec!["i", "Phone", "15", "Pro"]
+        );
+        assert_eq!(texts(&filter, "SDCard"), vec!["SD", "Card"]);
+        assert_eq!(texts(&filter, "wi_fi.4000"), vec!["wi", "fi", "4000"]);
+        assert_eq!(texts(&filter, "--Über--"), vec!["Über"]);
+        assert_eq!(texts(&filter, "plain"), vec!["plain"]);
+    }
+
+    #[test]
+    fn test_split_options() {
+        let filter = WordDelimiterGraphFilter::new()
+            .with_split_on_case_change(false)
+            .with_split_on_numerics(false);
+        assert_eq!(
+            texts(&filter, "PowerShot-SD500"),
+            vec!["PowerShot", "SD500"]
+        );
+        assert_eq!(texts(&filter, "iPhone15Pro"), vec!["iPhone15Pro"]);
+
+        let filter = WordDelimiterGraphFilter::new().with_generate_number_parts(false);
+        assert_eq!(
+            texts(&filter, "PowerShot-SD500"),
+            vec!["Power", "Shot", "SD"]
+        );
+    }
+
+    #[test]
+    fn test_graph() {
+        let filter = WordDelimiterGraphFilter::new()
+            .with_catenate_words(true)
+            .with_catenate_all(true)
+            .with_preserve_original(true);
+
+        assert_eq!(
+            run(&filter, words(&["PowerShot-SD500", "camera"])),
+            vec![
+                ("PowerShot-SD500".to_string(), 1, 4, 0, 15),
+                ("PowerShotSD500".to_string(), 0, 4, 0, 15),
+                ("PowerShotSD".to_string(), 0, 3, 0, 12),
+                ("Power".to_string(), 0, 1, 0, 5),
+                ("Shot".to_string(), 1, 1, 5, 9),
+                ("SD".to_string(), 1, 1, 10, 12),
+                ("500".to_string(), 1, 1, 12, 15),
+                ("camera".to_string(), 1, 1, 16, 22),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_catenate_without_parts() {
+        let filter = WordDelimiterGraphFilter::new()
+            .with_generate_word_parts(false)
+            .with_generate_number_parts(false)
+            .with_catenate_words(true)
+            .with_catenate_numbers(true);
+
+        assert_eq!(
+            run(&filter, words(&["wi-fi-4000-12", "x"])),
+            vec![
+                ("wifi".to_string(), 1, 1, 0, 5),
+                ("400012".to_string(), 1, 1, 6, 13),
+                ("x".to_string(), 1, 1, 14, 15),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skipped_parts_leave_no_holes() {
+        let filter = WordDelimiterGraphFilter::new()
+            .with_generate_word_parts(false)
+            .with_catenate_all(true);
+
+        assert_eq!(
+            run(&filter, words(&["Wi-Fi-6"])),
+            vec![
+                ("WiFi6".to_string(), 1, 2, 0, 7),
+                ("6".to_string(), 1, 1, 6, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_delimiter_only_tokens() {
+        let filter = WordDelimiterGraphFilter::new();
+        assert_eq!(
+            run(&filter, words(&["a", "--", "b"])),
+            vec![("a".to_string(), 1, 1, 0, 1), ("b".to_string(), 2, 1, 5, 6),]
+        );
+
+        let filter = WordDelimiterGraphFilter::new().with_preserve_original(true);
+        assert_eq!(texts(&filter, "--"), vec!["--"]);
+    }
+
+    #[test]
+    fn test_mismatched_offsets_are_kept() {
+        let filter = WordDelimiterGraphFilter::new();
+        let result = run(&filter, vec![Token::with_offsets("WiFi", 0, 10, 20)]);
+        assert_eq!(
+            result,
+            vec![
+                ("Wi".to_string(), 1, 1, 10, 20),
+                ("Fi".to_string(), 1, 1, 10, 20),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stopped_tokens_pass_through() {
+        let filter = WordDelimiterGraphFilter::new();
+        let tokens = vec![Token::new("Wi-Fi", 0).stop()];
+        let result: Vec<Token> = filter
+            .filter(Box::new(tokens.into_iter()))
+            .unwrap()
+            .collect();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].text, "Wi-Fi");
+        assert!(result[0].is_stopped());
+    }
+
+    #[test]
+    fn test_flatten_graph() {
+        let filter = WordDelimiterGraphFilter::new()
+            .with_catenate_words(true)
+            .with_preserve_original(true);
+        let graph = filter
+            .filter(Box::new(words(&["Wi-Fi", "router"]).into_iter()))
+            .unwrap();
+        let flattened: Vec<(String, usize)> = FlattenGraphFilter::new()
+            .filter(graph)
+            .unwrap()
+            .map(|t| (t.text, t.position_increment))
+            .collect();
+
+        assert_eq!(
+            flattened,
+            vec![
+                ("Wi-Fi".to_string(), 1),
+                ("WiFi".to_string(), 0),
+                ("Wi".to_string(), 0),
+                ("Fi".to_string(), 1),
+                ("router".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_name() {
+        assert_eq!(
+            WordDelimiterGraphFilter::new().name(),
+            "word_delimiter_graph"
+        );
+    }
+}
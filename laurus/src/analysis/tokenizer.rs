@@ -10,6 +10,7 @@
 //! - [`unicode_word::UnicodeWordTokenizer`] - Uses Unicode word boundaries
 //! - [`regex::RegexTokenizer`] - Custom regex-based tokenization
 //! - [`ngram::NgramTokenizer`] - Character n-gram tokenization
+//! - [`edge_ngram::EdgeNgramTokenizer`] - Word prefix (edge n-gram) tokenization
 //! - [`lindera::LinderaTokenizer`] - Japanese morphological analysis (requires `lindera` feature)
 //! - [`whole::WholeTokenizer`] - Treats entire text as single token
 //!
@@ -101,6 +102,7 @@ pub trait Tokenizer: Send + Sync {
 }
 
 // Individual tokenizer modules
+pub mod edge_ngram;
 pub mod lindera;
 pub mod ngram;
 pub mod regex;
@@ -0,0 +1,210 @@
+//! Edge n-gram tokenizer implementation.
+//!
+//! This module provides a tokenizer that splits text into words and emits
+//! the leading character n-grams (prefixes) of each word. Edge n-grams are
+//! the usual building block for search-as-you-type: indexing the prefixes
+//! lets a partially typed word match with a plain term query.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::analysis::tokenizer::Tokenizer;
+//! use laurus::analysis::tokenizer::edge_ngram::EdgeNgramTokenizer;
+//!
+//! let tokenizer = EdgeNgramTokenizer::new(1, 3).unwrap();
+//! let tokens: Vec<_> = tokenizer.tokenize("quick fox").unwrap()
+//!     .map(|t| t.text.to_string())
+//!     .collect();
+//! assert_eq!(tokens, vec!["q", "qu", "qui", "f", "fo", "fox"]);
+//! ```
+
+use crate::analysis::token::{Token, TokenStream};
+use crate::analysis::tokenizer::Tokenizer;
+use crate::error::{LaurusError, Result};
+
+/// A tokenizer that generates the edge n-grams of each word.
+///
+/// Words are maximal runs of alphanumeric characters. For every word, the
+/// prefixes of `min_gram` to `max_gram` characters are emitted, each with
+/// the byte offsets of the prefix in the original text.
+///
+/// All prefixes of a word are stacked at the word's position (the first
+/// has a position increment of 1, the rest 0), so phrase queries over the
+/// grams still see one position per word. Words shorter than `min_gram`
+/// produce no tokens but still take up a position.
+///
+/// # Examples
+///
+/// ```
+/// use laurus::analysis::tokenizer::edge_ngram::EdgeNgramTokenizer;
+/// use laurus::analysis::tokenizer::Tokenizer;
+///
+/// let tokenizer = EdgeNgramTokenizer::new(2, 4).unwrap();
+/// let tokens: Vec<_> = tokenizer.tokenize("search-as-you-type").unwrap().collect();
+///
+/// let texts: Vec<_> = tokens.iter().map(|t| t.text.as_str()).collect();
+/// assert_eq!(texts, vec!["se", "sea", "sear", "as", "yo", "you", "ty", "typ", "type"]);
+///
+/// // "as" starts a new position, while "sea" is stacked on "se"
+/// assert_eq!(tokens[1].position_increment, 0);
+/// assert_eq!(tokens[3].position_increment, 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct EdgeNgramTokenizer {
+    /// Minimum n-gram size
+    min_gram: usize,
+    /// Maximum n-gram size
+    max_gram: usize,
+}
+
+impl EdgeNgramTokenizer {
+    /// Create a new edge n-gram tokenizer.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_gram` - Minimum n-gram size (must be >= 1)
+    /// * `max_gram` - Maximum n-gram size (must be >= min_gram)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `min_gram` is 0
+    /// - `max_gram` is less than `min_gram`
+    pub fn new(min_gram: usize, max_gram: usize) -> Result<Self> {
+        if min_gram == 0 {
+            return Err(LaurusError::analysis(
+                "min_gram must be at least 1".to_string(),
+            ));
+        }
+        if max_gram < min_gram {
+            return Err(LaurusError::analysis(format!(
+                "max_gram ({}) must be >= min_gram ({})",
+                max_gram, min_gram
+            )));
+        }
+        Ok(Self { min_gram, max_gram })
+    }
+
+    /// Get the minimum n-gram size.
+    pub fn min_gram(&self) -> usize {
+        self.min_gram
+    }
+
+    /// Get the maximum n-gram size.
+    pub fn max_gram(&self) -> usize {
+        self.max_gram
+    }
+}
+
+impl Tokenizer for EdgeNgramTokenizer {
+    fn tokenize(&self, text: &str) -> Result<TokenStream> {
+        let mut tokens = Vec::new();
+        // Increment carried over from words too short to produce a gram.
+        let mut pending_increment = 0;
+
+        for (position, (word_start, word)) in words(text).into_iter().enumerate() {
+            pending_increment += 1;
+            let prefix_ends = word
+                .char_indices()
+                .map(|(i, c)| i + c.len_utf8())
+                .skip(self.min_gram - 1)
+                .take(self.max_gram - self.min_gram + 1);
+            for end in prefix_ends {
+                let increment = std::mem::take(&mut pending_increment);
+                tokens.push(
+                    Token::with_offsets(&word[..end], position, word_start, word_start + end)
+                        .with_position_increment(increment),
+                );
+            }
+        }
+
+        Ok(Box::new(tokens.into_iter()))
+    }
+
+    fn name(&self) -> &'static str {
+        "edge_ngram"
+    }
+}
+
+/// Split `text` into maximal runs of alphanumeric characters, each paired
+/// with its byte offset.
+fn words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                words.push((s, &text[s..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, &text[s..]));
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_ngram_creation() {
+        assert!(EdgeNgramTokenizer::new(1, 3).is_ok());
+        assert!(EdgeNgramTokenizer::new(0, 3).is_err());
+        assert!(EdgeNgramTokenizer::new(3, 2).is_err());
+    }
+
+    #[test]
+    fn test_edge_ngram_prefixes() {
+        let tokenizer = EdgeNgramTokenizer::new(2, 3).unwrap();
+        let tokens: Vec<Token> = tokenizer.tokenize("Hello, world").unwrap().collect();
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+
+        assert_eq!(texts, vec!["He", "Hel", "wo", "wor"]);
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|t| (t.start_offset, t.end_offset))
+                .collect::<Vec<_>>(),
+            vec![(0, 2), (0, 3), (7, 9), (7, 10)]
+        );
+    }
+
+    #[test]
+    fn test_edge_ngram_positions() {
+        let tokenizer = EdgeNgramTokenizer::new(2, 3).unwrap();
+        let tokens: Vec<Token> = tokenizer.tokenize("a big cat").unwrap().collect();
+        let texts: Vec<(&str, usize, usize)> = tokens
+            .iter()
+            .map(|t| (t.text.as_str(), t.position, t.position_increment))
+            .collect();
+
+        // "a" is too short, but its position is kept as a gap
+        assert_eq!(
+            texts,
+            vec![("bi", 1, 2), ("big", 1, 0), ("ca", 2, 1), ("cat", 2, 0)]
+        );
+    }
+
+    #[test]
+    fn test_edge_ngram_unicode() {
+        let tokenizer = EdgeNgramTokenizer::new(1, 2).unwrap();
+        let tokens: Vec<Token> = tokenizer.tokenize("日本語 ñu").unwrap().collect();
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+
+        assert_eq!(texts, vec!["日", "日本", "ñ", "ñu"]);
+        assert_eq!(tokens[1].end_offset, 6);
+        assert_eq!(tokens[2].start_offset, 10);
+        assert_eq!(tokens[3].end_offset, 13);
+    }
+
+    #[test]
+    fn test_tokenizer_name() {
+        let tokenizer = EdgeNgramTokenizer::new(1, 2).unwrap();
+        assert_eq!(tokenizer.name(), "edge_ngram");
+    }
+}
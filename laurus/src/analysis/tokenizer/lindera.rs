@@ -36,6 +36,11 @@ use crate::analysis::token::{Token, TokenStream, TokenType};
 use crate::analysis::tokenizer::Tokenizer;
 use crate::error::{LaurusError, Result};
 
+/// Token attribute holding the part-of-speech tag assigned by the
+/// dictionary, recorded when the tokenizer is created
+/// [`with_part_of_speech`](LinderaTokenizer::with_part_of_speech).
+pub const PART_OF_SPEECH_ATTRIBUTE: &str = "part_of_speech";
+
 /// A tokenizer that uses Lindera for morphological analysis.
 ///
 /// This tokenizer performs dictionary-based word segmentation for CJK languages,
@@ -65,6 +70,8 @@ use crate::error::{LaurusError, Result};
 pub struct LinderaTokenizer {
     // Add any necessary fields for the tokenizer
     inner: Segmenter,
+    /// Whether tokens carry their part-of-speech tag.
+    part_of_speech: bool,
 }
 
 impl LinderaTokenizer {
@@ -120,7 +127,22 @@ impl LinderaTokenizer {
         };
         let inner = Segmenter::new(mode, dict, user_dict);
 
-        Ok(Self { inner })
+        Ok(Self {
+            inner,
+            part_of_speech: false,
+        })
+    }
+
+    /// Record the part-of-speech tag of each token in its
+    /// [`PART_OF_SPEECH_ATTRIBUTE`] metadata attribute.
+    ///
+    /// The tag is the first detail field of the dictionary entry, e.g.
+    /// `"NNG"` or `"VV+EP"` for KO-DIC and `"名詞"` for IPADIC. Filters such
+    /// as [`PartOfSpeechStopFilter`](crate::analysis::token_filter::part_of_speech::PartOfSpeechStopFilter)
+    /// rely on it.
+    pub fn with_part_of_speech(mut self) -> Self {
+        self.part_of_speech = true;
+        self
     }
 
     /// Detect token type based on character content.
@@ -197,21 +219,30 @@ impl Tokenizer for LinderaTokenizer {
     fn tokenize(&self, text: &str) -> Result<TokenStream> {
         let mut tokens = Vec::new();
 
-        for token in self
+        for mut token in self
             .inner
             .segment(Cow::Borrowed(text))
             .map_err(|e| LaurusError::analysis(format!("Failed to segment text: {}", e)))?
         {
             let token_type = Self::detect_token_type(&token.surface);
-            tokens.push(
-                Token::with_offsets(
-                    token.surface,
-                    token.position,
-                    token.byte_start,
-                    token.byte_end,
-                )
-                .with_token_type(token_type),
-            );
+            let part_of_speech = if self.part_of_speech {
+                token.get_detail(0).map(str::to_string)
+            } else {
+                None
+            };
+            let mut laurus_token = Token::with_offsets(
+                token.surface,
+                token.position,
+                token.byte_start,
+                token.byte_end,
+            )
+            .with_token_type(token_type);
+            if let Some(part_of_speech) = part_of_speech
+                && let Some(metadata) = laurus_token.metadata_mut()
+            {
+                metadata.set_attribute(PART_OF_SPEECH_ATTRIBUTE, part_of_speech);
+            }
+            tokens.push(laurus_token);
         }
 
         Ok(Box::new(tokens.into_iter()))
@@ -1,11 +1,25 @@
+pub mod acl;
+pub mod backfill;
+pub mod by_query;
+pub mod changes;
+#[cfg(feature = "native")]
+pub mod expiry;
+pub mod export;
+pub mod federated;
+pub mod field_update;
 pub mod query;
+pub mod resource;
 pub mod schema;
 pub mod search;
+pub mod sharded;
+pub mod snapshot;
+pub mod tenant;
+pub mod transaction;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 
 use crate::analysis::analyzer::analyzer::Analyzer;
 use crate::analysis::analyzer::keyword::KeywordAnalyzer;
@@ -18,12 +32,15 @@ use crate::lexical::store::LexicalStore;
 use crate::lexical::store::config::LexicalIndexConfig;
 use crate::storage::Storage;
 use crate::storage::prefixed::PrefixedStorage;
-use crate::store::log::{DocumentLog, LogEntry};
+use crate::store::log::{BatchOperation, DocumentLog, LogEntry, LogRecord};
 use crate::vector::store::VectorStore;
 use crate::vector::store::config::VectorIndexConfig;
 
 use self::schema::Schema;
 
+/// Path of the write-ahead log relative to the engine storage root.
+const WAL_PATH: &str = "engine.wal";
+
 /// Combined statistics from both the lexical and vector stores.
 #[derive(Debug, Clone, Default)]
 pub struct EngineStats {
@@ -32,6 +49,11 @@ pub struct EngineStats {
     /// Per-field vector statistics, keyed by field name.
     /// Empty when the schema contains no vector fields.
     pub vector_fields: HashMap<String, crate::vector::index::field::VectorFieldStats>,
+    /// Progress of field backfills, ordered by field name.
+    pub backfills: Vec<self::backfill::BackfillProgress>,
+    /// Usage of every tenant with at least one document, ordered by tenant
+    /// key. Empty when the schema has no tenant field.
+    pub tenants: BTreeMap<String, self::tenant::TenantStats>,
 }
 
 /// Unified Engine that manages both Lexical and Vector indices.
@@ -43,10 +65,69 @@ pub struct EngineStats {
 /// A system field `_id` is automatically injected into every indexed document
 /// to track the external document identifier.
 pub struct Engine {
+    storage: Arc<dyn Storage>,
     schema: RwLock<Schema>,
     lexical: LexicalStore,
     vector: VectorStore,
     log: Arc<DocumentLog>,
+    /// Serializes transaction batches against [`Engine::commit`] so that a
+    /// commit never persists a partially applied batch.
+    batch_lock: tokio::sync::Mutex<()>,
+    /// Field backfills, checkpointed in `storage`.
+    backfills: Mutex<BTreeMap<String, self::backfill::BackfillProgress>>,
+    /// Resource sets referenced by schema analyzers, persisted in `storage`.
+    resources: RwLock<BTreeMap<String, self::resource::ResourceSet>>,
+    /// Options the engine was built with, reused for the engine built by
+    /// [`Engine::update_field`].
+    options: BuildOptions,
+    /// Stored documents and bytes per tenant.
+    tenant_usage: Mutex<self::tenant::TenantUsage>,
+    /// Serializes tenant writes so that quota checks see the usage left by
+    /// the previous write.
+    tenant_lock: tokio::sync::Mutex<()>,
+}
+
+/// Builder options that are not part of the [`Schema`].
+#[derive(Clone)]
+struct BuildOptions {
+    analyzer: Option<Arc<dyn Analyzer>>,
+    embedder: Option<Arc<dyn Embedder>>,
+    change_retention: usize,
+    tenant_quota: self::tenant::TenantQuota,
+    tenant_quotas: HashMap<String, self::tenant::TenantQuota>,
+}
+
+impl BuildOptions {
+    /// The quota of `tenant`: its own override or the default quota.
+    fn tenant_quota(&self, tenant: &str) -> self::tenant::TenantQuota {
+        self.tenant_quotas
+            .get(tenant)
+            .copied()
+            .unwrap_or(self.tenant_quota)
+    }
+}
+
+/// The analyzers configured for a text field by its [`TextOption`](crate::lexical::TextOption).
+struct FieldAnalyzers {
+    index: Option<Arc<dyn Analyzer>>,
+    search: Option<Arc<dyn Analyzer>>,
+    search_quote: Option<Arc<dyn Analyzer>>,
+}
+
+impl FieldAnalyzers {
+    /// Register the search analyzers of `field` in `per_field`, and its
+    /// index analyzer too when `with_index` is set.
+    fn register(self, per_field: &PerFieldAnalyzer, field: &str, with_index: bool) {
+        if with_index && let Some(index) = self.index {
+            per_field.add_analyzer(field, index);
+        }
+        if let Some(search) = self.search {
+            per_field.add_search_analyzer(field, search);
+        }
+        if let Some(search_quote) = self.search_quote {
+            per_field.add_search_quote_analyzer(field, search_quote);
+        }
+    }
 }
 
 use crate::engine::search::{FusionAlgorithm, SearchResult};
@@ -84,51 +165,33 @@ impl Engine {
         // read_all() internally syncs next_doc_id with doc_store segments.
         let records = self.log.read_all()?;
 
+        let vector_last_seq = self.vector.last_wal_seq();
+        let lexical_last_seq = self.lexical.last_wal_seq();
+
+        self.log.changes().restore(&records, self.log.last_seq());
+
         if records.is_empty() {
             return Ok(());
         }
 
-        let vector_last_seq = self.vector.last_wal_seq();
-        let lexical_last_seq = self.lexical.last_wal_seq();
-
         for record in records {
             if record.seq <= vector_last_seq && record.seq <= lexical_last_seq {
                 continue;
             }
 
             match record.entry {
-                LogEntry::Upsert {
-                    doc_id,
-                    external_id: _,
-                    document,
-                } => {
-                    // Restore document into document store
-                    let stored_doc = self.filter_stored_fields(&document);
-                    self.log.store_document(doc_id, stored_doc);
-
-                    // Re-index into both stores using the recorded doc_id.
-                    // Update seq only after BOTH stores succeed to maintain atomicity.
-                    if record.seq > lexical_last_seq {
-                        self.lexical.upsert_document(doc_id, document.clone())?;
-                    }
-
-                    if record.seq > vector_last_seq {
-                        // Filter for vector fields
-                        let mut vector_doc = Document::new();
-                        {
-                            let schema = self.schema.read();
-                            for (name, val) in &document.fields {
-                                if schema.fields.get(name).is_some_and(|fc| fc.is_vector()) {
-                                    vector_doc.fields.insert(name.clone(), val.clone());
-                                }
-                            }
-                        }
-                        self.vector
-                            .upsert_document_by_internal_id(doc_id, vector_doc)
-                            .await?;
+                LogEntry::Batch { records } => {
+                    // A batch is only present in the log if it was written
+                    // completely, so replaying it restores all of its entries.
+                    for inner in records {
+                        self.apply_log_entry(
+                            inner.seq,
+                            inner.entry,
+                            lexical_last_seq,
+                            vector_last_seq,
+                        )
+                        .await?;
                     }
-
-                    // Both stores succeeded — now update seq trackers
                     if record.seq > lexical_last_seq {
                         self.lexical.set_last_wal_seq(record.seq)?;
                     }
@@ -136,26 +199,108 @@ impl Engine {
                         self.vector.set_last_wal_seq(record.seq);
                     }
                 }
-                LogEntry::Delete {
+                entry => {
+                    self.apply_log_entry(record.seq, entry, lexical_last_seq, vector_last_seq)
+                        .await?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply a single upsert or delete log entry to the stores.
+    ///
+    /// Each store is only mutated if `seq` is newer than the given
+    /// `lexical_last_seq` / `vector_last_seq`, and the store sequence trackers
+    /// are advanced only after both stores succeed.
+    async fn apply_log_entry(
+        &self,
+        seq: u64,
+        entry: LogEntry,
+        lexical_last_seq: u64,
+        vector_last_seq: u64,
+    ) -> Result<()> {
+        if seq <= vector_last_seq && seq <= lexical_last_seq {
+            return Ok(());
+        }
+
+        match entry {
+            LogEntry::Upsert {
+                doc_id,
+                external_id: _,
+                document,
+            } => {
+                // Update seq only after BOTH stores succeed to maintain atomicity.
+                self.restore_document(
                     doc_id,
-                    external_id: _,
-                } => {
-                    if record.seq > lexical_last_seq {
-                        self.lexical.delete_document_by_internal_id(doc_id)?;
-                    }
-                    if record.seq > vector_last_seq {
-                        self.vector.delete_document_by_internal_id(doc_id).await?;
-                    }
+                    document,
+                    seq > lexical_last_seq,
+                    seq > vector_last_seq,
+                )
+                .await?;
+            }
+            LogEntry::Delete {
+                doc_id,
+                external_id: _,
+            } => {
+                self.tenant_usage.lock().remove(doc_id);
+                if seq > lexical_last_seq {
+                    self.lexical.delete_document_by_internal_id(doc_id)?;
+                }
+                if seq > vector_last_seq {
+                    self.vector.delete_document_by_internal_id(doc_id).await?;
+                }
+            }
+            LogEntry::Batch { .. } => {
+                return Err(crate::error::LaurusError::InvalidOperation(
+                    "Nested WAL batches are not supported".to_string(),
+                ));
+            }
+        }
 
-                    // Both stores succeeded — now update seq trackers
-                    if record.seq > lexical_last_seq {
-                        self.lexical.set_last_wal_seq(record.seq)?;
-                    }
-                    if record.seq > vector_last_seq {
-                        self.vector.set_last_wal_seq(record.seq);
+        // Both stores succeeded — now update seq trackers
+        if seq > lexical_last_seq {
+            self.lexical.set_last_wal_seq(seq)?;
+        }
+        if seq > vector_last_seq {
+            self.vector.set_last_wal_seq(seq);
+        }
+        Ok(())
+    }
+
+    /// Store a document under a known doc_id and index it into the lexical
+    /// and/or vector store, without writing to the WAL.
+    async fn restore_document(
+        &self,
+        doc_id: u64,
+        document: Document,
+        lexical: bool,
+        vector: bool,
+    ) -> Result<()> {
+        // Restore document into document store
+        let stored_doc = self.filter_stored_fields(&document);
+        self.track_tenant_usage(doc_id, &stored_doc);
+        self.log.store_document(doc_id, stored_doc);
+
+        // Re-index into both stores using the recorded doc_id.
+        if lexical {
+            self.lexical.upsert_document(doc_id, document.clone())?;
+        }
+
+        if vector {
+            // Filter for vector fields
+            let mut vector_doc = Document::new();
+            {
+                let schema = self.schema.read();
+                for (name, val) in &document.fields {
+                    if schema.fields.get(name).is_some_and(|fc| fc.is_vector()) {
+                        vector_doc.fields.insert(name.clone(), val.clone());
                     }
                 }
             }
+            self.vector
+                .upsert_document_by_internal_id(doc_id, vector_doc)
+                .await?;
         }
         Ok(())
     }
@@ -213,11 +358,72 @@ impl Engine {
         Ok(())
     }
 
+    /// Put (upsert) a document that expires after `ttl`.
+    ///
+    /// Behaves like [`put_document`](Self::put_document) but first sets the
+    /// schema's [`expiry_field`](Schema::expiry_field) to the current time
+    /// plus `ttl`, overwriting any value already present in `doc`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema has no expiry field, if `ttl` is out
+    /// of range, or if [`put_document`](Self::put_document) fails.
+    pub async fn put_document_with_ttl(
+        &self,
+        id: &str,
+        doc: Document,
+        ttl: std::time::Duration,
+    ) -> Result<()> {
+        let doc = self.apply_ttl(doc, ttl)?;
+        let _ = self.index_internal(id, doc, false).await?;
+        Ok(())
+    }
+
+    /// Add a document chunk that expires after `ttl`.
+    ///
+    /// Behaves like [`add_document`](Self::add_document) but first sets the
+    /// schema's [`expiry_field`](Schema::expiry_field) to the current time
+    /// plus `ttl`, overwriting any value already present in `doc`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema has no expiry field, if `ttl` is out
+    /// of range, or if [`add_document`](Self::add_document) fails.
+    pub async fn add_document_with_ttl(
+        &self,
+        id: &str,
+        doc: Document,
+        ttl: std::time::Duration,
+    ) -> Result<()> {
+        let doc = self.apply_ttl(doc, ttl)?;
+        let _ = self.index_internal(id, doc, true).await?;
+        Ok(())
+    }
+
+    /// Stamp the expiry field of `doc` with `now + ttl`.
+    fn apply_ttl(&self, mut doc: Document, ttl: std::time::Duration) -> Result<Document> {
+        let field = self.schema.read().expiry_field.clone().ok_or_else(|| {
+            crate::error::LaurusError::invalid_argument(
+                "TTL requires an expiry_field to be configured in the schema",
+            )
+        })?;
+        let expires_at = chrono::Duration::from_std(ttl)
+            .ok()
+            .and_then(|ttl| chrono::Utc::now().checked_add_signed(ttl))
+            .ok_or_else(|| {
+                crate::error::LaurusError::invalid_argument(format!("TTL {ttl:?} is out of range"))
+            })?;
+        doc.fields
+            .insert(field, crate::data::DataValue::DateTime(expires_at));
+        Ok(doc)
+    }
+
     async fn index_internal(&self, id: &str, mut doc: Document, as_chunk: bool) -> Result<u64> {
         // 1. Inject _id field
         use crate::data::DataValue;
         doc.fields
             .insert("_id".to_string(), DataValue::Text(id.to_string()));
+        self.validate_tenant_key(&doc)?;
 
         if !as_chunk {
             self.delete_documents(id).await?;
@@ -228,6 +434,7 @@ impl Engine {
 
         // 3. Store only stored fields for retrieval (WAL has full data for recovery)
         let stored_doc = self.filter_stored_fields(&doc);
+        self.track_tenant_usage(doc_id, &stored_doc);
         self.log.store_document(doc_id, stored_doc);
 
         // 4. Prepare vector document (extract vector fields only)
@@ -261,6 +468,295 @@ impl Engine {
         Ok(doc_id)
     }
 
+    /// Begin a transaction that buffers mutations until it is committed.
+    ///
+    /// See [`Transaction`](self::transaction::Transaction) for semantics.
+    pub fn begin(&self) -> self::transaction::Transaction<'_> {
+        self::transaction::Transaction::new(self)
+    }
+
+    /// Resolve, log and apply the operations of a transaction as one batch.
+    async fn commit_transaction(
+        &self,
+        operations: Vec<self::transaction::TransactionOperation>,
+    ) -> Result<()> {
+        use self::transaction::TransactionOperation;
+        use crate::data::DataValue;
+
+        let _batch_guard = self.batch_lock.lock().await;
+
+        // 1. Resolve operations into deletes of existing documents and
+        //    new documents, honoring their order within the transaction.
+        let mut deletes: Vec<(u64, String)> = Vec::new();
+        let mut deleted: HashSet<u64> = HashSet::new();
+        let mut upserts: Vec<(String, Document)> = Vec::new();
+        for operation in operations {
+            let (id, document, replace) = match operation {
+                TransactionOperation::Put { id, document } => (id, Some(document), true),
+                TransactionOperation::Add { id, document } => (id, Some(document), false),
+                TransactionOperation::Delete { id } => (id, None, true),
+            };
+            if replace {
+                for doc_id in self.lexical.find_doc_ids_by_term("_id", &id)? {
+                    if deleted.insert(doc_id) {
+                        deletes.push((doc_id, id.clone()));
+                    }
+                }
+                upserts.retain(|(existing, _)| existing != &id);
+            }
+            if let Some(mut document) = document {
+                document
+                    .fields
+                    .insert("_id".to_string(), DataValue::Text(id.clone()));
+                self.validate_tenant_key(&document)?;
+                upserts.push((id, document));
+            }
+        }
+        if deletes.is_empty() && upserts.is_empty() {
+            return Ok(());
+        }
+
+        // 2. Write the whole batch to the WAL as a single record.
+        let batch = deletes
+            .into_iter()
+            .map(|(doc_id, external_id)| BatchOperation::Delete {
+                doc_id,
+                external_id,
+            })
+            .chain(
+                upserts
+                    .into_iter()
+                    .map(|(external_id, document)| BatchOperation::Upsert {
+                        external_id,
+                        document,
+                    }),
+            )
+            .collect();
+        let record = self.log.append_batch(batch)?;
+
+        // 3. Apply the batch to the stores. Trackers advance per entry, so a
+        //    failure here is resumed from the right entry on recovery.
+        let LogEntry::Batch { records } = record.entry else {
+            unreachable!("append_batch always returns a batch record");
+        };
+        for inner in records {
+            self.apply_log_entry(inner.seq, inner.entry, 0, 0).await?;
+        }
+        self.lexical.set_last_wal_seq(record.seq)?;
+        self.vector.set_last_wal_seq(record.seq);
+
+        Ok(())
+    }
+
+    /// Subscribe to index mutations with a sequence number above `since_seq`.
+    ///
+    /// Every upsert and delete is assigned a sequence number when it is
+    /// written to the WAL. The returned subscription first yields retained
+    /// events newer than `since_seq` and then follows new mutations as they
+    /// happen, in sequence order. Mutations inside a
+    /// [`Transaction`](self::transaction::Transaction) are reported as
+    /// individual events. Events are emitted when a mutation is logged, not
+    /// when it is committed.
+    ///
+    /// The retained window is kept in memory and sized with
+    /// [`EngineBuilder::change_retention`]; it survives commits but not a
+    /// restart. Pass [`last_seq`](Self::last_seq) to only follow new
+    /// mutations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if events after `since_seq` are no longer retained.
+    pub fn changes(
+        &self,
+        since_seq: crate::store::log::SeqNumber,
+    ) -> Result<crate::store::changes::ChangeSubscription> {
+        self.log.changes().subscribe(since_seq)
+    }
+
+    /// Subscribe to the mutations visible to `identity`.
+    ///
+    /// Like [`changes`](Self::changes), but upserted documents only keep
+    /// their stored fields, and on an engine with an
+    /// [`acl_field`](Schema::acl_field) only documents the identity can
+    /// access are reported. See the [`changes`](self::changes) module.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if events after `since_seq` are no longer retained.
+    pub fn changes_as(
+        &self,
+        since_seq: crate::store::log::SeqNumber,
+        identity: &self::acl::Identity,
+    ) -> Result<self::changes::ScopedChangeSubscription> {
+        self.scoped_changes(since_seq, identity, None)
+    }
+
+    /// Subscribe to the mutations visible to `identity`, restricted to the
+    /// documents whose internal ID starts with `tenant_prefix`.
+    pub(crate) fn scoped_changes(
+        &self,
+        since_seq: crate::store::log::SeqNumber,
+        identity: &self::acl::Identity,
+        tenant_prefix: Option<String>,
+    ) -> Result<self::changes::ScopedChangeSubscription> {
+        let subscription = self.changes(since_seq)?;
+        let names: Vec<String> = self.schema.read().fields.keys().cloned().collect();
+        let mut stored_fields: HashSet<String> = names
+            .into_iter()
+            .filter(|name| self.is_field_stored(name))
+            .collect();
+        stored_fields.insert("_id".to_string());
+        let acl = self
+            .schema
+            .read()
+            .acl_field
+            .clone()
+            .map(|field| (field, identity.clone()));
+        Ok(self::changes::ScopedChangeSubscription::new(
+            subscription,
+            stored_fields,
+            acl,
+            tenant_prefix,
+        ))
+    }
+
+    /// Apply a mutation received from another engine's change feed.
+    ///
+    /// The mutation is written to this engine's WAL with its original
+    /// sequence number and doc_id, then applied to the stores through the
+    /// same path as WAL recovery. This is how replication followers mirror
+    /// a leader: feed them the leader's [`changes`](Self::changes) in order.
+    /// As with local mutations, the change becomes visible to search after
+    /// the next [`commit`](Self::commit).
+    ///
+    /// Returns `false` without doing anything if the event's sequence
+    /// number is not newer than [`last_seq`](Self::last_seq).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the WAL write or a store mutation fails.
+    pub async fn apply_change(&self, event: crate::store::changes::ChangeEvent) -> Result<bool> {
+        let record = LogRecord::from(event);
+        let _batch_guard = self.batch_lock.lock().await;
+        if !self.log.append_record(&record)? {
+            return Ok(false);
+        }
+        self.apply_log_entry(record.seq, record.entry, 0, 0).await?;
+        Ok(true)
+    }
+
+    /// Commit and capture the committed state of the engine in `staging`.
+    ///
+    /// Runs an incremental [`snapshot_to`](Self::snapshot_to) into
+    /// `staging`, so that repeated snapshots into the same storage only copy
+    /// the files that changed, and returns a
+    /// [`Snapshot`](self::snapshot::Snapshot) that reads the files from
+    /// there one at a time, e.g. to stream them to a replication follower.
+    /// Unlike the files of the engine itself, the copies are not deleted by
+    /// later merges; `staging` must not be written to while the snapshot is
+    /// read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commit fails or a file cannot be read or
+    /// written.
+    pub async fn snapshot(&self, staging: Arc<dyn Storage>) -> Result<self::snapshot::Snapshot> {
+        self.snapshot_to(staging.as_ref(), true).await?;
+        self::snapshot::Snapshot::open(staging)
+    }
+
+    /// Commit and copy the committed state of the engine into a backup
+    /// storage.
+    ///
+    /// `target` receives every committed file (excluding the WAL) and a
+    /// [`SnapshotManifest`](self::snapshot::SnapshotManifest) with the schema,
+    /// the sequence number of the last committed mutation and a checksum
+    /// per file. Writes keep being accepted while the files are copied, but
+    /// commits are blocked, and with them the segment merges that delete
+    /// files, so the copied set is consistent. Mutations made after the
+    /// initial commit are not included.
+    ///
+    /// With `incremental`, a snapshot already held by `target` is updated
+    /// in place: only files that changed since are written, and files that
+    /// no longer exist are deleted. Restore a snapshot with
+    /// [`snapshot::restore_snapshot`](self::snapshot::restore_snapshot).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commit fails or a file cannot be read or
+    /// written.
+    pub async fn snapshot_to(
+        &self,
+        target: &dyn Storage,
+        incremental: bool,
+    ) -> Result<self::snapshot::SnapshotReport> {
+        self.commit().await?;
+        let _batch_guard = self.batch_lock.lock().await;
+        self::snapshot::copy_files(
+            self.storage.as_ref(),
+            &[WAL_PATH],
+            target,
+            self.log.committed_seq()?,
+            self.schema(),
+            incremental,
+        )
+    }
+
+    /// Commit and start exporting every live document with its external ID.
+    ///
+    /// Only stored fields are exported. With `include_vectors`, each vector
+    /// field holds the indexed vector instead of the stored source value
+    /// (such as the text that was embedded), so that an
+    /// [`import`](Self::import) does not embed the documents again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the commit fails.
+    pub async fn export(&self, include_vectors: bool) -> Result<self::export::DocumentExport<'_>> {
+        self.commit().await?;
+        Ok(self::export::DocumentExport::new(
+            self,
+            include_vectors,
+            self.stats()?.document_count,
+        ))
+    }
+
+    /// Add every document of a JSON Lines export and commit.
+    ///
+    /// Each line holds one [`ExportedDocument`](self::export::ExportedDocument)
+    /// as written by [`DocumentExport::write_jsonl`](self::export::DocumentExport::write_jsonl).
+    /// Documents are added as chunks, so chunks sharing an external ID are
+    /// kept; import into an empty engine to recreate an index. Each batch of
+    /// documents is logged as a single WAL record and committed once.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Source of the JSONL stream.
+    /// * `batch_size` - Number of documents committed per batch.
+    /// * `on_progress` - Called with the number of documents imported so far.
+    ///
+    /// # Returns
+    ///
+    /// The number of imported documents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line cannot be parsed or a document cannot be
+    /// indexed. Batches committed before the failing line stay indexed.
+    pub async fn import<R: std::io::BufRead>(
+        &self,
+        reader: R,
+        batch_size: usize,
+        on_progress: impl FnMut(u64),
+    ) -> Result<u64> {
+        self::export::import_jsonl(self, reader, batch_size, on_progress).await
+    }
+
+    /// Sequence number of the most recent logged mutation.
+    pub fn last_seq(&self) -> crate::store::log::SeqNumber {
+        self.log.last_seq()
+    }
+
     /// Delete all documents (including chunks) by external ID.
     ///
     /// Looks up all internal document IDs associated with the given external
@@ -282,20 +778,221 @@ impl Engine {
     pub async fn delete_documents(&self, id: &str) -> Result<()> {
         let doc_ids = self.lexical.find_doc_ids_by_term("_id", id)?;
         for doc_id in doc_ids {
-            // 1. Write to log
-            let seq = self.log.append_delete(doc_id, id)?;
-            // 2. Delete from Lexical
-            self.lexical.delete_document_by_internal_id(doc_id)?;
-            // 3. Delete from Vector
-            self.vector.delete_document_by_internal_id(doc_id).await?;
-            // 4. Update trackers AFTER both deletes succeed.
-            // This ensures failed deletes are retried on recovery.
-            self.lexical.set_last_wal_seq(seq)?;
-            self.vector.set_last_wal_seq(seq);
+            self.delete_internal(doc_id, id).await?;
         }
         Ok(())
     }
 
+    /// Delete a single document by internal ID (WAL first, then both stores).
+    async fn delete_internal(&self, doc_id: u64, external_id: &str) -> Result<()> {
+        // 1. Write to log
+        let seq = self.log.append_delete(doc_id, external_id)?;
+        self.tenant_usage.lock().remove(doc_id);
+        // 2. Delete from Lexical
+        self.lexical.delete_document_by_internal_id(doc_id)?;
+        // 3. Delete from Vector
+        self.vector.delete_document_by_internal_id(doc_id).await?;
+        // 4. Update trackers AFTER both deletes succeed.
+        // This ensures failed deletes are retried on recovery.
+        self.lexical.set_last_wal_seq(seq)?;
+        self.vector.set_last_wal_seq(seq);
+        Ok(())
+    }
+
+    /// Delete every committed document matching a lexical query.
+    ///
+    /// Matching internal IDs are read from the committed index in pages of
+    /// [`ByQueryOptions::batch_size`](self::by_query::ByQueryOptions::batch_size)
+    /// and deleted batch by batch. Each deletion is written to the WAL
+    /// before the stores are mutated, exactly like
+    /// [`delete_documents`](Self::delete_documents), and every batch is
+    /// committed before the next page is read, so neither the matches nor
+    /// the WAL are ever held in full. Documents indexed after the run
+    /// started are never matched.
+    ///
+    /// The run stops before the next batch once the attached
+    /// [`CancellationToken`](self::by_query::CancellationToken) is cancelled;
+    /// deletions already applied are kept, committed and reported.
+    ///
+    /// # Parameters
+    ///
+    /// - `query` - The lexical query selecting documents to delete.
+    /// - `options` - Batch size, document limit and cancellation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be executed, if the WAL write
+    /// or store deletion fails for any matched document, or if a commit
+    /// fails.
+    pub async fn delete_by_query(
+        &self,
+        query: Box<dyn crate::lexical::query::Query>,
+        options: self::by_query::ByQueryOptions,
+    ) -> Result<self::by_query::ByQueryResponse> {
+        self.run_by_query(query, None, options).await
+    }
+
+    /// Apply a field patch to every committed document matching a lexical query.
+    ///
+    /// For each match, the stored fields of the document are merged with
+    /// `patch`: fields present in the patch overwrite existing values, and a
+    /// patch value of [`DataValue::Null`](crate::data::DataValue::Null)
+    /// removes the field. The original document is then deleted and the
+    /// merged document is re-indexed under the same external ID, so other
+    /// chunks sharing that ID are left untouched.
+    ///
+    /// Only stored fields can be read back from the document store. Fields
+    /// that are indexed but not stored are therefore lost on update unless
+    /// they are supplied again in the patch.
+    ///
+    /// Paging, batching, commits and cancellation behave as in
+    /// [`delete_by_query`](Self::delete_by_query). Re-indexed documents are
+    /// not matched again by the same run.
+    ///
+    /// # Parameters
+    ///
+    /// - `query` - The lexical query selecting documents to update.
+    /// - `patch` - The fields to set (or remove, with `Null`).
+    /// - `options` - Batch size, document limit and cancellation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the patch targets the system `_id` field, if the
+    /// query cannot be executed, if deleting or re-indexing a document
+    /// fails, or if a commit fails.
+    pub async fn update_by_query(
+        &self,
+        query: Box<dyn crate::lexical::query::Query>,
+        patch: Document,
+        options: self::by_query::ByQueryOptions,
+    ) -> Result<self::by_query::ByQueryResponse> {
+        if patch.fields.contains_key("_id") {
+            return Err(crate::error::LaurusError::invalid_argument(
+                "update_by_query cannot modify the system field '_id'",
+            ));
+        }
+        self.run_by_query(query, Some(&patch), options).await
+    }
+
+    /// Delete, or with a `patch` update, the committed documents matching
+    /// `query` page by page.
+    async fn run_by_query(
+        &self,
+        query: Box<dyn crate::lexical::query::Query>,
+        patch: Option<&Document>,
+        options: self::by_query::ByQueryOptions,
+    ) -> Result<self::by_query::ByQueryResponse> {
+        let matched =
+            self.lexical
+                .count(crate::lexical::search::searcher::LexicalSearchRequest::new(
+                    query.clone_box(),
+                ))?;
+        let mut response = self::by_query::ByQueryResponse {
+            matched,
+            ..Default::default()
+        };
+
+        // Documents re-indexed by this run get IDs from here on, so they are
+        // never visited twice.
+        let until = self.log.next_doc_id();
+        let batch_size = options.batch_size.max(1);
+        let mut remaining = options.max_docs.unwrap_or(usize::MAX);
+        let mut from = 0;
+        while remaining > 0 {
+            if options.is_cancelled() {
+                response.cancelled = true;
+                break;
+            }
+            let limit = batch_size.min(remaining);
+            let batch =
+                self.lexical
+                    .find_doc_ids_by_query_page(query.as_ref(), from, until, limit)?;
+            let Some(&last) = batch.last() else {
+                break;
+            };
+            from = last + 1;
+            remaining -= batch.len();
+
+            let resolved = self.resolve_ids_and_documents_batch(&batch)?;
+            for &doc_id in &batch {
+                let Some((external_id, Some(document))) = resolved.get(&doc_id) else {
+                    continue;
+                };
+                self.delete_internal(doc_id, external_id).await?;
+                if let Some(patch) = patch {
+                    let mut updated = document.clone();
+                    for (name, value) in &patch.fields {
+                        if matches!(value, crate::data::DataValue::Null) {
+                            updated.fields.remove(name);
+                        } else {
+                            updated.fields.insert(name.clone(), value.clone());
+                        }
+                    }
+                    self.index_internal(external_id, updated, true).await?;
+                }
+                response.processed += 1;
+            }
+            response.batches += 1;
+            self.commit().await?;
+            if batch.len() < limit {
+                break;
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Physically delete every committed document whose expiry has passed.
+    ///
+    /// Expired documents are already hidden from search and retrieval; this
+    /// method removes them from the indexes through the regular WAL-backed
+    /// delete path so that recovery stays consistent. It is a no-op when the
+    /// schema has no [`expiry_field`](Schema::expiry_field).
+    ///
+    /// Expiry is evaluated with second precision, matching how `DateTime`
+    /// values are indexed. Call [`commit`](Self::commit) afterwards to
+    /// persist the deletions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expiry query or any deletion fails.
+    pub async fn purge_expired(
+        &self,
+        options: self::by_query::ByQueryOptions,
+    ) -> Result<self::by_query::ByQueryResponse> {
+        let Some(field) = self.schema.read().expiry_field.clone() else {
+            return Ok(self::by_query::ByQueryResponse::default());
+        };
+        let now = chrono::Utc::now().timestamp() as f64;
+        let query =
+            crate::lexical::query::range::NumericRangeQuery::f64_range(field, None, Some(now));
+        self.delete_by_query(Box::new(query), options).await
+    }
+
+    /// Return the subset of `doc_ids` whose expiry timestamp has passed.
+    ///
+    /// Always empty when the schema has no expiry field.
+    fn expired_doc_ids(&self, doc_ids: &[u64]) -> Result<HashSet<u64>> {
+        let Some(field) = self.schema.read().expiry_field.clone() else {
+            return Ok(HashSet::new());
+        };
+        if doc_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+        let now = chrono::Utc::now();
+        let docs = self.log.get_documents_batch(doc_ids)?;
+        Ok(docs
+            .into_iter()
+            .filter(|(_, doc)| {
+                doc.fields
+                    .get(&field)
+                    .and_then(|v| v.as_datetime())
+                    .is_some_and(|expires_at| expires_at <= now)
+            })
+            .map(|(doc_id, _)| doc_id)
+            .collect())
+    }
+
     /// Commit changes to both stores and truncate the WAL.
     ///
     /// Persists all pending changes in the lexical store, vector store, and
@@ -308,6 +1005,7 @@ impl Engine {
     /// Returns an error if committing the lexical store, vector store,
     /// document store, or truncating the WAL fails.
     pub async fn commit(&self) -> Result<()> {
+        let _batch_guard = self.batch_lock.lock().await;
         self.lexical.commit()?;
         self.vector.commit().await?;
         self.log.commit_documents()?;
@@ -343,6 +1041,8 @@ impl Engine {
         Ok(EngineStats {
             document_count: live_count,
             vector_fields,
+            backfills: self.backfills(),
+            tenants: self.tenant_usage.lock().all(),
         })
     }
 
@@ -409,7 +1109,9 @@ impl Engine {
     ///
     /// After adding a field, new documents can include values for this field
     /// and searches can target it. Existing documents are unaffected (they
-    /// simply do not have a value for the new field).
+    /// simply do not have a value for the new field); use
+    /// [`add_field_with_backfill`](Self::add_field_with_backfill) to derive
+    /// the field for them as well.
     ///
     /// # Arguments
     ///
@@ -441,29 +1143,16 @@ impl Engine {
 
         // 2. Register in the appropriate store.
         if option.is_lexical() {
-            // Resolve the per-field analyzer if configured.
-            let field_analyzer = if let schema::FieldOption::Text(ref text_opt) = option
-                && let Some(ref analyzer_name) = text_opt.analyzer
-            {
-                let schema = self.schema.read();
-                let analyzer = match crate::analysis::analyzer::registry::create_analyzer_by_name(
-                    analyzer_name,
-                ) {
-                    Ok(a) => a,
-                    Err(_) => {
-                        let def = schema.analyzers.get(analyzer_name).ok_or_else(|| {
-                            crate::error::LaurusError::invalid_argument(format!(
-                                "Unknown analyzer '{analyzer_name}' for field '{name}': \
-                                     not a built-in and not defined in schema.analyzers"
-                            ))
-                        })?;
-                        crate::analysis::analyzer::registry::create_analyzer_from_definition(
-                            analyzer_name,
-                            def,
-                        )?
-                    }
-                };
-                Some(analyzer)
+            // Resolve the per-field analyzers if configured.
+            let mut field_analyzers = if let schema::FieldOption::Text(ref text_opt) = option {
+                // Resource sets are always locked before the schema.
+                let resources = self.resources.read();
+                Some(Self::resolve_text_analyzers(
+                    &self.schema.read(),
+                    &resources,
+                    name,
+                    text_opt,
+                )?)
             } else {
                 None
             };
@@ -471,7 +1160,12 @@ impl Engine {
             let lexical_opt = option
                 .to_lexical()
                 .expect("is_lexical() was true but to_lexical() returned None");
-            self.lexical.add_field(name, lexical_opt, field_analyzer)?;
+            let index_analyzer = field_analyzers.as_mut().and_then(|a| a.index.take());
+            self.lexical.add_field(name, lexical_opt, index_analyzer)?;
+            if let Some(field_analyzers) = field_analyzers {
+                self.lexical
+                    .with_per_field_analyzer(|pfa| field_analyzers.register(pfa, name, false))?;
+            }
         }
 
         if option.is_vector() {
@@ -511,6 +1205,279 @@ impl Engine {
         Ok(self.schema.read().clone())
     }
 
+    /// Add a field and backfill it for every existing document.
+    ///
+    /// Behaves like [`add_field`](Self::add_field), and additionally starts
+    /// a backfill: documents that exist when the field is added are read
+    /// back from the document store, the value of `source_field` is copied
+    /// into the new field, and the document is re-indexed. For a vector
+    /// field with an embedder, this embeds the source text; for a text
+    /// field with a new analyzer, it indexes the source text again.
+    ///
+    /// This method only records the backfill. Run it with
+    /// [`backfill_batch`](Self::backfill_batch) or a
+    /// [`BackfillTask`](self::backfill::BackfillTask). Progress is
+    /// checkpointed in storage and survives a restart, but the schema is
+    /// not: the caller must persist the returned schema as with
+    /// [`add_field`](Self::add_field).
+    ///
+    /// Documents are re-indexed from their stored fields like in
+    /// [`update_by_query`](Self::update_by_query), so every field of the
+    /// schema must be stored; otherwise backfilled documents would lose the
+    /// fields that are indexed but not stored.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The field name. Must not collide with an existing field.
+    /// * `option` - The field configuration.
+    /// * `source_field` - A stored field whose value is copied into `name`.
+    ///
+    /// # Returns
+    ///
+    /// The updated [`Schema`] and the initial progress of the backfill.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source_field` is not a stored field, if a field
+    /// of the schema is indexed but not stored, or for any reason
+    /// [`add_field`](Self::add_field) fails.
+    pub async fn add_field_with_backfill(
+        &self,
+        name: &str,
+        option: schema::FieldOption,
+        source_field: &str,
+    ) -> Result<(Schema, self::backfill::BackfillProgress)> {
+        if source_field == name
+            || !self.schema.read().fields.contains_key(source_field)
+            || !self.is_field_stored(source_field)
+        {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Backfill source '{source_field}' must be an existing stored field \
+                 other than '{name}'"
+            )));
+        }
+        let schema = self.schema();
+        if let Some(field) = schema.fields.keys().find(|f| !self.is_field_stored(f)) {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Field '{field}' is not stored; a backfill re-indexes every \
+                 document from its stored fields"
+            )));
+        }
+
+        let schema = self.add_field(name, option).await?;
+
+        // Every document with a lower internal ID than the next one to be
+        // assigned predates the field.
+        let next_doc_id = self.log.next_doc_id();
+        let total = self.stats()?.document_count;
+        let progress =
+            self::backfill::BackfillProgress::new(name, source_field, total, next_doc_id);
+
+        let mut backfills = self.backfills.lock();
+        backfills.insert(name.to_string(), progress.clone());
+        self::backfill::save(self.storage.as_ref(), &backfills)?;
+
+        Ok((schema, progress))
+    }
+
+    /// Backfill the next batch of documents for `field`.
+    ///
+    /// Scans up to `batch_size` internal document IDs, re-indexes every live
+    /// document among them that has a value for the source field, and
+    /// checkpoints the progress. Re-indexed documents go through the WAL;
+    /// they become visible to search after the next [`commit`](Self::commit).
+    ///
+    /// Calling this on a completed backfill is a no-op.
+    ///
+    /// # Returns
+    ///
+    /// The progress after the batch. The backfill is done once
+    /// [`completed`](self::backfill::BackfillProgress::completed) is `true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no backfill exists for `field`, if the field is
+    /// no longer in the schema, or if re-indexing a document fails.
+    pub async fn backfill_batch(
+        &self,
+        field: &str,
+        batch_size: usize,
+    ) -> Result<self::backfill::BackfillProgress> {
+        let progress = self.backfills.lock().get(field).cloned().ok_or_else(|| {
+            crate::error::LaurusError::invalid_argument(format!(
+                "No backfill exists for field '{field}'"
+            ))
+        })?;
+        let Some(range) = progress.next_range(batch_size) else {
+            return Ok(progress);
+        };
+        if !self.schema.read().fields.contains_key(field) {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Field '{field}' being backfilled is not in the schema"
+            )));
+        }
+
+        let doc_ids: Vec<u64> = range.clone().collect();
+        let documents = self.log.get_documents_batch(&doc_ids)?;
+
+        // The document store keeps deleted documents; only re-index the
+        // ones still live in the lexical index.
+        let live = self.live_doc_ids(&documents)?;
+
+        let mut processed = 0;
+        for doc_id in doc_ids {
+            let Some(document) = documents.get(&doc_id) else {
+                continue;
+            };
+            let (Some(external_id), Some(value)) = (
+                document.get("_id").and_then(|v| v.as_text()),
+                document.get(&progress.source_field),
+            ) else {
+                continue;
+            };
+            if !live.contains(&doc_id) {
+                continue;
+            }
+            let mut updated = document.clone();
+            updated.fields.insert(field.to_string(), value.clone());
+            self.delete_internal(doc_id, external_id).await?;
+            self.index_internal(external_id, updated, true).await?;
+            processed += 1;
+        }
+
+        let mut backfills = self.backfills.lock();
+        let progress = backfills.get_mut(field).ok_or_else(|| {
+            crate::error::LaurusError::invalid_argument(format!(
+                "Backfill of field '{field}' was removed while running"
+            ))
+        })?;
+        progress.advance(*range.end(), processed);
+        let progress = progress.clone();
+        self::backfill::save(self.storage.as_ref(), &backfills)?;
+
+        Ok(progress)
+    }
+
+    /// Internal IDs of the documents among `documents` that are still live in
+    /// the lexical index, including uncommitted ones.
+    fn live_doc_ids(&self, documents: &HashMap<u64, Document>) -> Result<HashSet<u64>> {
+        let mut live: HashSet<u64> = HashSet::new();
+        let mut checked: HashSet<&str> = HashSet::new();
+        for document in documents.values() {
+            if let Some(id) = document.get("_id").and_then(|v| v.as_text())
+                && checked.insert(id)
+            {
+                live.extend(self.lexical.find_doc_ids_by_term("_id", id)?);
+            }
+        }
+        Ok(live)
+    }
+
+    /// Progress of every field backfill, ordered by field name.
+    ///
+    /// Completed backfills are kept until their field is deleted.
+    pub fn backfills(&self) -> Vec<self::backfill::BackfillProgress> {
+        self.backfills.lock().values().cloned().collect()
+    }
+
+    /// Change the definition of an existing field, for example its analyzer,
+    /// its embedder or its vector index type.
+    ///
+    /// Indexed data cannot be converted in place, so this starts a
+    /// [`FieldUpdate`](self::field_update::FieldUpdate) that builds a new
+    /// engine with the updated schema in `storage` from this engine's stored
+    /// documents. This engine is not modified and keeps serving reads and
+    /// writes with the old definition while the new one is built; run the
+    /// update to completion and replace this engine with the one returned
+    /// by [`FieldUpdate::finish`](self::field_update::FieldUpdate::finish).
+    ///
+    /// The new engine is built with the same analyzer, embedder and change
+    /// retention as this one. Fields declared in the schema use their
+    /// schema analyzer and embedder, so changing an embedder definition in
+    /// `option` re-embeds every document with it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The field to change.
+    /// * `option` - The new field configuration.
+    /// * `storage` - Empty storage for the new engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - No field with the given name exists in the schema.
+    /// - A field is indexed but not stored, so documents cannot be rebuilt
+    ///   from the document store.
+    /// - A backfill is still running.
+    /// - `storage` is not empty, or the new engine cannot be built (e.g. the
+    ///   option references an unknown analyzer or embedder).
+    pub async fn update_field(
+        &self,
+        name: &str,
+        option: schema::FieldOption,
+        storage: Arc<dyn Storage>,
+    ) -> Result<self::field_update::FieldUpdate> {
+        let mut schema = self.schema();
+        if !schema.fields.contains_key(name) {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Field '{name}' does not exist in the schema"
+            )));
+        }
+        if let Some(field) = schema.fields.keys().find(|f| !self.is_field_stored(f)) {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Field '{field}' is not stored; updating a field rebuilds every \
+                 document from its stored fields"
+            )));
+        }
+        if let Some(backfill) = self.backfills.lock().values().find(|b| !b.completed) {
+            return Err(crate::error::LaurusError::InvalidOperation(format!(
+                "Field '{}' is still being backfilled",
+                backfill.field
+            )));
+        }
+        if !storage.list_files()?.is_empty() {
+            return Err(crate::error::LaurusError::invalid_argument(
+                "Storage for the updated engine must be empty",
+            ));
+        }
+        schema.fields.insert(name.to_string(), option);
+
+        // Mutations up to the last commit are part of the stored documents.
+        // If later ones were already evicted from the change feed, they
+        // completed long ago and are part of them as well.
+        let since_seq = self
+            .log
+            .committed_seq()?
+            .max(self.log.changes().evicted_through());
+
+        // The rebuilt engine refers to the same resource sets.
+        self::resource::save(storage.as_ref(), &self.resources.read())?;
+
+        let mut builder = EngineBuilder::new(storage, schema)
+            .change_retention(self.options.change_retention)
+            .tenant_quota(self.options.tenant_quota);
+        for (tenant, quota) in &self.options.tenant_quotas {
+            builder = builder.tenant_quota_for(tenant.clone(), *quota);
+        }
+        if let Some(analyzer) = &self.options.analyzer {
+            builder = builder.analyzer(analyzer.clone());
+        }
+        if let Some(embedder) = &self.options.embedder {
+            builder = builder.embedder(embedder.clone());
+        }
+        let target = builder.build().await?;
+
+        let progress = self::backfill::BackfillProgress::new(
+            name,
+            name,
+            self.stats()?.document_count,
+            self.log.next_doc_id(),
+        );
+        Ok(self::field_update::FieldUpdate::new(
+            name, target, progress, since_seq,
+        ))
+    }
+
     /// Dynamically remove a field from the engine schema at runtime.
     ///
     /// This removes the field definition from the schema so that it is no longer
@@ -538,6 +1505,9 @@ impl Engine {
     ///
     /// Returns an error if:
     /// - No field with the given name exists in the schema.
+    /// - The field is the schema's [`expiry_field`](Schema::expiry_field) or
+    ///   [`acl_field`](Schema::acl_field) or
+    ///   [`tenant_field`](Schema::tenant_field).
     /// - The underlying store rejects the deletion.
     pub async fn delete_field(&self, name: &str) -> Result<Schema> {
         // 1. Check that the field exists.
@@ -550,6 +1520,22 @@ impl Engine {
             })?
         };
 
+        if self.schema.read().expiry_field.as_deref() == Some(name) {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Field '{name}' is the schema expiry field and cannot be deleted"
+            )));
+        }
+        if self.schema.read().acl_field.as_deref() == Some(name) {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Field '{name}' is the schema ACL field and cannot be deleted"
+            )));
+        }
+        if self.schema.read().tenant_field.as_deref() == Some(name) {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Field '{name}' is the schema tenant field and cannot be deleted"
+            )));
+        }
+
         // 2. Remove from the appropriate store.
         if option.is_lexical() {
             self.lexical.delete_field(name)?;
@@ -566,9 +1552,172 @@ impl Engine {
             schema.default_fields.retain(|f| f != name);
         }
 
+        // 4. Drop any backfill of the field.
+        {
+            let mut backfills = self.backfills.lock();
+            if backfills.remove(name).is_some() {
+                self::backfill::save(self.storage.as_ref(), &backfills)?;
+            }
+        }
+
         Ok(self.schema.read().clone())
     }
 
+    /// List the [resource sets](self::resource) stored in this engine.
+    pub fn resource_sets(&self) -> Vec<self::resource::ResourceSet> {
+        self.resources.read().values().cloned().collect()
+    }
+
+    /// Get the resource set `name`, if it exists.
+    pub fn resource_set(&self, name: &str) -> Option<self::resource::ResourceSet> {
+        self.resources.read().get(name).cloned()
+    }
+
+    /// Create or replace the resource set `name`.
+    ///
+    /// The version of the set is incremented, and the analyzer of every
+    /// field whose schema analyzer refers to the set is rebuilt with the
+    /// new content. Queries parsed and documents written afterwards use the
+    /// new analyzers; documents already indexed are not re-analyzed.
+    ///
+    /// # Returns
+    ///
+    /// The stored [`ResourceSet`](self::resource::ResourceSet).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `name` is empty.
+    /// - An analyzer referring to the set cannot be built with the new
+    ///   content (e.g. invalid synonym rules or content of the wrong kind).
+    ///   The stored set is left unchanged in that case.
+    /// - Persisting the resource sets fails.
+    pub fn put_resource_set(
+        &self,
+        name: &str,
+        content: self::resource::ResourceContent,
+    ) -> Result<self::resource::ResourceSet> {
+        if name.is_empty() {
+            return Err(crate::error::LaurusError::invalid_argument(
+                "Resource set name must not be empty",
+            ));
+        }
+
+        let mut resources = self.resources.write();
+        let mut updated = resources.clone();
+        let version = resources.get(name).map_or(0, |set| set.version) + 1;
+        let set = self::resource::ResourceSet {
+            name: name.to_string(),
+            version,
+            content,
+        };
+        updated.insert(name.to_string(), set.clone());
+
+        // Build every affected analyzer before anything is persisted.
+        let analyzers = self.resource_analyzers(&updated, Some(name))?;
+        self::resource::save(self.storage.as_ref(), &updated)?;
+        *resources = updated;
+        self.register_field_analyzers(analyzers)?;
+
+        Ok(set)
+    }
+
+    /// Delete the resource set `name`.
+    ///
+    /// # Returns
+    ///
+    /// The deleted [`ResourceSet`](self::resource::ResourceSet).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the set does not exist, an analyzer in the
+    /// schema still refers to it, or persisting the resource sets fails.
+    pub fn delete_resource_set(&self, name: &str) -> Result<self::resource::ResourceSet> {
+        let mut resources = self.resources.write();
+        if !resources.contains_key(name) {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Resource set '{name}' does not exist"
+            )));
+        }
+        if let Some((analyzer_name, _)) = self
+            .schema
+            .read()
+            .analyzers
+            .iter()
+            .find(|(_, def)| def.resource_names().contains(&name))
+        {
+            return Err(crate::error::LaurusError::invalid_argument(format!(
+                "Resource set '{name}' is used by analyzer '{analyzer_name}'"
+            )));
+        }
+
+        let mut updated = resources.clone();
+        let set = updated.remove(name).expect("checked above");
+        self::resource::save(self.storage.as_ref(), &updated)?;
+        *resources = updated;
+        Ok(set)
+    }
+
+    /// Re-read the resource sets from storage and rebuild the analyzers
+    /// referring to them.
+    ///
+    /// Use this after the resource file was changed outside this engine,
+    /// for example by another engine sharing the same storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored resource sets cannot be read or an
+    /// analyzer cannot be built with them. The current analyzers are kept
+    /// in that case.
+    pub fn reload_resource_sets(&self) -> Result<Vec<self::resource::ResourceSet>> {
+        let mut resources = self.resources.write();
+        let updated = self::resource::load(self.storage.as_ref())?;
+        let analyzers = self.resource_analyzers(&updated, None)?;
+        *resources = updated;
+        self.register_field_analyzers(analyzers)?;
+        Ok(resources.values().cloned().collect())
+    }
+
+    /// Build the analyzers of every text field whose schema analyzers refer
+    /// to `changed`, or to any resource set when `changed` is `None`.
+    fn resource_analyzers(
+        &self,
+        resources: &BTreeMap<String, self::resource::ResourceSet>,
+        changed: Option<&str>,
+    ) -> Result<Vec<(String, FieldAnalyzers)>> {
+        let schema = self.schema.read();
+        let mut analyzers = Vec::new();
+        for (field, option) in &schema.fields {
+            let schema::FieldOption::Text(text_opt) = option else {
+                continue;
+            };
+            let names: Vec<&str> = text_opt
+                .analyzer_names()
+                .filter_map(|name| schema.analyzers.get(name))
+                .flat_map(|def| def.resource_names())
+                .collect();
+            let affected = match changed {
+                Some(changed) => names.contains(&changed),
+                None => !names.is_empty(),
+            };
+            if affected {
+                let field_analyzers =
+                    Self::resolve_text_analyzers(&schema, resources, field, text_opt)?;
+                analyzers.push((field.clone(), field_analyzers));
+            }
+        }
+        Ok(analyzers)
+    }
+
+    /// Register rebuilt field analyzers in the shared per-field analyzer.
+    fn register_field_analyzers(&self, analyzers: Vec<(String, FieldAnalyzers)>) -> Result<()> {
+        self.lexical.with_per_field_analyzer(|pfa| {
+            for (field, field_analyzers) in analyzers {
+                field_analyzers.register(pfa, &field, true);
+            }
+        })
+    }
+
     /// Resolve a [`LexicalSearchQuery`] into a concrete [`Query`] object.
     ///
     /// If the query is already an `Obj` variant, it is returned as-is.
@@ -710,25 +1859,128 @@ impl Engine {
     /// Get all documents (including chunks) by external ID.
     ///
     /// Only fields marked as stored in the schema are included in the
-    /// returned documents. If no documents match the given ID, an empty
-    /// `Vec` is returned (not an error).
+    /// returned documents. Expired documents are omitted. If no documents
+    /// match the given ID, an empty `Vec` is returned (not an error).
     ///
     /// # Parameters
     ///
-    /// - `id` - The external document identifier to look up.
+    /// - `id` - The external document identifier to look up.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the internal ID lookup or document retrieval fails.
+    pub async fn get_documents(&self, id: &str) -> Result<Vec<Document>> {
+        let mut doc_ids = self.lexical.find_doc_ids_by_term("_id", id)?;
+        let expired = self.expired_doc_ids(&doc_ids)?;
+        doc_ids.retain(|doc_id| !expired.contains(doc_id));
+        let mut docs = Vec::with_capacity(doc_ids.len());
+        for doc_id in doc_ids {
+            if let Some(doc) = self.get_document_by_internal_id(doc_id)? {
+                docs.push(doc);
+            }
+        }
+        Ok(docs)
+    }
+
+    /// Get the documents with the given external ID that are visible to
+    /// `identity`.
+    ///
+    /// Behaves like [`get_documents`](Self::get_documents), but when the
+    /// schema declares an [`acl_field`](Schema::acl_field), documents whose
+    /// ACL names none of the identity's principals are omitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the internal ID lookup or document retrieval fails.
+    pub async fn get_documents_as(
+        &self,
+        id: &str,
+        identity: &self::acl::Identity,
+    ) -> Result<Vec<Document>> {
+        let docs = self.get_documents(id).await?;
+        let Some(field) = self.schema.read().acl_field.clone() else {
+            return Ok(docs);
+        };
+        Ok(docs
+            .into_iter()
+            .filter(|doc| {
+                doc.fields
+                    .get(&field)
+                    .and_then(|v| v.as_text())
+                    .is_some_and(|acl| identity.can_access(acl))
+            })
+            .collect())
+    }
+
+    /// Get a handle scoping document operations to one tenant.
+    ///
+    /// See the [`tenant`](self::tenant) module for how documents of
+    /// different tenants are kept apart.
     ///
     /// # Errors
     ///
-    /// Returns an error if the internal ID lookup or document retrieval fails.
-    pub async fn get_documents(&self, id: &str) -> Result<Vec<Document>> {
-        let doc_ids = self.lexical.find_doc_ids_by_term("_id", id)?;
-        let mut docs = Vec::with_capacity(doc_ids.len());
-        for doc_id in doc_ids {
-            if let Some(doc) = self.get_document_by_internal_id(doc_id)? {
-                docs.push(doc);
+    /// Returns an error if the schema has no
+    /// [`tenant_field`](Schema::tenant_field), or if `key` is empty or
+    /// contains `/`.
+    pub fn tenant(&self, key: &str) -> Result<self::tenant::Tenant<'_>> {
+        let field = self.schema.read().tenant_field.clone().ok_or_else(|| {
+            crate::error::LaurusError::invalid_argument(
+                "Tenants require a tenant_field to be configured in the schema",
+            )
+        })?;
+        self::tenant::validate_key(key)?;
+        Ok(self::tenant::Tenant::new(self, field, key.to_string()))
+    }
+
+    /// Ensure a document written to an engine with a tenant field carries a
+    /// valid tenant key.
+    fn validate_tenant_key(&self, doc: &Document) -> Result<()> {
+        let Some(field) = self.schema.read().tenant_field.clone() else {
+            return Ok(());
+        };
+        match doc.fields.get(&field).and_then(|v| v.as_text()) {
+            Some(key) => self::tenant::validate_key(key),
+            None => Err(crate::error::LaurusError::invalid_argument(format!(
+                "Document must have a tenant key in field '{field}'"
+            ))),
+        }
+    }
+
+    /// Record the tenant usage of a document stored under `doc_id`.
+    fn track_tenant_usage(&self, doc_id: u64, stored_doc: &Document) {
+        let Some(field) = self.schema.read().tenant_field.clone() else {
+            return;
+        };
+        let mut usage = self.tenant_usage.lock();
+        match stored_doc.fields.get(&field).and_then(|v| v.as_text()) {
+            Some(tenant) => usage.insert(doc_id, tenant, self::tenant::stored_size(stored_doc)),
+            None => usage.remove(doc_id),
+        }
+    }
+
+    /// Rebuild the tenant usage from the live documents in the document
+    /// store.
+    fn load_tenant_usage(&self) -> Result<()> {
+        const BATCH_SIZE: u64 = 1000;
+
+        self.tenant_usage.lock().clear();
+        if self.schema.read().tenant_field.is_none() {
+            return Ok(());
+        }
+        let end = self.log.next_doc_id();
+        let mut start = 0;
+        while start < end {
+            let doc_ids: Vec<u64> = (start..end.min(start + BATCH_SIZE)).collect();
+            start += BATCH_SIZE;
+            let documents = self.log.get_documents_batch(&doc_ids)?;
+            let live = self.live_doc_ids(&documents)?;
+            for (doc_id, document) in &documents {
+                if live.contains(doc_id) {
+                    self.track_tenant_usage(*doc_id, &self.filter_stored_fields(document));
+                }
             }
         }
-        Ok(docs)
+        Ok(())
     }
 
     /// Check if a field should be stored based on the schema.
@@ -753,6 +2005,7 @@ impl Engine {
                 FieldOption::DateTime(o) => o.stored,
                 FieldOption::Geo(o) => o.stored,
                 FieldOption::Bytes(o) => o.stored,
+                FieldOption::Completion(o) => o.stored,
                 // Vector fields are always stored
                 FieldOption::Hnsw(_) | FieldOption::Flat(_) | FieldOption::Ivf(_) => true,
             }
@@ -822,9 +2075,54 @@ impl Engine {
         Ok(results)
     }
 
+    /// Create the analyzer named `analyzer_name` for `field`.
+    ///
+    /// Resolution order: built-in name → custom definition in
+    /// `schema.analyzers`, whose components may refer to `resources`.
+    fn resolve_field_analyzer(
+        schema: &Schema,
+        resources: &BTreeMap<String, self::resource::ResourceSet>,
+        field: &str,
+        analyzer_name: &str,
+    ) -> Result<Arc<dyn Analyzer>> {
+        use crate::analysis::analyzer::registry;
+
+        if let Ok(analyzer) = registry::create_analyzer_by_name(analyzer_name) {
+            return Ok(analyzer);
+        }
+        let def = schema.analyzers.get(analyzer_name).ok_or_else(|| {
+            crate::error::LaurusError::invalid_argument(format!(
+                "Unknown analyzer '{analyzer_name}' for field '{field}': \
+                 not a built-in and not defined in schema.analyzers"
+            ))
+        })?;
+        registry::create_analyzer_with_resources(analyzer_name, def, resources)
+    }
+
+    /// Create the index and search analyzers configured for the text field
+    /// `field`.
+    fn resolve_text_analyzers(
+        schema: &Schema,
+        resources: &BTreeMap<String, self::resource::ResourceSet>,
+        field: &str,
+        option: &crate::lexical::TextOption,
+    ) -> Result<FieldAnalyzers> {
+        let resolve = |name: &Option<String>| {
+            name.as_deref()
+                .map(|name| Self::resolve_field_analyzer(schema, resources, field, name))
+                .transpose()
+        };
+        Ok(FieldAnalyzers {
+            index: resolve(&option.analyzer)?,
+            search: resolve(&option.search_analyzer)?,
+            search_quote: resolve(&option.search_quote_analyzer)?,
+        })
+    }
+
     /// Split the unified schema into specialized configs.
     async fn split_schema(
         schema: &Schema,
+        resources: &BTreeMap<String, self::resource::ResourceSet>,
         analyzer: Option<Arc<dyn Analyzer>>,
         embedder: Option<Arc<dyn Embedder>>,
     ) -> Result<(LexicalIndexConfig, VectorIndexConfig)> {
@@ -848,33 +2146,29 @@ impl Engine {
             };
 
         // Register per-field analyzers declared in the schema.
-        // Resolution order: built-in name → custom definition in schema.analyzers.
         for (name, field_option) in &schema.fields {
-            if let schema::FieldOption::Text(text_opt) = field_option
-                && let Some(analyzer_name) = &text_opt.analyzer
-            {
-                let field_analyzer =
-                    match crate::analysis::analyzer::registry::create_analyzer_by_name(
-                        analyzer_name,
-                    ) {
-                        Ok(a) => a,
-                        Err(_) => {
-                            let def = schema.analyzers.get(analyzer_name).ok_or_else(|| {
-                                crate::error::LaurusError::invalid_argument(format!(
-                                    "Unknown analyzer '{analyzer_name}' for field '{name}': \
-                                 not a built-in and not defined in schema.analyzers"
-                                ))
-                            })?;
-                            crate::analysis::analyzer::registry::create_analyzer_from_definition(
-                                analyzer_name,
-                                def,
-                            )?
-                        }
-                    };
-                per_field_analyzer.add_analyzer(name, field_analyzer);
+            if let schema::FieldOption::Text(text_opt) = field_option {
+                Self::resolve_text_analyzers(schema, resources, name, text_opt)?.register(
+                    &per_field_analyzer,
+                    name,
+                    true,
+                );
             }
         }
 
+        // ACL entries are matched exactly, so they are only split on whitespace.
+        if let Some(acl_field) = &schema.acl_field {
+            use crate::analysis::analyzer::simple::SimpleAnalyzer;
+            use crate::analysis::tokenizer::whitespace::WhitespaceTokenizer;
+            per_field_analyzer.add_analyzer(
+                acl_field,
+                Arc::new(SimpleAnalyzer::new(Arc::new(WhitespaceTokenizer::new()))),
+            );
+        }
+        if let Some(tenant_field) = &schema.tenant_field {
+            per_field_analyzer.add_analyzer(tenant_field, Arc::new(KeywordAnalyzer::new()));
+        }
+
         let mut lexical_builder =
             LexicalIndexConfig::builder().analyzer(Arc::new(per_field_analyzer));
 
@@ -982,6 +2276,18 @@ impl Engine {
     /// When both lexical and vector search requests are present, both fetch
     /// limits are doubled (2x overfetch) to improve fusion quality.
     ///
+    /// If the schema declares an [`acl_field`](Schema::acl_field) and the
+    /// request carries an [`identity`](self::search::SearchRequest::identity),
+    /// documents not visible to it are excluded by a non-scoring filter on
+    /// both the lexical and the vector side, combined with any
+    /// `filter_query`.
+    ///
+    /// If the schema declares an [`expiry_field`](Schema::expiry_field),
+    /// expired candidates are dropped from both sides, and more candidates
+    /// are fetched in their place, so expired documents are invisible even
+    /// before [`purge_expired`](Self::purge_expired) deletes them and never
+    /// take the place of live matches in a page.
+    ///
     /// Results are paginated via `offset` and `limit` on the
     /// [`SearchRequest`](self::search::SearchRequest).
     ///
@@ -998,7 +2304,161 @@ impl Engine {
         &self,
         request: self::search::SearchRequest,
     ) -> Result<Vec<self::search::SearchResult>> {
-        // 0a. Resolve query to internal search components
+        let plan = self.plan_search(request).await?;
+        let hits = self.collect_hits(&plan, None)?;
+        plan.finish(hits, |ids| self.resolve_ids_and_documents_batch(ids))
+    }
+
+    /// Suggest completions of `prefix` from a completion field.
+    ///
+    /// Returns up to `limit` distinct inputs of committed documents that
+    /// start with `prefix`, ignoring case. Inputs matching the prefix
+    /// exactly come first, ordered by weight (highest first); if there are
+    /// fewer than `limit`, inputs within the field's
+    /// [`max_edits`](crate::lexical::core::field::CompletionOption::max_edits)
+    /// of the prefix fill the remaining slots, again by weight. Fuzzy
+    /// matching only applies once the prefix is at least
+    /// [`min_fuzzy_length`](crate::lexical::core::field::CompletionOption::min_fuzzy_length)
+    /// characters long.
+    ///
+    /// Inputs of expired documents are skipped. Suggestions are not
+    /// restricted by ACL; use [`suggest_as`](Self::suggest_as) to suggest
+    /// on behalf of an identity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `field` is not a completion field or the
+    /// completion FSTs cannot be read.
+    pub fn suggest(
+        &self,
+        field: &str,
+        prefix: &str,
+        limit: usize,
+    ) -> Result<Vec<crate::lexical::index::structures::completion::Suggestion>> {
+        self.suggest_in(field, prefix, limit, None, None)
+    }
+
+    /// Suggest completions of `prefix` from the documents visible to
+    /// `identity`.
+    ///
+    /// Behaves like [`suggest`](Self::suggest), but when the schema declares
+    /// an [`acl_field`](Schema::acl_field), inputs of documents whose ACL
+    /// names none of the identity's principals are skipped, as in
+    /// [`search`](Self::search).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `field` is not a completion field or the
+    /// completion FSTs cannot be read.
+    pub fn suggest_as(
+        &self,
+        field: &str,
+        prefix: &str,
+        limit: usize,
+        identity: &self::acl::Identity,
+    ) -> Result<Vec<crate::lexical::index::structures::completion::Suggestion>> {
+        self.suggest_in(field, prefix, limit, Some(identity), None)
+    }
+
+    /// Suggest completions of `prefix` from the unexpired documents visible
+    /// to `identity` that also match `filter`.
+    pub(crate) fn suggest_in(
+        &self,
+        field: &str,
+        prefix: &str,
+        limit: usize,
+        identity: Option<&self::acl::Identity>,
+        filter: Option<Box<dyn crate::lexical::query::Query>>,
+    ) -> Result<Vec<crate::lexical::index::structures::completion::Suggestion>> {
+        use crate::lexical::index::inverted::core::automaton::FuzzyPrefixAutomaton;
+        use crate::lexical::index::structures::completion::normalize;
+
+        let (option, acl_field) = {
+            let schema = self.schema.read();
+            match schema.fields.get(field) {
+                Some(schema::FieldOption::Completion(option)) => {
+                    (option.clone(), schema.acl_field.clone())
+                }
+                _ => {
+                    return Err(crate::error::LaurusError::invalid_argument(format!(
+                        "Field '{field}' is not a completion field"
+                    )));
+                }
+            }
+        };
+        let prefix = normalize(prefix);
+
+        // The same restriction as a search plan: the filter, narrowed to
+        // the identity's documents, without expired ones.
+        let mut plan = SearchPlan {
+            lexical_query: None,
+            vector_request: None,
+            filter_query: filter,
+            fusion: None,
+            offset: 0,
+            limit,
+        };
+        if let (Some(field), Some(identity)) = (acl_field, identity) {
+            plan.restrict(identity.filter_query(&field));
+        }
+        let allowed: Option<HashSet<u64>> = match plan.filter_query {
+            Some(filter) => Some(HashSet::from_iter(
+                self.lexical.find_doc_ids_by_query(filter)?,
+            )),
+            None => None,
+        };
+        let accept = |doc_id: u64| {
+            allowed
+                .as_ref()
+                .is_none_or(|allowed| allowed.contains(&doc_id))
+                && self
+                    .expired_doc_ids(&[doc_id])
+                    .is_ok_and(|expired| expired.is_empty())
+        };
+
+        let exact = FuzzyPrefixAutomaton::new(&prefix, 0, 0, false);
+        let mut suggestions = self.lexical.suggest(field, &exact, limit, &accept)?;
+        if suggestions.len() >= limit
+            || option.max_edits == 0
+            || prefix.chars().count() < option.min_fuzzy_length
+        {
+            return Ok(suggestions);
+        }
+
+        // Fuzzy matches include the exact ones, so ask for enough to fill
+        // the remaining slots after skipping them.
+        let fuzzy = FuzzyPrefixAutomaton::new(
+            &prefix,
+            option.max_edits,
+            option.prefix_length,
+            option.transpositions,
+        );
+        let fuzzy_suggestions =
+            self.lexical
+                .suggest(field, &fuzzy, limit + suggestions.len(), &accept)?;
+        let seen: std::collections::HashSet<String> =
+            suggestions.iter().map(|s| s.text.clone()).collect();
+        suggestions.extend(
+            fuzzy_suggestions
+                .into_iter()
+                .filter(|s| !seen.contains(&s.text)),
+        );
+        suggestions.truncate(limit);
+        Ok(suggestions)
+    }
+
+    /// Resolve a [`SearchRequest`](self::search::SearchRequest) into the
+    /// executable lexical query, vector request and filter.
+    ///
+    /// DSL parsing and query embedding happen here, so a plan can be
+    /// executed against several engines sharing the same schema (see
+    /// [`ShardedEngine`](self::sharded::ShardedEngine)) without repeating
+    /// that work.
+    pub(crate) async fn plan_search(
+        &self,
+        request: self::search::SearchRequest,
+    ) -> Result<SearchPlan> {
+        // Resolve query to internal search components
         //
         // When the query is a DSL string, parse it with UnifiedQueryParser to
         // extract both lexical and vector components. For other variants,
@@ -1012,6 +2472,7 @@ impl Engine {
             offset: request_offset,
             fusion_algorithm: request_fusion,
             filter_query: request_filter,
+            identity: request_identity,
             lexical_options,
             vector_options,
         } = request;
@@ -1046,83 +2507,24 @@ impl Engine {
                 )?,
             };
 
-        // 0b. Pre-process Filter
-        let (allowed_ids, lexical_query_override) = if let Some(filter_query) = &request_filter {
-            let req = crate::lexical::search::searcher::LexicalSearchRequest::new(
-                filter_query.clone_box(),
-            )
-            .limit(1_000_000)
-            .load_documents(false);
-
-            let filter_hits = self.lexical.search(req)?.hits;
-            let ids: Vec<u64> = filter_hits.into_iter().map(|h| h.doc_id).collect();
+        let fetch_count = request_offset.saturating_add(request_limit);
 
-            if ids.is_empty() {
-                return Ok(Vec::new());
+        let lexical_query = if let Some(lex_req) = &lexical_search_request {
+            let mut query = self.resolve_query(lex_req.query.clone())?;
+            if !lex_req.field_boosts.is_empty() {
+                query.apply_field_boosts(&lex_req.field_boosts);
             }
-
-            let new_lexical_query: Option<Box<dyn crate::lexical::query::Query>> =
-                if let Some(lex_req) = &lexical_search_request {
-                    use crate::lexical::query::boolean::BooleanQueryBuilder;
-                    let user_query = self.resolve_query(lex_req.query.clone())?;
-                    let bool_query = BooleanQueryBuilder::new()
-                        .must(user_query)
-                        .filter(filter_query.clone_box())
-                        .build();
-                    Some(Box::new(bool_query))
-                } else {
-                    None
-                };
-
-            (Some(ids), new_lexical_query)
-        } else {
-            (None, None)
-        };
-
-        // 1. Execute Lexical Search
-        let mut lexical_query_to_use = if lexical_query_override.is_some() {
-            lexical_query_override
-        } else if let Some(lex_req) = &lexical_search_request {
-            Some(self.resolve_query(lex_req.query.clone())?)
+            Some(query)
         } else {
             None
         };
 
-        if let Some(query) = &mut lexical_query_to_use
-            && let Some(lex_req) = &lexical_search_request
-            && !lex_req.field_boosts.is_empty()
-        {
-            query.apply_field_boosts(&lex_req.field_boosts);
-        }
-
-        let fetch_count = request_offset.saturating_add(request_limit);
-
-        let lexical_hits = if let Some(query) = &lexical_query_to_use {
-            let q = query.clone_box();
-            let overfetch_limit = if vector_search_request.is_some() {
-                fetch_count.saturating_mul(2)
-            } else {
-                fetch_count
-            };
-            let req = crate::lexical::search::searcher::LexicalSearchRequest::new(q)
-                .limit(overfetch_limit)
-                .load_documents(false);
-
-            self.lexical.search(req)?.hits
-        } else {
-            Vec::new()
-        };
-
-        // 2. Execute Vector Search
-        let vector_hits = if let Some(vector_req) = &vector_search_request {
+        let vector_request = if let Some(vector_req) = &vector_search_request {
             let mut vreq = vector_req.clone();
             if lexical_search_request.is_some() && vreq.params.limit < fetch_count.saturating_mul(2)
             {
                 vreq.params.limit = fetch_count.saturating_mul(2);
             }
-            if let Some(ids) = &allowed_ids {
-                vreq.params.allowed_ids = Some(ids.clone());
-            }
             // Embed Payloads into Vectors before searching.
             // NOTE: When using VectorQueryParser, query is already Vectors
             // at parse time, so this block is skipped. This fallback remains for
@@ -1166,191 +2568,362 @@ impl Engine {
                 vreq.query =
                     crate::vector::search::searcher::VectorSearchQuery::Vectors(query_vectors);
             }
-            self.vector.search(vreq)?.hits
+            Some(vreq)
         } else {
-            Vec::new()
+            None
         };
 
-        // 3. Fusion
-        if lexical_search_request.is_some() && vector_search_request.is_some() {
-            let algorithm = fusion_algorithm.unwrap_or(FusionAlgorithm::RRF { k: 60.0 });
-            let mut results = self.fuse_results(
-                lexical_hits,
-                vector_hits,
-                algorithm,
+        let fusion = if lexical_query.is_some() && vector_request.is_some() {
+            Some((
+                fusion_algorithm.unwrap_or(FusionAlgorithm::RRF { k: 60.0 }),
                 hybrid_mode,
-                fetch_count,
-            )?;
-            if request_offset > 0 {
-                results = results.into_iter().skip(request_offset).collect();
-            }
-            results.truncate(request_limit);
-            Ok(results)
-        } else if !vector_hits.is_empty() {
-            // Only vector results — batch-resolve external IDs and documents.
-            let paginated: Vec<_> = vector_hits
-                .into_iter()
-                .skip(request_offset)
-                .take(request_limit)
-                .collect();
-            let ids: Vec<u64> = paginated.iter().map(|h| h.doc_id).collect();
-            let resolved = self.resolve_ids_and_documents_batch(&ids)?;
-            let mut results = Vec::with_capacity(paginated.len());
-            for hit in paginated {
-                if let Some((external_id, document)) = resolved.get(&hit.doc_id) {
-                    results.push(SearchResult {
-                        id: external_id.clone(),
-                        score: hit.score,
-                        document: document.clone(),
-                    });
-                }
-            }
-            Ok(results)
+            ))
         } else {
-            // Only lexical results (or both empty)
-            let paginated: Vec<_> = lexical_hits
-                .into_iter()
-                .skip(request_offset)
-                .take(request_limit)
-                .collect();
-            let ids: Vec<u64> = paginated.iter().map(|h| h.doc_id).collect();
-            let resolved = self.resolve_ids_and_documents_batch(&ids)?;
-            let mut results = Vec::with_capacity(paginated.len());
-            for hit in paginated {
-                if let Some((external_id, document)) = resolved.get(&hit.doc_id) {
-                    results.push(SearchResult {
-                        id: external_id.clone(),
-                        score: hit.score,
-                        document: document.clone(),
-                    });
-                }
-            }
-            Ok(results)
+            None
+        };
+
+        let mut plan = SearchPlan {
+            lexical_query,
+            vector_request,
+            filter_query: request_filter,
+            fusion,
+            offset: request_offset,
+            limit: request_limit,
+        };
+
+        // The ACL filter is added after the query is resolved so that
+        // nothing in the request can widen it.
+        let acl_field = self.schema.read().acl_field.clone();
+        if let (Some(field), Some(identity)) = (acl_field, request_identity) {
+            plan.restrict(identity.filter_query(&field));
         }
+
+        Ok(plan)
     }
 
-    /// Combine results from lexical and vector engines.
-    fn fuse_results(
+    /// Execute a [`SearchPlan`] against this engine's stores and return the
+    /// raw, unpaginated hits.
+    ///
+    /// When `lexical_reader` is given, lexical scoring reads its term and
+    /// field statistics from it instead of this engine's own reader.
+    pub(crate) fn collect_hits(
         &self,
-        lexical_hits: Vec<crate::lexical::query::SearchHit>,
-        vector_hits: Vec<crate::vector::store::response::VectorHit>,
-        fusion: FusionAlgorithm,
-        mode: self::search::HybridMode,
-        limit: usize,
-    ) -> Result<Vec<SearchResult>> {
-        // Collect doc_id sets upfront for intersection filtering.
-        let lexical_ids: HashSet<u64> = lexical_hits.iter().map(|h| h.doc_id).collect();
-        let vector_ids: HashSet<u64> = vector_hits.iter().map(|h| h.doc_id).collect();
-
-        let mut fused_scores: HashMap<u64, (f32, Option<crate::data::Document>)> = HashMap::new();
-
-        match fusion {
-            FusionAlgorithm::RRF { k } => {
-                for (rank, hit) in lexical_hits.into_iter().enumerate() {
-                    let rrf_score = 1.0 / (k + (rank + 1) as f64);
-                    let entry = fused_scores
-                        .entry(hit.doc_id)
-                        .or_insert((0.0, hit.document));
-                    entry.0 += rrf_score as f32;
-                }
-                for (rank, hit) in vector_hits.into_iter().enumerate() {
-                    let rrf_score = 1.0 / (k + (rank + 1) as f64);
-                    let entry = fused_scores.entry(hit.doc_id).or_insert((0.0, None));
-                    entry.0 += rrf_score as f32;
-                }
+        plan: &SearchPlan,
+        lexical_reader: Option<Arc<dyn crate::lexical::reader::LexicalIndexReader>>,
+    ) -> Result<SearchHits> {
+        // 1. Pre-process Filter
+        //
+        // The filter is combined with the user query via `must` + `filter`
+        // for lexical search, and produces the allowed IDs for vector search.
+        let mut allowed_ids = None;
+        if plan.vector_request.is_some()
+            && let Some(filter_query) = &plan.filter_query
+        {
+            let ids = self
+                .lexical
+                .find_doc_ids_by_query(filter_query.clone_box())?;
+            if ids.is_empty() {
+                return Ok(SearchHits::default());
             }
-            FusionAlgorithm::WeightedSum {
-                lexical_weight,
-                vector_weight,
-            } => {
-                let lexical_min = lexical_hits
-                    .iter()
-                    .map(|h| h.score)
-                    .fold(f32::INFINITY, f32::min);
-                let lexical_max = lexical_hits
-                    .iter()
-                    .map(|h| h.score)
-                    .fold(f32::NEG_INFINITY, f32::max);
-
-                for hit in lexical_hits {
-                    let norm_score = if lexical_max > lexical_min {
-                        (hit.score - lexical_min) / (lexical_max - lexical_min)
-                    } else {
-                        1.0
-                    };
-                    let entry = fused_scores
-                        .entry(hit.doc_id)
-                        .or_insert((0.0, hit.document));
-                    entry.0 += norm_score * lexical_weight;
-                }
+            allowed_ids = Some(ids);
+        }
 
-                let vector_min = vector_hits
-                    .iter()
-                    .map(|h| h.score)
-                    .fold(f32::INFINITY, f32::min);
-                let vector_max = vector_hits
-                    .iter()
-                    .map(|h| h.score)
-                    .fold(f32::NEG_INFINITY, f32::max);
-
-                for hit in vector_hits {
-                    let norm_score = if vector_max > vector_min {
-                        (hit.score - vector_min) / (vector_max - vector_min)
-                    } else {
-                        1.0
-                    };
-                    let entry = fused_scores.entry(hit.doc_id).or_insert((0.0, None));
-                    entry.0 += norm_score * vector_weight;
-                }
+        let lexical_query = plan.lexical_query.as_ref().map(|user_query| {
+            let Some(filter_query) = &plan.filter_query else {
+                return user_query.clone_box();
+            };
+            Box::new(
+                crate::lexical::query::boolean::BooleanQueryBuilder::new()
+                    .must(user_query.clone_box())
+                    .filter(filter_query.clone_box())
+                    .build(),
+            ) as Box<dyn crate::lexical::query::Query>
+        });
+
+        // 2. Execute Lexical Search
+        let lexical_hits = if let Some(query) = lexical_query {
+            self.unexpired_hits(
+                plan.lexical_fetch_count(),
+                |limit| {
+                    let req = crate::lexical::search::searcher::LexicalSearchRequest::new(
+                        query.clone_box(),
+                    )
+                    .limit(limit)
+                    .load_documents(false);
+                    Ok(match &lexical_reader {
+                        Some(reader) => self.lexical.search_with_reader(req, reader.clone())?.hits,
+                        None => self.lexical.search(req)?.hits,
+                    })
+                },
+                |hit| hit.doc_id,
+            )?
+        } else {
+            Vec::new()
+        };
+
+        // 3. Execute Vector Search
+        let vector_hits = if let Some(vector_req) = &plan.vector_request {
+            let mut vreq = vector_req.clone();
+            if let Some(ids) = allowed_ids {
+                vreq.params.allowed_ids = Some(ids);
             }
-        }
+            self.unexpired_hits(
+                vector_req.params.limit,
+                |limit| {
+                    vreq.params.limit = limit;
+                    Ok(self.vector.search(vreq.clone())?.hits)
+                },
+                |hit| hit.doc_id,
+            )?
+        } else {
+            Vec::new()
+        };
 
-        // Intersection mode: keep only documents appearing in BOTH result sets.
-        if mode == self::search::HybridMode::Intersection {
-            fused_scores.retain(|id, _| lexical_ids.contains(id) && vector_ids.contains(id));
+        Ok(SearchHits {
+            lexical: lexical_hits,
+            vector: vector_hits,
+        })
+    }
+
+    /// Run `search` for `limit` hits and drop the expired ones, fetching
+    /// more until `limit` unexpired hits are found or no more candidates
+    /// come back.
+    ///
+    /// Only the returned candidates are checked for expiry, so the cost
+    /// does not depend on how many expired documents are still waiting for
+    /// [`purge_expired`](Self::purge_expired).
+    fn unexpired_hits<H>(
+        &self,
+        limit: usize,
+        mut search: impl FnMut(usize) -> Result<Vec<H>>,
+        doc_id: impl Fn(&H) -> u64,
+    ) -> Result<Vec<H>> {
+        let mut fetch = limit;
+        let mut fetched = 0;
+        loop {
+            let mut hits = search(fetch)?;
+            let found = hits.len();
+            let doc_ids: Vec<u64> = hits.iter().map(&doc_id).collect();
+            let expired = self.expired_doc_ids(&doc_ids)?;
+            hits.retain(|hit| !expired.contains(&doc_id(hit)));
+            if hits.len() >= limit || expired.is_empty() || found <= fetched || fetch == usize::MAX
+            {
+                hits.truncate(limit);
+                return Ok(hits);
+            }
+            fetched = found;
+            fetch = fetch.saturating_mul(2);
         }
+    }
+}
 
-        let mut intermediate: Vec<(u64, f32, Option<crate::data::Document>)> = fused_scores
-            .into_iter()
-            .map(|(doc_id, (score, document))| (doc_id, score, document))
-            .collect();
+/// A search request resolved against the schema, ready to be executed by
+/// [`Engine::collect_hits`].
+pub(crate) struct SearchPlan {
+    /// Resolved lexical query with field boosts applied.
+    lexical_query: Option<Box<dyn crate::lexical::query::Query>>,
+    /// Vector request with query payloads already embedded.
+    vector_request: Option<crate::vector::store::request::VectorSearchRequest>,
+    /// Filter restricting both lexical and vector candidates.
+    filter_query: Option<Box<dyn crate::lexical::query::Query>>,
+    /// Fusion settings; `Some` only when both lexical and vector parts exist.
+    fusion: Option<(FusionAlgorithm, self::search::HybridMode)>,
+    offset: usize,
+    limit: usize,
+}
+
+/// Raw lexical and vector hits collected for a [`SearchPlan`], each list
+/// ordered by descending score.
+#[derive(Default)]
+pub(crate) struct SearchHits {
+    pub(crate) lexical: Vec<crate::lexical::query::SearchHit>,
+    pub(crate) vector: Vec<crate::vector::store::response::VectorHit>,
+}
 
-        // Sort by fused score descending
-        intermediate.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+impl SearchPlan {
+    /// Narrow the plan to documents that also match `filter`.
+    pub(crate) fn restrict(&mut self, filter: Box<dyn crate::lexical::query::Query>) {
+        self.filter_query = Some(match self.filter_query.take() {
+            Some(existing) => {
+                use crate::lexical::query::boolean::BooleanQueryBuilder;
+                Box::new(
+                    BooleanQueryBuilder::new()
+                        .filter(existing)
+                        .filter(filter)
+                        .build(),
+                )
+            }
+            None => filter,
+        });
+    }
 
-        // Limit results
-        if intermediate.len() > limit {
-            intermediate.truncate(limit);
+    /// Number of lexical hits to fetch; doubled when vector hits are fused
+    /// in, to improve fusion quality.
+    pub(crate) fn lexical_fetch_count(&self) -> usize {
+        let fetch_count = self.offset.saturating_add(self.limit);
+        if self.vector_request.is_some() {
+            fetch_count.saturating_mul(2)
+        } else {
+            fetch_count
         }
+    }
+
+    /// Number of vector hits to fetch.
+    pub(crate) fn vector_fetch_count(&self) -> usize {
+        self.vector_request
+            .as_ref()
+            .map_or(0, |request| request.params.limit)
+    }
+
+    /// Fuse (for hybrid plans), paginate and resolve collected hits.
+    ///
+    /// `resolve` maps internal document IDs to their external ID and stored
+    /// document; hits it cannot resolve are dropped.
+    pub(crate) fn finish(
+        &self,
+        hits: SearchHits,
+        resolve: impl FnOnce(&[u64]) -> Result<HashMap<u64, (String, Option<Document>)>>,
+    ) -> Result<Vec<SearchResult>> {
+        let ranked = if let Some((fusion, mode)) = self.fusion {
+            fuse_hits(
+                hits.lexical,
+                hits.vector,
+                fusion,
+                mode,
+                self.offset.saturating_add(self.limit),
+            )
+        } else if !hits.vector.is_empty() {
+            hits.vector
+                .into_iter()
+                .map(|h| (h.doc_id, h.score, None))
+                .collect()
+        } else {
+            hits.lexical
+                .into_iter()
+                .map(|h| (h.doc_id, h.score, h.document))
+                .collect()
+        };
 
-        // Batch-resolve external IDs and fill missing documents.
-        // Collect IDs that need resolution (either missing external ID or
-        // missing document).
-        let ids_to_resolve: Vec<u64> = intermediate.iter().map(|(doc_id, _, _)| *doc_id).collect();
-        let resolved = self.resolve_ids_and_documents_batch(&ids_to_resolve)?;
+        let page: Vec<_> = ranked
+            .into_iter()
+            .skip(self.offset)
+            .take(self.limit)
+            .collect();
+        let ids: Vec<u64> = page.iter().map(|(doc_id, _, _)| *doc_id).collect();
+        let resolved = resolve(&ids)?;
 
-        let mut results = Vec::with_capacity(intermediate.len());
-        for (doc_id, score, document) in intermediate {
+        let mut results = Vec::with_capacity(page.len());
+        for (doc_id, score, document) in page {
             if let Some((external_id, resolved_doc)) = resolved.get(&doc_id) {
                 // Prefer the document already fetched by the lexical search;
                 // fall back to the batch-resolved copy.
-                let final_doc = if document.is_some() {
-                    document
-                } else {
-                    resolved_doc.clone()
-                };
                 results.push(SearchResult {
                     id: external_id.clone(),
                     score,
-                    document: final_doc,
+                    document: document.or_else(|| resolved_doc.clone()),
                 });
             }
         }
-
         Ok(results)
     }
 }
 
+/// Combine hits from lexical and vector search into a single list of
+/// `(doc_id, fused_score, document)` ordered by descending score.
+fn fuse_hits(
+    lexical_hits: Vec<crate::lexical::query::SearchHit>,
+    vector_hits: Vec<crate::vector::store::response::VectorHit>,
+    fusion: FusionAlgorithm,
+    mode: self::search::HybridMode,
+    limit: usize,
+) -> Vec<(u64, f32, Option<Document>)> {
+    // Collect doc_id sets upfront for intersection filtering.
+    let lexical_ids: HashSet<u64> = lexical_hits.iter().map(|h| h.doc_id).collect();
+    let vector_ids: HashSet<u64> = vector_hits.iter().map(|h| h.doc_id).collect();
+
+    let mut fused_scores: HashMap<u64, (f32, Option<Document>)> = HashMap::new();
+
+    match fusion {
+        FusionAlgorithm::RRF { k } => {
+            for (rank, hit) in lexical_hits.into_iter().enumerate() {
+                let rrf_score = 1.0 / (k + (rank + 1) as f64);
+                let entry = fused_scores
+                    .entry(hit.doc_id)
+                    .or_insert((0.0, hit.document));
+                entry.0 += rrf_score as f32;
+            }
+            for (rank, hit) in vector_hits.into_iter().enumerate() {
+                let rrf_score = 1.0 / (k + (rank + 1) as f64);
+                let entry = fused_scores.entry(hit.doc_id).or_insert((0.0, None));
+                entry.0 += rrf_score as f32;
+            }
+        }
+        FusionAlgorithm::WeightedSum {
+            lexical_weight,
+            vector_weight,
+        } => {
+            let lexical_min = lexical_hits
+                .iter()
+                .map(|h| h.score)
+                .fold(f32::INFINITY, f32::min);
+            let lexical_max = lexical_hits
+                .iter()
+                .map(|h| h.score)
+                .fold(f32::NEG_INFINITY, f32::max);
+
+            for hit in lexical_hits {
+                let norm_score = if lexical_max > lexical_min {
+                    (hit.score - lexical_min) / (lexical_max - lexical_min)
+                } else {
+                    1.0
+                };
+                let entry = fused_scores
+                    .entry(hit.doc_id)
+                    .or_insert((0.0, hit.document));
+                entry.0 += norm_score * lexical_weight;
+            }
+
+            let vector_min = vector_hits
+                .iter()
+                .map(|h| h.score)
+                .fold(f32::INFINITY, f32::min);
+            let vector_max = vector_hits
+                .iter()
+                .map(|h| h.score)
+                .fold(f32::NEG_INFINITY, f32::max);
+
+            for hit in vector_hits {
+                let norm_score = if vector_max > vector_min {
+                    (hit.score - vector_min) / (vector_max - vector_min)
+                } else {
+                    1.0
+                };
+                let entry = fused_scores.entry(hit.doc_id).or_insert((0.0, None));
+                entry.0 += norm_score * vector_weight;
+            }
+        }
+    }
+
+    // Intersection mode: keep only documents appearing in BOTH result sets.
+    if mode == self::search::HybridMode::Intersection {
+        fused_scores.retain(|id, _| lexical_ids.contains(id) && vector_ids.contains(id));
+    }
+
+    let mut intermediate: Vec<(u64, f32, Option<Document>)> = fused_scores
+        .into_iter()
+        .map(|(doc_id, (score, document))| (doc_id, score, document))
+        .collect();
+
+    // Sort by fused score descending
+    intermediate.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    // Limit results
+    if intermediate.len() > limit {
+        intermediate.truncate(limit);
+    }
+    intermediate
+}
+
 /// Builder for constructing an [`Engine`] with custom configuration.
 ///
 /// Use this when you need to specify a custom text analyzer or embedding
@@ -1378,6 +2951,10 @@ pub struct EngineBuilder {
     schema: Schema,
     analyzer: Option<Arc<dyn Analyzer>>,
     embedder: Option<Arc<dyn Embedder>>,
+    change_retention: usize,
+    tenant_quota: self::tenant::TenantQuota,
+    tenant_quotas: HashMap<String, self::tenant::TenantQuota>,
+    shard_id: u16,
 }
 
 impl EngineBuilder {
@@ -1388,6 +2965,10 @@ impl EngineBuilder {
             schema,
             analyzer: None,
             embedder: None,
+            change_retention: crate::store::changes::DEFAULT_CHANGE_RETENTION,
+            tenant_quota: self::tenant::TenantQuota::default(),
+            tenant_quotas: HashMap::new(),
+            shard_id: 0,
         }
     }
 
@@ -1415,6 +2996,97 @@ impl EngineBuilder {
         self
     }
 
+    /// Set how many mutation events are retained for [`Engine::changes`].
+    ///
+    /// Subscribers can resume from any sequence number inside this window.
+    /// A value of `0` disables retention; subscribers then only receive
+    /// mutations made after they subscribed. Defaults to
+    /// [`DEFAULT_CHANGE_RETENTION`](crate::store::changes::DEFAULT_CHANGE_RETENTION).
+    pub fn change_retention(mut self, max_events: usize) -> Self {
+        self.change_retention = max_events;
+        self
+    }
+
+    /// Set the quota of every tenant without a quota of its own.
+    ///
+    /// Defaults to [`TenantQuota::default`](self::tenant::TenantQuota), which
+    /// is unlimited.
+    pub fn tenant_quota(mut self, quota: self::tenant::TenantQuota) -> Self {
+        self.tenant_quota = quota;
+        self
+    }
+
+    /// Set the quota of one tenant, overriding the default quota.
+    pub fn tenant_quota_for(
+        mut self,
+        tenant: impl Into<String>,
+        quota: self::tenant::TenantQuota,
+    ) -> Self {
+        self.tenant_quotas.insert(tenant.into(), quota);
+        self
+    }
+
+    /// Set the shard ID recorded in the lexical and vector index configs.
+    ///
+    /// Used by [`ShardedEngine`](self::sharded::ShardedEngine) to tag the
+    /// segments of each shard. Defaults to `0`.
+    pub(crate) fn shard_id(mut self, shard_id: u16) -> Self {
+        self.shard_id = shard_id;
+        self
+    }
+
+    /// Ensure the schema's expiry field, if any, is an indexed and stored
+    /// `DateTime` field.
+    fn validate_expiry_field(schema: &Schema) -> Result<()> {
+        let Some(name) = &schema.expiry_field else {
+            return Ok(());
+        };
+        match schema.fields.get(name) {
+            Some(schema::FieldOption::DateTime(opt)) if opt.indexed && opt.stored => Ok(()),
+            _ => Err(crate::error::LaurusError::invalid_argument(format!(
+                "Expiry field '{name}' must be declared as an indexed and stored DateTime field"
+            ))),
+        }
+    }
+
+    /// Ensure the schema's ACL field, if any, is an indexed and stored
+    /// `Text` field without an analyzer of its own.
+    fn validate_acl_field(schema: &Schema) -> Result<()> {
+        let Some(name) = &schema.acl_field else {
+            return Ok(());
+        };
+        match schema.fields.get(name) {
+            Some(schema::FieldOption::Text(opt))
+                if opt.indexed && opt.stored && opt.analyzer.is_none() =>
+            {
+                Ok(())
+            }
+            _ => Err(crate::error::LaurusError::invalid_argument(format!(
+                "ACL field '{name}' must be declared as an indexed and stored Text field \
+                 without an analyzer"
+            ))),
+        }
+    }
+
+    /// Ensure the schema's tenant field, if any, is an indexed and stored
+    /// `Text` field without an analyzer of its own.
+    fn validate_tenant_field(schema: &Schema) -> Result<()> {
+        let Some(name) = &schema.tenant_field else {
+            return Ok(());
+        };
+        match schema.fields.get(name) {
+            Some(schema::FieldOption::Text(opt))
+                if opt.indexed && opt.stored && opt.analyzer.is_none() =>
+            {
+                Ok(())
+            }
+            _ => Err(crate::error::LaurusError::invalid_argument(format!(
+                "Tenant field '{name}' must be declared as an indexed and stored Text field \
+                 without an analyzer"
+            ))),
+        }
+    }
+
     /// Build the [`Engine`].
     ///
     /// Creates the lexical store, vector store, and document log (WAL),
@@ -1423,11 +3095,27 @@ impl EngineBuilder {
     ///
     /// # Errors
     ///
-    /// Returns an error if storage initialization, index creation, WAL
-    /// opening, or recovery replay fails.
+    /// Returns an error if the schema's expiry, ACL or tenant field is
+    /// invalid, or if storage initialization, index creation, WAL opening,
+    /// recovery replay, or loading the tenant usage fails.
     pub async fn build(self) -> Result<Engine> {
-        let (lexical_config, vector_config) =
-            Engine::split_schema(&self.schema, self.analyzer, self.embedder).await?;
+        Self::validate_expiry_field(&self.schema)?;
+        Self::validate_acl_field(&self.schema)?;
+        Self::validate_tenant_field(&self.schema)?;
+
+        let options = BuildOptions {
+            analyzer: self.analyzer.clone(),
+            embedder: self.embedder.clone(),
+            change_retention: self.change_retention,
+            tenant_quota: self.tenant_quota,
+            tenant_quotas: self.tenant_quotas,
+        };
+        let resources = self::resource::load(self.storage.as_ref())?;
+        let (mut lexical_config, mut vector_config) =
+            Engine::split_schema(&self.schema, &resources, self.analyzer, self.embedder).await?;
+        let LexicalIndexConfig::Inverted(inverted_config) = &mut lexical_config;
+        inverted_config.shard_id = self.shard_id;
+        vector_config.shard_id = self.shard_id;
 
         let lexical_storage = Arc::new(PrefixedStorage::new("lexical", self.storage.clone()));
         let vector_storage = Arc::new(PrefixedStorage::new("vector", self.storage.clone()));
@@ -1437,20 +3125,27 @@ impl EngineBuilder {
         let lexical = LexicalStore::new(lexical_storage, lexical_config)?;
         let vector = VectorStore::new(vector_storage, vector_config)?;
 
-        let log = Arc::new(DocumentLog::new(
-            self.storage,
-            "engine.wal",
-            document_storage,
-        )?);
+        let log = Arc::new(
+            DocumentLog::new(self.storage.clone(), WAL_PATH, document_storage)?
+                .with_change_retention(self.change_retention),
+        );
 
         let engine = Engine {
             schema: RwLock::new(self.schema),
             lexical,
             vector,
             log,
+            batch_lock: tokio::sync::Mutex::new(()),
+            backfills: Mutex::new(self::backfill::load(self.storage.as_ref())?),
+            resources: RwLock::new(resources),
+            options,
+            tenant_usage: Mutex::new(self::tenant::TenantUsage::default()),
+            tenant_lock: tokio::sync::Mutex::new(()),
+            storage: self.storage,
         };
 
         engine.recover().await?;
+        engine.load_tenant_usage()?;
 
         Ok(engine)
     }
@@ -0,0 +1,87 @@
+//! Document-level security.
+//!
+//! A schema can declare an [`acl_field`](super::schema::Schema::acl_field):
+//! a `Text` field listing, separated by whitespace, the principals (user or
+//! group names) allowed to see each document. Searches and lookups made
+//! with an [`Identity`] only return documents whose ACL names at least one
+//! of the identity's principals.
+//!
+//! In [`Engine::search`](super::Engine::search) the restriction is a
+//! non-scoring filter applied to both the lexical and the vector side,
+//! independently of the query, so it cannot be lifted from the query DSL.
+//! Documents without an ACL value are visible to no identity. Requests
+//! without an identity are not restricted.
+
+use crate::lexical::query::Query;
+use crate::lexical::query::boolean::BooleanQueryBuilder;
+use crate::lexical::query::term::TermQuery;
+
+/// The principals a search or lookup is made on behalf of.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Identity {
+    principals: Vec<String>,
+}
+
+impl Identity {
+    /// Create an identity from user and group names.
+    ///
+    /// Empty names are ignored. Principals are compared case-sensitively
+    /// with the whitespace-separated entries of the ACL field, so a name
+    /// containing whitespace never matches.
+    pub fn new<I, S>(principals: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            principals: principals
+                .into_iter()
+                .map(Into::into)
+                .filter(|principal: &String| !principal.is_empty())
+                .collect(),
+        }
+    }
+
+    /// The user and group names of this identity.
+    pub fn principals(&self) -> &[String] {
+        &self.principals
+    }
+
+    /// Whether a document with the given ACL value is visible.
+    pub(crate) fn can_access(&self, acl: &str) -> bool {
+        acl.split_whitespace()
+            .any(|entry| self.principals.iter().any(|principal| principal == entry))
+    }
+
+    /// Query matching the documents whose ACL in `field` names one of the
+    /// principals. Matches nothing when the identity has no principals.
+    pub(crate) fn filter_query(&self, field: &str) -> Box<dyn Query> {
+        let mut builder = BooleanQueryBuilder::new().minimum_should_match(1);
+        for principal in &self.principals {
+            builder = builder.should(Box::new(TermQuery::new(field, principal.as_str())));
+        }
+        Box::new(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_access_matches_any_principal() {
+        let identity = Identity::new(["alice", "engineering"]);
+        assert!(identity.can_access("bob engineering"));
+        assert!(identity.can_access("alice"));
+        assert!(!identity.can_access("bob  sales"));
+        assert!(!identity.can_access("Engineering"));
+        assert!(!identity.can_access(""));
+    }
+
+    #[test]
+    fn test_empty_principals_are_ignored() {
+        let identity = Identity::new(["", "alice"]);
+        assert_eq!(identity.principals(), ["alice".to_string()]);
+        assert!(!Identity::default().can_access("alice"));
+    }
+}
@@ -0,0 +1,220 @@
+//! Backfilling a newly added field for existing documents.
+//!
+//! [`Engine::add_field`](super::Engine::add_field) only affects documents
+//! written after the field was added. When a field is added with
+//! [`Engine::add_field_with_backfill`](super::Engine::add_field_with_backfill),
+//! the engine also records a [`BackfillProgress`] covering every document
+//! that existed at that point. Each call to
+//! [`Engine::backfill_batch`](super::Engine::backfill_batch) then reads the
+//! next range of those documents back from the document store, copies the
+//! value of the source field into the new field (for example `body` into an
+//! embedded `body_vec`), and re-indexes the document through the same
+//! WAL-backed path as [`Engine::update_by_query`](super::Engine::update_by_query).
+//!
+//! Progress is checkpointed in the engine storage after every batch, so a
+//! backfill interrupted by a restart resumes where it stopped.
+//! [`BackfillTask`] drives a backfill to completion in the background.
+
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+#[cfg(feature = "native")]
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "native")]
+use crate::error::LaurusError;
+use crate::error::Result;
+use crate::storage::Storage;
+
+#[cfg(feature = "native")]
+use super::Engine;
+#[cfg(feature = "native")]
+use super::by_query::CancellationToken;
+
+/// Default number of document IDs scanned per backfill batch.
+pub const DEFAULT_BACKFILL_BATCH_SIZE: usize = 500;
+
+/// Storage file holding the progress of every backfill.
+const BACKFILL_PATH: &str = "backfill.json";
+
+/// Progress of backfilling one field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackfillProgress {
+    /// The field being backfilled.
+    pub field: String,
+    /// The field whose stored value is copied into [`field`](Self::field).
+    pub source_field: String,
+    /// Number of live documents when the backfill started.
+    pub total: u64,
+    /// Number of documents re-indexed so far.
+    pub processed: u64,
+    /// `true` once every document has been scanned.
+    pub completed: bool,
+    /// Internal ID of the last document existing when the backfill started.
+    high_water_doc_id: u64,
+    /// Internal ID scanning resumes from.
+    next_doc_id: u64,
+}
+
+impl BackfillProgress {
+    pub(crate) fn new(field: &str, source_field: &str, total: u64, next_doc_id: u64) -> Self {
+        Self {
+            field: field.to_string(),
+            source_field: source_field.to_string(),
+            total,
+            processed: 0,
+            completed: next_doc_id == 0,
+            high_water_doc_id: next_doc_id.saturating_sub(1),
+            next_doc_id: 0,
+        }
+    }
+
+    /// The next range of internal IDs to scan, or `None` once all IDs have
+    /// been scanned.
+    pub(crate) fn next_range(&self, batch_size: usize) -> Option<std::ops::RangeInclusive<u64>> {
+        if self.completed || self.next_doc_id > self.high_water_doc_id {
+            return None;
+        }
+        let end = self
+            .next_doc_id
+            .saturating_add(batch_size.max(1) as u64 - 1)
+            .min(self.high_water_doc_id);
+        Some(self.next_doc_id..=end)
+    }
+
+    /// Record that every ID up to and including `last_doc_id` was scanned.
+    pub(crate) fn advance(&mut self, last_doc_id: u64, processed: u64) {
+        self.processed += processed;
+        self.next_doc_id = last_doc_id + 1;
+        self.completed = self.next_doc_id > self.high_water_doc_id;
+    }
+
+    /// Fraction of the scanned ID range, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f64 {
+        if self.completed {
+            return 1.0;
+        }
+        self.next_doc_id as f64 / (self.high_water_doc_id + 1) as f64
+    }
+}
+
+/// Load the progress of every backfill from `storage`.
+pub(crate) fn load(storage: &dyn Storage) -> Result<BTreeMap<String, BackfillProgress>> {
+    if !storage.file_exists(BACKFILL_PATH) {
+        return Ok(BTreeMap::new());
+    }
+    let mut data = Vec::new();
+    storage.open_input(BACKFILL_PATH)?.read_to_end(&mut data)?;
+    let entries: Vec<BackfillProgress> = serde_json::from_slice(&data)?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| (entry.field.clone(), entry))
+        .collect())
+}
+
+/// Persist the progress of every backfill to `storage`.
+pub(crate) fn save(
+    storage: &dyn Storage,
+    backfills: &BTreeMap<String, BackfillProgress>,
+) -> Result<()> {
+    if backfills.is_empty() {
+        if storage.file_exists(BACKFILL_PATH) {
+            storage.delete_file(BACKFILL_PATH)?;
+        }
+        return Ok(());
+    }
+    let entries: Vec<&BackfillProgress> = backfills.values().collect();
+    let data = serde_json::to_vec(&entries)?;
+    let mut output = storage.create_output(BACKFILL_PATH)?;
+    output.write_all(&data)?;
+    output.flush_and_sync()?;
+    output.close()
+}
+
+/// Handle to a backfill running in the background.
+///
+/// Dropping the handle does **not** stop the task; call
+/// [`stop`](Self::stop) to cancel it or [`wait`](Self::wait) to wait for
+/// completion.
+#[cfg(feature = "native")]
+#[derive(Debug)]
+pub struct BackfillTask {
+    token: CancellationToken,
+    handle: tokio::task::JoinHandle<Result<BackfillProgress>>,
+}
+
+#[cfg(feature = "native")]
+impl BackfillTask {
+    /// Spawn a task that backfills `field` until it is complete.
+    ///
+    /// Re-indexed documents are written to the WAL but not committed; they
+    /// become visible to search after the next [`Engine::commit`].
+    ///
+    /// Must be called from within a Tokio runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - The engine owning the backfill.
+    /// * `field` - The field being backfilled.
+    /// * `batch_size` - Number of document IDs scanned per batch.
+    pub fn spawn(engine: Arc<Engine>, field: &str, batch_size: usize) -> Self {
+        let token = CancellationToken::new();
+        let task_token = token.clone();
+        let field = field.to_string();
+        let handle = tokio::spawn(async move {
+            loop {
+                let progress = engine.backfill_batch(&field, batch_size).await?;
+                if progress.completed || task_token.is_cancelled() {
+                    return Ok(progress);
+                }
+            }
+        });
+        Self { token, handle }
+    }
+
+    /// Wait for the backfill to finish and return its final progress.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a batch failed or the task panicked.
+    pub async fn wait(self) -> Result<BackfillProgress> {
+        self.handle
+            .await
+            .map_err(|e| LaurusError::other(format!("Backfill task failed: {e}")))?
+    }
+
+    /// Stop the task after its current batch and wait for it to exit.
+    ///
+    /// The backfill can be resumed later from its checkpoint.
+    pub async fn stop(self) {
+        self.token.cancel();
+        let _ = self.handle.await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ranges_cover_all_ids() {
+        let mut progress = BackfillProgress::new("vec", "body", 5, 5);
+        assert_eq!(progress.next_range(2), Some(0..=1));
+        progress.advance(1, 2);
+        assert_eq!(progress.next_range(2), Some(2..=3));
+        progress.advance(3, 2);
+        assert_eq!(progress.next_range(2), Some(4..=4));
+        progress.advance(4, 1);
+        assert!(progress.completed);
+        assert_eq!(progress.next_range(2), None);
+        assert_eq!(progress.processed, 5);
+    }
+
+    #[test]
+    fn test_empty_index_is_completed() {
+        let progress = BackfillProgress::new("vec", "body", 0, 0);
+        assert!(progress.completed);
+        assert_eq!(progress.fraction(), 1.0);
+    }
+}
@@ -0,0 +1,133 @@
+//! Types for bulk mutations driven by a query.
+//!
+//! [`Engine::delete_by_query`](super::Engine::delete_by_query) and
+//! [`Engine::update_by_query`](super::Engine::update_by_query) page through
+//! the internal document IDs matching a lexical query, one batch at a time.
+//! Each batch goes through the same WAL-backed delete / upsert path as the
+//! single-document APIs and is committed before the next page is read, so
+//! memory and WAL size stay bounded by the batch size and a crash in the
+//! middle of a run leaves the index in a recoverable state.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Default number of documents processed per batch.
+pub const DEFAULT_BY_QUERY_BATCH_SIZE: usize = 1000;
+
+/// Cooperative cancellation handle for long-running engine operations.
+///
+/// Cloning the token yields another handle to the same flag, so a caller
+/// can keep one clone and pass the other into the operation. Cancellation
+/// is checked between batches; the batch in flight always completes.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a new token in the non-cancelled state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of every operation observing this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` once [`cancel`](Self::cancel) has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Options controlling a delete-by-query or update-by-query run.
+#[derive(Debug, Clone)]
+pub struct ByQueryOptions {
+    /// Number of matching documents mutated per batch.
+    /// Defaults to [`DEFAULT_BY_QUERY_BATCH_SIZE`]. A value of `0` is
+    /// treated as `1`.
+    pub batch_size: usize,
+    /// Upper bound on the number of documents to process.
+    /// `None` processes every match.
+    pub max_docs: Option<usize>,
+    /// Optional cancellation token checked before each batch.
+    pub cancellation: Option<CancellationToken>,
+}
+
+impl Default for ByQueryOptions {
+    fn default() -> Self {
+        Self {
+            batch_size: DEFAULT_BY_QUERY_BATCH_SIZE,
+            max_docs: None,
+            cancellation: None,
+        }
+    }
+}
+
+impl ByQueryOptions {
+    /// Set the batch size.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Limit the number of documents processed.
+    pub fn max_docs(mut self, max_docs: usize) -> Self {
+        self.max_docs = Some(max_docs);
+        self
+    }
+
+    /// Attach a cancellation token.
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Returns `true` if the attached token (if any) has been cancelled.
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(|token| token.is_cancelled())
+    }
+}
+
+/// Outcome of a delete-by-query or update-by-query run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ByQueryResponse {
+    /// Number of committed documents that matched the query.
+    pub matched: u64,
+    /// Number of documents actually deleted or updated.
+    pub processed: u64,
+    /// Number of batches executed.
+    pub batches: u64,
+    /// `true` if the run stopped early because of cancellation.
+    pub cancelled: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancellation_token_shared_between_clones() {
+        let token = CancellationToken::new();
+        let observer = token.clone();
+        assert!(!observer.is_cancelled());
+
+        token.cancel();
+        assert!(observer.is_cancelled());
+    }
+
+    #[test]
+    fn test_options_is_cancelled() {
+        let options = ByQueryOptions::default();
+        assert!(!options.is_cancelled());
+
+        let token = CancellationToken::new();
+        let options = ByQueryOptions::default().cancellation(token.clone());
+        assert!(!options.is_cancelled());
+        token.cancel();
+        assert!(options.is_cancelled());
+    }
+}
@@ -0,0 +1,119 @@
+//! Change feeds scoped to a caller.
+//!
+//! [`Engine::changes`](super::Engine::changes) reports every mutation with
+//! the full document, as needed to replicate an index.
+//! [`Engine::changes_as`](super::Engine::changes_as) wraps the same feed in
+//! a [`ScopedChangeSubscription`] that only reports what the caller could
+//! read with [`Engine::get_documents_as`](super::Engine::get_documents_as):
+//!
+//! - Upserted documents only keep their stored fields.
+//! - On an engine with an [`acl_field`](super::schema::Schema::acl_field),
+//!   upserts of documents the identity cannot access are skipped. Deletes
+//!   do not carry the document, so a delete is only reported for a document
+//!   whose upsert was reported by the same subscription.
+//! - Through a [`Tenant`](super::tenant::Tenant) handle, only mutations of
+//!   the tenant's documents are reported, under their tenant-local IDs.
+
+use std::collections::HashSet;
+
+use crate::data::DataValue;
+use crate::error::Result;
+use crate::store::changes::{ChangeEvent, ChangeKind, ChangeSubscription};
+use crate::store::log::SeqNumber;
+
+use super::acl::Identity;
+
+/// A [`ChangeSubscription`] filtered to the documents visible to an
+/// [`Identity`].
+///
+/// Created by [`Engine::changes_as`](super::Engine::changes_as) or
+/// [`Tenant::changes_as`](super::tenant::Tenant::changes_as).
+#[derive(Debug)]
+pub struct ScopedChangeSubscription {
+    inner: ChangeSubscription,
+    /// Fields kept in upserted documents.
+    stored_fields: HashSet<String>,
+    /// The ACL field and the identity it is checked against.
+    acl: Option<(String, Identity)>,
+    /// Prefix of the internal IDs of the tenant's documents.
+    tenant_prefix: Option<String>,
+    /// Documents whose upsert was reported, so that their delete is too.
+    visible: HashSet<u64>,
+}
+
+impl ScopedChangeSubscription {
+    pub(crate) fn new(
+        inner: ChangeSubscription,
+        stored_fields: HashSet<String>,
+        acl: Option<(String, Identity)>,
+        tenant_prefix: Option<String>,
+    ) -> Self {
+        Self {
+            inner,
+            stored_fields,
+            acl,
+            tenant_prefix,
+            visible: HashSet::new(),
+        }
+    }
+
+    /// Wait for the next event visible to the identity.
+    ///
+    /// Returns `Ok(None)` once the feed has been dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscriber fell so far behind that live
+    /// events were dropped, as [`ChangeSubscription::next`] does.
+    pub async fn next(&mut self) -> Result<Option<ChangeEvent>> {
+        while let Some(event) = self.inner.next().await? {
+            if let Some(event) = self.scope(event) {
+                return Ok(Some(event));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Sequence number of the last event read from the feed, including
+    /// events that were skipped.
+    pub fn last_seq(&self) -> SeqNumber {
+        self.inner.last_seq()
+    }
+
+    fn scope(&mut self, mut event: ChangeEvent) -> Option<ChangeEvent> {
+        if let Some(prefix) = &self.tenant_prefix {
+            event.external_id = event.external_id.strip_prefix(prefix.as_str())?.to_string();
+        }
+        match &mut event.kind {
+            ChangeKind::Upsert { document } => {
+                if let Some((field, identity)) = &self.acl {
+                    let allowed = document
+                        .fields
+                        .get(field)
+                        .and_then(|v| v.as_text())
+                        .is_some_and(|acl| identity.can_access(acl));
+                    if !allowed {
+                        // A replaced document may no longer be visible.
+                        self.visible.remove(&event.doc_id);
+                        return None;
+                    }
+                    self.visible.insert(event.doc_id);
+                }
+                document
+                    .fields
+                    .retain(|name, _| self.stored_fields.contains(name));
+                if self.tenant_prefix.is_some()
+                    && let Some(DataValue::Text(id)) = document.fields.get_mut("_id")
+                {
+                    id.clone_from(&event.external_id);
+                }
+            }
+            ChangeKind::Delete => {
+                if self.acl.is_some() && !self.visible.remove(&event.doc_id) {
+                    return None;
+                }
+            }
+        }
+        Some(event)
+    }
+}
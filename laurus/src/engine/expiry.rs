@@ -0,0 +1,105 @@
+//! Periodic background removal of expired documents.
+//!
+//! Documents past their [`expiry_field`](super::schema::Schema::expiry_field)
+//! timestamp are hidden from search immediately, but they keep occupying the
+//! indexes until they are deleted. [`ExpiryTask`] runs
+//! [`Engine::purge_expired`] on a fixed interval so that expired documents
+//! are physically removed through the WAL-backed delete path without any
+//! caller involvement.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::Engine;
+use super::by_query::{ByQueryOptions, ByQueryResponse, CancellationToken};
+use crate::error::Result;
+
+/// Handle to a running expiry task.
+///
+/// Dropping the handle does **not** stop the task; call
+/// [`stop`](Self::stop) to cancel it.
+#[derive(Debug)]
+pub struct ExpiryTask {
+    token: CancellationToken,
+    stop_tx: tokio::sync::oneshot::Sender<()>,
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl ExpiryTask {
+    /// Spawn a task that purges expired documents every `interval`.
+    ///
+    /// The first purge runs after one full interval has elapsed. Deletions
+    /// are written to the WAL but not committed; they become durable with
+    /// the next [`Engine::commit`]. Errors are logged and the task keeps
+    /// running.
+    ///
+    /// Must be called from within a Tokio runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `engine` - The engine to purge.
+    /// * `interval` - Time between purge runs.
+    pub fn spawn(engine: Arc<Engine>, interval: Duration) -> Self {
+        Self::spawn_with(interval, move |options| {
+            let engine = Arc::clone(&engine);
+            async move { engine.purge_expired(options).await }
+        })
+    }
+
+    /// Spawn a task that calls `purge` every `interval`.
+    ///
+    /// Like [`spawn`](Self::spawn), for callers that do not own the engine
+    /// through an `Arc`, e.g. because they replace it while the task runs.
+    /// `purge` receives the options to pass to [`Engine::purge_expired`],
+    /// whose cancellation token is cancelled by [`stop`](Self::stop).
+    ///
+    /// Must be called from within a Tokio runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Time between purge runs.
+    /// * `purge` - Purges the expired documents of the engine.
+    pub fn spawn_with<F, Fut>(interval: Duration, purge: F) -> Self
+    where
+        F: Fn(ByQueryOptions) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<ByQueryResponse>> + Send + 'static,
+    {
+        let token = CancellationToken::new();
+        let task_token = token.clone();
+        let (stop_tx, mut stop_rx) = tokio::sync::oneshot::channel();
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            // The first tick completes immediately; skip it.
+            ticker.tick().await;
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    _ = &mut stop_rx => break,
+                }
+                let options = ByQueryOptions::default().cancellation(task_token.clone());
+                match purge(options).await {
+                    Ok(response) if response.processed > 0 => {
+                        log::debug!("Purged {} expired documents", response.processed);
+                    }
+                    Ok(_) => {}
+                    Err(e) => log::warn!("Failed to purge expired documents: {e}"),
+                }
+            }
+        });
+        Self {
+            token,
+            stop_tx,
+            handle,
+        }
+    }
+
+    /// Stop the task and wait for it to exit.
+    ///
+    /// A purge in flight stops after its current batch.
+    pub async fn stop(self) {
+        self.token.cancel();
+        let _ = self.stop_tx.send(());
+        let _ = self.handle.await;
+    }
+}
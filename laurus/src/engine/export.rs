@@ -0,0 +1,226 @@
+//! Logical export and import of documents as JSON Lines.
+//!
+//! [`Engine::export`](super::Engine::export) returns a [`DocumentExport`]
+//! that reads every live stored document back from the document store in
+//! batches of internal IDs, paired with its external ID. Unlike a
+//! [`snapshot`](super::snapshot), the output does not depend on the on-disk
+//! format, so it can be imported by another laurus version or into an index
+//! with different analyzers. With `include_vectors`, the value of every
+//! vector field is replaced by the indexed vector, so documents are not
+//! embedded again on import.
+//!
+//! Each line of the JSONL format is one [`ExportedDocument`]. Chunks that
+//! share an external ID are exported as separate lines and
+//! [`Engine::import`](super::Engine::import) adds them back as chunks.
+
+use std::io::{BufRead, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::{DataValue, Document};
+use crate::error::{LaurusError, Result};
+
+use super::Engine;
+
+/// Default number of document IDs read per export batch and number of
+/// documents committed per import batch.
+pub const DEFAULT_EXPORT_BATCH_SIZE: usize = 1000;
+
+/// A document and its external ID, as written on one line of an export.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedDocument {
+    /// External document ID.
+    pub id: String,
+    /// Stored fields of the document.
+    pub document: Document,
+}
+
+/// An export of the live documents of an [`Engine`] in progress.
+///
+/// Created by [`Engine::export`]. Documents written after the export was
+/// created are not included.
+pub struct DocumentExport<'a> {
+    engine: &'a Engine,
+    include_vectors: bool,
+    /// Internal ID reading resumes from.
+    next_doc_id: u64,
+    /// Internal ID reading stops after, exclusive.
+    end_doc_id: u64,
+    total: u64,
+    exported: u64,
+}
+
+impl std::fmt::Debug for DocumentExport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DocumentExport")
+            .field("include_vectors", &self.include_vectors)
+            .field("next_doc_id", &self.next_doc_id)
+            .field("end_doc_id", &self.end_doc_id)
+            .field("exported", &self.exported)
+            .finish()
+    }
+}
+
+impl<'a> DocumentExport<'a> {
+    pub(crate) fn new(engine: &'a Engine, include_vectors: bool, total: u64) -> Self {
+        Self {
+            engine,
+            include_vectors,
+            next_doc_id: 0,
+            end_doc_id: engine.log.next_doc_id(),
+            total,
+            exported: 0,
+        }
+    }
+
+    /// Number of live documents when the export started.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Number of documents exported so far.
+    pub fn exported(&self) -> u64 {
+        self.exported
+    }
+
+    /// Read the next batch of live documents.
+    ///
+    /// A batch may be empty when every scanned ID was deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_size` - Number of document IDs scanned per batch.
+    ///
+    /// # Returns
+    ///
+    /// `None` once every document has been read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a document or vector cannot be read.
+    pub fn next_batch(&mut self, batch_size: usize) -> Result<Option<Vec<ExportedDocument>>> {
+        if self.next_doc_id >= self.end_doc_id {
+            return Ok(None);
+        }
+        let end = self
+            .next_doc_id
+            .saturating_add(batch_size.max(1) as u64)
+            .min(self.end_doc_id);
+        let doc_ids: Vec<u64> = (self.next_doc_id..end).collect();
+        self.next_doc_id = end;
+
+        let documents = self.engine.log.get_documents_batch(&doc_ids)?;
+        let live = self.engine.live_doc_ids(&documents)?;
+        let vectors = if self.include_vectors {
+            Some(self.engine.vector.reader()?)
+        } else {
+            None
+        };
+        let schema = self.engine.schema();
+
+        let mut batch = Vec::new();
+        for doc_id in doc_ids {
+            let Some(document) = documents.get(&doc_id).filter(|_| live.contains(&doc_id)) else {
+                continue;
+            };
+            let mut document = self.engine.filter_stored_fields(document);
+            let Some(DataValue::Text(id)) = document.fields.remove("_id") else {
+                continue;
+            };
+            if let Some(reader) = &vectors {
+                for (field, vector) in reader.get_vectors_for_doc(doc_id)? {
+                    if schema.fields.get(&field).is_some_and(|f| f.is_vector()) {
+                        document
+                            .fields
+                            .insert(field, DataValue::Vector(vector.data.to_vec()));
+                    }
+                }
+            }
+            batch.push(ExportedDocument { id, document });
+        }
+        self.exported += batch.len() as u64;
+        Ok(Some(batch))
+    }
+
+    /// Write every remaining document to `writer` as JSON Lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Destination of the JSONL stream.
+    /// * `batch_size` - Number of document IDs scanned per batch.
+    /// * `on_progress` - Called after every batch with the number of
+    ///   documents exported so far.
+    ///
+    /// # Returns
+    ///
+    /// The number of exported documents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a document cannot be read or written.
+    pub fn write_jsonl<W: Write>(
+        mut self,
+        writer: &mut W,
+        batch_size: usize,
+        mut on_progress: impl FnMut(u64),
+    ) -> Result<u64> {
+        while let Some(batch) = self.next_batch(batch_size)? {
+            for document in &batch {
+                serde_json::to_writer(&mut *writer, document)?;
+                writer.write_all(b"\n")?;
+            }
+            on_progress(self.exported);
+        }
+        writer.flush()?;
+        Ok(self.exported)
+    }
+}
+
+/// Read JSON Lines written by [`DocumentExport::write_jsonl`] into `engine`.
+///
+/// Every `batch_size` documents are written to the WAL as one batch record
+/// and committed together.
+pub(crate) async fn import_jsonl<R: BufRead>(
+    engine: &Engine,
+    reader: R,
+    batch_size: usize,
+    mut on_progress: impl FnMut(u64),
+) -> Result<u64> {
+    let batch_size = batch_size.max(1);
+    let mut imported = 0;
+    let mut transaction = engine.begin();
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let exported: ExportedDocument = serde_json::from_str(&line).map_err(|e| {
+            LaurusError::invalid_argument(format!(
+                "Invalid document on line {}: {e}",
+                line_number + 1
+            ))
+        })?;
+        transaction.add_document(&exported.id, exported.document);
+        if transaction.len() == batch_size {
+            imported +=
+                import_batch(engine, std::mem::replace(&mut transaction, engine.begin())).await?;
+            on_progress(imported);
+        }
+    }
+    if !transaction.is_empty() || imported == 0 {
+        imported += import_batch(engine, transaction).await?;
+        on_progress(imported);
+    }
+    Ok(imported)
+}
+
+/// Apply and commit one batch of imported documents.
+async fn import_batch(
+    engine: &Engine,
+    transaction: super::transaction::Transaction<'_>,
+) -> Result<u64> {
+    let count = transaction.len() as u64;
+    transaction.commit().await?;
+    engine.commit().await?;
+    Ok(count)
+}
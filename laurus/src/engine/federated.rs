@@ -0,0 +1,281 @@
+//! Federated search across several independent engines.
+//!
+//! [`federated_search`] runs one [`SearchRequest`] per index concurrently
+//! and combines the ranked lists into a single one. The indexes may have
+//! different schemas, so each query is written for its own index. Because
+//! scores from different indexes are not comparable, they are fused with a
+//! [`FusionAlgorithm`]:
+//!
+//! - [`RRF`](FusionAlgorithm::RRF): a result at 1-based rank `r` of its
+//!   index contributes `weight / (k + r)`.
+//! - [`WeightedSum`](FusionAlgorithm::WeightedSum): the scores of each index
+//!   are min-max normalized to `[0, 1]` and multiplied by the index weight.
+//!   The variant's lexical and vector weights only apply inside each
+//!   index's own hybrid search.
+//!
+//! Every result is tagged with the name of the index it came from.
+
+use std::collections::HashMap;
+
+use crate::data::Document;
+use crate::error::{LaurusError, Result};
+
+use super::Engine;
+use super::search::{FusionAlgorithm, SearchRequest, SearchResult};
+
+/// One index taking part in a federated search.
+pub struct FederatedQuery<'a> {
+    /// Name reported as the source of this query's results.
+    pub index: String,
+    /// Engine to search.
+    pub engine: &'a Engine,
+    /// Search to run on [`engine`](Self::engine). Its `limit` and `offset`
+    /// are replaced by the page of the federated search.
+    pub request: SearchRequest,
+    /// Multiplier applied to the fused scores of this index. Defaults to
+    /// `1.0`.
+    pub weight: f32,
+}
+
+impl<'a> FederatedQuery<'a> {
+    /// Create a query with weight `1.0`.
+    pub fn new(index: impl Into<String>, engine: &'a Engine, request: SearchRequest) -> Self {
+        Self {
+            index: index.into(),
+            engine,
+            request,
+            weight: 1.0,
+        }
+    }
+
+    /// Set the weight of this index.
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+/// Fusion and pagination of a federated search.
+#[derive(Debug, Clone, Copy)]
+pub struct FederatedSearchOptions {
+    /// How the ranked lists of the indexes are combined. Defaults to
+    /// [`RRF { k: 60.0 }`](FusionAlgorithm::RRF).
+    pub fusion: FusionAlgorithm,
+    /// Maximum number of results to return. Defaults to `10`.
+    pub limit: usize,
+    /// Number of fused results to skip. Defaults to `0`.
+    pub offset: usize,
+}
+
+impl Default for FederatedSearchOptions {
+    fn default() -> Self {
+        Self {
+            fusion: FusionAlgorithm::RRF { k: 60.0 },
+            limit: 10,
+            offset: 0,
+        }
+    }
+}
+
+/// A result of a federated search.
+#[derive(Debug, Clone)]
+pub struct FederatedSearchResult {
+    /// Name of the index the document was found in.
+    pub index: String,
+    /// External document ID.
+    pub id: String,
+    /// Fused score.
+    pub score: f32,
+    /// The stored document, if loaded.
+    pub document: Option<Document>,
+}
+
+/// Search several engines concurrently and fuse their results.
+///
+/// Each index ranks its first `offset + limit` documents and the requested
+/// page is cut from the fused list. Results of queries with the same index
+/// name and document ID are merged, summing their contributions.
+///
+/// # Errors
+///
+/// Returns an error if a weight is negative or not finite, or if the
+/// search fails on any index.
+pub async fn federated_search(
+    queries: Vec<FederatedQuery<'_>>,
+    options: FederatedSearchOptions,
+) -> Result<Vec<FederatedSearchResult>> {
+    let fetch_count = options.offset.saturating_add(options.limit);
+
+    let mut searches = Vec::with_capacity(queries.len());
+    let mut sources = Vec::with_capacity(queries.len());
+    for query in queries {
+        if !query.weight.is_finite() || query.weight < 0.0 {
+            return Err(LaurusError::invalid_argument(format!(
+                "Weight of index '{}' must be a non-negative number, got {}",
+                query.index, query.weight
+            )));
+        }
+        let mut request = query.request;
+        request.limit = fetch_count;
+        request.offset = 0;
+        searches.push(query.engine.search(request));
+        sources.push((query.index, query.weight));
+    }
+
+    let mut ranked = Vec::with_capacity(sources.len());
+    for ((index, weight), results) in sources
+        .into_iter()
+        .zip(futures::future::join_all(searches).await)
+    {
+        ranked.push((index, weight, results?));
+    }
+
+    Ok(fuse(ranked, options.fusion)
+        .into_iter()
+        .skip(options.offset)
+        .take(options.limit)
+        .collect())
+}
+
+/// Fuse the weighted ranked lists of several indexes, best first.
+fn fuse(
+    ranked: Vec<(String, f32, Vec<SearchResult>)>,
+    fusion: FusionAlgorithm,
+) -> Vec<FederatedSearchResult> {
+    let mut fused: Vec<FederatedSearchResult> = Vec::new();
+    let mut positions: HashMap<(String, String), usize> = HashMap::new();
+
+    for (index, weight, results) in ranked {
+        let (min, max) = results
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), r| {
+                (min.min(r.score), max.max(r.score))
+            });
+        for (rank, result) in results.into_iter().enumerate() {
+            let score = match fusion {
+                FusionAlgorithm::RRF { k } => (1.0 / (k + (rank + 1) as f64)) as f32,
+                FusionAlgorithm::WeightedSum { .. } => {
+                    if max > min {
+                        (result.score - min) / (max - min)
+                    } else {
+                        1.0
+                    }
+                }
+            } * weight;
+
+            match positions.entry((index.clone(), result.id.clone())) {
+                std::collections::hash_map::Entry::Occupied(entry) => {
+                    let existing = &mut fused[*entry.get()];
+                    existing.score += score;
+                    if existing.document.is_none() {
+                        existing.document = result.document;
+                    }
+                }
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    entry.insert(fused.len());
+                    fused.push(FederatedSearchResult {
+                        index: index.clone(),
+                        id: result.id,
+                        score,
+                        document: result.document,
+                    });
+                }
+            }
+        }
+    }
+
+    // Stable sort: ties keep the order of the queries and their ranks.
+    fused.sort_by(|a, b| b.score.total_cmp(&a.score));
+    fused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results(scores: &[(&str, f32)]) -> Vec<SearchResult> {
+        scores
+            .iter()
+            .map(|(id, score)| SearchResult {
+                id: id.to_string(),
+                score: *score,
+                document: None,
+            })
+            .collect()
+    }
+
+    fn ids(fused: &[FederatedSearchResult]) -> Vec<(&str, &str)> {
+        fused
+            .iter()
+            .map(|r| (r.index.as_str(), r.id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_rrf_interleaves_and_tags_indexes() {
+        let fused = fuse(
+            vec![
+                ("docs".into(), 1.0, results(&[("a", 9.0), ("b", 5.0)])),
+                ("code".into(), 1.0, results(&[("a", 0.3), ("c", 0.1)])),
+            ],
+            FusionAlgorithm::RRF { k: 60.0 },
+        );
+        // Same ID in different indexes stays separate.
+        assert_eq!(
+            ids(&fused),
+            vec![("docs", "a"), ("code", "a"), ("docs", "b"), ("code", "c")]
+        );
+        assert!((fused[0].score - 1.0 / 61.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_weights_reorder_indexes() {
+        let fused = fuse(
+            vec![
+                ("docs".into(), 1.0, results(&[("a", 9.0)])),
+                ("code".into(), 3.0, results(&[("x", 0.1), ("y", 0.05)])),
+            ],
+            FusionAlgorithm::RRF { k: 60.0 },
+        );
+        assert_eq!(
+            ids(&fused),
+            vec![("code", "x"), ("code", "y"), ("docs", "a")]
+        );
+    }
+
+    #[test]
+    fn test_weighted_sum_normalizes_per_index() {
+        let fused = fuse(
+            vec![
+                (
+                    "docs".into(),
+                    1.0,
+                    results(&[("a", 100.0), ("b", 50.0), ("c", 0.0)]),
+                ),
+                ("code".into(), 0.8, results(&[("x", 0.9), ("y", 0.1)])),
+            ],
+            FusionAlgorithm::WeightedSum {
+                lexical_weight: 0.5,
+                vector_weight: 0.5,
+            },
+        );
+        let scores: Vec<(&str, f32)> = fused.iter().map(|r| (r.id.as_str(), r.score)).collect();
+        assert_eq!(scores[0], ("a", 1.0));
+        assert_eq!(scores[1].0, "x");
+        assert!((scores[1].1 - 0.8).abs() < 1e-6);
+        assert_eq!(scores[2], ("b", 0.5));
+    }
+
+    #[test]
+    fn test_same_index_and_id_are_merged() {
+        let fused = fuse(
+            vec![
+                ("docs".into(), 1.0, results(&[("a", 2.0), ("b", 1.0)])),
+                ("docs".into(), 1.0, results(&[("b", 0.9)])),
+            ],
+            FusionAlgorithm::RRF { k: 60.0 },
+        );
+        assert_eq!(ids(&fused), vec![("docs", "b"), ("docs", "a")]);
+        assert!((fused[0].score - (1.0 / 62.0 + 1.0 / 61.0)).abs() < 1e-6);
+    }
+}
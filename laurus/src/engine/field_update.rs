@@ -0,0 +1,196 @@
+//! Changing the definition of an existing field with an online rebuild.
+//!
+//! Postings analyzed with an old analyzer and vectors produced by an old
+//! embedder or index type are spread across every segment and cannot be
+//! converted in place. [`Engine::update_field`](super::Engine::update_field)
+//! therefore starts a [`FieldUpdate`], which builds a complete second engine
+//! with the updated schema in separate storage:
+//!
+//! 1. [`FieldUpdate::copy_batch`] reads the next range of live documents
+//!    back from the source engine's document store and indexes them into
+//!    the new engine, analyzing and embedding them with the new definition.
+//! 2. [`FieldUpdate::catch_up`] replays the mutations the source engine
+//!    logged in the meantime from its [change feed](super::Engine::changes).
+//! 3. [`FieldUpdate::finish`] replays the remaining mutations, commits the
+//!    new engine and returns it.
+//!
+//! The source engine keeps serving reads and writes throughout, so queries
+//! use the old definition until the caller replaces the source engine with
+//! the returned one. Mutations made after `finish` started are not carried
+//! over, so writes must be stopped while it runs. Mutations are replayed
+//! from the change feed's retained window; call `catch_up` regularly so that
+//! none are evicted before they are replayed.
+
+use crate::error::Result;
+use crate::store::log::{LogRecord, SeqNumber};
+
+use super::Engine;
+use super::backfill::{BackfillProgress, DEFAULT_BACKFILL_BATCH_SIZE};
+use super::schema::Schema;
+
+/// A field definition change being built by
+/// [`Engine::update_field`](super::Engine::update_field).
+pub struct FieldUpdate {
+    field: String,
+    /// The engine being built with the updated schema.
+    target: Engine,
+    /// Progress of copying the source documents; `source_field` is the
+    /// updated field itself.
+    progress: BackfillProgress,
+    /// Sequence number of the last source mutation replayed into `target`.
+    applied_seq: SeqNumber,
+}
+
+impl FieldUpdate {
+    pub(crate) fn new(
+        field: &str,
+        target: Engine,
+        progress: BackfillProgress,
+        applied_seq: SeqNumber,
+    ) -> Self {
+        Self {
+            field: field.to_string(),
+            target,
+            progress,
+            applied_seq,
+        }
+    }
+
+    /// The field being updated.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// The schema of the new engine.
+    pub fn schema(&self) -> Schema {
+        self.target.schema()
+    }
+
+    /// Progress of copying the source documents.
+    pub fn progress(&self) -> &BackfillProgress {
+        &self.progress
+    }
+
+    /// Sequence number of the last source mutation replayed so far.
+    pub fn applied_seq(&self) -> SeqNumber {
+        self.applied_seq
+    }
+
+    /// Copy the next batch of live documents from `source`.
+    ///
+    /// Calling this once the copy is complete is a no-op.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The engine [`update_field`](Engine::update_field) was
+    ///   called on.
+    /// * `batch_size` - Number of document IDs scanned per batch.
+    ///
+    /// # Returns
+    ///
+    /// The progress after the batch. Copying is done once
+    /// [`completed`](BackfillProgress::completed) is `true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a document cannot be read or indexed.
+    pub async fn copy_batch(
+        &mut self,
+        source: &Engine,
+        batch_size: usize,
+    ) -> Result<&BackfillProgress> {
+        let Some(range) = self.progress.next_range(batch_size) else {
+            return Ok(&self.progress);
+        };
+
+        let doc_ids: Vec<u64> = range.clone().collect();
+        let documents = source.log.get_documents_batch(&doc_ids)?;
+        let live = source.live_doc_ids(&documents)?;
+
+        let mut copied = 0;
+        for doc_id in doc_ids {
+            if let Some(document) = documents.get(&doc_id)
+                && live.contains(&doc_id)
+            {
+                self.target
+                    .restore_document(doc_id, document.clone(), true, true)
+                    .await?;
+                copied += 1;
+            }
+        }
+
+        self.progress.advance(*range.end(), copied);
+        Ok(&self.progress)
+    }
+
+    /// Replay the mutations `source` logged since the last replay.
+    ///
+    /// Mutations are keyed by internal document ID, so replaying one for a
+    /// document that was also copied is harmless.
+    ///
+    /// # Returns
+    ///
+    /// The number of replayed mutations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if mutations after [`applied_seq`](Self::applied_seq)
+    /// are no longer retained by the source change feed, or if a mutation
+    /// cannot be applied.
+    pub async fn catch_up(&mut self, source: &Engine) -> Result<usize> {
+        let events = source.log.changes().retained_since(self.applied_seq)?;
+        let count = events.len();
+        for event in events {
+            let record = LogRecord::from(event);
+            self.target
+                .apply_log_entry(record.seq, record.entry, 0, 0)
+                .await?;
+            self.applied_seq = record.seq;
+        }
+        Ok(count)
+    }
+
+    /// Complete the update and return the new engine.
+    ///
+    /// Copies any remaining documents, replays the remaining mutations,
+    /// carries over the record of completed backfills and commits the new
+    /// engine. It continues the source engine's document IDs and sequence
+    /// numbers, so change feed consumers can keep following it from their
+    /// current position. No mutations may be made to `source` while this
+    /// runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if copying, replaying or committing fails.
+    pub async fn finish(mut self, source: &Engine) -> Result<Engine> {
+        while !self.progress.completed {
+            self.copy_batch(source, DEFAULT_BACKFILL_BATCH_SIZE).await?;
+        }
+        self.catch_up(source).await?;
+
+        let target = self.target;
+        target.log.set_next_doc_id(source.log.next_doc_id());
+        target.log.advance_seq(source.log.last_seq());
+        {
+            let backfills = source.backfills.lock().clone();
+            let mut target_backfills = target.backfills.lock();
+            *target_backfills = backfills;
+            super::backfill::save(target.storage.as_ref(), &target_backfills)?;
+        }
+        target.commit().await?;
+        // Nothing before this point can be replayed from the new engine.
+        target.log.changes().restore(&[], target.log.last_seq());
+
+        Ok(target)
+    }
+}
+
+impl std::fmt::Debug for FieldUpdate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FieldUpdate")
+            .field("field", &self.field)
+            .field("progress", &self.progress)
+            .field("applied_seq", &self.applied_seq)
+            .finish()
+    }
+}
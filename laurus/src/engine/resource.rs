@@ -0,0 +1,130 @@
+//! Named resource sets used by schema analyzers.
+//!
+//! Synonym rules, stop word lists and character mappings change more often
+//! than the rest of an analyzer. Instead of repeating them inline in every
+//! [`AnalyzerDefinition`](super::schema::analyzer::AnalyzerDefinition), a
+//! `synonym_graph` or `stop` token filter or a `mapping` char filter can
+//! name a [`ResourceSet`] stored in the engine storage.
+//!
+//! [`Engine::put_resource_set`](super::Engine::put_resource_set) replaces
+//! the content of a set, bumps its version and rebuilds every field
+//! analyzer that refers to it, so queries parsed afterwards use the new
+//! content without reopening the engine. Documents already indexed keep the
+//! terms produced by the content in effect when they were written; new
+//! synonyms are still found for them through query-time expansion.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::storage::Storage;
+
+/// Storage file holding every resource set.
+const RESOURCES_PATH: &str = "resources.json";
+
+/// The content of a [`ResourceSet`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResourceContent {
+    /// Synonym rules in the Solr syntax, for `synonym_graph` token filters.
+    Synonyms {
+        /// One rule per entry, e.g. `"ml, machine learning"`.
+        rules: Vec<String>,
+    },
+    /// A stop word list, for `stop` token filters.
+    StopWords {
+        /// The words to remove.
+        words: Vec<String>,
+    },
+    /// A character mapping, for `mapping` char filters.
+    Mapping {
+        /// Key-value pairs for replacement.
+        mapping: HashMap<String, String>,
+    },
+}
+
+impl ResourceContent {
+    /// The name of the content kind, as used in the serialized `type` tag.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ResourceContent::Synonyms { .. } => "synonyms",
+            ResourceContent::StopWords { .. } => "stop_words",
+            ResourceContent::Mapping { .. } => "mapping",
+        }
+    }
+}
+
+/// A named, versioned resource referenced by schema analyzers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceSet {
+    /// The name analyzer components refer to.
+    pub name: String,
+    /// Starts at 1 and is incremented on every update.
+    pub version: u64,
+    /// The resource content.
+    pub content: ResourceContent,
+}
+
+/// Load every resource set from `storage`.
+pub(crate) fn load(storage: &dyn Storage) -> Result<BTreeMap<String, ResourceSet>> {
+    if !storage.file_exists(RESOURCES_PATH) {
+        return Ok(BTreeMap::new());
+    }
+    let mut data = Vec::new();
+    storage.open_input(RESOURCES_PATH)?.read_to_end(&mut data)?;
+    let entries: Vec<ResourceSet> = serde_json::from_slice(&data)?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| (entry.name.clone(), entry))
+        .collect())
+}
+
+/// Persist every resource set to `storage`.
+pub(crate) fn save(storage: &dyn Storage, resources: &BTreeMap<String, ResourceSet>) -> Result<()> {
+    if resources.is_empty() {
+        if storage.file_exists(RESOURCES_PATH) {
+            storage.delete_file(RESOURCES_PATH)?;
+        }
+        return Ok(());
+    }
+    let entries: Vec<&ResourceSet> = resources.values().collect();
+    let data = serde_json::to_vec(&entries)?;
+    let mut output = storage.create_output(RESOURCES_PATH)?;
+    output.write_all(&data)?;
+    output.flush_and_sync()?;
+    output.close()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::storage::memory::{MemoryStorage, MemoryStorageConfig};
+
+    #[test]
+    fn test_save_and_load() {
+        let storage: Arc<dyn Storage> =
+            Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+        assert!(load(storage.as_ref()).unwrap().is_empty());
+
+        let mut resources = BTreeMap::new();
+        resources.insert(
+            "stop".to_string(),
+            ResourceSet {
+                name: "stop".into(),
+                version: 2,
+                content: ResourceContent::StopWords {
+                    words: vec!["the".into()],
+                },
+            },
+        );
+        save(storage.as_ref(), &resources).unwrap();
+        assert_eq!(load(storage.as_ref()).unwrap(), resources);
+
+        save(storage.as_ref(), &BTreeMap::new()).unwrap();
+        assert!(!storage.file_exists(RESOURCES_PATH));
+    }
+}
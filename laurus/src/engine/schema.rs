@@ -8,7 +8,8 @@ use self::analyzer::AnalyzerDefinition;
 use self::embedder::EmbedderDefinition;
 
 use crate::lexical::core::field::{
-    BooleanOption, BytesOption, DateTimeOption, FloatOption, GeoOption, IntegerOption, TextOption,
+    BooleanOption, BytesOption, CompletionOption, DateTimeOption, FloatOption, GeoOption,
+    IntegerOption, TextOption,
 };
 use crate::vector::core::field::{FlatOption, HnswOption, IvfOption};
 
@@ -32,6 +33,36 @@ pub struct Schema {
     /// Default fields for search.
     #[serde(default)]
     pub default_fields: Vec<String>,
+    /// Name of a `DateTime` field holding each document's expiry timestamp.
+    ///
+    /// When set, documents whose expiry is at or before the current time are
+    /// hidden from search and retrieval, and can be physically removed with
+    /// [`Engine::purge_expired`](crate::engine::Engine::purge_expired). The
+    /// field must be declared in [`fields`](Self::fields) as an indexed and
+    /// stored `DateTime` field. Documents without a value never expire.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry_field: Option<String>,
+    /// Name of a `Text` field listing the principals allowed to see each
+    /// document.
+    ///
+    /// The field holds whitespace-separated user or group names. When set,
+    /// searches and lookups made with an
+    /// [`Identity`](crate::engine::acl::Identity) only return documents
+    /// whose ACL names one of the identity's principals. The field must be
+    /// declared in [`fields`](Self::fields) as an indexed and stored `Text`
+    /// field without an analyzer; the engine indexes it with a
+    /// case-sensitive whitespace tokenizer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acl_field: Option<String>,
+    /// Name of a `Text` field holding the tenant each document belongs to.
+    ///
+    /// When set, documents are written and read through
+    /// [`Engine::tenant`](crate::engine::Engine::tenant) handles that scope
+    /// every operation to one tenant key. The field must be declared in
+    /// [`fields`](Self::fields) as an indexed and stored `Text` field without
+    /// an analyzer; the engine indexes the whole value as a single term.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_field: Option<String>,
 }
 
 impl Schema {
@@ -41,6 +72,9 @@ impl Schema {
             embedders: HashMap::new(),
             fields: HashMap::new(),
             default_fields: Vec::new(),
+            expiry_field: None,
+            acl_field: None,
+            tenant_field: None,
         }
     }
 
@@ -81,6 +115,8 @@ pub enum FieldOption {
     Geo(GeoOption),
     /// Bytes field options.
     Bytes(BytesOption),
+    /// Completion (suggestion) field options.
+    Completion(CompletionOption),
     /// HNSW vector index options.
     Hnsw(HnswOption),
     /// Flat vector index options.
@@ -106,6 +142,7 @@ impl FieldOption {
                 | Self::DateTime(_)
                 | Self::Geo(_)
                 | Self::Bytes(_)
+                | Self::Completion(_)
         )
     }
 
@@ -141,6 +178,9 @@ impl FieldOption {
             )),
             Self::Geo(o) => Some(crate::lexical::core::field::FieldOption::Geo(o.clone())),
             Self::Bytes(o) => Some(crate::lexical::core::field::FieldOption::Bytes(o.clone())),
+            Self::Completion(o) => Some(crate::lexical::core::field::FieldOption::Completion(
+                o.clone(),
+            )),
             _ => None,
         }
     }
@@ -152,6 +192,9 @@ pub struct SchemaBuilder {
     embedders: HashMap<String, EmbedderDefinition>,
     fields: HashMap<String, FieldOption>,
     default_fields: Vec<String>,
+    expiry_field: Option<String>,
+    acl_field: Option<String>,
+    tenant_field: Option<String>,
 }
 
 impl SchemaBuilder {
@@ -201,6 +244,14 @@ impl SchemaBuilder {
         self.add_field(name, FieldOption::Bytes(option.into()))
     }
 
+    pub fn add_completion_field(
+        self,
+        name: impl Into<String>,
+        option: impl Into<CompletionOption>,
+    ) -> Self {
+        self.add_field(name, FieldOption::Completion(option.into()))
+    }
+
     pub fn add_hnsw_field(self, name: impl Into<String>, option: impl Into<HnswOption>) -> Self {
         self.add_field(name, FieldOption::Hnsw(option.into()))
     }
@@ -241,12 +292,46 @@ impl SchemaBuilder {
         self
     }
 
+    /// Designate the `DateTime` field that holds document expiry timestamps.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The field name (must also be added as a `DateTime` field).
+    pub fn expiry_field(mut self, name: impl Into<String>) -> Self {
+        self.expiry_field = Some(name.into());
+        self
+    }
+
+    /// Designate the `Text` field that lists the principals allowed to see
+    /// each document.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The field name (must also be added as a `Text` field).
+    pub fn acl_field(mut self, name: impl Into<String>) -> Self {
+        self.acl_field = Some(name.into());
+        self
+    }
+
+    /// Designate the `Text` field that holds each document's tenant key.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The field name (must also be added as a `Text` field).
+    pub fn tenant_field(mut self, name: impl Into<String>) -> Self {
+        self.tenant_field = Some(name.into());
+        self
+    }
+
     pub fn build(self) -> Schema {
         Schema {
             analyzers: self.analyzers,
             embedders: self.embedders,
             fields: self.fields,
             default_fields: self.default_fields,
+            expiry_field: self.expiry_field,
+            acl_field: self.acl_field,
+            tenant_field: self.tenant_field,
         }
     }
 }
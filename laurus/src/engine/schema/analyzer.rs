@@ -41,6 +41,23 @@ pub struct AnalyzerDefinition {
     pub token_filters: Vec<TokenFilterConfig>,
 }
 
+impl AnalyzerDefinition {
+    /// Names of the resource sets referenced by the components of this
+    /// definition.
+    pub fn resource_names(&self) -> Vec<&str> {
+        let char_filters = self.char_filters.iter().filter_map(|config| match config {
+            CharFilterConfig::Mapping { resource, .. } => resource.as_deref(),
+            _ => None,
+        });
+        let token_filters = self.token_filters.iter().filter_map(|config| match config {
+            TokenFilterConfig::Stop { resource, .. }
+            | TokenFilterConfig::SynonymGraph { resource, .. } => resource.as_deref(),
+            _ => None,
+        });
+        char_filters.chain(token_filters).collect()
+    }
+}
+
 /// Configuration for a tokenizer component.
 ///
 /// Uses `{"type": "..."}` JSON format via serde's internally tagged
@@ -74,6 +91,15 @@ pub enum TokenizerConfig {
         max_gram: usize,
     },
 
+    /// Splits text into words (runs of alphanumeric characters) and emits
+    /// the prefixes of each word, stacked at the word's position.
+    EdgeNgram {
+        /// Minimum prefix length.
+        min_gram: usize,
+        /// Maximum prefix length.
+        max_gram: usize,
+    },
+
     /// Morphological tokenizer using Lindera.
     Lindera {
         /// Tokenization mode: `"normal"`, `"search"`, or `"decompose"`.
@@ -110,7 +136,12 @@ pub enum CharFilterConfig {
     /// Replaces strings using a mapping dictionary.
     Mapping {
         /// Key-value pairs for replacement.
+        #[serde(default)]
         mapping: HashMap<String, String>,
+        /// Name of a `mapping` [resource set](crate::engine::resource) used
+        /// instead of `mapping`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        resource: Option<String>,
     },
 
     /// Expands Japanese iteration marks (踊り字).
@@ -131,18 +162,40 @@ pub enum TokenFilterConfig {
     /// Converts tokens to lowercase.
     Lowercase,
 
+    /// Applies Unicode default case folding, e.g. "Straße" to "strasse".
+    CaseFold,
+
+    /// Folds accented, full-width, and other non-ASCII characters to
+    /// their ASCII equivalents, e.g. "café" to "cafe".
+    AsciiFolding {
+        /// Whether the original token is also kept at the same position
+        /// when it changes (default: `false`).
+        #[serde(default)]
+        preserve_original: bool,
+    },
+
     /// Removes stop words from the token stream.
     Stop {
         /// Custom stop word list. If `None`, uses default English
         /// stop words.
         #[serde(default)]
         words: Option<Vec<String>>,
+        /// Name of a `stop_words` [resource set](crate::engine::resource)
+        /// used instead of `words`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        resource: Option<String>,
+        /// Language whose default stop words are used instead of `words`,
+        /// e.g. `"french"`. See
+        /// [`STOP_WORD_LANGUAGES`](crate::analysis::token_filter::stop::STOP_WORD_LANGUAGES).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
     },
 
     /// Applies stemming to tokens.
     Stem {
-        /// Stemmer type: `"porter"` (default), `"simple"`, or
-        /// `"identity"`.
+        /// Stemmer type: `"porter"` (default), `"simple"`, `"identity"`,
+        /// or the name of a [Snowball](crate::analysis::token_filter::stem::snowball)
+        /// language such as `"french"` or `"german"`.
         #[serde(default)]
         stem_type: Option<String>,
     },
@@ -167,6 +220,146 @@ pub enum TokenFilterConfig {
 
     /// Flattens a synonym graph into a linear token stream.
     FlattenGraph,
+
+    /// Replaces each token with its prefixes, stacked at the token's
+    /// position. Useful for search-as-you-type when paired with a search
+    /// analyzer that leaves query terms whole.
+    EdgeNgram {
+        /// Minimum prefix length.
+        min_gram: usize,
+        /// Maximum prefix length.
+        max_gram: usize,
+        /// Whether tokens shorter than `min_gram` or longer than
+        /// `max_gram` are also kept unchanged (default: `false`).
+        #[serde(default)]
+        preserve_original: bool,
+    },
+
+    /// Combines adjacent tokens into shingles (word n-grams), e.g.
+    /// `"quick brown"`, stacked at the position of their first token.
+    Shingle {
+        /// Minimum number of tokens per shingle (default: 2).
+        #[serde(default = "default_shingle_size")]
+        min_shingle_size: usize,
+        /// Maximum number of tokens per shingle (default: 2).
+        #[serde(default = "default_shingle_size")]
+        max_shingle_size: usize,
+        /// String placed between the tokens of a shingle (default: `" "`).
+        #[serde(default = "default_token_separator")]
+        token_separator: String,
+        /// Whether the original tokens are emitted as well (default:
+        /// `true`).
+        #[serde(default = "default_true")]
+        output_unigrams: bool,
+    },
+
+    /// Adds or substitutes phonetic codes of tokens, so that names such
+    /// as "Stephen" and "Steven" match.
+    Phonetic {
+        /// Phonetic encoder: `"double_metaphone"` (default), `"soundex"`,
+        /// `"daitch_mokotoff"`, or `"beider_morse"`.
+        #[serde(default = "default_phonetic_encoder")]
+        encoder: String,
+        /// Whether the original token is kept and the codes are added at
+        /// the same position (default: `true`). When `false`, tokens are
+        /// replaced by their codes.
+        #[serde(default = "default_true")]
+        inject: bool,
+    },
+
+    /// Splits tokens such as "PowerShot-SD500" or "iPhone15Pro" into
+    /// subwords at delimiters, case changes, and letter/digit transitions,
+    /// producing a token graph. Place it before lowercasing.
+    WordDelimiterGraph {
+        /// Whether word parts such as "Power" are emitted (default:
+        /// `true`).
+        #[serde(default = "default_true")]
+        generate_word_parts: bool,
+        /// Whether number parts such as "500" are emitted (default:
+        /// `true`).
+        #[serde(default = "default_true")]
+        generate_number_parts: bool,
+        /// Whether tokens are split at case changes, e.g. "WiFi" into
+        /// "Wi" and "Fi" (default: `true`).
+        #[serde(default = "default_true")]
+        split_on_case_change: bool,
+        /// Whether tokens are split between letters and digits, e.g.
+        /// "SD500" into "SD" and "500" (default: `true`).
+        #[serde(default = "default_true")]
+        split_on_numerics: bool,
+        /// Whether runs of word parts are catenated, e.g. "WiFi" from
+        /// "Wi-Fi" (default: `false`).
+        #[serde(default)]
+        catenate_words: bool,
+        /// Whether runs of number parts are catenated, e.g. "5001234"
+        /// from "500-1234" (default: `false`).
+        #[serde(default)]
+        catenate_numbers: bool,
+        /// Whether all parts are catenated, e.g. "PowerShotSD500"
+        /// (default: `false`).
+        #[serde(default)]
+        catenate_all: bool,
+        /// Whether the original token is kept (default: `false`).
+        #[serde(default)]
+        preserve_original: bool,
+    },
+
+    /// Adds the dictionary words found inside compound words, e.g.
+    /// "dampf" and "schiff" from "Dampfschiff", at the compound's
+    /// position.
+    DictionaryDecompounder {
+        /// Dictionary words, matched case-insensitively.
+        words: Vec<String>,
+        /// Minimum length of tokens that are decompounded, in characters
+        /// (default: 5).
+        #[serde(default = "default_min_word_size")]
+        min_word_size: usize,
+        /// Minimum length of subwords, in characters (default: 2).
+        #[serde(default = "default_min_subword_size")]
+        min_subword_size: usize,
+        /// Maximum length of subwords, in characters (default: 15).
+        #[serde(default = "default_max_subword_size")]
+        max_subword_size: usize,
+        /// Whether only the longest subword starting at each character is
+        /// added (default: `false`).
+        #[serde(default)]
+        only_longest_match: bool,
+    },
+
+    /// Expands tokens with synonyms, producing a token graph.
+    ///
+    /// Rules come either inline or from a file. Inline rules use the Solr
+    /// synonym syntax: `"ml, machine learning"` declares equivalent terms
+    /// and `"ny => new york"` is a one-way mapping that replaces the
+    /// matched term. Rules match the tokens as they reach this filter, so
+    /// they should be normalized the same way (e.g. lowercased).
+    ///
+    /// The graph is flattened when indexing, and expanded into alternative
+    /// term and phrase queries when parsing queries.
+    SynonymGraph {
+        /// Inline rules in the Solr synonym syntax.
+        #[serde(default)]
+        synonyms: Vec<String>,
+        /// Path to a synonym file, used instead of inline rules.
+        #[serde(default)]
+        path: Option<String>,
+        /// Format of the file at `path`: `"solr"` (default), `"wordnet"`,
+        /// or `"json"` (an array of synonym groups).
+        #[serde(default)]
+        format: Option<String>,
+        /// Whether matched tokens are kept alongside their synonyms
+        /// (default: `true`). One-way rules always replace them.
+        #[serde(default = "default_true")]
+        keep_original: bool,
+        /// Optional weight multiplier for synonym matches at query time.
+        #[serde(default)]
+        boost: Option<f32>,
+        /// Name of a `synonyms` [resource set](crate::engine::resource)
+        /// used instead of inline rules or a file. Updating the set
+        /// reloads the analyzer without reopening the engine.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        resource: Option<String>,
+    },
 }
 
 fn default_regex_pattern() -> String {
@@ -177,6 +370,30 @@ fn default_true() -> bool {
     true
 }
 
+fn default_shingle_size() -> usize {
+    2
+}
+
+fn default_token_separator() -> String {
+    " ".to_string()
+}
+
+fn default_phonetic_encoder() -> String {
+    "double_metaphone".to_string()
+}
+
+fn default_min_word_size() -> usize {
+    5
+}
+
+fn default_min_subword_size() -> usize {
+    2
+}
+
+fn default_max_subword_size() -> usize {
+    15
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -195,6 +412,8 @@ mod tests {
                 TokenFilterConfig::Lowercase,
                 TokenFilterConfig::Stop {
                     words: Some(vec!["the".into(), "a".into()]),
+                    resource: None,
+                    language: None,
                 },
                 TokenFilterConfig::Stem { stem_type: None },
             ],
@@ -213,6 +432,7 @@ mod tests {
             r#"{"type": "unicode_word"}"#,
             r#"{"type": "regex", "pattern": "\\w+", "gaps": false}"#,
             r#"{"type": "ngram", "min_gram": 2, "max_gram": 3}"#,
+            r#"{"type": "edge_ngram", "min_gram": 1, "max_gram": 10}"#,
             r#"{"type": "whole"}"#,
         ];
         for json in configs {
@@ -228,6 +448,7 @@ mod tests {
             r#"{"type": "unicode_normalization", "form": "nfkc"}"#,
             r#"{"type": "pattern_replace", "pattern": "foo", "replacement": "bar"}"#,
             r#"{"type": "mapping", "mapping": {"a": "b"}}"#,
+            r#"{"type": "mapping", "resource": "chars"}"#,
             r#"{"type": "japanese_iteration_mark"}"#,
         ];
         for json in configs {
@@ -241,8 +462,12 @@ mod tests {
     fn test_token_filter_config_variants() {
         let configs = vec![
             r#"{"type": "lowercase"}"#,
+            r#"{"type": "case_fold"}"#,
+            r#"{"type": "ascii_folding"}"#,
+            r#"{"type": "ascii_folding", "preserve_original": true}"#,
             r#"{"type": "stop"}"#,
             r#"{"type": "stop", "words": ["the", "a"]}"#,
+            r#"{"type": "stop", "resource": "stop_words"}"#,
             r#"{"type": "stem"}"#,
             r#"{"type": "stem", "stem_type": "porter"}"#,
             r#"{"type": "boost", "boost": 2.0}"#,
@@ -250,6 +475,19 @@ mod tests {
             r#"{"type": "strip"}"#,
             r#"{"type": "remove_empty"}"#,
             r#"{"type": "flatten_graph"}"#,
+            r#"{"type": "edge_ngram", "min_gram": 2, "max_gram": 5}"#,
+            r#"{"type": "edge_ngram", "min_gram": 2, "max_gram": 5, "preserve_original": true}"#,
+            r#"{"type": "shingle"}"#,
+            r#"{"type": "shingle", "min_shingle_size": 2, "max_shingle_size": 3, "token_separator": "_", "output_unigrams": false}"#,
+            r#"{"type": "phonetic"}"#,
+            r#"{"type": "phonetic", "encoder": "soundex", "inject": false}"#,
+            r#"{"type": "word_delimiter_graph"}"#,
+            r#"{"type": "word_delimiter_graph", "split_on_case_change": false, "catenate_words": true, "catenate_numbers": true, "catenate_all": true, "preserve_original": true}"#,
+            r#"{"type": "dictionary_decompounder", "words": ["dampf", "schiff"]}"#,
+            r#"{"type": "dictionary_decompounder", "words": ["dampf"], "min_word_size": 6, "min_subword_size": 3, "max_subword_size": 10, "only_longest_match": true}"#,
+            r#"{"type": "synonym_graph", "synonyms": ["ml, machine learning", "ny => new york"]}"#,
+            r#"{"type": "synonym_graph", "path": "synonyms.txt", "format": "wordnet", "keep_original": false, "boost": 0.8}"#,
+            r#"{"type": "synonym_graph", "resource": "synonyms"}"#,
         ];
         for json in configs {
             let config: TokenFilterConfig = serde_json::from_str(json).unwrap();
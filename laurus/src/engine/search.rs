@@ -7,6 +7,8 @@ use crate::lexical::search::searcher::{LexicalSearchQuery, SortField};
 use crate::vector::VectorScoreMode;
 pub use crate::vector::search::searcher::VectorSearchQuery;
 
+use super::acl::Identity;
+
 // ── Query types (what to search for) ─────────────────────────────────────────
 
 /// Unified search query specification.
@@ -178,6 +180,14 @@ pub struct SearchRequest {
     /// vector searches are restricted to documents matching this filter.
     pub filter_query: Option<Box<dyn Query>>,
 
+    /// Identity the search is made on behalf of.
+    ///
+    /// When the schema declares an
+    /// [`acl_field`](super::schema::Schema::acl_field), only documents
+    /// whose ACL names one of the identity's principals are returned. When
+    /// `None`, the search is not restricted.
+    pub identity: Option<Identity>,
+
     /// Parameters controlling lexical search behavior.
     pub lexical_options: LexicalSearchOptions,
 
@@ -224,6 +234,7 @@ impl Default for SearchRequest {
             offset: 0,
             fusion_algorithm: None,
             filter_query: None,
+            identity: None,
             lexical_options: LexicalSearchOptions::default(),
             vector_options: VectorSearchOptions::default(),
         }
@@ -254,6 +265,7 @@ pub struct SearchRequestBuilder {
     offset: usize,
     fusion_algorithm: Option<FusionAlgorithm>,
     filter_query: Option<Box<dyn Query>>,
+    identity: Option<Identity>,
     lexical_options: LexicalSearchOptions,
     vector_options: VectorSearchOptions,
 }
@@ -275,6 +287,7 @@ impl SearchRequestBuilder {
             offset: 0,
             fusion_algorithm: None,
             filter_query: None,
+            identity: None,
             lexical_options: LexicalSearchOptions::default(),
             vector_options: VectorSearchOptions::default(),
         }
@@ -350,6 +363,15 @@ impl SearchRequestBuilder {
         self
     }
 
+    /// Restrict the search to documents visible to `identity`.
+    ///
+    /// Only has an effect when the schema declares an
+    /// [`acl_field`](super::schema::Schema::acl_field).
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
     // ── Lexical options ──────────────────────────────────────────────────
 
     /// Add a field-level boost for lexical search.
@@ -423,6 +445,7 @@ impl SearchRequestBuilder {
             offset: self.offset,
             fusion_algorithm: self.fusion_algorithm,
             filter_query: self.filter_query,
+            identity: self.identity,
             lexical_options: self.lexical_options,
             vector_options: self.vector_options,
         }
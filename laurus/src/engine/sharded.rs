@@ -0,0 +1,424 @@
+//! Hash-partitioned engine spreading documents over several local shards.
+//!
+//! A [`ShardedEngine`] owns N complete [`Engine`]s, each stored under
+//! `shard_<i>/` of the same storage and tagged with its shard ID. Documents
+//! are routed to a shard by a stable hash of their external ID, so every
+//! chunk of a document lives on the same shard and writes to different
+//! shards do not contend on one segment set or WAL.
+//!
+//! Searches are planned once (DSL parsing, query embedding) and executed on
+//! all shards in parallel. Lexical scoring on each shard reads term and
+//! field statistics summed over every shard, so BM25 scores are the same
+//! as in a single index and can be merged directly. Merged hits are keyed
+//! by [`create_doc_id`](crate::util::id::create_doc_id) with the shard in
+//! the upper bits, then fused and paginated exactly like
+//! [`Engine::search`].
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::analyzer::analyzer::Analyzer;
+use crate::data::Document;
+use crate::embedding::embedder::Embedder;
+use crate::error::{LaurusError, Result};
+use crate::lexical::reader::{GlobalStatsReader, LexicalIndexReader};
+use crate::storage::Storage;
+use crate::storage::prefixed::PrefixedStorage;
+use crate::util::id::{create_doc_id, get_local_id, get_shard_id};
+
+use super::acl::Identity;
+use super::schema::Schema;
+use super::search::{SearchRequest, SearchResult};
+use super::{Engine, EngineBuilder, EngineStats, SearchHits};
+
+/// Storage file recording the number of shards an index was created with.
+const SHARDING_PATH: &str = "sharding.json";
+
+/// Persisted sharding layout.
+#[derive(Debug, Serialize, Deserialize)]
+struct ShardingMeta {
+    num_shards: u16,
+}
+
+/// An engine that partitions documents over several local shards.
+///
+/// Each shard is a full [`Engine`] with its own lexical index, vector index
+/// and WAL. The API mirrors the document and search operations of
+/// [`Engine`]; per-shard operations such as snapshots are available via
+/// [`shards`](Self::shards).
+///
+/// # Example
+///
+/// ```ignore
+/// let engine = ShardedEngine::new(storage, schema, 4).await?;
+/// engine.put_document("doc1", doc).await?;
+/// engine.commit().await?;
+/// let results = engine
+///     .search(SearchRequestBuilder::new().query_dsl("title:rust").build())
+///     .await?;
+/// ```
+pub struct ShardedEngine {
+    shards: Vec<Engine>,
+}
+
+impl std::fmt::Debug for ShardedEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShardedEngine")
+            .field("num_shards", &self.shards.len())
+            .finish()
+    }
+}
+
+impl ShardedEngine {
+    /// Create a sharded engine with `num_shards` shards, the default
+    /// analyzer and no embedder.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `num_shards` is invalid or differs from the
+    /// count the index was created with, or if any shard fails to open.
+    pub async fn new(storage: Arc<dyn Storage>, schema: Schema, num_shards: u16) -> Result<Self> {
+        ShardedEngineBuilder::new(storage, schema, num_shards)
+            .build()
+            .await
+    }
+
+    /// Create a builder for a sharded engine with `num_shards` shards.
+    pub fn builder(
+        storage: Arc<dyn Storage>,
+        schema: Schema,
+        num_shards: u16,
+    ) -> ShardedEngineBuilder {
+        ShardedEngineBuilder::new(storage, schema, num_shards)
+    }
+
+    /// Number of shards.
+    pub fn num_shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// The shards, indexed by shard ID.
+    ///
+    /// Schema changes must be applied to every shard.
+    pub fn shards(&self) -> &[Engine] {
+        &self.shards
+    }
+
+    /// Shard ID that documents with the external ID `id` are routed to.
+    ///
+    /// Uses 64-bit FNV-1a, which is stable across processes and releases,
+    /// so reopened indexes keep routing IDs to the same shard.
+    pub fn shard_for(&self, id: &str) -> usize {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in id.as_bytes() {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+        (hash % self.shards.len() as u64) as usize
+    }
+
+    fn shard(&self, id: &str) -> &Engine {
+        &self.shards[self.shard_for(id)]
+    }
+
+    /// Put (upsert) a document on the shard owning `id`.
+    ///
+    /// See [`Engine::put_document`].
+    pub async fn put_document(&self, id: &str, doc: Document) -> Result<()> {
+        self.shard(id).put_document(id, doc).await
+    }
+
+    /// Add a document as a new chunk on the shard owning `id`.
+    ///
+    /// See [`Engine::add_document`].
+    pub async fn add_document(&self, id: &str, doc: Document) -> Result<()> {
+        self.shard(id).add_document(id, doc).await
+    }
+
+    /// Delete all documents with the external ID `id`.
+    ///
+    /// See [`Engine::delete_documents`].
+    pub async fn delete_documents(&self, id: &str) -> Result<()> {
+        self.shard(id).delete_documents(id).await
+    }
+
+    /// Get all documents with the external ID `id`.
+    ///
+    /// See [`Engine::get_documents`].
+    pub async fn get_documents(&self, id: &str) -> Result<Vec<Document>> {
+        self.shard(id).get_documents(id).await
+    }
+
+    /// Get the documents with the external ID `id` visible to `identity`.
+    ///
+    /// See [`Engine::get_documents_as`].
+    pub async fn get_documents_as(&self, id: &str, identity: &Identity) -> Result<Vec<Document>> {
+        self.shard(id).get_documents_as(id, identity).await
+    }
+
+    /// Commit every shard concurrently.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error reported by a shard; the other shards are
+    /// still committed.
+    pub async fn commit(&self) -> Result<()> {
+        futures::future::join_all(self.shards.iter().map(|shard| shard.commit()))
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Search all shards and merge the results.
+    ///
+    /// Behaves like [`Engine::search`] on an index holding every document:
+    /// lexical scores use collection-wide BM25 statistics, and lexical and
+    /// vector hits from all shards are merged by score before fusion and
+    /// pagination.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if query parsing or embedding fails, or if the
+    /// search fails on any shard.
+    pub async fn search(&self, request: SearchRequest) -> Result<Vec<SearchResult>> {
+        // All shards share the schema, analyzers and embedders, so the
+        // first shard can plan for all of them.
+        let plan = self.shards[0].plan_search(request).await?;
+
+        let readers: Vec<Arc<dyn LexicalIndexReader>> = self
+            .shards
+            .iter()
+            .map(|shard| shard.lexical.reader())
+            .collect::<Result<_>>()?;
+
+        let shard_hits: Vec<SearchHits> = self
+            .shards
+            .par_iter()
+            .zip(readers.par_iter())
+            .map(|(shard, reader)| {
+                let reader = Arc::new(GlobalStatsReader::new(reader.clone(), readers.clone()));
+                shard.collect_hits(&plan, Some(reader))
+            })
+            .collect::<Result<_>>()?;
+
+        let mut merged = SearchHits::default();
+        for (shard_id, hits) in shard_hits.into_iter().enumerate() {
+            let shard_id = shard_id as u16;
+            merged
+                .lexical
+                .extend(hits.lexical.into_iter().map(|mut hit| {
+                    hit.doc_id = create_doc_id(shard_id, hit.doc_id);
+                    hit
+                }));
+            merged.vector.extend(hits.vector.into_iter().map(|mut hit| {
+                hit.doc_id = create_doc_id(shard_id, hit.doc_id);
+                hit
+            }));
+        }
+        // Stable sorts keep shard order for equal scores.
+        merged.lexical.sort_by(|a, b| b.score.total_cmp(&a.score));
+        merged.lexical.truncate(plan.lexical_fetch_count());
+        merged.vector.sort_by(|a, b| b.score.total_cmp(&a.score));
+        merged.vector.truncate(plan.vector_fetch_count());
+
+        plan.finish(merged, |ids| self.resolve(ids))
+    }
+
+    /// Resolve global doc IDs on the shards they were collected from.
+    fn resolve(&self, ids: &[u64]) -> Result<HashMap<u64, (String, Option<Document>)>> {
+        let mut by_shard: HashMap<u16, Vec<u64>> = HashMap::new();
+        for &id in ids {
+            by_shard
+                .entry(get_shard_id(id))
+                .or_default()
+                .push(get_local_id(id));
+        }
+        let mut resolved = HashMap::with_capacity(ids.len());
+        for (shard_id, local_ids) in by_shard {
+            let shard = &self.shards[shard_id as usize];
+            for (local_id, entry) in shard.resolve_ids_and_documents_batch(&local_ids)? {
+                resolved.insert(create_doc_id(shard_id, local_id), entry);
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Statistics summed over all shards.
+    ///
+    /// Vector counts are summed per field; backfills are listed once per
+    /// shard.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the statistics of any shard cannot be retrieved.
+    pub fn stats(&self) -> Result<EngineStats> {
+        let mut total = EngineStats::default();
+        for shard in &self.shards {
+            let stats = shard.stats()?;
+            total.document_count += stats.document_count;
+            for (field, field_stats) in stats.vector_fields {
+                total
+                    .vector_fields
+                    .entry(field)
+                    .and_modify(|acc| acc.vector_count += field_stats.vector_count)
+                    .or_insert(field_stats);
+            }
+            total.backfills.extend(stats.backfills);
+            for (tenant, tenant_stats) in stats.tenants {
+                let acc = total.tenants.entry(tenant).or_default();
+                acc.document_count += tenant_stats.document_count;
+                acc.storage_bytes += tenant_stats.storage_bytes;
+            }
+        }
+        Ok(total)
+    }
+
+    /// Return a clone of the schema shared by all shards.
+    pub fn schema(&self) -> Schema {
+        self.shards[0].schema()
+    }
+}
+
+/// Builder for constructing a [`ShardedEngine`].
+///
+/// The options are applied to every shard, as with [`EngineBuilder`].
+pub struct ShardedEngineBuilder {
+    storage: Arc<dyn Storage>,
+    schema: Schema,
+    num_shards: u16,
+    analyzer: Option<Arc<dyn Analyzer>>,
+    embedder: Option<Arc<dyn Embedder>>,
+    change_retention: Option<usize>,
+}
+
+impl ShardedEngineBuilder {
+    /// Create a new builder with the given storage, schema and shard count.
+    pub fn new(storage: Arc<dyn Storage>, schema: Schema, num_shards: u16) -> Self {
+        Self {
+            storage,
+            schema,
+            num_shards,
+            analyzer: None,
+            embedder: None,
+            change_retention: None,
+        }
+    }
+
+    /// Set the analyzer for text fields. See [`EngineBuilder::analyzer`].
+    pub fn analyzer(mut self, analyzer: Arc<dyn Analyzer>) -> Self {
+        self.analyzer = Some(analyzer);
+        self
+    }
+
+    /// Set the embedder for vector fields. See [`EngineBuilder::embedder`].
+    pub fn embedder(mut self, embedder: Arc<dyn Embedder>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Set how many mutation events each shard retains.
+    /// See [`EngineBuilder::change_retention`].
+    pub fn change_retention(mut self, max_events: usize) -> Self {
+        self.change_retention = Some(max_events);
+        self
+    }
+
+    /// Build the [`ShardedEngine`], opening or creating every shard.
+    ///
+    /// The shard count is recorded in `sharding.json` on first build;
+    /// reopening with a different count would route IDs to the wrong
+    /// shards and is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `num_shards` is `0` or differs from the recorded
+    /// count, or if any shard fails to build.
+    pub async fn build(self) -> Result<ShardedEngine> {
+        if self.num_shards == 0 {
+            return Err(LaurusError::invalid_argument(
+                "A sharded engine needs at least one shard",
+            ));
+        }
+        Self::check_layout(self.storage.as_ref(), self.num_shards)?;
+
+        let mut shards = Vec::with_capacity(self.num_shards as usize);
+        for shard_id in 0..self.num_shards {
+            let storage: Arc<dyn Storage> = Arc::new(PrefixedStorage::new(
+                format!("shard_{shard_id}"),
+                self.storage.clone(),
+            ));
+            let mut builder = EngineBuilder::new(storage, self.schema.clone()).shard_id(shard_id);
+            if let Some(analyzer) = &self.analyzer {
+                builder = builder.analyzer(analyzer.clone());
+            }
+            if let Some(embedder) = &self.embedder {
+                builder = builder.embedder(embedder.clone());
+            }
+            if let Some(max_events) = self.change_retention {
+                builder = builder.change_retention(max_events);
+            }
+            shards.push(builder.build().await?);
+        }
+        Ok(ShardedEngine { shards })
+    }
+
+    /// Verify the recorded shard count, recording it on first use.
+    fn check_layout(storage: &dyn Storage, num_shards: u16) -> Result<()> {
+        if storage.file_exists(SHARDING_PATH) {
+            let mut data = Vec::new();
+            storage.open_input(SHARDING_PATH)?.read_to_end(&mut data)?;
+            let meta: ShardingMeta = serde_json::from_slice(&data)?;
+            if meta.num_shards != num_shards {
+                return Err(LaurusError::invalid_argument(format!(
+                    "Index was created with {} shards, cannot open it with {num_shards}",
+                    meta.num_shards
+                )));
+            }
+            return Ok(());
+        }
+        let data = serde_json::to_vec(&ShardingMeta { num_shards })?;
+        let mut output = storage.create_output(SHARDING_PATH)?;
+        output.write_all(&data)?;
+        output.flush_and_sync()?;
+        output.close()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::memory::{MemoryStorage, MemoryStorageConfig};
+
+    async fn engine(num_shards: u16) -> ShardedEngine {
+        let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+        ShardedEngine::new(storage, Schema::builder().build(), num_shards)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_shard_for_is_stable_and_spread() {
+        let engine = engine(4).await;
+        let mut counts = [0usize; 4];
+        for i in 0..400 {
+            let id = format!("doc{i}");
+            let shard = engine.shard_for(&id);
+            assert_eq!(shard, engine.shard_for(&id));
+            counts[shard] += 1;
+        }
+        assert!(counts.iter().all(|&count| count > 50), "{counts:?}");
+    }
+
+    #[tokio::test]
+    async fn test_zero_shards_rejected() {
+        let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+        let err = ShardedEngine::new(storage, Schema::builder().build(), 0)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("at least one shard"));
+    }
+}
@@ -0,0 +1,543 @@
+//! Copies of an engine's committed on-disk state.
+//!
+//! [`Engine::snapshot_to`](super::Engine::snapshot_to) copies every
+//! committed file of an [`Engine`](super::Engine) into a backup [`Storage`],
+//! together with a [`SnapshotManifest`] recording the schema, the last
+//! sequence number covered by the files and the size and CRC-32 checksum of
+//! every file. An incremental snapshot into a storage that already holds one
+//! only writes the files that changed since. [`restore_snapshot`] copies a
+//! backup into the storage of a new engine.
+//!
+//! A [`Snapshot`] reads such a backup one file at a time, e.g. to stream it
+//! to a replication follower, which writes it chunk by chunk with a
+//! [`SnapshotReceiver`]. Opening an engine on the received files reproduces
+//! the committed index; mutations after [`Snapshot::seq`] can then be
+//! replayed through [`Engine::apply_change`](super::Engine::apply_change).
+//! Files are streamed and verified against their checksums without ever
+//! being held in memory as a whole.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{LaurusError, Result};
+use crate::storage::{Storage, StorageInput, StorageOutput};
+use crate::store::log::SeqNumber;
+
+use super::schema::Schema;
+
+/// Name of the manifest file written into a backup storage.
+pub const SNAPSHOT_MANIFEST_PATH: &str = "snapshot.json";
+
+/// Size of the buffer that snapshot files are copied through.
+const COPY_BUFFER_SIZE: usize = 64 * 1024;
+
+/// A snapshot held by a backup storage, read one file at a time.
+///
+/// Taken with [`Engine::snapshot`](super::Engine::snapshot), or opened on a
+/// storage written by [`Engine::snapshot_to`](super::Engine::snapshot_to).
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    manifest: SnapshotManifest,
+    storage: Arc<dyn Storage>,
+}
+
+impl Snapshot {
+    /// Open the snapshot held by `storage`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `storage` holds no snapshot or its manifest
+    /// cannot be read.
+    pub fn open(storage: Arc<dyn Storage>) -> Result<Self> {
+        let manifest = SnapshotManifest::load(storage.as_ref())?.ok_or_else(missing_manifest)?;
+        Ok(Self { manifest, storage })
+    }
+
+    /// Last sequence number whose mutation is contained in the files.
+    pub fn seq(&self) -> SeqNumber {
+        self.manifest.seq
+    }
+
+    /// Schema of the engine when the snapshot was taken.
+    pub fn schema(&self) -> &Schema {
+        &self.manifest.schema
+    }
+
+    /// Every file of the snapshot, excluding the write-ahead log.
+    pub fn files(&self) -> &[SnapshotManifestFile] {
+        &self.manifest.files
+    }
+
+    /// Open a file of the snapshot for reading.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn open_file(&self, name: &str) -> Result<Box<dyn StorageInput>> {
+        self.storage.open_input(name)
+    }
+
+    /// Total size of the snapshot files in bytes.
+    pub fn size(&self) -> u64 {
+        self.manifest.size()
+    }
+}
+
+/// Writes a snapshot into a storage one chunk at a time.
+///
+/// The chunks of a file must arrive in order, starting at offset 0, and
+/// files one after another. Each file is verified against the size and
+/// checksum recorded for it once it is complete.
+#[derive(Debug)]
+pub struct SnapshotReceiver<'a> {
+    storage: &'a dyn Storage,
+    current: Option<ReceivedFile>,
+    files: Vec<SnapshotManifestFile>,
+}
+
+/// The file a [`SnapshotReceiver`] is writing.
+#[derive(Debug)]
+struct ReceivedFile {
+    file: SnapshotManifestFile,
+    output: Box<dyn StorageOutput>,
+    hasher: crc32fast::Hasher,
+    written: u64,
+}
+
+impl<'a> SnapshotReceiver<'a> {
+    /// Start receiving a snapshot into `storage`.
+    ///
+    /// Every existing file is deleted first. No engine may be open on
+    /// `storage` while this runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be deleted.
+    pub fn new(storage: &'a dyn Storage) -> Result<Self> {
+        for name in storage.list_files()? {
+            storage.delete_file(&name)?;
+        }
+        Ok(Self {
+            storage,
+            current: None,
+            files: Vec::new(),
+        })
+    }
+
+    /// Write a chunk of `file` starting at byte `offset`.
+    ///
+    /// A chunk at offset 0 starts the file, completing the previous one.
+    /// Any other chunk must continue the file being received.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chunk is out of order, if the previous file
+    /// does not match its checksum, or if writing fails.
+    pub fn write_chunk(
+        &mut self,
+        file: &SnapshotManifestFile,
+        offset: u64,
+        data: &[u8],
+    ) -> Result<()> {
+        if offset == 0 {
+            self.complete_file()?;
+            self.current = Some(ReceivedFile {
+                file: file.clone(),
+                output: self.storage.create_output(&file.name)?,
+                hasher: crc32fast::Hasher::new(),
+                written: 0,
+            });
+        }
+        let current = self
+            .current
+            .as_mut()
+            .filter(|current| current.file.name == file.name && current.written == offset)
+            .ok_or_else(|| {
+                LaurusError::InvalidOperation(format!(
+                    "Snapshot chunk of '{}' at offset {offset} is out of order",
+                    file.name
+                ))
+            })?;
+        current.output.write_all(data)?;
+        current.hasher.update(data);
+        current.written += data.len() as u64;
+        Ok(())
+    }
+
+    /// Complete the last file and sync the storage.
+    ///
+    /// # Returns
+    ///
+    /// Every file received.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the last file does not match its checksum or
+    /// the storage cannot be synced.
+    pub fn finish(mut self) -> Result<Vec<SnapshotManifestFile>> {
+        self.complete_file()?;
+        self.storage.sync()?;
+        Ok(self.files)
+    }
+
+    fn complete_file(&mut self) -> Result<()> {
+        let Some(mut current) = self.current.take() else {
+            return Ok(());
+        };
+        current.output.flush_and_sync()?;
+        current.output.close()?;
+        if current.written != current.file.size
+            || current.hasher.finalize() != current.file.checksum
+        {
+            return Err(LaurusError::InvalidOperation(format!(
+                "Snapshot file '{}' does not match its checksum",
+                current.file.name
+            )));
+        }
+        self.files.push(current.file);
+        Ok(())
+    }
+}
+
+/// A file recorded in a [`SnapshotManifest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotManifestFile {
+    /// File name relative to the engine storage root.
+    pub name: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// CRC-32 checksum of the file contents.
+    pub checksum: u32,
+}
+
+/// Description of a snapshot stored in a backup storage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// Last sequence number whose mutation is contained in the files.
+    pub seq: SeqNumber,
+    /// Schema of the engine when the snapshot was taken.
+    pub schema: Schema,
+    /// Every file of the snapshot.
+    pub files: Vec<SnapshotManifestFile>,
+}
+
+impl SnapshotManifest {
+    /// Read the manifest from a backup storage, if it holds a snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest exists but cannot be read or parsed.
+    pub fn load(storage: &dyn Storage) -> Result<Option<Self>> {
+        if !storage.file_exists(SNAPSHOT_MANIFEST_PATH) {
+            return Ok(None);
+        }
+        let mut data = Vec::new();
+        storage
+            .open_input(SNAPSHOT_MANIFEST_PATH)?
+            .read_to_end(&mut data)?;
+        Ok(Some(serde_json::from_slice(&data)?))
+    }
+
+    fn save(&self, storage: &dyn Storage) -> Result<()> {
+        let data = serde_json::to_vec_pretty(self)?;
+        write_file(storage, SNAPSHOT_MANIFEST_PATH, &data)
+    }
+
+    /// Total size of the snapshot files in bytes.
+    pub fn size(&self) -> u64 {
+        self.files.iter().map(|file| file.size).sum()
+    }
+}
+
+/// Outcome of [`Engine::snapshot_to`](super::Engine::snapshot_to).
+#[derive(Debug, Clone)]
+pub struct SnapshotReport {
+    /// The manifest written to the backup storage.
+    pub manifest: SnapshotManifest,
+    /// Number of files written.
+    pub copied_files: usize,
+    /// Number of bytes written.
+    pub copied_bytes: u64,
+    /// Number of files kept from the previous snapshot because they did not
+    /// change.
+    pub unchanged_files: usize,
+    /// Number of files of the previous snapshot that were deleted.
+    pub removed_files: usize,
+}
+
+/// Copy every file of `source` except those in `exclude` into `target`.
+///
+/// When `incremental` is `true` and `target` already holds a snapshot,
+/// files whose size and checksum match its manifest are not rewritten.
+/// Every source file is still read to compute its checksum, as a rebuilt
+/// index reuses segment names from zero. Otherwise every existing file of
+/// `target` is deleted first. Files of the previous snapshot that are no
+/// longer part of `source` are deleted, and the manifest is written last.
+pub(crate) fn copy_files(
+    source: &dyn Storage,
+    exclude: &[&str],
+    target: &dyn Storage,
+    seq: SeqNumber,
+    schema: Schema,
+    incremental: bool,
+) -> Result<SnapshotReport> {
+    let previous: HashMap<String, SnapshotManifestFile> =
+        match SnapshotManifest::load(target)?.filter(|_| incremental) {
+            Some(manifest) => manifest
+                .files
+                .into_iter()
+                .map(|file| (file.name.clone(), file))
+                .collect(),
+            None => {
+                for name in target.list_files()? {
+                    target.delete_file(&name)?;
+                }
+                HashMap::new()
+            }
+        };
+
+    let mut names = source.list_files()?;
+    names.retain(|name| !exclude.contains(&name.as_str()));
+    names.sort();
+
+    let mut report = SnapshotReport {
+        manifest: SnapshotManifest {
+            seq,
+            schema,
+            files: Vec::with_capacity(names.len()),
+        },
+        copied_files: 0,
+        copied_bytes: 0,
+        unchanged_files: 0,
+        removed_files: 0,
+    };
+    let mut buffer = vec![0; COPY_BUFFER_SIZE];
+    for name in names {
+        let size = source.file_size(&name)?;
+        let kept = match previous.get(&name) {
+            Some(file) if file.size == size && target.file_exists(&name) => {
+                if checksum(source, &name, &mut buffer)? == file.checksum {
+                    Some(file.clone())
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        let file = match kept {
+            Some(file) => {
+                report.unchanged_files += 1;
+                file
+            }
+            None => {
+                let file = copy_file(source, target, name, &mut buffer)?;
+                report.copied_files += 1;
+                report.copied_bytes += file.size;
+                file
+            }
+        };
+        report.manifest.files.push(file);
+    }
+
+    for name in previous.keys() {
+        if !report.manifest.files.iter().any(|file| &file.name == name) && target.file_exists(name)
+        {
+            target.delete_file(name)?;
+            report.removed_files += 1;
+        }
+    }
+
+    target.sync()?;
+    report.manifest.save(target)?;
+    target.sync()?;
+    Ok(report)
+}
+
+/// Restore the snapshot held by `backup` into `target`.
+///
+/// Every existing file of `target` is deleted first, and each file is
+/// verified against the manifest checksum. Open an engine on `target` with
+/// the returned [`SnapshotManifest::schema`] to use the restored index.
+/// No engine may be open on `target` while this runs.
+///
+/// # Errors
+///
+/// Returns an error if `backup` holds no snapshot, if a file is missing or
+/// does not match its checksum, or if writing to `target` fails.
+pub fn restore_snapshot(backup: &dyn Storage, target: &dyn Storage) -> Result<SnapshotManifest> {
+    let manifest = SnapshotManifest::load(backup)?.ok_or_else(missing_manifest)?;
+
+    let mut receiver = SnapshotReceiver::new(target)?;
+    let mut buffer = vec![0; COPY_BUFFER_SIZE];
+    for file in &manifest.files {
+        let mut input = backup.open_input(&file.name)?;
+        let mut offset = 0;
+        loop {
+            let read = input.read(&mut buffer)?;
+            // Empty files still get one chunk so that they are created.
+            if read > 0 || offset == 0 {
+                receiver.write_chunk(file, offset, &buffer[..read])?;
+            }
+            if read == 0 {
+                break;
+            }
+            offset += read as u64;
+        }
+    }
+    receiver.finish()?;
+
+    Ok(manifest)
+}
+
+fn missing_manifest() -> LaurusError {
+    LaurusError::invalid_argument(format!(
+        "Backup storage holds no snapshot ({SNAPSHOT_MANIFEST_PATH} is missing)"
+    ))
+}
+
+/// CRC-32 checksum of the file `name` of `storage`, read chunk by chunk.
+fn checksum(storage: &dyn Storage, name: &str, buffer: &mut [u8]) -> Result<u32> {
+    let mut input = storage.open_input(name)?;
+    let mut hasher = crc32fast::Hasher::new();
+    loop {
+        let read = input.read(buffer)?;
+        if read == 0 {
+            return Ok(hasher.finalize());
+        }
+        hasher.update(&buffer[..read]);
+    }
+}
+
+/// Copy the file `name` from `source` to `target` chunk by chunk.
+fn copy_file(
+    source: &dyn Storage,
+    target: &dyn Storage,
+    name: String,
+    buffer: &mut [u8],
+) -> Result<SnapshotManifestFile> {
+    let mut input = source.open_input(&name)?;
+    let mut output = target.create_output(&name)?;
+    let mut hasher = crc32fast::Hasher::new();
+    let mut size = 0;
+    loop {
+        let read = input.read(buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        output.write_all(&buffer[..read])?;
+        size += read as u64;
+    }
+    output.flush_and_sync()?;
+    output.close()?;
+    Ok(SnapshotManifestFile {
+        name,
+        size,
+        checksum: hasher.finalize(),
+    })
+}
+
+fn write_file(storage: &dyn Storage, name: &str, data: &[u8]) -> Result<()> {
+    let mut output = storage.create_output(name)?;
+    output.write_all(data)?;
+    output.flush_and_sync()?;
+    output.close()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::memory::{MemoryStorage, MemoryStorageConfig};
+
+    fn storage_with(files: &[(&str, &str)]) -> MemoryStorage {
+        let storage = MemoryStorage::new(MemoryStorageConfig::default());
+        for (name, data) in files {
+            write_file(&storage, name, data.as_bytes()).unwrap();
+        }
+        storage
+    }
+
+    #[test]
+    fn test_incremental_copy_skips_unchanged_files() {
+        let target = MemoryStorage::new(MemoryStorageConfig::default());
+        let source = storage_with(&[("a", "one"), ("b", "two"), ("wal", "x")]);
+        let report = copy_files(&source, &["wal"], &target, 1, Schema::new(), true).unwrap();
+        assert_eq!(report.copied_files, 2);
+        assert!(!target.file_exists("wal"));
+
+        let source = storage_with(&[("a", "one"), ("c", "three")]);
+        let report = copy_files(&source, &[], &target, 2, Schema::new(), true).unwrap();
+        assert_eq!(report.copied_files, 1);
+        assert_eq!(report.unchanged_files, 1);
+        assert_eq!(report.removed_files, 1);
+        assert!(!target.file_exists("b"));
+
+        let restored = MemoryStorage::new(MemoryStorageConfig::default());
+        let manifest = restore_snapshot(&target, &restored).unwrap();
+        assert_eq!(manifest.seq, 2);
+        let mut names = restored.list_files().unwrap();
+        names.sort();
+        assert_eq!(names, vec!["a", "c"]);
+    }
+
+    #[test]
+    fn test_incremental_copy_compares_segment_files() {
+        let target = MemoryStorage::new(MemoryStorageConfig::default());
+        let source = storage_with(&[
+            ("lexical/segment_000000.post", "one"),
+            ("lexical/segment_000000.meta", "one"),
+        ]);
+        copy_files(&source, &[], &target, 1, Schema::new(), true).unwrap();
+
+        // A rebuilt index reuses segment names, so same names and sizes do
+        // not mean the same content.
+        let source = storage_with(&[
+            ("lexical/segment_000000.post", "eno"),
+            ("lexical/segment_000000.meta", "one"),
+        ]);
+        let report = copy_files(&source, &[], &target, 2, Schema::new(), true).unwrap();
+        assert_eq!(report.unchanged_files, 1);
+        assert_eq!(report.copied_files, 1);
+        assert_eq!(report.manifest.files[1].name, "lexical/segment_000000.post");
+        assert_eq!(report.manifest.files[1].checksum, crc32fast::hash(b"eno"));
+    }
+
+    #[test]
+    fn test_restore_detects_corruption() {
+        let source = storage_with(&[("a", "one")]);
+        let backup = MemoryStorage::new(MemoryStorageConfig::default());
+        copy_files(&source, &[], &backup, 1, Schema::new(), false).unwrap();
+        write_file(&backup, "a", b"eno").unwrap();
+
+        let target = MemoryStorage::new(MemoryStorageConfig::default());
+        assert!(restore_snapshot(&backup, &target).is_err());
+    }
+
+    #[test]
+    fn test_receiver_verifies_chunks() {
+        let file = SnapshotManifestFile {
+            name: "a".to_string(),
+            size: 6,
+            checksum: crc32fast::hash(b"abcdef"),
+        };
+        let target = MemoryStorage::new(MemoryStorageConfig::default());
+
+        let mut receiver = SnapshotReceiver::new(&target).unwrap();
+        receiver.write_chunk(&file, 0, b"abc").unwrap();
+        assert!(receiver.write_chunk(&file, 4, b"ef").is_err());
+
+        let mut receiver = SnapshotReceiver::new(&target).unwrap();
+        receiver.write_chunk(&file, 0, b"abc").unwrap();
+        receiver.write_chunk(&file, 3, b"xyz").unwrap();
+        assert!(receiver.finish().is_err());
+
+        let mut receiver = SnapshotReceiver::new(&target).unwrap();
+        receiver.write_chunk(&file, 0, b"abc").unwrap();
+        receiver.write_chunk(&file, 3, b"def").unwrap();
+        assert_eq!(receiver.finish().unwrap(), vec![file]);
+        assert!(target.file_exists("a"));
+    }
+}
@@ -0,0 +1,522 @@
+//! Multi-tenant partitioning of a single engine.
+//!
+//! A schema can declare a [`tenant_field`](super::schema::Schema::tenant_field):
+//! a `Text` field holding the tenant each document belongs to. Every
+//! document written to such an engine must carry a tenant key, and
+//! [`Engine::tenant`](super::Engine::tenant) returns a [`Tenant`] handle
+//! scoping all document operations to one key:
+//!
+//! - Writes stamp the tenant key into the tenant field and store the
+//!   document under the internal ID `<tenant>/<id>`, so tenants never see or
+//!   replace each other's documents even when they use the same IDs. IDs in
+//!   results are reported without the prefix.
+//! - Searches get a non-scoring filter on the tenant field, applied to both
+//!   the lexical and the vector side independently of the query.
+//! - [`Tenant::delete_all`] finds every document of the tenant with a single
+//!   term lookup.
+//!
+//! Writes through a handle are checked against the tenant's [`TenantQuota`].
+//! Usage is tracked for every write to the engine and reported per tenant in
+//! [`EngineStats::tenants`](super::EngineStats::tenants).
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::data::{DataValue, Document};
+use crate::error::{LaurusError, Result};
+use crate::lexical::index::structures::completion::Suggestion;
+use crate::lexical::query::Query;
+use crate::lexical::query::term::TermQuery;
+use crate::store::log::SeqNumber;
+
+use super::Engine;
+use super::acl::Identity;
+use super::changes::ScopedChangeSubscription;
+use super::search::{SearchRequest, SearchResult};
+
+/// Separator between the tenant key and the document ID in internal IDs.
+const SEPARATOR: char = '/';
+
+/// Limits on the documents a tenant may store.
+///
+/// Both limits are unlimited by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TenantQuota {
+    /// Maximum number of stored documents. Every chunk added with
+    /// [`Tenant::add_document`] counts as one document.
+    pub max_documents: Option<u64>,
+    /// Maximum total size in bytes of the stored fields of the tenant's
+    /// documents.
+    pub max_storage_bytes: Option<u64>,
+}
+
+/// Current usage of a tenant.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TenantStats {
+    /// Number of stored documents, counting chunks individually.
+    pub document_count: u64,
+    /// Approximate size in bytes of the stored fields of the documents.
+    pub storage_bytes: u64,
+}
+
+/// Per-tenant usage, kept per internal document ID so that replaying or
+/// repeating a write or delete never counts a document twice.
+#[derive(Debug, Default)]
+pub(crate) struct TenantUsage {
+    documents: HashMap<u64, (String, u64)>,
+    tenants: BTreeMap<String, TenantStats>,
+}
+
+impl TenantUsage {
+    /// Record the document stored under `doc_id`, replacing any previous
+    /// record for it.
+    pub(crate) fn insert(&mut self, doc_id: u64, tenant: &str, bytes: u64) {
+        self.remove(doc_id);
+        let stats = self.tenants.entry(tenant.to_string()).or_default();
+        stats.document_count += 1;
+        stats.storage_bytes += bytes;
+        self.documents.insert(doc_id, (tenant.to_string(), bytes));
+    }
+
+    /// Forget the document stored under `doc_id`, if it was recorded.
+    pub(crate) fn remove(&mut self, doc_id: u64) {
+        let Some((tenant, bytes)) = self.documents.remove(&doc_id) else {
+            return;
+        };
+        if let Some(stats) = self.tenants.get_mut(&tenant) {
+            stats.document_count -= 1;
+            stats.storage_bytes -= bytes;
+            if stats.document_count == 0 {
+                self.tenants.remove(&tenant);
+            }
+        }
+    }
+
+    /// Stored size of the document recorded under `doc_id`.
+    pub(crate) fn bytes(&self, doc_id: u64) -> Option<u64> {
+        self.documents.get(&doc_id).map(|(_, bytes)| *bytes)
+    }
+
+    /// Usage of one tenant.
+    pub(crate) fn get(&self, tenant: &str) -> TenantStats {
+        self.tenants.get(tenant).copied().unwrap_or_default()
+    }
+
+    /// Usage of every tenant with at least one document.
+    pub(crate) fn all(&self) -> BTreeMap<String, TenantStats> {
+        self.tenants.clone()
+    }
+
+    /// Drop all records.
+    pub(crate) fn clear(&mut self) {
+        self.documents.clear();
+        self.tenants.clear();
+    }
+}
+
+/// Approximate storage size of a stored document: field names plus values.
+///
+/// The `_id` system field is not counted, so the size does not depend on
+/// how the ID is namespaced.
+pub(crate) fn stored_size(doc: &Document) -> u64 {
+    doc.fields
+        .iter()
+        .filter(|(name, _)| name.as_str() != "_id")
+        .map(|(name, value)| {
+            let value_size = match value {
+                DataValue::Null => 0,
+                DataValue::Bool(_) => 1,
+                DataValue::Int64(_) | DataValue::Float64(_) | DataValue::DateTime(_) => 8,
+                DataValue::Geo(..) => 16,
+                DataValue::Text(text) => text.len(),
+                DataValue::Bytes(bytes, _) => bytes.len(),
+                DataValue::Vector(vector) => vector.len() * std::mem::size_of::<f32>(),
+            };
+            (name.len() + value_size) as u64
+        })
+        .sum()
+}
+
+/// Ensure `key` can be used as a tenant key.
+pub(crate) fn validate_key(key: &str) -> Result<()> {
+    if key.is_empty() || key.contains(SEPARATOR) {
+        return Err(LaurusError::invalid_argument(format!(
+            "Tenant key '{key}' must be non-empty and must not contain '{SEPARATOR}'"
+        )));
+    }
+    Ok(())
+}
+
+/// A view of an [`Engine`] restricted to the documents of one tenant.
+///
+/// Obtained from [`Engine::tenant`](super::Engine::tenant).
+pub struct Tenant<'a> {
+    engine: &'a Engine,
+    field: String,
+    key: String,
+}
+
+impl<'a> Tenant<'a> {
+    pub(crate) fn new(engine: &'a Engine, field: String, key: String) -> Self {
+        Self { engine, field, key }
+    }
+
+    /// The tenant key.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The quota enforced on writes of this tenant.
+    pub fn quota(&self) -> TenantQuota {
+        self.engine.options.tenant_quota(&self.key)
+    }
+
+    /// Current usage of this tenant.
+    pub fn stats(&self) -> TenantStats {
+        self.engine.tenant_usage.lock().get(&self.key)
+    }
+
+    /// Put (upsert) a document of this tenant.
+    ///
+    /// Replaces every document of this tenant with the same ID, like
+    /// [`Engine::put_document`](super::Engine::put_document).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LaurusError::ResourceExhausted`] if the write would exceed
+    /// the tenant's quota, or an error if indexing fails.
+    pub async fn put_document(&self, id: &str, doc: Document) -> Result<()> {
+        self.write(id, doc, false).await
+    }
+
+    /// Add a document of this tenant as a new chunk, keeping existing
+    /// documents with the same ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LaurusError::ResourceExhausted`] if the write would exceed
+    /// the tenant's quota, or an error if indexing fails.
+    pub async fn add_document(&self, id: &str, doc: Document) -> Result<()> {
+        self.write(id, doc, true).await
+    }
+
+    /// Put (upsert) a document of this tenant that expires after `ttl`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema has no expiry field, if `ttl` is out
+    /// of range, or if [`put_document`](Self::put_document) fails.
+    pub async fn put_document_with_ttl(
+        &self,
+        id: &str,
+        doc: Document,
+        ttl: std::time::Duration,
+    ) -> Result<()> {
+        let doc = self.engine.apply_ttl(doc, ttl)?;
+        self.write(id, doc, false).await
+    }
+
+    /// Add a chunk of this tenant that expires after `ttl`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema has no expiry field, if `ttl` is out
+    /// of range, or if [`add_document`](Self::add_document) fails.
+    pub async fn add_document_with_ttl(
+        &self,
+        id: &str,
+        doc: Document,
+        ttl: std::time::Duration,
+    ) -> Result<()> {
+        let doc = self.engine.apply_ttl(doc, ttl)?;
+        self.write(id, doc, true).await
+    }
+
+    /// Get the documents of this tenant with the given ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the internal ID lookup or document retrieval fails.
+    pub async fn get_documents(&self, id: &str) -> Result<Vec<Document>> {
+        let docs = self.engine.get_documents(&self.internal_id(id)).await?;
+        Ok(docs
+            .into_iter()
+            .map(|doc| self.external_document(doc))
+            .collect())
+    }
+
+    /// Get the documents of this tenant with the given ID that are visible
+    /// to `identity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the internal ID lookup or document retrieval fails.
+    pub async fn get_documents_as(&self, id: &str, identity: &Identity) -> Result<Vec<Document>> {
+        let docs = self
+            .engine
+            .get_documents_as(&self.internal_id(id), identity)
+            .await?;
+        Ok(docs
+            .into_iter()
+            .map(|doc| self.external_document(doc))
+            .collect())
+    }
+
+    /// Subscribe to the mutations of this tenant's documents visible to
+    /// `identity`.
+    ///
+    /// Behaves like [`Engine::changes_as`](super::Engine::changes_as), and
+    /// reports documents under their IDs within this tenant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if events after `since_seq` are no longer retained.
+    pub fn changes_as(
+        &self,
+        since_seq: SeqNumber,
+        identity: &Identity,
+    ) -> Result<ScopedChangeSubscription> {
+        self.engine
+            .scoped_changes(since_seq, identity, Some(self.internal_id("")))
+    }
+
+    /// Suggest completions of `prefix` from the documents of this tenant.
+    ///
+    /// Behaves like [`Engine::suggest`](super::Engine::suggest), skipping
+    /// inputs of other tenants' documents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `field` is not a completion field or the
+    /// completion FSTs cannot be read.
+    pub fn suggest(&self, field: &str, prefix: &str, limit: usize) -> Result<Vec<Suggestion>> {
+        self.engine
+            .suggest_in(field, prefix, limit, None, Some(self.filter()))
+    }
+
+    /// Suggest completions of `prefix` from the documents of this tenant
+    /// visible to `identity`.
+    ///
+    /// Behaves like [`Engine::suggest_as`](super::Engine::suggest_as),
+    /// skipping inputs of other tenants' documents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `field` is not a completion field or the
+    /// completion FSTs cannot be read.
+    pub fn suggest_as(
+        &self,
+        field: &str,
+        prefix: &str,
+        limit: usize,
+        identity: &Identity,
+    ) -> Result<Vec<Suggestion>> {
+        self.engine
+            .suggest_in(field, prefix, limit, Some(identity), Some(self.filter()))
+    }
+
+    /// Delete all documents of this tenant with the given ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the WAL write or an index deletion fails.
+    pub async fn delete_documents(&self, id: &str) -> Result<()> {
+        let _guard = self.engine.tenant_lock.lock().await;
+        self.engine.delete_documents(&self.internal_id(id)).await
+    }
+
+    /// Delete every document of this tenant.
+    ///
+    /// The documents are found with a single lookup of the tenant key in the
+    /// tenant field and deleted through the WAL-backed delete path.
+    ///
+    /// # Returns
+    ///
+    /// The number of deleted documents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lookup, the WAL write or an index deletion
+    /// fails.
+    pub async fn delete_all(&self) -> Result<u64> {
+        let _guard = self.engine.tenant_lock.lock().await;
+        let doc_ids = self
+            .engine
+            .lexical
+            .find_doc_ids_by_term(&self.field, &self.key)?;
+        let documents = self.engine.log.get_documents_batch(&doc_ids)?;
+        let mut deleted = 0;
+        for doc_id in doc_ids {
+            let Some(id) = documents
+                .get(&doc_id)
+                .and_then(|doc| doc.get("_id"))
+                .and_then(|v| v.as_text())
+            else {
+                continue;
+            };
+            self.engine.delete_internal(doc_id, id).await?;
+            deleted += 1;
+        }
+        Ok(deleted)
+    }
+
+    /// Search the documents of this tenant.
+    ///
+    /// Behaves like [`Engine::search`](super::Engine::search) with an
+    /// additional filter on the tenant field.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the search fails.
+    pub async fn search(&self, request: SearchRequest) -> Result<Vec<SearchResult>> {
+        let mut plan = self.engine.plan_search(request).await?;
+        plan.restrict(self.filter());
+        let hits = self.engine.collect_hits(&plan, None)?;
+        let results = plan.finish(hits, |ids| {
+            let resolved = self.engine.resolve_ids_and_documents_batch(ids)?;
+            Ok(resolved
+                .into_iter()
+                .filter_map(|(doc_id, (id, doc))| {
+                    Some((doc_id, (self.external_id(&id)?.to_string(), doc)))
+                })
+                .collect())
+        })?;
+        Ok(results
+            .into_iter()
+            .map(|mut result| {
+                result.document = result.document.map(|doc| self.external_document(doc));
+                result
+            })
+            .collect())
+    }
+
+    /// Write a document after checking it against the tenant's quota.
+    async fn write(&self, id: &str, mut doc: Document, as_chunk: bool) -> Result<()> {
+        doc.fields
+            .insert(self.field.clone(), DataValue::Text(self.key.clone()));
+        let internal_id = self.internal_id(id);
+
+        // Serialize the quota check with the write it admits.
+        let _guard = self.engine.tenant_lock.lock().await;
+        let replaced = if as_chunk {
+            Vec::new()
+        } else {
+            self.engine
+                .lexical
+                .find_doc_ids_by_term("_id", &internal_id)?
+        };
+        self.check_quota(&replaced, &self.engine.filter_stored_fields(&doc))?;
+        self.engine
+            .index_internal(&internal_id, doc, as_chunk)
+            .await?;
+        Ok(())
+    }
+
+    /// Fail if storing `doc` in place of the documents `replaced` would take
+    /// the tenant over its quota. Writes that do not increase usage are
+    /// always allowed.
+    fn check_quota(&self, replaced: &[u64], doc: &Document) -> Result<()> {
+        let quota = self.quota();
+        let (current, freed_documents, freed_bytes) = {
+            let usage = self.engine.tenant_usage.lock();
+            let freed: Vec<u64> = replaced.iter().filter_map(|id| usage.bytes(*id)).collect();
+            (
+                usage.get(&self.key),
+                freed.len() as u64,
+                freed.iter().sum::<u64>(),
+            )
+        };
+
+        let documents = current.document_count - freed_documents + 1;
+        if let Some(max) = quota.max_documents
+            && documents > max
+            && documents > current.document_count
+        {
+            return Err(LaurusError::ResourceExhausted(format!(
+                "Tenant '{}' has reached its quota of {max} documents",
+                self.key
+            )));
+        }
+        let bytes = current.storage_bytes - freed_bytes + stored_size(doc);
+        if let Some(max) = quota.max_storage_bytes
+            && bytes > max
+            && bytes > current.storage_bytes
+        {
+            return Err(LaurusError::ResourceExhausted(format!(
+                "Tenant '{}' would use {bytes} bytes, exceeding its storage quota of {max} bytes",
+                self.key
+            )));
+        }
+        Ok(())
+    }
+
+    /// Query matching the documents of this tenant.
+    fn filter(&self) -> Box<dyn Query> {
+        Box::new(TermQuery::new(self.field.as_str(), self.key.as_str()))
+    }
+
+    fn internal_id(&self, id: &str) -> String {
+        format!("{}{SEPARATOR}{id}", self.key)
+    }
+
+    /// The ID within this tenant of an internal ID, if it belongs to it.
+    fn external_id<'b>(&self, internal_id: &'b str) -> Option<&'b str> {
+        internal_id
+            .strip_prefix(self.key.as_str())?
+            .strip_prefix(SEPARATOR)
+    }
+
+    /// Rewrite the `_id` system field of a stored document to the ID within
+    /// this tenant.
+    fn external_document(&self, mut doc: Document) -> Document {
+        if let Some(DataValue::Text(id)) = doc.fields.get_mut("_id")
+            && let Some(external) = self.external_id(id)
+        {
+            *id = external.to_string();
+        }
+        doc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_counts_each_document_once() {
+        let mut usage = TenantUsage::default();
+        usage.insert(1, "acme", 10);
+        usage.insert(2, "acme", 5);
+        usage.insert(2, "acme", 7);
+        usage.insert(3, "globex", 3);
+        assert_eq!(
+            usage.get("acme"),
+            TenantStats {
+                document_count: 2,
+                storage_bytes: 17
+            }
+        );
+
+        usage.remove(1);
+        usage.remove(1);
+        usage.remove(3);
+        assert_eq!(usage.get("acme").document_count, 1);
+        assert_eq!(usage.all().keys().collect::<Vec<_>>(), vec!["acme"]);
+    }
+
+    #[test]
+    fn test_stored_size_ignores_id() {
+        let doc = Document::builder()
+            .add_text("_id", "acme/doc1")
+            .add_text("body", "hello")
+            .add_vector("v", vec![0.0, 1.0])
+            .build();
+        assert_eq!(stored_size(&doc), (4 + 5) + (1 + 8));
+    }
+
+    #[test]
+    fn test_validate_key() {
+        assert!(validate_key("acme").is_ok());
+        assert!(validate_key("").is_err());
+        assert!(validate_key("a/b").is_err());
+    }
+}
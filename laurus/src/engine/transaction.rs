@@ -0,0 +1,122 @@
+//! Atomic multi-document transactions.
+//!
+//! A [`Transaction`] buffers puts, adds and deletes in memory. Nothing is
+//! written to the WAL or to any store until [`Transaction::commit`], which
+//! resolves the buffered operations against the current index and writes
+//! them as a single [`LogEntry::Batch`](crate::store::log::LogEntry::Batch)
+//! record. Recovery replays a batch in full or not at all, and an
+//! [`Engine::commit`](super::Engine::commit) never persists half of an
+//! applied batch.
+
+use crate::data::Document;
+use crate::error::Result;
+
+use super::Engine;
+
+/// A single buffered operation of a [`Transaction`].
+#[derive(Debug, Clone)]
+pub enum TransactionOperation {
+    /// Replace all documents with the given external ID.
+    Put { id: String, document: Document },
+    /// Add a document chunk under the given external ID.
+    Add { id: String, document: Document },
+    /// Delete all documents with the given external ID.
+    Delete { id: String },
+}
+
+/// A set of document mutations that become durable together.
+///
+/// Created by [`Engine::begin`]. Operations are applied in the order they
+/// were buffered: a put or delete also discards earlier operations on the
+/// same ID within the transaction. Dropping the transaction without calling
+/// [`commit`](Self::commit) discards every buffered operation.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut txn = engine.begin();
+/// txn.delete_documents("old");
+/// txn.put_document("new", doc);
+/// txn.commit().await?;
+/// engine.commit().await?;
+/// ```
+pub struct Transaction<'a> {
+    engine: &'a Engine,
+    operations: Vec<TransactionOperation>,
+}
+
+impl<'a> Transaction<'a> {
+    pub(crate) fn new(engine: &'a Engine) -> Self {
+        Self {
+            engine,
+            operations: Vec::new(),
+        }
+    }
+
+    /// Buffer a put (upsert) of `doc` under `id`.
+    pub fn put_document(&mut self, id: &str, doc: Document) -> &mut Self {
+        self.push(TransactionOperation::Put {
+            id: id.to_string(),
+            document: doc,
+        })
+    }
+
+    /// Buffer the addition of `doc` as a new chunk under `id`.
+    pub fn add_document(&mut self, id: &str, doc: Document) -> &mut Self {
+        self.push(TransactionOperation::Add {
+            id: id.to_string(),
+            document: doc,
+        })
+    }
+
+    /// Buffer the deletion of all documents with external ID `id`.
+    pub fn delete_documents(&mut self, id: &str) -> &mut Self {
+        self.push(TransactionOperation::Delete { id: id.to_string() })
+    }
+
+    /// Buffer an arbitrary operation.
+    pub fn push(&mut self, operation: TransactionOperation) -> &mut Self {
+        self.operations.push(operation);
+        self
+    }
+
+    /// Number of buffered operations.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns `true` if no operations are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Discard every buffered operation.
+    pub fn rollback(self) {}
+
+    /// Write all buffered operations to the WAL as one batch and apply them.
+    ///
+    /// Once this returns, the batch is durable: it survives a crash and is
+    /// replayed in full on recovery. Like single-document mutations, the
+    /// changes become visible to search after the next
+    /// [`Engine::commit`](super::Engine::commit), and all of them become
+    /// visible at once.
+    ///
+    /// An empty transaction is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if resolving existing documents, writing the WAL or
+    /// applying the batch to the stores fails. If the WAL write succeeded,
+    /// the batch is still replayed on the next recovery.
+    pub async fn commit(self) -> Result<()> {
+        self.engine.commit_transaction(self.operations).await
+    }
+}
+
+impl std::fmt::Debug for Transaction<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Transaction")
+            .field("operations", &self.operations)
+            .finish_non_exhaustive()
+    }
+}
@@ -55,6 +55,7 @@
 //!     stored_fields,
 //!     field_lengths,
 //!     point_values: AHashMap::new(),
+//!     completion_inputs: AHashMap::new(),
 //! };
 //!
 //! assert_eq!(analyzed_doc.field_lengths["content"], 2);
@@ -76,6 +77,7 @@ use crate::lexical::core::field::FieldValue;
 /// - `stored_fields` - Original field values to be stored (for retrieval)
 /// - `field_lengths` - Number of terms per field (used for BM25 scoring)
 /// - `point_values` - Numeric point values per field (for BKD tree range queries)
+/// - `completion_inputs` - Suggestion inputs and weights per completion field
 ///
 /// # Usage
 ///
@@ -92,6 +94,8 @@ pub struct AnalyzedDocument {
     pub field_lengths: AHashMap<String, u32>,
     /// Field name to numeric point values (for BKD tree).
     pub point_values: AHashMap<String, Vec<f64>>,
+    /// Field name to completion input and its weight (for the completion FST).
+    pub completion_inputs: AHashMap<String, (String, u64)>,
 }
 
 /// An analyzed term with position and metadata.
@@ -115,6 +119,7 @@ impl AnalyzedDocument {
             stored_fields: AHashMap::new(),
             field_lengths: AHashMap::new(),
             point_values: AHashMap::new(),
+            completion_inputs: AHashMap::new(),
         }
     }
 
@@ -109,6 +109,8 @@ impl<D: rkyv::rancor::Fallible + ?Sized>
 ///         stored: true,
 ///         term_vectors: true,
 ///         analyzer: None,
+///         search_analyzer: None,
+///         search_quote_analyzer: None,
 ///     }),
 /// };
 /// ```
@@ -199,6 +201,18 @@ pub struct TextOption {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[rkyv(with = rkyv::with::Skip)]
     pub analyzer: Option<String>,
+
+    /// Analyzer name for query text on this field. When `None`, the
+    /// [`analyzer`](Self::analyzer) is used for queries as well.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[rkyv(with = rkyv::with::Skip)]
+    pub search_analyzer: Option<String>,
+
+    /// Analyzer name for quoted phrases in query text on this field. When
+    /// `None`, the [`search_analyzer`](Self::search_analyzer) is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[rkyv(with = rkyv::with::Skip)]
+    pub search_quote_analyzer: Option<String>,
 }
 
 impl TextOption {
@@ -251,8 +265,9 @@ impl TextOption {
     ///
     /// When set, the engine constructs the corresponding analyzer for this
     /// field instead of using the default. Supported names include
-    /// `"standard"`, `"keyword"`, `"english"`, `"japanese"`, `"simple"`,
-    /// and `"noop"`.
+    /// `"standard"`, `"standard_folding"`, `"keyword"`, `"english"`,
+    /// `"japanese"`, `"korean"`, `"chinese"`, `"phonetic"`, `"simple"`, and
+    /// `"noop"`.
     ///
     /// # Arguments
     ///
@@ -265,6 +280,50 @@ impl TextOption {
         self.analyzer = Some(name.into());
         self
     }
+
+    /// Sets the analyzer name used for query text on this field.
+    ///
+    /// Use it when queries should be analyzed differently from documents,
+    /// e.g. to expand synonyms only at query time, or to search an
+    /// n-gram indexed field with whole words.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The analyzer name.
+    ///
+    /// # Returns
+    ///
+    /// The modified `TextOption` for method chaining.
+    pub fn search_analyzer(mut self, name: impl Into<String>) -> Self {
+        self.search_analyzer = Some(name.into());
+        self
+    }
+
+    /// Sets the analyzer name used for quoted phrases in query text on this
+    /// field.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The analyzer name.
+    ///
+    /// # Returns
+    ///
+    /// The modified `TextOption` for method chaining.
+    pub fn search_quote_analyzer(mut self, name: impl Into<String>) -> Self {
+        self.search_quote_analyzer = Some(name.into());
+        self
+    }
+
+    /// The analyzer names this field refers to.
+    pub fn analyzer_names(&self) -> impl Iterator<Item = &str> {
+        [
+            &self.analyzer,
+            &self.search_analyzer,
+            &self.search_quote_analyzer,
+        ]
+        .into_iter()
+        .filter_map(|name| name.as_deref())
+    }
 }
 
 impl Default for TextOption {
@@ -274,6 +333,8 @@ impl Default for TextOption {
             stored: true,
             term_vectors: false,
             analyzer: None,
+            search_analyzer: None,
+            search_quote_analyzer: None,
         }
     }
 }
@@ -486,6 +547,107 @@ impl GeoOption {
     }
 }
 
+/// Options for Completion (search-as-you-type suggestion) fields.
+///
+/// The text value of a completion field is not indexed for search. Instead,
+/// each segment compiles the lowercased inputs into a weighted FST that
+/// answers [`Engine::suggest`](crate::Engine::suggest) with the
+/// highest-weighted inputs starting with a prefix, tolerating typos once the
+/// prefix is long enough.
+#[derive(
+    Debug, Clone, PartialEq, Serialize, Deserialize, Archive, RkyvSerialize, RkyvDeserialize,
+)]
+pub struct CompletionOption {
+    /// Whether to store the original value.
+    #[serde(default = "default_true")]
+    pub stored: bool,
+
+    /// Name of an `Integer` field holding each document's suggestion weight.
+    /// When `None`, or when a document has no integer value, the weight is 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[rkyv(with = rkyv::with::Skip)]
+    pub weight_field: Option<String>,
+
+    /// Maximum number of edits allowed between the prefix and an input.
+    #[serde(default = "default_max_edits")]
+    pub max_edits: u32,
+
+    /// Number of leading prefix characters that must match exactly.
+    #[serde(default = "default_fuzzy_prefix_length")]
+    pub prefix_length: usize,
+
+    /// Minimum prefix length, in characters, before edits are allowed.
+    #[serde(default = "default_min_fuzzy_length")]
+    pub min_fuzzy_length: usize,
+
+    /// Whether swapping two adjacent characters counts as a single edit.
+    #[serde(default = "default_true")]
+    pub transpositions: bool,
+}
+
+fn default_max_edits() -> u32 {
+    1
+}
+
+fn default_fuzzy_prefix_length() -> usize {
+    1
+}
+
+fn default_min_fuzzy_length() -> usize {
+    3
+}
+
+impl Default for CompletionOption {
+    fn default() -> Self {
+        Self {
+            stored: true,
+            weight_field: None,
+            max_edits: default_max_edits(),
+            prefix_length: default_fuzzy_prefix_length(),
+            min_fuzzy_length: default_min_fuzzy_length(),
+            transpositions: true,
+        }
+    }
+}
+
+impl CompletionOption {
+    /// Set whether the field is stored.
+    pub fn stored(mut self, stored: bool) -> Self {
+        self.stored = stored;
+        self
+    }
+
+    /// Set the name of the `Integer` field holding the suggestion weight.
+    pub fn weight_field(mut self, name: impl Into<String>) -> Self {
+        self.weight_field = Some(name.into());
+        self
+    }
+
+    /// Set the maximum number of edits allowed (0 disables fuzzy matching).
+    pub fn max_edits(mut self, max_edits: u32) -> Self {
+        self.max_edits = max_edits;
+        self
+    }
+
+    /// Set the number of leading prefix characters that must match exactly.
+    pub fn prefix_length(mut self, prefix_length: usize) -> Self {
+        self.prefix_length = prefix_length;
+        self
+    }
+
+    /// Set the minimum prefix length before edits are allowed.
+    pub fn min_fuzzy_length(mut self, min_fuzzy_length: usize) -> Self {
+        self.min_fuzzy_length = min_fuzzy_length;
+        self
+    }
+
+    /// Set whether adjacent transpositions count as a single edit.
+    pub fn transpositions(mut self, transpositions: bool) -> Self {
+        self.transpositions = transpositions;
+        self
+    }
+}
+
 /// Unified field option type that wraps all field-specific options.
 ///
 /// This enum provides a type-safe way to store configuration options
@@ -502,6 +664,8 @@ impl GeoOption {
 ///     stored: true,
 ///     term_vectors: true,
 ///     analyzer: None,
+///     search_analyzer: None,
+///     search_quote_analyzer: None,
 /// });
 ///
 /// // Bytes field (e.g. for binary data)
@@ -531,6 +695,9 @@ pub enum FieldOption {
 
     /// Options for geographic point fields.
     Geo(GeoOption),
+
+    /// Options for completion (suggestion) fields.
+    Completion(CompletionOption),
 }
 
 impl Default for FieldOption {
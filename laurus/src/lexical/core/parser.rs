@@ -284,6 +284,7 @@ impl DocumentParser {
             stored_fields,
             field_lengths,
             point_values,
+            completion_inputs: AHashMap::new(),
         })
     }
 
@@ -106,6 +106,206 @@ impl Automaton for LevenshteinAutomaton {
     }
 }
 
+/// A Levenshtein automaton that matches strings starting with a fuzzy prefix.
+///
+/// A candidate matches when some prefix of it is within `max_edits` edits
+/// of the pattern, and its first `prefix_length` characters equal those of
+/// the pattern. Unlike [`LevenshteinAutomaton`], it runs incrementally over
+/// bytes: it implements [`fst::Automaton`] so that an FST can be walked
+/// without visiting keys that can no longer match.
+///
+/// When walking an FST, a `\0` byte marks the end of the part of the key
+/// matched against the pattern; everything after it is accepted if the
+/// part before it matched. This lets keys carry a payload after the input.
+#[derive(Debug, Clone)]
+pub struct FuzzyPrefixAutomaton {
+    /// The pattern characters
+    pattern: Vec<char>,
+    /// Maximum edit distance
+    max_edits: u32,
+    /// Number of leading characters that must match exactly
+    prefix_length: usize,
+    /// Whether to use Damerau-Levenshtein (transpositions count as 1 edit)
+    transpositions: bool,
+}
+
+/// The state of a [`FuzzyPrefixAutomaton`] after reading some bytes.
+#[derive(Debug, Clone)]
+pub enum FuzzyPrefixState {
+    /// Reading characters that have not matched the pattern yet.
+    Reading(FuzzyPrefixRows),
+    /// A prefix of the characters read matched the pattern.
+    Matched,
+    /// The `\0` after a matching prefix was read; the rest is accepted.
+    Complete,
+    /// The key can no longer match.
+    Dead,
+}
+
+/// Dynamic programming rows of a [`FuzzyPrefixAutomaton`] being read.
+#[derive(Debug, Clone)]
+pub struct FuzzyPrefixRows {
+    /// Edit distances from each pattern prefix to the characters read
+    row: Vec<u32>,
+    /// The row before the last character, for transpositions
+    previous_row: Vec<u32>,
+    /// The last character read
+    last_char: Option<char>,
+    /// Bytes of a partially read UTF-8 character
+    pending: Vec<u8>,
+    /// Number of characters read
+    chars_read: usize,
+}
+
+impl FuzzyPrefixAutomaton {
+    /// Create a new fuzzy prefix automaton.
+    pub fn new(
+        pattern: impl AsRef<str>,
+        max_edits: u32,
+        prefix_length: usize,
+        transpositions: bool,
+    ) -> Self {
+        FuzzyPrefixAutomaton {
+            pattern: pattern.as_ref().chars().collect(),
+            max_edits,
+            prefix_length,
+            transpositions,
+        }
+    }
+
+    /// Get the maximum edit distance.
+    pub fn max_edits(&self) -> u32 {
+        self.max_edits
+    }
+
+    /// Get the prefix length.
+    pub fn prefix_length(&self) -> usize {
+        self.prefix_length
+    }
+
+    /// The state after reading `rows`, depending on whether they match.
+    fn settle(&self, rows: FuzzyPrefixRows) -> FuzzyPrefixState {
+        let exact = self.prefix_length.min(self.pattern.len());
+        if rows.chars_read >= exact && rows.row[self.pattern.len()] <= self.max_edits {
+            FuzzyPrefixState::Matched
+        } else if rows.row.iter().all(|&d| d > self.max_edits) {
+            FuzzyPrefixState::Dead
+        } else {
+            FuzzyPrefixState::Reading(rows)
+        }
+    }
+
+    /// Feed one character to the rows.
+    fn step(&self, rows: &FuzzyPrefixRows, c: char) -> FuzzyPrefixState {
+        let exact = self.prefix_length.min(self.pattern.len());
+        if rows.chars_read < exact && self.pattern[rows.chars_read] != c {
+            return FuzzyPrefixState::Dead;
+        }
+
+        let mut row = Vec::with_capacity(rows.row.len());
+        row.push(rows.row[0] + 1);
+        for i in 1..=self.pattern.len() {
+            let cost = u32::from(self.pattern[i - 1] != c);
+            let mut distance = (rows.row[i] + 1)
+                .min(row[i - 1] + 1)
+                .min(rows.row[i - 1] + cost);
+            if self.transpositions
+                && i > 1
+                && rows.last_char == Some(self.pattern[i - 1])
+                && self.pattern[i - 2] == c
+            {
+                distance = distance.min(rows.previous_row[i - 2] + 1);
+            }
+            row.push(distance);
+        }
+
+        self.settle(FuzzyPrefixRows {
+            previous_row: rows.row.clone(),
+            row,
+            last_char: Some(c),
+            pending: Vec::new(),
+            chars_read: rows.chars_read + 1,
+        })
+    }
+}
+
+impl Automaton for FuzzyPrefixAutomaton {
+    fn matches(&self, candidate: &str) -> bool {
+        let mut state = fst::Automaton::start(self);
+        for &byte in candidate.as_bytes() {
+            state = fst::Automaton::accept(self, &state, byte);
+        }
+        matches!(state, FuzzyPrefixState::Matched)
+    }
+
+    fn initial_seek_term(&self) -> Option<String> {
+        if self.prefix_length > 0 && !self.pattern.is_empty() {
+            Some(self.pattern.iter().take(self.prefix_length).collect())
+        } else {
+            None
+        }
+    }
+}
+
+impl fst::Automaton for FuzzyPrefixAutomaton {
+    type State = FuzzyPrefixState;
+
+    fn start(&self) -> FuzzyPrefixState {
+        let row: Vec<u32> = (0..=self.pattern.len() as u32).collect();
+        self.settle(FuzzyPrefixRows {
+            previous_row: row.clone(),
+            row,
+            last_char: None,
+            pending: Vec::new(),
+            chars_read: 0,
+        })
+    }
+
+    fn is_match(&self, state: &FuzzyPrefixState) -> bool {
+        matches!(state, FuzzyPrefixState::Complete)
+    }
+
+    fn can_match(&self, state: &FuzzyPrefixState) -> bool {
+        !matches!(state, FuzzyPrefixState::Dead)
+    }
+
+    fn will_always_match(&self, state: &FuzzyPrefixState) -> bool {
+        matches!(state, FuzzyPrefixState::Complete)
+    }
+
+    fn accept(&self, state: &FuzzyPrefixState, byte: u8) -> FuzzyPrefixState {
+        match state {
+            FuzzyPrefixState::Reading(_) if byte == 0 => FuzzyPrefixState::Dead,
+            FuzzyPrefixState::Reading(rows) => {
+                let mut pending = rows.pending.clone();
+                pending.push(byte);
+                let expected = match pending[0] {
+                    0x00..=0x7F => 1,
+                    0xC0..=0xDF => 2,
+                    0xE0..=0xEF => 3,
+                    0xF0..=0xF7 => 4,
+                    _ => return FuzzyPrefixState::Dead,
+                };
+                if pending.len() < expected {
+                    return FuzzyPrefixState::Reading(FuzzyPrefixRows {
+                        pending,
+                        ..rows.clone()
+                    });
+                }
+                match std::str::from_utf8(&pending)
+                    .ok()
+                    .and_then(|s| s.chars().next())
+                {
+                    Some(c) => self.step(rows, c),
+                    None => FuzzyPrefixState::Dead,
+                }
+            }
+            FuzzyPrefixState::Matched if byte == 0 => FuzzyPrefixState::Complete,
+            other => other.clone(),
+        }
+    }
+}
+
 /// An automaton backed by a regular expression.
 #[derive(Debug, Clone)]
 pub struct RegexAutomaton {
@@ -301,4 +501,45 @@ mod tests {
         assert!(!automaton.matches("xello")); // prefix doesn't match
         assert!(!automaton.matches("world")); // prefix doesn't match
     }
+
+    #[test]
+    fn test_fuzzy_prefix_automaton() {
+        let automaton = FuzzyPrefixAutomaton::new("helo", 1, 1, true);
+
+        assert!(automaton.matches("helo")); // exact
+        assert!(automaton.matches("hello world")); // 1 insertion, then anything
+        assert!(automaton.matches("hleo")); // 1 transposition
+        assert!(automaton.matches("halo")); // 1 substitution
+
+        assert!(!automaton.matches("he")); // too short
+        assert!(!automaton.matches("jelo")); // first character must match exactly
+        assert!(!automaton.matches("hxyz")); // too different
+    }
+
+    #[test]
+    fn test_fuzzy_prefix_automaton_utf8() {
+        let automaton = FuzzyPrefixAutomaton::new("東京", 1, 0, false);
+
+        assert!(automaton.matches("東京都"));
+        assert!(automaton.matches("東都"));
+        assert!(!automaton.matches("大阪"));
+    }
+
+    #[test]
+    fn test_fuzzy_prefix_automaton_key_separator() {
+        use fst::Automaton as _;
+
+        let automaton = FuzzyPrefixAutomaton::new("ab", 0, 0, false);
+        let run = |key: &[u8]| {
+            let mut state = automaton.start();
+            for &byte in key {
+                state = automaton.accept(&state, byte);
+            }
+            automaton.is_match(&state)
+        };
+
+        assert!(run(b"abc\0payload\0"));
+        assert!(!run(b"a\0b"));
+        assert!(!run(b"abc"));
+    }
 }
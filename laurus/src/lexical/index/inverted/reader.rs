@@ -15,12 +15,14 @@ use crate::analysis::token::Token;
 use crate::error::{LaurusError, Result};
 use crate::lexical::core::document::Document;
 use crate::lexical::core::field::FieldValue;
+use crate::lexical::index::inverted::core::automaton::FuzzyPrefixAutomaton;
 use crate::lexical::index::inverted::core::posting::{Posting, PostingList};
 use crate::lexical::index::inverted::core::terms::{
     InvertedIndexTerms, MergedInvertedIndexTerms, TermDictionaryAccess, Terms,
 };
 use crate::lexical::index::inverted::segment::SegmentInfo;
 use crate::lexical::index::structures::bkd_tree::{BKDReader, BKDTree};
+use crate::lexical::index::structures::completion::{CompletionIndex, Suggestion};
 use crate::lexical::index::structures::dictionary::HybridTermDictionary;
 use crate::lexical::index::structures::dictionary::TermInfo;
 use crate::lexical::index::structures::doc_values::DocValuesReader;
@@ -319,6 +321,9 @@ pub struct SegmentReader {
     /// Cached BKD trees: field -> tree
     bkd_trees: RwLock<AHashMap<String, Arc<dyn BKDTree>>>,
 
+    /// Cached completion FSTs: field -> FST
+    completion_indexes: RwLock<AHashMap<String, Arc<CompletionIndex>>>,
+
     /// Whether the segment is loaded.
     loaded: AtomicBool,
 }
@@ -344,6 +349,7 @@ impl SegmentReader {
             doc_values: RwLock::new(None),
             deletion_bitmap: RwLock::new(None),
             bkd_trees: RwLock::new(AHashMap::new()),
+            completion_indexes: RwLock::new(AHashMap::new()),
             loaded: AtomicBool::new(false),
         };
 
@@ -926,6 +932,50 @@ impl SegmentReader {
 
         Ok(None)
     }
+
+    /// Get the completion FST for a field, loading it if necessary.
+    pub fn get_completion_index(&self, field: &str) -> Result<Option<Arc<CompletionIndex>>> {
+        // Check cache
+        if let Some(index) = self.completion_indexes.read().unwrap().get(field) {
+            return Ok(Some(index.clone()));
+        }
+
+        // Try to open file
+        let completion_file = format!("{}.{}.sugg", self.info.segment_id, field);
+        if self.storage.file_exists(&completion_file) {
+            let index = Arc::new(CompletionIndex::open(
+                self.storage.clone(),
+                &completion_file,
+            )?);
+
+            // Update cache
+            self.completion_indexes
+                .write()
+                .unwrap()
+                .insert(field.to_string(), index.clone());
+
+            return Ok(Some(index));
+        }
+
+        Ok(None)
+    }
+
+    /// Find the `limit` highest-weighted completions of live documents in
+    /// this segment that `accept` allows.
+    pub fn suggest(
+        &self,
+        field: &str,
+        automaton: &FuzzyPrefixAutomaton,
+        limit: usize,
+        accept: &dyn Fn(u64) -> bool,
+    ) -> Result<Vec<Suggestion>> {
+        let Some(index) = self.get_completion_index(field)? else {
+            return Ok(Vec::new());
+        };
+        Ok(index.suggest(automaton, limit, |doc_id| {
+            !self.is_deleted(doc_id).unwrap_or(false) && accept(doc_id)
+        }))
+    }
 }
 
 #[derive(Debug)]
@@ -1411,6 +1461,35 @@ impl crate::lexical::reader::LexicalIndexReader for InvertedIndexReader {
             Ok(Some(Arc::new(MultiSegmentBKDTree { trees })))
         }
     }
+
+    fn suggest(
+        &self,
+        field: &str,
+        automaton: &FuzzyPrefixAutomaton,
+        limit: usize,
+        accept: &dyn Fn(u64) -> bool,
+    ) -> Result<Vec<Suggestion>> {
+        self.check_closed()?;
+
+        // Each segment returns its own top `limit`; keep the highest weight
+        // of every text across segments.
+        let mut best: AHashMap<String, u64> = AHashMap::new();
+        for segment_reader in &self.segment_readers {
+            let reader = segment_reader.read().unwrap();
+            for suggestion in reader.suggest(field, automaton, limit, accept)? {
+                let weight = best.entry(suggestion.text).or_insert(suggestion.weight);
+                *weight = (*weight).max(suggestion.weight);
+            }
+        }
+
+        let mut suggestions: Vec<Suggestion> = best
+            .into_iter()
+            .map(|(text, weight)| Suggestion { text, weight })
+            .collect();
+        suggestions.sort_by(|a, b| b.weight.cmp(&a.weight).then_with(|| a.text.cmp(&b.text)));
+        suggestions.truncate(limit);
+        Ok(suggestions)
+    }
 }
 
 // Implementation of TermDictionaryAccess for InvertedIndexReader
@@ -653,6 +653,16 @@ impl crate::lexical::search::searcher::LexicalSearcher for InvertedIndexSearcher
     ) -> Result<u64> {
         InvertedIndexSearcher::count(self, request)
     }
+
+    fn suggest(
+        &self,
+        field: &str,
+        automaton: &crate::lexical::index::inverted::core::automaton::FuzzyPrefixAutomaton,
+        limit: usize,
+        accept: &dyn Fn(u64) -> bool,
+    ) -> Result<Vec<crate::lexical::index::structures::completion::Suggestion>> {
+        self.reader.suggest(field, automaton, limit, accept)
+    }
 }
 
 #[cfg(test)]
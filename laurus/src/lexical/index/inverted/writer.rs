@@ -11,6 +11,8 @@ use crate::analysis::analyzer::analyzer::Analyzer;
 use crate::analysis::analyzer::per_field::PerFieldAnalyzer;
 use crate::analysis::analyzer::standard::StandardAnalyzer;
 use crate::analysis::token::Token;
+use crate::analysis::token_filter::Filter;
+use crate::analysis::token_filter::flatten_graph::FlattenGraphFilter;
 use crate::error::{LaurusError, Result};
 use crate::lexical::core::analyzed::{AnalyzedDocument, AnalyzedTerm};
 use crate::lexical::core::document::Document;
@@ -20,6 +22,7 @@ use crate::lexical::index::inverted::IndexMetadata;
 use crate::lexical::index::inverted::core::posting::{Posting, TermPostingIndex};
 use crate::lexical::index::inverted::segment::SegmentInfo;
 use crate::lexical::index::structures::bkd_tree::BKDWriter;
+use crate::lexical::index::structures::completion::CompletionBuilder;
 use crate::lexical::index::structures::dictionary::{TermDictionaryBuilder, TermInfo};
 use crate::lexical::index::structures::doc_values::DocValuesWriter;
 use crate::lexical::writer::LexicalIndexWriter;
@@ -382,6 +385,7 @@ impl InvertedIndexWriter {
         let mut field_terms = AHashMap::new();
         let mut stored_fields = AHashMap::new();
         let mut point_values = AHashMap::new();
+        let mut completion_inputs = AHashMap::new();
 
         // Process each field in the document
         for (field_name, val) in &doc.fields {
@@ -411,6 +415,7 @@ impl InvertedIndexWriter {
                 Some(FieldOption::DateTime(opt)) => (opt.indexed, opt.stored),
                 Some(FieldOption::Geo(opt)) => (opt.indexed, opt.stored),
                 Some(FieldOption::Bytes(opt)) => (false, opt.stored), // Bytes are not lexically indexed
+                Some(FieldOption::Completion(opt)) => (false, opt.stored), // Indexed in the completion FST
                 None => (true, true), // Internal or schema-less default
             };
 
@@ -430,7 +435,7 @@ impl InvertedIndexWriter {
                             self.config.analyzer.analyze(text)?
                         };
                         let token_vec: Vec<Token> = tokens.collect();
-                        let analyzed_terms = self.tokens_to_analyzed_terms(token_vec);
+                        let analyzed_terms = self.tokens_to_analyzed_terms(token_vec)?;
 
                         field_terms.insert(field_name.clone(), analyzed_terms);
                     }
@@ -507,6 +512,17 @@ impl InvertedIndexWriter {
                 }
             }
 
+            // Completion inputs go to the completion FST, weighted by another field
+            if let Some(FieldOption::Completion(opt)) = option
+                && let DataValue::Text(text) = val
+            {
+                let weight = match opt.weight_field.as_ref().and_then(|f| doc.fields.get(f)) {
+                    Some(DataValue::Int64(weight)) => (*weight).max(0) as u64,
+                    _ => 0,
+                };
+                completion_inputs.insert(field_name.clone(), (text.clone(), weight));
+            }
+
             // Store the field if enabled
             if should_store {
                 stored_fields.insert(field_name.clone(), val.clone());
@@ -524,28 +540,49 @@ impl InvertedIndexWriter {
             stored_fields,
             field_lengths,
             point_values,
+            completion_inputs,
         })
     }
 
     /// Convert tokens to analyzed terms.
-    fn tokens_to_analyzed_terms(&self, tokens: Vec<Token>) -> Vec<AnalyzedTerm> {
+    ///
+    /// Positions follow the tokens' position increments, so stacked tokens
+    /// (e.g. synonyms) share a position. Token graphs are flattened first,
+    /// because the index stores positions but not position lengths.
+    fn tokens_to_analyzed_terms(&self, tokens: Vec<Token>) -> Result<Vec<AnalyzedTerm>> {
+        let tokens: Vec<Token> = if tokens.iter().any(|t| t.position_length > 1) {
+            FlattenGraphFilter::new()
+                .filter(Box::new(tokens.into_iter()))?
+                .collect()
+        } else {
+            tokens
+        };
+
         let mut term_frequencies = AHashMap::new();
         let mut analyzed_terms = Vec::new();
+        let mut position: Option<u32> = None;
+
+        for token in tokens {
+            let increment = token.position_increment as u32;
+            let current = match position {
+                None => increment.saturating_sub(1),
+                Some(previous) => previous + increment,
+            };
+            position = Some(current);
 
-        for (position, token) in tokens.into_iter().enumerate() {
             let term = token.text;
             let frequency = term_frequencies.entry(term.clone()).or_insert(0);
             *frequency += 1;
 
             analyzed_terms.push(AnalyzedTerm {
                 term: term.clone(),
-                position: position as u32,
+                position: current,
                 frequency: *frequency,
                 offset: (token.start_offset, token.end_offset),
             });
         }
 
-        analyzed_terms
+        Ok(analyzed_terms)
     }
 
     /// Add an analyzed document to the inverted index.
@@ -613,8 +650,8 @@ impl InvertedIndexWriter {
         // Write segment metadata
         self.write_segment_metadata(&segment_name)?;
 
-        // Write BKD trees for numeric fields
         self.write_bkd_trees(&segment_name)?;
+        self.write_completion_fsts(&segment_name)?;
 
         // COMPATIBILITY: Also write documents as JSON for BasicIndexReader
         self.write_json_documents(&segment_name)?;
@@ -900,6 +937,32 @@ impl InvertedIndexWriter {
         Ok(())
     }
 
+    /// Write completion FSTs for completion fields.
+    fn write_completion_fsts(&self, segment_name: &str) -> Result<()> {
+        let mut builders: AHashMap<String, CompletionBuilder> = AHashMap::new();
+
+        for (doc_id, doc) in &self.buffered_docs {
+            for (field, (input, weight)) in &doc.completion_inputs {
+                builders
+                    .entry(field.clone())
+                    .or_default()
+                    .add(input, *weight, *doc_id);
+            }
+        }
+
+        for (field, builder) in builders {
+            if builder.is_empty() {
+                continue;
+            }
+
+            let file_name = format!("{segment_name}.{field}.sugg");
+            let mut output = self.storage.create_output(&file_name)?;
+            std::io::Write::write_all(&mut output, &builder.build()?)?;
+            output.close()?;
+        }
+        Ok(())
+    }
+
     /// Write segment metadata.
     fn write_segment_metadata(&self, segment_name: &str) -> Result<()> {
         let min_id = self
@@ -1112,19 +1175,24 @@ impl InvertedIndexWriter {
 
             manager.initialize_segment(&segment_id, min_doc_id, max_doc_id)?;
 
-            let delete_result = manager.delete_document(&segment_id, doc_id, "upsert");
-            if delete_result.is_err() {
-                // If initializing failed (e.g. bitmap corrupted), try force re-init
-                // In production code we should be more careful, but here we prioritize consistency
-                manager.initialize_segment(&segment_id, min_doc_id, max_doc_id)?;
-                manager.delete_document(&segment_id, doc_id, "upsert")?;
-            }
+            let newly_deleted = match manager.delete_document(&segment_id, doc_id, "upsert") {
+                Ok(newly_deleted) => newly_deleted,
+                Err(_) => {
+                    // If initializing failed (e.g. bitmap corrupted), try force re-init
+                    // In production code we should be more careful, but here we prioritize consistency
+                    manager.initialize_segment(&segment_id, min_doc_id, max_doc_id)?;
+                    manager.delete_document(&segment_id, doc_id, "upsert")?
+                }
+            };
 
             // Update segment metadata to reflect deletions
             self.update_segment_meta_deletions(&segment_id)?;
 
-            // Track globally
-            self.stats.deleted_count += 1;
+            // Track globally. A replayed WAL delete hits a document whose bit
+            // is already set and must not be counted twice.
+            if newly_deleted {
+                self.stats.deleted_count += 1;
+            }
         }
 
         // Add to pending deletions for NRT visibility
@@ -1,3 +1,4 @@
 pub mod bkd_tree;
+pub mod completion;
 pub mod dictionary;
 pub mod doc_values;
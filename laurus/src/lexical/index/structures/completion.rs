@@ -0,0 +1,373 @@
+//! Weighted FST for prefix completion.
+//!
+//! Each segment compiles the inputs of a completion field into an FST whose
+//! keys are `normalized input \0 input \0 doc id` and whose values are the
+//! complement of the input's weight. FST outputs along a path only grow and
+//! the output stored at a shared prefix is the smallest of its keys, so a
+//! best-first walk visits completions from the highest weight down and can
+//! stop after the first `k` live, distinct ones.
+//!
+//! # Examples
+//!
+//! ```
+//! use laurus::lexical::index::inverted::core::automaton::FuzzyPrefixAutomaton;
+//! use laurus::lexical::index::structures::completion::{CompletionBuilder, CompletionIndex};
+//!
+//! let mut builder = CompletionBuilder::new();
+//! builder.add("Rust", 10, 0);
+//! builder.add("Ruby", 20, 1);
+//! builder.add("Python", 30, 2);
+//! let index = CompletionIndex::new(builder.build().unwrap()).unwrap();
+//!
+//! let automaton = FuzzyPrefixAutomaton::new("ru", 0, 0, false);
+//! let texts: Vec<_> = index
+//!     .suggest(&automaton, 10, |_| true)
+//!     .into_iter()
+//!     .map(|s| s.text)
+//!     .collect();
+//! assert_eq!(texts, vec!["Ruby", "Rust"]);
+//! ```
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashSet};
+use std::io::Read;
+use std::sync::Arc;
+
+use fst::raw::CompiledAddr;
+use fst::{Automaton, Map, MapBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{LaurusError, Result};
+use crate::storage::Storage;
+
+/// A completion returned by a suggestion lookup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suggestion {
+    /// The input text as it was indexed.
+    pub text: String,
+    /// The weight of the input.
+    pub weight: u64,
+}
+
+/// Normalize completion input or a prefix for matching.
+///
+/// Inputs are matched case-insensitively; `\0` is reserved as the key
+/// separator and removed.
+pub fn normalize(text: &str) -> String {
+    text.to_lowercase().chars().filter(|&c| c != '\0').collect()
+}
+
+/// Builder for the completion FST of one field in one segment.
+#[derive(Debug, Clone, Default)]
+pub struct CompletionBuilder {
+    /// Keys and their costs (`u64::MAX - weight`).
+    entries: Vec<(Vec<u8>, u64)>,
+}
+
+impl CompletionBuilder {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the input of a document with its weight.
+    ///
+    /// Inputs that are empty after normalization are ignored.
+    pub fn add(&mut self, input: &str, weight: u64, doc_id: u64) {
+        let normalized = normalize(input);
+        if normalized.is_empty() {
+            return;
+        }
+        let mut key = normalized.into_bytes();
+        key.push(0);
+        key.extend_from_slice(input.as_bytes());
+        key.push(0);
+        key.extend_from_slice(&doc_id.to_be_bytes());
+        self.entries.push((key, u64::MAX - weight));
+    }
+
+    /// Check whether no inputs were added.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Build the FST and return its bytes.
+    pub fn build(mut self) -> Result<Vec<u8>> {
+        self.entries.sort_unstable();
+        self.entries.dedup_by(|a, b| a.0 == b.0);
+
+        let mut builder = MapBuilder::memory();
+        for (key, cost) in &self.entries {
+            builder
+                .insert(key, *cost)
+                .map_err(|e| LaurusError::index(format!("Completion FST build error: {e}")))?;
+        }
+        builder
+            .into_inner()
+            .map_err(|e| LaurusError::index(format!("Completion FST finalize error: {e}")))
+    }
+}
+
+/// A loaded completion FST.
+#[derive(Debug)]
+pub struct CompletionIndex {
+    map: Map<Vec<u8>>,
+}
+
+impl CompletionIndex {
+    /// Load a completion FST from the bytes produced by [`CompletionBuilder::build`].
+    pub fn new(bytes: Vec<u8>) -> Result<Self> {
+        let map = Map::new(bytes)
+            .map_err(|e| LaurusError::index(format!("Invalid completion FST: {e}")))?;
+        Ok(Self { map })
+    }
+
+    /// Open a completion FST from storage.
+    pub fn open(storage: Arc<dyn Storage>, path: &str) -> Result<Self> {
+        let mut input = storage.open_input(path)?;
+        let mut bytes = Vec::new();
+        input.read_to_end(&mut bytes)?;
+        Self::new(bytes)
+    }
+
+    /// Get the number of inputs.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Check whether the FST has no inputs.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Find the `limit` highest-weighted inputs accepted by `automaton`.
+    ///
+    /// The automaton is run over the normalized input followed by the rest
+    /// of the key, so it must accept everything after the first `\0` (see
+    /// [`FuzzyPrefixAutomaton`](crate::lexical::index::inverted::core::automaton::FuzzyPrefixAutomaton)).
+    /// Inputs of documents for which `is_live` returns false are skipped,
+    /// and each text is returned once, with its highest weight. Results are
+    /// ordered by weight (highest first), then by text.
+    pub fn suggest<A, F>(&self, automaton: &A, limit: usize, mut is_live: F) -> Vec<Suggestion>
+    where
+        A: Automaton,
+        F: FnMut(u64) -> bool,
+    {
+        let mut suggestions = Vec::new();
+        let start = automaton.start();
+        if limit == 0 || !automaton.can_match(&start) {
+            return suggestions;
+        }
+
+        let fst = self.map.as_fst();
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse(Path {
+            cost: 0,
+            key: Vec::new(),
+            addr: fst.root().addr(),
+            state: Some(start),
+        }));
+
+        let mut seen = HashSet::new();
+        // Cost of the `limit`-th suggestion; ties with it are still collected
+        // so that the final order does not depend on the FST layout.
+        let mut cutoff = None;
+        while let Some(Reverse(path)) = heap.pop() {
+            if cutoff.is_some_and(|cutoff| path.cost > cutoff) {
+                break;
+            }
+
+            let Some(state) = path.state else {
+                if let Some((text, doc_id)) = decode_key(&path.key)
+                    && is_live(doc_id)
+                    && seen.insert(text.clone())
+                {
+                    suggestions.push(Suggestion {
+                        text,
+                        weight: u64::MAX - path.cost,
+                    });
+                    if suggestions.len() == limit {
+                        cutoff = Some(path.cost);
+                    }
+                }
+                continue;
+            };
+
+            let node = fst.node(path.addr);
+            if node.is_final() && automaton.is_match(&state) {
+                heap.push(Reverse(Path {
+                    cost: path.cost + node.final_output().value(),
+                    key: path.key.clone(),
+                    addr: path.addr,
+                    state: None,
+                }));
+            }
+            for transition in node.transitions() {
+                let next = automaton.accept(&state, transition.inp);
+                if !automaton.can_match(&next) {
+                    continue;
+                }
+                let mut key = path.key.clone();
+                key.push(transition.inp);
+                heap.push(Reverse(Path {
+                    cost: path.cost + transition.out.value(),
+                    key,
+                    addr: transition.addr,
+                    state: Some(next),
+                }));
+            }
+        }
+
+        suggestions.sort_by(|a, b| b.weight.cmp(&a.weight).then_with(|| a.text.cmp(&b.text)));
+        suggestions.truncate(limit);
+        suggestions
+    }
+}
+
+/// A partial key on the best-first search frontier.
+struct Path<S> {
+    /// Sum of the FST outputs read so far; a lower bound on the cost of
+    /// every key extending this path.
+    cost: u64,
+    key: Vec<u8>,
+    addr: CompiledAddr,
+    /// The automaton state after `key`, or `None` once `key` is a whole
+    /// key and `cost` its exact cost.
+    state: Option<S>,
+}
+
+impl<S> PartialEq for Path<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<S> Eq for Path<S> {}
+
+impl<S> PartialOrd for Path<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Path<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost
+            .cmp(&other.cost)
+            .then_with(|| self.key.cmp(&other.key))
+            .then_with(|| other.state.is_none().cmp(&self.state.is_none()))
+    }
+}
+
+/// Split a key into its input text and document ID.
+fn decode_key(key: &[u8]) -> Option<(String, u64)> {
+    let separator = key.iter().position(|&b| b == 0)?;
+    let doc_id_start = key.len().checked_sub(8)?;
+    if doc_id_start <= separator || key[doc_id_start - 1] != 0 {
+        return None;
+    }
+    let text = String::from_utf8(key[separator + 1..doc_id_start - 1].to_vec()).ok()?;
+    let doc_id = u64::from_be_bytes(key[doc_id_start..].try_into().ok()?);
+    Some((text, doc_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexical::index::inverted::core::automaton::FuzzyPrefixAutomaton;
+
+    fn index(inputs: &[(&str, u64)]) -> CompletionIndex {
+        let mut builder = CompletionBuilder::new();
+        for (doc_id, (input, weight)) in inputs.iter().enumerate() {
+            builder.add(input, *weight, doc_id as u64);
+        }
+        CompletionIndex::new(builder.build().unwrap()).unwrap()
+    }
+
+    fn texts(suggestions: Vec<Suggestion>) -> Vec<String> {
+        suggestions.into_iter().map(|s| s.text).collect()
+    }
+
+    #[test]
+    fn test_suggest_orders_by_weight() {
+        let index = index(&[
+            ("New York", 5),
+            ("Newark", 9),
+            ("New Delhi", 7),
+            ("Boston", 100),
+        ]);
+        let automaton = FuzzyPrefixAutomaton::new("new", 0, 0, false);
+
+        let suggestions = index.suggest(&automaton, 10, |_| true);
+        assert_eq!(
+            suggestions,
+            vec![
+                Suggestion {
+                    text: "Newark".to_string(),
+                    weight: 9
+                },
+                Suggestion {
+                    text: "New Delhi".to_string(),
+                    weight: 7
+                },
+                Suggestion {
+                    text: "New York".to_string(),
+                    weight: 5
+                },
+            ]
+        );
+        assert_eq!(
+            texts(index.suggest(&automaton, 2, |_| true)),
+            vec!["Newark", "New Delhi"]
+        );
+    }
+
+    #[test]
+    fn test_suggest_fuzzy_prefix() {
+        let index = index(&[("javascript", 3), ("java", 2), ("lava lamp", 10)]);
+
+        let exact = FuzzyPrefixAutomaton::new("jaav", 0, 1, true);
+        assert!(index.suggest(&exact, 10, |_| true).is_empty());
+
+        let fuzzy = FuzzyPrefixAutomaton::new("jaav", 1, 1, true);
+        assert_eq!(
+            texts(index.suggest(&fuzzy, 10, |_| true)),
+            vec!["javascript", "java"]
+        );
+    }
+
+    #[test]
+    fn test_suggest_skips_deleted_and_duplicates() {
+        let index = index(&[("apple", 1), ("apple", 8), ("apricot", 5), ("Apple", 3)]);
+        let automaton = FuzzyPrefixAutomaton::new("ap", 0, 0, false);
+
+        let suggestions = index.suggest(&automaton, 10, |doc_id| doc_id != 1);
+        assert_eq!(
+            suggestions,
+            vec![
+                Suggestion {
+                    text: "apricot".to_string(),
+                    weight: 5
+                },
+                Suggestion {
+                    text: "Apple".to_string(),
+                    weight: 3
+                },
+                Suggestion {
+                    text: "apple".to_string(),
+                    weight: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_key() {
+        let mut builder = CompletionBuilder::new();
+        builder.add("Hello", 1, 42);
+        let (key, _) = builder.entries[0].clone();
+
+        assert_eq!(decode_key(&key), Some(("Hello".to_string(), 42)));
+        assert_eq!(decode_key(b"no separator"), None);
+    }
+}
@@ -24,7 +24,7 @@ pub use fuzzy::FuzzyQuery;
 pub use geo::{GeoBoundingBox, GeoBoundingBoxQuery, GeoDistanceQuery, GeoPoint, GeoQuery};
 pub use multi_term::MultiTermQuery;
 pub use parser::LexicalQueryParser;
-pub use phrase::PhraseQuery;
+pub use phrase::{MultiPhraseQuery, PhraseQuery};
 pub use prefix::PrefixQuery;
 pub use range::NumericRangeQuery;
 pub use regexp::RegexpQuery;
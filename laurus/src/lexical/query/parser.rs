@@ -20,12 +20,13 @@ use pest_derive::Parser;
 use crate::analysis::analyzer::analyzer::Analyzer;
 use crate::analysis::analyzer::per_field::PerFieldAnalyzer;
 use crate::analysis::analyzer::standard::StandardAnalyzer;
+use crate::analysis::token::Token;
 use crate::error::{LaurusError, Result};
 use crate::lexical::core::field::NumericType;
 use crate::lexical::query::Query;
 use crate::lexical::query::boolean::{BooleanClause, BooleanQuery, Occur};
 use crate::lexical::query::fuzzy::FuzzyQuery;
-use crate::lexical::query::phrase::PhraseQuery;
+use crate::lexical::query::phrase::{MultiPhraseQuery, PhraseQuery};
 use crate::lexical::query::range::NumericRangeQuery;
 use crate::lexical::query::term::TermQuery;
 use crate::lexical::query::wildcard::WildcardQuery;
@@ -478,18 +479,22 @@ impl LexicalQueryParser {
         }
 
         self.create_query_over_fields(field, |field_name| {
-            let terms = self.analyze_term(Some(field_name), &phrase_content)?;
-            let mut phrase_query = PhraseQuery::new(field_name, terms);
-
-            if let Some(slop_value) = slop {
-                phrase_query = phrase_query.with_slop(slop_value);
-            }
-
-            if boost != 1.0 {
-                phrase_query = phrase_query.with_boost(boost);
-            }
+            let tokens = self.analyze_tokens(Some(field_name), &phrase_content, true)?;
+            let queries = match expand_graph(tokens) {
+                // One phrase per path through the token graph (e.g. per
+                // multi-word synonym).
+                TokenGraph::Paths(paths) => paths
+                    .into_iter()
+                    .map(|(terms, path_boost)| {
+                        phrase_query(field_name, terms, boost * path_boost, slop)
+                    })
+                    .collect(),
+                TokenGraph::Positions(positions) => {
+                    vec![multi_phrase_query(field_name, positions, boost, slop)]
+                }
+            };
 
-            Ok(Box::new(phrase_query))
+            Ok(any_of(queries))
         })
     }
 
@@ -574,28 +579,39 @@ impl LexicalQueryParser {
         }
 
         self.create_query_over_fields(field, |field_name| {
-            let terms = self.analyze_term(Some(field_name), &term)?;
+            let tokens = self.analyze_tokens(Some(field_name), &term, false)?;
 
-            if terms.is_empty() {
+            if tokens.is_empty() {
                 return Err(LaurusError::parse("No terms after analysis".to_string()));
             }
 
-            if terms.len() == 1 {
-                let query = TermQuery::new(field_name, &terms[0]);
-                if boost != 1.0 {
-                    Ok(Box::new(query.with_boost(boost)))
-                } else {
-                    Ok(Box::new(query))
-                }
-            } else {
-                // Multiple terms - create a phrase query
-                let query = PhraseQuery::new(field_name, terms);
-                if boost != 1.0 {
-                    Ok(Box::new(query.with_boost(boost)))
-                } else {
-                    Ok(Box::new(query))
+            let queries = match expand_graph(tokens) {
+                // One query per path through the token graph (e.g. per
+                // multi-word synonym).
+                TokenGraph::Paths(paths) => paths
+                    .into_iter()
+                    .map(|(mut terms, path_boost)| {
+                        let boost = boost * path_boost;
+                        if terms.len() == 1 {
+                            term_query(field_name, terms.remove(0), boost)
+                        } else {
+                            // Multiple terms - create a phrase query
+                            phrase_query(field_name, terms, boost, None)
+                        }
+                    })
+                    .collect(),
+                // A single position matches any of its terms.
+                TokenGraph::Positions(mut positions) if positions.len() == 1 => positions
+                    .remove(0)
+                    .into_iter()
+                    .map(|(term, term_boost)| term_query(field_name, term, boost * term_boost))
+                    .collect(),
+                TokenGraph::Positions(positions) => {
+                    vec![multi_phrase_query(field_name, positions, boost, None)]
                 }
-            }
+            };
+
+            Ok(any_of(queries))
         })
     }
 
@@ -609,10 +625,22 @@ impl LexicalQueryParser {
     }
 
     fn analyze_term(&self, field: Option<&str>, term: &str) -> Result<Vec<String>> {
+        let tokens = self.analyze_tokens(field, term, false)?;
+        Ok(tokens.into_iter().map(|t| t.text).collect())
+    }
+
+    /// Analyze query text, with the field's search quote analyzer for quoted
+    /// phrases and its search analyzer otherwise.
+    fn analyze_tokens(&self, field: Option<&str>, term: &str, quoted: bool) -> Result<Vec<Token>> {
         let token_stream = if let Some(field_name) = field {
             // Use field-specific analyzer if available (PerFieldAnalyzer)
             if let Some(per_field) = self.analyzer.as_any().downcast_ref::<PerFieldAnalyzer>() {
-                per_field.analyze_field(field_name, term)?
+                let analyzer = if quoted {
+                    per_field.get_search_quote_analyzer(field_name)
+                } else {
+                    per_field.get_search_analyzer(field_name)
+                };
+                analyzer.analyze(term)?
             } else {
                 self.analyzer.analyze(term)?
             }
@@ -620,9 +648,186 @@ impl LexicalQueryParser {
             self.analyzer.analyze(term)?
         };
 
-        let tokens: Vec<String> = token_stream.into_iter().map(|t| t.text).collect();
-        Ok(tokens)
+        Ok(token_stream.collect())
+    }
+}
+
+/// Maximum number of paths a query's token graph is expanded to before it
+/// is searched by position instead.
+const MAX_GRAPH_PATHS: usize = 64;
+
+/// The alternatives of an analyzed query text.
+#[derive(Debug, PartialEq)]
+enum TokenGraph {
+    /// Every path through the graph, with its terms and the lowest token
+    /// boost along it.
+    Paths(Vec<(Vec<String>, f32)>),
+    /// The alternative terms at each position, with their boosts.
+    Positions(Vec<Vec<(String, f32)>>),
+}
+
+/// Expand an analyzed token graph into the alternatives to search for.
+///
+/// Each token leaves the node given by the accumulated position increments
+/// and arrives `position_length` nodes later. Tokens stacked on a single
+/// position (phonetic codes, preserved originals, single-word synonyms)
+/// only multiply the alternatives of that position, so such graphs are
+/// returned by position. Only tokens spanning several positions, such as
+/// multi-word synonyms, need every path to be enumerated; if there are more
+/// than [`MAX_GRAPH_PATHS`], the graph is flattened to its positions
+/// instead.
+fn expand_graph(tokens: Vec<Token>) -> TokenGraph {
+    let mut edges: Vec<(usize, usize, Token)> = Vec::with_capacity(tokens.len());
+    let mut node: Option<usize> = None;
+    for token in tokens {
+        let from = match node {
+            None => 0,
+            Some(previous) => previous + token.position_increment,
+        };
+        node = Some(from);
+        let to = from + token.position_length.max(1);
+        edges.push((from, to, token));
+    }
+
+    let Some(&(start, _, _)) = edges.first() else {
+        return TokenGraph::Paths(vec![(Vec::new(), 1.0)]);
+    };
+    if edges.iter().any(|(from, to, _)| to - from > 1) {
+        let mut paths = Vec::new();
+        if collect_paths(&edges, start, &mut Vec::new(), &mut paths) {
+            return TokenGraph::Paths(paths);
+        }
+    }
+
+    // Skip over gaps left by removed tokens, as paths do.
+    let mut positions: Vec<(usize, Vec<(String, f32)>)> = Vec::new();
+    for (from, _, token) in edges {
+        let alternatives = match positions.iter_mut().find(|(node, _)| *node == from) {
+            Some((_, alternatives)) => alternatives,
+            None => {
+                positions.push((from, Vec::new()));
+                &mut positions.last_mut().unwrap().1
+            }
+        };
+        match alternatives
+            .iter_mut()
+            .find(|(term, _)| *term == token.text)
+        {
+            Some((_, boost)) => *boost = boost.max(token.boost),
+            None => alternatives.push((token.text, token.boost)),
+        }
+    }
+    positions.sort_by_key(|(node, _)| *node);
+    TokenGraph::Positions(positions.into_iter().map(|(_, terms)| terms).collect())
+}
+
+/// Collect the paths from `node` to the end of the graph into `paths`.
+///
+/// Returns `false` once more than [`MAX_GRAPH_PATHS`] paths are found.
+fn collect_paths<'a>(
+    edges: &'a [(usize, usize, Token)],
+    node: usize,
+    current: &mut Vec<&'a Token>,
+    paths: &mut Vec<(Vec<String>, f32)>,
+) -> bool {
+    // Skip over gaps left by removed tokens.
+    let Some(next) = edges
+        .iter()
+        .map(|(from, _, _)| *from)
+        .filter(|from| *from >= node)
+        .min()
+    else {
+        if paths.len() == MAX_GRAPH_PATHS {
+            return false;
+        }
+        let terms = current.iter().map(|t| t.text.clone()).collect();
+        let boost = current.iter().map(|t| t.boost).fold(1.0, f32::min);
+        paths.push((terms, boost));
+        return true;
+    };
+
+    for (from, to, token) in edges {
+        if *from == next {
+            current.push(token);
+            let complete = collect_paths(edges, *to, current, paths);
+            current.pop();
+            if !complete {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn term_query(field: &str, term: String, boost: f32) -> Box<dyn Query> {
+    let query = TermQuery::new(field, term);
+    if boost != 1.0 {
+        Box::new(query.with_boost(boost))
+    } else {
+        Box::new(query)
+    }
+}
+
+fn phrase_query(field: &str, terms: Vec<String>, boost: f32, slop: Option<u32>) -> Box<dyn Query> {
+    let mut query = PhraseQuery::new(field, terms);
+    if let Some(slop) = slop {
+        query = query.with_slop(slop);
+    }
+    if boost != 1.0 {
+        query = query.with_boost(boost);
+    }
+    Box::new(query)
+}
+
+/// Phrase over the alternatives of each position, boosted by the weakest
+/// position's best term.
+///
+/// A plain [`PhraseQuery`] is used when no position has alternatives.
+fn multi_phrase_query(
+    field: &str,
+    positions: Vec<Vec<(String, f32)>>,
+    boost: f32,
+    slop: Option<u32>,
+) -> Box<dyn Query> {
+    let boost = boost
+        * positions
+            .iter()
+            .map(|terms| {
+                terms
+                    .iter()
+                    .map(|(_, boost)| *boost)
+                    .fold(f32::MIN, f32::max)
+            })
+            .fold(1.0, f32::min);
+    let terms: Vec<Vec<String>> = positions
+        .into_iter()
+        .map(|terms| terms.into_iter().map(|(term, _)| term).collect())
+        .collect();
+    if terms.iter().all(|terms| terms.len() == 1) {
+        let terms = terms.into_iter().flatten().collect();
+        return phrase_query(field, terms, boost, slop);
+    }
+
+    let mut query = MultiPhraseQuery::new(field, terms);
+    if let Some(slop) = slop {
+        query = query.with_slop(slop);
+    }
+    if boost != 1.0 {
+        query = query.with_boost(boost);
+    }
+    Box::new(query)
+}
+
+/// Combine alternative queries, matching documents that match any of them.
+fn any_of(mut queries: Vec<Box<dyn Query>>) -> Box<dyn Query> {
+    if queries.len() == 1 {
+        return queries.pop().unwrap();
+    }
+    let mut bool_query = BooleanQuery::new();
+    for query in queries {
+        bool_query.add_clause(BooleanClause::new(query, Occur::Should));
     }
+    Box::new(bool_query)
 }
 
 /// Builder for constructing a [`QueryParser`] with a fluent API.
@@ -766,4 +971,116 @@ mod tests {
         // Unfortunately standard debug format might be opaque, but we can assume BooleanQuery is created if multiple fields.
         // If it was single field, it would be TermQuery.
     }
+
+    #[test]
+    fn test_synonym_graph_expansion() {
+        use crate::analysis::analyzer::pipeline::PipelineAnalyzer;
+        use crate::analysis::synonym::dictionary::SynonymDictionary;
+        use crate::analysis::token_filter::synonym_graph::SynonymGraphFilter;
+        use crate::analysis::tokenizer::whitespace::WhitespaceTokenizer;
+
+        let dict = SynonymDictionary::from_solr_rules("ml, machine learning").unwrap();
+        let analyzer = PipelineAnalyzer::new(Arc::new(WhitespaceTokenizer::new()))
+            .add_filter(Arc::new(SynonymGraphFilter::new(dict, true)));
+        let parser = LexicalQueryParser::new(Arc::new(analyzer)).with_default_field("content");
+
+        // A term with a multi-word synonym becomes a term OR a phrase.
+        let query = format!("{:?}", parser.parse("ml").unwrap());
+        assert!(query.contains("BooleanQuery"), "{query}");
+        assert!(query.contains("TermQuery"), "{query}");
+        assert!(query.contains("PhraseQuery"), "{query}");
+
+        // A phrase expands to one phrase per path.
+        let TokenGraph::Paths(paths) = expand_graph(
+            parser
+                .analyze_tokens(Some("content"), "ml tutorial", false)
+                .unwrap(),
+        ) else {
+            panic!("multi-word synonyms expand to paths");
+        };
+        let terms: Vec<Vec<String>> = paths.into_iter().map(|(terms, _)| terms).collect();
+        assert_eq!(
+            terms,
+            vec![
+                vec!["ml".to_string(), "tutorial".to_string()],
+                vec![
+                    "machine".to_string(),
+                    "learning".to_string(),
+                    "tutorial".to_string()
+                ],
+            ]
+        );
+
+        // Without synonyms the query is unchanged.
+        let query = format!("{:?}", parser.parse("rust").unwrap());
+        assert!(!query.contains("BooleanQuery"), "{query}");
+    }
+
+    #[test]
+    fn test_stacked_tokens_expand_by_position() {
+        use crate::analysis::analyzer::phonetic::PhoneticAnalyzer;
+
+        let parser = LexicalQueryParser::new(Arc::new(PhoneticAnalyzer::new().unwrap()))
+            .with_default_field("name");
+
+        // Every word is stacked with its phonetic codes: at least 2^5 paths,
+        // searched as one phrase of alternatives instead.
+        let query = parser
+            .parse(r#"name:"john paul smith jones jackson""#)
+            .unwrap();
+        let query = query
+            .as_any()
+            .downcast_ref::<MultiPhraseQuery>()
+            .expect("a multi-phrase query");
+        assert_eq!(query.terms().len(), 5);
+        assert!(query.terms().iter().all(|terms| terms.len() >= 2));
+        assert_eq!(query.terms()[0][0], "john");
+
+        // A single word matches the word or its code.
+        let query = format!("{:?}", parser.parse("name:john").unwrap());
+        assert!(query.contains("BooleanQuery"), "{query}");
+        assert!(!query.contains("PhraseQuery"), "{query}");
+    }
+
+    #[test]
+    fn test_graph_expansion_falls_back_to_positions() {
+        let token = |text: &str, increment: usize, length: usize| {
+            Token::new(text, 0)
+                .with_position_increment(increment)
+                .with_position_length(length)
+        };
+
+        // Each word has a two-word synonym: 2^7 paths exceed the limit.
+        let mut tokens = Vec::new();
+        for i in 0..7 {
+            tokens.push(token(&format!("w{i}"), 1, 2));
+            tokens.push(token(&format!("a{i}"), 0, 1));
+            tokens.push(token(&format!("b{i}"), 1, 1));
+        }
+        let TokenGraph::Positions(positions) = expand_graph(tokens) else {
+            panic!("too many paths are flattened");
+        };
+        assert_eq!(positions.len(), 14);
+        assert_eq!(positions[0].len(), 2);
+        assert_eq!(positions[1].len(), 1);
+    }
+
+    #[test]
+    fn test_search_analyzers() {
+        use crate::analysis::analyzer::keyword::KeywordAnalyzer;
+
+        let analyzer = PerFieldAnalyzer::new(Arc::new(StandardAnalyzer::new().unwrap()));
+        analyzer.add_analyzer("code", Arc::new(KeywordAnalyzer::new()));
+        analyzer.add_search_analyzer("code", Arc::new(StandardAnalyzer::new().unwrap()));
+        analyzer.add_search_quote_analyzer("code", Arc::new(KeywordAnalyzer::new()));
+        let parser = LexicalQueryParser::new(Arc::new(analyzer));
+
+        // Terms use the search analyzer, which lowercases.
+        let query = format!("{:?}", parser.parse("code:Foo").unwrap());
+        assert!(query.contains("\"foo\""), "{query}");
+
+        // Quoted phrases use the search quote analyzer, which keeps the text whole.
+        let query = format!("{:?}", parser.parse(r#"code:"Foo Bar""#).unwrap());
+        assert!(query.contains("\"Foo Bar\""), "{query}");
+    }
 }
@@ -60,50 +60,70 @@ impl PhraseMatcher {
         field: &str,
         terms: &[String],
         slop: u32,
+    ) -> Result<Vec<PhraseMatch>> {
+        let terms: Vec<Vec<String>> = terms.iter().map(|term| vec![term.clone()]).collect();
+        Self::find_multi_phrase_matches(reader, field, &terms, slop)
+    }
+
+    /// Find all documents containing the phrase, where each phrase position
+    /// may be filled by any of several alternative terms.
+    pub fn find_multi_phrase_matches(
+        reader: &dyn LexicalIndexReader,
+        field: &str,
+        terms: &[Vec<String>],
+        slop: u32,
     ) -> Result<Vec<PhraseMatch>> {
         if terms.is_empty() {
             return Ok(Vec::new());
         }
 
-        // Get posting iterators for all terms
-        let mut iterators = Vec::new();
-        for term in terms {
-            match reader.postings(field, term)? {
-                Some(iter) => iterators.push(iter),
-                None => return Ok(Vec::new()), // If any term is missing, no phrase matches
-            }
-        }
-
         let mut phrase_matches = Vec::new();
-        let mut doc_candidates = std::collections::HashMap::new();
-
-        // Find documents that contain all terms
-        for (term_idx, iter) in iterators.iter_mut().enumerate() {
-            while iter.next()? {
-                let doc_id = iter.doc_id();
-                if doc_id == u64::MAX {
-                    break;
+        let mut doc_candidates: HashMap<u64, Vec<Vec<u64>>> = HashMap::new();
+
+        // Collect the positions of every alternative, merged per phrase position
+        for (term_idx, alternatives) in terms.iter().enumerate() {
+            let mut found = false;
+            for term in alternatives {
+                let Some(mut iter) = reader.postings(field, term)? else {
+                    continue;
+                };
+                found = true;
+                while iter.next()? {
+                    let doc_id = iter.doc_id();
+                    if doc_id == u64::MAX {
+                        break;
+                    }
+
+                    let positions = iter.positions()?;
+                    doc_candidates
+                        .entry(doc_id)
+                        .or_insert_with(|| vec![Vec::new(); terms.len()])[term_idx]
+                        .extend(positions);
                 }
-
-                let positions = iter.positions()?;
-                doc_candidates
-                    .entry(doc_id)
-                    .or_insert_with(Vec::new)
-                    .push((term_idx, positions));
+            }
+            if !found {
+                // If any phrase position has no term in the index, no phrase matches
+                return Ok(Vec::new());
             }
         }
 
         // Check each candidate document for valid phrase matches
-        for (doc_id, term_positions) in doc_candidates {
-            // Sort by term index to ensure correct order
-            let mut term_positions = term_positions;
-            term_positions.sort_by_key(|(term_idx, _)| *term_idx);
-
-            // Skip if we don't have all terms
-            if term_positions.len() != terms.len() {
+        for (doc_id, positions) in doc_candidates {
+            // Skip if we don't have all phrase positions
+            if positions.iter().any(Vec::is_empty) {
                 continue;
             }
 
+            let term_positions: Vec<(usize, Vec<u64>)> = positions
+                .into_iter()
+                .map(|mut positions| {
+                    positions.sort_unstable();
+                    positions.dedup();
+                    positions
+                })
+                .enumerate()
+                .collect();
+
             // Find valid phrase occurrences in this document
             let phrase_positions = Self::find_phrase_positions(&term_positions, slop);
 
@@ -517,6 +537,142 @@ impl Query for PhraseQuery {
     }
 }
 
+/// A phrase query where each position may be filled by any of several
+/// alternative terms.
+///
+/// This is how a token graph with stacked tokens (synonyms, phonetic codes,
+/// preserved originals) is searched as a phrase without expanding it into
+/// one [`PhraseQuery`] per combination of alternatives.
+#[derive(Debug, Clone)]
+pub struct MultiPhraseQuery {
+    /// The field to search in.
+    field: String,
+    /// The alternative terms for each phrase position, in order.
+    terms: Vec<Vec<String>>,
+    /// The boost factor for this query.
+    boost: f32,
+    /// Optional slop - maximum allowed distance between positions (0 = exact phrase).
+    slop: u32,
+}
+
+impl MultiPhraseQuery {
+    /// Create a new multi-phrase query.
+    pub fn new<S: Into<String>>(field: S, terms: Vec<Vec<String>>) -> Self {
+        MultiPhraseQuery {
+            field: field.into(),
+            terms,
+            boost: 1.0,
+            slop: 0,
+        }
+    }
+
+    /// Set the boost factor for this query.
+    pub fn with_boost(mut self, boost: f32) -> Self {
+        self.boost = boost;
+        self
+    }
+
+    /// Set the slop (maximum distance between positions).
+    pub fn with_slop(mut self, slop: u32) -> Self {
+        self.slop = slop;
+        self
+    }
+
+    /// Get the field name.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Get the alternative terms of each phrase position.
+    pub fn terms(&self) -> &[Vec<String>] {
+        &self.terms
+    }
+
+    /// Get the slop value.
+    pub fn slop(&self) -> u32 {
+        self.slop
+    }
+
+    fn find_matches(&self, reader: &dyn LexicalIndexReader) -> Result<Vec<PhraseMatch>> {
+        PhraseMatcher::find_multi_phrase_matches(reader, &self.field, &self.terms, self.slop)
+    }
+}
+
+impl Query for MultiPhraseQuery {
+    fn matcher(&self, reader: &dyn LexicalIndexReader) -> Result<Box<dyn Matcher>> {
+        let matches = self.find_matches(reader)?;
+        if matches.is_empty() {
+            return Ok(Box::new(EmptyMatcher::new()));
+        }
+
+        let current_doc_id = matches[0].doc_id;
+        Ok(Box::new(PhraseMatcher {
+            matches,
+            current_index: 0,
+            current_doc_id,
+        }))
+    }
+
+    fn scorer(&self, reader: &dyn LexicalIndexReader) -> Result<Box<dyn Scorer>> {
+        let total_docs = reader.doc_count();
+        if self.terms.is_empty() || total_docs == 0 {
+            return Ok(Box::new(BM25Scorer::new(0, 0, 0, 1.0, 1, self.boost)));
+        }
+
+        let phrase_matches = self.find_matches(reader)?;
+        let avg_field_length = match reader.field_statistics(&self.field) {
+            Ok(field_stats) => field_stats.avg_field_length,
+            Err(_) => 10.0, // Default fallback
+        };
+
+        // Same phrase length boost as PhraseQuery
+        let phrase_boost = self.boost * (1.0 + 0.2 * (self.terms.len() as f32 - 1.0));
+
+        Ok(Box::new(PhraseScorer::new(
+            &phrase_matches,
+            total_docs,
+            avg_field_length,
+            phrase_boost,
+        )))
+    }
+
+    fn boost(&self) -> f32 {
+        self.boost
+    }
+
+    fn set_boost(&mut self, boost: f32) {
+        self.boost = boost;
+    }
+
+    fn description(&self) -> String {
+        format!(
+            "MultiPhraseQuery(field:{}, terms:{:?}, slop:{})",
+            self.field, self.terms, self.slop
+        )
+    }
+
+    fn clone_box(&self) -> Box<dyn Query> {
+        Box::new(self.clone())
+    }
+
+    fn is_empty(&self, _reader: &dyn LexicalIndexReader) -> Result<bool> {
+        Ok(self.terms.is_empty())
+    }
+
+    fn cost(&self, _reader: &dyn LexicalIndexReader) -> Result<u64> {
+        let terms: usize = self.terms.iter().map(Vec::len).sum();
+        Ok(terms as u64 * 100) // Rough estimate
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn field(&self) -> Option<&str> {
+        Some(&self.field)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
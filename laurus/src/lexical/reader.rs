@@ -3,7 +3,9 @@
 use crate::error::Result;
 use crate::lexical::core::document::Document;
 use crate::lexical::core::field::FieldValue;
+use crate::lexical::index::inverted::core::automaton::FuzzyPrefixAutomaton;
 use crate::lexical::index::structures::bkd_tree::BKDTree;
+use crate::lexical::index::structures::completion::Suggestion;
 use std::sync::Arc;
 
 /// Information about a term in the index.
@@ -130,6 +132,22 @@ pub trait LexicalIndexReader: Send + Sync + std::fmt::Debug {
         Ok(None)
     }
 
+    /// Find the `limit` highest-weighted inputs of a completion field
+    /// accepted by `automaton`, ordered by weight (highest first).
+    ///
+    /// Inputs of documents for which `accept` returns false are skipped.
+    fn suggest(
+        &self,
+        field: &str,
+        automaton: &FuzzyPrefixAutomaton,
+        limit: usize,
+        accept: &dyn Fn(u64) -> bool,
+    ) -> Result<Vec<Suggestion>> {
+        // Default implementation has no completion support
+        let _ = (field, automaton, limit, accept);
+        Ok(Vec::new())
+    }
+
     /// Get document frequency for a specific term in a field.
     fn term_doc_freq(&self, field: &str, term: &str) -> Result<u64> {
         match self.term_info(field, term)? {
@@ -237,3 +255,134 @@ pub trait PostingIterator: Send + std::fmt::Debug {
     /// (e.g., deciding iteration order for conjunctive queries).
     fn cost(&self) -> u64;
 }
+
+/// Reader that reports term and field statistics summed over a group of
+/// readers while serving postings and documents from one of them.
+///
+/// Wrapping each shard's reader this way makes BM25 use collection-wide
+/// document frequencies and field lengths, so scores from different
+/// shards are directly comparable (distributed IDF).
+#[derive(Debug)]
+pub(crate) struct GlobalStatsReader {
+    /// Reader whose documents are searched.
+    local: Arc<dyn LexicalIndexReader>,
+    /// Every reader of the collection, including `local`.
+    all: Vec<Arc<dyn LexicalIndexReader>>,
+}
+
+impl GlobalStatsReader {
+    /// Wrap `local`, taking statistics from `all` (which must include `local`).
+    pub(crate) fn new(
+        local: Arc<dyn LexicalIndexReader>,
+        all: Vec<Arc<dyn LexicalIndexReader>>,
+    ) -> Self {
+        Self { local, all }
+    }
+}
+
+impl LexicalIndexReader for GlobalStatsReader {
+    fn doc_count(&self) -> u64 {
+        self.all.iter().map(|reader| reader.doc_count()).sum()
+    }
+
+    fn max_doc(&self) -> u64 {
+        self.local.max_doc()
+    }
+
+    fn is_deleted(&self, doc_id: u64) -> bool {
+        self.local.is_deleted(doc_id)
+    }
+
+    fn document(&self, doc_id: u64) -> Result<Option<Document>> {
+        self.local.document(doc_id)
+    }
+
+    fn term_info(&self, field: &str, term: &str) -> Result<Option<ReaderTermInfo>> {
+        // The posting location must stay local; only the frequencies are global.
+        let Some(mut info) = self.local.term_info(field, term)? else {
+            return Ok(None);
+        };
+        let (mut doc_freq, mut total_freq) = (0, 0);
+        for reader in &self.all {
+            if let Some(other) = reader.term_info(field, term)? {
+                doc_freq += other.doc_freq;
+                total_freq += other.total_freq;
+            }
+        }
+        info.doc_freq = doc_freq;
+        info.total_freq = total_freq;
+        Ok(Some(info))
+    }
+
+    fn postings(&self, field: &str, term: &str) -> Result<Option<Box<dyn PostingIterator>>> {
+        self.local.postings(field, term)
+    }
+
+    fn field_stats(&self, field: &str) -> Result<Option<FieldStats>> {
+        let mut merged: Option<FieldStats> = None;
+        let mut total_length = 0.0;
+        for reader in &self.all {
+            let Some(stats) = reader.field_stats(field)? else {
+                continue;
+            };
+            total_length += stats.avg_length * stats.doc_count as f64;
+            match &mut merged {
+                None => merged = Some(stats),
+                Some(acc) => {
+                    acc.unique_terms = acc.unique_terms.max(stats.unique_terms);
+                    acc.total_terms += stats.total_terms;
+                    acc.doc_count += stats.doc_count;
+                    acc.min_length = acc.min_length.min(stats.min_length);
+                    acc.max_length = acc.max_length.max(stats.max_length);
+                }
+            }
+        }
+        if let Some(acc) = &mut merged
+            && acc.doc_count > 0
+        {
+            acc.avg_length = total_length / acc.doc_count as f64;
+        }
+        Ok(merged)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        // The wrapped readers are shared and closed by their owners.
+        Ok(())
+    }
+
+    fn is_closed(&self) -> bool {
+        self.local.is_closed()
+    }
+
+    fn get_bkd_tree(&self, field: &str) -> Result<Option<Arc<dyn BKDTree>>> {
+        self.local.get_bkd_tree(field)
+    }
+
+    fn suggest(
+        &self,
+        field: &str,
+        automaton: &FuzzyPrefixAutomaton,
+        limit: usize,
+        accept: &dyn Fn(u64) -> bool,
+    ) -> Result<Vec<Suggestion>> {
+        self.local.suggest(field, automaton, limit, accept)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        // Expose the concrete local reader so that downcasts (e.g. to read
+        // the analyzer or field lengths) keep working.
+        self.local.as_any()
+    }
+
+    fn get_doc_value(&self, field: &str, doc_id: u64) -> Result<Option<FieldValue>> {
+        self.local.get_doc_value(field, doc_id)
+    }
+
+    fn has_doc_values(&self, field: &str) -> bool {
+        self.local.has_doc_values(field)
+    }
+
+    fn doc_ids(&self) -> Result<Vec<u64>> {
+        self.local.doc_ids()
+    }
+}
@@ -2,11 +2,13 @@
 
 use std::collections::HashSet;
 use std::ops::Range;
+use std::sync::Arc;
 
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::analysis::analyzer::analyzer::Analyzer;
+use crate::analysis::analyzer::per_field::PerFieldAnalyzer;
 use crate::analysis::analyzer::standard::StandardAnalyzer;
 use crate::analysis::token::Token;
 use crate::error::Result;
@@ -190,7 +192,11 @@ pub struct Highlighter {
     /// Configuration for highlighting.
     config: HighlightConfig,
     /// Text analyzer for tokenization.
-    analyzer: Box<dyn Analyzer>,
+    ///
+    /// A [`PerFieldAnalyzer`] tokenizes each field with its index analyzer,
+    /// so highlighted spans line up with the indexed terms that matched,
+    /// even when the field is searched with a different analyzer.
+    analyzer: Arc<dyn Analyzer>,
 }
 
 impl std::fmt::Debug for Highlighter {
@@ -207,12 +213,12 @@ impl Highlighter {
     pub fn new(config: HighlightConfig) -> Self {
         Highlighter {
             config,
-            analyzer: Box::new(StandardAnalyzer::new().unwrap()),
+            analyzer: Arc::new(StandardAnalyzer::new().unwrap()),
         }
     }
 
     /// Create a highlighter with a custom analyzer.
-    pub fn with_analyzer(config: HighlightConfig, analyzer: Box<dyn Analyzer>) -> Self {
+    pub fn with_analyzer(config: HighlightConfig, analyzer: Arc<dyn Analyzer>) -> Self {
         Highlighter { config, analyzer }
     }
 
@@ -238,7 +244,7 @@ impl Highlighter {
         }
 
         // Find highlight spans
-        let highlight_spans = self.find_highlight_spans(text, &highlight_terms)?;
+        let highlight_spans = self.find_highlight_spans(field_name, text, &highlight_terms)?;
 
         if highlight_spans.is_empty() {
             return self.create_no_highlight_result(field_name, text);
@@ -284,13 +290,17 @@ impl Highlighter {
     /// Find highlight spans in text.
     fn find_highlight_spans(
         &self,
+        field_name: &str,
         text: &str,
         terms: &HashSet<String>,
     ) -> Result<Vec<HighlightSpan>> {
         let mut spans = Vec::new();
 
         // Tokenize the text
-        let tokens = self.analyzer.analyze(text)?;
+        let tokens = match self.analyzer.as_any().downcast_ref::<PerFieldAnalyzer>() {
+            Some(per_field) => per_field.analyze_field(field_name, text)?,
+            None => self.analyzer.analyze(text)?,
+        };
         let tokens: Vec<Token> = tokens.collect();
 
         // Find matching tokens
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::Result;
 use crate::lexical::core::field::FieldValue;
+use crate::lexical::index::inverted::reader::InvertedIndexReader;
 use crate::lexical::query::Query;
 use crate::lexical::query::QueryResult;
 use crate::lexical::reader::LexicalIndexReader;
@@ -241,7 +242,15 @@ impl ResultProcessor {
     /// Result containing the new processor instance
     pub fn new(config: ResultProcessorConfig, reader: Arc<dyn LexicalIndexReader>) -> Result<Self> {
         let highlighter = if config.enable_highlighting {
-            Some(Highlighter::new(HighlightConfig::default()))
+            // Highlight with the analyzers the index was written with.
+            let inverted_reader = reader.as_any().downcast_ref::<InvertedIndexReader>();
+            Some(match inverted_reader {
+                Some(inverted_reader) => Highlighter::with_analyzer(
+                    HighlightConfig::default(),
+                    Arc::clone(inverted_reader.analyzer()),
+                ),
+                None => Highlighter::new(HighlightConfig::default()),
+            })
         } else {
             None
         };
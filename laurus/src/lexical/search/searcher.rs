@@ -4,6 +4,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::error::Result;
+use crate::lexical::index::inverted::core::automaton::FuzzyPrefixAutomaton;
+use crate::lexical::index::structures::completion::Suggestion;
 use crate::lexical::query::{LexicalSearchResults, Query};
 
 /// Sort order for search results.
@@ -240,4 +242,14 @@ pub trait LexicalSearcher: Send + Sync + std::fmt::Debug {
     /// Returns the number of documents that match the given search request,
     /// applying the min_score threshold if specified in the request parameters.
     fn count(&self, request: LexicalSearchRequest) -> Result<u64>;
+
+    /// Find the `limit` highest-weighted inputs of a completion field
+    /// accepted by `automaton`, skipping documents rejected by `accept`.
+    fn suggest(
+        &self,
+        field: &str,
+        automaton: &FuzzyPrefixAutomaton,
+        limit: usize,
+        accept: &dyn Fn(u64) -> bool,
+    ) -> Result<Vec<Suggestion>>;
 }
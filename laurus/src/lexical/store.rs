@@ -12,7 +12,11 @@ use crate::lexical::core::document::Document;
 use crate::lexical::index::LexicalIndex;
 use crate::lexical::index::factory::LexicalIndexFactory;
 use crate::lexical::index::inverted::InvertedIndexStats;
+use crate::lexical::index::inverted::core::automaton::FuzzyPrefixAutomaton;
+use crate::lexical::index::inverted::searcher::InvertedIndexSearcher;
+use crate::lexical::index::structures::completion::Suggestion;
 use crate::lexical::query::LexicalSearchResults;
+use crate::lexical::reader::LexicalIndexReader;
 use crate::lexical::search::searcher::{LexicalSearchRequest, LexicalSearcher};
 use crate::lexical::store::config::LexicalIndexConfig;
 use crate::lexical::writer::LexicalIndexWriter;
@@ -214,6 +218,84 @@ impl LexicalStore {
         Ok(ids)
     }
 
+    /// Find all committed internal document IDs matching a query.
+    ///
+    /// Unlike [`find_doc_ids_by_term`](Self::find_doc_ids_by_term), only the
+    /// committed index is consulted because arbitrary queries cannot be
+    /// evaluated against the writer's in-memory buffer. Documents already
+    /// marked as deleted in the pending writer are excluded. The returned IDs
+    /// are sorted in ascending order.
+    pub(crate) fn find_doc_ids_by_query(
+        &self,
+        query: Box<dyn crate::lexical::query::Query>,
+    ) -> Result<Vec<u64>> {
+        let guard = self.writer_cache.lock();
+
+        let request = LexicalSearchRequest::new(query)
+            .limit(usize::MAX)
+            .load_documents(false);
+
+        // Same lock order as find_doc_ids_by_term (Writer -> Searcher).
+        let results = self.search(request)?;
+        let mut ids: Vec<u64> = results
+            .hits
+            .into_iter()
+            .map(|hit| hit.doc_id)
+            .filter(|doc_id| {
+                guard
+                    .as_ref()
+                    .is_none_or(|writer| !writer.is_updated_deleted(*doc_id))
+            })
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        Ok(ids)
+    }
+
+    /// Find up to `limit` internal document IDs matching `query` in the
+    /// range `from..until`.
+    ///
+    /// Like [`find_doc_ids_by_query`](Self::find_doc_ids_by_query), but the
+    /// matches are walked in ascending order from `from` and collection stops
+    /// after `limit` IDs, so a large result set can be paged through in
+    /// bounded memory by passing the last returned ID plus one. Fewer than
+    /// `limit` IDs are returned only once the range is exhausted.
+    pub(crate) fn find_doc_ids_by_query_page(
+        &self,
+        query: &dyn crate::lexical::query::Query,
+        from: u64,
+        until: u64,
+        limit: usize,
+    ) -> Result<Vec<u64>> {
+        let guard = self.writer_cache.lock();
+
+        // Same lock order as find_doc_ids_by_term (Writer -> Reader).
+        let reader = self.reader()?;
+        let mut matcher = query.matcher(reader.as_ref())?;
+        let mut ids = Vec::new();
+        if matcher.is_exhausted() || (matcher.doc_id() < from && !matcher.skip_to(from)?) {
+            return Ok(ids);
+        }
+        while ids.len() < limit && !matcher.is_exhausted() {
+            let doc_id = matcher.doc_id();
+            if doc_id >= until {
+                break;
+            }
+            if guard
+                .as_ref()
+                .is_none_or(|writer| !writer.is_updated_deleted(doc_id))
+            {
+                ids.push(doc_id);
+            }
+            if !matcher.next()? {
+                break;
+            }
+        }
+
+        Ok(ids)
+    }
+
     /// Commit any pending changes to the index.
     ///
     /// This method flushes all pending write operations to storage and makes them
@@ -449,6 +531,25 @@ impl LexicalStore {
         guard.as_ref().unwrap().search(request)
     }
 
+    /// Open a reader over the committed segments of this index.
+    pub(crate) fn reader(&self) -> Result<Arc<dyn LexicalIndexReader>> {
+        self.index.reader()
+    }
+
+    /// Search through `reader` instead of the cached searcher.
+    ///
+    /// Used by [`ShardedEngine`](crate::ShardedEngine) to score against a
+    /// reader that reports collection-wide term and field statistics.
+    pub(crate) fn search_with_reader(
+        &self,
+        request: LexicalSearchRequest,
+        reader: Arc<dyn LexicalIndexReader>,
+    ) -> Result<LexicalSearchResults> {
+        let searcher = InvertedIndexSearcher::from_arc(reader)
+            .with_default_fields(self.index.default_fields()?);
+        searcher.search(request)
+    }
+
     /// Count documents matching the request.
     ///
     /// Uses a cached searcher for improved performance.
@@ -500,6 +601,38 @@ impl LexicalStore {
         guard.as_ref().unwrap().count(request)
     }
 
+    /// Find the `limit` highest-weighted inputs of a completion field
+    /// accepted by `automaton`, skipping documents rejected by `accept`.
+    ///
+    /// Uses the cached searcher, so completion FSTs loaded by earlier calls
+    /// are reused until the next commit.
+    pub fn suggest(
+        &self,
+        field: &str,
+        automaton: &FuzzyPrefixAutomaton,
+        limit: usize,
+        accept: &dyn Fn(u64) -> bool,
+    ) -> Result<Vec<Suggestion>> {
+        // Fast path: read lock, cache hit.
+        {
+            let guard = self.searcher_cache.read();
+            if let Some(ref searcher) = *guard {
+                return searcher.suggest(field, automaton, limit, accept);
+            }
+        }
+
+        // Slow path: populate under write lock, then downgrade to read lock.
+        let mut guard = self.searcher_cache.write();
+        if guard.is_none() {
+            *guard = Some(self.index.searcher()?);
+        }
+        let guard = parking_lot::RwLockWriteGuard::downgrade(guard);
+        guard
+            .as_ref()
+            .unwrap()
+            .suggest(field, automaton, limit, accept)
+    }
+
     /// Close the search engine and release resources.
     ///
     /// Drops the cached writer and searcher, then marks the underlying index
@@ -637,6 +770,32 @@ impl LexicalStore {
         Ok(())
     }
 
+    /// Run `f` with the index's
+    /// [`PerFieldAnalyzer`](crate::analysis::analyzer::per_field::PerFieldAnalyzer).
+    ///
+    /// The writer and every reader share it, so analyzers registered in it
+    /// apply to the next document written and the next query parsed. Terms
+    /// already indexed are left as they are.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index analyzer is not a `PerFieldAnalyzer`.
+    pub fn with_per_field_analyzer<R>(
+        &self,
+        f: impl FnOnce(&crate::analysis::analyzer::per_field::PerFieldAnalyzer) -> R,
+    ) -> Result<R> {
+        let index_analyzer = self.analyzer()?;
+        let pfa = index_analyzer
+            .as_any()
+            .downcast_ref::<crate::analysis::analyzer::per_field::PerFieldAnalyzer>()
+            .ok_or_else(|| {
+                crate::error::LaurusError::internal(
+                    "The index analyzer does not support per-field analyzers",
+                )
+            })?;
+        Ok(f(pfa))
+    }
+
     /// Remove a field from the lexical store.
     ///
     /// Removes the field from the underlying index (if it was dynamically added)
@@ -84,7 +84,21 @@ pub use embedding::precomputed::PrecomputedEmbedder;
 pub use engine::Engine;
 pub use engine::EngineBuilder;
 pub use engine::EngineStats;
+pub use engine::acl::Identity;
+#[cfg(feature = "native")]
+pub use engine::backfill::BackfillTask;
+pub use engine::backfill::{BackfillProgress, DEFAULT_BACKFILL_BATCH_SIZE};
+pub use engine::by_query::{ByQueryOptions, ByQueryResponse, CancellationToken};
+pub use engine::changes::ScopedChangeSubscription;
+#[cfg(feature = "native")]
+pub use engine::expiry::ExpiryTask;
+pub use engine::export::{DEFAULT_EXPORT_BATCH_SIZE, DocumentExport, ExportedDocument};
+pub use engine::federated::{
+    FederatedQuery, FederatedSearchOptions, FederatedSearchResult, federated_search,
+};
+pub use engine::field_update::FieldUpdate;
 pub use engine::query::UnifiedQueryParser;
+pub use engine::resource::{ResourceContent, ResourceSet};
 pub use engine::schema::analyzer::{
     AnalyzerDefinition, CharFilterConfig, TokenFilterConfig, TokenizerConfig,
 };
@@ -94,15 +108,25 @@ pub use engine::search::{
     FusionAlgorithm, HybridMode, LexicalSearchOptions, SearchQuery, SearchRequest,
     SearchRequestBuilder, SearchResult, VectorSearchOptions, VectorSearchQuery,
 };
+pub use engine::sharded::{ShardedEngine, ShardedEngineBuilder};
+pub use engine::snapshot::{
+    Snapshot, SnapshotManifest, SnapshotManifestFile, SnapshotReceiver, SnapshotReport,
+    restore_snapshot,
+};
+pub use engine::tenant::{Tenant, TenantQuota, TenantStats};
+pub use engine::transaction::{Transaction, TransactionOperation};
 pub use error::{LaurusError, Result};
 pub use lexical::core::field::{
-    BooleanOption, BytesOption, DateTimeOption, FloatOption, GeoOption, IntegerOption, TextOption,
+    BooleanOption, BytesOption, CompletionOption, DateTimeOption, FloatOption, GeoOption,
+    IntegerOption, TextOption,
 };
+pub use lexical::index::structures::completion::Suggestion;
 pub use lexical::search::searcher::{
     LexicalSearchParams, LexicalSearchQuery, LexicalSearchRequest, SortField, SortOrder,
 };
 pub use maintenance::deletion::DeletionConfig;
 pub use storage::{Storage, StorageConfig, StorageFactory};
+pub use store::changes::{ChangeEvent, ChangeKind, ChangeSubscription};
 pub use vector::core::distance::DistanceMetric;
 pub use vector::core::field::{FlatOption, HnswOption, IvfOption};
 pub use vector::core::quantization::QuantizationMethod;
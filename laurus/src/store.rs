@@ -5,9 +5,11 @@
 //!
 //! # Submodules
 //!
+//! - [`changes`] -- In-memory change data capture feed of document log mutations.
 //! - [`document`] -- Segmented document storage (`UnifiedDocumentStore`) with binary segment
 //!   files, a JSON manifest for segment metadata, and readers/writers for individual segments.
 //! - [`log`] -- Write-ahead log for crash-safe document ingestion.
 
+pub mod changes;
 pub mod document;
 pub mod log;
@@ -0,0 +1,367 @@
+//! Change data capture feed for document log mutations.
+//!
+//! Every upsert and delete written to the [`DocumentLog`](super::log::DocumentLog)
+//! is published to a [`ChangeFeed`] in sequence-number order. The feed keeps
+//! a bounded in-memory window of recent events so that a subscriber can
+//! resume from a known sequence number, and forwards new events to live
+//! subscribers.
+//!
+//! The retained window survives [`Engine::commit`](crate::Engine::commit)
+//! (which truncates the WAL) but not a process restart: after reopening,
+//! only the mutations replayed from the WAL are available again.
+
+use std::collections::VecDeque;
+
+use futures::Stream;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::data::Document;
+use crate::error::{LaurusError, Result};
+use crate::store::log::{LogEntry, LogRecord, SeqNumber};
+
+/// Default number of change events retained in memory.
+pub const DEFAULT_CHANGE_RETENTION: usize = 10_000;
+
+/// Capacity of the live broadcast channel.
+///
+/// A subscriber that falls further behind than this misses events and
+/// receives an error from [`ChangeSubscription::next`].
+const LIVE_CHANNEL_CAPACITY: usize = 1024;
+
+/// The kind of mutation recorded by a [`ChangeEvent`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ChangeKind {
+    /// A document was inserted. The document contains all fields,
+    /// including non-stored ones and the `_id` system field.
+    Upsert { document: Document },
+    /// A document was deleted.
+    Delete,
+}
+
+/// A single mutation observed through [`ChangeFeed`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeEvent {
+    /// Sequence number assigned by the document log.
+    pub seq: SeqNumber,
+    /// Internal document ID.
+    pub doc_id: u64,
+    /// External document ID.
+    pub external_id: String,
+    /// What happened to the document.
+    pub kind: ChangeKind,
+}
+
+impl ChangeEvent {
+    /// Convert a log record into change events. Batches are flattened into
+    /// their individual entries.
+    fn from_record(record: &LogRecord) -> Vec<ChangeEvent> {
+        match &record.entry {
+            LogEntry::Upsert {
+                doc_id,
+                external_id,
+                document,
+            } => vec![ChangeEvent {
+                seq: record.seq,
+                doc_id: *doc_id,
+                external_id: external_id.clone(),
+                kind: ChangeKind::Upsert {
+                    document: document.clone(),
+                },
+            }],
+            LogEntry::Delete {
+                doc_id,
+                external_id,
+            } => vec![ChangeEvent {
+                seq: record.seq,
+                doc_id: *doc_id,
+                external_id: external_id.clone(),
+                kind: ChangeKind::Delete,
+            }],
+            LogEntry::Batch { records } => records.iter().flat_map(Self::from_record).collect(),
+        }
+    }
+}
+
+impl From<ChangeEvent> for LogRecord {
+    /// Rebuild the log record described by an event, e.g. to replay it on a
+    /// replication follower.
+    fn from(event: ChangeEvent) -> Self {
+        let entry = match event.kind {
+            ChangeKind::Upsert { document } => LogEntry::Upsert {
+                doc_id: event.doc_id,
+                external_id: event.external_id,
+                document,
+            },
+            ChangeKind::Delete => LogEntry::Delete {
+                doc_id: event.doc_id,
+                external_id: event.external_id,
+            },
+        };
+        LogRecord {
+            seq: event.seq,
+            entry,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct FeedState {
+    events: VecDeque<ChangeEvent>,
+    max_events: usize,
+    /// Highest sequence number that is no longer available from the window.
+    evicted_through: SeqNumber,
+}
+
+/// Ordered, bounded feed of document log mutations.
+#[derive(Debug)]
+pub struct ChangeFeed {
+    state: Mutex<FeedState>,
+    sender: broadcast::Sender<ChangeEvent>,
+}
+
+impl ChangeFeed {
+    /// Create a feed retaining at most `max_events` events.
+    ///
+    /// A retention of `0` keeps no history: subscribers only receive events
+    /// published after they subscribed.
+    pub fn new(max_events: usize) -> Self {
+        let (sender, _) = broadcast::channel(LIVE_CHANNEL_CAPACITY);
+        Self {
+            state: Mutex::new(FeedState {
+                events: VecDeque::new(),
+                max_events,
+                evicted_through: 0,
+            }),
+            sender,
+        }
+    }
+
+    /// Publish the events of a freshly written log record.
+    ///
+    /// Must be called in sequence-number order; the document log does so
+    /// while holding its writer lock.
+    pub(crate) fn publish(&self, record: &LogRecord) {
+        let mut state = self.state.lock();
+        if state.max_events == 0 && self.sender.receiver_count() == 0 {
+            state.evicted_through = record.seq;
+            return;
+        }
+        for event in ChangeEvent::from_record(record) {
+            state.push(event.clone());
+            // Sending only fails when there are no receivers.
+            let _ = self.sender.send(event);
+        }
+    }
+
+    /// Replace the retained window with the records replayed from the WAL.
+    ///
+    /// Events up to `last_seq` that are not part of `records` are reported
+    /// as no longer retained.
+    pub(crate) fn restore(&self, records: &[LogRecord], last_seq: SeqNumber) {
+        let mut state = self.state.lock();
+        state.events.clear();
+        let events: Vec<ChangeEvent> = records.iter().flat_map(ChangeEvent::from_record).collect();
+        state.evicted_through = events
+            .first()
+            .map_or(last_seq, |event| event.seq.saturating_sub(1));
+        for event in events {
+            state.push(event);
+        }
+    }
+
+    /// Subscribe to every event with a sequence number greater than `since_seq`.
+    ///
+    /// Retained events are delivered first, followed by live events, with no
+    /// gaps or duplicates in between.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if events after `since_seq` have already been evicted
+    /// from the retained window.
+    pub fn subscribe(&self, since_seq: SeqNumber) -> Result<ChangeSubscription> {
+        let state = self.state.lock();
+        let backlog = state.retained_since(since_seq)?;
+        // Subscribing while holding the state lock guarantees that every
+        // event published afterwards reaches the receiver.
+        let receiver = self.sender.subscribe();
+        Ok(ChangeSubscription {
+            backlog,
+            receiver,
+            last_seq: since_seq,
+        })
+    }
+
+    /// Every retained event with a sequence number greater than `since_seq`,
+    /// without following live events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if events after `since_seq` have already been evicted
+    /// from the retained window.
+    pub(crate) fn retained_since(&self, since_seq: SeqNumber) -> Result<VecDeque<ChangeEvent>> {
+        self.state.lock().retained_since(since_seq)
+    }
+
+    /// The highest sequence number that can no longer be resumed from.
+    pub fn evicted_through(&self) -> SeqNumber {
+        self.state.lock().evicted_through
+    }
+}
+
+impl Default for ChangeFeed {
+    fn default() -> Self {
+        Self::new(DEFAULT_CHANGE_RETENTION)
+    }
+}
+
+impl FeedState {
+    fn retained_since(&self, since_seq: SeqNumber) -> Result<VecDeque<ChangeEvent>> {
+        if since_seq < self.evicted_through {
+            return Err(LaurusError::invalid_argument(format!(
+                "Changes after seq {since_seq} are no longer retained; \
+                 the oldest resumable seq is {}",
+                self.evicted_through
+            )));
+        }
+        Ok(self
+            .events
+            .iter()
+            .filter(|event| event.seq > since_seq)
+            .cloned()
+            .collect())
+    }
+
+    fn push(&mut self, event: ChangeEvent) {
+        if self.max_events == 0 {
+            self.evicted_through = event.seq;
+            return;
+        }
+        while self.events.len() >= self.max_events {
+            if let Some(evicted) = self.events.pop_front() {
+                self.evicted_through = evicted.seq;
+            }
+        }
+        self.events.push_back(event);
+    }
+}
+
+/// A subscription to a [`ChangeFeed`], yielding events in sequence order.
+#[derive(Debug)]
+pub struct ChangeSubscription {
+    backlog: VecDeque<ChangeEvent>,
+    receiver: broadcast::Receiver<ChangeEvent>,
+    last_seq: SeqNumber,
+}
+
+impl ChangeSubscription {
+    /// Wait for the next event.
+    ///
+    /// Returns `Ok(None)` once the feed has been dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscriber fell so far behind that live
+    /// events were dropped. The subscription can be re-established with
+    /// [`ChangeFeed::subscribe`] from [`last_seq`](Self::last_seq).
+    pub async fn next(&mut self) -> Result<Option<ChangeEvent>> {
+        if let Some(event) = self.backlog.pop_front() {
+            self.last_seq = event.seq;
+            return Ok(Some(event));
+        }
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) if event.seq <= self.last_seq => continue,
+                Ok(event) => {
+                    self.last_seq = event.seq;
+                    return Ok(Some(event));
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(None),
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    return Err(LaurusError::other(format!(
+                        "Change subscriber lagged behind by {missed} events after seq {}",
+                        self.last_seq
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Sequence number of the last event returned by [`next`](Self::next),
+    /// or the `since_seq` the subscription started from.
+    pub fn last_seq(&self) -> SeqNumber {
+        self.last_seq
+    }
+
+    /// Convert the subscription into a [`Stream`] of events.
+    ///
+    /// The stream ends when the feed is dropped or after the first error.
+    pub fn into_stream(self) -> impl Stream<Item = Result<ChangeEvent>> + Send {
+        futures::stream::unfold(Some(self), |subscription| async move {
+            let mut subscription = subscription?;
+            match subscription.next().await {
+                Ok(Some(event)) => Some((Ok(event), Some(subscription))),
+                Ok(None) => None,
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delete_record(seq: SeqNumber) -> LogRecord {
+        LogRecord {
+            seq,
+            entry: LogEntry::Delete {
+                doc_id: seq,
+                external_id: format!("doc{seq}"),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backlog_then_live() {
+        let feed = ChangeFeed::new(10);
+        feed.publish(&delete_record(1));
+        feed.publish(&delete_record(2));
+
+        let mut subscription = feed.subscribe(1).unwrap();
+        feed.publish(&delete_record(3));
+
+        assert_eq!(subscription.next().await.unwrap().unwrap().seq, 2);
+        assert_eq!(subscription.next().await.unwrap().unwrap().seq, 3);
+        assert_eq!(subscription.last_seq(), 3);
+    }
+
+    #[test]
+    fn test_retention_evicts_oldest() {
+        let feed = ChangeFeed::new(2);
+        for seq in 1..=4 {
+            feed.publish(&delete_record(seq));
+        }
+        assert_eq!(feed.evicted_through(), 2);
+        assert!(feed.subscribe(1).is_err());
+        assert!(feed.subscribe(2).is_ok());
+    }
+
+    #[test]
+    fn test_restore_flattens_batches() {
+        let feed = ChangeFeed::new(10);
+        let batch = LogRecord {
+            seq: 7,
+            entry: LogEntry::Batch {
+                records: vec![delete_record(5), delete_record(6)],
+            },
+        };
+        feed.restore(&[batch], 7);
+        assert_eq!(feed.evicted_through(), 4);
+
+        let subscription = feed.subscribe(4).unwrap();
+        let seqs: Vec<_> = subscription.backlog.iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![5, 6]);
+    }
+}
@@ -22,6 +22,10 @@
 //! The WAL log file stores records in a simple binary format:
 //! `[u32: length][json: LogRecord]` repeated for each entry.
 //! Each entry is followed by `flush_and_sync()` for durability.
+//!
+//! Truncating the WAL records the last sequence number in a sidecar file
+//! (`<wal_path>.seq`) so that sequence numbers keep increasing across
+//! commits and restarts.
 
 use std::io::{Read, Write};
 use std::sync::Arc;
@@ -33,6 +37,7 @@ use serde::{Deserialize, Serialize};
 use crate::data::Document;
 use crate::error::Result;
 use crate::storage::Storage;
+use crate::store::changes::ChangeFeed;
 use crate::store::document::UnifiedDocumentStore;
 
 /// Sequence number for log entries.
@@ -56,6 +61,28 @@ pub enum LogEntry {
         #[serde(default)]
         external_id: String,
     },
+    /// A group of upserts and deletes written as a single record.
+    ///
+    /// The whole batch is serialized into one length-prefixed record, so a
+    /// crash while writing it leaves a truncated tail that
+    /// [`DocumentLog::read_all`] discards. Recovery therefore sees either
+    /// every entry of the batch or none of them.
+    Batch {
+        /// The batched records, each with its own sequence number.
+        records: Vec<LogRecord>,
+    },
+}
+
+/// An operation to be written as part of a [`LogEntry::Batch`].
+#[derive(Debug, Clone)]
+pub enum BatchOperation {
+    /// Insert a document under a newly assigned doc_id.
+    Upsert {
+        external_id: String,
+        document: Document,
+    },
+    /// Delete an existing document.
+    Delete { doc_id: u64, external_id: String },
 }
 
 /// A log record combining a sequence number with an entry.
@@ -86,6 +113,7 @@ pub struct DocumentLog {
     wal_writer: Mutex<Option<Box<dyn crate::storage::StorageOutput>>>,
     next_seq: AtomicU64,
     doc_store: RwLock<UnifiedDocumentStore>,
+    changes: ChangeFeed,
 }
 
 impl DocumentLog {
@@ -120,9 +148,22 @@ impl DocumentLog {
             wal_writer: Mutex::new(None),
             next_seq: AtomicU64::new(1),
             doc_store: RwLock::new(doc_store),
+            changes: ChangeFeed::default(),
         })
     }
 
+    /// Set how many change events are retained for [`changes`](Self::changes)
+    /// subscribers.
+    pub fn with_change_retention(mut self, max_events: usize) -> Self {
+        self.changes = ChangeFeed::new(max_events);
+        self
+    }
+
+    /// The change data capture feed fed by every appended entry.
+    pub fn changes(&self) -> &ChangeFeed {
+        &self.changes
+    }
+
     /// Open or create the WAL file for appending.
     fn ensure_writer(&self) -> Result<()> {
         let mut writer_guard = self.wal_writer.lock();
@@ -159,6 +200,8 @@ impl DocumentLog {
         };
 
         Self::write_record(&mut writer_guard, &record)?;
+        // Published under the writer lock to keep events in seq order.
+        self.changes.publish(&record);
 
         Ok((doc_id, seq))
     }
@@ -182,10 +225,97 @@ impl DocumentLog {
         };
 
         Self::write_record(&mut writer_guard, &record)?;
+        self.changes.publish(&record);
 
         Ok(seq)
     }
 
+    /// Append a batch of operations to the log as a single record.
+    ///
+    /// Doc_ids for upserts and sequence numbers for every operation are
+    /// assigned in order; the batch record itself receives the next sequence
+    /// number after its last operation. The record is written and synced in
+    /// one step, so it is either fully present in the log or absent.
+    ///
+    /// Returns the batch record.
+    pub fn append_batch(&self, operations: Vec<BatchOperation>) -> Result<LogRecord> {
+        self.ensure_writer()?;
+
+        let mut writer_guard = self.wal_writer.lock();
+
+        let records = operations
+            .into_iter()
+            .map(|operation| {
+                let entry = match operation {
+                    BatchOperation::Upsert {
+                        external_id,
+                        document,
+                    } => LogEntry::Upsert {
+                        doc_id: self.next_doc_id.fetch_add(1, Ordering::SeqCst),
+                        external_id,
+                        document,
+                    },
+                    BatchOperation::Delete {
+                        doc_id,
+                        external_id,
+                    } => LogEntry::Delete {
+                        doc_id,
+                        external_id,
+                    },
+                };
+                LogRecord {
+                    seq: self.next_seq.fetch_add(1, Ordering::SeqCst),
+                    entry,
+                }
+            })
+            .collect();
+
+        let record = LogRecord {
+            seq: self.next_seq.fetch_add(1, Ordering::SeqCst),
+            entry: LogEntry::Batch { records },
+        };
+
+        Self::write_record(&mut writer_guard, &record)?;
+        self.changes.publish(&record);
+
+        Ok(record)
+    }
+
+    /// Append a record that was produced by another log, keeping its
+    /// sequence number and doc_ids.
+    ///
+    /// Used by replication followers to mirror a leader's log. Records must
+    /// arrive in sequence order; a record whose sequence number is not newer
+    /// than the last one in this log is ignored. The `next_seq` and
+    /// `next_doc_id` counters are advanced past the record's values.
+    ///
+    /// Returns `true` if the record was appended.
+    pub fn append_record(&self, record: &LogRecord) -> Result<bool> {
+        self.ensure_writer()?;
+
+        let mut writer_guard = self.wal_writer.lock();
+
+        if record.seq < self.next_seq.load(Ordering::SeqCst) {
+            return Ok(false);
+        }
+
+        let entries = match &record.entry {
+            LogEntry::Batch { records } => records.iter().map(|r| &r.entry).collect(),
+            entry => vec![entry],
+        };
+        for entry in entries {
+            if let LogEntry::Upsert { doc_id, .. } = entry {
+                self.next_doc_id.fetch_max(doc_id + 1, Ordering::SeqCst);
+            }
+        }
+        self.next_seq.store(record.seq + 1, Ordering::SeqCst);
+
+        Self::write_record(&mut writer_guard, record)?;
+        self.changes.publish(record);
+
+        Ok(true)
+    }
+
     /// Write a single record to the WAL file.
     fn write_record(
         writer_guard: &mut Option<Box<dyn crate::storage::StorageOutput>>,
@@ -214,6 +344,8 @@ impl DocumentLog {
     /// greater than the maximum values found in the log, and syncs
     /// `next_doc_id` with the committed document store segments.
     pub fn read_all(&self) -> Result<Vec<LogRecord>> {
+        self.restore_seq_checkpoint()?;
+
         if !self.wal_storage.file_exists(&self.wal_path) {
             // Even with an empty WAL, sync next_doc_id with doc_store.
             let store_next = self.doc_store.read().next_doc_id();
@@ -249,10 +381,16 @@ impl DocumentLog {
             if record.seq > max_seq {
                 max_seq = record.seq;
             }
-            if let LogEntry::Upsert { doc_id, .. } = &record.entry
-                && *doc_id > max_doc_id
-            {
-                max_doc_id = *doc_id;
+            let entries = match &record.entry {
+                LogEntry::Batch { records } => records.iter().map(|r| &r.entry).collect(),
+                entry => vec![entry],
+            };
+            for entry in entries {
+                if let LogEntry::Upsert { doc_id, .. } = entry
+                    && *doc_id > max_doc_id
+                {
+                    max_doc_id = *doc_id;
+                }
             }
             records.push(record);
         }
@@ -283,6 +421,13 @@ impl DocumentLog {
             *writer_guard = None;
         }
 
+        // Persist the sequence counter before discarding the entries that
+        // carry it.
+        let mut seq_writer = self.wal_storage.create_output(&self.seq_path())?;
+        seq_writer.write_all(&self.last_seq().to_le_bytes())?;
+        seq_writer.flush_and_sync()?;
+        seq_writer.close()?;
+
         let mut writer = self.wal_storage.create_output(&self.wal_path)?;
         writer.flush_and_sync()?;
         writer.close()?;
@@ -294,11 +439,45 @@ impl DocumentLog {
         Ok(())
     }
 
+    /// Path of the sidecar file holding the sequence number at the last
+    /// truncation.
+    fn seq_path(&self) -> String {
+        format!("{}.seq", self.wal_path)
+    }
+
+    /// Advance `next_seq` past the sequence number saved by the last
+    /// [`truncate`](Self::truncate), if any.
+    fn restore_seq_checkpoint(&self) -> Result<()> {
+        let checkpoint = self.committed_seq()?;
+        self.next_seq.fetch_max(checkpoint + 1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Sequence number saved by the last [`truncate`](Self::truncate), i.e.
+    /// the last sequence number covered by committed data. `0` if the log
+    /// has never been truncated.
+    pub fn committed_seq(&self) -> Result<SeqNumber> {
+        let path = self.seq_path();
+        if !self.wal_storage.file_exists(&path) {
+            return Ok(0);
+        }
+        let mut reader = self.wal_storage.open_input(&path)?;
+        let mut bytes = [0u8; 8];
+        reader.read_exact(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
     /// Get the last used sequence number.
     pub fn last_seq(&self) -> SeqNumber {
         self.next_seq.load(Ordering::SeqCst).saturating_sub(1)
     }
 
+    /// Advance the sequence counter so that [`last_seq`](Self::last_seq) is
+    /// at least `seq`.
+    pub(crate) fn advance_seq(&self, seq: SeqNumber) {
+        self.next_seq.fetch_max(seq + 1, Ordering::SeqCst);
+    }
+
     /// Get the current next_doc_id value.
     pub fn next_doc_id(&self) -> u64 {
         self.next_doc_id.load(Ordering::SeqCst)
@@ -430,6 +609,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_append_batch() {
+        let wal_storage = make_storage();
+        let doc_storage = make_storage();
+        let log = DocumentLog::new(wal_storage.clone(), "test.log", doc_storage.clone()).unwrap();
+
+        let doc = Document::builder()
+            .add_field("body", DataValue::Text("hello".to_string()))
+            .build();
+        let (doc_id, _) = log.append("ext_1", doc.clone()).unwrap();
+
+        let record = log
+            .append_batch(vec![
+                BatchOperation::Delete {
+                    doc_id,
+                    external_id: "ext_1".to_string(),
+                },
+                BatchOperation::Upsert {
+                    external_id: "ext_2".to_string(),
+                    document: doc,
+                },
+            ])
+            .unwrap();
+        assert_eq!(record.seq, 4);
+        let LogEntry::Batch { records } = &record.entry else {
+            panic!("Expected Batch");
+        };
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].seq, 2);
+        assert_eq!(records[1].seq, 3);
+        assert!(matches!(
+            records[1].entry,
+            LogEntry::Upsert { doc_id: 2, .. }
+        ));
+
+        // Counters are restored from entries nested inside the batch.
+        let log = DocumentLog::new(wal_storage, "test.log", doc_storage).unwrap();
+        let records = log.read_all().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(log.next_doc_id(), 3);
+        assert_eq!(log.last_seq(), 4);
+    }
+
     #[test]
     fn test_truncate() {
         let log = make_log();
@@ -443,6 +665,7 @@ mod tests {
 
         let records = log.read_all().unwrap();
         assert!(records.is_empty());
+        assert_eq!(log.last_seq(), 1);
 
         // Sequence and doc_id should continue monotonically.
         let doc2 = Document::builder()
@@ -486,6 +709,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_seq_survives_truncate_and_reopen() {
+        let wal_storage = make_storage();
+        let doc_storage = make_storage();
+
+        {
+            let log =
+                DocumentLog::new(wal_storage.clone(), "test.log", doc_storage.clone()).unwrap();
+            log.read_all().unwrap();
+            log.append_delete(1, "ext_1").unwrap();
+            log.append_delete(2, "ext_2").unwrap();
+            log.truncate().unwrap();
+        }
+
+        let log = DocumentLog::new(wal_storage, "test.log", doc_storage).unwrap();
+        assert!(log.read_all().unwrap().is_empty());
+        assert_eq!(log.last_seq(), 2);
+        assert_eq!(log.append_delete(3, "ext_3").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_append_record_keeps_seq_and_doc_id() {
+        let log = make_log();
+
+        let doc = Document::builder()
+            .add_field("body", DataValue::Text("hello".to_string()))
+            .build();
+        let record = LogRecord {
+            seq: 10,
+            entry: LogEntry::Upsert {
+                doc_id: 7,
+                external_id: "ext_1".to_string(),
+                document: doc,
+            },
+        };
+        assert!(log.append_record(&record).unwrap());
+        // Replaying the same record is a no-op.
+        assert!(!log.append_record(&record).unwrap());
+
+        assert_eq!(log.last_seq(), 10);
+        assert_eq!(log.next_doc_id(), 8);
+        assert_eq!(log.read_all().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_set_next_doc_id() {
         let log = make_log();
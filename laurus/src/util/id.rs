@@ -37,6 +37,11 @@ pub fn get_local_id(doc_id: u64) -> u64 {
     doc_id & LOCAL_ID_MASK
 }
 
+/// Extract the shard ID from a 64-bit doc ID.
+pub fn get_shard_id(doc_id: u64) -> u16 {
+    (doc_id >> LOCAL_ID_BITS) as u16
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,6 +53,7 @@ mod tests {
         let doc_id = create_doc_id(shard_id, local_id);
 
         assert_eq!(get_local_id(doc_id), local_id);
+        assert_eq!(get_shard_id(doc_id), shard_id);
     }
 
     #[test]
@@ -377,6 +377,11 @@ impl VectorStore {
         Ok(parking_lot::RwLockWriteGuard::downgrade(guard))
     }
 
+    /// Get a reader over the committed vectors.
+    pub(crate) fn reader(&self) -> Result<Arc<dyn crate::vector::reader::VectorIndexReader>> {
+        self.index.reader()
+    }
+
     /// Execute a low-level vector similarity search.
     pub fn search_index(
         &self,
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use laurus::lexical::{TermQuery, TextOption};
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::vector::{FlatOption, QueryVector, Vector};
+use laurus::{
+    ChangeKind, Document, Engine, FieldOption, Identity, Schema, SearchRequestBuilder, Storage,
+    VectorSearchQuery,
+};
+
+fn acl_schema() -> Schema {
+    Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .add_field("acl", FieldOption::Text(TextOption::default()))
+        .add_field(
+            "embedding",
+            FieldOption::Flat(FlatOption {
+                dimension: 2,
+                ..Default::default()
+            }),
+        )
+        .acl_field("acl")
+        .build()
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+async fn populate() -> laurus::Result<Engine> {
+    let engine = Engine::new(new_storage(), acl_schema()).await?;
+    for (id, body, acl, vector) in [
+        ("public", "rust guide", "Everyone", [1.0, 0.0]),
+        ("eng", "rust internals", "Engineering alice", [0.9, 0.1]),
+        ("hr", "rust salaries", "HR", [0.8, 0.2]),
+    ] {
+        engine
+            .put_document(
+                id,
+                Document::builder()
+                    .add_text("body", body)
+                    .add_text("acl", acl)
+                    .add_vector("embedding", vector.to_vec())
+                    .build(),
+            )
+            .await?;
+    }
+    engine
+        .put_document(
+            "unlisted",
+            Document::builder()
+                .add_text("body", "rust secrets")
+                .add_vector("embedding", vec![0.7, 0.3])
+                .build(),
+        )
+        .await?;
+    engine.commit().await?;
+    Ok(engine)
+}
+
+async fn search_ids(
+    engine: &Engine,
+    builder: SearchRequestBuilder,
+    identity: Option<Identity>,
+) -> laurus::Result<Vec<String>> {
+    let builder = match identity {
+        Some(identity) => builder.identity(identity),
+        None => builder,
+    };
+    let mut ids: Vec<String> = engine
+        .search(builder.limit(100).build())
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    Ok(ids)
+}
+
+fn engineer() -> Identity {
+    Identity::new(["bob", "Everyone", "Engineering"])
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_lexical_search_is_filtered_by_identity() -> laurus::Result<()> {
+    let engine = populate().await?;
+    let query = || SearchRequestBuilder::new().query_dsl("body:rust");
+
+    assert_eq!(
+        search_ids(&engine, query(), Some(engineer())).await?,
+        vec!["eng", "public"]
+    );
+    assert_eq!(
+        search_ids(&engine, query(), Some(Identity::new(["alice"]))).await?,
+        vec!["eng"]
+    );
+    // Principals are case-sensitive and an empty identity sees nothing.
+    assert!(
+        search_ids(&engine, query(), Some(Identity::new(["hr"])))
+            .await?
+            .is_empty()
+    );
+    assert!(
+        search_ids(&engine, query(), Some(Identity::default()))
+            .await?
+            .is_empty()
+    );
+    // Without an identity the search is not restricted.
+    assert_eq!(search_ids(&engine, query(), None).await?.len(), 4);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dsl_cannot_bypass_acl() -> laurus::Result<()> {
+    let engine = populate().await?;
+
+    let ids = search_ids(
+        &engine,
+        SearchRequestBuilder::new().query_dsl("body:rust OR acl:HR OR body:secrets"),
+        Some(engineer()),
+    )
+    .await?;
+    assert_eq!(ids, vec!["eng", "public"]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_vector_and_hybrid_search_are_filtered() -> laurus::Result<()> {
+    let engine = populate().await?;
+    let vector = || {
+        VectorSearchQuery::Vectors(vec![QueryVector {
+            vector: Vector::new(vec![0.75, 0.25]),
+            weight: 1.0,
+            fields: None,
+        }])
+    };
+
+    let ids = search_ids(
+        &engine,
+        SearchRequestBuilder::new().vector_query(vector()),
+        Some(Identity::new(["HR"])),
+    )
+    .await?;
+    assert_eq!(ids, vec!["hr"]);
+
+    let ids = search_ids(
+        &engine,
+        SearchRequestBuilder::new()
+            .lexical_query(laurus::LexicalSearchQuery::Obj(Box::new(TermQuery::new(
+                "body", "guide",
+            ))))
+            .vector_query(vector()),
+        Some(engineer()),
+    )
+    .await?;
+    assert_eq!(ids, vec!["eng", "public"]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_acl_is_combined_with_filter_query() -> laurus::Result<()> {
+    let engine = populate().await?;
+
+    let ids = search_ids(
+        &engine,
+        SearchRequestBuilder::new()
+            .query_dsl("body:rust")
+            .filter_query(Box::new(TermQuery::new("body", "internals"))),
+        Some(engineer()),
+    )
+    .await?;
+    assert_eq!(ids, vec!["eng"]);
+
+    let ids = search_ids(
+        &engine,
+        SearchRequestBuilder::new()
+            .query_dsl("body:rust")
+            .filter_query(Box::new(TermQuery::new("body", "salaries"))),
+        Some(engineer()),
+    )
+    .await?;
+    assert!(ids.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_get_documents_as_hides_unauthorized_documents() -> laurus::Result<()> {
+    let engine = populate().await?;
+
+    assert_eq!(engine.get_documents_as("eng", &engineer()).await?.len(), 1);
+    assert!(engine.get_documents_as("hr", &engineer()).await?.is_empty());
+    assert!(
+        engine
+            .get_documents_as("unlisted", &engineer())
+            .await?
+            .is_empty()
+    );
+    assert_eq!(engine.get_documents("hr").await?.len(), 1);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_changes_as_only_reports_visible_documents() -> laurus::Result<()> {
+    let engine = populate().await?;
+    engine.delete_documents("eng").await?;
+    engine.delete_documents("hr").await?;
+
+    let mut subscription = engine.changes_as(0, &engineer())?;
+    let mut events = Vec::new();
+    for _ in 0..3 {
+        let event = subscription.next().await?.unwrap();
+        let upsert = matches!(event.kind, ChangeKind::Upsert { .. });
+        events.push((event.external_id, upsert));
+    }
+    assert_eq!(
+        events,
+        vec![
+            ("public".to_string(), true),
+            ("eng".to_string(), true),
+            ("eng".to_string(), false),
+        ]
+    );
+
+    // A caller without a listed principal gets no events at all.
+    let mut subscription = engine.changes_as(0, &Identity::new(["nobody"]))?;
+    let next = tokio::time::timeout(Duration::from_millis(200), subscription.next()).await;
+    assert!(next.is_err());
+    assert_eq!(subscription.last_seq(), engine.last_seq());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_acl_field_rejected() {
+    let schema = Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .acl_field("missing")
+        .build();
+    assert!(Engine::new(new_storage(), schema).await.is_err());
+
+    let schema = Schema::builder()
+        .add_field(
+            "acl",
+            FieldOption::Text(TextOption::default().analyzer("standard")),
+        )
+        .acl_field("acl")
+        .build();
+    assert!(Engine::new(new_storage(), schema).await.is_err());
+
+    let schema = Schema::builder()
+        .add_field(
+            "acl",
+            FieldOption::Text(TextOption::default().stored(false)),
+        )
+        .acl_field("acl")
+        .build();
+    assert!(Engine::new(new_storage(), schema).await.is_err());
+}
+
+#[tokio::test]
+async fn test_acl_field_cannot_be_deleted() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), acl_schema()).await?;
+    let err = engine.delete_field("acl").await.unwrap_err();
+    assert!(err.to_string().contains("ACL field"));
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    AnalyzerDefinition, Document, Engine, FieldOption, Schema, SearchRequestBuilder,
+    TokenFilterConfig, TokenizerConfig,
+};
+
+fn whitespace(token_filters: Vec<TokenFilterConfig>) -> AnalyzerDefinition {
+    AnalyzerDefinition {
+        char_filters: vec![],
+        tokenizer: TokenizerConfig::Whitespace,
+        token_filters,
+    }
+}
+
+/// Index edge n-grams and keep query terms whole.
+fn prefix_schema() -> Schema {
+    Schema::builder()
+        .add_analyzer(
+            "prefixes",
+            AnalyzerDefinition {
+                char_filters: vec![],
+                tokenizer: TokenizerConfig::EdgeNgram {
+                    min_gram: 1,
+                    max_gram: 10,
+                },
+                token_filters: vec![TokenFilterConfig::Lowercase],
+            },
+        )
+        .add_analyzer("plain", whitespace(vec![TokenFilterConfig::Lowercase]))
+        .add_field(
+            "body",
+            FieldOption::Text(
+                TextOption::default()
+                    .analyzer("prefixes")
+                    .search_analyzer("plain"),
+            ),
+        )
+        .build()
+}
+
+/// Index and search with word shingles.
+fn shingle_schema() -> Schema {
+    Schema::builder()
+        .add_analyzer(
+            "shingles",
+            whitespace(vec![
+                TokenFilterConfig::Lowercase,
+                TokenFilterConfig::Shingle {
+                    min_shingle_size: 2,
+                    max_shingle_size: 2,
+                    token_separator: " ".into(),
+                    output_unigrams: true,
+                },
+            ]),
+        )
+        .add_field(
+            "body",
+            FieldOption::Text(TextOption::default().analyzer("shingles")),
+        )
+        .build()
+}
+
+async fn populate(schema: Schema) -> laurus::Result<Engine> {
+    let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+    let engine = Engine::new(storage, schema).await?;
+    for (id, body) in [
+        ("ml", "Machine learning tutorial"),
+        ("mt", "Machine translation, learning the hard way"),
+        ("other", "Cooking tutorial"),
+    ] {
+        engine
+            .put_document(id, Document::builder().add_text("body", body).build())
+            .await?;
+    }
+    engine.commit().await?;
+    Ok(engine)
+}
+
+async fn search_ids(engine: &Engine, query: &str) -> laurus::Result<Vec<String>> {
+    let mut ids: Vec<String> = engine
+        .search(
+            SearchRequestBuilder::new()
+                .query_dsl(query)
+                .limit(10)
+                .build(),
+        )
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    Ok(ids)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_edge_ngram_matches_prefixes() -> laurus::Result<()> {
+    let engine = populate(prefix_schema()).await?;
+
+    assert_eq!(search_ids(&engine, "body:mach").await?, vec!["ml", "mt"]);
+    assert_eq!(search_ids(&engine, "body:tut").await?, vec!["ml", "other"]);
+    assert!(search_ids(&engine, "body:achine").await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_edge_ngram_phrase_prefix() -> laurus::Result<()> {
+    let engine = populate(prefix_schema()).await?;
+
+    // Each word's prefixes share its position, so phrases still line up.
+    assert_eq!(
+        search_ids(&engine, r#"body:"machine lea""#).await?,
+        vec!["ml"]
+    );
+    assert_eq!(search_ids(&engine, r#"body:"ma tr""#).await?, vec!["mt"]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_shingle_phrases() -> laurus::Result<()> {
+    let engine = populate(shingle_schema()).await?;
+
+    assert_eq!(
+        search_ids(&engine, r#"body:"machine learning""#).await?,
+        vec!["ml"]
+    );
+    assert_eq!(
+        search_ids(&engine, r#"body:"learning tutorial""#).await?,
+        vec!["ml"]
+    );
+    assert_eq!(search_ids(&engine, "body:machine").await?, vec!["ml", "mt"]);
+    assert!(
+        search_ids(&engine, r#"body:"tutorial machine""#)
+            .await?
+            .is_empty()
+    );
+    Ok(())
+}
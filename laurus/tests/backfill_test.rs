@@ -0,0 +1,220 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::vector::{FlatOption, Vector};
+use laurus::{
+    BackfillTask, Document, EmbedInput, EmbedInputType, Embedder, Engine, FieldOption,
+    LexicalSearchQuery, Schema, SearchRequestBuilder, Storage,
+};
+
+#[derive(Debug)]
+struct LengthEmbedder;
+
+#[async_trait]
+impl Embedder for LengthEmbedder {
+    async fn embed(&self, input: &EmbedInput<'_>) -> laurus::Result<Vector> {
+        match input {
+            EmbedInput::Text(text) => Ok(Vector::new(vec![text.len() as f32; 128])),
+            _ => Err(laurus::LaurusError::invalid_argument("text only")),
+        }
+    }
+
+    fn supported_input_types(&self) -> Vec<EmbedInputType> {
+        vec![EmbedInputType::Text]
+    }
+
+    fn name(&self) -> &str {
+        "length"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn schema() -> Schema {
+    Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .build()
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+async fn search_count(engine: &Engine, dsl: &str) -> laurus::Result<usize> {
+    let request = SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::from(dsl))
+        .limit(100)
+        .build();
+    Ok(engine.search(request).await?.len())
+}
+
+async fn populate(engine: &Engine, count: usize) -> laurus::Result<()> {
+    for i in 0..count {
+        engine
+            .put_document(
+                &format!("doc{i}"),
+                Document::builder().add_text("body", "Hello World").build(),
+            )
+            .await?;
+    }
+    engine.commit().await
+}
+
+fn keyword_field() -> FieldOption {
+    FieldOption::Text(TextOption::default().analyzer("keyword"))
+}
+
+#[tokio::test]
+async fn test_backfill_text_field() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+    populate(&engine, 5).await?;
+
+    let (schema, progress) = engine
+        .add_field_with_backfill("body_exact", keyword_field(), "body")
+        .await?;
+    assert!(schema.fields.contains_key("body_exact"));
+    assert_eq!(progress.total, 5);
+    assert!(!progress.completed);
+
+    let mut progress = progress;
+    while !progress.completed {
+        progress = engine.backfill_batch("body_exact", 2).await?;
+    }
+    engine.commit().await?;
+
+    assert_eq!(progress.processed, 5);
+    assert_eq!(
+        search_count(&engine, "body_exact:\"Hello World\"").await?,
+        5
+    );
+    assert_eq!(engine.stats()?.document_count, 5);
+    assert!(engine.stats()?.backfills[0].completed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_skips_deleted_documents() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+    populate(&engine, 3).await?;
+    engine.delete_documents("doc1").await?;
+
+    engine
+        .add_field_with_backfill("body_exact", keyword_field(), "body")
+        .await?;
+    let progress = engine.backfill_batch("body_exact", 100).await?;
+    engine.commit().await?;
+
+    assert!(progress.completed);
+    assert_eq!(progress.processed, 2);
+    assert_eq!(engine.stats()?.document_count, 2);
+    assert!(engine.get_documents("doc1").await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_resumes_after_reopen() -> laurus::Result<()> {
+    let storage = new_storage();
+    let (schema, processed) = {
+        let engine = Engine::new(storage.clone(), schema()).await?;
+        populate(&engine, 4).await?;
+        let (schema, _) = engine
+            .add_field_with_backfill("body_exact", keyword_field(), "body")
+            .await?;
+        let progress = engine.backfill_batch("body_exact", 2).await?;
+        assert!(progress.processed > 0);
+        // No commit: the re-indexed documents only live in the WAL.
+        (schema, progress.processed)
+    };
+
+    let engine = Engine::new(storage, schema).await?;
+    let mut progress = engine.backfills().remove(0);
+    assert_eq!(progress.processed, processed);
+    assert!(!progress.completed);
+
+    while !progress.completed {
+        progress = engine.backfill_batch("body_exact", 2).await?;
+    }
+    assert_eq!(progress.processed, 4);
+    engine.commit().await?;
+
+    assert_eq!(
+        search_count(&engine, "body_exact:\"Hello World\"").await?,
+        4
+    );
+    assert_eq!(engine.stats()?.document_count, 4);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_backfill_task_embeds_source_field() -> laurus::Result<()> {
+    let engine = Engine::builder(new_storage(), schema())
+        .embedder(Arc::new(LengthEmbedder))
+        .build()
+        .await?;
+    let engine = Arc::new(engine);
+    populate(&engine, 3).await?;
+
+    engine
+        .add_field_with_backfill("body_vec", FieldOption::Flat(FlatOption::new(128)), "body")
+        .await?;
+    let progress = BackfillTask::spawn(engine.clone(), "body_vec", 1)
+        .wait()
+        .await?;
+    engine.commit().await?;
+
+    assert!(progress.completed);
+    assert_eq!(progress.processed, 3);
+    assert_eq!(engine.stats()?.vector_fields["body_vec"].vector_count, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_requires_stored_source() -> laurus::Result<()> {
+    let schema = Schema::builder()
+        .add_field(
+            "body",
+            FieldOption::Text(TextOption::default().stored(false)),
+        )
+        .build();
+    let engine = Engine::new(new_storage(), schema).await?;
+
+    let result = engine
+        .add_field_with_backfill("body_exact", keyword_field(), "body")
+        .await;
+    assert!(result.is_err());
+    assert!(!engine.schema().fields.contains_key("body_exact"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_backfill_rejects_unstored_fields() -> laurus::Result<()> {
+    let schema = Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .add_field(
+            "notes",
+            FieldOption::Text(TextOption::default().stored(false)),
+        )
+        .build();
+    let engine = Engine::new(new_storage(), schema).await?;
+
+    // Re-indexing from stored fields would drop "notes" from every
+    // backfilled document.
+    let result = engine
+        .add_field_with_backfill("body_exact", keyword_field(), "body")
+        .await;
+    assert!(result.unwrap_err().to_string().contains("notes"));
+    assert!(!engine.schema().fields.contains_key("body_exact"));
+
+    Ok(())
+}
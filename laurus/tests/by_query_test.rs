@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use laurus::lexical::TermQuery;
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    ByQueryOptions, CancellationToken, DataValue, Document, Engine, FieldOption,
+    LexicalSearchQuery, Schema, SearchRequestBuilder, Storage,
+};
+
+async fn setup_engine() -> laurus::Result<Engine> {
+    let storage: Arc<dyn Storage> = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+    let schema = Schema::builder()
+        .add_field("tenant", FieldOption::Text(TextOption::default()))
+        .add_field("status", FieldOption::Text(TextOption::default()))
+        .build();
+    let engine = Engine::new(storage, schema).await?;
+
+    for i in 0..10 {
+        let tenant = if i % 2 == 0 { "acme" } else { "globex" };
+        engine
+            .put_document(
+                &format!("doc{i}"),
+                Document::builder()
+                    .add_text("tenant", tenant)
+                    .add_text("status", "active")
+                    .build(),
+            )
+            .await?;
+    }
+    engine.commit().await?;
+    Ok(engine)
+}
+
+async fn count(engine: &Engine, dsl: &str) -> laurus::Result<usize> {
+    let request = SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::from(dsl))
+        .limit(100)
+        .build();
+    Ok(engine.search(request).await?.len())
+}
+
+#[tokio::test]
+async fn test_delete_by_query() -> laurus::Result<()> {
+    let engine = setup_engine().await?;
+
+    let response = engine
+        .delete_by_query(
+            Box::new(TermQuery::new("tenant", "acme")),
+            ByQueryOptions::default().batch_size(2),
+        )
+        .await?;
+    assert_eq!(response.matched, 5);
+    assert_eq!(response.processed, 5);
+    assert_eq!(response.batches, 3);
+    assert!(!response.cancelled);
+
+    engine.commit().await?;
+    assert_eq!(count(&engine, "tenant:acme").await?, 0);
+    assert_eq!(count(&engine, "tenant:globex").await?, 5);
+    assert!(engine.get_documents("doc0").await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_by_query_max_docs() -> laurus::Result<()> {
+    let engine = setup_engine().await?;
+
+    let response = engine
+        .delete_by_query(
+            Box::new(TermQuery::new("tenant", "acme")),
+            ByQueryOptions::default().max_docs(3),
+        )
+        .await?;
+    assert_eq!(response.matched, 5);
+    assert_eq!(response.processed, 3);
+
+    engine.commit().await?;
+    assert_eq!(count(&engine, "tenant:acme").await?, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_by_query_cancelled() -> laurus::Result<()> {
+    let engine = setup_engine().await?;
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let response = engine
+        .delete_by_query(
+            Box::new(TermQuery::new("tenant", "acme")),
+            ByQueryOptions::default().cancellation(token),
+        )
+        .await?;
+    assert!(response.cancelled);
+    assert_eq!(response.processed, 0);
+
+    engine.commit().await?;
+    assert_eq!(count(&engine, "tenant:acme").await?, 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_by_query() -> laurus::Result<()> {
+    let engine = setup_engine().await?;
+
+    let patch = Document::builder().add_text("status", "archived").build();
+    let response = engine
+        .update_by_query(
+            Box::new(TermQuery::new("tenant", "globex")),
+            patch,
+            ByQueryOptions::default(),
+        )
+        .await?;
+    assert_eq!(response.matched, 5);
+    assert_eq!(response.processed, 5);
+
+    engine.commit().await?;
+    assert_eq!(count(&engine, "status:archived").await?, 5);
+    assert_eq!(count(&engine, "status:active").await?, 5);
+
+    let docs = engine.get_documents("doc1").await?;
+    assert_eq!(docs.len(), 1);
+    assert_eq!(
+        docs[0].get("status").and_then(|v| v.as_text()),
+        Some("archived")
+    );
+    assert_eq!(
+        docs[0].get("tenant").and_then(|v| v.as_text()),
+        Some("globex")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_by_query_commits_each_batch() -> laurus::Result<()> {
+    let engine = setup_engine().await?;
+
+    // The patch keeps the documents matching, so re-indexed documents
+    // must not be picked up again by later pages.
+    let patch = Document::builder().add_text("status", "active").build();
+    let response = engine
+        .update_by_query(
+            Box::new(TermQuery::new("tenant", "acme")),
+            patch,
+            ByQueryOptions::default().batch_size(2),
+        )
+        .await?;
+    assert_eq!(response.matched, 5);
+    assert_eq!(response.processed, 5);
+    assert_eq!(response.batches, 3);
+
+    // Every batch is committed by the run itself.
+    assert_eq!(count(&engine, "tenant:acme").await?, 5);
+    assert_eq!(engine.get_documents("doc0").await?.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_by_query_removes_null_fields() -> laurus::Result<()> {
+    let engine = setup_engine().await?;
+
+    let patch = Document::builder()
+        .add_field("status", DataValue::Null)
+        .build();
+    engine
+        .update_by_query(
+            Box::new(TermQuery::new("tenant", "acme")),
+            patch,
+            ByQueryOptions::default(),
+        )
+        .await?;
+    engine.commit().await?;
+
+    let docs = engine.get_documents("doc0").await?;
+    assert_eq!(docs.len(), 1);
+    assert!(docs[0].get("status").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_by_query_rejects_id_patch() -> laurus::Result<()> {
+    let engine = setup_engine().await?;
+
+    let patch = Document::builder().add_text("_id", "other").build();
+    let result = engine
+        .update_by_query(
+            Box::new(TermQuery::new("tenant", "acme")),
+            patch,
+            ByQueryOptions::default(),
+        )
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
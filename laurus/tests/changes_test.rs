@@ -0,0 +1,146 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{ChangeKind, Document, Engine, FieldOption, Identity, Schema, Storage};
+
+fn schema() -> Schema {
+    Schema::builder()
+        .add_field("title", FieldOption::Text(TextOption::default()))
+        .build()
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+fn doc(title: &str) -> Document {
+    Document::builder().add_text("title", title).build()
+}
+
+#[tokio::test]
+async fn test_changes_survive_commit() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+    engine.put_document("a", doc("hello")).await?;
+    engine.put_document("a", doc("world")).await?;
+    engine.commit().await?;
+
+    let mut changes = engine.changes(0)?;
+    let first = changes.next().await?.unwrap();
+    assert_eq!(first.external_id, "a");
+    let ChangeKind::Upsert { document } = &first.kind else {
+        panic!("Expected upsert");
+    };
+    assert_eq!(
+        document.get("title").and_then(|v| v.as_text()),
+        Some("hello")
+    );
+
+    let second = changes.next().await?.unwrap();
+    assert!(matches!(second.kind, ChangeKind::Delete));
+    assert_eq!(second.doc_id, first.doc_id);
+
+    let third = changes.next().await?.unwrap();
+    assert!(matches!(third.kind, ChangeKind::Upsert { .. }));
+    assert!(first.seq < second.seq && second.seq < third.seq);
+    assert_eq!(engine.last_seq(), third.seq);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_changes_as_drops_unstored_fields() -> laurus::Result<()> {
+    let schema = Schema::builder()
+        .add_field("title", FieldOption::Text(TextOption::default()))
+        .add_field(
+            "secret",
+            FieldOption::Text(TextOption::default().stored(false)),
+        )
+        .build();
+    let engine = Engine::new(new_storage(), schema).await?;
+    let document = Document::builder()
+        .add_text("title", "hello")
+        .add_text("secret", "hidden")
+        .build();
+    engine.put_document("a", document).await?;
+
+    let ChangeKind::Upsert { document } = engine.changes(0)?.next().await?.unwrap().kind else {
+        panic!("Expected upsert");
+    };
+    assert!(document.get("secret").is_some());
+
+    let mut changes = engine.changes_as(0, &Identity::default())?;
+    let ChangeKind::Upsert { document } = changes.next().await?.unwrap().kind else {
+        panic!("Expected upsert");
+    };
+    assert!(document.get("secret").is_none());
+    assert!(document.get("title").is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_changes_follow_live_mutations() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+    engine.put_document("a", doc("hello")).await?;
+
+    let mut changes = engine.changes(engine.last_seq())?;
+    let mut txn = engine.begin();
+    txn.delete_documents("a").put_document("b", doc("world"));
+    txn.commit().await?;
+
+    let event = changes.next().await?.unwrap();
+    assert_eq!(event.external_id, "a");
+    assert!(matches!(event.kind, ChangeKind::Delete));
+    let event = changes.next().await?.unwrap();
+    assert_eq!(event.external_id, "b");
+    assert!(matches!(event.kind, ChangeKind::Upsert { .. }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_changes_retention_window() -> laurus::Result<()> {
+    let engine = Engine::builder(new_storage(), schema())
+        .change_retention(2)
+        .build()
+        .await?;
+    for id in ["a", "b", "c"] {
+        engine.add_document(id, doc("hello")).await?;
+    }
+
+    assert!(engine.changes(0).is_err());
+    let mut changes = engine.changes(1)?;
+    assert_eq!(changes.next().await?.unwrap().external_id, "b");
+    assert_eq!(changes.next().await?.unwrap().external_id, "c");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_seq_monotonic_across_restart() -> laurus::Result<()> {
+    let storage = new_storage();
+    let last_seq = {
+        let engine = Engine::new(storage.clone(), schema()).await?;
+        engine.put_document("a", doc("hello")).await?;
+        engine.put_document("b", doc("hello")).await?;
+        engine.commit().await?;
+        engine.last_seq()
+    };
+
+    let engine = Engine::new(storage.clone(), schema()).await?;
+    assert!(engine.changes(0).is_err());
+    let mut changes = engine.changes(last_seq)?;
+    engine.put_document("c", doc("hello")).await?;
+    let event = changes.next().await?.unwrap();
+    assert_eq!(event.seq, last_seq + 1);
+    drop(engine);
+
+    // The uncommitted write is replayed and reported again after reopening.
+    let engine = Engine::new(storage, schema()).await?;
+    assert_eq!(engine.get_documents("c").await?.len(), 1);
+    let mut changes = engine.changes(last_seq)?;
+    assert_eq!(changes.next().await?.unwrap().external_id, "c");
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    CompletionOption, DateTimeOption, Document, Engine, FieldOption, Identity, IntegerOption,
+    Schema, Suggestion,
+};
+
+fn schema() -> Schema {
+    Schema::builder()
+        .add_field(
+            "title",
+            FieldOption::Completion(CompletionOption::default().weight_field("popularity")),
+        )
+        .add_field("popularity", FieldOption::Integer(IntegerOption::default()))
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .build()
+}
+
+fn doc(title: &str, popularity: i64) -> Document {
+    Document::builder()
+        .add_text("title", title)
+        .add_integer("popularity", popularity)
+        .build()
+}
+
+async fn engine() -> laurus::Result<Engine> {
+    let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+    Engine::new(storage, schema()).await
+}
+
+fn texts(suggestions: Vec<Suggestion>) -> Vec<String> {
+    suggestions.into_iter().map(|s| s.text).collect()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_suggest_by_weight() -> laurus::Result<()> {
+    let engine = engine().await?;
+    for (id, title, popularity) in [
+        ("1", "Rust Programming", 50),
+        ("2", "Ruby on Rails", 80),
+        ("3", "Python Cookbook", 100),
+        ("4", "rust in action", 20),
+    ] {
+        engine.put_document(id, doc(title, popularity)).await?;
+    }
+    engine.commit().await?;
+
+    let suggestions = engine.suggest("title", "ru", 10)?;
+    assert_eq!(
+        suggestions,
+        vec![
+            Suggestion {
+                text: "Ruby on Rails".to_string(),
+                weight: 80
+            },
+            Suggestion {
+                text: "Rust Programming".to_string(),
+                weight: 50
+            },
+            Suggestion {
+                text: "rust in action".to_string(),
+                weight: 20
+            },
+        ]
+    );
+    assert_eq!(
+        texts(engine.suggest("title", "RUST", 1)?),
+        vec!["Rust Programming"]
+    );
+    assert!(engine.suggest("title", "go", 10)?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_suggest_fuzzy_fills_remaining_slots() -> laurus::Result<()> {
+    let engine = engine().await?;
+    engine.put_document("1", doc("javascript", 10)).await?;
+    engine.put_document("2", doc("jvaa", 90)).await?;
+    engine.put_document("3", doc("java", 5)).await?;
+    engine.commit().await?;
+
+    // Exact prefix matches come first, even with lower weights.
+    assert_eq!(
+        texts(engine.suggest("title", "java", 10)?),
+        vec!["javascript", "java", "jvaa"]
+    );
+    // A typo still finds completions once the prefix is long enough.
+    assert_eq!(
+        texts(engine.suggest("title", "jvas", 10)?),
+        vec!["jvaa", "javascript"]
+    );
+    // Short prefixes are matched exactly.
+    assert_eq!(texts(engine.suggest("title", "jv", 10)?), vec!["jvaa"]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_suggest_across_segments_and_deletions() -> laurus::Result<()> {
+    let engine = engine().await?;
+    engine.put_document("1", doc("Tokyo", 30)).await?;
+    engine.put_document("2", doc("Toronto", 20)).await?;
+    engine.commit().await?;
+
+    engine.put_document("3", doc("Toulouse", 25)).await?;
+    engine.put_document("2", doc("Toronto", 40)).await?;
+    engine.delete_documents("1").await?;
+    engine.commit().await?;
+
+    let suggestions = engine.suggest("title", "to", 10)?;
+    assert_eq!(
+        suggestions,
+        vec![
+            Suggestion {
+                text: "Toronto".to_string(),
+                weight: 40
+            },
+            Suggestion {
+                text: "Toulouse".to_string(),
+                weight: 25
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_suggest_requires_completion_field() -> laurus::Result<()> {
+    let engine = engine().await?;
+    assert!(engine.suggest("body", "a", 10).is_err());
+    assert!(engine.suggest("missing", "a", 10).is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_suggest_skips_invisible_documents() -> laurus::Result<()> {
+    let schema = Schema::builder()
+        .add_field(
+            "title",
+            FieldOption::Completion(CompletionOption::default().weight_field("popularity")),
+        )
+        .add_field("popularity", FieldOption::Integer(IntegerOption::default()))
+        .add_field("acl", FieldOption::Text(TextOption::default()))
+        .add_field("tenant", FieldOption::Text(TextOption::default()))
+        .add_field(
+            "expires_at",
+            FieldOption::DateTime(DateTimeOption::default()),
+        )
+        .acl_field("acl")
+        .tenant_field("tenant")
+        .expiry_field("expires_at")
+        .build();
+    let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+    let engine = Engine::new(storage, schema).await?;
+
+    let acme = engine.tenant("acme")?;
+    let titled = |title: &str, acl: &str| {
+        Document::builder()
+            .add_text("title", title)
+            .add_integer("popularity", 10)
+            .add_text("acl", acl)
+            .build()
+    };
+    acme.put_document("1", titled("Roadmap", "Everyone"))
+        .await?;
+    acme.put_document("2", titled("Revenue", "Finance")).await?;
+    let expired = Document::builder()
+        .add_text("title", "Retired")
+        .add_integer("popularity", 10)
+        .add_text("acl", "Everyone")
+        .add_datetime(
+            "expires_at",
+            chrono::Utc::now() - chrono::Duration::hours(1),
+        )
+        .build();
+    acme.put_document("3", expired).await?;
+    engine
+        .tenant("globex")?
+        .put_document("1", titled("Rebrand", "Everyone"))
+        .await?;
+    engine.commit().await?;
+
+    let mut all = texts(engine.suggest("title", "r", 10)?);
+    all.sort();
+    assert_eq!(all, vec!["Rebrand", "Revenue", "Roadmap"]);
+
+    let mut tenant = texts(acme.suggest("title", "r", 10)?);
+    tenant.sort();
+    assert_eq!(tenant, vec!["Revenue", "Roadmap"]);
+
+    let everyone = Identity::new(["Everyone"]);
+    assert_eq!(
+        texts(acme.suggest_as("title", "r", 10, &everyone)?),
+        vec!["Roadmap"]
+    );
+    let mut visible = texts(engine.suggest_as("title", "r", 10, &everyone)?);
+    visible.sort();
+    assert_eq!(visible, vec!["Rebrand", "Roadmap"]);
+    Ok(())
+}
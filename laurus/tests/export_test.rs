@@ -0,0 +1,166 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::vector::{FlatOption, Vector};
+use laurus::{
+    DataValue, Document, EmbedInput, EmbedInputType, Embedder, Engine, FieldOption,
+    LexicalSearchQuery, Schema, SearchRequestBuilder, Storage,
+};
+
+#[derive(Debug)]
+struct LengthEmbedder;
+
+#[async_trait]
+impl Embedder for LengthEmbedder {
+    async fn embed(&self, input: &EmbedInput<'_>) -> laurus::Result<Vector> {
+        match input {
+            EmbedInput::Text(text) => Ok(Vector::new(vec![text.len() as f32; 4])),
+            _ => Err(laurus::LaurusError::invalid_argument("text only")),
+        }
+    }
+
+    fn supported_input_types(&self) -> Vec<EmbedInputType> {
+        vec![EmbedInputType::Text]
+    }
+
+    fn name(&self) -> &str {
+        "length"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn schema() -> Schema {
+    Schema::builder()
+        .add_field("title", FieldOption::Text(TextOption::default()))
+        .add_field("title_vec", FieldOption::Flat(FlatOption::new(4)))
+        .build()
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+fn doc(title: &str) -> Document {
+    Document::builder()
+        .add_text("title", title)
+        .add_text("title_vec", title)
+        .build()
+}
+
+async fn count(engine: &Engine, dsl: &str) -> laurus::Result<usize> {
+    let request = SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::from(dsl))
+        .limit(100)
+        .build();
+    Ok(engine.search(request).await?.len())
+}
+
+async fn populate() -> laurus::Result<Engine> {
+    let engine = Engine::builder(new_storage(), schema())
+        .embedder(Arc::new(LengthEmbedder))
+        .build()
+        .await?;
+    engine.put_document("a", doc("rust")).await?;
+    engine.put_document("b", doc("search")).await?;
+    engine.add_document("c", doc("engine one")).await?;
+    engine.add_document("c", doc("engine two")).await?;
+    engine.put_document("d", doc("deleted")).await?;
+    engine.commit().await?;
+    engine.delete_documents("d").await?;
+    // Replaced documents are exported once.
+    engine.put_document("a", doc("rust lang")).await?;
+    Ok(engine)
+}
+
+#[tokio::test]
+async fn test_export_import_round_trip() -> laurus::Result<()> {
+    let engine = populate().await?;
+
+    let mut output = Vec::new();
+    let mut reported = 0;
+    let export = engine.export(false).await?;
+    assert_eq!(export.total(), 4);
+    let exported = export.write_jsonl(&mut output, 2, |n| reported = n)?;
+    assert_eq!(exported, 4);
+    assert_eq!(reported, 4);
+    assert_eq!(String::from_utf8_lossy(&output).lines().count(), 4);
+
+    let imported_engine = Engine::builder(new_storage(), schema())
+        .embedder(Arc::new(LengthEmbedder))
+        .build()
+        .await?;
+    let mut reports = Vec::new();
+    let imported = imported_engine
+        .import(output.as_slice(), 3, |n| reports.push(n))
+        .await?;
+    assert_eq!(imported, 4);
+    // Progress is reported once per committed batch.
+    assert_eq!(reports, vec![3, 4]);
+
+    assert_eq!(count(&imported_engine, "title:lang").await?, 1);
+    assert_eq!(count(&imported_engine, "title:deleted").await?, 0);
+    assert_eq!(imported_engine.get_documents("c").await?.len(), 2);
+    let a = imported_engine.get_documents("a").await?;
+    assert_eq!(
+        a[0].get("title_vec"),
+        Some(&DataValue::Text("rust lang".into()))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_export_with_vectors() -> laurus::Result<()> {
+    let engine = populate().await?;
+
+    let mut export = engine.export(true).await?;
+    let mut documents = Vec::new();
+    while let Some(batch) = export.next_batch(100)? {
+        documents.extend(batch);
+    }
+    assert_eq!(export.exported(), 4);
+    let a = documents.iter().find(|d| d.id == "a").unwrap();
+    assert!(matches!(
+        a.document.get("title_vec"),
+        Some(DataValue::Vector(v)) if v.len() == 4
+    ));
+    assert!(a.document.get("_id").is_none());
+
+    // Vectors are imported as-is, so no embedder is needed.
+    let mut output = Vec::new();
+    for document in &documents {
+        serde_json::to_writer(&mut output, document).unwrap();
+        output.push(b'\n');
+    }
+    let imported_engine = Engine::new(new_storage(), schema()).await?;
+    assert_eq!(
+        imported_engine
+            .import(output.as_slice(), 100, |_| {})
+            .await?,
+        4
+    );
+    assert_eq!(count(&imported_engine, "title:engine").await?, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_import_rejects_invalid_line() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+    let input = "{\"id\":\"a\",\"document\":{\"fields\":{}}}\nnot json\n";
+    let err = engine
+        .import(input.as_bytes(), 1, |_| {})
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("line 2"));
+    // The batch before the invalid line was committed.
+    assert_eq!(engine.get_documents("a").await?.len(), 1);
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::MemoryStorage;
+use laurus::{DataValue, Document, Engine, FieldOption, Schema, SearchRequestBuilder};
+use laurus::{FederatedQuery, FederatedSearchOptions, FusionAlgorithm, federated_search};
+
+async fn engine(field: &str, docs: &[(&str, &str)]) -> Engine {
+    let schema = Schema::builder()
+        .add_field(field, FieldOption::Text(TextOption::default()))
+        .build();
+    let engine = Engine::new(Arc::new(MemoryStorage::new(Default::default())), schema)
+        .await
+        .unwrap();
+    for (id, text) in docs {
+        let doc = Document::builder()
+            .add_field(field, DataValue::Text(text.to_string()))
+            .build();
+        engine.put_document(id, doc).await.unwrap();
+    }
+    engine.commit().await.unwrap();
+    engine
+}
+
+async fn fixtures() -> (Engine, Engine) {
+    let docs = engine(
+        "body",
+        &[
+            ("d1", "rust search engine guide"),
+            ("d2", "rust rust rust"),
+            ("d3", "cooking recipes"),
+        ],
+    )
+    .await;
+    let tickets = engine(
+        "summary",
+        &[("t1", "rust build fails"), ("t2", "rust panic in search")],
+    )
+    .await;
+    (docs, tickets)
+}
+
+fn query(dsl: &str) -> laurus::SearchRequest {
+    SearchRequestBuilder::new().query_dsl(dsl).build()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_results_are_fused_and_tagged() {
+    let (docs, tickets) = fixtures().await;
+
+    let results = federated_search(
+        vec![
+            FederatedQuery::new("docs", &docs, query("body:rust")),
+            FederatedQuery::new("tickets", &tickets, query("summary:rust")),
+        ],
+        FederatedSearchOptions::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results.len(), 4);
+    let tagged: Vec<(&str, &str)> = results
+        .iter()
+        .map(|r| (r.index.as_str(), r.id.as_str()))
+        .collect();
+    // The best document of each index shares the top RRF score.
+    assert_eq!(tagged[0].0, "docs");
+    assert_eq!(tagged[1].0, "tickets");
+    assert!((results[0].score - results[1].score).abs() < 1e-6);
+    assert!(tagged.contains(&("docs", "d1")));
+    assert!(tagged.contains(&("tickets", "t2")));
+    assert!(results.iter().all(|r| r.document.is_some()));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_weights_and_pagination() {
+    let (docs, tickets) = fixtures().await;
+
+    let queries = || {
+        vec![
+            FederatedQuery::new("docs", &docs, query("body:rust")),
+            FederatedQuery::new("tickets", &tickets, query("summary:rust")).weight(2.0),
+        ]
+    };
+    let all = federated_search(queries(), FederatedSearchOptions::default())
+        .await
+        .unwrap();
+    let indexes: Vec<&str> = all.iter().map(|r| r.index.as_str()).collect();
+    assert_eq!(indexes, vec!["tickets", "tickets", "docs", "docs"]);
+
+    let page = federated_search(
+        queries(),
+        FederatedSearchOptions {
+            offset: 1,
+            limit: 2,
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    let expected: Vec<&str> = all[1..3].iter().map(|r| r.id.as_str()).collect();
+    let actual: Vec<&str> = page.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_weighted_sum_normalizes_each_index() {
+    let (docs, tickets) = fixtures().await;
+
+    let results = federated_search(
+        vec![
+            FederatedQuery::new("docs", &docs, query("body:rust")),
+            FederatedQuery::new("tickets", &tickets, query("summary:rust")).weight(0.5),
+        ],
+        FederatedSearchOptions {
+            fusion: FusionAlgorithm::WeightedSum {
+                lexical_weight: 1.0,
+                vector_weight: 1.0,
+            },
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(results[0].index, "docs");
+    assert!((results[0].score - 1.0).abs() < 1e-6);
+    let top_ticket = results.iter().find(|r| r.index == "tickets").unwrap();
+    assert!((top_ticket.score - 0.5).abs() < 1e-6);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_negative_weight_is_rejected() {
+    let (docs, _) = fixtures().await;
+
+    let err = federated_search(
+        vec![FederatedQuery::new("docs", &docs, query("body:rust")).weight(-1.0)],
+        FederatedSearchOptions::default(),
+    )
+    .await
+    .unwrap_err();
+    assert!(err.to_string().contains("non-negative"));
+}
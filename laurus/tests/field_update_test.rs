@@ -0,0 +1,226 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::vector::{FlatOption, HnswOption, Vector};
+use laurus::{
+    Document, EmbedInput, EmbedInputType, Embedder, Engine, FieldOption, LexicalSearchQuery,
+    Schema, SearchRequestBuilder, Storage,
+};
+
+#[derive(Debug)]
+struct LengthEmbedder;
+
+#[async_trait]
+impl Embedder for LengthEmbedder {
+    async fn embed(&self, input: &EmbedInput<'_>) -> laurus::Result<Vector> {
+        match input {
+            EmbedInput::Text(text) => Ok(Vector::new(vec![text.len() as f32; 128])),
+            _ => Err(laurus::LaurusError::invalid_argument("text only")),
+        }
+    }
+
+    fn supported_input_types(&self) -> Vec<EmbedInputType> {
+        vec![EmbedInputType::Text]
+    }
+
+    fn name(&self) -> &str {
+        "length"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+fn schema() -> Schema {
+    Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .build()
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+async fn search_count(engine: &Engine, dsl: &str) -> laurus::Result<usize> {
+    let request = SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::from(dsl))
+        .limit(100)
+        .build();
+    Ok(engine.search(request).await?.len())
+}
+
+async fn put(engine: &Engine, id: &str) -> laurus::Result<()> {
+    engine
+        .put_document(
+            id,
+            Document::builder().add_text("body", "Hello World").build(),
+        )
+        .await
+}
+
+fn keyword_field() -> FieldOption {
+    FieldOption::Text(TextOption::default().analyzer("keyword"))
+}
+
+#[tokio::test]
+async fn test_update_field_changes_analyzer() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+    for i in 0..5 {
+        put(&engine, &format!("doc{i}")).await?;
+    }
+    engine.commit().await?;
+
+    let mut update = engine
+        .update_field("body", keyword_field(), new_storage())
+        .await?;
+    assert_eq!(update.progress().total, 5);
+    while !update.copy_batch(&engine, 2).await?.completed {}
+
+    // The source engine still uses the old analyzer.
+    assert_eq!(search_count(&engine, "body:hello").await?, 5);
+
+    let updated = update.finish(&engine).await?;
+    assert_eq!(search_count(&updated, "body:hello").await?, 0);
+    assert_eq!(search_count(&updated, "body:\"Hello World\"").await?, 5);
+    assert_eq!(updated.stats()?.document_count, 5);
+    assert!(matches!(
+        &updated.schema().fields["body"],
+        FieldOption::Text(opt) if opt.analyzer.as_deref() == Some("keyword")
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_field_replays_concurrent_writes() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+    for i in 0..4 {
+        put(&engine, &format!("doc{i}")).await?;
+    }
+    engine.commit().await?;
+    // Uncommitted mutations are carried over as well.
+    engine.delete_documents("doc0").await?;
+
+    let mut update = engine
+        .update_field("body", keyword_field(), new_storage())
+        .await?;
+    update.copy_batch(&engine, 2).await?;
+
+    put(&engine, "doc1").await?;
+    engine.delete_documents("doc2").await?;
+    put(&engine, "doc4").await?;
+    assert!(update.catch_up(&engine).await? > 0);
+
+    engine.delete_documents("doc3").await?;
+    let updated = update.finish(&engine).await?;
+
+    assert_eq!(updated.stats()?.document_count, 2);
+    assert_eq!(updated.last_seq(), engine.last_seq());
+    for (id, live) in [
+        ("doc0", false),
+        ("doc1", true),
+        ("doc2", false),
+        ("doc3", false),
+        ("doc4", true),
+    ] {
+        assert_eq!(!updated.get_documents(id).await?.is_empty(), live, "{id}");
+    }
+
+    // New writes continue after the source engine's IDs.
+    put(&updated, "doc5").await?;
+    updated.commit().await?;
+    assert_eq!(search_count(&updated, "body:\"Hello World\"").await?, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_field_changes_vector_index_type() -> laurus::Result<()> {
+    let schema = Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .add_field("body_vec", FieldOption::Flat(FlatOption::new(128)))
+        .build();
+    let engine = Engine::builder(new_storage(), schema)
+        .embedder(Arc::new(LengthEmbedder))
+        .build()
+        .await?;
+    for i in 0..3 {
+        engine
+            .put_document(
+                &format!("doc{i}"),
+                Document::builder()
+                    .add_text("body", "Hello World")
+                    .add_text("body_vec", "Hello World")
+                    .build(),
+            )
+            .await?;
+    }
+    engine.commit().await?;
+
+    let update = engine
+        .update_field(
+            "body_vec",
+            FieldOption::Hnsw(HnswOption::default()),
+            new_storage(),
+        )
+        .await?;
+    let updated = update.finish(&engine).await?;
+
+    assert!(matches!(
+        updated.schema().fields["body_vec"],
+        FieldOption::Hnsw(_)
+    ));
+    assert_eq!(updated.stats()?.vector_fields["body_vec"].vector_count, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_field_rejects_invalid_requests() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+    put(&engine, "doc0").await?;
+    engine.commit().await?;
+
+    // Unknown field.
+    assert!(
+        engine
+            .update_field("title", keyword_field(), new_storage())
+            .await
+            .is_err()
+    );
+
+    // Storage already in use.
+    let storage = new_storage();
+    Engine::new(storage.clone(), schema())
+        .await?
+        .commit()
+        .await?;
+    assert!(
+        engine
+            .update_field("body", keyword_field(), storage)
+            .await
+            .is_err()
+    );
+
+    // Documents cannot be rebuilt when a field is not stored.
+    let unstored = Schema::builder()
+        .add_field(
+            "body",
+            FieldOption::Text(TextOption::default().stored(false)),
+        )
+        .build();
+    let engine = Engine::new(new_storage(), unstored).await?;
+    assert!(
+        engine
+            .update_field("body", keyword_field(), new_storage())
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    AnalyzerDefinition, Document, Engine, FieldOption, Schema, SearchRequestBuilder,
+    TokenFilterConfig, TokenizerConfig,
+};
+
+/// Index names with the built-in `phonetic` analyzer.
+fn builtin_schema() -> Schema {
+    Schema::builder()
+        .add_field(
+            "name",
+            FieldOption::Text(TextOption::default().analyzer("phonetic")),
+        )
+        .build()
+}
+
+/// Index names with a custom analyzer that replaces words by their codes.
+fn soundex_schema() -> Schema {
+    Schema::builder()
+        .add_analyzer(
+            "soundex",
+            AnalyzerDefinition {
+                char_filters: vec![],
+                tokenizer: TokenizerConfig::Whitespace,
+                token_filters: vec![TokenFilterConfig::Phonetic {
+                    encoder: "soundex".into(),
+                    inject: false,
+                }],
+            },
+        )
+        .add_field(
+            "name",
+            FieldOption::Text(TextOption::default().analyzer("soundex")),
+        )
+        .build()
+}
+
+async fn populate(schema: Schema) -> laurus::Result<Engine> {
+    let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+    let engine = Engine::new(storage, schema).await?;
+    for (id, name) in [
+        ("1", "Stephen Schmidt"),
+        ("2", "Steven Smith"),
+        ("3", "Robert Miller"),
+    ] {
+        engine
+            .put_document(id, Document::builder().add_text("name", name).build())
+            .await?;
+    }
+    engine.commit().await?;
+    Ok(engine)
+}
+
+async fn search_ids(engine: &Engine, query: &str) -> laurus::Result<Vec<String>> {
+    let results = engine
+        .search(
+            SearchRequestBuilder::new()
+                .query_dsl(query)
+                .limit(10)
+                .build(),
+        )
+        .await?;
+    Ok(results.into_iter().map(|r| r.id).collect())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_phonetic_analyzer_matches_similar_names() -> laurus::Result<()> {
+    let engine = populate(builtin_schema()).await?;
+
+    let mut ids = search_ids(&engine, "name:Steven").await?;
+    ids.sort();
+    assert_eq!(ids, vec!["1", "2"]);
+
+    let mut ids = search_ids(&engine, "name:Smith").await?;
+    ids.sort();
+    assert_eq!(ids, vec!["1", "2"]);
+
+    assert!(search_ids(&engine, "name:Johnson").await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_phonetic_analyzer_ranks_exact_spelling_first() -> laurus::Result<()> {
+    let engine = populate(builtin_schema()).await?;
+
+    let ids = search_ids(&engine, "name:Schmidt").await?;
+    assert_eq!(ids.first().map(String::as_str), Some("1"));
+    assert_eq!(ids.len(), 2);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_phonetic_filter_replacing_tokens() -> laurus::Result<()> {
+    let engine = populate(soundex_schema()).await?;
+
+    let mut ids = search_ids(&engine, "name:Stefan").await?;
+    ids.sort();
+    assert_eq!(ids, vec!["1", "2"]);
+    assert_eq!(search_ids(&engine, "name:Muller").await?, vec!["3"]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_phonetic_phrase_of_many_words() -> laurus::Result<()> {
+    let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+    let engine = Engine::new(storage, builtin_schema()).await?;
+    for (id, name) in [
+        ("1", "John Paul Smith Jones Jackson"),
+        ("2", "Jon Paul Smyth Jones Jakson"),
+        ("3", "Jackson Jones Smith Paul John"),
+    ] {
+        engine
+            .put_document(id, Document::builder().add_text("name", name).build())
+            .await?;
+    }
+    engine.commit().await?;
+
+    // Each word has several phonetic alternatives, far more combinations
+    // than a query may expand to.
+    let mut ids = search_ids(&engine, r#"name:"john paul smith jones jackson""#).await?;
+    ids.sort();
+    assert_eq!(ids, vec!["1", "2"]);
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    Document, Engine, FieldOption, LexicalSearchQuery, Schema, SearchRequestBuilder,
+    SnapshotReceiver, Storage,
+};
+
+fn schema() -> Schema {
+    Schema::builder()
+        .add_field("title", FieldOption::Text(TextOption::default()))
+        .build()
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+fn doc(title: &str) -> Document {
+    Document::builder().add_text("title", title).build()
+}
+
+async fn count(engine: &Engine, dsl: &str) -> laurus::Result<usize> {
+    let request = SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::from(dsl))
+        .limit(100)
+        .build();
+    Ok(engine.search(request).await?.len())
+}
+
+/// Feed every retained leader change after `since_seq` into the follower.
+async fn replicate(leader: &Engine, follower: &Engine, since_seq: u64) -> laurus::Result<()> {
+    let mut changes = leader.changes(since_seq)?;
+    while changes.last_seq() < leader.last_seq() {
+        let event = changes.next().await?.unwrap();
+        follower.apply_change(event).await?;
+    }
+    follower.commit().await
+}
+
+#[tokio::test]
+async fn test_follower_from_snapshot_and_changes() -> laurus::Result<()> {
+    let leader = Engine::new(new_storage(), schema()).await?;
+    leader.put_document("a", doc("rust")).await?;
+    leader.put_document("b", doc("rust")).await?;
+
+    let snapshot = leader.snapshot(new_storage()).await?;
+    assert_eq!(snapshot.seq(), 2);
+    assert!(snapshot.files().iter().all(|f| f.name != "engine.wal"));
+
+    // Stream the files in small chunks, as a follower receives them.
+    let follower_storage = new_storage();
+    let mut receiver = SnapshotReceiver::new(follower_storage.as_ref())?;
+    for file in snapshot.files() {
+        let mut data = Vec::new();
+        snapshot.open_file(&file.name)?.read_to_end(&mut data)?;
+        let mut offset = 0;
+        for chunk in data.chunks(16) {
+            receiver.write_chunk(file, offset, chunk)?;
+            offset += chunk.len() as u64;
+        }
+        if data.is_empty() {
+            receiver.write_chunk(file, 0, &[])?;
+        }
+    }
+    receiver.finish()?;
+    let follower = Engine::new(follower_storage, snapshot.schema().clone()).await?;
+    assert_eq!(follower.last_seq(), 2);
+    assert_eq!(count(&follower, "title:rust").await?, 2);
+
+    leader.put_document("c", doc("rust")).await?;
+    leader.delete_documents("a").await?;
+    replicate(&leader, &follower, follower.last_seq()).await?;
+
+    assert_eq!(follower.last_seq(), leader.last_seq());
+    assert_eq!(count(&follower, "title:rust").await?, 2);
+    assert!(follower.get_documents("a").await?.is_empty());
+    assert_eq!(follower.get_documents("c").await?.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_apply_change_is_idempotent() -> laurus::Result<()> {
+    let leader = Engine::new(new_storage(), schema()).await?;
+    let follower = Engine::new(new_storage(), schema()).await?;
+
+    leader.put_document("a", doc("rust")).await?;
+    let event = leader.changes(0)?.next().await?.unwrap();
+    assert!(follower.apply_change(event.clone()).await?);
+    assert!(!follower.apply_change(event).await?);
+    follower.commit().await?;
+
+    assert_eq!(follower.stats()?.document_count, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_follower_recovers_replicated_wal() -> laurus::Result<()> {
+    let leader = Engine::new(new_storage(), schema()).await?;
+    let follower_storage = new_storage();
+    {
+        let follower = Engine::new(follower_storage.clone(), schema()).await?;
+        leader.put_document("a", doc("rust")).await?;
+        let mut changes = leader.changes(0)?;
+        follower
+            .apply_change(changes.next().await?.unwrap())
+            .await?;
+        // No commit: the replicated change only lives in the follower WAL.
+    }
+
+    let follower = Engine::new(follower_storage, schema()).await?;
+    assert_eq!(follower.last_seq(), leader.last_seq());
+    follower.commit().await?;
+    assert_eq!(count(&follower, "title:rust").await?, 1);
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    AnalyzerDefinition, Document, Engine, FieldOption, ResourceContent, Schema,
+    SearchRequestBuilder, Storage, TokenFilterConfig, TokenizerConfig,
+};
+
+fn resource_schema() -> Schema {
+    Schema::builder()
+        .add_analyzer(
+            "managed",
+            AnalyzerDefinition {
+                char_filters: vec![],
+                tokenizer: TokenizerConfig::Whitespace,
+                token_filters: vec![
+                    TokenFilterConfig::Lowercase,
+                    TokenFilterConfig::Stop {
+                        words: None,
+                        resource: Some("stop".into()),
+                        language: None,
+                    },
+                    TokenFilterConfig::SynonymGraph {
+                        synonyms: vec![],
+                        path: None,
+                        format: None,
+                        keep_original: true,
+                        boost: None,
+                        resource: Some("synonyms".into()),
+                    },
+                ],
+            },
+        )
+        .add_field(
+            "body",
+            FieldOption::Text(TextOption::default().analyzer("managed")),
+        )
+        .build()
+}
+
+fn synonyms(rules: &[&str]) -> ResourceContent {
+    ResourceContent::Synonyms {
+        rules: rules.iter().map(|rule| rule.to_string()).collect(),
+    }
+}
+
+fn stop_words(words: &[&str]) -> ResourceContent {
+    ResourceContent::StopWords {
+        words: words.iter().map(|word| word.to_string()).collect(),
+    }
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+async fn search_ids(engine: &Engine, query: &str) -> laurus::Result<Vec<String>> {
+    let mut ids: Vec<String> = engine
+        .search(
+            SearchRequestBuilder::new()
+                .query_dsl(query)
+                .limit(10)
+                .build(),
+        )
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    Ok(ids)
+}
+
+/// Create the resource sets before the engine that refers to them.
+async fn seeded_engine(storage: Arc<dyn Storage>) -> laurus::Result<Engine> {
+    let seed = Engine::new(storage.clone(), Schema::builder().build()).await?;
+    seed.put_resource_set("synonyms", synonyms(&[]))?;
+    seed.put_resource_set("stop", stop_words(&["the"]))?;
+    drop(seed);
+    Engine::new(storage, resource_schema()).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_missing_resource_set_is_rejected() {
+    assert!(Engine::new(new_storage(), resource_schema()).await.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_updated_synonyms_apply_without_reopening() -> laurus::Result<()> {
+    let engine = seeded_engine(new_storage()).await?;
+    engine
+        .put_document(
+            "car",
+            Document::builder()
+                .add_text("body", "The car is red")
+                .build(),
+        )
+        .await?;
+    engine.commit().await?;
+
+    assert!(search_ids(&engine, "body:automobile").await?.is_empty());
+
+    let set = engine.put_resource_set("synonyms", synonyms(&["car, automobile"]))?;
+    assert_eq!(set.version, 2);
+    assert_eq!(search_ids(&engine, "body:automobile").await?, vec!["car"]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_invalid_update_keeps_previous_set() -> laurus::Result<()> {
+    let engine = seeded_engine(new_storage()).await?;
+
+    // Stop words cannot back the synonym filter.
+    assert!(
+        engine
+            .put_resource_set("synonyms", stop_words(&["car"]))
+            .is_err()
+    );
+    assert!(
+        engine
+            .put_resource_set("synonyms", synonyms(&["a => b => c"]))
+            .is_err()
+    );
+    assert_eq!(engine.resource_set("synonyms").unwrap().version, 1);
+
+    // Sets used by the schema cannot be deleted.
+    assert!(engine.delete_resource_set("stop").is_err());
+    engine.put_resource_set("unused", stop_words(&[]))?;
+    assert_eq!(engine.delete_resource_set("unused")?.name, "unused");
+    assert_eq!(engine.resource_sets().len(), 2);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_resource_sets_persist_and_reload() -> laurus::Result<()> {
+    let storage = new_storage();
+    let engine = seeded_engine(storage.clone()).await?;
+    engine
+        .put_document(
+            "doc",
+            Document::builder().add_text("body", "the fox").build(),
+        )
+        .await?;
+    engine.commit().await?;
+    assert_eq!(search_ids(&engine, "body:fox").await?, vec!["doc"]);
+
+    // Another engine on the same storage changes the stop words.
+    let other = Engine::new(storage.clone(), resource_schema()).await?;
+    assert_eq!(other.resource_set("stop").unwrap().version, 1);
+    other.put_resource_set("stop", stop_words(&["fox"]))?;
+    drop(other);
+
+    assert_eq!(engine.resource_set("stop").unwrap().version, 1);
+    engine.reload_resource_sets()?;
+    assert_eq!(engine.resource_set("stop").unwrap().version, 2);
+    // "fox" is now a stop word, so the query has no terms left.
+    assert!(search_ids(&engine, "body:fox").await.is_err());
+    Ok(())
+}
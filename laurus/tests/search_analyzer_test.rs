@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    AnalyzerDefinition, Document, Engine, FieldOption, Schema, SearchRequestBuilder,
+    TokenFilterConfig, TokenizerConfig,
+};
+
+fn definition(token_filters: Vec<TokenFilterConfig>) -> AnalyzerDefinition {
+    AnalyzerDefinition {
+        char_filters: vec![],
+        tokenizer: TokenizerConfig::Whitespace,
+        token_filters,
+    }
+}
+
+/// Index with a plain analyzer and expand synonyms only at query time.
+fn query_time_synonyms_schema() -> Schema {
+    Schema::builder()
+        .add_analyzer("plain", definition(vec![TokenFilterConfig::Lowercase]))
+        .add_analyzer(
+            "synonyms",
+            definition(vec![
+                TokenFilterConfig::Lowercase,
+                TokenFilterConfig::SynonymGraph {
+                    synonyms: vec!["ml, machine learning".into()],
+                    path: None,
+                    format: None,
+                    keep_original: true,
+                    boost: None,
+                    resource: None,
+                },
+            ]),
+        )
+        .add_field(
+            "body",
+            FieldOption::Text(
+                TextOption::default()
+                    .analyzer("plain")
+                    .search_analyzer("synonyms")
+                    .search_quote_analyzer("plain"),
+            ),
+        )
+        .build()
+}
+
+async fn populate(schema: Schema) -> laurus::Result<Engine> {
+    let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+    let engine = Engine::new(storage, schema).await?;
+    for (id, body) in [
+        ("ml", "ML tutorial for beginners"),
+        ("long", "Machine Learning tutorial in depth"),
+        ("other", "Cooking tutorial"),
+    ] {
+        engine
+            .put_document(id, Document::builder().add_text("body", body).build())
+            .await?;
+    }
+    engine.commit().await?;
+    Ok(engine)
+}
+
+async fn search_ids(engine: &Engine, query: &str) -> laurus::Result<Vec<String>> {
+    let mut ids: Vec<String> = engine
+        .search(
+            SearchRequestBuilder::new()
+                .query_dsl(query)
+                .limit(10)
+                .build(),
+        )
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    Ok(ids)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_search_analyzer_expands_query_terms() -> laurus::Result<()> {
+    let engine = populate(query_time_synonyms_schema()).await?;
+
+    // The index holds no synonyms, but query terms are expanded.
+    assert_eq!(search_ids(&engine, "body:ml").await?, vec!["long", "ml"]);
+    assert_eq!(search_ids(&engine, "body:tutorial").await?.len(), 3);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_search_quote_analyzer_keeps_phrases_exact() -> laurus::Result<()> {
+    let engine = populate(query_time_synonyms_schema()).await?;
+
+    assert_eq!(
+        search_ids(&engine, r#"body:"ml tutorial""#).await?,
+        vec!["ml"]
+    );
+    assert_eq!(
+        search_ids(&engine, r#"body:"machine learning""#).await?,
+        vec!["long"]
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_unknown_search_analyzer_is_rejected() {
+    let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+    let schema = Schema::builder()
+        .add_field(
+            "body",
+            FieldOption::Text(TextOption::default().search_analyzer("missing")),
+        )
+        .build();
+    assert!(Engine::new(storage, schema).await.is_err());
+}
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use laurus::lexical::TermQuery;
+use laurus::lexical::TextOption;
+use laurus::storage::memory::MemoryStorage;
+use laurus::vector::FlatOption;
+use laurus::vector::QueryVector;
+use laurus::vector::Vector;
+use laurus::{DataValue, Document, Engine, ShardedEngine};
+use laurus::{FieldOption, Schema};
+use laurus::{LexicalSearchQuery, SearchRequest, SearchRequestBuilder, VectorSearchQuery};
+
+const DOC_COUNT: usize = 40;
+
+fn schema() -> Schema {
+    Schema::builder()
+        .add_field("title", FieldOption::Text(TextOption::default()))
+        .add_field(
+            "embedding",
+            FieldOption::Flat(FlatOption {
+                dimension: 2,
+                ..Default::default()
+            }),
+        )
+        .build()
+}
+
+/// Even documents mention "rust", odd ones "go"; every title has a
+/// different length so BM25 scores are unique.
+fn doc(i: usize) -> Document {
+    let word = if i.is_multiple_of(2) { "rust" } else { "go" };
+    let title = format!("{word}{}", " filler".repeat(i));
+    let angle = i as f32 * 0.07;
+    Document::builder()
+        .add_field("title", DataValue::Text(title))
+        .add_vector("embedding", vec![angle.cos(), angle.sin()])
+        .build()
+}
+
+async fn populated() -> (Engine, ShardedEngine) {
+    let single = Engine::new(Arc::new(MemoryStorage::new(Default::default())), schema())
+        .await
+        .unwrap();
+    let sharded = ShardedEngine::new(
+        Arc::new(MemoryStorage::new(Default::default())),
+        schema(),
+        4,
+    )
+    .await
+    .unwrap();
+    for i in 0..DOC_COUNT {
+        single
+            .put_document(&format!("doc{i}"), doc(i))
+            .await
+            .unwrap();
+        sharded
+            .put_document(&format!("doc{i}"), doc(i))
+            .await
+            .unwrap();
+    }
+    single.commit().await.unwrap();
+    sharded.commit().await.unwrap();
+    (single, sharded)
+}
+
+fn lexical_request(term: &str, offset: usize, limit: usize) -> SearchRequest {
+    SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::Obj(Box::new(TermQuery::new(
+            "title", term,
+        ))))
+        .offset(offset)
+        .limit(limit)
+        .build()
+}
+
+fn query_vector() -> VectorSearchQuery {
+    VectorSearchQuery::Vectors(vec![QueryVector {
+        vector: Vector::new(vec![0.8, 0.6]),
+        weight: 1.0,
+        fields: None,
+    }])
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_documents_are_routed_to_one_shard() {
+    let (_, sharded) = populated().await;
+
+    assert_eq!(sharded.stats().unwrap().document_count, DOC_COUNT as u64);
+    let per_shard: Vec<u64> = sharded
+        .shards()
+        .iter()
+        .map(|shard| shard.stats().unwrap().document_count)
+        .collect();
+    assert!(per_shard.iter().all(|&count| count > 0), "{per_shard:?}");
+
+    let owner = sharded.shard_for("doc7");
+    for (shard_id, shard) in sharded.shards().iter().enumerate() {
+        let docs = shard.get_documents("doc7").await.unwrap();
+        assert_eq!(docs.len(), usize::from(shard_id == owner));
+    }
+    assert_eq!(sharded.get_documents("doc7").await.unwrap().len(), 1);
+
+    sharded.delete_documents("doc7").await.unwrap();
+    sharded.commit().await.unwrap();
+    assert!(sharded.get_documents("doc7").await.unwrap().is_empty());
+    assert_eq!(
+        sharded.stats().unwrap().document_count,
+        DOC_COUNT as u64 - 1
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_lexical_scores_use_global_statistics() {
+    let (single, sharded) = populated().await;
+
+    let expected = single
+        .search(lexical_request("rust", 0, 100))
+        .await
+        .unwrap();
+    let actual = sharded
+        .search(lexical_request("rust", 0, 100))
+        .await
+        .unwrap();
+
+    assert_eq!(actual.len(), DOC_COUNT / 2);
+    let expected: Vec<(String, f32)> = expected.into_iter().map(|r| (r.id, r.score)).collect();
+    let actual: Vec<(String, f32)> = actual.into_iter().map(|r| (r.id, r.score)).collect();
+    for ((expected_id, expected_score), (actual_id, actual_score)) in expected.iter().zip(&actual) {
+        assert_eq!(expected_id, actual_id);
+        assert!(
+            (expected_score - actual_score).abs() < 1e-4,
+            "{expected_id}: {expected_score} != {actual_score}"
+        );
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_pagination_spans_shards() {
+    let (single, sharded) = populated().await;
+
+    let expected: Vec<String> = single
+        .search(lexical_request("rust", 5, 5))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    let actual: Vec<String> = sharded
+        .search(lexical_request("rust", 5, 5))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+
+    assert_eq!(actual.len(), 5);
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_vector_and_hybrid_results_are_merged() {
+    let (single, sharded) = populated().await;
+
+    let vector_request = || {
+        SearchRequestBuilder::new()
+            .vector_query(query_vector())
+            .limit(5)
+            .build()
+    };
+    let expected: Vec<String> = single
+        .search(vector_request())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    let actual: Vec<String> = sharded
+        .search(vector_request())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    assert_eq!(actual.len(), 5);
+    assert_eq!(actual, expected);
+
+    let hybrid_request = || {
+        SearchRequestBuilder::new()
+            .lexical_query(LexicalSearchQuery::Obj(Box::new(TermQuery::new(
+                "title", "rust",
+            ))))
+            .vector_query(query_vector())
+            .limit(10)
+            .build()
+    };
+    let expected: HashMap<String, f32> = single
+        .search(hybrid_request())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.id, r.score))
+        .collect();
+    let actual: HashMap<String, f32> = sharded
+        .search(hybrid_request())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.id, r.score))
+        .collect();
+    assert_eq!(actual.len(), 10);
+    for (id, score) in &actual {
+        let expected_score = expected.get(id).unwrap_or_else(|| panic!("{id} missing"));
+        assert!((expected_score - score).abs() < 1e-6, "{id}");
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_reopen_requires_same_shard_count() {
+    let storage = Arc::new(MemoryStorage::new(Default::default()));
+    let engine = ShardedEngine::new(storage.clone(), schema(), 3)
+        .await
+        .unwrap();
+    engine.put_document("doc1", doc(0)).await.unwrap();
+    engine.commit().await.unwrap();
+    drop(engine);
+
+    let err = ShardedEngine::new(storage.clone(), schema(), 2)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("created with 3 shards"));
+
+    let reopened = ShardedEngine::new(storage, schema(), 3).await.unwrap();
+    assert_eq!(reopened.get_documents("doc1").await.unwrap().len(), 1);
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    AnalyzerDefinition, Document, Engine, FieldOption, Schema, SearchRequestBuilder, Storage,
+    TokenFilterConfig, TokenizerConfig,
+};
+
+fn synonym_schema(filter: TokenFilterConfig) -> Schema {
+    Schema::builder()
+        .add_analyzer(
+            "synonyms",
+            AnalyzerDefinition {
+                char_filters: vec![],
+                tokenizer: TokenizerConfig::Whitespace,
+                token_filters: vec![TokenFilterConfig::Lowercase, filter],
+            },
+        )
+        .add_field(
+            "body",
+            FieldOption::Text(TextOption::default().analyzer("synonyms")),
+        )
+        .build()
+}
+
+fn inline_rules(rules: &[&str]) -> TokenFilterConfig {
+    TokenFilterConfig::SynonymGraph {
+        synonyms: rules.iter().map(|rule| rule.to_string()).collect(),
+        path: None,
+        format: None,
+        keep_original: true,
+        boost: None,
+        resource: None,
+    }
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+async fn populate(schema: Schema) -> laurus::Result<Engine> {
+    let engine = Engine::new(new_storage(), schema).await?;
+    for (id, body) in [
+        ("ml", "ML tutorial for beginners"),
+        ("long", "Machine Learning tutorial in depth"),
+        ("nyc", "Pizza in New York"),
+        ("other", "Cooking tutorial"),
+    ] {
+        engine
+            .put_document(id, Document::builder().add_text("body", body).build())
+            .await?;
+    }
+    engine.commit().await?;
+    Ok(engine)
+}
+
+async fn search_ids(engine: &Engine, query: &str) -> laurus::Result<Vec<String>> {
+    let mut ids: Vec<String> = engine
+        .search(
+            SearchRequestBuilder::new()
+                .query_dsl(query)
+                .limit(10)
+                .build(),
+        )
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    Ok(ids)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_equivalent_synonyms_match_both_ways() -> laurus::Result<()> {
+    let engine = populate(synonym_schema(inline_rules(&["ml, machine learning"]))).await?;
+
+    assert_eq!(search_ids(&engine, "body:ml").await?, vec!["long", "ml"]);
+    assert_eq!(
+        search_ids(&engine, r#"body:"machine learning""#).await?,
+        vec!["long", "ml"]
+    );
+    // Phrases match across a multi-word synonym in either direction.
+    assert_eq!(
+        search_ids(&engine, r#"body:"ml tutorial""#).await?,
+        vec!["long", "ml"]
+    );
+    assert_eq!(
+        search_ids(&engine, r#"body:"machine learning tutorial""#).await?,
+        vec!["long", "ml"]
+    );
+    // The original positions are kept, so unrelated phrases do not match.
+    assert!(
+        search_ids(&engine, r#"body:"learning for""#)
+            .await?
+            .is_empty()
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_one_way_rule_replaces_original() -> laurus::Result<()> {
+    let engine = populate(synonym_schema(inline_rules(&["nyc => new york"]))).await?;
+
+    assert_eq!(search_ids(&engine, "body:nyc").await?, vec!["nyc"]);
+    assert_eq!(
+        search_ids(&engine, r#"body:"new york""#).await?,
+        vec!["nyc"]
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_synonym_file() -> laurus::Result<()> {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("synonyms.txt");
+    std::fs::write(&path, "# Solr rules\nml, machine learning\n").unwrap();
+
+    let engine = populate(synonym_schema(TokenFilterConfig::SynonymGraph {
+        synonyms: vec![],
+        path: Some(path.to_string_lossy().into_owned()),
+        format: None,
+        keep_original: true,
+        boost: None,
+        resource: None,
+    }))
+    .await?;
+    assert_eq!(search_ids(&engine, "body:ml").await?, vec!["long", "ml"]);
+
+    let invalid = Engine::new(
+        new_storage(),
+        synonym_schema(TokenFilterConfig::SynonymGraph {
+            synonyms: vec![],
+            path: Some(path.to_string_lossy().into_owned()),
+            format: Some("xml".into()),
+            keep_original: true,
+            boost: None,
+            resource: None,
+        }),
+    )
+    .await;
+    assert!(invalid.is_err());
+    Ok(())
+}
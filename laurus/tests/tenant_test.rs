@@ -0,0 +1,278 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::vector::{FlatOption, QueryVector, Vector};
+use laurus::{
+    ChangeKind, DataValue, Document, Engine, FieldOption, Identity, LaurusError, Schema,
+    SearchRequestBuilder, Storage, TenantQuota, VectorSearchQuery,
+};
+
+fn tenant_schema() -> Schema {
+    Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .add_field("tenant", FieldOption::Text(TextOption::default()))
+        .add_field(
+            "embedding",
+            FieldOption::Flat(FlatOption {
+                dimension: 2,
+                ..Default::default()
+            }),
+        )
+        .tenant_field("tenant")
+        .build()
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+fn doc(body: &str, vector: [f32; 2]) -> Document {
+    Document::builder()
+        .add_text("body", body)
+        .add_vector("embedding", vector.to_vec())
+        .build()
+}
+
+async fn populate(engine: &Engine) -> laurus::Result<()> {
+    let acme = engine.tenant("acme")?;
+    acme.put_document("doc1", doc("rust guide", [1.0, 0.0]))
+        .await?;
+    acme.put_document("doc2", doc("rust internals", [0.9, 0.1]))
+        .await?;
+    let globex = engine.tenant("globex")?;
+    globex
+        .put_document("doc1", doc("rust secrets", [0.95, 0.05]))
+        .await?;
+    engine.commit().await?;
+    Ok(())
+}
+
+async fn search_ids(
+    tenant: &laurus::Tenant<'_>,
+    builder: SearchRequestBuilder,
+) -> laurus::Result<Vec<String>> {
+    let mut ids: Vec<String> = tenant
+        .search(builder.limit(100).build())
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    Ok(ids)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tenants_are_isolated() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), tenant_schema()).await?;
+    populate(&engine).await?;
+    let acme = engine.tenant("acme")?;
+    let globex = engine.tenant("globex")?;
+
+    // Lexical search, including a DSL query naming another tenant.
+    let query = || SearchRequestBuilder::new().query_dsl("body:rust OR tenant:globex");
+    assert_eq!(search_ids(&acme, query()).await?, vec!["doc1", "doc2"]);
+    assert_eq!(search_ids(&globex, query()).await?, vec!["doc1"]);
+
+    // Vector search.
+    let vector =
+        SearchRequestBuilder::new().vector_query(VectorSearchQuery::Vectors(vec![QueryVector {
+            vector: Vector::new(vec![0.95, 0.05]),
+            weight: 1.0,
+            fields: None,
+        }]));
+    assert_eq!(search_ids(&acme, vector).await?, vec!["doc1", "doc2"]);
+
+    // Lookups see only the tenant's own document under a shared ID, and
+    // report it under the tenant-local ID.
+    let docs = globex.get_documents("doc1").await?;
+    assert_eq!(docs.len(), 1);
+    assert_eq!(
+        docs[0].get("body"),
+        Some(&DataValue::Text("rust secrets".into()))
+    );
+    assert_eq!(docs[0].get("_id"), Some(&DataValue::Text("doc1".into())));
+    assert!(globex.get_documents("doc2").await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_delete_all_removes_only_one_tenant() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), tenant_schema()).await?;
+    populate(&engine).await?;
+
+    assert_eq!(engine.tenant("acme")?.delete_all().await?, 2);
+    engine.commit().await?;
+
+    let acme = engine.tenant("acme")?;
+    assert!(acme.get_documents("doc1").await?.is_empty());
+    assert_eq!(
+        engine.tenant("globex")?.get_documents("doc1").await?.len(),
+        1
+    );
+    let stats = engine.stats()?;
+    assert_eq!(stats.document_count, 1);
+    assert_eq!(stats.tenants.keys().collect::<Vec<_>>(), vec!["globex"]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_changes_are_scoped_to_tenant() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), tenant_schema()).await?;
+    populate(&engine).await?;
+    let globex = engine.tenant("globex")?;
+    globex.delete_documents("doc1").await?;
+
+    let mut changes = globex.changes_as(0, &Identity::default())?;
+    let event = changes.next().await?.unwrap();
+    assert_eq!(event.external_id, "doc1");
+    let ChangeKind::Upsert { document } = &event.kind else {
+        panic!("Expected upsert");
+    };
+    assert_eq!(document.get("_id"), Some(&DataValue::Text("doc1".into())));
+    assert_eq!(
+        document.get("body"),
+        Some(&DataValue::Text("rust secrets".into()))
+    );
+    let event = changes.next().await?.unwrap();
+    assert_eq!(event.external_id, "doc1");
+    assert!(matches!(event.kind, ChangeKind::Delete));
+
+    // Another tenant sees none of these mutations.
+    let mut changes = engine
+        .tenant("initech")?
+        .changes_as(0, &Identity::default())?;
+    let next = tokio::time::timeout(Duration::from_millis(200), changes.next()).await;
+    assert!(next.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_stats_report_per_tenant_usage() -> laurus::Result<()> {
+    let storage = new_storage();
+    let engine = Engine::new(storage.clone(), tenant_schema()).await?;
+    populate(&engine).await?;
+
+    let stats = engine.stats()?;
+    assert_eq!(stats.tenants["acme"].document_count, 2);
+    assert_eq!(stats.tenants["globex"].document_count, 1);
+    assert!(stats.tenants["acme"].storage_bytes > stats.tenants["globex"].storage_bytes);
+
+    // Replacing a document does not change the count.
+    let acme = engine.tenant("acme")?;
+    acme.put_document("doc1", doc("rust guide v2", [1.0, 0.0]))
+        .await?;
+    assert_eq!(acme.stats().document_count, 2);
+    engine.commit().await?;
+    let before = engine.stats()?.tenants;
+    drop(acme);
+    drop(engine);
+
+    // Usage survives a reopen.
+    let engine = Engine::new(storage, tenant_schema()).await?;
+    assert_eq!(engine.stats()?.tenants, before);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_document_quota() -> laurus::Result<()> {
+    let engine = Engine::builder(new_storage(), tenant_schema())
+        .tenant_quota(TenantQuota {
+            max_documents: Some(2),
+            max_storage_bytes: None,
+        })
+        .tenant_quota_for("globex", TenantQuota::default())
+        .build()
+        .await?;
+    populate(&engine).await?;
+
+    let acme = engine.tenant("acme")?;
+    let err = acme
+        .put_document("doc3", doc("rust more", [0.0, 1.0]))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, LaurusError::ResourceExhausted(_)));
+    assert!(err.to_string().contains("quota of 2 documents"), "{err}");
+
+    // Replacing an existing document stays within the quota.
+    acme.put_document("doc2", doc("rust internals v2", [0.9, 0.1]))
+        .await?;
+    // Other tenants have their own quota.
+    let globex = engine.tenant("globex")?;
+    for i in 0..3 {
+        globex
+            .put_document(&format!("extra{i}"), doc("rust", [0.0, 1.0]))
+            .await?;
+    }
+    assert_eq!(globex.stats().document_count, 4);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_storage_quota() -> laurus::Result<()> {
+    let engine = Engine::builder(new_storage(), tenant_schema())
+        .tenant_quota_for(
+            "acme",
+            TenantQuota {
+                max_documents: None,
+                max_storage_bytes: Some(100),
+            },
+        )
+        .build()
+        .await?;
+    let acme = engine.tenant("acme")?;
+    acme.put_document("small", doc("tiny", [0.0, 1.0])).await?;
+
+    let err = acme
+        .put_document("large", doc(&"x".repeat(200), [0.0, 1.0]))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, LaurusError::ResourceExhausted(_)));
+    assert!(
+        err.to_string().contains("storage quota of 100 bytes"),
+        "{err}"
+    );
+    assert_eq!(acme.stats().document_count, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_tenant_key_is_required() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), tenant_schema()).await?;
+    assert!(
+        engine
+            .put_document("doc1", doc("no tenant", [1.0, 0.0]))
+            .await
+            .is_err()
+    );
+    assert!(engine.tenant("").is_err());
+    assert!(engine.tenant("a/b").is_err());
+
+    let plain = Engine::new(
+        new_storage(),
+        Schema::builder()
+            .add_field("body", FieldOption::Text(TextOption::default()))
+            .build(),
+    )
+    .await?;
+    assert!(plain.tenant("acme").is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_tenant_field_rejected() -> laurus::Result<()> {
+    let schema = Schema::builder()
+        .add_field(
+            "tenant",
+            FieldOption::Text(TextOption::default().analyzer("standard")),
+        )
+        .tenant_field("tenant")
+        .build();
+    assert!(Engine::new(new_storage(), schema).await.is_err());
+
+    let engine = Engine::new(new_storage(), tenant_schema()).await?;
+    let err = engine.delete_field("tenant").await.unwrap_err();
+    assert!(err.to_string().contains("tenant field"));
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    Document, Engine, FieldOption, LexicalSearchQuery, Schema, SearchRequestBuilder, Storage,
+};
+
+fn schema() -> Schema {
+    Schema::builder()
+        .add_field("title", FieldOption::Text(TextOption::default()))
+        .build()
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+fn doc(title: &str) -> Document {
+    Document::builder().add_text("title", title).build()
+}
+
+async fn count(engine: &Engine, dsl: &str) -> laurus::Result<usize> {
+    let request = SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::from(dsl))
+        .limit(100)
+        .build();
+    Ok(engine.search(request).await?.len())
+}
+
+#[tokio::test]
+async fn test_transaction_commit() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+    engine.put_document("old", doc("rust")).await?;
+    engine.commit().await?;
+
+    let mut txn = engine.begin();
+    txn.delete_documents("old")
+        .put_document("a", doc("rust"))
+        .add_document("b", doc("rust"))
+        .add_document("b", doc("rust"));
+    assert_eq!(txn.len(), 4);
+    txn.commit().await?;
+    engine.commit().await?;
+
+    assert_eq!(count(&engine, "title:rust").await?, 3);
+    assert!(engine.get_documents("old").await?.is_empty());
+    assert_eq!(engine.get_documents("b").await?.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_rollback() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+
+    let mut txn = engine.begin();
+    txn.put_document("a", doc("rust"));
+    txn.rollback();
+    engine.commit().await?;
+
+    assert_eq!(count(&engine, "title:rust").await?, 0);
+    assert!(engine.get_documents("a").await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_later_operations_win() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), schema()).await?;
+
+    let mut txn = engine.begin();
+    txn.add_document("a", doc("first"))
+        .put_document("a", doc("second"))
+        .put_document("b", doc("second"))
+        .delete_documents("b");
+    txn.commit().await?;
+    engine.commit().await?;
+
+    let docs = engine.get_documents("a").await?;
+    assert_eq!(docs.len(), 1);
+    assert_eq!(
+        docs[0].get("title").and_then(|v| v.as_text()),
+        Some("second")
+    );
+    assert!(engine.get_documents("b").await?.is_empty());
+    assert_eq!(engine.stats()?.document_count, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_recovered_from_wal() -> laurus::Result<()> {
+    let storage = new_storage();
+    {
+        let engine = Engine::new(storage.clone(), schema()).await?;
+        engine.put_document("old", doc("rust")).await?;
+        engine.commit().await?;
+
+        let mut txn = engine.begin();
+        txn.delete_documents("old")
+            .put_document("a", doc("rust"))
+            .put_document("b", doc("rust"));
+        txn.commit().await?;
+        // No engine commit: the batch only lives in the WAL.
+    }
+
+    let engine = Engine::new(storage, schema()).await?;
+    engine.commit().await?;
+    assert_eq!(count(&engine, "title:rust").await?, 2);
+    assert!(engine.get_documents("old").await?.is_empty());
+    assert_eq!(engine.get_documents("a").await?.len(), 1);
+    assert_eq!(engine.stats()?.document_count, 2);
+
+    Ok(())
+}
@@ -0,0 +1,317 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use laurus::lexical::{TermQuery, TextOption};
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::vector::{FlatOption, QueryVector, Vector};
+use laurus::{
+    ByQueryOptions, ByQueryResponse, DateTimeOption, Document, Engine, ExpiryTask, FieldOption,
+    LexicalSearchQuery, Schema, SearchRequestBuilder, Storage, VectorSearchQuery,
+};
+
+fn ttl_schema() -> Schema {
+    Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .add_field(
+            "expires_at",
+            FieldOption::DateTime(DateTimeOption::default()),
+        )
+        .expiry_field("expires_at")
+        .build()
+}
+
+fn new_storage() -> Arc<dyn Storage> {
+    Arc::new(MemoryStorage::new(MemoryStorageConfig::default()))
+}
+
+async fn search_count(engine: &Engine, dsl: &str) -> laurus::Result<usize> {
+    let request = SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::from(dsl))
+        .limit(100)
+        .build();
+    Ok(engine.search(request).await?.len())
+}
+
+async fn populate(engine: &Engine) -> laurus::Result<()> {
+    engine
+        .put_document(
+            "expired",
+            Document::builder()
+                .add_text("body", "session")
+                .add_datetime("expires_at", Utc::now() - chrono::Duration::hours(1))
+                .build(),
+        )
+        .await?;
+    engine
+        .put_document_with_ttl(
+            "live",
+            Document::builder().add_text("body", "session").build(),
+            Duration::from_secs(3600),
+        )
+        .await?;
+    engine
+        .put_document(
+            "forever",
+            Document::builder().add_text("body", "session").build(),
+        )
+        .await?;
+    engine.commit().await
+}
+
+#[tokio::test]
+async fn test_expired_documents_are_invisible() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), ttl_schema()).await?;
+    populate(&engine).await?;
+
+    assert_eq!(search_count(&engine, "body:session").await?, 2);
+    assert!(engine.get_documents("expired").await?.is_empty());
+    assert_eq!(engine.get_documents("live").await?.len(), 1);
+    assert_eq!(engine.get_documents("forever").await?.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_expired_documents_do_not_shorten_pages() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), ttl_schema()).await?;
+    // Short expired documents outscore the longer live ones, so without
+    // filtering they would fill the whole top-k.
+    for i in 0..20 {
+        engine
+            .put_document(
+                &format!("expired{i}"),
+                Document::builder()
+                    .add_text("body", "session")
+                    .add_datetime("expires_at", Utc::now() - chrono::Duration::hours(1))
+                    .build(),
+            )
+            .await?;
+    }
+    for i in 0..3 {
+        engine
+            .put_document(
+                &format!("live{i}"),
+                Document::builder()
+                    .add_text("body", "session kept alive by a much longer body")
+                    .build(),
+            )
+            .await?;
+    }
+    engine.commit().await?;
+
+    let request = SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::from("body:session"))
+        .limit(3)
+        .build();
+    let mut ids: Vec<String> = engine
+        .search(request)
+        .await?
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec!["live0", "live1", "live2"]);
+
+    let request = SearchRequestBuilder::new()
+        .lexical_query(LexicalSearchQuery::from("body:session"))
+        .offset(2)
+        .limit(3)
+        .build();
+    assert_eq!(engine.search(request).await?.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_expired_documents_are_dropped_from_vector_search() -> laurus::Result<()> {
+    let schema = Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .add_field(
+            "embedding",
+            FieldOption::Flat(FlatOption {
+                dimension: 2,
+                ..Default::default()
+            }),
+        )
+        .add_field(
+            "expires_at",
+            FieldOption::DateTime(DateTimeOption::default()),
+        )
+        .expiry_field("expires_at")
+        .build();
+    let engine = Engine::new(new_storage(), schema).await?;
+    // The expired documents are the nearest neighbours, so they would fill
+    // the whole top-k if they were not skipped.
+    for i in 0..10 {
+        engine
+            .put_document(
+                &format!("expired{i}"),
+                Document::builder()
+                    .add_text("body", "session")
+                    .add_vector("embedding", vec![1.0, 0.01 * i as f32])
+                    .add_datetime("expires_at", Utc::now() - chrono::Duration::hours(1))
+                    .build(),
+            )
+            .await?;
+    }
+    for (i, body) in ["session", "other", "session"].into_iter().enumerate() {
+        engine
+            .put_document(
+                &format!("live{i}"),
+                Document::builder()
+                    .add_text("body", body)
+                    .add_vector("embedding", vec![0.5, 0.5 + 0.1 * i as f32])
+                    .build(),
+            )
+            .await?;
+    }
+    engine.commit().await?;
+
+    let search = |filter: Option<TermQuery>| {
+        let mut builder = SearchRequestBuilder::new()
+            .vector_query(VectorSearchQuery::Vectors(vec![QueryVector {
+                vector: Vector::new(vec![1.0, 0.0]),
+                weight: 1.0,
+                fields: None,
+            }]))
+            .limit(3);
+        if let Some(filter) = filter {
+            builder = builder.filter_query(Box::new(filter));
+        }
+        let engine = &engine;
+        async move {
+            let mut ids: Vec<String> = engine
+                .search(builder.build())
+                .await?
+                .into_iter()
+                .map(|r| r.id)
+                .collect();
+            ids.sort();
+            laurus::Result::Ok(ids)
+        }
+    };
+
+    assert_eq!(search(None).await?, vec!["live0", "live1", "live2"]);
+    assert_eq!(
+        search(Some(TermQuery::new("body", "session"))).await?,
+        vec!["live0", "live2"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purge_expired() -> laurus::Result<()> {
+    let engine = Engine::new(new_storage(), ttl_schema()).await?;
+    populate(&engine).await?;
+
+    let response = engine.purge_expired(ByQueryOptions::default()).await?;
+    assert_eq!(response.matched, 1);
+    assert_eq!(response.processed, 1);
+    engine.commit().await?;
+
+    assert_eq!(engine.stats()?.document_count, 2);
+    assert_eq!(search_count(&engine, "body:session").await?, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_purge_expired_survives_recovery() -> laurus::Result<()> {
+    let storage = new_storage();
+    {
+        let engine = Engine::new(storage.clone(), ttl_schema()).await?;
+        populate(&engine).await?;
+        engine.purge_expired(ByQueryOptions::default()).await?;
+        // No commit: the deletion only lives in the WAL.
+    }
+
+    let engine = Engine::new(storage, ttl_schema()).await?;
+    engine.commit().await?;
+    assert_eq!(engine.stats()?.document_count, 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_expiry_task() -> laurus::Result<()> {
+    let engine = Arc::new(Engine::new(new_storage(), ttl_schema()).await?);
+    populate(&engine).await?;
+
+    let task = ExpiryTask::spawn(engine.clone(), Duration::from_millis(20));
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    task.stop().await;
+
+    engine.commit().await?;
+    assert_eq!(engine.stats()?.document_count, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_expiry_task_follows_replaced_engine() -> laurus::Result<()> {
+    let slot = Arc::new(tokio::sync::RwLock::new(None::<Engine>));
+    let task_slot = slot.clone();
+    let task = ExpiryTask::spawn_with(Duration::from_millis(20), move |options| {
+        let slot = task_slot.clone();
+        async move {
+            match slot.read().await.as_ref() {
+                Some(engine) => engine.purge_expired(options).await,
+                None => Ok(ByQueryResponse::default()),
+            }
+        }
+    });
+
+    // The engine is put in place after the task has started.
+    let engine = Engine::new(new_storage(), ttl_schema()).await?;
+    populate(&engine).await?;
+    *slot.write().await = Some(engine);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    task.stop().await;
+
+    let guard = slot.read().await;
+    let engine = guard.as_ref().unwrap();
+    engine.commit().await?;
+    assert_eq!(engine.stats()?.document_count, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_ttl_requires_expiry_field() -> laurus::Result<()> {
+    let schema = Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .build();
+    let engine = Engine::new(new_storage(), schema).await?;
+
+    let result = engine
+        .put_document_with_ttl(
+            "doc1",
+            Document::builder().add_text("body", "hello").build(),
+            Duration::from_secs(60),
+        )
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_expiry_field_rejected() {
+    let schema = Schema::builder()
+        .add_field("body", FieldOption::Text(TextOption::default()))
+        .expiry_field("body")
+        .build();
+    assert!(Engine::new(new_storage(), schema).await.is_err());
+
+    let schema = Schema::builder()
+        .add_field(
+            "expires_at",
+            FieldOption::DateTime(DateTimeOption::default().stored(false)),
+        )
+        .expiry_field("expires_at")
+        .build();
+    assert!(Engine::new(new_storage(), schema).await.is_err());
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use laurus::lexical::TextOption;
+use laurus::storage::memory::{MemoryStorage, MemoryStorageConfig};
+use laurus::{
+    AnalyzerDefinition, Document, Engine, FieldOption, Schema, SearchRequestBuilder,
+    TokenFilterConfig, TokenizerConfig,
+};
+
+/// Index product names split into subwords, keeping the catenated and
+/// original forms as well.
+fn product_schema() -> Schema {
+    Schema::builder()
+        .add_analyzer(
+            "product",
+            AnalyzerDefinition {
+                char_filters: vec![],
+                tokenizer: TokenizerConfig::Whitespace,
+                token_filters: vec![
+                    TokenFilterConfig::WordDelimiterGraph {
+                        generate_word_parts: true,
+                        generate_number_parts: true,
+                        split_on_case_change: true,
+                        split_on_numerics: true,
+                        catenate_words: true,
+                        catenate_numbers: false,
+                        catenate_all: true,
+                        preserve_original: true,
+                    },
+                    TokenFilterConfig::Lowercase,
+                ],
+            },
+        )
+        .add_field(
+            "name",
+            FieldOption::Text(TextOption::default().analyzer("product")),
+        )
+        .build()
+}
+
+/// Index German text with compounds split by a dictionary.
+fn german_schema() -> Schema {
+    Schema::builder()
+        .add_analyzer(
+            "german_compounds",
+            AnalyzerDefinition {
+                char_filters: vec![],
+                tokenizer: TokenizerConfig::Whitespace,
+                token_filters: vec![
+                    TokenFilterConfig::Lowercase,
+                    TokenFilterConfig::DictionaryDecompounder {
+                        words: vec!["dampf".into(), "schiff".into(), "fahrt".into()],
+                        min_word_size: 5,
+                        min_subword_size: 2,
+                        max_subword_size: 15,
+                        only_longest_match: false,
+                    },
+                ],
+            },
+        )
+        .add_field(
+            "title",
+            FieldOption::Text(TextOption::default().analyzer("german_compounds")),
+        )
+        .build()
+}
+
+async fn populate(schema: Schema, field: &str, values: &[&str]) -> laurus::Result<Engine> {
+    let storage = Arc::new(MemoryStorage::new(MemoryStorageConfig::default()));
+    let engine = Engine::new(storage, schema).await?;
+    for (i, value) in values.iter().enumerate() {
+        engine
+            .put_document(
+                &(i + 1).to_string(),
+                Document::builder().add_text(field, *value).build(),
+            )
+            .await?;
+    }
+    engine.commit().await?;
+    Ok(engine)
+}
+
+async fn search_ids(engine: &Engine, query: &str) -> laurus::Result<Vec<String>> {
+    let results = engine
+        .search(
+            SearchRequestBuilder::new()
+                .query_dsl(query)
+                .limit(10)
+                .build(),
+        )
+        .await?;
+    let mut ids: Vec<String> = results.into_iter().map(|r| r.id).collect();
+    ids.sort();
+    Ok(ids)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_word_delimiter_graph_matches_parts_and_catenations() -> laurus::Result<()> {
+    let engine = populate(
+        product_schema(),
+        "name",
+        &["Canon PowerShot-SD500", "Apple iPhone15Pro", "WiFi router"],
+    )
+    .await?;
+
+    assert_eq!(search_ids(&engine, "name:sd500").await?, vec!["1"]);
+    assert_eq!(search_ids(&engine, "name:PowerShot").await?, vec!["1"]);
+    assert_eq!(search_ids(&engine, "name:shot").await?, vec!["1"]);
+    assert_eq!(
+        search_ids(&engine, r#"name:"PowerShot-SD500""#).await?,
+        vec!["1"]
+    );
+    assert_eq!(search_ids(&engine, "name:iphone").await?, vec!["2"]);
+    assert_eq!(search_ids(&engine, "name:iPhone15Pro").await?, vec!["2"]);
+    assert_eq!(search_ids(&engine, "name:wifi").await?, vec!["3"]);
+    assert_eq!(search_ids(&engine, r#"name:"Wi-Fi""#).await?, vec!["3"]);
+    assert!(search_ids(&engine, "name:sd600").await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_dictionary_decompounder_matches_subwords() -> laurus::Result<()> {
+    let engine = populate(
+        german_schema(),
+        "title",
+        &["Dampfschifffahrt", "Segelschiff", "Dampflok"],
+    )
+    .await?;
+
+    assert_eq!(search_ids(&engine, "title:schiff").await?, vec!["1", "2"]);
+    assert_eq!(search_ids(&engine, "title:dampf").await?, vec!["1", "3"]);
+    assert_eq!(search_ids(&engine, "title:fahrt").await?, vec!["1"]);
+    Ok(())
+}